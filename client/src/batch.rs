@@ -11,20 +11,28 @@ pub struct Batch<T: Model> {
 
 impl<T: Model> Batch<T> {
     pub fn insert(&mut self, instance: T) {
-        let insert_request = instance.to_insert_request();
-        let mut batch_item = BatchItem::new();
-        batch_item.item = Some(BatchItemData::Insert(insert_request));
-        self.items.push(batch_item);
+        self.items.push(insert_batch_item(&instance));
     }
 
     pub fn delete(&mut self, hash_key: String, sort_key: Value) {
-        let delete_request = create_delete_request(hash_key, sort_key);
-        let mut batch_item = BatchItem::new();
-        batch_item.item = Some(BatchItemData::Delete(delete_request));
-        self.items.push(batch_item);
+        self.items.push(delete_batch_item(hash_key, sort_key));
     }
 }
 
+pub(crate) fn insert_batch_item<T: Model>(instance: &T) -> BatchItem {
+    let mut batch_item = BatchItem::new();
+    batch_item.item = Some(BatchItemData::Insert(instance.to_insert_request()));
+    batch_item
+}
+
+pub(crate) fn delete_batch_item(hash_key: String, sort_key: Value) -> BatchItem {
+    let mut batch_item = BatchItem::new();
+    batch_item.item = Some(BatchItemData::Delete(create_delete_request(
+        hash_key, sort_key,
+    )));
+    batch_item
+}
+
 pub fn get_batch_item_hash_key(batch_item: &BatchItem) -> String {
     match batch_item.item.as_ref().unwrap() {
         BatchItemData::Insert(insert) => insert.hash_key.clone(),
@@ -0,0 +1,90 @@
+use crate::batch::{Batch, GetMany};
+use crate::connection::Connection;
+use crate::error::ConnectionError;
+use crate::model::{Model, VersionedModel};
+use crate::template::WriteTemplate;
+use common::value::Value;
+use std::net::SocketAddrV4;
+use tokio::runtime::{Builder, Runtime};
+
+// connection is declared first, so that it is dropped while runtime is still alive
+pub struct BlockingConnection {
+    connection: Connection,
+    runtime: Runtime,
+}
+
+impl BlockingConnection {
+    pub fn new(addr: SocketAddrV4) -> Result<BlockingConnection, ConnectionError> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        let connection = runtime.block_on(Connection::new(addr))?;
+
+        Ok(BlockingConnection {
+            connection,
+            runtime,
+        })
+    }
+
+    pub fn get<T: Model>(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<Option<T>, ConnectionError> {
+        self.runtime
+            .block_on(self.connection.get(hash_key, sort_key))
+    }
+
+    pub fn insert<T: Model>(&self, instance: T) -> Result<(), ConnectionError> {
+        self.runtime.block_on(self.connection.insert(instance))
+    }
+
+    pub fn delete(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        table_name: &str,
+    ) -> Result<bool, ConnectionError> {
+        self.runtime
+            .block_on(self.connection.delete(hash_key, sort_key, table_name))
+    }
+
+    pub fn get_many<T: Model>(&self, get_many: GetMany<T>) -> Result<Vec<T>, ConnectionError> {
+        self.runtime.block_on(self.connection.get_many(get_many))
+    }
+
+    pub fn get_many_optional<T: Model>(
+        &self,
+        get_many: GetMany<T>,
+    ) -> Result<Vec<Option<T>>, ConnectionError> {
+        self.runtime
+            .block_on(self.connection.get_many_optional(get_many))
+    }
+
+    pub fn get_many_consistent<T: Model>(
+        &self,
+        get_many: GetMany<T>,
+    ) -> Result<Vec<T>, ConnectionError> {
+        self.runtime
+            .block_on(self.connection.get_many_consistent(get_many))
+    }
+
+    pub fn batch<T: Model>(&self, batch: Batch<T>) -> Result<bool, ConnectionError> {
+        self.runtime.block_on(self.connection.batch(batch))
+    }
+
+    pub fn save_template<S>(
+        &self,
+        template: &WriteTemplate<S>,
+        source: &S,
+    ) -> Result<bool, ConnectionError> {
+        self.runtime
+            .block_on(self.connection.save_template(template, source))
+    }
+
+    pub fn save<T: VersionedModel>(&self, instance: &mut T) -> Result<(), ConnectionError> {
+        self.runtime.block_on(self.connection.save(instance))
+    }
+
+    pub fn sync_model<T: Model>(&self) -> Result<(), ConnectionError> {
+        self.runtime.block_on(self.connection.sync_model::<T>())
+    }
+}
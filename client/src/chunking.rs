@@ -0,0 +1,94 @@
+use protobuf::Message;
+
+// batches and get_many requests are split into chunks per partition, so that callers can hand over
+// any number of items without building huge frames or flooding a single server thread
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChunkingPolicy {
+    pub max_items: usize,
+    // encoded size of items, a single larger item is still sent as a chunk of its own
+    pub max_bytes: usize,
+    // chunks sent to a server thread waiting for response at once, thread serves all its partitions
+    pub max_in_flight_per_partition: usize,
+    // encoded size of rows a get_many response is filled with, rest of chunk is asked for in
+    // further pages, 0 for the server's limit
+    pub max_response_bytes: usize,
+}
+
+impl Default for ChunkingPolicy {
+    fn default() -> ChunkingPolicy {
+        ChunkingPolicy {
+            max_items: 1000,
+            max_bytes: 1024 * 1024,
+            max_in_flight_per_partition: 4,
+            max_response_bytes: 16 * 1024 * 1024,
+        }
+    }
+}
+
+// order of items is kept, every chunk has at least one item
+pub(crate) fn split_into_chunks<M: Message>(items: Vec<M>, policy: &ChunkingPolicy) -> Vec<Vec<M>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_bytes = 0;
+    for item in items {
+        let item_bytes = item.compute_size() as usize;
+        if !chunk.is_empty()
+            && (chunk.len() >= policy.max_items || chunk_bytes + item_bytes > policy.max_bytes)
+        {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_bytes = 0;
+        }
+        chunk.push(item);
+        chunk_bytes += item_bytes;
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::GetRequest;
+
+    fn get_request(hash_key: &str) -> GetRequest {
+        let mut get_request = GetRequest::new();
+        get_request.hash_key = hash_key.to_string();
+        get_request
+    }
+
+    #[test]
+    fn items_are_split_by_count_and_size() {
+        let policy = ChunkingPolicy {
+            max_items: 2,
+            max_bytes: 12,
+            max_in_flight_per_partition: 1,
+            max_response_bytes: 0,
+        };
+        // every small item takes 3 bytes, big one takes 22
+        let items = vec![
+            get_request("a"),
+            get_request("b"),
+            get_request("c"),
+            get_request(&"x".repeat(20)),
+            get_request("d"),
+        ];
+
+        let chunk_hash_keys: Vec<Vec<String>> = split_into_chunks(items, &policy)
+            .into_iter()
+            .map(|chunk| chunk.into_iter().map(|item| item.hash_key).collect())
+            .collect();
+        assert_eq!(
+            chunk_hash_keys,
+            vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string()],
+                vec!["x".repeat(20)],
+                vec!["d".to_string()],
+            ]
+        );
+        assert!(split_into_chunks(Vec::<GetRequest>::new(), &policy).is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error::ConnectionError;
+use std::time::{Duration, Instant};
+
+static FAILURE_THRESHOLD: u32 = 5;
+static COOLDOWN: Duration = Duration::from_secs(5);
+
+#[derive(Debug, PartialEq)]
+enum CircuitState {
+    Closed,
+    Open(Instant),
+    // cooldown passed, single probe request is let through
+    HalfOpen,
+}
+
+pub(crate) struct CircuitBreaker {
+    state: CircuitState,
+    consecutive_failures: u32,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new() -> CircuitBreaker {
+        CircuitBreaker {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+        }
+    }
+
+    // returns whether stream has to be reconnected before sending, previous failure could leave it half read
+    pub(crate) fn before_request(&mut self, thread: usize) -> Result<bool, ConnectionError> {
+        match self.state {
+            CircuitState::Closed => Ok(self.consecutive_failures > 0),
+            CircuitState::Open(opened_at) if opened_at.elapsed() >= COOLDOWN => {
+                self.state = CircuitState::HalfOpen;
+                Ok(true)
+            }
+            _ => Err(ConnectionError::CircuitOpen(thread)),
+        }
+    }
+
+    pub(crate) fn record_success(&mut self) {
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+    }
+
+    pub(crate) fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+        if self.state == CircuitState::HalfOpen || self.consecutive_failures >= FAILURE_THRESHOLD {
+            self.state = CircuitState::Open(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_opens_after_consecutive_failures_and_half_opens_after_cooldown() {
+        let mut circuit_breaker = CircuitBreaker::new();
+        for _ in 0..FAILURE_THRESHOLD {
+            assert!(circuit_breaker.before_request(0).is_ok());
+            circuit_breaker.record_failure();
+        }
+        assert!(matches!(
+            circuit_breaker.before_request(0),
+            Err(ConnectionError::CircuitOpen(0))
+        ));
+
+        circuit_breaker.state = CircuitState::Open(Instant::now() - COOLDOWN);
+        assert!(circuit_breaker.before_request(0).unwrap());
+        // only one probe is let through while half open
+        assert!(circuit_breaker.before_request(0).is_err());
+
+        circuit_breaker.record_failure();
+        assert!(circuit_breaker.before_request(0).is_err());
+
+        circuit_breaker.state = CircuitState::HalfOpen;
+        circuit_breaker.record_success();
+        assert!(!circuit_breaker.before_request(0).unwrap());
+    }
+}
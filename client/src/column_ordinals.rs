@@ -0,0 +1,76 @@
+use crate::Model;
+use protos::{GetResponse, InsertRequest};
+use storage::table::TableSchema;
+
+// values of models are sent by ordinal of their column in model's schema instead of repeating
+// column names with every row, server answers with rows by name when its schema has another version
+pub(crate) fn insert_by_ordinal(insert_request: &mut InsertRequest, table_schema: &TableSchema) {
+    let mut values = std::mem::take(&mut insert_request.values);
+    insert_request.column_values = table_schema
+        .columns
+        .keys()
+        .map(|name| values.remove(name).unwrap_or_default())
+        .collect();
+}
+
+// models read their values by name
+pub(crate) fn model_from_get_response<T: Model>(mut get_response: GetResponse) -> T {
+    if !get_response.column_values.is_empty() {
+        let column_values = std::mem::take(&mut get_response.column_values);
+        get_response
+            .values
+            .extend(T::table_schema().columns.into_keys().zip(column_values));
+    }
+    T::from_get_response(get_response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as client;
+    use client::Model;
+    use common::value::Value::*;
+    use macros::DatabaseModel;
+    use protos::util::{
+        parse_message_field_from_value, parse_proto_from_value, parse_value_from_message_field,
+        parse_value_from_proto,
+    };
+    use protos::{DeleteRequest, GetResponse, InsertRequest};
+    use std::collections::HashMap;
+
+    #[derive(DatabaseModel, Debug, PartialEq)]
+    struct Account {
+        hash_key: String,
+        sort_key: i32,
+        name: String,
+        balance: Option<i64>,
+    }
+
+    #[test]
+    fn models_are_sent_and_read_by_ordinal() {
+        let account = Account {
+            hash_key: "account_1".to_string(),
+            sort_key: -1,
+            name: "ann".to_string(),
+            balance: None,
+        };
+        let table_schema = Account::table_schema();
+        let mut insert_request = account.to_insert_request();
+        insert_by_ordinal(&mut insert_request, &table_schema);
+
+        assert!(insert_request.values.is_empty());
+        let column_values: Vec<_> = insert_request
+            .column_values
+            .iter()
+            .cloned()
+            .map(parse_value_from_proto)
+            .collect();
+        assert_eq!(column_values, vec![Null, Varchar("ann".to_string())]);
+
+        let mut get_response = GetResponse::new();
+        get_response.hash_key = insert_request.hash_key;
+        get_response.sort_key = insert_request.sort_key;
+        get_response.column_values = insert_request.column_values;
+        assert_eq!(model_from_get_response::<Account>(get_response), account);
+    }
+}
@@ -1,58 +1,238 @@
 use crate::batch::{get_batch_item_hash_key, Batch, GetMany};
-use crate::connection_util::{create_delete_request, create_get_request};
-use crate::model::Model;
+use crate::chunking::{split_into_chunks, ChunkingPolicy};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::column_ordinals::{insert_by_ordinal, model_from_get_response};
+use crate::connection_util::{
+    create_delete_request, create_get_request, set_transaction, validate_sort_key,
+};
+use crate::dynamic_row::DynamicRow;
+use crate::error::ConnectionError;
+use crate::hedging::{HedgingPolicy, ReadHedging};
+use crate::leader::{connect_streams, LeaderRouting};
+use crate::lease::Lease;
+use crate::model::{Model, VersionedModel};
+use crate::multiplexer::MultiplexedStream;
 use crate::pool::ConnectionPool;
-use crate::transaction::Transaction;
-use common::partition::get_hash_key_target_partition;
+use crate::prepared::PreparedRequest;
+use crate::schema_cache::{self, SchemaCacheKey};
+use crate::session::{ConsistencyToken, Session};
+use crate::template::{TableWrite, WriteTemplate};
+use crate::transaction::{ActiveTransaction, Transaction, TransactionScope};
+use common::partition::{get_partition_thread, PartitionMap};
 use common::value::Value;
-use protobuf::Message;
+use futures::stream::{self, Stream, StreamExt};
+use protobuf::{Message, MessageField};
+use protos::util::{
+    parse_message_field_from_value, parse_proto_from_value, parse_value_from_message_field,
+};
 use protos::{
-    AbortTransaction, BatchRequest, BeginTransaction, CommitTransaction, DropTableRequest,
-    GetManyRequest, ProtoRequest, ProtoRequestData, ProtoResponse, ProtoResponseData,
-    SyncModelRequest,
+    AbortTransaction, AcquireLeaseRequest, BatchItem, BatchRequest, BeginTransaction,
+    CommitTransaction, CompactionRequest, DeleteWhereRequest, DescribeServerRequest,
+    DescribeTableRequest, DropTableRequest, ErrorCode, GetManyRequest, GetRequest, GetResponse,
+    GetSequenceRequest, InsertRequest, KeyDistributionRequest, KillTransactionRequest,
+    ListTransactionsRequest, MigrateBucketRequest, MoveRowRequest, PartitionLoadRequest,
+    PartitionMapRequest, PlanMigrationRequest, PromoteRequest, ProtoRequest, ProtoRequestData,
+    ProtoResponse, ProtoResponseData, QueryByIndexRequest, ReadExplanation, ReleaseLeaseRequest,
+    RenewLeaseRequest, RowCountRequest, SaveRequest, SetReadOnlyRequest, SyncModelRequest,
+    SyncModelsRequest, TableAccessRequest,
 };
 use rand::{thread_rng, Rng};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
 use std::net::SocketAddrV4;
-use std::sync::{Arc, Weak};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::net::TcpStream;
-use tokio::sync::Mutex;
-use tokio::task::JoinSet;
+use std::ops::{Bound, RangeBounds};
+use std::pin::pin;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex as StdMutex, RwLock, Weak};
+use std::time::{Duration, Instant};
+use storage::data_dir::DataDir;
+use storage::sstable::{
+    CompactionBucket, CompactionSettings, CompactionSettingsUpdate, CompactionState, ReadTrace,
+    SSTableRead, TableCompactionState,
+};
+use storage::statistics::TableStatistics;
+use storage::table::TableSchema;
+use tokio::time::{sleep, timeout};
+
+// requests not answered in that time count as partition failure
+pub(crate) static REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// migration flushes memtables and rewrites sstables of every table
+static MIGRATE_BUCKET_TIMEOUT: Duration = Duration::from_secs(3600);
+
+// rows of a table held by partition, bytes are sized as encoded in sstables
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionRows {
+    pub partition: usize,
+    pub memtable_rows: u64,
+    pub sstable_rows: u64,
+    pub bytes: u64,
+}
+
+// requests to a partition over the last window closed by the server, hot partitions got many times
+// more requests than the median partition for several windows in a row
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionLoad {
+    pub partition: usize,
+    pub requests_per_second: f64,
+    pub average_latency: Duration,
+    pub skew: f64,
+    pub hot: bool,
+}
+
+// access of a table over the last minute the server closed, latencies are upper bounds of power of
+// two buckets, so at most twice the actual p99
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableAccess {
+    pub table: String,
+    pub reads_per_second: f64,
+    pub writes_per_second: f64,
+    pub read_bytes_per_second: f64,
+    pub written_bytes_per_second: f64,
+    pub read_p99_latency: Duration,
+    pub write_p99_latency: Duration,
+}
 
+// version, config and topology of the server, as support captures a deployment in one call
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerDescription {
+    pub version: String,
+    pub uptime: Duration,
+    pub number_of_threads: usize,
+    pub config: BTreeMap<String, String>,
+    pub features: Vec<String>,
+    pub databases: Vec<DatabaseDescription>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatabaseDescription {
+    pub name: String,
+    pub data_dir: DataDir,
+    // thread serving every partition, indexed by partition
+    pub partition_threads: Vec<usize>,
+}
+
+// can be shared between tasks, every server thread has a socket of its own that requests are
+// multiplexed over, so nothing stays locked for the duration of a request
 pub struct Connection {
-    pub(crate) inner: Arc<Mutex<ConnectionInner>>,
+    // replaced once pooled connection sets a session
+    inner: RwLock<Arc<ConnectionInner>>,
+    // whether sockets are shared with other connections of the pool, held while session is set
+    shares_sockets: tokio::sync::Mutex<bool>,
     pub(crate) pool: Option<Weak<ConnectionPool>>,
 }
 
 impl Connection {
     pub async fn new(addr: SocketAddrV4) -> Result<Connection, ConnectionError> {
+        Connection::new_for_database(addr, "").await
+    }
+
+    // server may host several databases, empty name is the default one
+    pub async fn new_for_database(
+        addr: SocketAddrV4,
+        database: &str,
+    ) -> Result<Connection, ConnectionError> {
         Ok(Connection {
-            inner: Arc::new(Mutex::new(ConnectionInner::new(addr).await?)),
+            inner: RwLock::new(Arc::new(ConnectionInner::new(addr, database).await?)),
+            shares_sockets: tokio::sync::Mutex::new(false),
             pool: None,
         })
     }
 
     pub(crate) fn new_for_pool(
-        inner: Arc<Mutex<ConnectionInner>>,
+        inner: Arc<ConnectionInner>,
         pool: Weak<ConnectionPool>,
     ) -> Connection {
         Connection {
-            inner,
+            inner: RwLock::new(inner),
+            shares_sockets: tokio::sync::Mutex::new(true),
             pool: Some(pool),
         }
     }
 
+    fn inner(&self) -> Arc<ConnectionInner> {
+        self.inner.read().unwrap().clone()
+    }
+
     pub async fn get<T: Model>(
         &self,
         hash_key: String,
         sort_key: Value,
     ) -> Result<Option<T>, ConnectionError> {
-        self.inner.lock().await.get(hash_key, sort_key, None).await
+        self.inner().get(hash_key, sort_key, None, None).await
     }
 
     pub async fn insert<T: Model>(&self, instance: T) -> Result<(), ConnectionError> {
-        self.inner.lock().await.insert(instance, None).await
+        self.inner().insert(instance, None).await?;
+        Ok(())
+    }
+
+    // fails instead of returning state older than the write token was returned by
+    pub async fn get_with_token<T: Model>(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        min_consistency_token: &ConsistencyToken,
+    ) -> Result<Option<T>, ConnectionError> {
+        self.inner()
+            .get(hash_key, sort_key, None, Some(min_consistency_token))
+            .await
+    }
+
+    pub async fn insert_with_token<T: Model>(
+        &self,
+        instance: T,
+    ) -> Result<ConsistencyToken, ConnectionError> {
+        self.inner().insert(instance, None).await
+    }
+
+    // inserts model into append-only table, sort key of the model is ignored and the one assigned
+    // by server from sequence of its hash key is returned
+    pub async fn append<T: Model>(&self, instance: T) -> Result<u64, ConnectionError> {
+        self.inner().append(instance).await
+    }
+
+    // model without version is inserted only if its row does not exist yet, the one with version
+    // only if the row still has it, version of saved row is set on the model. Otherwise
+    // VersionConflict is returned and the model has to be read again
+    pub async fn save<T: VersionedModel>(&self, instance: &mut T) -> Result<(), ConnectionError> {
+        let version = self.inner().save(&*instance).await?;
+        instance.set_version(version);
+        Ok(())
+    }
+
+    // get without a model, values of every column are returned as they are
+    pub async fn get_row(
+        &self,
+        table_name: &str,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<Option<DynamicRow>, ConnectionError> {
+        self.inner().get_row(table_name, hash_key, sort_key).await
+    }
+
+    // get_row along with memtable and sstables the server consulted to read it
+    pub async fn explain_get_row(
+        &self,
+        table_name: &str,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<(Option<DynamicRow>, ReadTrace), ConnectionError> {
+        self.inner()
+            .explain_get_row(table_name, hash_key, sort_key)
+            .await
+    }
+
+    // insert without a model, values have to match table schema
+    pub async fn insert_values(
+        &self,
+        table_name: &str,
+        hash_key: String,
+        sort_key: Value,
+        values: HashMap<String, Value>,
+    ) -> Result<(), ConnectionError> {
+        self.inner()
+            .insert_values(table_name, hash_key, sort_key, values)
+            .await
     }
 
     pub async fn delete(
@@ -61,274 +241,1567 @@ impl Connection {
         sort_key: Value,
         table_name: &str,
     ) -> Result<bool, ConnectionError> {
-        self.inner
-            .lock()
-            .await
+        self.inner()
             .delete(hash_key, sort_key, table_name, None)
             .await
     }
 
+    // deletes models of hash key with sort key in range, e.g. delete_where("user_1", Int32(0)..Int32(100), None),
+    // filter limits it to models having the value of column, returns number of deleted models
+    pub async fn delete_where<T: Model>(
+        &self,
+        hash_key: String,
+        range: impl RangeBounds<Value>,
+        filter: Option<(&str, Value)>,
+    ) -> Result<u64, ConnectionError> {
+        self.inner()
+            .delete_where::<T>(hash_key, range, filter)
+            .await
+    }
+
+    // moves model to new key without a window in which it is under both or neither of them,
+    // returns false if there is no model under old key. Existing model under new key is not
+    // overwritten. Keys of different partitions are moved by a transaction, which only keeps
+    // values, version is kept by moves within a partition
+    pub async fn move_row<T: Model>(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        new_hash_key: String,
+        new_sort_key: Value,
+    ) -> Result<bool, ConnectionError> {
+        if self.inner().get_partition(&hash_key) == self.inner().get_partition(&new_hash_key) {
+            return self
+                .inner()
+                .move_row::<T>(hash_key, sort_key, new_hash_key, new_sort_key)
+                .await;
+        }
+
+        let mut transaction = self.begin_deferred_transaction();
+        let moved = transaction
+            .get_for_update::<T>(hash_key.clone(), sort_key.clone())
+            .await?;
+        let Some(moved) = moved else {
+            transaction.abort().await?;
+            return Ok(false);
+        };
+        let existing = transaction
+            .get_for_update::<T>(new_hash_key.clone(), new_sort_key.clone())
+            .await?;
+        if existing.is_some() {
+            transaction.abort().await?;
+            return Err(ConnectionError::Validation(format!(
+                "Model under hash key '{}' already exists, it is not overwritten by a move",
+                new_hash_key
+            )));
+        }
+        transaction
+            .insert(with_key(&moved, new_hash_key, new_sort_key))
+            .await?;
+        // model got deleted since it was read for update
+        if !transaction
+            .delete(hash_key.clone(), sort_key, &T::table_name())
+            .await?
+        {
+            transaction.abort().await?;
+            return Ok(false);
+        }
+        transaction.commit().await?;
+        Ok(true)
+    }
+
     pub async fn get_many<T: Model>(
         &self,
         get_many: GetMany<T>,
     ) -> Result<Vec<T>, ConnectionError> {
-        self.inner.lock().await.get_many(get_many, None).await
+        self.inner().get_many(get_many, None).await
+    }
+
+    // every key has to be in the same partition, models are read in a single request without any
+    // write to the partition in between, e.g. a batch is seen either whole or not at all
+    pub async fn get_many_consistent<T: Model>(
+        &self,
+        get_many: GetMany<T>,
+    ) -> Result<Vec<T>, ConnectionError> {
+        self.inner().get_many_consistent(get_many, None).await
+    }
+
+    // one per added key in order keys were added, None for keys that were not found
+    pub async fn get_many_optional<T: Model>(
+        &self,
+        get_many: GetMany<T>,
+    ) -> Result<Vec<Option<T>>, ConnectionError> {
+        self.inner().get_many_optional(get_many, None).await
+    }
+
+    // yields models partition by partition, so that one slow partition does not hold up the others
+    pub fn get_many_stream<T: Model + 'static>(
+        &self,
+        get_many: GetMany<T>,
+    ) -> impl Stream<Item = Result<T, ConnectionError>> + 'static {
+        self.inner().get_many_stream(get_many, None)
+    }
+
+    // models with value of indexed column in range, ordered by that value, e.g. query_index("age", 30..)
+    pub async fn query_index<T: Model>(
+        &self,
+        column: &str,
+        range: impl RangeBounds<Value>,
+    ) -> Result<Vec<T>, ConnectionError> {
+        let (models, _) = self
+            .inner()
+            .query_index_page(column, range, 0, String::new())
+            .await?;
+        Ok(models)
+    }
+
+    // at most limit models past the ones answered with continuation token, which is empty for the
+    // first page and once there are no more of them, e.g.
+    // let (models, token) = connection.query_index_page::<User>("age", 30.., 100, token).await?
+    pub async fn query_index_page<T: Model>(
+        &self,
+        column: &str,
+        range: impl RangeBounds<Value>,
+        limit: u32,
+        continuation_token: String,
+    ) -> Result<(Vec<T>, String), ConnectionError> {
+        self.inner()
+            .query_index_page(column, range, limit, continuation_token)
+            .await
+    }
+
+    pub async fn acquire_lease(
+        &self,
+        name: String,
+        table_name: &str,
+        ttl: Duration,
+    ) -> Result<Lease, ConnectionError> {
+        let mut acquire_lease_request = AcquireLeaseRequest::new();
+        acquire_lease_request.name = name.clone();
+        acquire_lease_request.ttl_millis = ttl.as_millis() as u64;
+
+        let (version, expires_at) = self
+            .inner()
+            .send_lease_request(
+                &name,
+                table_name,
+                ProtoRequestData::AcquireLease(acquire_lease_request),
+            )
+            .await?;
+
+        Ok(Lease {
+            name,
+            table_name: table_name.to_string(),
+            version,
+            expires_at,
+            ttl,
+        })
+    }
+
+    pub async fn renew_lease(&self, lease: &mut Lease) -> Result<(), ConnectionError> {
+        let mut renew_lease_request = RenewLeaseRequest::new();
+        renew_lease_request.name = lease.name.clone();
+        renew_lease_request.version = lease.version;
+        renew_lease_request.ttl_millis = lease.ttl.as_millis() as u64;
+
+        let (_, expires_at) = self
+            .inner()
+            .send_lease_request(
+                &lease.name,
+                &lease.table_name,
+                ProtoRequestData::RenewLease(renew_lease_request),
+            )
+            .await?;
+
+        lease.expires_at = expires_at;
+        Ok(())
+    }
+
+    pub async fn release_lease(&self, lease: Lease) -> Result<(), ConnectionError> {
+        let mut release_lease_request = ReleaseLeaseRequest::new();
+        release_lease_request.name = lease.name.clone();
+        release_lease_request.version = lease.version;
+
+        self.inner()
+            .send_lease_request(
+                &lease.name,
+                &lease.table_name,
+                ProtoRequestData::ReleaseLease(release_lease_request),
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn next_sequence(
+        &self,
+        hash_key: String,
+        table_name: &str,
+    ) -> Result<u64, ConnectionError> {
+        self.inner().next_sequence(hash_key, table_name).await
+    }
+
+    pub async fn row_count<T: Model>(&self, hash_key: String) -> Result<u64, ConnectionError> {
+        self.inner().row_count::<T>(hash_key).await
     }
 
     pub async fn batch<T: Model>(&self, batch: Batch<T>) -> Result<bool, ConnectionError> {
-        self.inner.lock().await.batch(batch, None).await
+        self.inner().batch(batch, None).await
+    }
+
+    // one logical save fanned out by template into writes of several tables, not atomic across partitions
+    pub async fn save_template<S>(
+        &self,
+        template: &WriteTemplate<S>,
+        source: &S,
+    ) -> Result<bool, ConnectionError> {
+        self.inner()
+            .apply_writes(template.writes(source), None)
+            .await
     }
 
     pub async fn begin_transaction(&self) -> Result<Transaction, ConnectionError> {
         let (transaction_id, coordinator_partition) =
-            self.inner.lock().await.begin_transaction().await?;
+            self.inner().begin_transaction(None, None).await?;
         Ok(Transaction::new(
             transaction_id,
-            coordinator_partition,
-            self.inner.clone(),
+            Some(coordinator_partition),
+            self.inner().clone(),
         ))
     }
 
-    pub async fn sync_model<T: Model>(&self) -> Result<(), ConnectionError> {
-        self.inner.lock().await.sync_model::<T>().await
+    // transaction is begun by server thread its first operation goes to, along with that operation,
+    // transaction without any operation is never sent to server at all
+    pub fn begin_deferred_transaction(&self) -> Transaction {
+        Transaction::new(thread_rng().gen(), None, self.inner().clone())
     }
 
-    pub async fn drop_table(&self, table_name: String) -> Result<(), ConnectionError> {
-        self.inner.lock().await.drop_table(table_name).await
+    // transactions open on any partition, oldest first
+    pub async fn list_transactions(&self) -> Result<Vec<ActiveTransaction>, ConnectionError> {
+        self.inner().list_transactions().await
     }
-}
 
-pub(crate) struct ConnectionInner {
-    streams: HashMap<usize, Arc<Mutex<TcpStream>>>,
-}
+    // aborts transaction on every partition, its client gets an error on next request or commit
+    pub async fn kill_transaction(&self, transaction_id: u64) -> Result<(), ConnectionError> {
+        self.inner().kill_transaction(transaction_id).await
+    }
 
-#[derive(Debug)]
-pub enum ConnectionError {
-    Client(String),
-    Server(String),
-}
+    pub async fn sync_model<T: Model>(&self) -> Result<(), ConnectionError> {
+        self.inner().sync_schema(T::table_schema()).await
+    }
 
-impl ConnectionInner {
-    pub(crate) async fn new(address: SocketAddrV4) -> Result<ConnectionInner, ConnectionError> {
-        let mut stream = TcpStream::connect(address).await.map_err(|e| {
-            ConnectionError::Client(format!("Failed to connect to server: {}", e.to_string()))
-        })?;
+    pub async fn sync_schema(&self, table_schema: TableSchema) -> Result<(), ConnectionError> {
+        self.inner().sync_schema(table_schema).await
+    }
 
-        let num_of_threads = stream
-            .read_u32()
-            .await
-            .map_err(|e| ConnectionError::Server(e.to_string()))?;
+    // either every table is created or synced, or none of them is
+    pub async fn sync_schemas(
+        &self,
+        table_schemas: Vec<TableSchema>,
+    ) -> Result<(), ConnectionError> {
+        self.inner().sync_schemas(table_schemas).await
+    }
 
-        let mut streams = HashMap::from([(0, Arc::new(Mutex::new(stream)))]);
+    // cached for the whole process until DDL flows through it or server reports schema mismatch
+    pub async fn describe_table(&self, table_name: &str) -> Result<TableSchema, ConnectionError> {
+        self.inner().table_schema(table_name).await
+    }
 
-        let starting_port = address.port();
-        let last_port = starting_port + num_of_threads as u16;
-        for (partition, port) in (starting_port..last_port).enumerate().skip(1) {
-            let new_address = SocketAddrV4::new(address.ip().clone(), port);
-            let mut stream = TcpStream::connect(new_address).await.map_err(|e| {
-                ConnectionError::Client(format!("Failed to connect to server: {}", e.to_string()))
-            })?;
+    // estimates kept on flush and compaction, see storage::statistics::TableStatistics
+    pub async fn table_statistics(
+        &self,
+        table_name: &str,
+    ) -> Result<TableStatistics, ConnectionError> {
+        let (_, table_statistics) = self.inner().describe_table(table_name).await?;
+        Ok(table_statistics)
+    }
 
-            stream
-                .read_u32()
-                .await
-                .map_err(|e| ConnectionError::Server(e.to_string()))?;
-            streams.insert(partition, Arc::new(Mutex::new(stream)));
-        }
+    pub async fn drop_table(&self, table_name: String) -> Result<(), ConnectionError> {
+        self.inner().drop_table(table_name).await
+    }
 
-        Ok(ConnectionInner { streams })
+    // writes to read-only table are rejected until the flag is cleared
+    pub async fn set_read_only(
+        &self,
+        table_name: &str,
+        read_only: bool,
+    ) -> Result<(), ConnectionError> {
+        self.inner().set_read_only(table_name, read_only).await
     }
 
-    pub(crate) async fn get<T: Model>(
+    // differences from schema of existing table, sync model rejects any of them, so that the table
+    // has to be dropped and synced again to apply them
+    pub async fn plan_migration(
+        &self,
+        table_schema: TableSchema,
+    ) -> Result<Vec<String>, ConnectionError> {
+        self.inner().plan_migration(table_schema).await
+    }
+
+    // moves hash key's bucket, together with every other hash key in it, to target partition
+    pub async fn migrate_bucket(
         &self,
         hash_key: String,
-        sort_key: Value,
-        transaction_id: Option<u64>,
-    ) -> Result<Option<T>, ConnectionError> {
-        let partition = get_hash_key_target_partition(&hash_key, self.streams.len());
-        let get_request = create_get_request(hash_key, sort_key);
+        target_partition: usize,
+    ) -> Result<(), ConnectionError> {
+        self.inner()
+            .migrate_bucket(hash_key, target_partition)
+            .await
+    }
 
-        let mut request = ProtoRequest::new();
-        request.table = T::table_name();
-        request.data = Some(ProtoRequestData::Get(get_request));
-        request.transaction_id = transaction_id;
+    // compaction buckets of every table, settings that are set in update are changed first
+    pub async fn compaction(
+        &self,
+        update: CompactionSettingsUpdate,
+    ) -> Result<CompactionState, ConnectionError> {
+        self.inner().compaction(update).await
+    }
 
-        let proto_response = send_request(self.streams[&partition].clone(), request).await?;
+    // row counts of every partition, uneven counts point to skewed hash keys
+    pub async fn key_distribution(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<PartitionRows>, ConnectionError> {
+        self.inner().key_distribution(table_name).await
+    }
 
-        match proto_response.data {
-            None => Ok(None),
-            Some(proto_response_data) => match proto_response_data {
-                ProtoResponseData::Get(get_response) => {
-                    Ok(Some(T::from_get_response(get_response)))
-                }
-                ProtoResponseData::ClientError(client_error) => {
-                    Err(ConnectionError::Client(client_error.detail))
-                }
-                ProtoResponseData::ServerError(server_error) => {
-                    Err(ConnectionError::Server(server_error.detail))
-                }
-                _ => panic!("Invalid proto response type"),
-            },
+    // empty until the server closed its first window
+    pub async fn partition_load(&self) -> Result<Vec<PartitionLoad>, ConnectionError> {
+        self.inner().partition_load().await
+    }
+
+    // tables accessed within the last minute, ordered by name
+    pub async fn table_access(&self) -> Result<Vec<TableAccess>, ConnectionError> {
+        self.inner().table_access().await
+    }
+
+    pub async fn describe_server(&self) -> Result<ServerDescription, ConnectionError> {
+        self.inner().describe_server().await
+    }
+
+    // standby stops applying records of its leader and starts accepting writes, false if server
+    // was not a standby
+    pub async fn promote(&self) -> Result<bool, ConnectionError> {
+        self.inner().promote().await
+    }
+
+    // applies to every request sent afterwards, server keeps session per socket, so pooled
+    // connection moves to sockets of its own first rather than set it for the whole pool
+    pub async fn set_session(&self, session: Session) -> Result<(), ConnectionError> {
+        let mut shares_sockets = self.shares_sockets.lock().await;
+        if *shares_sockets {
+            let inner = self.inner().with_own_sockets().await?;
+            *self.inner.write().unwrap() = Arc::new(inner);
+            *shares_sockets = false;
         }
+        self.inner().set_session(session).await
     }
 
-    pub(crate) async fn insert<T: Model>(
-        &self,
-        instance: T,
-        transaction_id: Option<u64>,
-    ) -> Result<(), ConnectionError> {
-        let partition = get_hash_key_target_partition(&instance.hash_key(), self.streams.len());
-        let insert_request = instance.to_insert_request();
+    // shared by every connection of a pool but those which set a session, None disables hedging
+    pub fn set_read_hedging(&self, hedging_policy: Option<HedgingPolicy>) {
+        self.inner().set_read_hedging(hedging_policy)
+    }
 
-        let mut request = ProtoRequest::new();
-        request.table = T::table_name();
-        request.transaction_id = transaction_id;
+    // reads without transaction go to the server connection was opened to even if it follows
+    // another one, they may miss latest writes then. Shared like read hedging
+    pub fn set_read_from_followers(&self, read_from_followers: bool) {
+        self.inner()
+            .leader_routing
+            .read_from_followers
+            .store(read_from_followers, Ordering::Relaxed);
+    }
 
-        request.data = Some(ProtoRequestData::Insert(insert_request));
+    // shared like read hedging, applies to batches and get_many
+    pub fn set_chunking(&self, chunking_policy: ChunkingPolicy) {
+        *self.inner().chunking_policy.write().unwrap() = chunking_policy;
+    }
 
-        let proto_response = send_request(self.streams[&partition].clone(), request).await?;
+    pub async fn refresh_partition_map(&self) -> Result<(), ConnectionError> {
+        self.inner().fetch_partition_map().await
+    }
 
-        match proto_response.data.unwrap() {
-            ProtoResponseData::Insert(_) => Ok(()),
-            ProtoResponseData::ClientError(client_error) => {
-                Err(ConnectionError::Client(client_error.detail))
-            }
-            ProtoResponseData::ServerError(server_error) => {
-                Err(ConnectionError::Server(server_error.detail))
-            }
-            _ => panic!("Invalid proto response type"),
-        }
+    // meant to be kept for hot loops, works for transactions of any connection as well
+    pub fn prepare<T: Model>(&self) -> PreparedRequest<T> {
+        PreparedRequest::new()
     }
 
-    pub(crate) async fn delete(
+    pub async fn get_prepared<T: Model>(
         &self,
+        prepared_request: &PreparedRequest<T>,
         hash_key: String,
         sort_key: Value,
-        table_name: &str,
-        transaction_id: Option<u64>,
-    ) -> Result<bool, ConnectionError> {
-        let partition = get_hash_key_target_partition(&hash_key, self.streams.len());
+    ) -> Result<Option<T>, ConnectionError> {
+        self.inner()
+            .get_prepared(prepared_request, hash_key, sort_key, None)
+            .await
+    }
 
-        let mut request = ProtoRequest::new();
-        request.table = table_name.to_string();
-        request.transaction_id = transaction_id;
+    pub async fn insert_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        instance: T,
+    ) -> Result<(), ConnectionError> {
+        self.inner()
+            .insert_prepared(prepared_request, instance, None)
+            .await
+    }
 
-        let delete_request = create_delete_request(hash_key, sort_key);
-        request.data = Some(ProtoRequestData::Delete(delete_request));
+    pub async fn delete_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<bool, ConnectionError> {
+        self.inner()
+            .delete_prepared(prepared_request, hash_key, sort_key, None)
+            .await
+    }
+}
 
-        let proto_response = send_request(self.streams[&partition].clone(), request).await?;
+pub(crate) struct ConnectionInner {
+    streams: HashMap<usize, Arc<MultiplexedStream>>,
+    circuit_breakers: HashMap<usize, Arc<StdMutex<CircuitBreaker>>>,
+    partition_map: RwLock<PartitionMap>,
+    read_hedging: RwLock<Option<Arc<ReadHedging>>>,
+    chunking_policy: RwLock<ChunkingPolicy>,
+    // session timeout if one was set
+    request_timeout: RwLock<Duration>,
+    database: String,
+    // leader of the server connection was opened to, if it is not the leader itself
+    leader_routing: Arc<LeaderRouting>,
+    // tables whose model schema was already compared against server
+    #[cfg(feature = "schema-check")]
+    checked_tables: StdMutex<std::collections::HashSet<String>>,
+    // schema versions of tables whose server schema differs from their model
+    server_schema_versions: StdMutex<HashMap<String, u64>>,
+}
 
-        match proto_response.data.unwrap() {
-            ProtoResponseData::Delete(delete_response) => Ok(delete_response.okay),
-            ProtoResponseData::ClientError(client_error) => {
-                Err(ConnectionError::Client(client_error.detail))
-            }
-            ProtoResponseData::ServerError(server_error) => {
-                Err(ConnectionError::Server(server_error.detail))
-            }
-            _ => panic!("Invalid proto response type"),
-        }
+impl ConnectionInner {
+    pub(crate) async fn new(
+        address: SocketAddrV4,
+        database: &str,
+    ) -> Result<ConnectionInner, ConnectionError> {
+        let streams = connect_streams(address, database).await?;
+        let circuit_breakers = (0..streams.len())
+            .map(|partition| (partition, Arc::new(StdMutex::new(CircuitBreaker::new()))))
+            .collect();
+
+        let connection_inner = ConnectionInner {
+            partition_map: RwLock::new(PartitionMap::new(streams.len())),
+            streams,
+            circuit_breakers,
+            read_hedging: RwLock::new(None),
+            chunking_policy: RwLock::new(ChunkingPolicy::default()),
+            request_timeout: RwLock::new(REQUEST_TIMEOUT),
+            database: database.to_string(),
+            leader_routing: Arc::new(LeaderRouting::default()),
+            #[cfg(feature = "schema-check")]
+            checked_tables: StdMutex::new(std::collections::HashSet::new()),
+            server_schema_versions: StdMutex::new(HashMap::new()),
+        };
+        connection_inner.fetch_partition_map().await?;
+
+        Ok(connection_inner)
     }
 
-    pub(crate) async fn get_many<T: Model>(
+    // same server, database and settings, sockets are not shared with anyone
+    pub(crate) async fn with_own_sockets(&self) -> Result<ConnectionInner, ConnectionError> {
+        let connection_inner =
+            ConnectionInner::new(self.streams[&0].address, &self.database).await?;
+        *connection_inner.read_hedging.write().unwrap() = self.read_hedging.read().unwrap().clone();
+        *connection_inner.chunking_policy.write().unwrap() = *self.chunking_policy.read().unwrap();
+        let read_from_followers = self
+            .leader_routing
+            .read_from_followers
+            .load(Ordering::Relaxed);
+        connection_inner
+            .leader_routing
+            .read_from_followers
+            .store(read_from_followers, Ordering::Relaxed);
+        Ok(connection_inner)
+    }
+
+    #[cfg(feature = "schema-check")]
+    async fn check_model_schema(
         &self,
-        get_many: GetMany<T>,
-        transaction_id: Option<u64>,
-    ) -> Result<Vec<T>, ConnectionError> {
-        if get_many.items.is_empty() {
-            return Ok(vec![]);
+        table_name: fn() -> String,
+        model_schema: fn() -> TableSchema,
+    ) {
+        let table_name = table_name();
+        if !self
+            .checked_tables
+            .lock()
+            .unwrap()
+            .insert(table_name.clone())
+        {
+            return;
         }
 
-        let mut item_batches: Vec<_> = (0..self.streams.len()).map(|_| Vec::new()).collect();
-        let mut responses = Vec::with_capacity(get_many.items.len());
-
-        for item in get_many.items {
-            let partition = get_hash_key_target_partition(&item.hash_key, self.streams.len());
-            item_batches[partition].push(item);
+        match self.table_schema(&table_name).await {
+            Ok(table_schema) => {
+                let mismatches =
+                    crate::schema_check::schema_mismatches(&model_schema(), &table_schema);
+                for mismatch in mismatches {
+                    tracing::warn!(
+                        "Model of table '{}' is out of sync: {}",
+                        table_name,
+                        mismatch
+                    );
+                }
+            }
+            // checked again on next access
+            Err(error) => {
+                self.checked_tables.lock().unwrap().remove(&table_name);
+                tracing::warn!("Could not check model of table '{}': {}", table_name, error);
+            }
         }
+    }
 
-        let mut join_set = JoinSet::new();
-        for (partition, item_batch) in item_batches
-            .into_iter()
-            .enumerate()
-            .filter(|(_, batch)| !batch.is_empty())
-        {
-            let mut get_many_request = GetManyRequest::new();
-            get_many_request.items = item_batch;
+    #[cfg(not(feature = "schema-check"))]
+    async fn check_model_schema(
+        &self,
+        _table_name: fn() -> String,
+        _model_schema: fn() -> TableSchema,
+    ) {
+    }
 
-            let mut proto_request = ProtoRequest::new();
-            proto_request.table = T::table_name();
-            proto_request.transaction_id = transaction_id;
+    pub(crate) fn get_partition(&self, hash_key: &str) -> usize {
+        self.partition_map.read().unwrap().get_partition(hash_key)
+    }
 
-            proto_request.data = Some(ProtoRequestData::GetMany(get_many_request));
+    // server thread owning partition of the hash key
+    fn get_thread(&self, hash_key: &str) -> usize {
+        get_partition_thread(self.get_partition(hash_key), self.streams.len())
+    }
 
-            join_set.spawn(send_request(
-                self.streams[&partition].clone(),
-                proto_request,
-            ));
+    // partition count is kept by server, so it is taken from the map rather than from thread count
+    fn set_partition_map(&self, partition_map_string: &str) -> Result<(), ConnectionError> {
+        let partition_map =
+            PartitionMap::from_string(partition_map_string).map_err(ConnectionError::decode)?;
+        if partition_map.num_of_partitions < self.streams.len() {
+            return Err(ConnectionError::decode(format!(
+                "Partition map is for {} partitions, connected to {} threads",
+                partition_map.num_of_partitions,
+                self.streams.len()
+            )));
         }
 
-        while let Some(result) = join_set.join_next().await {
-            let response = result.unwrap()?;
-            match response.data.unwrap() {
-                ProtoResponseData::GetMany(get_many_response) => {
-                    for response in get_many_response.items {
-                        responses.push(T::from_get_response(response));
-                    }
-                }
-                ProtoResponseData::ClientError(client_error) => {
-                    Err(ConnectionError::Client(client_error.detail))?
-                }
-                ProtoResponseData::ServerError(server_error) => {
-                    Err(ConnectionError::Server(server_error.detail))?
+        *self.partition_map.write().unwrap() = partition_map;
+        Ok(())
+    }
+
+    // server answers misrouted requests with owner of the hash key and its current partition map,
+    // which replaces the one of the connection
+    fn check_partition(&self, proto_response: &ProtoResponse) -> Result<(), ConnectionError> {
+        match &proto_response.data {
+            Some(ProtoResponseData::ClientError(client_error))
+                if client_error.code.enum_value_or_default() == ErrorCode::WRONG_PARTITION =>
+            {
+                if let Some(partition_map) = client_error.metadata.get("partition_map") {
+                    self.set_partition_map(partition_map)?;
                 }
-                _ => panic!("Invalid proto response type"),
+                Err(ConnectionError::from(client_error.clone()))
             }
+            _ => Ok(()),
         }
-
-        Ok(responses)
     }
 
-    pub(crate) async fn batch<T: Model>(
+    async fn send_to_hash_key(
         &self,
-        batch: Batch<T>,
-        transaction_id: Option<u64>,
+        hash_key: &str,
+        proto_request: ProtoRequest,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        self.send_bytes_to_hash_key(hash_key, proto_request.write_to_bytes().unwrap())
+            .await
+    }
+
+    async fn send_bytes_to_hash_key(
+        &self,
+        hash_key: &str,
+        request_bytes: Vec<u8>,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        self.route_bytes_to_hash_key(hash_key, request_bytes, false)
+            .await
+    }
+
+    // misrouted request is retried once on the owner named by server
+    async fn route_bytes_to_hash_key(
+        &self,
+        hash_key: &str,
+        request_bytes: Vec<u8>,
+        follower_read: bool,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        let partition = self.get_partition(hash_key);
+        let thread = get_partition_thread(partition, self.streams.len());
+        let proto_response = self
+            .send_bytes_to_node(thread, request_bytes.clone(), follower_read)
+            .await?;
+        let partition = match self.check_partition(&proto_response) {
+            Ok(()) => return Ok(proto_response),
+            Err(ConnectionError::WrongPartition(partition))
+                if partition < self.partition_map.read().unwrap().num_of_partitions =>
+            {
+                partition
+            }
+            Err(error) => return Err(error),
+        };
+
+        let thread = get_partition_thread(partition, self.streams.len());
+        let proto_response = self
+            .send_bytes_to_node(thread, request_bytes, follower_read)
+            .await?;
+        self.check_partition(&proto_response)?;
+        Ok(proto_response)
+    }
+
+    // with hedging enabled, read is sent again if it takes longer than usual, first response wins
+    async fn send_read_to_hash_key(
+        &self,
+        hash_key: &str,
+        request_bytes: Vec<u8>,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        let read_hedging = self.read_hedging.read().unwrap().clone();
+        let read_hedging = match read_hedging {
+            Some(read_hedging) => read_hedging,
+            None => {
+                return self
+                    .route_bytes_to_hash_key(hash_key, request_bytes, true)
+                    .await
+            }
+        };
+
+        let start = Instant::now();
+        let mut first_attempt =
+            pin!(self.route_bytes_to_hash_key(hash_key, request_bytes.clone(), true));
+        let result = tokio::select! {
+            result = &mut first_attempt => result,
+            _ = sleep(read_hedging.delay()) => {
+                tokio::select! {
+                    result = &mut first_attempt => result,
+                    result = self.route_bytes_to_hash_key(hash_key, request_bytes, true) => result,
+                }
+            }
+        };
+
+        if result.is_ok() {
+            read_hedging.record(start.elapsed());
+        }
+        result
+    }
+
+    pub(crate) fn set_read_hedging(&self, hedging_policy: Option<HedgingPolicy>) {
+        *self.read_hedging.write().unwrap() =
+            hedging_policy.map(|hedging_policy| Arc::new(ReadHedging::new(hedging_policy)));
+    }
+
+    // returned future does not borrow self, so it can be spawned
+    pub(crate) async fn set_session(&self, session: Session) -> Result<(), ConnectionError> {
+        let request_bytes = session.to_proto_request().write_to_bytes().unwrap();
+        self.leader_routing
+            .set_session_request(request_bytes.clone());
+
+        let leader = self.leader_routing.node(false);
+        let leader_streams = leader.iter().flat_map(|leader| leader.streams.values());
+        for stream in self.streams.values().chain(leader_streams) {
+            let proto_response =
+                timeout(REQUEST_TIMEOUT, stream.set_session(request_bytes.clone()))
+                    .await
+                    .unwrap_or(Err(ConnectionError::Timeout(REQUEST_TIMEOUT)))?;
+            parse_session_response(proto_response)?;
+        }
+
+        *self.request_timeout.write().unwrap() = session.timeout.unwrap_or(REQUEST_TIMEOUT);
+        Ok(())
+    }
+
+    fn send_to_partition(
+        &self,
+        partition: usize,
+        proto_request: ProtoRequest,
+    ) -> impl Future<Output = Result<ProtoResponse, ConnectionError>> + 'static {
+        self.send_bytes_to_partition(partition, proto_request.write_to_bytes().unwrap())
+    }
+
+    fn send_bytes_to_partition(
+        &self,
+        partition: usize,
+        request_bytes: Vec<u8>,
+    ) -> impl Future<Output = Result<ProtoResponse, ConnectionError>> + 'static {
+        let thread = get_partition_thread(partition, self.streams.len());
+        self.send_bytes_to_thread(thread, request_bytes)
+    }
+
+    fn send_to_thread(
+        &self,
+        thread: usize,
+        proto_request: ProtoRequest,
+    ) -> impl Future<Output = Result<ProtoResponse, ConnectionError>> + 'static {
+        self.send_bytes_to_thread(thread, proto_request.write_to_bytes().unwrap())
+    }
+
+    // every server thread listens on its own port and handles all of its partitions
+    fn send_bytes_to_thread(
+        &self,
+        thread: usize,
+        request_bytes: Vec<u8>,
+    ) -> impl Future<Output = Result<ProtoResponse, ConnectionError>> + 'static {
+        self.send_bytes_to_node(thread, request_bytes, false)
+    }
+
+    // request goes to the leader of the server, unless it is a read followers may serve. Request
+    // rejected by a server that no longer leads is sent once more to the leader it names
+    fn send_bytes_to_node(
+        &self,
+        thread: usize,
+        request_bytes: Vec<u8>,
+        follower_read: bool,
+    ) -> impl Future<Output = Result<ProtoResponse, ConnectionError>> + 'static {
+        let leader = self.leader_routing.node(follower_read);
+        let own_stream = self.streams[&thread].clone();
+        let own_circuit_breaker = self.circuit_breakers[&thread].clone();
+        let (stream, circuit_breaker) = match &leader {
+            Some(leader) => (
+                leader.streams[&thread].clone(),
+                leader.circuit_breakers[&thread].clone(),
+            ),
+            None => (own_stream.clone(), own_circuit_breaker.clone()),
+        };
+        let request_timeout = *self.request_timeout.read().unwrap();
+        let leader_routing = self.leader_routing.clone();
+        let num_of_threads = self.streams.len();
+        let address = self.streams[&0].address;
+        let database = self.database.clone();
+
+        async move {
+            let result = send_request_with_circuit_breaker(
+                thread,
+                stream,
+                circuit_breaker,
+                request_bytes.clone(),
+                request_timeout,
+            )
+            .await;
+            let mut proto_response = match (result, &leader) {
+                (
+                    Err(error @ (ConnectionError::Io(_) | ConnectionError::Timeout(_))),
+                    Some(leader),
+                ) => {
+                    leader_routing.forget(leader.address);
+                    return Err(error);
+                }
+                (result, _) => result?,
+            };
+            if let Some(hint) = not_leader_hint(&proto_response) {
+                let (stream, circuit_breaker) = match leader_routing
+                    .follow(&hint, address, &database, num_of_threads)
+                    .await?
+                {
+                    Some(leader) => (
+                        leader.streams[&thread].clone(),
+                        leader.circuit_breakers[&thread].clone(),
+                    ),
+                    None => (own_stream, own_circuit_breaker),
+                };
+                proto_response = send_request_with_circuit_breaker(
+                    thread,
+                    stream,
+                    circuit_breaker,
+                    request_bytes,
+                    request_timeout,
+                )
+                .await?;
+            }
+            if let Some(latency) = proto_response.latency.as_ref() {
+                tracing::debug!(
+                    "Server latency on thread {}: total={}us queueing={}us parsing={}us \
+                     validation={}us memtable={}us sstable_io={}us commit_log={}us",
+                    thread,
+                    latency.total_micros,
+                    latency.queueing_micros,
+                    latency.parsing_micros,
+                    latency.validation_micros,
+                    latency.memtable_micros,
+                    latency.sstable_io_micros,
+                    latency.commit_log_micros
+                );
+            }
+            if let Some(ProtoResponseData::ClientError(client_error)) = &proto_response.data {
+                if client_error.code.enum_value_or_default() == ErrorCode::SCHEMA_MISMATCH {
+                    if let Some(table) = client_error.metadata.get("table") {
+                        schema_cache::invalidate(SchemaCacheKey {
+                            address,
+                            database,
+                            table: table.clone(),
+                        });
+                    }
+                }
+            }
+            Ok(proto_response)
+        }
+    }
+
+    fn schema_cache_key(&self, table_name: &str) -> SchemaCacheKey {
+        SchemaCacheKey {
+            address: self.streams[&0].address,
+            database: self.database.clone(),
+            table: table_name.to_string(),
+        }
+    }
+
+    pub(crate) async fn get<T: Model>(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        transaction: Option<TransactionScope>,
+        min_consistency_token: Option<&ConsistencyToken>,
+    ) -> Result<Option<T>, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        validate_sort_key(&sort_key)?;
+        let get_request = create_get_request(hash_key.clone(), sort_key);
+
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+        request.schema_version = T::table_schema().schema_version();
+        request.data = Some(ProtoRequestData::Get(get_request));
+        set_transaction(&mut request, transaction);
+        if let Some(min_consistency_token) = min_consistency_token {
+            request.min_consistency_token = min_consistency_token.as_str().to_string();
+        }
+
+        // reads registered in transaction are not sent twice
+        let proto_response = match transaction {
+            Some(_) => self.send_to_hash_key(&hash_key, request).await?,
+            None => {
+                self.send_read_to_hash_key(&hash_key, request.write_to_bytes().unwrap())
+                    .await?
+            }
+        };
+        parse_get_response(proto_response)
+    }
+
+    pub(crate) async fn get_row(
+        &self,
+        table_name: &str,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<Option<DynamicRow>, ConnectionError> {
+        validate_sort_key(&sort_key)?;
+        let get_request = create_get_request(hash_key.clone(), sort_key);
+
+        let mut request = ProtoRequest::new();
+        request.table = table_name.to_string();
+        request.data = Some(ProtoRequestData::Get(get_request));
+
+        let proto_response = self
+            .send_read_to_hash_key(&hash_key, request.write_to_bytes().unwrap())
+            .await?;
+        parse_get_response_with(proto_response, DynamicRow::from_get_response)
+    }
+
+    pub(crate) async fn explain_get_row(
+        &self,
+        table_name: &str,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<(Option<DynamicRow>, ReadTrace), ConnectionError> {
+        validate_sort_key(&sort_key)?;
+        let get_request = create_get_request(hash_key.clone(), sort_key);
+
+        let mut request = ProtoRequest::new();
+        request.table = table_name.to_string();
+        request.data = Some(ProtoRequestData::Get(get_request));
+        request.explain = true;
+
+        let mut proto_response = self
+            .send_read_to_hash_key(&hash_key, request.write_to_bytes().unwrap())
+            .await?;
+        // rows hidden by row policy or read as of some time are not explained
+        let read_trace = proto_response
+            .read_explanations
+            .pop()
+            .map(read_explanation_to_trace)
+            .unwrap_or_default();
+        let row = parse_get_response_with(proto_response, DynamicRow::from_get_response)?;
+        Ok((row, read_trace))
+    }
+
+    pub(crate) async fn get_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        hash_key: String,
+        sort_key: Value,
+        transaction: Option<TransactionScope>,
+    ) -> Result<Option<T>, ConnectionError> {
+        validate_sort_key(&sort_key)?;
+        let request_bytes =
+            prepared_request.get_request_bytes(hash_key.clone(), sort_key, transaction);
+
+        // reads registered in transaction are not sent twice
+        let proto_response = match transaction {
+            Some(_) => {
+                self.send_bytes_to_hash_key(&hash_key, request_bytes)
+                    .await?
+            }
+            None => self.send_read_to_hash_key(&hash_key, request_bytes).await?,
+        };
+        parse_get_response(proto_response)
+    }
+
+    pub(crate) async fn insert<T: Model>(
+        &self,
+        instance: T,
+        transaction: Option<TransactionScope>,
+    ) -> Result<ConsistencyToken, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let hash_key = instance.hash_key();
+        let insert_request = instance.to_insert_request();
+        validate_sort_key(&parse_value_from_message_field(
+            insert_request.sort_key.clone(),
+        ))?;
+
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+        set_transaction(&mut request, transaction);
+
+        let table_schema = T::table_schema();
+        if !self.sends_by_name(&request.table, table_schema.schema_version()) {
+            let mut ordinal_request = request.clone();
+            let mut ordinal_insert_request = insert_request.clone();
+            insert_by_ordinal(&mut ordinal_insert_request, &table_schema);
+            ordinal_request.schema_version = table_schema.schema_version();
+            ordinal_request.data = Some(ProtoRequestData::Insert(ordinal_insert_request));
+
+            let proto_response = self.send_to_hash_key(&hash_key, ordinal_request).await?;
+            // insert was rejected as a whole, so it is sent again by name
+            if !self.record_schema_version_mismatch(&request.table, &proto_response) {
+                return parse_insert_response(proto_response);
+            }
+        }
+
+        request.data = Some(ProtoRequestData::Insert(insert_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+        parse_insert_response(proto_response)
+    }
+
+    pub(crate) async fn save<T: VersionedModel>(
+        &self,
+        instance: &T,
+    ) -> Result<u64, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let hash_key = instance.hash_key();
+        let insert_request = instance.to_insert_request();
+        validate_sort_key(&parse_value_from_message_field(
+            insert_request.sort_key.clone(),
+        ))?;
+        let mut save_request = SaveRequest::new();
+        save_request.insert = MessageField::some(insert_request);
+        save_request.version = instance.version();
+
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+        request.data = Some(ProtoRequestData::Save(save_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+        match proto_response.data.unwrap() {
+            ProtoResponseData::Save(save_response) => Ok(save_response.version),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    // values are sent by name and sort key is left unset, server assigns it
+    pub(crate) async fn append<T: Model>(&self, instance: T) -> Result<u64, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let hash_key = instance.hash_key();
+        let mut insert_request = instance.to_insert_request();
+        insert_request.sort_key = MessageField::none();
+
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+        request.data = Some(ProtoRequestData::Insert(insert_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+        match proto_response.data.unwrap() {
+            ProtoResponseData::Insert(insert_response) => Ok(insert_response.sequence),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    fn sends_by_name(&self, table_name: &str, schema_version: u64) -> bool {
+        self.server_schema_versions
+            .lock()
+            .unwrap()
+            .get(table_name)
+            .is_some_and(|server_schema_version| *server_schema_version != schema_version)
+    }
+
+    // remembers schema version of server when it rejected values sent by ordinal
+    fn record_schema_version_mismatch(
+        &self,
+        table_name: &str,
+        proto_response: &ProtoResponse,
+    ) -> bool {
+        let Some(ProtoResponseData::ClientError(client_error)) = proto_response.data.as_ref()
+        else {
+            return false;
+        };
+        if client_error.code.enum_value_or_default() != ErrorCode::SCHEMA_VERSION_MISMATCH {
+            return false;
+        }
+        let schema_version = client_error
+            .metadata
+            .get("schema_version")
+            .and_then(|schema_version| schema_version.parse().ok())
+            .unwrap_or_default();
+        self.server_schema_versions
+            .lock()
+            .unwrap()
+            .insert(table_name.to_string(), schema_version);
+        true
+    }
+
+    pub(crate) async fn insert_values(
+        &self,
+        table_name: &str,
+        hash_key: String,
+        sort_key: Value,
+        values: HashMap<String, Value>,
+    ) -> Result<(), ConnectionError> {
+        validate_sort_key(&sort_key)?;
+        let mut insert_request = InsertRequest::new();
+        insert_request.hash_key = hash_key.clone();
+        insert_request.sort_key = parse_message_field_from_value(sort_key);
+        insert_request.values = values
+            .into_iter()
+            .map(|(name, value)| (name, parse_proto_from_value(value)))
+            .collect();
+
+        let mut request = ProtoRequest::new();
+        request.table = table_name.to_string();
+        request.data = Some(ProtoRequestData::Insert(insert_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+        parse_insert_response(proto_response)?;
+        Ok(())
+    }
+
+    pub(crate) async fn insert_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        instance: T,
+        transaction: Option<TransactionScope>,
+    ) -> Result<(), ConnectionError> {
+        let hash_key = instance.hash_key();
+        let insert_request = instance.to_insert_request();
+        validate_sort_key(&parse_value_from_message_field(
+            insert_request.sort_key.clone(),
+        ))?;
+
+        let table_name = &prepared_request.table_name;
+        if !self.sends_by_name(table_name, prepared_request.schema_version) {
+            let request_bytes =
+                prepared_request.insert_request_bytes(insert_request, false, transaction);
+            let proto_response = self
+                .send_bytes_to_hash_key(&hash_key, request_bytes)
+                .await?;
+            // insert was rejected as a whole, so it is sent again by name
+            if !self.record_schema_version_mismatch(table_name, &proto_response) {
+                parse_insert_response(proto_response)?;
+                return Ok(());
+            }
+        }
+
+        let request_bytes =
+            prepared_request.insert_request_bytes(instance.to_insert_request(), true, transaction);
+        let proto_response = self
+            .send_bytes_to_hash_key(&hash_key, request_bytes)
+            .await?;
+        parse_insert_response(proto_response)?;
+        Ok(())
+    }
+
+    pub(crate) async fn delete(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        table_name: &str,
+        transaction: Option<TransactionScope>,
     ) -> Result<bool, ConnectionError> {
-        if batch.items.is_empty() {
-            return Ok(true);
+        validate_sort_key(&sort_key)?;
+        let mut request = ProtoRequest::new();
+        request.table = table_name.to_string();
+        set_transaction(&mut request, transaction);
+
+        let delete_request = create_delete_request(hash_key.clone(), sort_key);
+        request.data = Some(ProtoRequestData::Delete(delete_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+        parse_delete_response(proto_response)
+    }
+
+    pub(crate) async fn delete_where<T: Model>(
+        &self,
+        hash_key: String,
+        range: impl RangeBounds<Value>,
+        filter: Option<(&str, Value)>,
+    ) -> Result<u64, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let mut delete_where_request = DeleteWhereRequest::new();
+        delete_where_request.hash_key = hash_key.clone();
+        match range.start_bound() {
+            Bound::Included(start) | Bound::Excluded(start) => {
+                delete_where_request.start = parse_message_field_from_value(start.clone());
+                delete_where_request.start_inclusive =
+                    matches!(range.start_bound(), Bound::Included(_));
+            }
+            Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            Bound::Included(end) | Bound::Excluded(end) => {
+                delete_where_request.end = parse_message_field_from_value(end.clone());
+                delete_where_request.end_inclusive =
+                    matches!(range.end_bound(), Bound::Included(_));
+            }
+            Bound::Unbounded => {}
+        }
+        if let Some((column, value)) = filter {
+            delete_where_request.filter_column = Some(column.to_string());
+            delete_where_request.filter_value = parse_message_field_from_value(value);
         }
 
-        let mut item_batches: Vec<_> = (0..self.streams.len()).map(|_| Vec::new()).collect();
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+        request.data = Some(ProtoRequestData::DeleteWhere(delete_where_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::DeleteWhere(delete_where_response) => {
+                Ok(delete_where_response.deleted)
+            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn move_row<T: Model>(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        new_hash_key: String,
+        new_sort_key: Value,
+    ) -> Result<bool, ConnectionError> {
+        validate_sort_key(&sort_key)?;
+        validate_sort_key(&new_sort_key)?;
+        let mut move_row_request = MoveRowRequest::new();
+        move_row_request.hash_key = hash_key.clone();
+        move_row_request.sort_key = parse_message_field_from_value(sort_key);
+        move_row_request.new_hash_key = new_hash_key;
+        move_row_request.new_sort_key = parse_message_field_from_value(new_sort_key);
+
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+        request.data = Some(ProtoRequestData::MoveRow(move_row_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::MoveRow(move_row_response) => Ok(move_row_response.moved),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn delete_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        hash_key: String,
+        sort_key: Value,
+        transaction: Option<TransactionScope>,
+    ) -> Result<bool, ConnectionError> {
+        validate_sort_key(&sort_key)?;
+        let request_bytes =
+            prepared_request.delete_request_bytes(hash_key.clone(), sort_key, transaction);
+
+        let proto_response = self
+            .send_bytes_to_hash_key(&hash_key, request_bytes)
+            .await?;
+        parse_delete_response(proto_response)
+    }
+
+    pub(crate) async fn next_sequence(
+        &self,
+        hash_key: String,
+        table_name: &str,
+    ) -> Result<u64, ConnectionError> {
+        let mut request = ProtoRequest::new();
+        request.table = table_name.to_string();
+
+        let mut get_sequence_request = GetSequenceRequest::new();
+        get_sequence_request.hash_key = hash_key.clone();
+        request.data = Some(ProtoRequestData::GetSequence(get_sequence_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::Sequence(sequence_response) => Ok(sequence_response.value),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn row_count<T: Model>(
+        &self,
+        hash_key: String,
+    ) -> Result<u64, ConnectionError> {
+        let mut request = ProtoRequest::new();
+        request.table = T::table_name();
+
+        let mut row_count_request = RowCountRequest::new();
+        row_count_request.hash_key = hash_key.clone();
+        request.data = Some(ProtoRequestData::RowCount(row_count_request));
+
+        let proto_response = self.send_to_hash_key(&hash_key, request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::RowCount(row_count_response) => Ok(row_count_response.count),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn send_lease_request(
+        &self,
+        name: &str,
+        table_name: &str,
+        request_data: ProtoRequestData,
+    ) -> Result<(u64, u128), ConnectionError> {
+        let mut request = ProtoRequest::new();
+        request.table = table_name.to_string();
+        request.data = Some(request_data);
+
+        let proto_response = self.send_to_hash_key(name, request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::Lease(lease_response) => {
+                Ok((lease_response.version, lease_response.expires_at as u128))
+            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn get_many<T: Model>(
+        self: &Arc<Self>,
+        get_many: GetMany<T>,
+        transaction_id: Option<u64>,
+    ) -> Result<Vec<T>, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let mut responses = pin!(self.get_many_responses(get_many, transaction_id));
+
+        let mut models = Vec::new();
+        while let Some(response) = responses.next().await {
+            let (_, response) = response?;
+            models.extend(
+                self.parse_get_many_response(response)?
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+
+        Ok(models)
+    }
 
-        for item in batch.items {
-            let hash_key = get_batch_item_hash_key(&item);
-            let partition = get_hash_key_target_partition(&hash_key, self.streams.len());
-            item_batches[partition].push(item);
+    // one per added key in order keys were added, None for keys that were not found
+    pub(crate) async fn get_many_optional<T: Model>(
+        self: &Arc<Self>,
+        get_many: GetMany<T>,
+        transaction_id: Option<u64>,
+    ) -> Result<Vec<Option<T>>, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let mut models: Vec<Option<T>> = (0..get_many.items.len()).map(|_| None).collect();
+        let mut responses = pin!(self.get_many_responses(get_many, transaction_id));
+
+        while let Some(response) = responses.next().await {
+            let (item_indices, response) = response?;
+            let chunk_models = self.parse_get_many_response(response)?;
+            for (index, model) in item_indices.into_iter().zip(chunk_models) {
+                models[index] = model;
+            }
+        }
+
+        Ok(models)
+    }
+
+    pub(crate) async fn get_many_consistent<T: Model>(
+        &self,
+        get_many: GetMany<T>,
+        transaction_id: Option<u64>,
+    ) -> Result<Vec<T>, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let hash_key = match get_many.items.first() {
+            Some(item) => item.hash_key.clone(),
+            None => return Ok(Vec::new()),
+        };
+        let partition = self.get_partition(&hash_key);
+        if get_many
+            .items
+            .iter()
+            .any(|item| self.get_partition(&item.hash_key) != partition)
+        {
+            return Err(ConnectionError::Validation(
+                "Keys of consistent get_many have to be in a single partition".to_string(),
+            ));
+        }
+
+        let number_of_items = get_many.items.len();
+        let mut get_many_request = GetManyRequest::new();
+        get_many_request.items = get_many.items;
+        get_many_request.consistent = true;
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = T::table_name();
+        proto_request.schema_version = T::table_schema().schema_version();
+        proto_request.transaction_id = transaction_id;
+        proto_request.data = Some(ProtoRequestData::GetMany(get_many_request));
+
+        let response = self
+            .send_bytes_to_hash_key(&hash_key, proto_request.write_to_bytes().unwrap())
+            .await?;
+        let models = self.parse_get_many_response(response)?;
+        // items asked for again would no longer be read at the same point in time
+        if models.len() < number_of_items {
+            return Err(ConnectionError::Validation(
+                "Rows of consistent get_many do not fit into a single response".to_string(),
+            ));
+        }
+        Ok(models.into_iter().flatten().collect())
+    }
+
+    // models of a chunk are yielded as soon as its response arrives, regardless of other chunks
+    pub(crate) fn get_many_stream<T: Model + 'static>(
+        self: &Arc<Self>,
+        get_many: GetMany<T>,
+        transaction_id: Option<u64>,
+    ) -> impl Stream<Item = Result<T, ConnectionError>> + 'static {
+        let responses = self.get_many_responses(get_many, transaction_id);
+
+        let connection_inner = self.clone();
+        responses.flat_map(move |result| {
+            let models = match result
+                .and_then(|(_, response)| connection_inner.parse_get_many_response::<T>(response))
+            {
+                Ok(models) => models.into_iter().flatten().map(Ok).collect(),
+                Err(error) => vec![Err(error)],
+            };
+            stream::iter(models)
+        })
+    }
+
+    // chunked requests to every thread holding any of the keys, responses come in as they arrive,
+    // each along with indices of its items among added keys
+    fn get_many_responses<T: Model>(
+        self: &Arc<Self>,
+        get_many: GetMany<T>,
+        transaction_id: Option<u64>,
+    ) -> impl Stream<Item = Result<(Vec<usize>, ProtoResponse), ConnectionError>> + 'static {
+        let chunking_policy = *self.chunking_policy.read().unwrap();
+        let mut item_batches: Vec<_> = (0..self.streams.len()).map(|_| Vec::new()).collect();
+        let mut index_batches: Vec<_> = (0..self.streams.len()).map(|_| Vec::new()).collect();
+        for (index, item) in get_many.items.into_iter().enumerate() {
+            let thread = self.get_thread(&item.hash_key);
+            item_batches[thread].push(item);
+            index_batches[thread].push(index);
         }
 
-        let mut join_set = JoinSet::new();
-        for (partition, item_batch) in item_batches
+        let thread_requests = item_batches
             .into_iter()
+            .zip(index_batches)
             .enumerate()
-            .filter(|(_, batch)| !batch.is_empty())
-        {
-            let mut batch_request = BatchRequest::new();
-            batch_request.items = item_batch;
+            .map(|(thread, (item_batch, index_batch))| {
+                let mut indices = index_batch.into_iter();
+                split_into_chunks(item_batch, &chunking_policy)
+                    .into_iter()
+                    .map(|chunk| {
+                        let chunk_indices: Vec<_> = indices.by_ref().take(chunk.len()).collect();
+                        let connection_inner = self.clone();
+                        async move {
+                            let response = connection_inner
+                                .get_many_pages::<T>(thread, chunk, transaction_id)
+                                .await?;
+                            Ok((chunk_indices, response))
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        pace_thread_requests(thread_requests, &chunking_policy)
+    }
+
+    // server leaves out items that do not fit into its response, they are asked for again with
+    // its continuation token until every item is answered, pages are merged into response of the
+    // first one
+    async fn get_many_pages<T: Model>(
+        &self,
+        thread: usize,
+        items: Vec<GetRequest>,
+        transaction_id: Option<u64>,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        let max_response_bytes = self.chunking_policy.read().unwrap().max_response_bytes;
+        let mut continuation_token = String::new();
+        let mut merged_response: Option<ProtoResponse> = None;
+        loop {
+            let mut get_many_request = GetManyRequest::new();
+            get_many_request.items = items.clone();
+            get_many_request.max_response_bytes = max_response_bytes as u64;
+            get_many_request.continuation_token = continuation_token;
+
+            let mut proto_request = ProtoRequest::new();
+            proto_request.table = T::table_name();
+            proto_request.schema_version = T::table_schema().schema_version();
+            proto_request.transaction_id = transaction_id;
+            proto_request.data = Some(ProtoRequestData::GetMany(get_many_request));
+
+            let mut response = self.send_to_thread(thread, proto_request).await?;
+            let mut page = match response.data.as_mut() {
+                Some(ProtoResponseData::GetMany(page)) => std::mem::take(page),
+                // errors are returned as they are, even after some pages were answered
+                _ => return Ok(response),
+            };
+            continuation_token = std::mem::take(&mut page.continuation_token);
+            let page_is_empty = page.found.is_empty();
+
+            match merged_response.as_mut() {
+                Some(merged_response) => {
+                    if let Some(ProtoResponseData::GetMany(merged)) = merged_response.data.as_mut()
+                    {
+                        merged.items.extend(page.items);
+                        merged.found.extend(page.found);
+                    }
+                    merged_response
+                        .read_explanations
+                        .extend(response.read_explanations);
+                }
+                None => {
+                    response.data = Some(ProtoResponseData::GetMany(page));
+                    merged_response = Some(response);
+                }
+            }
+            if continuation_token.is_empty() || page_is_empty {
+                return Ok(merged_response.unwrap());
+            }
+        }
+    }
+
+    // one per answered item, in order items were requested
+    fn parse_get_many_response<T: Model>(
+        &self,
+        response: ProtoResponse,
+    ) -> Result<Vec<Option<T>>, ConnectionError> {
+        // items could have been partially processed already, so the request is not retried
+        self.check_partition(&response)?;
+
+        match response.data.unwrap() {
+            ProtoResponseData::GetMany(get_many_response) => {
+                let mut items = get_many_response.items.into_iter();
+                Ok(get_many_response
+                    .found
+                    .into_iter()
+                    .map(|found| match found {
+                        true => items.next().map(model_from_get_response),
+                        false => None,
+                    })
+                    .collect())
+            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn batch<T: Model>(
+        &self,
+        batch: Batch<T>,
+        transaction_id: Option<u64>,
+    ) -> Result<bool, ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let writes = batch
+            .items
+            .into_iter()
+            .map(|item| TableWrite {
+                table_name: T::table_name(),
+                item,
+            })
+            .collect();
+        self.apply_writes(writes, transaction_id).await
+    }
+
+    // writes are grouped per server thread and table, every group is sent in chunks of batch requests,
+    // items of different chunks are applied independently, like those of different threads
+    pub(crate) async fn apply_writes(
+        &self,
+        writes: Vec<TableWrite>,
+        transaction_id: Option<u64>,
+    ) -> Result<bool, ConnectionError> {
+        if writes.is_empty() {
+            return Ok(true);
+        }
+
+        let mut item_batches: HashMap<(usize, String), Vec<BatchItem>> = HashMap::new();
+        for write in writes {
+            let thread = self.get_thread(&get_batch_item_hash_key(&write.item));
+            item_batches
+                .entry((thread, write.table_name))
+                .or_default()
+                .push(write.item);
+        }
 
-            let mut proto_request = ProtoRequest::new();
-            proto_request.table = T::table_name();
-            proto_request.transaction_id = transaction_id;
+        let chunking_policy = *self.chunking_policy.read().unwrap();
+        let mut thread_requests: HashMap<usize, Vec<_>> = HashMap::new();
+        for ((thread, table_name), item_batch) in item_batches {
+            for chunk in split_into_chunks(item_batch, &chunking_policy) {
+                let mut batch_request = BatchRequest::new();
+                batch_request.items = chunk;
 
-            proto_request.data = Some(ProtoRequestData::Batch(batch_request));
+                let mut proto_request = ProtoRequest::new();
+                proto_request.table = table_name.clone();
+                proto_request.transaction_id = transaction_id;
 
-            join_set.spawn(send_request(
-                self.streams[&partition].clone(),
-                proto_request,
-            ));
+                proto_request.data = Some(ProtoRequestData::Batch(batch_request));
+
+                thread_requests
+                    .entry(thread)
+                    .or_default()
+                    .push(self.send_to_thread(thread, proto_request));
+            }
         }
 
-        while let Some(result) = join_set.join_next().await {
-            let response = result.unwrap()?;
+        let mut responses = pin!(pace_thread_requests(
+            thread_requests.into_values().collect(),
+            &chunking_policy
+        ));
+        while let Some(response) = responses.next().await {
+            let response = response?;
+            // items could have been partially processed already, so the request is not retried
+            self.check_partition(&response)?;
+
             match response.data.unwrap() {
                 ProtoResponseData::Batch(batch_response) => {
                     if !batch_response.okay {
@@ -336,10 +1809,10 @@ impl ConnectionInner {
                     }
                 }
                 ProtoResponseData::ClientError(client_error) => {
-                    Err(ConnectionError::Client(client_error.detail))?
+                    Err(ConnectionError::from(client_error))?
                 }
                 ProtoResponseData::ServerError(server_error) => {
-                    Err(ConnectionError::Server(server_error.detail))?
+                    Err(ConnectionError::from(server_error))?
                 }
                 _ => panic!("Invalid proto response type"),
             }
@@ -348,25 +1821,28 @@ impl ConnectionInner {
         Ok(true)
     }
 
-    async fn begin_transaction(&self) -> Result<(u64, usize), ConnectionError> {
-        let mut rng = thread_rng();
-        let coordinator_partition = rng.gen_range(0..self.streams.len());
+    // server generates the id unless client chose it, coordinator is picked at random unless given
+    pub(crate) async fn begin_transaction(
+        &self,
+        transaction_id: Option<u64>,
+        coordinator_partition: Option<usize>,
+    ) -> Result<(u64, usize), ConnectionError> {
+        let coordinator_partition =
+            coordinator_partition.unwrap_or_else(|| thread_rng().gen_range(0..self.streams.len()));
 
         let mut proto_request = ProtoRequest::new();
+        proto_request.transaction_id = transaction_id;
         proto_request.data = Some(ProtoRequestData::BeginTransaction(BeginTransaction::new()));
 
-        let coordinator_stream = self.streams[&coordinator_partition].clone();
-        let proto_response = send_request(coordinator_stream.clone(), proto_request).await?;
+        let proto_response = self
+            .send_to_partition(coordinator_partition, proto_request)
+            .await?;
         match proto_response.data.unwrap() {
             ProtoResponseData::Transaction(transaction) => {
                 Ok((transaction.transaction_id, coordinator_partition))
             }
-            ProtoResponseData::ClientError(client_error) => {
-                Err(ConnectionError::Client(client_error.detail))
-            }
-            ProtoResponseData::ServerError(server_error) => {
-                Err(ConnectionError::Server(server_error.detail))
-            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
             _ => panic!("Invalid proto response type"),
         }
     }
@@ -380,8 +1856,9 @@ impl ConnectionInner {
         proto_request.transaction_id = Some(transaction_id);
         proto_request.data = Some(ProtoRequestData::CommitTransaction(CommitTransaction::new()));
 
-        let coordinator_stream = self.streams[&coordinator_partition].clone();
-        let proto_response = send_request(coordinator_stream.clone(), proto_request).await?;
+        let proto_response = self
+            .send_to_partition(coordinator_partition, proto_request)
+            .await?;
         handle_transaction_response(proto_response)
     }
 
@@ -394,94 +1871,703 @@ impl ConnectionInner {
         proto_request.transaction_id = Some(transaction_id);
         proto_request.data = Some(ProtoRequestData::AbortTransaction(AbortTransaction::new()));
 
-        let coordinator_stream = self.streams[&coordinator_partition].clone();
-        let proto_response = send_request(coordinator_stream.clone(), proto_request).await?;
+        let proto_response = self
+            .send_to_partition(coordinator_partition, proto_request)
+            .await?;
         handle_transaction_response(proto_response)
     }
 
-    pub(crate) async fn sync_model<T: Model>(&self) -> Result<(), ConnectionError> {
+    pub(crate) async fn list_transactions(
+        &self,
+    ) -> Result<Vec<ActiveTransaction>, ConnectionError> {
         let mut proto_request = ProtoRequest::new();
-        proto_request.table = T::table_name();
+        proto_request.data = Some(ProtoRequestData::ListTransactions(
+            ListTransactionsRequest::new(),
+        ));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::ListTransactions(list_transactions_response) => {
+                Ok(list_transactions_response
+                    .transactions
+                    .into_iter()
+                    .map(|active_transaction| ActiveTransaction {
+                        id: active_transaction.transaction_id,
+                        age: Duration::from_millis(active_transaction.age_millis),
+                        tables: active_transaction.tables,
+                        coordinator_partition: active_transaction
+                            .coordinator_partition
+                            .map(|partition| partition as usize),
+                    })
+                    .collect())
+            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn kill_transaction(
+        &self,
+        transaction_id: u64,
+    ) -> Result<(), ConnectionError> {
+        let mut kill_transaction_request = KillTransactionRequest::new();
+        kill_transaction_request.transaction_id = transaction_id;
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::KillTransaction(kill_transaction_request));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+        handle_transaction_response(proto_response)
+    }
+
+    pub(crate) async fn sync_schema(
+        &self,
+        table_schema: TableSchema,
+    ) -> Result<(), ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = table_schema.name.clone();
 
         let mut sync_model_request = SyncModelRequest::new();
-        sync_model_request.schema_string = T::table_schema().to_string();
+        sync_model_request.schema_string = table_schema.to_string();
         proto_request.data = Some(ProtoRequestData::SyncModel(sync_model_request));
 
-        let proto_response = send_request(self.streams[&0].clone(), proto_request).await?;
+        let proto_response = self.send_to_partition(0, proto_request).await;
+        schema_cache::invalidate(self.schema_cache_key(&table_schema.name));
 
-        match proto_response.data.unwrap() {
+        match proto_response?.data.unwrap() {
             ProtoResponseData::Model(_) => Ok(()),
-            ProtoResponseData::ClientError(client_error) => {
-                Err(ConnectionError::Client(client_error.detail))
-            }
-            ProtoResponseData::ServerError(server_error) => {
-                Err(ConnectionError::Server(server_error.detail))
-            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn sync_schemas(
+        &self,
+        table_schemas: Vec<TableSchema>,
+    ) -> Result<(), ConnectionError> {
+        let mut sync_models_request = SyncModelsRequest::new();
+        sync_models_request.schema_strings = table_schemas
+            .iter()
+            .map(|table_schema| table_schema.to_string())
+            .collect();
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::SyncModels(sync_models_request));
+
+        let proto_response = self.send_to_partition(0, proto_request).await;
+        for table_schema in &table_schemas {
+            schema_cache::invalidate(self.schema_cache_key(&table_schema.name));
+        }
+
+        match proto_response?.data.unwrap() {
+            ProtoResponseData::Model(_) => Ok(()),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn set_read_only(
+        &self,
+        table_name: &str,
+        read_only: bool,
+    ) -> Result<(), ConnectionError> {
+        let mut set_read_only_request = SetReadOnlyRequest::new();
+        set_read_only_request.read_only = read_only;
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = table_name.to_string();
+        proto_request.data = Some(ProtoRequestData::SetReadOnly(set_read_only_request));
+
+        let proto_response = self.send_to_partition(0, proto_request).await;
+        // read-only flag is one of table options, which are part of described schema
+        schema_cache::invalidate(self.schema_cache_key(table_name));
+
+        match proto_response?.data.unwrap() {
+            ProtoResponseData::SetReadOnly(_) => Ok(()),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
             _ => panic!("Invalid proto response type"),
         }
     }
 
     pub(crate) async fn drop_table(&self, table_name: String) -> Result<(), ConnectionError> {
+        let schema_cache_key = self.schema_cache_key(&table_name);
         let mut proto_request = ProtoRequest::new();
         proto_request.table = table_name;
 
         proto_request.data = Some(ProtoRequestData::DropTable(DropTableRequest::new()));
 
-        let proto_response = send_request(self.streams[&0].clone(), proto_request).await?;
+        let proto_response = self.send_to_partition(0, proto_request).await;
+        schema_cache::invalidate(schema_cache_key);
 
-        match proto_response.data.unwrap() {
+        match proto_response?.data.unwrap() {
             ProtoResponseData::DropTable(_) => Ok(()),
-            ProtoResponseData::ClientError(client_error) => {
-                Err(ConnectionError::Client(client_error.detail))
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn table_schema(
+        &self,
+        table_name: &str,
+    ) -> Result<TableSchema, ConnectionError> {
+        let schema_cache_key = self.schema_cache_key(table_name);
+        if let Ok(table_schema) = schema_cache::get(&schema_cache_key) {
+            return Ok(table_schema);
+        }
+
+        // whoever waited for describe in flight finds its schema cached
+        let describe_lock = schema_cache::describe_lock(&schema_cache_key);
+        let _describing = describe_lock.lock().await;
+        let version = match schema_cache::get(&schema_cache_key) {
+            Ok(table_schema) => return Ok(table_schema),
+            Err(version) => version,
+        };
+
+        let (table_schema, _) = self.describe_table(table_name).await?;
+        schema_cache::insert(schema_cache_key, version, table_schema.clone());
+        Ok(table_schema)
+    }
+
+    // always asks server, statistics change with every flush and compaction
+    pub(crate) async fn describe_table(
+        &self,
+        table_name: &str,
+    ) -> Result<(TableSchema, TableStatistics), ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = table_name.to_string();
+        proto_request.data = Some(ProtoRequestData::DescribeTable(DescribeTableRequest::new()));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::DescribeTable(describe_table_response) => {
+                let table_schema = TableSchema::from_string(&describe_table_response.schema_string)
+                    .map_err(ConnectionError::decode)?;
+                // servers from before statistics were kept send none
+                let table_statistics = describe_table_response
+                    .statistics
+                    .into_option()
+                    .map(|statistics| TableStatistics {
+                        sstables: statistics.sstables as usize,
+                        row_count_estimate: statistics.row_count_estimate,
+                        average_row_size: statistics.average_row_size,
+                        hash_key_cardinality: statistics.hash_key_cardinality,
+                        rows_per_hash_key: statistics.rows_per_hash_key,
+                        disk_usage: statistics.disk_usage,
+                    })
+                    .unwrap_or_default();
+                Ok((table_schema, table_statistics))
+            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn fetch_partition_map(&self) -> Result<(), ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::PartitionMap(PartitionMapRequest::new()));
+
+        // asked of the server connection was opened to, which knows who leads it
+        let request_timeout = *self.request_timeout.read().unwrap();
+        let proto_response = send_request_with_circuit_breaker(
+            0,
+            self.streams[&0].clone(),
+            self.circuit_breakers[&0].clone(),
+            proto_request.write_to_bytes().unwrap(),
+            request_timeout,
+        )
+        .await?;
+        let (partition_map, leader) = parse_partition_map_response(proto_response)?;
+        self.set_partition_map(&partition_map)?;
+        self.leader_routing
+            .follow(
+                &leader,
+                self.streams[&0].address,
+                &self.database,
+                self.streams.len(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn migrate_bucket(
+        &self,
+        hash_key: String,
+        target_partition: usize,
+    ) -> Result<(), ConnectionError> {
+        let mut migrate_bucket_request = MigrateBucketRequest::new();
+        migrate_bucket_request.hash_key = hash_key;
+        migrate_bucket_request.target_partition = target_partition as u32;
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::MigrateBucket(migrate_bucket_request));
+
+        // server handles it alongside other requests to partition 0, so they are not held up by it
+        let (stream, circuit_breaker) = match self.leader_routing.node(false) {
+            Some(leader) => (
+                leader.streams[&0].clone(),
+                leader.circuit_breakers[&0].clone(),
+            ),
+            None => (self.streams[&0].clone(), self.circuit_breakers[&0].clone()),
+        };
+        let proto_response = send_request_with_circuit_breaker(
+            0,
+            stream,
+            circuit_breaker,
+            proto_request.write_to_bytes().unwrap(),
+            MIGRATE_BUCKET_TIMEOUT,
+        )
+        .await?;
+        let (partition_map, _) = parse_partition_map_response(proto_response)?;
+        self.set_partition_map(&partition_map)
+    }
+
+    pub(crate) async fn describe_server(&self) -> Result<ServerDescription, ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::DescribeServer(
+            DescribeServerRequest::new(),
+        ));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::DescribeServer(describe_server_response) => Ok(ServerDescription {
+                version: describe_server_response.version,
+                uptime: Duration::from_millis(describe_server_response.uptime_millis),
+                number_of_threads: describe_server_response.number_of_threads as usize,
+                config: describe_server_response.config.into_iter().collect(),
+                features: describe_server_response.features,
+                databases: describe_server_response
+                    .databases
+                    .into_iter()
+                    .map(|database| DatabaseDescription {
+                        name: database.name,
+                        data_dir: DataDir {
+                            root: database.data_dir,
+                            sstables: database.sstables_dir,
+                            commit_logs: database.commit_logs_dir,
+                            table_schemas_file: database.table_schemas_file,
+                        },
+                        partition_threads: database
+                            .partition_threads
+                            .into_iter()
+                            .map(|thread| thread as usize)
+                            .collect(),
+                    })
+                    .collect(),
+            }),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn promote(&self) -> Result<bool, ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::Promote(PromoteRequest::new()));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::Promote(promote_response) => Ok(promote_response.promoted),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    // models and continuation token, which is empty once there are no more of them
+    pub(crate) async fn query_index_page<T: Model>(
+        &self,
+        column: &str,
+        range: impl RangeBounds<Value>,
+        limit: u32,
+        continuation_token: String,
+    ) -> Result<(Vec<T>, String), ConnectionError> {
+        self.check_model_schema(T::table_name, T::table_schema)
+            .await;
+        let mut query_by_index_request = QueryByIndexRequest::new();
+        query_by_index_request.column = column.to_string();
+        query_by_index_request.limit = limit;
+        query_by_index_request.continuation_token = continuation_token;
+        match range.start_bound() {
+            Bound::Included(start) | Bound::Excluded(start) => {
+                query_by_index_request.start = parse_message_field_from_value(start.clone());
+                query_by_index_request.start_inclusive =
+                    matches!(range.start_bound(), Bound::Included(_));
+            }
+            Bound::Unbounded => {}
+        }
+        match range.end_bound() {
+            Bound::Included(end) | Bound::Excluded(end) => {
+                query_by_index_request.end = parse_message_field_from_value(end.clone());
+                query_by_index_request.end_inclusive =
+                    matches!(range.end_bound(), Bound::Included(_));
+            }
+            Bound::Unbounded => {}
+        }
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = T::table_name();
+        proto_request.schema_version = T::table_schema().schema_version();
+        proto_request.data = Some(ProtoRequestData::QueryByIndex(query_by_index_request));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::QueryByIndex(query_by_index_response) => Ok((
+                query_by_index_response
+                    .items
+                    .into_iter()
+                    .map(model_from_get_response)
+                    .collect(),
+                query_by_index_response.continuation_token,
+            )),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn key_distribution(
+        &self,
+        table_name: &str,
+    ) -> Result<Vec<PartitionRows>, ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = table_name.to_string();
+        proto_request.data = Some(ProtoRequestData::KeyDistribution(
+            KeyDistributionRequest::new(),
+        ));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::KeyDistribution(key_distribution_response) => {
+                Ok(key_distribution_response
+                    .partitions
+                    .into_iter()
+                    .map(|partition_key_distribution| PartitionRows {
+                        partition: partition_key_distribution.partition as usize,
+                        memtable_rows: partition_key_distribution.memtable_rows,
+                        sstable_rows: partition_key_distribution.sstable_rows,
+                        bytes: partition_key_distribution.bytes,
+                    })
+                    .collect())
+            }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn partition_load(&self) -> Result<Vec<PartitionLoad>, ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::PartitionLoad(PartitionLoadRequest::new()));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::PartitionLoad(partition_load_response) => {
+                Ok(partition_load_response
+                    .partitions
+                    .into_iter()
+                    .map(|partition_load_stats| PartitionLoad {
+                        partition: partition_load_stats.partition as usize,
+                        requests_per_second: partition_load_stats.requests_per_second,
+                        average_latency: Duration::from_micros(
+                            partition_load_stats.average_latency_micros,
+                        ),
+                        skew: partition_load_stats.skew,
+                        hot: partition_load_stats.hot,
+                    })
+                    .collect())
             }
-            ProtoResponseData::ServerError(server_error) => {
-                Err(ConnectionError::Server(server_error.detail))
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn table_access(&self) -> Result<Vec<TableAccess>, ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::TableAccess(TableAccessRequest::new()));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::TableAccess(table_access_response) => Ok(table_access_response
+                .tables
+                .into_iter()
+                .map(|table_access_stats| TableAccess {
+                    table: table_access_stats.table,
+                    reads_per_second: table_access_stats.reads_per_second,
+                    writes_per_second: table_access_stats.writes_per_second,
+                    read_bytes_per_second: table_access_stats.read_bytes_per_second,
+                    written_bytes_per_second: table_access_stats.written_bytes_per_second,
+                    read_p99_latency: Duration::from_micros(
+                        table_access_stats.read_p99_latency_micros,
+                    ),
+                    write_p99_latency: Duration::from_micros(
+                        table_access_stats.write_p99_latency_micros,
+                    ),
+                })
+                .collect()),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn compaction(
+        &self,
+        update: CompactionSettingsUpdate,
+    ) -> Result<CompactionState, ConnectionError> {
+        let mut compaction_request = CompactionRequest::new();
+        compaction_request.bucket_low = update.bucket_low;
+        compaction_request.bucket_high = update.bucket_high;
+        compaction_request.sstable_min_size = update.sstable_min_size;
+        compaction_request.interval_millis =
+            update.interval.map(|interval| interval.as_millis() as u64);
+        compaction_request.max_concurrent_compactions = update
+            .max_concurrent_compactions
+            .map(|max_concurrent_compactions| max_concurrent_compactions as u32);
+        compaction_request.io_rate_limit_mb = update.io_rate_limit_mb;
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::Compaction(compaction_request));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::Compaction(compaction_response) => Ok(CompactionState {
+                settings: CompactionSettings {
+                    bucket_low: compaction_response.bucket_low,
+                    bucket_high: compaction_response.bucket_high,
+                    sstable_min_size: compaction_response.sstable_min_size,
+                    interval: Duration::from_millis(compaction_response.interval_millis),
+                    max_concurrent_compactions: compaction_response.max_concurrent_compactions
+                        as usize,
+                    io_rate_limit_mb: compaction_response.io_rate_limit_mb,
+                },
+                tables: compaction_response
+                    .tables
+                    .into_iter()
+                    .map(|table_compaction| TableCompactionState {
+                        table_name: table_compaction.table,
+                        buckets: table_compaction
+                            .buckets
+                            .into_iter()
+                            .map(|compaction_bucket| CompactionBucket {
+                                sstables: compaction_bucket.sstables as usize,
+                                bytes: compaction_bucket.bytes,
+                                pending: compaction_bucket.pending,
+                            })
+                            .collect(),
+                        pending_sstables: table_compaction.pending_sstables as usize,
+                        last_duration: table_compaction
+                            .last_duration_millis
+                            .map(Duration::from_millis),
+                        priority: table_compaction.priority as usize,
+                    })
+                    .collect(),
+            }),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        }
+    }
+
+    pub(crate) async fn plan_migration(
+        &self,
+        table_schema: TableSchema,
+    ) -> Result<Vec<String>, ConnectionError> {
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = table_schema.name.clone();
+
+        let mut plan_migration_request = PlanMigrationRequest::new();
+        plan_migration_request.schema_string = table_schema.to_string();
+        proto_request.data = Some(ProtoRequestData::PlanMigration(plan_migration_request));
+
+        let proto_response = self.send_to_partition(0, proto_request).await?;
+
+        match proto_response.data.unwrap() {
+            ProtoResponseData::PlanMigration(plan_migration_response) => {
+                Ok(plan_migration_response.steps)
             }
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
             _ => panic!("Invalid proto response type"),
         }
     }
 }
 
-async fn send_request(
-    stream: Arc<Mutex<TcpStream>>,
+pub(crate) async fn send_request(
+    stream: &MultiplexedStream,
     proto_request: ProtoRequest,
 ) -> Result<ProtoResponse, ConnectionError> {
-    let request_bytes = proto_request.write_to_bytes().unwrap();
-    let request_size_prefix = (request_bytes.len() as u32).to_be_bytes();
+    stream.send(proto_request.write_to_bytes().unwrap()).await
+}
+
+async fn send_request_with_circuit_breaker(
+    thread: usize,
+    stream: Arc<MultiplexedStream>,
+    circuit_breaker: Arc<StdMutex<CircuitBreaker>>,
+    request_bytes: Vec<u8>,
+    request_timeout: Duration,
+) -> Result<ProtoResponse, ConnectionError> {
+    let reconnect = circuit_breaker.lock().unwrap().before_request(thread)?;
 
-    let mut stream = stream.try_lock().unwrap();
+    let result = timeout(request_timeout, async {
+        // probe after cooldown goes through a fresh socket
+        if reconnect {
+            stream.reset().await;
+        }
+        stream.send(request_bytes).await
+    })
+    .await
+    .unwrap_or(Err(ConnectionError::Timeout(request_timeout)));
+
+    // errors returned by server in response body do not count as failures
+    let mut circuit_breaker = circuit_breaker.lock().unwrap();
+    match &result {
+        Ok(_) => circuit_breaker.record_success(),
+        Err(_) => circuit_breaker.record_failure(),
+    }
 
-    stream
-        .write_all(&request_size_prefix)
-        .await
-        .map_err(|e| ConnectionError::Client(e.to_string()))?;
-    stream
-        .write_all(&request_bytes)
-        .await
-        .map_err(|e| ConnectionError::Client(e.to_string()))?;
+    result
+}
 
-    let response_size = stream
-        .read_u32()
-        .await
-        .map_err(|e| ConnectionError::Client(e.to_string()))?;
-    let mut buffer = vec![0u8; response_size as usize];
-    stream
-        .read_exact(&mut buffer)
-        .await
-        .map_err(|e| ConnectionError::Client(e.to_string()))?;
+// partition map along with leader of the server, empty if it leads itself
+fn parse_partition_map_response(
+    proto_response: ProtoResponse,
+) -> Result<(String, String), ConnectionError> {
+    match proto_response.data.unwrap() {
+        ProtoResponseData::PartitionMap(partition_map_response) => Ok((
+            partition_map_response.partition_map,
+            partition_map_response.leader,
+        )),
+        ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+        _ => panic!("Invalid proto response type"),
+    }
+}
+
+pub(crate) fn parse_session_response(proto_response: ProtoResponse) -> Result<(), ConnectionError> {
+    match proto_response.data.unwrap() {
+        ProtoResponseData::Session(_) => Ok(()),
+        ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+        _ => panic!("Invalid proto response type"),
+    }
+}
+
+// leader named by server that rejected request as it does not lead
+fn not_leader_hint(proto_response: &ProtoResponse) -> Option<String> {
+    match &proto_response.data {
+        Some(ProtoResponseData::ClientError(client_error))
+            if client_error.code.enum_value_or_default() == ErrorCode::NOT_LEADER =>
+        {
+            client_error.metadata.get("leader").cloned()
+        }
+        _ => None,
+    }
+}
+
+// chunks of every server thread are sent concurrently, up to the limit of chunks in flight
+fn pace_thread_requests<F: Future + 'static>(
+    thread_requests: Vec<Vec<F>>,
+    chunking_policy: &ChunkingPolicy,
+) -> impl Stream<Item = F::Output> + 'static {
+    let max_in_flight = chunking_policy.max_in_flight_per_partition.max(1);
+    stream::select_all(
+        thread_requests
+            .into_iter()
+            .map(move |requests| Box::pin(stream::iter(requests).buffer_unordered(max_in_flight))),
+    )
+}
+
+fn parse_get_response<T: Model>(
+    proto_response: ProtoResponse,
+) -> Result<Option<T>, ConnectionError> {
+    parse_get_response_with(proto_response, model_from_get_response)
+}
+
+fn parse_get_response_with<T>(
+    proto_response: ProtoResponse,
+    from_get_response: impl FnOnce(GetResponse) -> T,
+) -> Result<Option<T>, ConnectionError> {
+    match proto_response.data {
+        None => Ok(None),
+        Some(proto_response_data) => match proto_response_data {
+            ProtoResponseData::Get(get_response) => Ok(Some(from_get_response(get_response))),
+            ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+            ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+            _ => panic!("Invalid proto response type"),
+        },
+    }
+}
+
+fn read_explanation_to_trace(read_explanation: ReadExplanation) -> ReadTrace {
+    ReadTrace {
+        primary_key: read_explanation.primary_key,
+        memtable_hit: read_explanation.memtable_hit,
+        memtable_time: Duration::from_micros(read_explanation.memtable_micros),
+        sstables: read_explanation
+            .sstables
+            .into_iter()
+            .map(|sstable_read| SSTableRead {
+                file_name: sstable_read.file_name,
+                partition_skipped: sstable_read.partition_skipped,
+                rows_read: sstable_read.rows_read as usize,
+                bytes_read: sstable_read.bytes_read,
+                found: sstable_read.found,
+                time: Duration::from_micros(sstable_read.micros),
+            })
+            .collect(),
+        sstables_not_consulted: read_explanation.sstables_not_consulted as usize,
+    }
+}
+
+// token is empty for inserts done within transaction
+fn parse_insert_response(
+    proto_response: ProtoResponse,
+) -> Result<ConsistencyToken, ConnectionError> {
+    match proto_response.data.unwrap() {
+        ProtoResponseData::Insert(insert_response) => Ok(insert_response.consistency_token.into()),
+        ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+        _ => panic!("Invalid proto response type"),
+    }
+}
 
-    ProtoResponse::parse_from_bytes(&buffer).map_err(|e| ConnectionError::Client(e.to_string()))
+fn parse_delete_response(proto_response: ProtoResponse) -> Result<bool, ConnectionError> {
+    match proto_response.data.unwrap() {
+        ProtoResponseData::Delete(delete_response) => Ok(delete_response.okay),
+        ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+        _ => panic!("Invalid proto response type"),
+    }
+}
+
+// same model under another key
+fn with_key<T: Model>(instance: &T, hash_key: String, sort_key: Value) -> T {
+    let mut get_response = GetResponse::new();
+    get_response.hash_key = hash_key;
+    get_response.sort_key = parse_message_field_from_value(sort_key);
+    get_response.values = instance.to_insert_request().values;
+    T::from_get_response(get_response)
 }
 
 fn handle_transaction_response(proto_response: ProtoResponse) -> Result<(), ConnectionError> {
     match proto_response.data.unwrap() {
         ProtoResponseData::Transaction(_) => Ok(()),
-        ProtoResponseData::ClientError(client_error) => {
-            Err(ConnectionError::Client(client_error.detail))
-        }
-        ProtoResponseData::ServerError(server_error) => {
-            Err(ConnectionError::Server(server_error.detail))
-        }
+        ProtoResponseData::ClientError(client_error) => Err(client_error.into()),
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
         _ => panic!("Invalid proto response type"),
     }
 }
@@ -490,8 +2576,298 @@ impl Drop for Connection {
     fn drop(&mut self) {
         if let Some(pool_ref) = self.pool.take() {
             if let Some(pool) = pool_ref.upgrade() {
-                pool.put_back(self.inner.clone());
+                pool.put_back();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::ConnectionPool;
+    use protos::{DescribeTableResponse, GetResponse, PartitionMapResponse, SessionResponse};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    async fn read_request(stream: &mut TcpStream) -> (u64, ProtoRequest) {
+        let correlation_id = stream.read_u64().await.unwrap();
+        let size = stream.read_u32().await.unwrap();
+        let mut buffer = vec![0u8; size as usize];
+        stream.read_exact(&mut buffer).await.unwrap();
+        (
+            correlation_id,
+            ProtoRequest::parse_from_bytes(&buffer).unwrap(),
+        )
+    }
+
+    async fn write_response(stream: &mut TcpStream, correlation_id: u64, data: ProtoResponseData) {
+        let mut proto_response = ProtoResponse::new();
+        proto_response.data = Some(data);
+        let response_bytes = proto_response.write_to_bytes().unwrap();
+        stream.write_u64(correlation_id).await.unwrap();
+        stream.write_u32(response_bytes.len() as u32).await.unwrap();
+        stream.write_all(&response_bytes).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn connection_is_shared_between_tasks() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = match listener.local_addr().unwrap() {
+            std::net::SocketAddr::V4(address) => address,
+            _ => unreachable!(),
+        };
+
+        // gets are answered only once both arrived, so a request holding the connection would hang
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_u32(1).await.unwrap();
+
+            let (correlation_id, _) = read_request(&mut stream).await;
+            let mut partition_map_response = PartitionMapResponse::new();
+            partition_map_response.partition_map = PartitionMap::new(1).to_string();
+            write_response(
+                &mut stream,
+                correlation_id,
+                ProtoResponseData::PartitionMap(partition_map_response),
+            )
+            .await;
+
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                requests.push(read_request(&mut stream).await);
             }
+            for (correlation_id, request) in requests.into_iter().rev() {
+                let mut get_response = GetResponse::new();
+                if let Some(ProtoRequestData::Get(get_request)) = request.data {
+                    get_response.hash_key = get_request.hash_key;
+                    get_response.sort_key = get_request.sort_key;
+                }
+                write_response(
+                    &mut stream,
+                    correlation_id,
+                    ProtoResponseData::Get(get_response),
+                )
+                .await;
+            }
+        });
+
+        let connection = Arc::new(Connection::new(address).await.unwrap());
+        let tasks: Vec<_> = ["first", "second"]
+            .into_iter()
+            .map(|hash_key| {
+                let connection = connection.clone();
+                tokio::spawn(async move {
+                    connection
+                        .get_row("users", hash_key.to_string(), Value::Int32(-1))
+                        .await
+                })
+            })
+            .collect();
+
+        for (task, hash_key) in tasks.into_iter().zip(["first", "second"]) {
+            let row = timeout(Duration::from_secs(5), task)
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            assert_eq!(row.hash_key, hash_key);
+        }
+    }
+
+    #[tokio::test]
+    async fn pooled_connections_describe_table_once() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = match listener.local_addr().unwrap() {
+            std::net::SocketAddr::V4(address) => address,
+            _ => unreachable!(),
+        };
+
+        // describe is answered late, so that every connection misses the cache meanwhile
+        let describes = Arc::new(AtomicUsize::new(0));
+        let server_describes = describes.clone();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_u32(1).await.unwrap();
+
+            let (correlation_id, _) = read_request(&mut stream).await;
+            let mut partition_map_response = PartitionMapResponse::new();
+            partition_map_response.partition_map = PartitionMap::new(1).to_string();
+            write_response(
+                &mut stream,
+                correlation_id,
+                ProtoResponseData::PartitionMap(partition_map_response),
+            )
+            .await;
+
+            loop {
+                let (correlation_id, request) = read_request(&mut stream).await;
+                if let Some(ProtoRequestData::DescribeTable(_)) = request.data {
+                    server_describes.fetch_add(1, Ordering::SeqCst);
+                    sleep(Duration::from_millis(100)).await;
+                    let mut describe_table_response = DescribeTableResponse::new();
+                    describe_table_response.schema_string =
+                        "users>sort_key:INT32;age:INT32".to_string();
+                    write_response(
+                        &mut stream,
+                        correlation_id,
+                        ProtoResponseData::DescribeTable(describe_table_response),
+                    )
+                    .await;
+                }
+            }
+        });
+
+        let pool = ConnectionPool::new(address, 4, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let mut tasks = Vec::new();
+        for _ in 0..4 {
+            let connection = pool.acquire().await.unwrap();
+            tasks.push(tokio::spawn(async move {
+                connection.describe_table("users").await
+            }));
+        }
+
+        for task in tasks {
+            let table_schema = timeout(Duration::from_secs(5), task)
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+            assert!(table_schema.columns.contains_key("age"));
+        }
+        assert_eq!(describes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn pooled_connections_keep_sessions_of_their_own() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = match listener.local_addr().unwrap() {
+            std::net::SocketAddr::V4(address) => address,
+            _ => unreachable!(),
+        };
+
+        // like server, keeps session per socket, gets are answered with keyspace of the session
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                stream.write_u32(1).await.unwrap();
+                tokio::spawn(async move {
+                    let mut keyspace = String::new();
+                    loop {
+                        let (correlation_id, request) = read_request(&mut stream).await;
+                        let data = match request.data {
+                            Some(ProtoRequestData::PartitionMap(_)) => {
+                                let mut partition_map_response = PartitionMapResponse::new();
+                                partition_map_response.partition_map =
+                                    PartitionMap::new(1).to_string();
+                                ProtoResponseData::PartitionMap(partition_map_response)
+                            }
+                            Some(ProtoRequestData::SetSession(set_session_request)) => {
+                                keyspace = set_session_request.keyspace;
+                                ProtoResponseData::Session(SessionResponse::new())
+                            }
+                            Some(ProtoRequestData::Get(get_request)) => {
+                                let mut get_response = GetResponse::new();
+                                get_response.hash_key = keyspace.clone();
+                                get_response.sort_key = get_request.sort_key;
+                                ProtoResponseData::Get(get_response)
+                            }
+                            _ => continue,
+                        };
+                        write_response(&mut stream, correlation_id, data).await;
+                    }
+                });
+            }
+        });
+
+        let pool = ConnectionPool::new(address, 2, Duration::from_secs(1))
+            .await
+            .unwrap();
+        let first = pool.acquire().await.unwrap();
+        let second = pool.acquire().await.unwrap();
+        for (connection, keyspace) in [(&first, "first"), (&second, "second")] {
+            let session = Session {
+                keyspace: Some(keyspace.to_string()),
+                ..Session::default()
+            };
+            connection.set_session(session).await.unwrap();
+        }
+
+        for (connection, keyspace) in [(&first, "first"), (&second, "second")] {
+            let row = connection
+                .get_row("", "key".to_string(), Value::Int32(-1))
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(row.hash_key, keyspace);
         }
+        // connections of the pool without a session are not affected either
+        drop(first);
+        let third = pool.acquire().await.unwrap();
+        let row = third
+            .get_row("", "key".to_string(), Value::Int32(-1))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.hash_key, "");
+    }
+
+    #[tokio::test]
+    async fn hedged_get_is_answered_while_first_attempt_hangs() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = match listener.local_addr().unwrap() {
+            std::net::SocketAddr::V4(address) => address,
+            _ => unreachable!(),
+        };
+
+        // first get is never answered, the way a slow one would not be, later ones are answered
+        // over the same socket, as server handles its requests concurrently
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_u32(1).await.unwrap();
+            let mut gets_received = 0;
+            loop {
+                let (correlation_id, request) = read_request(&mut stream).await;
+                let data = match request.data {
+                    Some(ProtoRequestData::PartitionMap(_)) => {
+                        let mut partition_map_response = PartitionMapResponse::new();
+                        partition_map_response.partition_map = PartitionMap::new(1).to_string();
+                        ProtoResponseData::PartitionMap(partition_map_response)
+                    }
+                    Some(ProtoRequestData::Get(get_request)) => {
+                        gets_received += 1;
+                        if gets_received == 1 {
+                            continue;
+                        }
+                        let mut get_response = GetResponse::new();
+                        get_response.hash_key = get_request.hash_key;
+                        get_response.sort_key = get_request.sort_key;
+                        ProtoResponseData::Get(get_response)
+                    }
+                    _ => continue,
+                };
+                write_response(&mut stream, correlation_id, data).await;
+            }
+        });
+
+        let connection = Connection::new(address).await.unwrap();
+        connection.set_read_hedging(Some(HedgingPolicy {
+            percentile: 0.95,
+            min_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+        }));
+        let row = timeout(
+            Duration::from_secs(5),
+            connection.get_row("users", "key".to_string(), Value::Int32(-1)),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+        assert_eq!(row.hash_key, "key");
     }
 }
@@ -1,6 +1,8 @@
+use crate::error::ConnectionError;
+use crate::transaction::TransactionScope;
 use common::value::Value;
 use protos::util::parse_message_field_from_value;
-use protos::{DeleteRequest, GetRequest};
+use protos::{DeleteRequest, GetRequest, ProtoRequest};
 
 pub fn create_get_request(hash_key: String, sort_key: Value) -> GetRequest {
     let mut get_request = GetRequest::new();
@@ -17,3 +19,15 @@ pub fn create_delete_request(hash_key: String, sort_key: Value) -> DeleteRequest
 
     delete_request
 }
+
+pub fn set_transaction(request: &mut ProtoRequest, transaction: Option<TransactionScope>) {
+    if let Some(transaction) = transaction {
+        request.transaction_id = Some(transaction.id);
+        request.begins_transaction = transaction.begins;
+    }
+}
+
+// same key policy as server, so invalid keys fail before being sent
+pub fn validate_sort_key(sort_key: &Value) -> Result<(), ConnectionError> {
+    sort_key.validate_key().map_err(ConnectionError::Validation)
+}
@@ -0,0 +1,61 @@
+use common::value::Value;
+use protos::util::{parse_value_from_message_field, parse_value_from_proto};
+use protos::GetResponse;
+use std::collections::HashMap;
+
+// row of any table, for callers that have no model compiled for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynamicRow {
+    pub hash_key: String,
+    pub sort_key: Value,
+    pub values: HashMap<String, Value>,
+}
+
+impl DynamicRow {
+    pub(crate) fn from_get_response(get_response: GetResponse) -> DynamicRow {
+        DynamicRow {
+            hash_key: get_response.hash_key,
+            sort_key: parse_value_from_message_field(get_response.sort_key),
+            values: get_response
+                .values
+                .into_iter()
+                .map(|(name, value)| (name, parse_value_from_proto(value)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::util::{parse_message_field_from_value, parse_proto_from_value};
+
+    #[test]
+    fn row_is_read_from_get_response() {
+        let mut get_response = GetResponse::new();
+        get_response.hash_key = "user".to_string();
+        get_response.sort_key = parse_message_field_from_value(Value::Int32(7));
+        get_response.values.insert(
+            "email".to_string(),
+            parse_proto_from_value(Value::Varchar("user@example.com".to_string())),
+        );
+        get_response
+            .values
+            .insert("age".to_string(), parse_proto_from_value(Value::Null));
+
+        assert_eq!(
+            DynamicRow::from_get_response(get_response),
+            DynamicRow {
+                hash_key: "user".to_string(),
+                sort_key: Value::Int32(7),
+                values: HashMap::from([
+                    (
+                        "email".to_string(),
+                        Value::Varchar("user@example.com".to_string())
+                    ),
+                    ("age".to_string(), Value::Null),
+                ]),
+            }
+        );
+    }
+}
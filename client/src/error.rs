@@ -0,0 +1,349 @@
+use protos::{ClientError, ErrorCode, ServerError};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum ConnectionError {
+    // connecting, writing or reading the stream failed
+    Io(std::io::Error),
+    Timeout(Duration),
+    // response or data sent by server could not be parsed
+    Decode {
+        detail: String,
+        source: Option<protobuf::Error>,
+    },
+    // request was rejected by server or client as invalid
+    Validation(String),
+    // hash key is longer than table allows, both in bytes
+    HashKeyTooLong {
+        length: usize,
+        limit: usize,
+    },
+    NotFound(String),
+    // table takes more bytes on disk than its quota allows, inserts fail until it gets smaller
+    QuotaExceeded {
+        usage: u64,
+        limit: u64,
+    },
+    // values do not match schema table has on server, cached schema of the table gets dropped
+    SchemaMismatch(String),
+    // request lost to concurrent change, retrying it may succeed
+    Conflict(String),
+    // row has another version than save expected, 0 if there is none, it has to be read again
+    VersionConflict {
+        expected: u64,
+        actual: u64,
+    },
+    Server {
+        code: ErrorCode,
+        detail: String,
+        retryable: bool,
+        retry_after: Option<Duration>,
+    },
+    // hash key is owned by given partition, client re-routes such requests by itself,
+    // so this only surfaces when partition map keeps changing
+    WrongPartition(usize),
+    PoolExhausted,
+    // server thread failed repeatedly, requests to it fail fast until cooldown passes
+    CircuitOpen(usize),
+    // server is a standby or a cluster follower, writes go to its leader at given address, if
+    // server knows it
+    NotLeader(Option<String>),
+}
+
+impl ConnectionError {
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ConnectionError::Io(_)
+            | ConnectionError::Timeout(_)
+            | ConnectionError::Conflict(_)
+            | ConnectionError::WrongPartition(_)
+            | ConnectionError::PoolExhausted
+            | ConnectionError::CircuitOpen(_) => true,
+            ConnectionError::Server { retryable, .. } => *retryable,
+            ConnectionError::Decode { .. }
+            | ConnectionError::Validation(_)
+            | ConnectionError::HashKeyTooLong { .. }
+            | ConnectionError::NotFound(_)
+            | ConnectionError::SchemaMismatch(_)
+            | ConnectionError::QuotaExceeded { .. }
+            | ConnectionError::VersionConflict { .. }
+            | ConnectionError::NotLeader(_) => false,
+        }
+    }
+
+    pub(crate) fn decode(detail: String) -> ConnectionError {
+        ConnectionError::Decode {
+            detail,
+            source: None,
+        }
+    }
+}
+
+impl Display for ConnectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectionError::Io(error) => write!(f, "I/O error: {}", error),
+            ConnectionError::Timeout(duration) => {
+                write!(f, "Request timed out after {:?}", duration)
+            }
+            ConnectionError::Decode { detail, .. } => {
+                write!(f, "Failed to decode response: {}", detail)
+            }
+            ConnectionError::Validation(detail) => write!(f, "Invalid request: {}", detail),
+            ConnectionError::HashKeyTooLong { length, limit } => write!(
+                f,
+                "Invalid request: hash key of {} bytes is longer than {} bytes",
+                length, limit
+            ),
+            ConnectionError::NotFound(detail) => write!(f, "Not found: {}", detail),
+            ConnectionError::SchemaMismatch(detail) => write!(f, "Schema mismatch: {}", detail),
+            ConnectionError::QuotaExceeded { usage, limit } => write!(
+                f,
+                "Quota exceeded: table takes {} bytes, limit is {} bytes",
+                usage, limit
+            ),
+            ConnectionError::Conflict(detail) => write!(f, "Conflict: {}", detail),
+            ConnectionError::VersionConflict { expected, actual } => write!(
+                f,
+                "Conflict: row has version {}, save expected {}",
+                actual, expected
+            ),
+            ConnectionError::Server { code, detail, .. } => {
+                write!(f, "Server error {:?}: {}", code, detail)
+            }
+            ConnectionError::WrongPartition(partition) => {
+                write!(f, "Hash key is owned by partition {}", partition)
+            }
+            ConnectionError::PoolExhausted => write!(f, "Connection pool exhausted"),
+            ConnectionError::CircuitOpen(thread) => {
+                write!(f, "Circuit open for server thread {}", thread)
+            }
+            ConnectionError::NotLeader(Some(leader)) => {
+                write!(f, "Server does not lead, writes go to leader at {}", leader)
+            }
+            ConnectionError::NotLeader(None) => write!(f, "Server does not lead"),
+        }
+    }
+}
+
+impl std::error::Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConnectionError::Io(error) => Some(error),
+            ConnectionError::Decode {
+                source: Some(error),
+                ..
+            } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ConnectionError {
+    fn from(error: std::io::Error) -> ConnectionError {
+        ConnectionError::Io(error)
+    }
+}
+
+impl From<protobuf::Error> for ConnectionError {
+    fn from(error: protobuf::Error) -> ConnectionError {
+        ConnectionError::Decode {
+            detail: error.to_string(),
+            source: Some(error),
+        }
+    }
+}
+
+impl From<ClientError> for ConnectionError {
+    fn from(client_error: ClientError) -> ConnectionError {
+        match client_error.code.enum_value_or_default() {
+            ErrorCode::NOT_FOUND => ConnectionError::NotFound(client_error.detail),
+            ErrorCode::CONFLICT => ConnectionError::Conflict(client_error.detail),
+            ErrorCode::SCHEMA_MISMATCH | ErrorCode::SCHEMA_VERSION_MISMATCH => {
+                ConnectionError::SchemaMismatch(client_error.detail)
+            }
+            ErrorCode::HASH_KEY_TOO_LONG => {
+                let metadata_value = |name: &str| {
+                    client_error
+                        .metadata
+                        .get(name)
+                        .and_then(|value| value.parse::<usize>().ok())
+                };
+                match (metadata_value("length"), metadata_value("limit")) {
+                    (Some(length), Some(limit)) => {
+                        ConnectionError::HashKeyTooLong { length, limit }
+                    }
+                    _ => ConnectionError::Validation(client_error.detail),
+                }
+            }
+            ErrorCode::QUOTA_EXCEEDED => {
+                let metadata_value = |name: &str| {
+                    client_error
+                        .metadata
+                        .get(name)
+                        .and_then(|value| value.parse::<u64>().ok())
+                };
+                match (metadata_value("usage"), metadata_value("limit")) {
+                    (Some(usage), Some(limit)) => ConnectionError::QuotaExceeded { usage, limit },
+                    _ => ConnectionError::Validation(client_error.detail),
+                }
+            }
+            ErrorCode::VERSION_CONFLICT => {
+                let metadata_value = |name: &str| {
+                    client_error
+                        .metadata
+                        .get(name)
+                        .and_then(|value| value.parse::<u64>().ok())
+                };
+                match (metadata_value("expected"), metadata_value("actual")) {
+                    (Some(expected), Some(actual)) => {
+                        ConnectionError::VersionConflict { expected, actual }
+                    }
+                    _ => ConnectionError::Conflict(client_error.detail),
+                }
+            }
+            ErrorCode::WRONG_PARTITION => match client_error
+                .metadata
+                .get("partition")
+                .and_then(|partition| partition.parse().ok())
+            {
+                Some(partition) => ConnectionError::WrongPartition(partition),
+                None => ConnectionError::Validation(client_error.detail),
+            },
+            ErrorCode::NOT_LEADER => {
+                ConnectionError::NotLeader(client_error.metadata.get("leader").cloned())
+            }
+            _ => ConnectionError::Validation(client_error.detail),
+        }
+    }
+}
+
+impl From<ServerError> for ConnectionError {
+    fn from(server_error: ServerError) -> ConnectionError {
+        match server_error.code.enum_value_or_default() {
+            ErrorCode::CONFLICT => ConnectionError::Conflict(server_error.detail),
+            code => ConnectionError::Server {
+                code,
+                detail: server_error.detail,
+                retryable: server_error.retryable,
+                retry_after: match server_error.retry_after_millis {
+                    0 => None,
+                    millis => Some(Duration::from_millis(millis)),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::EnumOrUnknown;
+    use std::error::Error;
+
+    #[test]
+    fn proto_errors_map_to_error_kinds() {
+        let mut client_error = ClientError::new();
+        client_error.detail = "Table named 'users' not found".to_string();
+        client_error.code = EnumOrUnknown::new(ErrorCode::NOT_FOUND);
+        assert!(matches!(
+            ConnectionError::from(client_error),
+            ConnectionError::NotFound(_)
+        ));
+
+        let mut client_error = ClientError::new();
+        client_error.code = EnumOrUnknown::new(ErrorCode::HASH_KEY_TOO_LONG);
+        client_error
+            .metadata
+            .insert("length".to_string(), "200".to_string());
+        client_error
+            .metadata
+            .insert("limit".to_string(), "128".to_string());
+        assert!(matches!(
+            ConnectionError::from(client_error),
+            ConnectionError::HashKeyTooLong {
+                length: 200,
+                limit: 128
+            }
+        ));
+
+        let mut client_error = ClientError::new();
+        client_error.code = EnumOrUnknown::new(ErrorCode::WRONG_PARTITION);
+        client_error
+            .metadata
+            .insert("partition".to_string(), "3".to_string());
+        let error = ConnectionError::from(client_error);
+        assert!(error.is_retryable());
+        assert!(matches!(error, ConnectionError::WrongPartition(3)));
+
+        let mut client_error = ClientError::new();
+        client_error.code = EnumOrUnknown::new(ErrorCode::QUOTA_EXCEEDED);
+        client_error
+            .metadata
+            .insert("usage".to_string(), "2048".to_string());
+        client_error
+            .metadata
+            .insert("limit".to_string(), "1024".to_string());
+        assert!(matches!(
+            ConnectionError::from(client_error),
+            ConnectionError::QuotaExceeded {
+                usage: 2048,
+                limit: 1024
+            }
+        ));
+
+        let mut client_error = ClientError::new();
+        client_error.code = EnumOrUnknown::new(ErrorCode::SCHEMA_MISMATCH);
+        let error = ConnectionError::from(client_error);
+        assert!(!error.is_retryable());
+        assert!(matches!(error, ConnectionError::SchemaMismatch(_)));
+
+        let mut client_error = ClientError::new();
+        client_error.code = EnumOrUnknown::new(ErrorCode::VERSION_CONFLICT);
+        client_error
+            .metadata
+            .insert("expected".to_string(), "2".to_string());
+        client_error
+            .metadata
+            .insert("actual".to_string(), "3".to_string());
+        let error = ConnectionError::from(client_error);
+        assert!(!error.is_retryable());
+        assert!(matches!(
+            error,
+            ConnectionError::VersionConflict {
+                expected: 2,
+                actual: 3
+            }
+        ));
+
+        let mut client_error = ClientError::new();
+        client_error.code = EnumOrUnknown::new(ErrorCode::NOT_LEADER);
+        client_error
+            .metadata
+            .insert("leader".to_string(), "10.0.0.1:29800".to_string());
+        let error = ConnectionError::from(client_error);
+        assert!(!error.is_retryable());
+        assert!(
+            matches!(error, ConnectionError::NotLeader(Some(leader)) if leader == "10.0.0.1:29800")
+        );
+
+        let mut server_error = ServerError::new();
+        server_error.code = EnumOrUnknown::new(ErrorCode::UNAVAILABLE);
+        server_error.retryable = true;
+        server_error.retry_after_millis = 250;
+        let error = ConnectionError::from(server_error);
+        assert!(error.is_retryable());
+        assert!(matches!(
+            error,
+            ConnectionError::Server {
+                code: ErrorCode::UNAVAILABLE,
+                retry_after: Some(duration),
+                ..
+            } if duration == Duration::from_millis(250)
+        ));
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert!(ConnectionError::from(io_error).source().is_some());
+    }
+}
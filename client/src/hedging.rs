@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+static LATENCY_SAMPLES: usize = 1024;
+// until that many gets were measured, max_delay is used
+static MIN_LATENCY_SAMPLES: usize = 32;
+
+// get not answered within given percentile of recent get latencies is sent again, first response wins
+#[derive(Debug, Clone)]
+pub struct HedgingPolicy {
+    pub percentile: f64,
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for HedgingPolicy {
+    fn default() -> HedgingPolicy {
+        HedgingPolicy {
+            percentile: 0.95,
+            min_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(50),
+        }
+    }
+}
+
+pub(crate) struct ReadHedging {
+    policy: HedgingPolicy,
+    latencies: Mutex<VecDeque<Duration>>,
+}
+
+impl ReadHedging {
+    pub(crate) fn new(policy: HedgingPolicy) -> ReadHedging {
+        ReadHedging {
+            policy,
+            latencies: Mutex::new(VecDeque::with_capacity(LATENCY_SAMPLES)),
+        }
+    }
+
+    pub(crate) fn delay(&self) -> Duration {
+        let latencies = self.latencies.lock().unwrap();
+        if latencies.len() < MIN_LATENCY_SAMPLES {
+            return self.policy.max_delay;
+        }
+
+        let mut sorted_latencies: Vec<_> = latencies.iter().copied().collect();
+        sorted_latencies.sort_unstable();
+        let percentile = self.policy.percentile.clamp(0.0, 1.0);
+        let index = ((sorted_latencies.len() - 1) as f64 * percentile).round() as usize;
+
+        sorted_latencies[index].clamp(self.policy.min_delay, self.policy.max_delay)
+    }
+
+    pub(crate) fn record(&self, latency: Duration) {
+        let mut latencies = self.latencies.lock().unwrap();
+        if latencies.len() == LATENCY_SAMPLES {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_follows_latency_percentile_within_bounds() {
+        let read_hedging = ReadHedging::new(HedgingPolicy {
+            percentile: 0.9,
+            min_delay: Duration::from_millis(2),
+            max_delay: Duration::from_millis(20),
+        });
+        assert_eq!(read_hedging.delay(), Duration::from_millis(20));
+
+        for millis in 1..=100 {
+            read_hedging.record(Duration::from_micros(millis * 100));
+        }
+        assert_eq!(read_hedging.delay(), Duration::from_micros(9000));
+
+        read_hedging.record(Duration::from_secs(1));
+        for _ in 0..LATENCY_SAMPLES {
+            read_hedging.record(Duration::from_micros(100));
+        }
+        assert_eq!(read_hedging.delay(), Duration::from_millis(2));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::dynamic_row::DynamicRow;
+use common::value::Value;
+use serde_json::{Map, Value as JsonValue};
+use std::collections::HashMap;
+use storage::table::{Column, ColumnType, TableSchema};
+
+// strings shorter than that get VARCHAR of default length, so that next documents have some room
+pub static DEFAULT_VARCHAR_LENGTH: usize = 255;
+
+// Type mapping rules:
+// string -> VARCHAR(max(255, length)), integer -> INT64 (UNSIGNED64 above i64 range),
+// float -> FLOAT64, bool -> BOOLEAN, null/array/object -> cannot be inferred.
+// Inferred columns are always nullable, so that they can be added to existing tables.
+pub fn infer_table_schema(
+    table_name: &str,
+    document: &JsonValue,
+    current_schema: Option<&TableSchema>,
+) -> Result<TableSchema, String> {
+    let fields = document
+        .as_object()
+        .ok_or("Document has to be a JSON object".to_string())?;
+
+    if !fields
+        .get("hash_key")
+        .is_some_and(|hash_key| hash_key.is_string())
+    {
+        return Err("Document has to contain string field 'hash_key'".to_string());
+    }
+    let sort_key = fields
+        .get("sort_key")
+        .ok_or("Document has to contain field 'sort_key'".to_string())?;
+
+    let mut table_schema = match current_schema {
+        Some(current_schema) => current_schema.clone(),
+        None => TableSchema::new(
+            table_name.to_string(),
+            infer_column_type(sort_key, "sort_key")?,
+        ),
+    };
+
+    let mut errors = Vec::new();
+    for (field_name, field_value) in fields {
+        if field_name == "hash_key" || field_name == "sort_key" {
+            continue;
+        }
+
+        match table_schema.columns.get_mut(field_name) {
+            Some(column) => {
+                if let Err(error) = extend_column_type(&mut column.column_type, field_value) {
+                    errors.push(format!("'{}': {}", field_name, error));
+                }
+            }
+            None => match infer_column_type(field_value, field_name) {
+                Ok(column_type) => {
+                    table_schema
+                        .columns
+                        .insert(field_name.clone(), Column::new(column_type, true));
+                }
+                Err(error) => errors.push(error),
+            },
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(format!("Cannot infer schema: {:?}", errors));
+    }
+
+    Ok(table_schema)
+}
+
+// returns hash key, sort key and values with missing columns set to null
+pub fn document_to_values(
+    document: &JsonValue,
+    table_schema: &TableSchema,
+) -> Result<(String, Value, HashMap<String, Value>), String> {
+    let fields = document
+        .as_object()
+        .ok_or("Document has to be a JSON object".to_string())?;
+
+    let hash_key = fields
+        .get("hash_key")
+        .and_then(|hash_key| hash_key.as_str())
+        .ok_or("Document has to contain string field 'hash_key'".to_string())?
+        .to_string();
+    let sort_key = json_to_value(
+        fields.get("sort_key").unwrap_or(&JsonValue::Null),
+        &table_schema.sort_key_type,
+    )?;
+
+    let mut values = HashMap::new();
+    for (column_name, column) in &table_schema.columns {
+        let value = json_to_value(
+            fields.get(column_name).unwrap_or(&JsonValue::Null),
+            &column.column_type,
+        )
+        .map_err(|error| format!("'{}': {}", column_name, error))?;
+        values.insert(column_name.clone(), value);
+    }
+
+    Ok((hash_key, sort_key, values))
+}
+
+// sort key given as JSON, bare words are taken as strings
+pub fn parse_sort_key(sort_key: &str, table_schema: &TableSchema) -> Result<Value, String> {
+    let json_value =
+        serde_json::from_str(sort_key).unwrap_or_else(|_| JsonValue::String(sort_key.to_string()));
+    json_to_value(&json_value, &table_schema.sort_key_type)
+}
+
+// inverse of document_to_values
+pub fn row_to_document(row: &DynamicRow) -> JsonValue {
+    let mut fields = Map::new();
+    fields.insert(
+        "hash_key".to_string(),
+        JsonValue::String(row.hash_key.clone()),
+    );
+    fields.insert("sort_key".to_string(), value_to_json(&row.sort_key));
+    for (column_name, value) in &row.values {
+        fields.insert(column_name.clone(), value_to_json(value));
+    }
+
+    JsonValue::Object(fields)
+}
+
+fn infer_column_type(json_value: &JsonValue, field_name: &str) -> Result<ColumnType, String> {
+    match json_value {
+        JsonValue::String(string) => Ok(ColumnType::Varchar(
+            DEFAULT_VARCHAR_LENGTH.max(string.len()),
+        )),
+        JsonValue::Number(number) if number.is_i64() => Ok(ColumnType::Int64),
+        JsonValue::Number(number) if number.is_u64() => Ok(ColumnType::Unsigned64),
+        JsonValue::Number(_) => Ok(ColumnType::Float64),
+        JsonValue::Bool(_) => Ok(ColumnType::Boolean),
+        JsonValue::Null => Err(format!("'{}': cannot infer type from null", field_name)),
+        _ => Err(format!(
+            "'{}': nested objects and arrays are not supported",
+            field_name
+        )),
+    }
+}
+
+fn extend_column_type(column_type: &mut ColumnType, json_value: &JsonValue) -> Result<(), String> {
+    if let (ColumnType::Varchar(max_length), JsonValue::String(string)) = (&column_type, json_value)
+    {
+        if string.len() > *max_length {
+            *column_type = ColumnType::Varchar(string.len());
+        }
+        return Ok(());
+    }
+
+    json_to_value(json_value, column_type).map(|_| ())
+}
+
+fn json_to_value(json_value: &JsonValue, column_type: &ColumnType) -> Result<Value, String> {
+    let value = match (column_type, json_value) {
+        (_, JsonValue::Null) => Some(Value::Null),
+        (ColumnType::Varchar(_), JsonValue::String(string)) => Some(Value::Varchar(string.clone())),
+        (ColumnType::Int32, JsonValue::Number(number)) => number
+            .as_i64()
+            .and_then(|number| i32::try_from(number).ok())
+            .map(Value::Int32),
+        (ColumnType::Int64, JsonValue::Number(number)) => number.as_i64().map(Value::Int64),
+        (ColumnType::Unsigned32, JsonValue::Number(number)) => number
+            .as_u64()
+            .and_then(|number| u32::try_from(number).ok())
+            .map(Value::Unsigned32),
+        (ColumnType::Unsigned64, JsonValue::Number(number)) => {
+            number.as_u64().map(Value::Unsigned64)
+        }
+        (ColumnType::Float32, JsonValue::Number(number)) => {
+            number.as_f64().map(|number| Value::Float32(number as f32))
+        }
+        (ColumnType::Float64, JsonValue::Number(number)) => number.as_f64().map(Value::Float64),
+        (ColumnType::Boolean, JsonValue::Bool(bool)) => Some(Value::Boolean(*bool)),
+        _ => None,
+    };
+
+    value.ok_or(format!("expected '{}', got '{}'", column_type, json_value))
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Varchar(value) => JsonValue::from(value.clone()),
+        Value::Int32(value) => JsonValue::from(*value),
+        Value::Int64(value) => JsonValue::from(*value),
+        Value::Unsigned32(value) => JsonValue::from(*value),
+        Value::Unsigned64(value) => JsonValue::from(*value),
+        Value::Float32(value) => JsonValue::from(*value),
+        Value::Float64(value) => JsonValue::from(*value),
+        Value::Boolean(value) => JsonValue::from(*value),
+        Value::Null => JsonValue::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn infer_schema_for_new_table() {
+        let document = json!({
+            "hash_key": "user",
+            "sort_key": 1,
+            "name": "John",
+            "score": 1.5,
+            "active": true,
+        });
+
+        let table_schema = infer_table_schema("users", &document, None).unwrap();
+        assert_eq!(
+            table_schema.to_string(),
+            "users>sort_key:INT64;active:BOOLEAN?;name:VARCHAR(255)?;score:FLOAT64?"
+        );
+
+        let (hash_key, sort_key, values) = document_to_values(&document, &table_schema).unwrap();
+        assert_eq!(hash_key, "user");
+        assert_eq!(sort_key, Value::Int64(1));
+        assert_eq!(values["name"], Value::Varchar("John".to_string()));
+    }
+
+    #[test]
+    fn infer_schema_extends_existing_table() {
+        let current_schema =
+            TableSchema::from_string("users>sort_key:INT64;age:INT32;name:VARCHAR(5)").unwrap();
+        let document = json!({
+            "hash_key": "user",
+            "sort_key": 1,
+            "name": "Johnathan",
+            "email": "john@example.com",
+        });
+
+        let table_schema = infer_table_schema("users", &document, Some(&current_schema)).unwrap();
+        assert_eq!(
+            table_schema.to_string(),
+            "users>sort_key:INT64;age:INT32;email:VARCHAR(255)?;name:VARCHAR(9)"
+        );
+
+        let invalid_document = json!({"hash_key": "user", "sort_key": 1, "age": "old"});
+        let error =
+            infer_table_schema("users", &invalid_document, Some(&current_schema)).unwrap_err();
+        assert_eq!(
+            error,
+            "Cannot infer schema: [\"'age': expected 'INT32', got '\\\"old\\\"'\"]"
+        );
+    }
+
+    #[test]
+    fn row_is_converted_back_to_document() {
+        let table_schema =
+            TableSchema::from_string("users>sort_key:INT64;email:VARCHAR(255)?;score:FLOAT64")
+                .unwrap();
+        let document = json!({"hash_key": "user", "sort_key": 1, "email": null, "score": 1.5});
+
+        let (hash_key, sort_key, values) = document_to_values(&document, &table_schema).unwrap();
+        let row = DynamicRow {
+            hash_key,
+            sort_key,
+            values,
+        };
+        assert_eq!(row_to_document(&row), document);
+
+        assert_eq!(parse_sort_key("1", &table_schema), Ok(Value::Int64(1)));
+        assert!(parse_sort_key("first", &table_schema).is_err());
+    }
+}
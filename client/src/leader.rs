@@ -0,0 +1,135 @@
+use crate::circuit_breaker::CircuitBreaker;
+use crate::connection::{parse_session_response, REQUEST_TIMEOUT};
+use crate::error::ConnectionError;
+use crate::multiplexer::MultiplexedStream;
+use std::collections::HashMap;
+use std::net::{SocketAddr, SocketAddrV4};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, RwLock};
+use tokio::time::timeout;
+
+// socket of every thread of the server, by thread
+pub(crate) async fn connect_streams(
+    address: SocketAddrV4,
+    database: &str,
+) -> Result<HashMap<usize, Arc<MultiplexedStream>>, ConnectionError> {
+    let stream = MultiplexedStream::connect(address, database).await?;
+    let num_of_threads = stream.num_of_threads;
+
+    let mut streams = HashMap::from([(0, Arc::new(stream))]);
+
+    let starting_port = address.port();
+    let last_port = starting_port + num_of_threads as u16;
+    for (thread, port) in (starting_port..last_port).enumerate().skip(1) {
+        let new_address = SocketAddrV4::new(*address.ip(), port);
+        let stream = MultiplexedStream::connect(new_address, database).await?;
+        streams.insert(thread, Arc::new(stream));
+    }
+    Ok(streams)
+}
+
+// server leading the one connection was opened to, it runs the same threads and partitions
+pub(crate) struct LeaderNode {
+    pub(crate) address: SocketAddrV4,
+    pub(crate) streams: HashMap<usize, Arc<MultiplexedStream>>,
+    pub(crate) circuit_breakers: HashMap<usize, Arc<StdMutex<CircuitBreaker>>>,
+}
+
+// writes go to the leader server points at, reads as well unless they may be served by followers.
+// Shared with requests in flight, so that they follow the leader when it changes
+#[derive(Default)]
+pub(crate) struct LeaderRouting {
+    leader: RwLock<Option<Arc<LeaderNode>>>,
+    pub(crate) read_from_followers: AtomicBool,
+    // set on sockets of leaders connected later as well
+    session_request: StdMutex<Option<Vec<u8>>>,
+}
+
+impl LeaderRouting {
+    // none if request goes to the server connection was opened to
+    pub(crate) fn node(&self, follower_read: bool) -> Option<Arc<LeaderNode>> {
+        if follower_read && self.read_from_followers.load(Ordering::Relaxed) {
+            return None;
+        }
+        self.leader.read().unwrap().clone()
+    }
+
+    pub(crate) fn set_session_request(&self, session_request: Vec<u8>) {
+        *self.session_request.lock().unwrap() = Some(session_request);
+    }
+
+    // leader is at "host:port" of its first thread, empty hint means server connection was opened
+    // to leads itself
+    pub(crate) async fn follow(
+        &self,
+        hint: &str,
+        own_address: SocketAddrV4,
+        database: &str,
+        num_of_threads: usize,
+    ) -> Result<Option<Arc<LeaderNode>>, ConnectionError> {
+        let address = match hint {
+            "" => own_address,
+            hint => resolve(hint).await?,
+        };
+        if address == own_address {
+            *self.leader.write().unwrap() = None;
+            return Ok(None);
+        }
+        let current = self.leader.read().unwrap().clone();
+        if let Some(leader) = current.filter(|leader| leader.address == address) {
+            return Ok(Some(leader));
+        }
+
+        let streams = connect_streams(address, database).await?;
+        if streams.len() != num_of_threads {
+            return Err(ConnectionError::decode(format!(
+                "Leader at {} runs {} threads, server connection was opened to runs {}",
+                address,
+                streams.len(),
+                num_of_threads
+            )));
+        }
+        let session_request = self.session_request.lock().unwrap().clone();
+        if let Some(session_request) = session_request {
+            for stream in streams.values() {
+                let proto_response =
+                    timeout(REQUEST_TIMEOUT, stream.set_session(session_request.clone()))
+                        .await
+                        .unwrap_or(Err(ConnectionError::Timeout(REQUEST_TIMEOUT)))?;
+                parse_session_response(proto_response)?;
+            }
+        }
+
+        let leader = Arc::new(LeaderNode {
+            address,
+            circuit_breakers: streams
+                .keys()
+                .map(|thread| (*thread, Arc::new(StdMutex::new(CircuitBreaker::new()))))
+                .collect(),
+            streams,
+        });
+        *self.leader.write().unwrap() = Some(leader.clone());
+        Ok(Some(leader))
+    }
+
+    // requests go to the server connection was opened to again, which names the new leader
+    pub(crate) fn forget(&self, address: SocketAddrV4) {
+        let mut leader = self.leader.write().unwrap();
+        if leader
+            .as_ref()
+            .is_some_and(|leader| leader.address == address)
+        {
+            *leader = None;
+        }
+    }
+}
+
+async fn resolve(hint: &str) -> Result<SocketAddrV4, ConnectionError> {
+    tokio::net::lookup_host(hint)
+        .await?
+        .find_map(|address| match address {
+            SocketAddr::V4(address) => Some(address),
+            SocketAddr::V6(_) => None,
+        })
+        .ok_or_else(|| ConnectionError::decode(format!("Leader '{}' has no IPv4 address", hint)))
+}
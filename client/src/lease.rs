@@ -0,0 +1,11 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Lease {
+    pub name: String,
+    pub table_name: String,
+    // fencing token, pass it along with writes guarded by the lease
+    pub version: u64,
+    pub expires_at: u128,
+    pub ttl: Duration,
+}
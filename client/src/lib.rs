@@ -1,9 +1,40 @@
 mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod chunking;
+mod circuit_breaker;
+mod column_ordinals;
 mod connection;
 mod connection_util;
+mod dynamic_row;
+mod error;
+mod hedging;
+pub mod inference;
+mod leader;
+mod lease;
 mod model;
+mod multiplexer;
 pub mod pool;
+mod prepared;
+pub mod probe;
+mod registry;
+mod schema_cache;
+#[cfg(feature = "schema-check")]
+mod schema_check;
+mod session;
+mod template;
 mod transaction;
 
-pub use connection::Connection;
-pub use model::Model;
+pub use chunking::ChunkingPolicy;
+pub use connection::{
+    Connection, DatabaseDescription, PartitionLoad, PartitionRows, ServerDescription, TableAccess,
+};
+pub use dynamic_row::DynamicRow;
+pub use error::ConnectionError;
+pub use hedging::HedgingPolicy;
+pub use lease::Lease;
+pub use model::{Model, VersionedModel};
+pub use prepared::PreparedRequest;
+pub use session::{Consistency, ConsistencyToken, Session};
+pub use template::WriteTemplate;
+pub use transaction::ActiveTransaction;
@@ -9,3 +9,205 @@ pub trait Model {
     fn table_name() -> String;
     fn table_schema() -> TableSchema;
 }
+
+// model with a #[version] field, it holds version of row the model was read from, 0 or None
+// for models not stored yet, save() writes it only if the row still has that version
+pub trait VersionedModel: Model {
+    fn version(&self) -> u64;
+    fn set_version(&mut self, version: u64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::value::Value::*;
+    use macros::DatabaseModel;
+    use protos::util::{
+        parse_message_field_from_value, parse_proto_from_value, parse_value_from_message_field,
+        parse_value_from_proto,
+    };
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use storage::table::ColumnType;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+        zip_codes: Vec<u32>,
+    }
+
+    #[derive(DatabaseModel)]
+    struct Customer {
+        hash_key: String,
+        sort_key: i32,
+        #[column(serde_json, max_length = 4096)]
+        address: Address,
+        #[column(serde_json)]
+        tags: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn serde_json_fields_are_stored_as_varchar() {
+        let table_schema = Customer::table_schema();
+        assert_eq!(
+            table_schema.columns["address"].column_type,
+            ColumnType::Varchar(4096)
+        );
+        assert!(!table_schema.columns["address"].nullable);
+        assert_eq!(
+            table_schema.columns["tags"].column_type,
+            ColumnType::Varchar(1024)
+        );
+        assert!(table_schema.columns["tags"].nullable);
+
+        let customer = Customer {
+            hash_key: "customer_1".to_string(),
+            sort_key: 1,
+            address: Address {
+                city: "Gdansk".to_string(),
+                zip_codes: vec![80001, 80002],
+            },
+            tags: None,
+        };
+        let insert_request = customer.to_insert_request();
+        assert_eq!(
+            parse_value_from_proto(insert_request.values["address"].clone()),
+            Varchar(r#"{"city":"Gdansk","zip_codes":[80001,80002]}"#.to_string())
+        );
+        assert_eq!(
+            parse_value_from_proto(insert_request.values["tags"].clone()),
+            Null
+        );
+
+        let mut get_response = GetResponse::new();
+        get_response.hash_key = insert_request.hash_key;
+        get_response.sort_key = insert_request.sort_key;
+        get_response.values = insert_request.values;
+        get_response.values.insert(
+            "tags".to_string(),
+            parse_proto_from_value(Varchar(r#"["vip"]"#.to_string())),
+        );
+        let read_customer = Customer::from_get_response(get_response);
+        assert_eq!(read_customer.address, customer.address);
+        assert_eq!(read_customer.tags, Some(vec!["vip".to_string()]));
+    }
+
+    #[derive(DatabaseModel, Debug)]
+    struct Order {
+        hash_key: String,
+        sort_key: String,
+        amount: i64,
+        #[column(trim, truncate)]
+        note: Option<String>,
+    }
+
+    #[test]
+    fn builder_requires_fields_and_update_changes_only_set_ones() {
+        let error = Order::builder()
+            .hash_key("customer_1".to_string())
+            .build()
+            .unwrap_err();
+        assert_eq!(error, "Missing required fields: sort_key, amount");
+        assert_eq!(
+            Order::table_schema().to_string(),
+            "order>sort_key:VARCHAR(1024);amount:INT64;note:VARCHAR(1000)?\
+             #transform=note:trim,transform=note:truncate"
+        );
+
+        let mut order = Order::builder()
+            .hash_key("customer_1".to_string())
+            .sort_key("order_1".to_string())
+            .amount(100)
+            .build()
+            .unwrap();
+        assert_eq!(order.note, None);
+
+        let update = OrderUpdate {
+            note: Some(Some("gift".to_string())),
+            ..OrderUpdate::default()
+        };
+        let values = update.values();
+        assert_eq!(values.len(), 1);
+        assert_eq!(
+            parse_value_from_proto(values["note"].clone()),
+            Varchar("gift".to_string())
+        );
+
+        update.apply(&mut order);
+        assert_eq!(order.amount, 100);
+        assert_eq!(order.note, Some("gift".to_string()));
+    }
+
+    #[derive(DatabaseModel)]
+    #[ttl(field = "expires_at")]
+    struct LoginToken {
+        hash_key: String,
+        sort_key: i32,
+        // millis since epoch
+        expires_at: Option<i64>,
+    }
+
+    #[test]
+    fn ttl_field_expires_rows() {
+        let table_schema = LoginToken::table_schema();
+        assert_eq!(
+            table_schema.to_string(),
+            "logintoken>sort_key:INT32;expires_at:INT64?#ttl_column=expires_at"
+        );
+
+        let token = LoginToken {
+            hash_key: "user_1".to_string(),
+            sort_key: 1,
+            expires_at: Some(1_000),
+        };
+        let insert_request = token.to_insert_request();
+        let row = storage::Row::new(
+            insert_request.hash_key,
+            parse_value_from_message_field(insert_request.sort_key),
+            insert_request
+                .values
+                .into_iter()
+                .map(|(name, value)| (name, parse_value_from_proto(value)))
+                .collect(),
+        );
+        assert!(table_schema.options.is_row_expired(&row, 1_000));
+        assert!(!table_schema.options.is_row_expired(&row, 999));
+    }
+
+    #[derive(DatabaseModel)]
+    struct Account {
+        hash_key: String,
+        sort_key: i32,
+        balance: i64,
+        #[version]
+        version: u64,
+    }
+
+    #[test]
+    fn version_field_is_not_a_column() {
+        assert_eq!(
+            Account::table_schema().to_string(),
+            "account>sort_key:INT32;balance:INT64"
+        );
+
+        let mut account = Account::builder()
+            .hash_key("user_1".to_string())
+            .sort_key(1)
+            .balance(100)
+            .build()
+            .unwrap();
+        assert_eq!(account.version(), 0);
+        let insert_request = account.to_insert_request();
+        assert_eq!(insert_request.values.len(), 1);
+
+        let mut get_response = GetResponse::new();
+        get_response.hash_key = insert_request.hash_key;
+        get_response.sort_key = insert_request.sort_key;
+        get_response.values = insert_request.values;
+        get_response.version = 3;
+        account = Account::from_get_response(get_response);
+        assert_eq!(account.version(), 3);
+        account.set_version(4);
+        assert_eq!(account.version, 4);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::error::ConnectionError;
+use protobuf::Message;
+use protos::{ProtoRequest, ProtoResponse};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::SocketAddrV4;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, Weak};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::{oneshot, Mutex};
+
+type ResponseSender = oneshot::Sender<Result<ProtoResponse, protobuf::Error>>;
+// correlation id -> generation of socket the request was written to and its waiter
+type PendingResponses = Arc<StdMutex<HashMap<u64, (u64, ResponseSender)>>>;
+
+struct Writer {
+    write_half: OwnedWriteHalf,
+    generation: u64,
+}
+
+// every request to a server thread goes through one socket, server handles them concurrently and
+// responses are matched to requests by correlation id
+pub(crate) struct MultiplexedStream {
+    pub(crate) address: SocketAddrV4,
+    pub(crate) num_of_threads: u32,
+    // None once socket broke, next request reconnects
+    writer: Arc<Mutex<Option<Writer>>>,
+    pending: PendingResponses,
+    next_correlation_id: AtomicU64,
+    next_generation: AtomicU64,
+    // server keeps session per socket, so it is sent again whenever socket is reopened
+    session_request: StdMutex<Option<Vec<u8>>>,
+    // request with only database set, appended to every request so that it overrides the field
+    database_suffix: Vec<u8>,
+}
+
+impl MultiplexedStream {
+    pub(crate) async fn connect(
+        address: SocketAddrV4,
+        database: &str,
+    ) -> Result<MultiplexedStream, ConnectionError> {
+        let mut database_request = ProtoRequest::new();
+        database_request.database = database.to_string();
+
+        let multiplexed_stream = MultiplexedStream {
+            address,
+            num_of_threads: 0,
+            writer: Arc::new(Mutex::new(None)),
+            pending: Arc::new(StdMutex::new(HashMap::new())),
+            next_correlation_id: AtomicU64::new(0),
+            next_generation: AtomicU64::new(0),
+            session_request: StdMutex::new(None),
+            database_suffix: database_request.write_to_bytes().unwrap(),
+        };
+
+        let (writer, num_of_threads) = multiplexed_stream.open_socket().await?;
+        *multiplexed_stream.writer.lock().await = Some(writer);
+
+        Ok(MultiplexedStream {
+            num_of_threads,
+            ..multiplexed_stream
+        })
+    }
+
+    pub(crate) async fn send(
+        &self,
+        mut request_bytes: Vec<u8>,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        request_bytes.extend_from_slice(&self.database_suffix);
+        #[cfg(feature = "opentelemetry")]
+        request_bytes.extend_from_slice(&trace_context_suffix());
+        let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = oneshot::channel();
+        // late response to a request given up on is discarded by the reader
+        let _pending_guard = PendingGuard {
+            pending: &self.pending,
+            correlation_id,
+        };
+        {
+            let mut writer_guard = self.writer.lock().await;
+            // writer is taken out for the write, so that a write cancelled halfway drops the socket
+            let mut writer = match writer_guard.take() {
+                Some(writer) => writer,
+                None => self.open_socket().await?.0,
+            };
+
+            self.pending
+                .lock()
+                .unwrap()
+                .insert(correlation_id, (writer.generation, sender));
+            if let Err(error) =
+                write_frame(&mut writer.write_half, correlation_id, &request_bytes).await
+            {
+                return Err(error.into());
+            }
+            *writer_guard = Some(writer);
+        }
+
+        match receiver.await {
+            Ok(result) => Ok(result?),
+            Err(_) => Err(ConnectionError::Io(std::io::Error::new(
+                ErrorKind::ConnectionAborted,
+                "Connection closed before response arrived",
+            ))),
+        }
+    }
+
+    pub(crate) async fn set_session(
+        &self,
+        request_bytes: Vec<u8>,
+    ) -> Result<ProtoResponse, ConnectionError> {
+        *self.session_request.lock().unwrap() = Some(request_bytes.clone());
+        self.send(request_bytes).await
+    }
+
+    // drops current socket, requests pending on it fail and next request reconnects
+    pub(crate) async fn reset(&self) {
+        *self.writer.lock().await = None;
+    }
+
+    async fn open_socket(&self) -> Result<(Writer, u32), ConnectionError> {
+        let mut stream = TcpStream::connect(self.address).await?;
+        let num_of_threads = stream.read_u32().await?;
+
+        let (read_half, mut write_half) = stream.into_split();
+        // response to restored session has no waiter and is discarded by the reader
+        let session_request = self.session_request.lock().unwrap().clone();
+        if let Some(mut session_request) = session_request {
+            session_request.extend_from_slice(&self.database_suffix);
+            let correlation_id = self.next_correlation_id.fetch_add(1, Ordering::Relaxed);
+            write_frame(&mut write_half, correlation_id, &session_request).await?;
+        }
+
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        tokio::spawn(read_responses(
+            read_half,
+            generation,
+            self.pending.clone(),
+            Arc::downgrade(&self.writer),
+        ));
+
+        Ok((
+            Writer {
+                write_half,
+                generation,
+            },
+            num_of_threads,
+        ))
+    }
+}
+
+// request with only trace context of the current span set, appended like database suffix, the
+// application sets propagator and tracing layer, without them nothing is appended
+#[cfg(feature = "opentelemetry")]
+fn trace_context_suffix() -> Vec<u8> {
+    use opentelemetry::global;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let context = tracing::Span::current().context();
+    let mut trace_request = ProtoRequest::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut trace_request.trace_context)
+    });
+    trace_request.write_to_bytes().unwrap()
+}
+
+struct PendingGuard<'a> {
+    pending: &'a PendingResponses,
+    correlation_id: u64,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.pending.lock().unwrap().remove(&self.correlation_id);
+    }
+}
+
+// frame is correlation id, size of request and request itself
+async fn write_frame(
+    write_half: &mut OwnedWriteHalf,
+    correlation_id: u64,
+    request_bytes: &[u8],
+) -> std::io::Result<()> {
+    let mut frame = Vec::with_capacity(12 + request_bytes.len());
+    frame.extend_from_slice(&correlation_id.to_be_bytes());
+    frame.extend_from_slice(&(request_bytes.len() as u32).to_be_bytes());
+    frame.extend_from_slice(request_bytes);
+    write_half.write_all(&frame).await
+}
+
+// writer is held weakly, so that dropping the stream closes the socket and ends the reader
+async fn read_responses(
+    mut read_half: OwnedReadHalf,
+    generation: u64,
+    pending: PendingResponses,
+    writer: Weak<Mutex<Option<Writer>>>,
+) {
+    while let Ok((correlation_id, response_bytes)) = read_frame(&mut read_half).await {
+        let response_sender = pending.lock().unwrap().remove(&correlation_id);
+        if let Some((_, response_sender)) = response_sender {
+            let _ = response_sender.send(ProtoResponse::parse_from_bytes(&response_bytes));
+        }
+    }
+
+    if let Some(writer) = writer.upgrade() {
+        let mut writer = writer.lock().await;
+        if writer
+            .as_ref()
+            .is_some_and(|writer| writer.generation == generation)
+        {
+            *writer = None;
+        }
+    }
+    // dropping senders fails requests still waiting on that socket
+    pending
+        .lock()
+        .unwrap()
+        .retain(|_, (request_generation, _)| *request_generation != generation);
+}
+
+async fn read_frame(read_half: &mut OwnedReadHalf) -> std::io::Result<(u64, Vec<u8>)> {
+    let correlation_id = read_half.read_u64().await?;
+    let response_size = read_half.read_u32().await?;
+    let mut buffer = vec![0u8; response_size as usize];
+    read_half.read_exact(&mut buffer).await?;
+
+    Ok((correlation_id, buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protos::{ProtoResponseData, SequenceResponse};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn responses_are_matched_by_correlation_id() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = match listener.local_addr().unwrap() {
+            std::net::SocketAddr::V4(address) => address,
+            _ => unreachable!(),
+        };
+
+        // answers two requests in reverse order, echoing request size as sequence value
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_u32(1).await.unwrap();
+
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let correlation_id = stream.read_u64().await.unwrap();
+                let size = stream.read_u32().await.unwrap();
+                let mut buffer = vec![0u8; size as usize];
+                stream.read_exact(&mut buffer).await.unwrap();
+                requests.push((correlation_id, size));
+            }
+
+            for (correlation_id, size) in requests.into_iter().rev() {
+                let mut sequence_response = SequenceResponse::new();
+                sequence_response.value = size as u64;
+                let mut proto_response = ProtoResponse::new();
+                proto_response.data = Some(ProtoResponseData::Sequence(sequence_response));
+                let response_bytes = proto_response.write_to_bytes().unwrap();
+
+                stream.write_u64(correlation_id).await.unwrap();
+                stream.write_u32(response_bytes.len() as u32).await.unwrap();
+                stream.write_all(&response_bytes).await.unwrap();
+            }
+        });
+
+        let stream = MultiplexedStream::connect(address, "").await.unwrap();
+        assert_eq!(stream.num_of_threads, 1);
+
+        let (first, second) = tokio::join!(stream.send(vec![0; 3]), stream.send(vec![0; 5]));
+        let sequence_value = |proto_response: ProtoResponse| match proto_response.data {
+            Some(ProtoResponseData::Sequence(sequence_response)) => sequence_response.value,
+            _ => panic!("Invalid proto response type"),
+        };
+        assert_eq!(sequence_value(first.unwrap()), 3);
+        assert_eq!(sequence_value(second.unwrap()), 5);
+    }
+
+    #[tokio::test]
+    async fn session_is_restored_on_new_socket() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = match listener.local_addr().unwrap() {
+            std::net::SocketAddr::V4(address) => address,
+            _ => unreachable!(),
+        };
+
+        // reports size of first request received on each socket
+        let (first_sizes_sender, mut first_sizes) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                stream.write_u32(1).await.unwrap();
+                let first_sizes_sender = first_sizes_sender.clone();
+                tokio::spawn(async move {
+                    let mut first = true;
+                    while let Ok(correlation_id) = stream.read_u64().await {
+                        let size = stream.read_u32().await.unwrap();
+                        let mut buffer = vec![0u8; size as usize];
+                        stream.read_exact(&mut buffer).await.unwrap();
+                        if first {
+                            first_sizes_sender.send(size).unwrap();
+                            first = false;
+                        }
+
+                        let response_bytes = ProtoResponse::new().write_to_bytes().unwrap();
+                        stream.write_u64(correlation_id).await.unwrap();
+                        stream.write_u32(response_bytes.len() as u32).await.unwrap();
+                        stream.write_all(&response_bytes).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        let stream = MultiplexedStream::connect(address, "").await.unwrap();
+        stream.set_session(vec![0; 7]).await.unwrap();
+        assert_eq!(first_sizes.recv().await, Some(7));
+
+        stream.reset().await;
+        stream.send(vec![0; 3]).await.unwrap();
+        assert_eq!(first_sizes.recv().await, Some(7));
+    }
+}
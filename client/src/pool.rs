@@ -1,15 +1,15 @@
-use crate::connection::{Connection, ConnectionError, ConnectionInner};
-use std::collections::VecDeque;
+use crate::connection::{Connection, ConnectionInner};
+use crate::error::ConnectionError;
 use std::net::SocketAddrV4;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::Mutex as TokioMutex;
 use tokio::sync::Semaphore;
-use tokio::task::JoinSet;
 use tokio::time::sleep;
 
+// connections handed out share one socket per server thread and table schemas described by any of
+// them, pool only limits how many are leased at once
 pub struct ConnectionPool {
-    connections: Mutex<VecDeque<Connection>>,
+    inner: Arc<ConnectionInner>,
     acquire_timeout: Duration,
     pub semaphore: Semaphore,
 }
@@ -20,56 +20,29 @@ impl ConnectionPool {
         pool_size: usize,
         timeout: Duration,
     ) -> Result<Arc<ConnectionPool>, ConnectionError> {
-        let pool = Arc::new(ConnectionPool {
-            connections: Mutex::new(VecDeque::with_capacity(pool_size)),
+        Ok(Arc::new(ConnectionPool {
+            inner: Arc::new(ConnectionInner::new(addr, "").await?),
             acquire_timeout: timeout,
             semaphore: Semaphore::new(pool_size),
-        });
-
-        let mut join_set = JoinSet::new();
-        for _ in 0..pool_size {
-            join_set.spawn(ConnectionInner::new(addr));
-        }
-
-        let mut connections = VecDeque::with_capacity(pool_size);
-        while let Some(Ok(session)) = join_set.join_next().await {
-            connections.push_back(session?);
-        }
-
-        {
-            let mut pool_connections = pool.connections.lock().unwrap();
-            for connection in connections {
-                pool_connections.push_back(Connection::new_for_pool(
-                    Arc::new(TokioMutex::new(connection)),
-                    Arc::downgrade(&pool),
-                ));
-            }
-        }
-
-        Ok(pool)
+        }))
     }
 
-    pub async fn acquire(&self) -> Result<Connection, String> {
+    pub async fn acquire(self: &Arc<Self>) -> Result<Connection, ConnectionError> {
         let permit = tokio::select! {
-            _ = sleep(self.acquire_timeout) => Err("Connection pool acquire timeout expired".to_string()),
+            _ = sleep(self.acquire_timeout) => Err(ConnectionError::PoolExhausted),
             permit = self.semaphore.acquire() => {
-                permit.map_err(|e| e.to_string())
+                permit.map_err(|_| ConnectionError::PoolExhausted)
             }
         }?;
 
-        let mut connections = self.connections.lock().unwrap();
-        let connection = connections.pop_front().unwrap();
-
         permit.forget();
-        Ok(connection)
+        Ok(Connection::new_for_pool(
+            self.inner.clone(),
+            Arc::downgrade(self),
+        ))
     }
 
-    pub(crate) fn put_back(self: &Arc<Self>, connection_inner: Arc<TokioMutex<ConnectionInner>>) {
-        let mut connections = self.connections.lock().unwrap();
-        connections.push_back(Connection::new_for_pool(
-            connection_inner,
-            Arc::downgrade(self),
-        ));
+    pub(crate) fn put_back(&self) {
         self.semaphore.add_permits(1);
     }
 }
@@ -0,0 +1,227 @@
+use crate::connection_util::{create_delete_request, create_get_request, set_transaction};
+use crate::transaction::TransactionScope;
+use crate::Model;
+use common::value::Value;
+use protobuf::{CodedOutputStream, Message, MessageFull};
+use protos::{InsertRequest, ProtoRequest};
+use std::marker::PhantomData;
+
+// field numbers of the Request.data oneof in request.proto
+static GET_FIELD_NUMBER: u32 = 1;
+static INSERT_FIELD_NUMBER: u32 = 2;
+static DELETE_FIELD_NUMBER: u32 = 3;
+
+// everything requests of a model share is worked out once, schema of the model included, so that
+// hot loops only encode keys and values of every request
+pub struct PreparedRequest<T: Model> {
+    pub(crate) table_name: String,
+    pub(crate) schema_version: u64,
+    // column names in order of model's schema, values of inserts are sent by their ordinal
+    columns: Vec<String>,
+    // encoded fields shared by every request, protobuf allows appending them after the data field
+    skeleton: Vec<u8>,
+    // same without schema version, for inserts sent by column name once server schema differs
+    by_name_skeleton: Vec<u8>,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T: Model> PreparedRequest<T> {
+    pub(crate) fn new() -> PreparedRequest<T> {
+        let table_schema = T::table_schema();
+        let mut proto_request = ProtoRequest::new();
+        proto_request.table = T::table_name();
+        let by_name_skeleton = proto_request.write_to_bytes().unwrap();
+        proto_request.schema_version = table_schema.schema_version();
+
+        PreparedRequest {
+            table_name: proto_request.table.clone(),
+            schema_version: proto_request.schema_version,
+            columns: table_schema.columns.into_keys().collect(),
+            skeleton: proto_request.write_to_bytes().unwrap(),
+            by_name_skeleton,
+            _phantom_data: PhantomData,
+        }
+    }
+
+    pub(crate) fn get_request_bytes(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        transaction: Option<TransactionScope>,
+    ) -> Vec<u8> {
+        self.encode(
+            GET_FIELD_NUMBER,
+            &create_get_request(hash_key, sort_key),
+            &self.skeleton,
+            transaction,
+        )
+    }
+
+    pub(crate) fn insert_request_bytes(
+        &self,
+        mut insert_request: InsertRequest,
+        by_name: bool,
+        transaction: Option<TransactionScope>,
+    ) -> Vec<u8> {
+        if by_name {
+            return self.encode(
+                INSERT_FIELD_NUMBER,
+                &insert_request,
+                &self.by_name_skeleton,
+                transaction,
+            );
+        }
+
+        let mut values = std::mem::take(&mut insert_request.values);
+        insert_request.column_values = self
+            .columns
+            .iter()
+            .map(|name| values.remove(name).unwrap_or_default())
+            .collect();
+        self.encode(
+            INSERT_FIELD_NUMBER,
+            &insert_request,
+            &self.skeleton,
+            transaction,
+        )
+    }
+
+    pub(crate) fn delete_request_bytes(
+        &self,
+        hash_key: String,
+        sort_key: Value,
+        transaction: Option<TransactionScope>,
+    ) -> Vec<u8> {
+        self.encode(
+            DELETE_FIELD_NUMBER,
+            &create_delete_request(hash_key, sort_key),
+            &self.skeleton,
+            transaction,
+        )
+    }
+
+    fn encode<M: MessageFull>(
+        &self,
+        field_number: u32,
+        message: &M,
+        skeleton: &[u8],
+        transaction: Option<TransactionScope>,
+    ) -> Vec<u8> {
+        let mut request_bytes = Vec::new();
+        {
+            let mut output_stream = CodedOutputStream::vec(&mut request_bytes);
+            output_stream.write_message(field_number, message).unwrap();
+            output_stream.flush().unwrap();
+        }
+        request_bytes.extend_from_slice(skeleton);
+        // transaction may be begun by the request, so its fields differ from request to request
+        if transaction.is_some() {
+            let mut transaction_request = ProtoRequest::new();
+            set_transaction(&mut transaction_request, transaction);
+            request_bytes.extend_from_slice(&transaction_request.write_to_bytes().unwrap());
+        }
+
+        request_bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as client;
+    use crate::column_ordinals::insert_by_ordinal;
+    use client::Model;
+    use common::value::Value::*;
+    use macros::DatabaseModel;
+    use protos::util::{
+        parse_message_field_from_value, parse_proto_from_value, parse_value_from_message_field,
+        parse_value_from_proto,
+    };
+    use protos::{DeleteRequest, GetResponse, ProtoRequestData};
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use storage::table::TableSchema;
+
+    static SCHEMAS_BUILT: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(DatabaseModel)]
+    struct Account {
+        hash_key: String,
+        sort_key: i32,
+        name: String,
+    }
+
+    // counts schemas worked out, which every insert sent without preparing it does
+    struct CountedAccount(Account);
+
+    impl Model for CountedAccount {
+        fn from_get_response(get_response: GetResponse) -> CountedAccount {
+            CountedAccount(Account::from_get_response(get_response))
+        }
+
+        fn to_insert_request(&self) -> InsertRequest {
+            self.0.to_insert_request()
+        }
+
+        fn to_delete_request(&self) -> DeleteRequest {
+            self.0.to_delete_request()
+        }
+
+        fn hash_key(&self) -> String {
+            self.0.hash_key()
+        }
+
+        fn table_name() -> String {
+            Account::table_name()
+        }
+
+        fn table_schema() -> TableSchema {
+            SCHEMAS_BUILT.fetch_add(1, Ordering::SeqCst);
+            Account::table_schema()
+        }
+    }
+
+    #[test]
+    fn prepared_inserts_match_model_inserts_without_working_out_schema() {
+        let prepared_request = PreparedRequest::<CountedAccount>::new();
+        let transaction = TransactionScope {
+            id: 7,
+            begins: true,
+        };
+        let accounts: Vec<_> = (0..100)
+            .map(|number| {
+                CountedAccount(Account {
+                    hash_key: format!("account_{}", number),
+                    sort_key: -1,
+                    name: "ann".to_string(),
+                })
+            })
+            .collect();
+        let requests_bytes: Vec<_> = accounts
+            .iter()
+            .map(|account| {
+                prepared_request.insert_request_bytes(
+                    account.to_insert_request(),
+                    false,
+                    Some(transaction),
+                )
+            })
+            .collect();
+        assert_eq!(SCHEMAS_BUILT.load(Ordering::SeqCst), 1);
+
+        for (account, request_bytes) in accounts.iter().zip(requests_bytes) {
+            let table_schema = CountedAccount::table_schema();
+            let mut insert_request = account.to_insert_request();
+            insert_by_ordinal(&mut insert_request, &table_schema);
+            let mut request = ProtoRequest::new();
+            request.table = CountedAccount::table_name();
+            request.schema_version = table_schema.schema_version();
+            set_transaction(&mut request, Some(transaction));
+            request.data = Some(ProtoRequestData::Insert(insert_request));
+            assert_eq!(
+                ProtoRequest::parse_from_bytes(&request_bytes).unwrap(),
+                request
+            );
+        }
+    }
+}
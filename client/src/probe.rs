@@ -0,0 +1,48 @@
+use crate::connection::send_request;
+use crate::error::ConnectionError;
+use crate::multiplexer::MultiplexedStream;
+use protos::{
+    ErrorCode, HealthRequest, ProtoRequest, ProtoRequestData, ProtoResponseData, ReadyRequest,
+};
+use std::net::SocketAddrV4;
+
+// probes talk to a single thread only, so that they stay cheap for orchestrators,
+// healthy server may still report background tasks failing on some tables
+pub async fn check_health(address: SocketAddrV4) -> Result<Vec<String>, ConnectionError> {
+    let mut request = ProtoRequest::new();
+    request.data = Some(ProtoRequestData::Health(HealthRequest::new()));
+
+    match send_probe(address, request).await? {
+        ProtoResponseData::Health(health_response) => Ok(health_response.degraded),
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+        _ => panic!("Invalid proto response type"),
+    }
+}
+
+pub async fn check_ready(address: SocketAddrV4) -> Result<(), ConnectionError> {
+    let mut request = ProtoRequest::new();
+    request.data = Some(ProtoRequestData::Ready(ReadyRequest::new()));
+
+    match send_probe(address, request).await? {
+        ProtoResponseData::Ready(ready_response) => match ready_response.ready {
+            true => Ok(()),
+            false => Err(ConnectionError::Server {
+                code: ErrorCode::UNAVAILABLE,
+                detail: ready_response.detail,
+                retryable: true,
+                retry_after: None,
+            }),
+        },
+        ProtoResponseData::ServerError(server_error) => Err(server_error.into()),
+        _ => panic!("Invalid proto response type"),
+    }
+}
+
+async fn send_probe(
+    address: SocketAddrV4,
+    request: ProtoRequest,
+) -> Result<ProtoResponseData, ConnectionError> {
+    let stream = MultiplexedStream::connect(address, "").await?;
+    let proto_response = send_request(&stream, request).await?;
+    Ok(proto_response.data.unwrap())
+}
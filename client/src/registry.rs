@@ -0,0 +1,14 @@
+/// Generates `sync_models(&Connection)`, which syncs every listed model in a single request,
+/// either all of them are synced or none, with all schema incompatibilities reported at once.
+#[macro_export]
+macro_rules! register_models {
+    ($($model:ty),+ $(,)?) => {
+        pub async fn sync_models(
+            connection: &$crate::Connection,
+        ) -> Result<(), $crate::ConnectionError> {
+            connection
+                .sync_schemas(vec![$(<$model as $crate::Model>::table_schema()),+])
+                .await
+        }
+    };
+}
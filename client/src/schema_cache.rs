@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+use std::net::SocketAddrV4;
+use std::sync::{Arc, Mutex};
+use storage::table::TableSchema;
+
+// described schemas are shared by every connection of the process, frameworks tend to open
+// a connection per request or per worker and would otherwise describe the same tables over again
+static SCHEMA_CACHE: Mutex<BTreeMap<SchemaCacheKey, CachedSchema>> = Mutex::new(BTreeMap::new());
+// held while table is described, so that connections of a pool missing the cache at once,
+// e.g. on startup, wait for a single describe instead of sending their own
+static DESCRIBE_LOCKS: Mutex<BTreeMap<SchemaCacheKey, Arc<tokio::sync::Mutex<()>>>> =
+    Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct SchemaCacheKey {
+    pub address: SocketAddrV4,
+    pub database: String,
+    pub table: String,
+}
+
+// version is bumped by every invalidation, so that describe which was in flight meanwhile
+// does not put back schema from before DDL
+#[derive(Debug, Default)]
+struct CachedSchema {
+    version: u64,
+    table_schema: Option<TableSchema>,
+}
+
+// cached schema, or version to pass to insert once schema is described
+pub(crate) fn get(key: &SchemaCacheKey) -> Result<TableSchema, u64> {
+    match SCHEMA_CACHE.lock().unwrap().get(key) {
+        Some(CachedSchema {
+            table_schema: Some(table_schema),
+            ..
+        }) => Ok(table_schema.clone()),
+        Some(cached_schema) => Err(cached_schema.version),
+        None => Err(0),
+    }
+}
+
+pub(crate) fn describe_lock(key: &SchemaCacheKey) -> Arc<tokio::sync::Mutex<()>> {
+    DESCRIBE_LOCKS
+        .lock()
+        .unwrap()
+        .entry(key.clone())
+        .or_default()
+        .clone()
+}
+
+pub(crate) fn insert(key: SchemaCacheKey, version: u64, table_schema: TableSchema) {
+    let mut schema_cache = SCHEMA_CACHE.lock().unwrap();
+    let cached_schema = schema_cache.entry(key).or_default();
+    if cached_schema.version == version {
+        cached_schema.table_schema = Some(table_schema);
+    }
+}
+
+pub(crate) fn invalidate(key: SchemaCacheKey) {
+    let mut schema_cache = SCHEMA_CACHE.lock().unwrap();
+    let cached_schema = schema_cache.entry(key).or_default();
+    cached_schema.version += 1;
+    cached_schema.table_schema = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+    use storage::table::ColumnType;
+
+    fn key(table: &str) -> SchemaCacheKey {
+        SchemaCacheKey {
+            address: SocketAddrV4::new(Ipv4Addr::LOCALHOST, 29800),
+            database: "schema_cache".to_string(),
+            table: table.to_string(),
+        }
+    }
+
+    #[test]
+    fn describe_racing_invalidation_is_not_cached() {
+        let table_schema = TableSchema::new("users".to_string(), ColumnType::Int32);
+        let cached_name = |table: &str| get(&key(table)).map(|table_schema| table_schema.name);
+
+        let version = get(&key("users")).unwrap_err();
+        insert(key("users"), version, table_schema.clone());
+        assert_eq!(cached_name("users"), Ok("users".to_string()));
+        assert_eq!(cached_name("orders"), Err(0));
+
+        // schema described before table got dropped arrives after invalidation
+        invalidate(key("users"));
+        insert(key("users"), version, table_schema.clone());
+        assert_eq!(cached_name("users"), Err(version + 1));
+
+        insert(key("users"), version + 1, table_schema);
+        assert_eq!(cached_name("users"), Ok("users".to_string()));
+    }
+}
@@ -0,0 +1,99 @@
+use storage::table::TableSchema;
+
+// differences between schema derived from a model and the one table has on server
+pub(crate) fn schema_mismatches(
+    model_schema: &TableSchema,
+    table_schema: &TableSchema,
+) -> Vec<String> {
+    let mut mismatches = Vec::new();
+
+    if model_schema.sort_key_type != table_schema.sort_key_type {
+        mismatches.push(format!(
+            "sort key is {:?} in model, {:?} in table",
+            model_schema.sort_key_type, table_schema.sort_key_type
+        ));
+    }
+
+    for (name, model_column) in &model_schema.columns {
+        match table_schema.columns.get(name) {
+            None => mismatches.push(format!("column '{}' is missing in table", name)),
+            Some(table_column) => {
+                if model_column.column_type != table_column.column_type {
+                    mismatches.push(format!(
+                        "column '{}' is {:?} in model, {:?} in table",
+                        name, model_column.column_type, table_column.column_type
+                    ));
+                }
+                if model_column.nullable != table_column.nullable {
+                    mismatches.push(format!(
+                        "column '{}' is {} in model, {} in table",
+                        name,
+                        nullability(model_column.nullable),
+                        nullability(table_column.nullable)
+                    ));
+                }
+            }
+        }
+    }
+
+    for name in table_schema.columns.keys() {
+        if !model_schema.columns.contains_key(name) {
+            mismatches.push(format!("column '{}' is missing in model", name));
+        }
+    }
+
+    mismatches
+}
+
+fn nullability(nullable: bool) -> &'static str {
+    match nullable {
+        true => "nullable",
+        false => "not nullable",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use storage::table::{Column, ColumnType};
+
+    #[test]
+    fn mismatched_columns_are_reported() {
+        let mut model_schema = TableSchema::new("users".to_string(), ColumnType::Varchar(16));
+        model_schema
+            .columns
+            .insert("age".to_string(), Column::new(ColumnType::Int32, false));
+        model_schema.columns.insert(
+            "email".to_string(),
+            Column::new(ColumnType::Varchar(64), true),
+        );
+        model_schema.columns.insert(
+            "name".to_string(),
+            Column::new(ColumnType::Varchar(32), false),
+        );
+
+        let mut table_schema = TableSchema::new("users".to_string(), ColumnType::Varchar(16));
+        table_schema
+            .columns
+            .insert("age".to_string(), Column::new(ColumnType::Int64, false));
+        table_schema.columns.insert(
+            "email".to_string(),
+            Column::new(ColumnType::Varchar(64), false),
+        );
+        table_schema.columns.insert(
+            "created_at".to_string(),
+            Column::new(ColumnType::Int64, false),
+        );
+
+        assert_eq!(
+            schema_mismatches(&model_schema, &table_schema),
+            vec![
+                "column 'age' is Int32 in model, Int64 in table".to_string(),
+                "column 'email' is nullable in model, not nullable in table".to_string(),
+                "column 'name' is missing in table".to_string(),
+                "column 'created_at' is missing in model".to_string(),
+            ]
+        );
+        assert!(schema_mismatches(&model_schema, &model_schema).is_empty());
+    }
+}
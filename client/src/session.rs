@@ -0,0 +1,66 @@
+use protobuf::EnumOrUnknown;
+use protos::{Consistency as ProtoConsistency, ProtoRequest, ProtoRequestData, SetSessionRequest};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Consistency {
+    // writes are acknowledged according to table's durability
+    #[default]
+    Default,
+    // writes are acknowledged once commit log is synced
+    Durable,
+}
+
+// returned by writes and attached to later reads, which then fail rather than see state older
+// than the write, opaque to clients but can be stored and passed to other connections
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyToken(String);
+
+impl ConsistencyToken {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ConsistencyToken {
+    fn from(token: String) -> ConsistencyToken {
+        ConsistencyToken(token)
+    }
+}
+
+// settings sent once per connection, server applies them to every request sent over it
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    // used as request timeout by client, server abandons reads taking longer
+    pub timeout: Option<Duration>,
+    pub consistency: Consistency,
+    // table used by requests that do not name one
+    pub keyspace: Option<String>,
+    // milliseconds since epoch, gets see rows as of that time and writes are rejected,
+    // superseded rows are found only for tables keeping history
+    pub read_as_of: Option<u128>,
+    // every n-th response carries time server spent on each phase of the request, which is logged
+    // at debug level, for finding out where tail latency goes
+    pub latency_sample_every: Option<u32>,
+}
+
+impl Session {
+    pub(crate) fn to_proto_request(&self) -> ProtoRequest {
+        let mut set_session_request = SetSessionRequest::new();
+        set_session_request.timeout_millis = self
+            .timeout
+            .map(|timeout| timeout.as_millis() as u64)
+            .unwrap_or(0);
+        set_session_request.consistency = EnumOrUnknown::new(match self.consistency {
+            Consistency::Default => ProtoConsistency::DEFAULT,
+            Consistency::Durable => ProtoConsistency::DURABLE,
+        });
+        set_session_request.keyspace = self.keyspace.clone().unwrap_or_default();
+        set_session_request.read_as_of_millis = self.read_as_of.unwrap_or(0) as u64;
+        set_session_request.latency_sample_every = self.latency_sample_every.unwrap_or(0);
+
+        let mut proto_request = ProtoRequest::new();
+        proto_request.data = Some(ProtoRequestData::SetSession(set_session_request));
+        proto_request
+    }
+}
@@ -0,0 +1,150 @@
+use crate::batch::{delete_batch_item, insert_batch_item};
+use crate::Model;
+use common::value::Value;
+use protos::BatchItem;
+
+// single write of a template, its table and hash key decide which request it joins
+pub(crate) struct TableWrite {
+    pub(crate) table_name: String,
+    pub(crate) item: BatchItem,
+}
+
+type WriteMapping<S> = Box<dyn Fn(&S) -> Option<TableWrite> + Send + Sync>;
+
+// declarative fan-out of one logical save into writes of several tables,
+// e.g. entity table, lookup table keyed by email and table maintaining row counts per hash key
+pub struct WriteTemplate<S> {
+    mappings: Vec<WriteMapping<S>>,
+}
+
+impl<S> Default for WriteTemplate<S> {
+    fn default() -> WriteTemplate<S> {
+        WriteTemplate {
+            mappings: Vec::new(),
+        }
+    }
+}
+
+impl<S> WriteTemplate<S> {
+    pub fn new() -> WriteTemplate<S> {
+        WriteTemplate::default()
+    }
+
+    pub fn insert<T: Model + 'static>(
+        self,
+        mapping: impl Fn(&S) -> T + Send + Sync + 'static,
+    ) -> WriteTemplate<S> {
+        self.insert_if(move |source| Some(mapping(source)))
+    }
+
+    // mapping returning None skips the write, e.g. when optional field is not set
+    pub fn insert_if<T: Model + 'static>(
+        mut self,
+        mapping: impl Fn(&S) -> Option<T> + Send + Sync + 'static,
+    ) -> WriteTemplate<S> {
+        self.mappings.push(Box::new(move |source| {
+            mapping(source).map(|instance| TableWrite {
+                table_name: T::table_name(),
+                item: insert_batch_item(&instance),
+            })
+        }));
+        self
+    }
+
+    // mapping returns hash key and sort key of row to delete, e.g. stale lookup row
+    pub fn delete_if<T: Model + 'static>(
+        mut self,
+        mapping: impl Fn(&S) -> Option<(String, Value)> + Send + Sync + 'static,
+    ) -> WriteTemplate<S> {
+        self.mappings.push(Box::new(move |source| {
+            mapping(source).map(|(hash_key, sort_key)| TableWrite {
+                table_name: T::table_name(),
+                item: delete_batch_item(hash_key, sort_key),
+            })
+        }));
+        self
+    }
+
+    pub(crate) fn writes(&self, source: &S) -> Vec<TableWrite> {
+        self.mappings
+            .iter()
+            .filter_map(|mapping| mapping(source))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::get_batch_item_hash_key;
+    use common::value::Value::*;
+    use macros::DatabaseModel;
+    use protos::util::{
+        parse_message_field_from_value, parse_proto_from_value, parse_value_from_message_field,
+        parse_value_from_proto,
+    };
+    use protos::{BatchItemData, DeleteRequest, GetResponse, InsertRequest};
+    use std::collections::HashMap;
+    use storage::table::TableSchema;
+
+    #[derive(DatabaseModel)]
+    struct User {
+        hash_key: String,
+        sort_key: String,
+        email: Option<String>,
+    }
+
+    #[derive(DatabaseModel)]
+    struct UserByEmail {
+        hash_key: String,
+        sort_key: String,
+        user_id: String,
+    }
+
+    #[test]
+    fn template_fans_out_into_writes_of_every_table() {
+        let template = WriteTemplate::new()
+            .insert(|user: &User| User {
+                hash_key: user.hash_key.clone(),
+                sort_key: user.sort_key.clone(),
+                email: user.email.clone(),
+            })
+            .insert_if(|user: &User| {
+                user.email.clone().map(|email| UserByEmail {
+                    hash_key: email,
+                    sort_key: "user".to_string(),
+                    user_id: user.hash_key.clone(),
+                })
+            })
+            .delete_if::<UserByEmail>(|_: &User| None);
+
+        let user = User {
+            hash_key: "user_1".to_string(),
+            sort_key: "profile".to_string(),
+            email: Some("user@example.com".to_string()),
+        };
+        let writes: Vec<_> = template
+            .writes(&user)
+            .into_iter()
+            .map(|write| (write.table_name, get_batch_item_hash_key(&write.item)))
+            .collect();
+        assert_eq!(
+            writes,
+            vec![
+                ("user".to_string(), "user_1".to_string()),
+                ("userbyemail".to_string(), "user@example.com".to_string()),
+            ]
+        );
+
+        let user = User {
+            email: None,
+            ..user
+        };
+        let writes = template.writes(&user);
+        assert_eq!(writes.len(), 1);
+        assert!(matches!(
+            writes[0].item.item,
+            Some(BatchItemData::Insert(_))
+        ));
+    }
+}
@@ -1,26 +1,70 @@
-use crate::connection::{ConnectionError, ConnectionInner};
+use crate::connection::ConnectionInner;
+use crate::error::ConnectionError;
+use crate::prepared::PreparedRequest;
+use crate::template::WriteTemplate;
 use crate::Model;
 use common::value::Value;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
+// transaction open on server, as reported to operators
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveTransaction {
+    pub id: u64,
+    pub age: Duration,
+    pub tables: Vec<String>,
+    pub coordinator_partition: Option<usize>,
+}
+
+// transaction a request is part of, deferred transaction is begun along with its first request
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TransactionScope {
+    pub id: u64,
+    pub begins: bool,
+}
+
+// coordinator of deferred transaction is only known once its first operation gets to server
+#[derive(Debug, Clone, Copy)]
+enum Coordinator {
+    Deferred,
+    // first operation failed, transaction may or may not have been begun along with it
+    Attempted(usize),
+    Begun(usize),
+}
+
+impl Coordinator {
+    fn partition(self) -> Option<usize> {
+        match self {
+            Coordinator::Deferred => None,
+            Coordinator::Attempted(partition) | Coordinator::Begun(partition) => Some(partition),
+        }
+    }
+}
+
 pub struct Transaction {
     id: u64,
-    connection: Arc<Mutex<ConnectionInner>>,
-    coordinator_partition: usize,
+    connection: Arc<ConnectionInner>,
+    // held by first operation of deferred transaction, so that others wait until it is begun
+    coordinator: Mutex<Coordinator>,
     finished: bool,
 }
 
 impl Transaction {
     pub(crate) fn new(
         id: u64,
-        coordinator_partition: usize,
-        connection: Arc<Mutex<ConnectionInner>>,
+        coordinator_partition: Option<usize>,
+        connection: Arc<ConnectionInner>,
     ) -> Transaction {
+        let coordinator = match coordinator_partition {
+            Some(coordinator_partition) => Coordinator::Begun(coordinator_partition),
+            None => Coordinator::Deferred,
+        };
         Transaction {
             id,
             connection,
-            coordinator_partition,
+            coordinator: Mutex::new(coordinator),
             finished: false,
         }
     }
@@ -30,13 +74,20 @@ impl Transaction {
         hash_key: String,
         sort_key: Value,
     ) -> Result<Option<T>, ConnectionError> {
-        let connection = self.connection.lock().await;
-        connection.get(hash_key, sort_key, Some(self.id)).await
+        let connection = &self.connection;
+        self.scoped(&hash_key.clone(), |transaction| {
+            connection.get(hash_key, sort_key, Some(transaction), None)
+        })
+        .await
     }
 
     pub async fn insert<T: Model>(&self, instance: T) -> Result<(), ConnectionError> {
-        let connection = self.connection.lock().await;
-        connection.insert(instance, Some(self.id)).await
+        let connection = &self.connection;
+        self.scoped(&instance.hash_key(), |transaction| {
+            connection.insert(instance, Some(transaction))
+        })
+        .await?;
+        Ok(())
     }
 
     pub async fn delete(
@@ -45,37 +96,150 @@ impl Transaction {
         sort_key: Value,
         table_name: &str,
     ) -> Result<bool, ConnectionError> {
-        let connection = self.connection.lock().await;
+        let connection = &self.connection;
+        self.scoped(&hash_key.clone(), |transaction| {
+            connection.delete(hash_key, sort_key, table_name, Some(transaction))
+        })
+        .await
+    }
+
+    pub async fn get_for_update_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<Option<T>, ConnectionError> {
+        let connection = &self.connection;
+        self.scoped(&hash_key.clone(), |transaction| {
+            connection.get_prepared(prepared_request, hash_key, sort_key, Some(transaction))
+        })
+        .await
+    }
+
+    pub async fn insert_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        instance: T,
+    ) -> Result<(), ConnectionError> {
+        let connection = &self.connection;
+        self.scoped(&instance.hash_key(), |transaction| {
+            connection.insert_prepared(prepared_request, instance, Some(transaction))
+        })
+        .await
+    }
+
+    pub async fn delete_prepared<T: Model>(
+        &self,
+        prepared_request: &PreparedRequest<T>,
+        hash_key: String,
+        sort_key: Value,
+    ) -> Result<bool, ConnectionError> {
+        let connection = &self.connection;
+        self.scoped(&hash_key.clone(), |transaction| {
+            connection.delete_prepared(prepared_request, hash_key, sort_key, Some(transaction))
+        })
+        .await
+    }
+
+    // writes of template become visible together on commit
+    pub async fn save_template<S>(
+        &self,
+        template: &WriteTemplate<S>,
+        source: &S,
+    ) -> Result<bool, ConnectionError> {
+        // writes go to several threads, so none of them can begin the transaction
+        self.begin(&mut *self.coordinator.lock().await).await?;
+        let connection = &self.connection;
         connection
-            .delete(hash_key, sort_key, table_name, Some(self.id))
+            .apply_writes(template.writes(source), Some(self.id))
             .await
     }
 
+    // transaction that was never begun is not sent to server
     pub async fn commit(&mut self) -> Result<(), ConnectionError> {
-        let connection = self.connection.lock().await;
+        let connection = &self.connection;
         self.finished = true;
-        connection
-            .commit_transaction(self.id, self.coordinator_partition)
-            .await
+        match self.coordinator.get_mut().partition() {
+            Some(coordinator_partition) => {
+                connection
+                    .commit_transaction(self.id, coordinator_partition)
+                    .await
+            }
+            None => Ok(()),
+        }
     }
 
     pub async fn abort(&mut self) -> Result<(), ConnectionError> {
-        let connection = self.connection.lock().await;
+        let connection = &self.connection;
         self.finished = true;
-        connection
-            .abort_transaction(self.id, self.coordinator_partition)
-            .await
+        match self.coordinator.get_mut().partition() {
+            Some(coordinator_partition) => {
+                connection
+                    .abort_transaction(self.id, coordinator_partition)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    // operation of transaction not yet begun begins it on the thread owning its hash key
+    async fn scoped<R, F>(
+        &self,
+        hash_key: &str,
+        operation: impl FnOnce(TransactionScope) -> F,
+    ) -> Result<R, ConnectionError>
+    where
+        F: Future<Output = Result<R, ConnectionError>>,
+    {
+        let mut coordinator = self.coordinator.lock().await;
+        if let Coordinator::Attempted(_) = *coordinator {
+            self.begin(&mut coordinator).await?;
+        }
+        if let Coordinator::Begun(_) = *coordinator {
+            drop(coordinator);
+            return operation(TransactionScope {
+                id: self.id,
+                begins: false,
+            })
+            .await;
+        }
+
+        let result = operation(TransactionScope {
+            id: self.id,
+            begins: true,
+        })
+        .await;
+        // misrouted request was retried on its owner, so partition map already names it
+        let partition = self.connection.get_partition(hash_key);
+        *coordinator = match result {
+            Ok(_) => Coordinator::Begun(partition),
+            Err(_) => Coordinator::Attempted(partition),
+        };
+        result
+    }
+
+    // beginning transaction again on the same thread is a no-op
+    async fn begin(&self, coordinator: &mut Coordinator) -> Result<(), ConnectionError> {
+        if let Coordinator::Begun(_) = coordinator {
+            return Ok(());
+        }
+        let (_, coordinator_partition) = self
+            .connection
+            .begin_transaction(Some(self.id), coordinator.partition())
+            .await?;
+        *coordinator = Coordinator::Begun(coordinator_partition);
+        Ok(())
     }
 }
 
 impl Drop for Transaction {
     fn drop(&mut self) {
-        if !self.finished {
+        if let (false, Some(coordinator_partition)) =
+            (self.finished, self.coordinator.get_mut().partition())
+        {
             let connection = self.connection.clone();
             let transaction_id = self.id;
-            let coordinator_partition = self.coordinator_partition;
             let _ = tokio::spawn(async move {
-                let connection = connection.lock().await;
                 connection
                     .abort_transaction(transaction_id, coordinator_partition)
                     .await
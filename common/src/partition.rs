@@ -1,9 +1,129 @@
 use murmur3::murmur3_32;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 use std::io::Cursor;
 
 pub static MURMUR3_SEED: u32 = 1119284470;
+// granularity at which hash key space can be reassigned between partitions
+pub static NUM_OF_HASH_BUCKETS: u32 = 4096;
+
+fn hash_key_hash(hash_key: &str) -> u32 {
+    murmur3_32(&mut Cursor::new(&hash_key), MURMUR3_SEED).unwrap()
+}
 
 pub fn get_hash_key_target_partition(hash_key: &str, num_of_partitions: usize) -> usize {
-    let hash = murmur3_32(&mut Cursor::new(&hash_key), MURMUR3_SEED).unwrap();
-    (hash % (num_of_partitions as u32)) as usize
+    (hash_key_hash(hash_key) % (num_of_partitions as u32)) as usize
+}
+
+pub fn get_hash_key_bucket(hash_key: &str) -> u32 {
+    hash_key_hash(hash_key) % NUM_OF_HASH_BUCKETS
+}
+
+// partitions are spread over server threads round robin, clients route by the same rule
+pub fn get_partition_thread(partition: usize, num_of_threads: usize) -> usize {
+    partition % num_of_threads
+}
+
+// hash keys are partitioned by hash modulo, except for buckets reassigned to other partitions
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartitionMap {
+    pub num_of_partitions: usize,
+    pub reassigned_buckets: BTreeMap<u32, usize>,
+}
+
+impl PartitionMap {
+    pub fn new(num_of_partitions: usize) -> PartitionMap {
+        PartitionMap {
+            num_of_partitions,
+            reassigned_buckets: BTreeMap::new(),
+        }
+    }
+
+    pub fn get_partition(&self, hash_key: &str) -> usize {
+        if !self.reassigned_buckets.is_empty() {
+            if let Some(partition) = self.reassigned_buckets.get(&get_hash_key_bucket(hash_key)) {
+                return *partition;
+            }
+        }
+
+        get_hash_key_target_partition(hash_key, self.num_of_partitions)
+    }
+
+    pub fn reassign_bucket(&mut self, bucket: u32, partition: usize) -> Result<(), String> {
+        if bucket >= NUM_OF_HASH_BUCKETS {
+            return Err(format!("Invalid hash bucket {}", bucket));
+        }
+        if partition >= self.num_of_partitions {
+            return Err(format!(
+                "Invalid partition {}, there are {} partitions",
+                partition, self.num_of_partitions
+            ));
+        }
+
+        self.reassigned_buckets.insert(bucket, partition);
+        Ok(())
+    }
+
+    // e.g. "8;12:3,40:0" - 8 partitions, bucket 12 moved to partition 3 and bucket 40 to 0
+    pub fn from_string(partition_map_string: &str) -> Result<PartitionMap, String> {
+        let invalid_error = || format!("Invalid partition map '{}'", partition_map_string);
+
+        let (num_of_partitions, buckets_string) = partition_map_string
+            .split_once(';')
+            .ok_or_else(invalid_error)?;
+        let num_of_partitions = num_of_partitions.parse().map_err(|_| invalid_error())?;
+
+        let mut partition_map = PartitionMap::new(num_of_partitions);
+        for bucket_string in buckets_string.split(',').filter(|s| !s.is_empty()) {
+            let (bucket, partition) = bucket_string.split_once(':').ok_or_else(invalid_error)?;
+            partition_map.reassign_bucket(
+                bucket.parse().map_err(|_| invalid_error())?,
+                partition.parse().map_err(|_| invalid_error())?,
+            )?;
+        }
+
+        Ok(partition_map)
+    }
+}
+
+impl Display for PartitionMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let buckets: Vec<_> = self
+            .reassigned_buckets
+            .iter()
+            .map(|(bucket, partition)| format!("{}:{}", bucket, partition))
+            .collect();
+
+        write!(f, "{};{}", self.num_of_partitions, buckets.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reassigned_bucket_overrides_hash_partitioning() {
+        let hash_key = "celebrity";
+        let mut partition_map = PartitionMap::new(4);
+        let default_partition = partition_map.get_partition(hash_key);
+        assert_eq!(
+            default_partition,
+            get_hash_key_target_partition(hash_key, 4)
+        );
+
+        let target_partition = (default_partition + 1) % 4;
+        partition_map
+            .reassign_bucket(get_hash_key_bucket(hash_key), target_partition)
+            .unwrap();
+        assert_eq!(partition_map.get_partition(hash_key), target_partition);
+        assert!(partition_map.reassign_bucket(0, 4).is_err());
+
+        let parsed = PartitionMap::from_string(&partition_map.to_string()).unwrap();
+        assert_eq!(parsed, partition_map);
+        assert_eq!(
+            PartitionMap::from_string("4;").unwrap(),
+            PartitionMap::new(4)
+        );
+    }
 }
@@ -36,6 +36,22 @@ impl Value {
     }
 }
 
+impl Value {
+    // keys are compared by their encoded bits, so float keys must be finite,
+    // NaN has no place in that order and infinities are rejected along with it
+    pub fn validate_key(&self) -> Result<(), String> {
+        let finite = match self {
+            Float32(value) => value.is_finite(),
+            Float64(value) => value.is_finite(),
+            _ => true,
+        };
+        match finite {
+            true => Ok(()),
+            false => Err(format!("Key cannot be NaN or infinite, got {}", self)),
+        }
+    }
+}
+
 impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
@@ -68,3 +84,17 @@ impl GetSize for Value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_finite_floats_are_rejected_as_keys() {
+        assert!(Float32(f32::NAN).validate_key().is_err());
+        assert!(Float32(f32::INFINITY).validate_key().is_err());
+        assert!(Float64(f64::NEG_INFINITY).validate_key().is_err());
+        assert!(Float64(-0.5).validate_key().is_ok());
+        assert!(Int32(-1).validate_key().is_ok());
+    }
+}
@@ -1,15 +1,23 @@
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use std::collections::BTreeMap;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
 
+use common::value::Value;
+use std::collections::HashMap;
 use storage::table::{Column, ColumnType, TableSchema};
+use storage::table_options::{encode_metadata_value, ColumnTransform, TableOptions, Transform};
+use storage::{decode_row, encode_row, Row};
 use syn::{
-    parse_macro_input, Data, DeriveInput, Field, Fields, GenericArgument, PathArguments, Type,
+    parse_macro_input, Attribute, Data, DeriveInput, Field, Fields, GenericArgument, Ident, LitInt,
+    LitStr, PathArguments, Type, Visibility,
 };
 
-#[proc_macro_derive(DatabaseModel)]
+// same as String fields, json of bigger values needs #[column(serde_json, max_length = 4096)]
+static SERDE_JSON_DEFAULT_MAX_LENGTH: usize = 1024;
+
+#[proc_macro_derive(DatabaseModel, attributes(table, column, ttl, version))]
 pub fn derive_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -25,11 +33,23 @@ pub fn derive_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     };
 
     let (sort_key, fields) = extract_fields(fields);
+    let (version, fields) = extract_version_field(fields);
+    let mut table_options = extract_table_options(&input.attrs);
+    table_options.ttl_column = extract_ttl_field(&input.attrs, &fields);
+    table_options
+        .transforms
+        .extend(extract_column_transforms(&fields));
 
-    let from_get_impl = proc_from_get_response(&sort_key, &fields);
+    let from_get_impl = proc_from_get_response(&sort_key, &fields, version.as_ref());
     let insert_impl = proc_to_insert_request(&sort_key, &fields);
     let delete_impl = proc_to_delete_request(&sort_key);
-    let table_schema_impl = proc_table_schema(&sort_key, &fields, table_name.clone());
+    let table_schema_impl =
+        proc_table_schema(&sort_key, &fields, table_name.clone(), table_options);
+    let builder_impl = proc_builder(&name, &input.vis, &sort_key, &fields, version.as_ref());
+    let update_impl = proc_update(&name, &input.vis, &fields);
+    let versioned_impl = version
+        .as_ref()
+        .map(|version| proc_versioned(&name, version));
 
     let expanded = quote! {
         impl Model for #name {
@@ -57,12 +77,20 @@ pub fn derive_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 #table_schema_impl
             }
         }
+
+        #builder_impl
+        #update_impl
+        #versioned_impl
     };
 
     proc_macro::TokenStream::from(expanded)
 }
 
-fn proc_from_get_response(sort_key: &Field, fields: &Vec<Field>) -> TokenStream {
+fn proc_from_get_response(
+    sort_key: &Field,
+    fields: &[Field],
+    version: Option<&Field>,
+) -> TokenStream {
     let sort_key_value_quote = parse_from_value_quote(sort_key);
     let sort_key_operation = quote! {
         let sort_key = match parse_value_from_message_field(get_response.sort_key) {
@@ -102,6 +130,13 @@ fn proc_from_get_response(sort_key: &Field, fields: &Vec<Field>) -> TokenStream
             }
         })
         .collect();
+    let version_field = version.map(|version| {
+        let version_ident = version.ident.as_ref().unwrap();
+        match get_field_type(version).as_str() {
+            "Option" => quote! { #version_ident: Some(get_response.version), },
+            _ => quote! { #version_ident: get_response.version, },
+        }
+    });
 
     quote! {
         let hash_key = get_response.hash_key.clone();
@@ -112,12 +147,14 @@ fn proc_from_get_response(sort_key: &Field, fields: &Vec<Field>) -> TokenStream
             hash_key,
             sort_key,
             #struct_fields
+            #version_field
         }
     }
 }
 
 fn proc_to_insert_request(sort_key: &Field, fields: &Vec<Field>) -> TokenStream {
-    let sort_key_value_quote = parse_to_value_quote(sort_key);
+    let sort_key_ident = sort_key.ident.as_ref().unwrap();
+    let sort_key_value_quote = parse_to_value_quote(sort_key, &quote!(self.#sort_key_ident));
     let sort_key_operation = quote! {
         #sort_key_value_quote
         insert_request.sort_key = parse_message_field_from_value(value);
@@ -126,8 +163,9 @@ fn proc_to_insert_request(sort_key: &Field, fields: &Vec<Field>) -> TokenStream
     let field_operations: TokenStream = fields
         .into_iter()
         .map(|field| {
-            let field_name = field.ident.as_ref().unwrap().to_string();
-            let value_quote = parse_to_value_quote(field);
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_name = field_ident.to_string();
+            let value_quote = parse_to_value_quote(field, &quote!(self.#field_ident));
             quote! {
                 #value_quote
                 values.insert(#field_name.to_string(), parse_proto_from_value(value));
@@ -150,7 +188,8 @@ fn proc_to_insert_request(sort_key: &Field, fields: &Vec<Field>) -> TokenStream
 }
 
 fn proc_to_delete_request(sort_key: &Field) -> TokenStream {
-    let sort_key_value_quote = parse_to_value_quote(sort_key);
+    let sort_key_ident = sort_key.ident.as_ref().unwrap();
+    let sort_key_value_quote = parse_to_value_quote(sort_key, &quote!(self.#sort_key_ident));
     let sort_key_operation = quote! {
         #sort_key_value_quote
         delete_request.sort_key = parse_message_field_from_value(value);
@@ -165,7 +204,12 @@ fn proc_to_delete_request(sort_key: &Field) -> TokenStream {
     }
 }
 
-fn proc_table_schema(sort_key: &Field, fields: &Vec<Field>, table_name: String) -> TokenStream {
+fn proc_table_schema(
+    sort_key: &Field,
+    fields: &Vec<Field>,
+    table_name: String,
+    table_options: TableOptions,
+) -> TokenStream {
     let columns: BTreeMap<_, _> = fields
         .into_iter()
         .map(|field| {
@@ -182,6 +226,9 @@ fn proc_table_schema(sort_key: &Field, fields: &Vec<Field>, table_name: String)
 
     let mut table_schema = TableSchema::new(table_name, sort_key_type);
     table_schema.columns = columns;
+    table_schema.options = table_options;
+
+    check_table_schema_layout(&table_schema);
 
     let table_schema_string = table_schema.to_string();
     quote! {
@@ -189,6 +236,265 @@ fn proc_table_schema(sort_key: &Field, fields: &Vec<Field>, table_name: String)
     }
 }
 
+// fields other than Option<> are required, build() lists every one that was not set,
+// built model is not stored yet, so it starts without version
+fn proc_builder(
+    name: &Ident,
+    visibility: &Visibility,
+    sort_key: &Field,
+    fields: &[Field],
+    version: Option<&Field>,
+) -> TokenStream {
+    let builder_name = format_ident!("{}Builder", name);
+    let fields: Vec<_> = std::iter::once(sort_key).chain(fields).collect();
+
+    let builder_fields: TokenStream = fields
+        .iter()
+        .map(|field| {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_type = &field.ty;
+            match get_field_type(field).as_str() {
+                "Option" => quote! { #field_ident: #field_type, },
+                _ => quote! { #field_ident: Option<#field_type>, },
+            }
+        })
+        .collect();
+
+    let setters: TokenStream = fields
+        .iter()
+        .map(|field| {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_type = &field.ty;
+            let assignment = match get_field_type(field).as_str() {
+                "Option" => quote! { self.#field_ident = #field_ident; },
+                _ => quote! { self.#field_ident = Some(#field_ident); },
+            };
+            quote! {
+                pub fn #field_ident(mut self, #field_ident: #field_type) -> Self {
+                    #assignment
+                    self
+                }
+            }
+        })
+        .collect();
+
+    let (required_fields, optional_fields): (Vec<&Field>, Vec<&Field>) = fields
+        .iter()
+        .partition(|field| get_field_type(field) != "Option");
+    let required_fields: Vec<_> = required_fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let optional_fields: Vec<_> = optional_fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let required_field_names: Vec<_> = required_fields
+        .iter()
+        .map(|field_ident| field_ident.to_string())
+        .collect();
+    let version_ident = version.map(|version| version.ident.as_ref().unwrap());
+    let version_field =
+        version_ident.map(|version_ident| quote! { #version_ident: Default::default(), });
+
+    quote! {
+        #[derive(Default)]
+        #visibility struct #builder_name {
+            hash_key: Option<String>,
+            #builder_fields
+        }
+
+        impl #builder_name {
+            pub fn hash_key(mut self, hash_key: String) -> Self {
+                self.hash_key = Some(hash_key);
+                self
+            }
+
+            #setters
+
+            pub fn build(self) -> Result<#name, String> {
+                let mut missing_fields = Vec::new();
+                if self.hash_key.is_none() {
+                    missing_fields.push("hash_key");
+                }
+                #(
+                    if self.#required_fields.is_none() {
+                        missing_fields.push(#required_field_names);
+                    }
+                )*
+                if !missing_fields.is_empty() {
+                    return Err(format!("Missing required fields: {}", missing_fields.join(", ")));
+                }
+
+                Ok(#name {
+                    hash_key: self.hash_key.unwrap(),
+                    #(#required_fields: self.#required_fields.unwrap(),)*
+                    #(#optional_fields: self.#optional_fields,)*
+                    #version_field
+                })
+            }
+        }
+
+        impl #name {
+            pub fn builder() -> #builder_name {
+                #builder_name::default()
+            }
+        }
+    }
+}
+
+// columns left as None are not changed, keys are not part of update as they identify the row
+fn proc_update(name: &Ident, visibility: &Visibility, fields: &[Field]) -> TokenStream {
+    let update_name = format_ident!("{}Update", name);
+    let field_idents: Vec<_> = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    let field_types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    let value_operations: TokenStream = fields
+        .iter()
+        .map(|field| {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_name = field_ident.to_string();
+            let value_quote = parse_to_value_quote(field, &quote!((*field_value)));
+            quote! {
+                if let Some(field_value) = &self.#field_ident {
+                    #value_quote
+                    values.insert(#field_name.to_string(), parse_proto_from_value(value));
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        #[derive(Default)]
+        #visibility struct #update_name {
+            #(pub #field_idents: Option<#field_types>,)*
+        }
+
+        impl #update_name {
+            // changed columns, in the same shape as values of InsertRequest
+            pub fn values(&self) -> HashMap<String, ::protos::ProtoValue> {
+                let mut values = HashMap::new();
+                #value_operations
+                values
+            }
+
+            pub fn apply(self, model: &mut #name) {
+                #(
+                    if let Some(field_value) = self.#field_idents {
+                        model.#field_idents = field_value;
+                    }
+                )*
+            }
+        }
+    }
+}
+
+fn proc_versioned(name: &Ident, version: &Field) -> TokenStream {
+    let version_ident = version.ident.as_ref().unwrap();
+    let (get_version, set_version) = match get_field_type(version).as_str() {
+        "Option" => (
+            quote! { self.#version_ident.unwrap_or_default() },
+            quote! { self.#version_ident = Some(version); },
+        ),
+        _ => (
+            quote! { self.#version_ident },
+            quote! { self.#version_ident = version; },
+        ),
+    };
+
+    quote! {
+        impl VersionedModel for #name {
+            fn version(&self) -> u64 {
+                #get_version
+            }
+
+            fn set_version(&mut self, version: u64) {
+                #set_version
+            }
+        }
+    }
+}
+
+// model gets its schema back by parsing generated string, and its rows are encoded and decoded
+// column by column in schema order, so both are checked while model compiles rather than once
+// rows read back are garbage
+fn check_table_schema_layout(table_schema: &TableSchema) {
+    let table_schema_string = table_schema.to_string();
+    let parsed_schema = TableSchema::from_string(&table_schema_string).unwrap_or_else(|error| {
+        panic!(
+            "Schema '{}' of model cannot be parsed: {}",
+            table_schema_string, error
+        )
+    });
+    if parsed_schema.to_string() != table_schema_string {
+        panic!(
+            "Schema '{}' of model is parsed as '{}'",
+            table_schema_string, parsed_schema
+        );
+    }
+    let column_names: Vec<_> = table_schema.columns.keys().collect();
+    let parsed_column_names: Vec<_> = parsed_schema.columns.keys().collect();
+    if column_names != parsed_column_names {
+        panic!(
+            "Columns of model are {:?}, parsed schema has {:?}",
+            column_names, parsed_column_names
+        );
+    }
+
+    // every column gets a value of its own, so that columns swapped in layout do not go unnoticed
+    let values: HashMap<_, _> = table_schema
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, (column_name, column))| {
+            let value = layout_sample_value(&column.column_type, index + 1, column_name);
+            (column_name.clone(), value)
+        })
+        .collect();
+    let sort_key = layout_sample_value(&table_schema.sort_key_type, 0, "sort_key");
+    let row = Row::new("hash_key".to_string(), sort_key, values);
+
+    let row_bytes = encode_row(&row, table_schema);
+    if row_bytes.len() != parsed_schema.row_byte_size() {
+        panic!(
+            "Rows of model take {} bytes, parsed schema expects {}",
+            row_bytes.len(),
+            parsed_schema.row_byte_size()
+        );
+    }
+    let decoded_row = decode_row(&row_bytes, &parsed_schema);
+    if decoded_row.sort_key != row.sort_key || decoded_row.values != row.values {
+        panic!(
+            "Row of model {:?} is decoded as {:?}",
+            row.values, decoded_row.values
+        );
+    }
+}
+
+// leading zero byte is read back as null, so every byte is set, floats stay finite
+fn layout_sample_value(column_type: &ColumnType, index: usize, column_name: &str) -> Value {
+    let byte = (index % 126) as u8 + 1;
+    match column_type {
+        // shorter than column, so that padding is read back too
+        ColumnType::Varchar(max_length) => Value::Varchar(
+            column_name
+                .chars()
+                .take(max_length.saturating_sub(1).max(1))
+                .collect(),
+        ),
+        ColumnType::Int32 => Value::Int32(i32::from_be_bytes([byte; 4])),
+        ColumnType::Int64 => Value::Int64(i64::from_be_bytes([byte; 8])),
+        ColumnType::Unsigned32 => Value::Unsigned32(u32::from_be_bytes([byte; 4])),
+        ColumnType::Unsigned64 => Value::Unsigned64(u64::from_be_bytes([byte; 8])),
+        ColumnType::Float32 => Value::Float32(f32::from_be_bytes([byte; 4])),
+        ColumnType::Float64 => Value::Float64(f64::from_be_bytes([byte; 8])),
+        ColumnType::Boolean => Value::Boolean(true),
+    }
+}
+
 fn extract_fields(fields: &Punctuated<Field, Comma>) -> (Field, Vec<Field>) {
     let mut hash_key = None;
     let mut sort_key = None;
@@ -208,12 +514,218 @@ fn extract_fields(fields: &Punctuated<Field, Comma>) -> (Field, Vec<Field>) {
     }
 
     let sort_key = sort_key.expect("Struct must contain field 'sort_key'");
+    if extract_column_options(&sort_key).serde_json {
+        panic!("'sort_key' cannot be stored as json");
+    }
 
     (sort_key, other_fields)
 }
 
+// #[version] field is not a column, it holds version of row the model was read from
+fn extract_version_field(fields: Vec<Field>) -> (Option<Field>, Vec<Field>) {
+    let (version_fields, fields): (Vec<_>, Vec<_>) = fields.into_iter().partition(|field| {
+        field
+            .attrs
+            .iter()
+            .any(|attribute| attribute.path().is_ident("version"))
+    });
+    if version_fields.len() > 1 {
+        panic!("Only one field can be #[version]");
+    }
+
+    let version = version_fields.into_iter().next();
+    if let Some(version) = &version {
+        let field_type = match get_field_type(version).as_str() {
+            "Option" => get_option_generic_type(version),
+            field_type => field_type.to_string(),
+        };
+        if field_type != "u64" {
+            panic!(
+                "Version field '{}' must be of type 'u64' or 'Option<u64>'",
+                version.ident.as_ref().unwrap()
+            );
+        }
+    }
+
+    (version, fields)
+}
+
+// #[table(row_counts, retention = "30d", retention_column = "created_at", references = "user_id:user")],
+// #[table(index = "age", index = "city")] indexes columns to be queried by value range,
+// #[table(unique_index = "email")] also keeps values of a column distinct within a partition,
+// metadata is written as is, e.g. #[table(comment = "orders, by tenant", tag = "team:payments")]
+fn extract_table_options(attributes: &[Attribute]) -> TableOptions {
+    let mut option_strings = Vec::new();
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("table") {
+            continue;
+        }
+
+        attribute
+            .parse_nested_meta(|meta| {
+                let name = meta.path.require_ident()?.to_string();
+                match meta.value() {
+                    Ok(value) => {
+                        let value = value.parse::<LitStr>()?.value();
+                        let value = match name.as_str() {
+                            "comment" | "owner" => encode_metadata_value(&value),
+                            "tag" => match value.split_once(':') {
+                                Some((key, value)) => format!(
+                                    "{}:{}",
+                                    encode_metadata_value(key),
+                                    encode_metadata_value(value)
+                                ),
+                                None => value,
+                            },
+                            _ => value,
+                        };
+                        option_strings.push(format!("{}={}", name, value));
+                    }
+                    Err(_) => option_strings.push(name),
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    if option_strings.is_empty() {
+        return TableOptions::default();
+    }
+
+    TableOptions::from_string(&option_strings.join(",")).unwrap()
+}
+
+// #[ttl(field = "expires_at")], rows expire once time in that field has passed, so the field
+// holds millis since epoch, expiry is then set on the model like any other value
+fn extract_ttl_field(attributes: &[Attribute], fields: &[Field]) -> Option<String> {
+    let mut ttl_field = None;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("ttl") {
+            continue;
+        }
+
+        attribute
+            .parse_nested_meta(|meta| {
+                match meta.path.require_ident()?.to_string().as_str() {
+                    "field" => ttl_field = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    other => return Err(meta.error(format!("Unknown ttl option '{}'", other))),
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    let ttl_field = ttl_field?;
+    let field = fields
+        .iter()
+        .find(|field| field.ident.as_ref().unwrap() == &ttl_field)
+        .unwrap_or_else(|| panic!("TTL field '{}' is not a field of struct", ttl_field));
+    let field_type = match get_field_type(field).as_str() {
+        "Option" => get_option_generic_type(field),
+        field_type => field_type.to_string(),
+    };
+    if !matches!(field_type.as_str(), "i64" | "u64") || extract_column_options(field).serde_json {
+        panic!("TTL field '{}' must be of type 'i64' or 'u64'", ttl_field);
+    }
+
+    Some(ttl_field)
+}
+
+#[derive(Default)]
+struct ColumnOptions {
+    // field is stored as json in VARCHAR column, for types without native column type,
+    // None of Option<> field is stored as null
+    serde_json: bool,
+    max_length: Option<usize>,
+    // applied by server to inserted values, in order of declaration
+    transforms: Vec<Transform>,
+}
+
+// #[column(serde_json)], #[column(serde_json, max_length = 4096)], #[column(trim, lowercase)]
+fn extract_column_options(field: &Field) -> ColumnOptions {
+    let mut column_options = ColumnOptions::default();
+
+    for attribute in &field.attrs {
+        if !attribute.path().is_ident("column") {
+            continue;
+        }
+
+        attribute
+            .parse_nested_meta(|meta| {
+                match meta.path.require_ident()?.to_string().as_str() {
+                    "serde_json" => column_options.serde_json = true,
+                    "lowercase" => column_options.transforms.push(Transform::Lowercase),
+                    "trim" => column_options.transforms.push(Transform::Trim),
+                    "truncate" => column_options.transforms.push(Transform::Truncate),
+                    "max_length" => {
+                        let max_length = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                        column_options.max_length = Some(max_length);
+                    }
+                    other => return Err(meta.error(format!("Unknown column option '{}'", other))),
+                }
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    if column_options.max_length.is_some() && !column_options.serde_json {
+        panic!("'max_length' can only be set for serde_json columns");
+    }
+
+    column_options
+}
+
+fn extract_column_transforms(fields: &[Field]) -> Vec<ColumnTransform> {
+    let mut column_transforms = Vec::new();
+    for field in fields {
+        let column_options = extract_column_options(field);
+        if column_options.transforms.is_empty() {
+            continue;
+        }
+
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        let field_type = match get_field_type(field).as_str() {
+            "Option" => get_option_generic_type(field),
+            field_type => field_type.to_string(),
+        };
+        if field_type != "String" || column_options.serde_json {
+            panic!(
+                "Field '{}' has to be a String to be transformed",
+                field_name
+            );
+        }
+        column_transforms.extend(column_options.transforms.into_iter().map(|transform| {
+            ColumnTransform {
+                column: field_name.clone(),
+                transform,
+            }
+        }));
+    }
+
+    column_transforms
+}
+
 fn parse_from_value_quote(field: &Field) -> TokenStream {
     let field_type = get_field_type(field);
+    if extract_column_options(field).serde_json {
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        let from_json_quote = quote! {
+            serde_json::from_str(&val)
+                .expect(&format!("Invalid json in field '{}'", #field_name))
+        };
+        return match field_type.as_str() {
+            "Option" => quote! {
+                Varchar(val) => Some(#from_json_quote),
+                Null => None,
+            },
+            _ => quote! {
+                Varchar(val) => #from_json_quote,
+            },
+        };
+    }
+
     match field_type.as_str() {
         "String" => {
             quote! {
@@ -269,48 +781,62 @@ fn parse_from_value_quote(field: &Field) -> TokenStream {
     }
 }
 
-fn parse_to_value_quote(field: &Field) -> TokenStream {
-    let field_name = field.ident.as_ref().unwrap();
+// source is a place expression holding value of the field, e.g. self.name
+fn parse_to_value_quote(field: &Field, source: &TokenStream) -> TokenStream {
     let field_type = get_field_type(field);
+    if extract_column_options(field).serde_json {
+        return match field_type.as_str() {
+            "Option" => quote! {
+                let value = match &#source {
+                    Some(inner) => Varchar(serde_json::to_string(inner).unwrap()),
+                    None => Null
+                };
+            },
+            _ => quote! {
+                let value = Varchar(serde_json::to_string(&#source).unwrap());
+            },
+        };
+    }
+
     match field_type.as_str() {
         "String" => {
             quote! {
-                let value = Varchar(self.#field_name.clone());
+                let value = Varchar(#source.clone());
             }
         }
         "i32" => {
             quote! {
-                let value = Int32(self.#field_name);
+                let value = Int32(#source);
             }
         }
         "i64" => {
             quote! {
-                let value = Int64(self.#field_name);
+                let value = Int64(#source);
             }
         }
         "u32" => {
             quote! {
-                let value = Unsigned32(self.#field_name);
+                let value = Unsigned32(#source);
             }
         }
         "u64" => {
             quote! {
-                let value = Unsigned64(self.#field_name);
+                let value = Unsigned64(#source);
             }
         }
         "f32" => {
             quote! {
-                let value = Float32(self.#field_name);
+                let value = Float32(#source);
             }
         }
         "f64" => {
             quote! {
-                let value = Float64(self.#field_name);
+                let value = Float64(#source);
             }
         }
         "bool" => {
             quote! {
-                let value = Boolean(self.#field_name);
+                let value = Boolean(#source);
             }
         }
         "Option" => {
@@ -318,7 +844,7 @@ fn parse_to_value_quote(field: &Field) -> TokenStream {
             let inner_type_value_quote = parse_to_value_quote_for_option_inner(&inner_type);
 
             quote! {
-                let value = match &self.#field_name {
+                let value = match &#source {
                     Some(inner) => #inner_type_value_quote
                     None => Null
                 };
@@ -458,6 +984,14 @@ fn parse_to_value_quote_for_option_inner(field_type: &str) -> TokenStream {
 
 fn field_to_column_type(field: &Field) -> (ColumnType, bool) {
     let field_type = get_field_type(field);
+    let column_options = extract_column_options(field);
+    if column_options.serde_json {
+        let max_length = column_options
+            .max_length
+            .unwrap_or(SERDE_JSON_DEFAULT_MAX_LENGTH);
+        return (ColumnType::Varchar(max_length), field_type == "Option");
+    }
+
     match field_type.as_str() {
         "String" => (ColumnType::Varchar(1024), false),
         "i32" => (ColumnType::Int32, false),
@@ -488,3 +1022,32 @@ fn option_generic_type_to_column_type(field_type: &str) -> ColumnType {
         other_type => panic!("Unsupported '{}' field type", other_type),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_of_every_column_type_round_trips() {
+        let mut table_schema = TableSchema::new("layout".to_string(), ColumnType::Varchar(16));
+        let column_types = [
+            ColumnType::Varchar(8),
+            ColumnType::Int32,
+            ColumnType::Int64,
+            ColumnType::Unsigned32,
+            ColumnType::Unsigned64,
+            ColumnType::Float32,
+            ColumnType::Float64,
+            ColumnType::Boolean,
+            ColumnType::Int32,
+        ];
+        for (index, column_type) in column_types.into_iter().enumerate() {
+            table_schema.columns.insert(
+                format!("column_{}", index),
+                Column::new(column_type, index % 2 == 0),
+            );
+        }
+
+        check_table_schema_layout(&table_schema);
+    }
+}
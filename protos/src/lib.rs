@@ -6,11 +6,27 @@ pub mod util;
 pub use common::{value::Data as ProtoValueData, Value as ProtoValue};
 pub use request::{
     batch_item::Item as BatchItemData, request::Data as ProtoRequestData, AbortTransaction,
-    BatchItem, BatchRequest, BeginTransaction, CommitTransaction, DeleteRequest, DropTableRequest,
-    GetManyRequest, GetRequest, InsertRequest, Request as ProtoRequest, SyncModelRequest,
+    AcquireLeaseRequest, BatchItem, BatchRequest, BeginTransaction, CommitTransaction,
+    CompactionRequest, Consistency, DeleteRequest, DeleteWhereRequest, DescribeServerRequest,
+    DescribeTableRequest, DropTableRequest, GetManyRequest, GetRequest, GetSequenceRequest,
+    HealthRequest, InsertRequest, KeyDistributionRequest, KillTransactionRequest,
+    ListTransactionsRequest, MigrateBucketRequest, MoveRowRequest, PartitionLoadRequest,
+    PartitionMapRequest, PlanMigrationRequest, PromoteRequest, QueryByIndexRequest,
+    RaftAppendRequest, RaftEntry, RaftLogPosition, RaftVoteRequest, ReadyRequest,
+    ReleaseLeaseRequest, RenewLeaseRequest, ReplicateRequest, Request as ProtoRequest,
+    RowCountRequest, SaveRequest, SetReadOnlyRequest, SetSessionRequest, SyncModelRequest,
+    SyncModelsRequest, TableAccessRequest,
 };
 pub use response::{
-    response::Data as ProtoResponseData, BatchResponse, ClientError, DeleteResponse,
-    DropTableResponse, GetManyResponse, GetResponse, InsertResponse, Response as ProtoResponse,
-    ServerError, SyncModelResponse, TransactionResponse,
+    response::Data as ProtoResponseData, ActiveTransaction, BatchResponse, ClientError,
+    CompactionBucket, CompactionResponse, DatabaseDescription, DeleteResponse, DeleteWhereResponse,
+    DescribeServerResponse, DescribeTableResponse, DropTableResponse, ErrorCode, GetManyResponse,
+    GetResponse, HealthResponse, InsertResponse, KeyDistributionResponse, LatencyBreakdown,
+    LeaseResponse, ListTransactionsResponse, MoveRowResponse, PartitionKeyDistribution,
+    PartitionLoadResponse, PartitionLoadStats, PartitionMapResponse, PlanMigrationResponse,
+    PromoteResponse, QueryByIndexResponse, RaftAppendResponse, RaftVoteResponse, ReadExplanation,
+    ReadyResponse, ReplicateResponse, ReplicatedRecords, Response as ProtoResponse,
+    RowCountResponse, SSTableReadExplanation, SaveResponse, SequenceResponse, ServerError,
+    SessionResponse, SetReadOnlyResponse, SyncModelResponse, TableAccessResponse, TableAccessStats,
+    TableCompaction, TableStatistics as ProtoTableStatistics, TransactionResponse,
 };
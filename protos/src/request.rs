@@ -33,6 +33,18 @@ pub struct Request {
     pub transaction_id: ::std::option::Option<u64>,
     // @@protoc_insertion_point(field:Request.table)
     pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:Request.database)
+    pub database: ::std::string::String,
+    // @@protoc_insertion_point(field:Request.min_consistency_token)
+    pub min_consistency_token: ::std::string::String,
+    // @@protoc_insertion_point(field:Request.explain)
+    pub explain: bool,
+    // @@protoc_insertion_point(field:Request.begins_transaction)
+    pub begins_transaction: bool,
+    // @@protoc_insertion_point(field:Request.schema_version)
+    pub schema_version: u64,
+    // @@protoc_insertion_point(field:Request.trace_context)
+    pub trace_context: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
     // message oneof groups
     pub data: ::std::option::Option<request::Data>,
     // special fields
@@ -541,100 +553,6610 @@ impl Request {
         }
     }
 
+    // .PlanMigrationRequest plan_migration = 13;
+
+    pub fn plan_migration(&self) -> &PlanMigrationRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::PlanMigration(ref v)) => v,
+            _ => <PlanMigrationRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_plan_migration(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_plan_migration(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::PlanMigration(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_plan_migration(&mut self, v: PlanMigrationRequest) {
+        self.data = ::std::option::Option::Some(request::Data::PlanMigration(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_plan_migration(&mut self) -> &mut PlanMigrationRequest {
+        if let ::std::option::Option::Some(request::Data::PlanMigration(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::PlanMigration(PlanMigrationRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::PlanMigration(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_plan_migration(&mut self) -> PlanMigrationRequest {
+        if self.has_plan_migration() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::PlanMigration(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PlanMigrationRequest::new()
+        }
+    }
+
+    // .GetSequenceRequest get_sequence = 14;
+
+    pub fn get_sequence(&self) -> &GetSequenceRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::GetSequence(ref v)) => v,
+            _ => <GetSequenceRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_get_sequence(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_get_sequence(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::GetSequence(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_get_sequence(&mut self, v: GetSequenceRequest) {
+        self.data = ::std::option::Option::Some(request::Data::GetSequence(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_get_sequence(&mut self) -> &mut GetSequenceRequest {
+        if let ::std::option::Option::Some(request::Data::GetSequence(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::GetSequence(GetSequenceRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::GetSequence(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_get_sequence(&mut self) -> GetSequenceRequest {
+        if self.has_get_sequence() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::GetSequence(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GetSequenceRequest::new()
+        }
+    }
+
+    // .AcquireLeaseRequest acquire_lease = 15;
+
+    pub fn acquire_lease(&self) -> &AcquireLeaseRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::AcquireLease(ref v)) => v,
+            _ => <AcquireLeaseRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_acquire_lease(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_acquire_lease(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::AcquireLease(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_acquire_lease(&mut self, v: AcquireLeaseRequest) {
+        self.data = ::std::option::Option::Some(request::Data::AcquireLease(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_acquire_lease(&mut self) -> &mut AcquireLeaseRequest {
+        if let ::std::option::Option::Some(request::Data::AcquireLease(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::AcquireLease(AcquireLeaseRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::AcquireLease(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_acquire_lease(&mut self) -> AcquireLeaseRequest {
+        if self.has_acquire_lease() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::AcquireLease(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AcquireLeaseRequest::new()
+        }
+    }
+
+    // .RenewLeaseRequest renew_lease = 16;
+
+    pub fn renew_lease(&self) -> &RenewLeaseRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RenewLease(ref v)) => v,
+            _ => <RenewLeaseRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_renew_lease(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_renew_lease(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RenewLease(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_renew_lease(&mut self, v: RenewLeaseRequest) {
+        self.data = ::std::option::Option::Some(request::Data::RenewLease(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_renew_lease(&mut self) -> &mut RenewLeaseRequest {
+        if let ::std::option::Option::Some(request::Data::RenewLease(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::RenewLease(RenewLeaseRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::RenewLease(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_renew_lease(&mut self) -> RenewLeaseRequest {
+        if self.has_renew_lease() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::RenewLease(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RenewLeaseRequest::new()
+        }
+    }
+
+    // .ReleaseLeaseRequest release_lease = 17;
+
+    pub fn release_lease(&self) -> &ReleaseLeaseRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::ReleaseLease(ref v)) => v,
+            _ => <ReleaseLeaseRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_release_lease(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_release_lease(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::ReleaseLease(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_release_lease(&mut self, v: ReleaseLeaseRequest) {
+        self.data = ::std::option::Option::Some(request::Data::ReleaseLease(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_release_lease(&mut self) -> &mut ReleaseLeaseRequest {
+        if let ::std::option::Option::Some(request::Data::ReleaseLease(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::ReleaseLease(ReleaseLeaseRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::ReleaseLease(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_release_lease(&mut self) -> ReleaseLeaseRequest {
+        if self.has_release_lease() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::ReleaseLease(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReleaseLeaseRequest::new()
+        }
+    }
+
+    // .RowCountRequest row_count = 18;
+
+    pub fn row_count(&self) -> &RowCountRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RowCount(ref v)) => v,
+            _ => <RowCountRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_row_count(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_row_count(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RowCount(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row_count(&mut self, v: RowCountRequest) {
+        self.data = ::std::option::Option::Some(request::Data::RowCount(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_row_count(&mut self) -> &mut RowCountRequest {
+        if let ::std::option::Option::Some(request::Data::RowCount(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::RowCount(RowCountRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::RowCount(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_row_count(&mut self) -> RowCountRequest {
+        if self.has_row_count() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::RowCount(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RowCountRequest::new()
+        }
+    }
+
+    // .DescribeTableRequest describe_table = 19;
+
+    pub fn describe_table(&self) -> &DescribeTableRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::DescribeTable(ref v)) => v,
+            _ => <DescribeTableRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_describe_table(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_describe_table(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::DescribeTable(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_describe_table(&mut self, v: DescribeTableRequest) {
+        self.data = ::std::option::Option::Some(request::Data::DescribeTable(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_describe_table(&mut self) -> &mut DescribeTableRequest {
+        if let ::std::option::Option::Some(request::Data::DescribeTable(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::DescribeTable(DescribeTableRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::DescribeTable(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_describe_table(&mut self) -> DescribeTableRequest {
+        if self.has_describe_table() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::DescribeTable(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DescribeTableRequest::new()
+        }
+    }
+
+    // .HealthRequest health = 20;
+
+    pub fn health(&self) -> &HealthRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Health(ref v)) => v,
+            _ => <HealthRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_health(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Health(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: HealthRequest) {
+        self.data = ::std::option::Option::Some(request::Data::Health(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_health(&mut self) -> &mut HealthRequest {
+        if let ::std::option::Option::Some(request::Data::Health(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::Health(HealthRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::Health(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_health(&mut self) -> HealthRequest {
+        if self.has_health() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::Health(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            HealthRequest::new()
+        }
+    }
+
+    // .ReadyRequest ready = 21;
+
+    pub fn ready(&self) -> &ReadyRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Ready(ref v)) => v,
+            _ => <ReadyRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_ready(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_ready(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Ready(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready(&mut self, v: ReadyRequest) {
+        self.data = ::std::option::Option::Some(request::Data::Ready(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ready(&mut self) -> &mut ReadyRequest {
+        if let ::std::option::Option::Some(request::Data::Ready(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::Ready(ReadyRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::Ready(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ready(&mut self) -> ReadyRequest {
+        if self.has_ready() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::Ready(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReadyRequest::new()
+        }
+    }
+
+    // .PartitionMapRequest partition_map = 22;
+
+    pub fn partition_map(&self) -> &PartitionMapRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::PartitionMap(ref v)) => v,
+            _ => <PartitionMapRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_partition_map(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_partition_map(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::PartitionMap(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_partition_map(&mut self, v: PartitionMapRequest) {
+        self.data = ::std::option::Option::Some(request::Data::PartitionMap(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_partition_map(&mut self) -> &mut PartitionMapRequest {
+        if let ::std::option::Option::Some(request::Data::PartitionMap(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::PartitionMap(PartitionMapRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::PartitionMap(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_partition_map(&mut self) -> PartitionMapRequest {
+        if self.has_partition_map() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::PartitionMap(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PartitionMapRequest::new()
+        }
+    }
+
+    // .MigrateBucketRequest migrate_bucket = 23;
+
+    pub fn migrate_bucket(&self) -> &MigrateBucketRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::MigrateBucket(ref v)) => v,
+            _ => <MigrateBucketRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_migrate_bucket(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_migrate_bucket(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::MigrateBucket(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_migrate_bucket(&mut self, v: MigrateBucketRequest) {
+        self.data = ::std::option::Option::Some(request::Data::MigrateBucket(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_migrate_bucket(&mut self) -> &mut MigrateBucketRequest {
+        if let ::std::option::Option::Some(request::Data::MigrateBucket(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::MigrateBucket(MigrateBucketRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::MigrateBucket(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_migrate_bucket(&mut self) -> MigrateBucketRequest {
+        if self.has_migrate_bucket() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::MigrateBucket(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MigrateBucketRequest::new()
+        }
+    }
+
+    // .KeyDistributionRequest key_distribution = 24;
+
+    pub fn key_distribution(&self) -> &KeyDistributionRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::KeyDistribution(ref v)) => v,
+            _ => <KeyDistributionRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_key_distribution(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_key_distribution(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::KeyDistribution(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_distribution(&mut self, v: KeyDistributionRequest) {
+        self.data = ::std::option::Option::Some(request::Data::KeyDistribution(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_distribution(&mut self) -> &mut KeyDistributionRequest {
+        if let ::std::option::Option::Some(request::Data::KeyDistribution(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::KeyDistribution(KeyDistributionRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::KeyDistribution(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_distribution(&mut self) -> KeyDistributionRequest {
+        if self.has_key_distribution() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::KeyDistribution(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyDistributionRequest::new()
+        }
+    }
+
+    // .ListTransactionsRequest list_transactions = 25;
+
+    pub fn list_transactions(&self) -> &ListTransactionsRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::ListTransactions(ref v)) => v,
+            _ => <ListTransactionsRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_list_transactions(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_list_transactions(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::ListTransactions(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_transactions(&mut self, v: ListTransactionsRequest) {
+        self.data = ::std::option::Option::Some(request::Data::ListTransactions(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_transactions(&mut self) -> &mut ListTransactionsRequest {
+        if let ::std::option::Option::Some(request::Data::ListTransactions(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::ListTransactions(ListTransactionsRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::ListTransactions(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_transactions(&mut self) -> ListTransactionsRequest {
+        if self.has_list_transactions() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::ListTransactions(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListTransactionsRequest::new()
+        }
+    }
+
+    // .KillTransactionRequest kill_transaction = 26;
+
+    pub fn kill_transaction(&self) -> &KillTransactionRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::KillTransaction(ref v)) => v,
+            _ => <KillTransactionRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_kill_transaction(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_kill_transaction(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::KillTransaction(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kill_transaction(&mut self, v: KillTransactionRequest) {
+        self.data = ::std::option::Option::Some(request::Data::KillTransaction(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_kill_transaction(&mut self) -> &mut KillTransactionRequest {
+        if let ::std::option::Option::Some(request::Data::KillTransaction(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::KillTransaction(KillTransactionRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::KillTransaction(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_kill_transaction(&mut self) -> KillTransactionRequest {
+        if self.has_kill_transaction() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::KillTransaction(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KillTransactionRequest::new()
+        }
+    }
+
+    // .SetSessionRequest set_session = 27;
+
+    pub fn set_session(&self) -> &SetSessionRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::SetSession(ref v)) => v,
+            _ => <SetSessionRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_set_session(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_set_session(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::SetSession(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set_session(&mut self, v: SetSessionRequest) {
+        self.data = ::std::option::Option::Some(request::Data::SetSession(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_set_session(&mut self) -> &mut SetSessionRequest {
+        if let ::std::option::Option::Some(request::Data::SetSession(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::SetSession(SetSessionRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::SetSession(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_set_session(&mut self) -> SetSessionRequest {
+        if self.has_set_session() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::SetSession(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetSessionRequest::new()
+        }
+    }
+
+    // .SetReadOnlyRequest set_read_only = 28;
+
+    pub fn set_read_only(&self) -> &SetReadOnlyRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::SetReadOnly(ref v)) => v,
+            _ => <SetReadOnlyRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_set_read_only(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_set_read_only(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::SetReadOnly(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set_read_only(&mut self, v: SetReadOnlyRequest) {
+        self.data = ::std::option::Option::Some(request::Data::SetReadOnly(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_set_read_only(&mut self) -> &mut SetReadOnlyRequest {
+        if let ::std::option::Option::Some(request::Data::SetReadOnly(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::SetReadOnly(SetReadOnlyRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::SetReadOnly(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_set_read_only(&mut self) -> SetReadOnlyRequest {
+        if self.has_set_read_only() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::SetReadOnly(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetReadOnlyRequest::new()
+        }
+    }
+
+    // .CompactionRequest compaction = 30;
+
+    pub fn compaction(&self) -> &CompactionRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Compaction(ref v)) => v,
+            _ => <CompactionRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_compaction(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_compaction(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Compaction(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compaction(&mut self, v: CompactionRequest) {
+        self.data = ::std::option::Option::Some(request::Data::Compaction(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_compaction(&mut self) -> &mut CompactionRequest {
+        if let ::std::option::Option::Some(request::Data::Compaction(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::Compaction(CompactionRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::Compaction(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_compaction(&mut self) -> CompactionRequest {
+        if self.has_compaction() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::Compaction(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CompactionRequest::new()
+        }
+    }
+
+    // .SyncModelsRequest sync_models = 32;
+
+    pub fn sync_models(&self) -> &SyncModelsRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::SyncModels(ref v)) => v,
+            _ => <SyncModelsRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_sync_models(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_sync_models(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::SyncModels(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sync_models(&mut self, v: SyncModelsRequest) {
+        self.data = ::std::option::Option::Some(request::Data::SyncModels(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sync_models(&mut self) -> &mut SyncModelsRequest {
+        if let ::std::option::Option::Some(request::Data::SyncModels(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::SyncModels(SyncModelsRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::SyncModels(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sync_models(&mut self) -> SyncModelsRequest {
+        if self.has_sync_models() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::SyncModels(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SyncModelsRequest::new()
+        }
+    }
+
+    // .DescribeServerRequest describe_server = 34;
+
+    pub fn describe_server(&self) -> &DescribeServerRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::DescribeServer(ref v)) => v,
+            _ => <DescribeServerRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_describe_server(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_describe_server(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::DescribeServer(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_describe_server(&mut self, v: DescribeServerRequest) {
+        self.data = ::std::option::Option::Some(request::Data::DescribeServer(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_describe_server(&mut self) -> &mut DescribeServerRequest {
+        if let ::std::option::Option::Some(request::Data::DescribeServer(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::DescribeServer(DescribeServerRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::DescribeServer(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_describe_server(&mut self) -> DescribeServerRequest {
+        if self.has_describe_server() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::DescribeServer(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DescribeServerRequest::new()
+        }
+    }
+
+    // .PartitionLoadRequest partition_load = 36;
+
+    pub fn partition_load(&self) -> &PartitionLoadRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::PartitionLoad(ref v)) => v,
+            _ => <PartitionLoadRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_partition_load(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_partition_load(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::PartitionLoad(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_partition_load(&mut self, v: PartitionLoadRequest) {
+        self.data = ::std::option::Option::Some(request::Data::PartitionLoad(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_partition_load(&mut self) -> &mut PartitionLoadRequest {
+        if let ::std::option::Option::Some(request::Data::PartitionLoad(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::PartitionLoad(PartitionLoadRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::PartitionLoad(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_partition_load(&mut self) -> PartitionLoadRequest {
+        if self.has_partition_load() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::PartitionLoad(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PartitionLoadRequest::new()
+        }
+    }
+
+    // .QueryByIndexRequest query_by_index = 39;
+
+    pub fn query_by_index(&self) -> &QueryByIndexRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::QueryByIndex(ref v)) => v,
+            _ => <QueryByIndexRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_query_by_index(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_query_by_index(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::QueryByIndex(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query_by_index(&mut self, v: QueryByIndexRequest) {
+        self.data = ::std::option::Option::Some(request::Data::QueryByIndex(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_query_by_index(&mut self) -> &mut QueryByIndexRequest {
+        if let ::std::option::Option::Some(request::Data::QueryByIndex(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::QueryByIndex(QueryByIndexRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::QueryByIndex(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_query_by_index(&mut self) -> QueryByIndexRequest {
+        if self.has_query_by_index() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::QueryByIndex(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryByIndexRequest::new()
+        }
+    }
+
+    // .TableAccessRequest table_access = 40;
+
+    pub fn table_access(&self) -> &TableAccessRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::TableAccess(ref v)) => v,
+            _ => <TableAccessRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_table_access(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_table_access(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::TableAccess(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_table_access(&mut self, v: TableAccessRequest) {
+        self.data = ::std::option::Option::Some(request::Data::TableAccess(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_table_access(&mut self) -> &mut TableAccessRequest {
+        if let ::std::option::Option::Some(request::Data::TableAccess(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::TableAccess(TableAccessRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::TableAccess(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_table_access(&mut self) -> TableAccessRequest {
+        if self.has_table_access() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::TableAccess(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TableAccessRequest::new()
+        }
+    }
+
+    // .DeleteWhereRequest delete_where = 41;
+
+    pub fn delete_where(&self) -> &DeleteWhereRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::DeleteWhere(ref v)) => v,
+            _ => <DeleteWhereRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_delete_where(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_delete_where(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::DeleteWhere(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete_where(&mut self, v: DeleteWhereRequest) {
+        self.data = ::std::option::Option::Some(request::Data::DeleteWhere(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_delete_where(&mut self) -> &mut DeleteWhereRequest {
+        if let ::std::option::Option::Some(request::Data::DeleteWhere(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::DeleteWhere(DeleteWhereRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::DeleteWhere(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_delete_where(&mut self) -> DeleteWhereRequest {
+        if self.has_delete_where() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::DeleteWhere(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DeleteWhereRequest::new()
+        }
+    }
+
+    // .MoveRowRequest move_row = 42;
+
+    pub fn move_row(&self) -> &MoveRowRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::MoveRow(ref v)) => v,
+            _ => <MoveRowRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_move_row(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_move_row(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::MoveRow(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_move_row(&mut self, v: MoveRowRequest) {
+        self.data = ::std::option::Option::Some(request::Data::MoveRow(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_move_row(&mut self) -> &mut MoveRowRequest {
+        if let ::std::option::Option::Some(request::Data::MoveRow(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::MoveRow(MoveRowRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::MoveRow(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_move_row(&mut self) -> MoveRowRequest {
+        if self.has_move_row() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::MoveRow(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MoveRowRequest::new()
+        }
+    }
+
+    // .SaveRequest save = 43;
+
+    pub fn save(&self) -> &SaveRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Save(ref v)) => v,
+            _ => <SaveRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_save(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_save(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Save(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_save(&mut self, v: SaveRequest) {
+        self.data = ::std::option::Option::Some(request::Data::Save(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_save(&mut self) -> &mut SaveRequest {
+        if let ::std::option::Option::Some(request::Data::Save(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::Save(SaveRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::Save(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_save(&mut self) -> SaveRequest {
+        if self.has_save() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::Save(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SaveRequest::new()
+        }
+    }
+
+    // .ReplicateRequest replicate = 44;
+
+    pub fn replicate(&self) -> &ReplicateRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Replicate(ref v)) => v,
+            _ => <ReplicateRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_replicate(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_replicate(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Replicate(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replicate(&mut self, v: ReplicateRequest) {
+        self.data = ::std::option::Option::Some(request::Data::Replicate(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_replicate(&mut self) -> &mut ReplicateRequest {
+        if let ::std::option::Option::Some(request::Data::Replicate(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::Replicate(ReplicateRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::Replicate(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_replicate(&mut self) -> ReplicateRequest {
+        if self.has_replicate() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::Replicate(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReplicateRequest::new()
+        }
+    }
+
+    // .PromoteRequest promote = 45;
+
+    pub fn promote(&self) -> &PromoteRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Promote(ref v)) => v,
+            _ => <PromoteRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_promote(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_promote(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::Promote(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_promote(&mut self, v: PromoteRequest) {
+        self.data = ::std::option::Option::Some(request::Data::Promote(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_promote(&mut self) -> &mut PromoteRequest {
+        if let ::std::option::Option::Some(request::Data::Promote(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::Promote(PromoteRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::Promote(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_promote(&mut self) -> PromoteRequest {
+        if self.has_promote() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::Promote(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PromoteRequest::new()
+        }
+    }
+
+    // .RaftVoteRequest raft_vote = 46;
+
+    pub fn raft_vote(&self) -> &RaftVoteRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RaftVote(ref v)) => v,
+            _ => <RaftVoteRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_raft_vote(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_raft_vote(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RaftVote(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raft_vote(&mut self, v: RaftVoteRequest) {
+        self.data = ::std::option::Option::Some(request::Data::RaftVote(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_raft_vote(&mut self) -> &mut RaftVoteRequest {
+        if let ::std::option::Option::Some(request::Data::RaftVote(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::RaftVote(RaftVoteRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::RaftVote(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_raft_vote(&mut self) -> RaftVoteRequest {
+        if self.has_raft_vote() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::RaftVote(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RaftVoteRequest::new()
+        }
+    }
+
+    // .RaftAppendRequest raft_append = 47;
+
+    pub fn raft_append(&self) -> &RaftAppendRequest {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RaftAppend(ref v)) => v,
+            _ => <RaftAppendRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_raft_append(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_raft_append(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(request::Data::RaftAppend(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raft_append(&mut self, v: RaftAppendRequest) {
+        self.data = ::std::option::Option::Some(request::Data::RaftAppend(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_raft_append(&mut self) -> &mut RaftAppendRequest {
+        if let ::std::option::Option::Some(request::Data::RaftAppend(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(request::Data::RaftAppend(RaftAppendRequest::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(request::Data::RaftAppend(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_raft_append(&mut self) -> RaftAppendRequest {
+        if self.has_raft_append() {
+            match self.data.take() {
+                ::std::option::Option::Some(request::Data::RaftAppend(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RaftAppendRequest::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(47);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetRequest>(
+            "get",
+            Request::has_get,
+            Request::get,
+            Request::mut_get,
+            Request::set_get,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, InsertRequest>(
+            "insert",
+            Request::has_insert,
+            Request::insert,
+            Request::mut_insert,
+            Request::set_insert,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteRequest>(
+            "delete",
+            Request::has_delete,
+            Request::delete,
+            Request::mut_delete,
+            Request::set_delete,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetManyRequest>(
+            "get_many",
+            Request::has_get_many,
+            Request::get_many,
+            Request::mut_get_many,
+            Request::set_get_many,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, BatchRequest>(
+            "batch",
+            Request::has_batch,
+            Request::batch,
+            Request::mut_batch,
+            Request::set_batch,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, BeginTransaction>(
+            "begin_transaction",
+            Request::has_begin_transaction,
+            Request::begin_transaction,
+            Request::mut_begin_transaction,
+            Request::set_begin_transaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, CommitTransaction>(
+            "commit_transaction",
+            Request::has_commit_transaction,
+            Request::commit_transaction,
+            Request::mut_commit_transaction,
+            Request::set_commit_transaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AbortTransaction>(
+            "abort_transaction",
+            Request::has_abort_transaction,
+            Request::abort_transaction,
+            Request::mut_abort_transaction,
+            Request::set_abort_transaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SyncModelRequest>(
+            "sync_model",
+            Request::has_sync_model,
+            Request::sync_model,
+            Request::mut_sync_model,
+            Request::set_sync_model,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DropTableRequest>(
+            "drop_table",
+            Request::has_drop_table,
+            Request::drop_table,
+            Request::mut_drop_table,
+            Request::set_drop_table,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PlanMigrationRequest>(
+            "plan_migration",
+            Request::has_plan_migration,
+            Request::plan_migration,
+            Request::mut_plan_migration,
+            Request::set_plan_migration,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetSequenceRequest>(
+            "get_sequence",
+            Request::has_get_sequence,
+            Request::get_sequence,
+            Request::mut_get_sequence,
+            Request::set_get_sequence,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AcquireLeaseRequest>(
+            "acquire_lease",
+            Request::has_acquire_lease,
+            Request::acquire_lease,
+            Request::mut_acquire_lease,
+            Request::set_acquire_lease,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RenewLeaseRequest>(
+            "renew_lease",
+            Request::has_renew_lease,
+            Request::renew_lease,
+            Request::mut_renew_lease,
+            Request::set_renew_lease,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ReleaseLeaseRequest>(
+            "release_lease",
+            Request::has_release_lease,
+            Request::release_lease,
+            Request::mut_release_lease,
+            Request::set_release_lease,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RowCountRequest>(
+            "row_count",
+            Request::has_row_count,
+            Request::row_count,
+            Request::mut_row_count,
+            Request::set_row_count,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DescribeTableRequest>(
+            "describe_table",
+            Request::has_describe_table,
+            Request::describe_table,
+            Request::mut_describe_table,
+            Request::set_describe_table,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, HealthRequest>(
+            "health",
+            Request::has_health,
+            Request::health,
+            Request::mut_health,
+            Request::set_health,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ReadyRequest>(
+            "ready",
+            Request::has_ready,
+            Request::ready,
+            Request::mut_ready,
+            Request::set_ready,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PartitionMapRequest>(
+            "partition_map",
+            Request::has_partition_map,
+            Request::partition_map,
+            Request::mut_partition_map,
+            Request::set_partition_map,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MigrateBucketRequest>(
+            "migrate_bucket",
+            Request::has_migrate_bucket,
+            Request::migrate_bucket,
+            Request::mut_migrate_bucket,
+            Request::set_migrate_bucket,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, KeyDistributionRequest>(
+            "key_distribution",
+            Request::has_key_distribution,
+            Request::key_distribution,
+            Request::mut_key_distribution,
+            Request::set_key_distribution,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ListTransactionsRequest>(
+            "list_transactions",
+            Request::has_list_transactions,
+            Request::list_transactions,
+            Request::mut_list_transactions,
+            Request::set_list_transactions,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, KillTransactionRequest>(
+            "kill_transaction",
+            Request::has_kill_transaction,
+            Request::kill_transaction,
+            Request::mut_kill_transaction,
+            Request::set_kill_transaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SetSessionRequest>(
+            "set_session",
+            Request::has_set_session,
+            Request::set_session,
+            Request::mut_set_session,
+            Request::set_set_session,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SetReadOnlyRequest>(
+            "set_read_only",
+            Request::has_set_read_only,
+            Request::set_read_only,
+            Request::mut_set_read_only,
+            Request::set_set_read_only,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, CompactionRequest>(
+            "compaction",
+            Request::has_compaction,
+            Request::compaction,
+            Request::mut_compaction,
+            Request::set_compaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SyncModelsRequest>(
+            "sync_models",
+            Request::has_sync_models,
+            Request::sync_models,
+            Request::mut_sync_models,
+            Request::set_sync_models,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DescribeServerRequest>(
+            "describe_server",
+            Request::has_describe_server,
+            Request::describe_server,
+            Request::mut_describe_server,
+            Request::set_describe_server,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PartitionLoadRequest>(
+            "partition_load",
+            Request::has_partition_load,
+            Request::partition_load,
+            Request::mut_partition_load,
+            Request::set_partition_load,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, QueryByIndexRequest>(
+            "query_by_index",
+            Request::has_query_by_index,
+            Request::query_by_index,
+            Request::mut_query_by_index,
+            Request::set_query_by_index,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TableAccessRequest>(
+            "table_access",
+            Request::has_table_access,
+            Request::table_access,
+            Request::mut_table_access,
+            Request::set_table_access,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteWhereRequest>(
+            "delete_where",
+            Request::has_delete_where,
+            Request::delete_where,
+            Request::mut_delete_where,
+            Request::set_delete_where,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MoveRowRequest>(
+            "move_row",
+            Request::has_move_row,
+            Request::move_row,
+            Request::mut_move_row,
+            Request::set_move_row,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SaveRequest>(
+            "save",
+            Request::has_save,
+            Request::save,
+            Request::mut_save,
+            Request::set_save,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ReplicateRequest>(
+            "replicate",
+            Request::has_replicate,
+            Request::replicate,
+            Request::mut_replicate,
+            Request::set_replicate,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PromoteRequest>(
+            "promote",
+            Request::has_promote,
+            Request::promote,
+            Request::mut_promote,
+            Request::set_promote,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RaftVoteRequest>(
+            "raft_vote",
+            Request::has_raft_vote,
+            Request::raft_vote,
+            Request::mut_raft_vote,
+            Request::set_raft_vote,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RaftAppendRequest>(
+            "raft_append",
+            Request::has_raft_append,
+            Request::raft_append,
+            Request::mut_raft_append,
+            Request::set_raft_append,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "transaction_id",
+            |m: &Request| { &m.transaction_id },
+            |m: &mut Request| { &mut m.transaction_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table",
+            |m: &Request| { &m.table },
+            |m: &mut Request| { &mut m.table },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "database",
+            |m: &Request| { &m.database },
+            |m: &mut Request| { &mut m.database },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "min_consistency_token",
+            |m: &Request| { &m.min_consistency_token },
+            |m: &mut Request| { &mut m.min_consistency_token },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "explain",
+            |m: &Request| { &m.explain },
+            |m: &mut Request| { &mut m.explain },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "begins_transaction",
+            |m: &Request| { &m.begins_transaction },
+            |m: &mut Request| { &mut m.begins_transaction },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "schema_version",
+            |m: &Request| { &m.schema_version },
+            |m: &mut Request| { &mut m.schema_version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "trace_context",
+            |m: &Request| { &m.trace_context },
+            |m: &mut Request| { &mut m.trace_context },
+        ));
+        oneofs.push(request::Data::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Request>(
+            "Request",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Request {
+    const NAME: &'static str = "Request";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.data = ::std::option::Option::Some(request::Data::Get(is.read_message()?));
+                },
+                18 => {
+                    self.data = ::std::option::Option::Some(request::Data::Insert(is.read_message()?));
+                },
+                26 => {
+                    self.data = ::std::option::Option::Some(request::Data::Delete(is.read_message()?));
+                },
+                34 => {
+                    self.data = ::std::option::Option::Some(request::Data::GetMany(is.read_message()?));
+                },
+                42 => {
+                    self.data = ::std::option::Option::Some(request::Data::Batch(is.read_message()?));
+                },
+                50 => {
+                    self.data = ::std::option::Option::Some(request::Data::BeginTransaction(is.read_message()?));
+                },
+                58 => {
+                    self.data = ::std::option::Option::Some(request::Data::CommitTransaction(is.read_message()?));
+                },
+                66 => {
+                    self.data = ::std::option::Option::Some(request::Data::AbortTransaction(is.read_message()?));
+                },
+                74 => {
+                    self.data = ::std::option::Option::Some(request::Data::SyncModel(is.read_message()?));
+                },
+                82 => {
+                    self.data = ::std::option::Option::Some(request::Data::DropTable(is.read_message()?));
+                },
+                106 => {
+                    self.data = ::std::option::Option::Some(request::Data::PlanMigration(is.read_message()?));
+                },
+                114 => {
+                    self.data = ::std::option::Option::Some(request::Data::GetSequence(is.read_message()?));
+                },
+                122 => {
+                    self.data = ::std::option::Option::Some(request::Data::AcquireLease(is.read_message()?));
+                },
+                130 => {
+                    self.data = ::std::option::Option::Some(request::Data::RenewLease(is.read_message()?));
+                },
+                138 => {
+                    self.data = ::std::option::Option::Some(request::Data::ReleaseLease(is.read_message()?));
+                },
+                146 => {
+                    self.data = ::std::option::Option::Some(request::Data::RowCount(is.read_message()?));
+                },
+                154 => {
+                    self.data = ::std::option::Option::Some(request::Data::DescribeTable(is.read_message()?));
+                },
+                162 => {
+                    self.data = ::std::option::Option::Some(request::Data::Health(is.read_message()?));
+                },
+                170 => {
+                    self.data = ::std::option::Option::Some(request::Data::Ready(is.read_message()?));
+                },
+                178 => {
+                    self.data = ::std::option::Option::Some(request::Data::PartitionMap(is.read_message()?));
+                },
+                186 => {
+                    self.data = ::std::option::Option::Some(request::Data::MigrateBucket(is.read_message()?));
+                },
+                194 => {
+                    self.data = ::std::option::Option::Some(request::Data::KeyDistribution(is.read_message()?));
+                },
+                202 => {
+                    self.data = ::std::option::Option::Some(request::Data::ListTransactions(is.read_message()?));
+                },
+                210 => {
+                    self.data = ::std::option::Option::Some(request::Data::KillTransaction(is.read_message()?));
+                },
+                218 => {
+                    self.data = ::std::option::Option::Some(request::Data::SetSession(is.read_message()?));
+                },
+                226 => {
+                    self.data = ::std::option::Option::Some(request::Data::SetReadOnly(is.read_message()?));
+                },
+                242 => {
+                    self.data = ::std::option::Option::Some(request::Data::Compaction(is.read_message()?));
+                },
+                258 => {
+                    self.data = ::std::option::Option::Some(request::Data::SyncModels(is.read_message()?));
+                },
+                274 => {
+                    self.data = ::std::option::Option::Some(request::Data::DescribeServer(is.read_message()?));
+                },
+                290 => {
+                    self.data = ::std::option::Option::Some(request::Data::PartitionLoad(is.read_message()?));
+                },
+                314 => {
+                    self.data = ::std::option::Option::Some(request::Data::QueryByIndex(is.read_message()?));
+                },
+                322 => {
+                    self.data = ::std::option::Option::Some(request::Data::TableAccess(is.read_message()?));
+                },
+                330 => {
+                    self.data = ::std::option::Option::Some(request::Data::DeleteWhere(is.read_message()?));
+                },
+                338 => {
+                    self.data = ::std::option::Option::Some(request::Data::MoveRow(is.read_message()?));
+                },
+                346 => {
+                    self.data = ::std::option::Option::Some(request::Data::Save(is.read_message()?));
+                },
+                354 => {
+                    self.data = ::std::option::Option::Some(request::Data::Replicate(is.read_message()?));
+                },
+                362 => {
+                    self.data = ::std::option::Option::Some(request::Data::Promote(is.read_message()?));
+                },
+                370 => {
+                    self.data = ::std::option::Option::Some(request::Data::RaftVote(is.read_message()?));
+                },
+                378 => {
+                    self.data = ::std::option::Option::Some(request::Data::RaftAppend(is.read_message()?));
+                },
+                88 => {
+                    self.transaction_id = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                98 => {
+                    self.table = is.read_string()?;
+                },
+                234 => {
+                    self.database = is.read_string()?;
+                },
+                250 => {
+                    self.min_consistency_token = is.read_string()?;
+                },
+                264 => {
+                    self.explain = is.read_bool()?;
+                },
+                280 => {
+                    self.begins_transaction = is.read_bool()?;
+                },
+                296 => {
+                    self.schema_version = is.read_uint64()?;
+                },
+                306 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.trace_context.insert(key, value);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.transaction_id {
+            my_size += ::protobuf::rt::uint64_size(11, v);
+        }
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.table);
+        }
+        if !self.database.is_empty() {
+            my_size += ::protobuf::rt::string_size(29, &self.database);
+        }
+        if !self.min_consistency_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(31, &self.min_consistency_token);
+        }
+        if self.explain != false {
+            my_size += 2 + 1;
+        }
+        if self.begins_transaction != false {
+            my_size += 2 + 1;
+        }
+        if self.schema_version != 0 {
+            my_size += ::protobuf::rt::uint64_size(37, self.schema_version);
+        }
+        for (k, v) in &self.trace_context {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &request::Data::Get(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Insert(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Delete(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::GetMany(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Batch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::BeginTransaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::CommitTransaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::AbortTransaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::SyncModel(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::DropTable(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::PlanMigration(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::GetSequence(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::AcquireLease(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::RenewLease(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::ReleaseLease(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::RowCount(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::DescribeTable(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Health(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Ready(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::PartitionMap(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::MigrateBucket(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::KeyDistribution(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::ListTransactions(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::KillTransaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::SetSession(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::SetReadOnly(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Compaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::SyncModels(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::DescribeServer(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::PartitionLoad(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::QueryByIndex(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::TableAccess(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::DeleteWhere(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::MoveRow(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Save(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Replicate(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::Promote(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::RaftVote(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &request::Data::RaftAppend(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.transaction_id {
+            os.write_uint64(11, v)?;
+        }
+        if !self.table.is_empty() {
+            os.write_string(12, &self.table)?;
+        }
+        if !self.database.is_empty() {
+            os.write_string(29, &self.database)?;
+        }
+        if !self.min_consistency_token.is_empty() {
+            os.write_string(31, &self.min_consistency_token)?;
+        }
+        if self.explain != false {
+            os.write_bool(33, self.explain)?;
+        }
+        if self.begins_transaction != false {
+            os.write_bool(35, self.begins_transaction)?;
+        }
+        if self.schema_version != 0 {
+            os.write_uint64(37, self.schema_version)?;
+        }
+        for (k, v) in &self.trace_context {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(306)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &request::Data::Get(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &request::Data::Insert(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &request::Data::Delete(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &request::Data::GetMany(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &request::Data::Batch(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &request::Data::BeginTransaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &request::Data::CommitTransaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+                &request::Data::AbortTransaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+                },
+                &request::Data::SyncModel(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+                },
+                &request::Data::DropTable(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+                },
+                &request::Data::PlanMigration(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+                },
+                &request::Data::GetSequence(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+                },
+                &request::Data::AcquireLease(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(15, v, os)?;
+                },
+                &request::Data::RenewLease(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(16, v, os)?;
+                },
+                &request::Data::ReleaseLease(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(17, v, os)?;
+                },
+                &request::Data::RowCount(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
+                },
+                &request::Data::DescribeTable(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(19, v, os)?;
+                },
+                &request::Data::Health(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(20, v, os)?;
+                },
+                &request::Data::Ready(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(21, v, os)?;
+                },
+                &request::Data::PartitionMap(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(22, v, os)?;
+                },
+                &request::Data::MigrateBucket(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(23, v, os)?;
+                },
+                &request::Data::KeyDistribution(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(24, v, os)?;
+                },
+                &request::Data::ListTransactions(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(25, v, os)?;
+                },
+                &request::Data::KillTransaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(26, v, os)?;
+                },
+                &request::Data::SetSession(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(27, v, os)?;
+                },
+                &request::Data::SetReadOnly(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(28, v, os)?;
+                },
+                &request::Data::Compaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(30, v, os)?;
+                },
+                &request::Data::SyncModels(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(32, v, os)?;
+                },
+                &request::Data::DescribeServer(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(34, v, os)?;
+                },
+                &request::Data::PartitionLoad(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(36, v, os)?;
+                },
+                &request::Data::QueryByIndex(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(39, v, os)?;
+                },
+                &request::Data::TableAccess(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(40, v, os)?;
+                },
+                &request::Data::DeleteWhere(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(41, v, os)?;
+                },
+                &request::Data::MoveRow(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(42, v, os)?;
+                },
+                &request::Data::Save(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(43, v, os)?;
+                },
+                &request::Data::Replicate(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(44, v, os)?;
+                },
+                &request::Data::Promote(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(45, v, os)?;
+                },
+                &request::Data::RaftVote(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(46, v, os)?;
+                },
+                &request::Data::RaftAppend(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(47, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Request {
+        Request::new()
+    }
+
+    fn clear(&mut self) {
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.transaction_id = ::std::option::Option::None;
+        self.table.clear();
+        self.database.clear();
+        self.min_consistency_token.clear();
+        self.explain = false;
+        self.begins_transaction = false;
+        self.schema_version = 0;
+        self.trace_context.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Request {
+        static instance: ::protobuf::rt::Lazy<Request> = ::protobuf::rt::Lazy::new();
+        instance.get(Request::new)
+    }
+}
+
+impl ::protobuf::MessageFull for Request {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Request").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Request {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Request {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Request`
+pub mod request {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:Request.data)
+    pub enum Data {
+        // @@protoc_insertion_point(oneof_field:Request.get)
+        Get(super::GetRequest),
+        // @@protoc_insertion_point(oneof_field:Request.insert)
+        Insert(super::InsertRequest),
+        // @@protoc_insertion_point(oneof_field:Request.delete)
+        Delete(super::DeleteRequest),
+        // @@protoc_insertion_point(oneof_field:Request.get_many)
+        GetMany(super::GetManyRequest),
+        // @@protoc_insertion_point(oneof_field:Request.batch)
+        Batch(super::BatchRequest),
+        // @@protoc_insertion_point(oneof_field:Request.begin_transaction)
+        BeginTransaction(super::BeginTransaction),
+        // @@protoc_insertion_point(oneof_field:Request.commit_transaction)
+        CommitTransaction(super::CommitTransaction),
+        // @@protoc_insertion_point(oneof_field:Request.abort_transaction)
+        AbortTransaction(super::AbortTransaction),
+        // @@protoc_insertion_point(oneof_field:Request.sync_model)
+        SyncModel(super::SyncModelRequest),
+        // @@protoc_insertion_point(oneof_field:Request.drop_table)
+        DropTable(super::DropTableRequest),
+        // @@protoc_insertion_point(oneof_field:Request.plan_migration)
+        PlanMigration(super::PlanMigrationRequest),
+        // @@protoc_insertion_point(oneof_field:Request.get_sequence)
+        GetSequence(super::GetSequenceRequest),
+        // @@protoc_insertion_point(oneof_field:Request.acquire_lease)
+        AcquireLease(super::AcquireLeaseRequest),
+        // @@protoc_insertion_point(oneof_field:Request.renew_lease)
+        RenewLease(super::RenewLeaseRequest),
+        // @@protoc_insertion_point(oneof_field:Request.release_lease)
+        ReleaseLease(super::ReleaseLeaseRequest),
+        // @@protoc_insertion_point(oneof_field:Request.row_count)
+        RowCount(super::RowCountRequest),
+        // @@protoc_insertion_point(oneof_field:Request.describe_table)
+        DescribeTable(super::DescribeTableRequest),
+        // @@protoc_insertion_point(oneof_field:Request.health)
+        Health(super::HealthRequest),
+        // @@protoc_insertion_point(oneof_field:Request.ready)
+        Ready(super::ReadyRequest),
+        // @@protoc_insertion_point(oneof_field:Request.partition_map)
+        PartitionMap(super::PartitionMapRequest),
+        // @@protoc_insertion_point(oneof_field:Request.migrate_bucket)
+        MigrateBucket(super::MigrateBucketRequest),
+        // @@protoc_insertion_point(oneof_field:Request.key_distribution)
+        KeyDistribution(super::KeyDistributionRequest),
+        // @@protoc_insertion_point(oneof_field:Request.list_transactions)
+        ListTransactions(super::ListTransactionsRequest),
+        // @@protoc_insertion_point(oneof_field:Request.kill_transaction)
+        KillTransaction(super::KillTransactionRequest),
+        // @@protoc_insertion_point(oneof_field:Request.set_session)
+        SetSession(super::SetSessionRequest),
+        // @@protoc_insertion_point(oneof_field:Request.set_read_only)
+        SetReadOnly(super::SetReadOnlyRequest),
+        // @@protoc_insertion_point(oneof_field:Request.compaction)
+        Compaction(super::CompactionRequest),
+        // @@protoc_insertion_point(oneof_field:Request.sync_models)
+        SyncModels(super::SyncModelsRequest),
+        // @@protoc_insertion_point(oneof_field:Request.describe_server)
+        DescribeServer(super::DescribeServerRequest),
+        // @@protoc_insertion_point(oneof_field:Request.partition_load)
+        PartitionLoad(super::PartitionLoadRequest),
+        // @@protoc_insertion_point(oneof_field:Request.query_by_index)
+        QueryByIndex(super::QueryByIndexRequest),
+        // @@protoc_insertion_point(oneof_field:Request.table_access)
+        TableAccess(super::TableAccessRequest),
+        // @@protoc_insertion_point(oneof_field:Request.delete_where)
+        DeleteWhere(super::DeleteWhereRequest),
+        // @@protoc_insertion_point(oneof_field:Request.move_row)
+        MoveRow(super::MoveRowRequest),
+        // @@protoc_insertion_point(oneof_field:Request.save)
+        Save(super::SaveRequest),
+        // @@protoc_insertion_point(oneof_field:Request.replicate)
+        Replicate(super::ReplicateRequest),
+        // @@protoc_insertion_point(oneof_field:Request.promote)
+        Promote(super::PromoteRequest),
+        // @@protoc_insertion_point(oneof_field:Request.raft_vote)
+        RaftVote(super::RaftVoteRequest),
+        // @@protoc_insertion_point(oneof_field:Request.raft_append)
+        RaftAppend(super::RaftAppendRequest),
+    }
+
+    impl ::protobuf::Oneof for Data {
+    }
+
+    impl ::protobuf::OneofFull for Data {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Request as ::protobuf::MessageFull>::descriptor().oneof_by_name("data").unwrap()).clone()
+        }
+    }
+
+    impl Data {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Data>("data")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:GetRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetRequest {
+    // message fields
+    // @@protoc_insertion_point(field:GetRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:GetRequest.sort_key)
+    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetRequest {
+    fn default() -> &'a GetRequest {
+        <GetRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetRequest {
+    pub fn new() -> GetRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &GetRequest| { &m.hash_key },
+            |m: &mut GetRequest| { &mut m.hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "sort_key",
+            |m: &GetRequest| { &m.sort_key },
+            |m: &mut GetRequest| { &mut m.sort_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetRequest>(
+            "GetRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetRequest {
+    const NAME: &'static str = "GetRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetRequest {
+        GetRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.sort_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetRequest {
+        static instance: GetRequest = GetRequest {
+            hash_key: ::std::string::String::new(),
+            sort_key: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:InsertRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct InsertRequest {
+    // message fields
+    // @@protoc_insertion_point(field:InsertRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:InsertRequest.sort_key)
+    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:InsertRequest.values)
+    pub values: ::std::collections::HashMap<::std::string::String, super::common::Value>,
+    // @@protoc_insertion_point(field:InsertRequest.column_values)
+    pub column_values: ::std::vec::Vec<super::common::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:InsertRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a InsertRequest {
+    fn default() -> &'a InsertRequest {
+        <InsertRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertRequest {
+    pub fn new() -> InsertRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &InsertRequest| { &m.hash_key },
+            |m: &mut InsertRequest| { &mut m.hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "sort_key",
+            |m: &InsertRequest| { &m.sort_key },
+            |m: &mut InsertRequest| { &mut m.sort_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "values",
+            |m: &InsertRequest| { &m.values },
+            |m: &mut InsertRequest| { &mut m.values },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "column_values",
+            |m: &InsertRequest| { &m.column_values },
+            |m: &mut InsertRequest| { &mut m.column_values },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InsertRequest>(
+            "InsertRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for InsertRequest {
+    const NAME: &'static str = "InsertRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_message()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.values.insert(key, value);
+                },
+                34 => {
+                    self.column_values.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for (k, v) in &self.values {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.compute_size();
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        for value in &self.column_values {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for (k, v) in &self.values {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.cached_size() as u64;
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        for v in &self.column_values {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> InsertRequest {
+        InsertRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.sort_key.clear();
+        self.values.clear();
+        self.column_values.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static InsertRequest {
+        static instance: ::protobuf::rt::Lazy<InsertRequest> = ::protobuf::rt::Lazy::new();
+        instance.get(InsertRequest::new)
+    }
+}
+
+impl ::protobuf::MessageFull for InsertRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InsertRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for InsertRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InsertRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DeleteRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeleteRequest {
+    // message fields
+    // @@protoc_insertion_point(field:DeleteRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:DeleteRequest.sort_key)
+    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeleteRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteRequest {
+    fn default() -> &'a DeleteRequest {
+        <DeleteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteRequest {
+    pub fn new() -> DeleteRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &DeleteRequest| { &m.hash_key },
+            |m: &mut DeleteRequest| { &mut m.hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "sort_key",
+            |m: &DeleteRequest| { &m.sort_key },
+            |m: &mut DeleteRequest| { &mut m.sort_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteRequest>(
+            "DeleteRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteRequest {
+    const NAME: &'static str = "DeleteRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteRequest {
+        DeleteRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.sort_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteRequest {
+        static instance: DeleteRequest = DeleteRequest {
+            hash_key: ::std::string::String::new(),
+            sort_key: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:GetSequenceRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetSequenceRequest {
+    // message fields
+    // @@protoc_insertion_point(field:GetSequenceRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetSequenceRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetSequenceRequest {
+    fn default() -> &'a GetSequenceRequest {
+        <GetSequenceRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetSequenceRequest {
+    pub fn new() -> GetSequenceRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &GetSequenceRequest| { &m.hash_key },
+            |m: &mut GetSequenceRequest| { &mut m.hash_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetSequenceRequest>(
+            "GetSequenceRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetSequenceRequest {
+    const NAME: &'static str = "GetSequenceRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetSequenceRequest {
+        GetSequenceRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetSequenceRequest {
+        static instance: GetSequenceRequest = GetSequenceRequest {
+            hash_key: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetSequenceRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetSequenceRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetSequenceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetSequenceRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:AcquireLeaseRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AcquireLeaseRequest {
+    // message fields
+    // @@protoc_insertion_point(field:AcquireLeaseRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:AcquireLeaseRequest.ttl_millis)
+    pub ttl_millis: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:AcquireLeaseRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AcquireLeaseRequest {
+    fn default() -> &'a AcquireLeaseRequest {
+        <AcquireLeaseRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AcquireLeaseRequest {
+    pub fn new() -> AcquireLeaseRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &AcquireLeaseRequest| { &m.name },
+            |m: &mut AcquireLeaseRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ttl_millis",
+            |m: &AcquireLeaseRequest| { &m.ttl_millis },
+            |m: &mut AcquireLeaseRequest| { &mut m.ttl_millis },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AcquireLeaseRequest>(
+            "AcquireLeaseRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AcquireLeaseRequest {
+    const NAME: &'static str = "AcquireLeaseRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.ttl_millis = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.ttl_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.ttl_millis);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.ttl_millis != 0 {
+            os.write_uint64(2, self.ttl_millis)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AcquireLeaseRequest {
+        AcquireLeaseRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.ttl_millis = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AcquireLeaseRequest {
+        static instance: AcquireLeaseRequest = AcquireLeaseRequest {
+            name: ::std::string::String::new(),
+            ttl_millis: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AcquireLeaseRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AcquireLeaseRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AcquireLeaseRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcquireLeaseRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:RenewLeaseRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RenewLeaseRequest {
+    // message fields
+    // @@protoc_insertion_point(field:RenewLeaseRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:RenewLeaseRequest.version)
+    pub version: u64,
+    // @@protoc_insertion_point(field:RenewLeaseRequest.ttl_millis)
+    pub ttl_millis: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:RenewLeaseRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RenewLeaseRequest {
+    fn default() -> &'a RenewLeaseRequest {
+        <RenewLeaseRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenewLeaseRequest {
+    pub fn new() -> RenewLeaseRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &RenewLeaseRequest| { &m.name },
+            |m: &mut RenewLeaseRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &RenewLeaseRequest| { &m.version },
+            |m: &mut RenewLeaseRequest| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ttl_millis",
+            |m: &RenewLeaseRequest| { &m.ttl_millis },
+            |m: &mut RenewLeaseRequest| { &mut m.ttl_millis },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RenewLeaseRequest>(
+            "RenewLeaseRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RenewLeaseRequest {
+    const NAME: &'static str = "RenewLeaseRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.version = is.read_uint64()?;
+                },
+                24 => {
+                    self.ttl_millis = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.version);
+        }
+        if self.ttl_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.ttl_millis);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(2, self.version)?;
+        }
+        if self.ttl_millis != 0 {
+            os.write_uint64(3, self.ttl_millis)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RenewLeaseRequest {
+        RenewLeaseRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.version = 0;
+        self.ttl_millis = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RenewLeaseRequest {
+        static instance: RenewLeaseRequest = RenewLeaseRequest {
+            name: ::std::string::String::new(),
+            version: 0,
+            ttl_millis: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RenewLeaseRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RenewLeaseRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RenewLeaseRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenewLeaseRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReleaseLeaseRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReleaseLeaseRequest {
+    // message fields
+    // @@protoc_insertion_point(field:ReleaseLeaseRequest.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:ReleaseLeaseRequest.version)
+    pub version: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:ReleaseLeaseRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReleaseLeaseRequest {
+    fn default() -> &'a ReleaseLeaseRequest {
+        <ReleaseLeaseRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReleaseLeaseRequest {
+    pub fn new() -> ReleaseLeaseRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &ReleaseLeaseRequest| { &m.name },
+            |m: &mut ReleaseLeaseRequest| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &ReleaseLeaseRequest| { &m.version },
+            |m: &mut ReleaseLeaseRequest| { &mut m.version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReleaseLeaseRequest>(
+            "ReleaseLeaseRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReleaseLeaseRequest {
+    const NAME: &'static str = "ReleaseLeaseRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                16 => {
+                    self.version = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(2, self.version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReleaseLeaseRequest {
+        ReleaseLeaseRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.version = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReleaseLeaseRequest {
+        static instance: ReleaseLeaseRequest = ReleaseLeaseRequest {
+            name: ::std::string::String::new(),
+            version: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReleaseLeaseRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReleaseLeaseRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReleaseLeaseRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReleaseLeaseRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:RowCountRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RowCountRequest {
+    // message fields
+    // @@protoc_insertion_point(field:RowCountRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:RowCountRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RowCountRequest {
+    fn default() -> &'a RowCountRequest {
+        <RowCountRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RowCountRequest {
+    pub fn new() -> RowCountRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &RowCountRequest| { &m.hash_key },
+            |m: &mut RowCountRequest| { &mut m.hash_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RowCountRequest>(
+            "RowCountRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RowCountRequest {
+    const NAME: &'static str = "RowCountRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RowCountRequest {
+        RowCountRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RowCountRequest {
+        static instance: RowCountRequest = RowCountRequest {
+            hash_key: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RowCountRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RowCountRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RowCountRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RowCountRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:GetManyRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetManyRequest {
+    // message fields
+    // @@protoc_insertion_point(field:GetManyRequest.items)
+    pub items: ::std::vec::Vec<GetRequest>,
+    // @@protoc_insertion_point(field:GetManyRequest.consistent)
+    pub consistent: bool,
+    // @@protoc_insertion_point(field:GetManyRequest.limit)
+    pub limit: u32,
+    // @@protoc_insertion_point(field:GetManyRequest.max_response_bytes)
+    pub max_response_bytes: u64,
+    // @@protoc_insertion_point(field:GetManyRequest.continuation_token)
+    pub continuation_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetManyRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetManyRequest {
+    fn default() -> &'a GetManyRequest {
+        <GetManyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetManyRequest {
+    pub fn new() -> GetManyRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &GetManyRequest| { &m.items },
+            |m: &mut GetManyRequest| { &mut m.items },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "consistent",
+            |m: &GetManyRequest| { &m.consistent },
+            |m: &mut GetManyRequest| { &mut m.consistent },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &GetManyRequest| { &m.limit },
+            |m: &mut GetManyRequest| { &mut m.limit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_response_bytes",
+            |m: &GetManyRequest| { &m.max_response_bytes },
+            |m: &mut GetManyRequest| { &mut m.max_response_bytes },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "continuation_token",
+            |m: &GetManyRequest| { &m.continuation_token },
+            |m: &mut GetManyRequest| { &mut m.continuation_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetManyRequest>(
+            "GetManyRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetManyRequest {
+    const NAME: &'static str = "GetManyRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                16 => {
+                    self.consistent = is.read_bool()?;
+                },
+                24 => {
+                    self.limit = is.read_uint32()?;
+                },
+                32 => {
+                    self.max_response_bytes = is.read_uint64()?;
+                },
+                42 => {
+                    self.continuation_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.consistent != false {
+            my_size += 1 + 1;
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.limit);
+        }
+        if self.max_response_bytes != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.max_response_bytes);
+        }
+        if !self.continuation_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.continuation_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.consistent != false {
+            os.write_bool(2, self.consistent)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(3, self.limit)?;
+        }
+        if self.max_response_bytes != 0 {
+            os.write_uint64(4, self.max_response_bytes)?;
+        }
+        if !self.continuation_token.is_empty() {
+            os.write_string(5, &self.continuation_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetManyRequest {
+        GetManyRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.consistent = false;
+        self.limit = 0;
+        self.max_response_bytes = 0;
+        self.continuation_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetManyRequest {
+        static instance: GetManyRequest = GetManyRequest {
+            items: ::std::vec::Vec::new(),
+            consistent: false,
+            limit: 0,
+            max_response_bytes: 0,
+            continuation_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetManyRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetManyRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetManyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetManyRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:BatchRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BatchRequest {
+    // message fields
+    // @@protoc_insertion_point(field:BatchRequest.items)
+    pub items: ::std::vec::Vec<BatchItem>,
+    // special fields
+    // @@protoc_insertion_point(special_field:BatchRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BatchRequest {
+    fn default() -> &'a BatchRequest {
+        <BatchRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchRequest {
+    pub fn new() -> BatchRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &BatchRequest| { &m.items },
+            |m: &mut BatchRequest| { &mut m.items },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchRequest>(
+            "BatchRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BatchRequest {
+    const NAME: &'static str = "BatchRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BatchRequest {
+        BatchRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BatchRequest {
+        static instance: BatchRequest = BatchRequest {
+            items: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BatchRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BatchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:BatchItem)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BatchItem {
+    // message oneof groups
+    pub item: ::std::option::Option<batch_item::Item>,
+    // special fields
+    // @@protoc_insertion_point(special_field:BatchItem.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BatchItem {
+    fn default() -> &'a BatchItem {
+        <BatchItem as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchItem {
+    pub fn new() -> BatchItem {
+        ::std::default::Default::default()
+    }
+
+    // .InsertRequest insert = 1;
+
+    pub fn insert(&self) -> &InsertRequest {
+        match self.item {
+            ::std::option::Option::Some(batch_item::Item::Insert(ref v)) => v,
+            _ => <InsertRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_insert(&mut self) {
+        self.item = ::std::option::Option::None;
+    }
+
+    pub fn has_insert(&self) -> bool {
+        match self.item {
+            ::std::option::Option::Some(batch_item::Item::Insert(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insert(&mut self, v: InsertRequest) {
+        self.item = ::std::option::Option::Some(batch_item::Item::Insert(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_insert(&mut self) -> &mut InsertRequest {
+        if let ::std::option::Option::Some(batch_item::Item::Insert(_)) = self.item {
+        } else {
+            self.item = ::std::option::Option::Some(batch_item::Item::Insert(InsertRequest::new()));
+        }
+        match self.item {
+            ::std::option::Option::Some(batch_item::Item::Insert(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_insert(&mut self) -> InsertRequest {
+        if self.has_insert() {
+            match self.item.take() {
+                ::std::option::Option::Some(batch_item::Item::Insert(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            InsertRequest::new()
+        }
+    }
+
+    // .DeleteRequest delete = 2;
+
+    pub fn delete(&self) -> &DeleteRequest {
+        match self.item {
+            ::std::option::Option::Some(batch_item::Item::Delete(ref v)) => v,
+            _ => <DeleteRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_delete(&mut self) {
+        self.item = ::std::option::Option::None;
+    }
+
+    pub fn has_delete(&self) -> bool {
+        match self.item {
+            ::std::option::Option::Some(batch_item::Item::Delete(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete(&mut self, v: DeleteRequest) {
+        self.item = ::std::option::Option::Some(batch_item::Item::Delete(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_delete(&mut self) -> &mut DeleteRequest {
+        if let ::std::option::Option::Some(batch_item::Item::Delete(_)) = self.item {
+        } else {
+            self.item = ::std::option::Option::Some(batch_item::Item::Delete(DeleteRequest::new()));
+        }
+        match self.item {
+            ::std::option::Option::Some(batch_item::Item::Delete(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_delete(&mut self) -> DeleteRequest {
+        if self.has_delete() {
+            match self.item.take() {
+                ::std::option::Option::Some(batch_item::Item::Delete(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DeleteRequest::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, InsertRequest>(
+            "insert",
+            BatchItem::has_insert,
+            BatchItem::insert,
+            BatchItem::mut_insert,
+            BatchItem::set_insert,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteRequest>(
+            "delete",
+            BatchItem::has_delete,
+            BatchItem::delete,
+            BatchItem::mut_delete,
+            BatchItem::set_delete,
+        ));
+        oneofs.push(batch_item::Item::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchItem>(
+            "BatchItem",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BatchItem {
+    const NAME: &'static str = "BatchItem";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.item = ::std::option::Option::Some(batch_item::Item::Insert(is.read_message()?));
+                },
+                18 => {
+                    self.item = ::std::option::Option::Some(batch_item::Item::Delete(is.read_message()?));
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.item {
+            match v {
+                &batch_item::Item::Insert(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &batch_item::Item::Delete(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let ::std::option::Option::Some(ref v) = self.item {
+            match v {
+                &batch_item::Item::Insert(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &batch_item::Item::Delete(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BatchItem {
+        BatchItem::new()
+    }
+
+    fn clear(&mut self) {
+        self.item = ::std::option::Option::None;
+        self.item = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BatchItem {
+        static instance: BatchItem = BatchItem {
+            item: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BatchItem {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchItem").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BatchItem {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchItem {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `BatchItem`
+pub mod batch_item {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:BatchItem.item)
+    pub enum Item {
+        // @@protoc_insertion_point(oneof_field:BatchItem.insert)
+        Insert(super::InsertRequest),
+        // @@protoc_insertion_point(oneof_field:BatchItem.delete)
+        Delete(super::DeleteRequest),
+    }
+
+    impl ::protobuf::Oneof for Item {
+    }
+
+    impl ::protobuf::OneofFull for Item {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::BatchItem as ::protobuf::MessageFull>::descriptor().oneof_by_name("item").unwrap()).clone()
+        }
+    }
+
+    impl Item {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Item>("item")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:BeginTransaction)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BeginTransaction {
+    // special fields
+    // @@protoc_insertion_point(special_field:BeginTransaction.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BeginTransaction {
+    fn default() -> &'a BeginTransaction {
+        <BeginTransaction as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BeginTransaction {
+    pub fn new() -> BeginTransaction {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BeginTransaction>(
+            "BeginTransaction",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BeginTransaction {
+    const NAME: &'static str = "BeginTransaction";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BeginTransaction {
+        BeginTransaction::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BeginTransaction {
+        static instance: BeginTransaction = BeginTransaction {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BeginTransaction {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BeginTransaction").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BeginTransaction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BeginTransaction {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:CommitTransaction)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct CommitTransaction {
+    // special fields
+    // @@protoc_insertion_point(special_field:CommitTransaction.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a CommitTransaction {
+    fn default() -> &'a CommitTransaction {
+        <CommitTransaction as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitTransaction {
+    pub fn new() -> CommitTransaction {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CommitTransaction>(
+            "CommitTransaction",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for CommitTransaction {
+    const NAME: &'static str = "CommitTransaction";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> CommitTransaction {
+        CommitTransaction::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static CommitTransaction {
+        static instance: CommitTransaction = CommitTransaction {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for CommitTransaction {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CommitTransaction").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for CommitTransaction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitTransaction {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:AbortTransaction)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct AbortTransaction {
+    // special fields
+    // @@protoc_insertion_point(special_field:AbortTransaction.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a AbortTransaction {
+    fn default() -> &'a AbortTransaction {
+        <AbortTransaction as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AbortTransaction {
+    pub fn new() -> AbortTransaction {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AbortTransaction>(
+            "AbortTransaction",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for AbortTransaction {
+    const NAME: &'static str = "AbortTransaction";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> AbortTransaction {
+        AbortTransaction::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static AbortTransaction {
+        static instance: AbortTransaction = AbortTransaction {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for AbortTransaction {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("AbortTransaction").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for AbortTransaction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AbortTransaction {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SyncModelRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SyncModelRequest {
+    // message fields
+    // @@protoc_insertion_point(field:SyncModelRequest.schema_string)
+    pub schema_string: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:SyncModelRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SyncModelRequest {
+    fn default() -> &'a SyncModelRequest {
+        <SyncModelRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncModelRequest {
+    pub fn new() -> SyncModelRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "schema_string",
+            |m: &SyncModelRequest| { &m.schema_string },
+            |m: &mut SyncModelRequest| { &mut m.schema_string },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SyncModelRequest>(
+            "SyncModelRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SyncModelRequest {
+    const NAME: &'static str = "SyncModelRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.schema_string = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.schema_string.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.schema_string);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.schema_string.is_empty() {
+            os.write_string(1, &self.schema_string)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SyncModelRequest {
+        SyncModelRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.schema_string.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SyncModelRequest {
+        static instance: SyncModelRequest = SyncModelRequest {
+            schema_string: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SyncModelRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SyncModelRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SyncModelRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncModelRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SyncModelsRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SyncModelsRequest {
+    // message fields
+    // @@protoc_insertion_point(field:SyncModelsRequest.schema_strings)
+    pub schema_strings: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:SyncModelsRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SyncModelsRequest {
+    fn default() -> &'a SyncModelsRequest {
+        <SyncModelsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncModelsRequest {
+    pub fn new() -> SyncModelsRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "schema_strings",
+            |m: &SyncModelsRequest| { &m.schema_strings },
+            |m: &mut SyncModelsRequest| { &mut m.schema_strings },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SyncModelsRequest>(
+            "SyncModelsRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SyncModelsRequest {
+    const NAME: &'static str = "SyncModelsRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.schema_strings.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.schema_strings {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.schema_strings {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SyncModelsRequest {
+        SyncModelsRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.schema_strings.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SyncModelsRequest {
+        static instance: SyncModelsRequest = SyncModelsRequest {
+            schema_strings: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SyncModelsRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SyncModelsRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SyncModelsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncModelsRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DropTableRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DropTableRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:DropTableRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DropTableRequest {
+    fn default() -> &'a DropTableRequest {
+        <DropTableRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DropTableRequest {
+    pub fn new() -> DropTableRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DropTableRequest>(
+            "DropTableRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DropTableRequest {
+    const NAME: &'static str = "DropTableRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DropTableRequest {
+        DropTableRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DropTableRequest {
+        static instance: DropTableRequest = DropTableRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DropTableRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DropTableRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DropTableRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DropTableRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PlanMigrationRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PlanMigrationRequest {
+    // message fields
+    // @@protoc_insertion_point(field:PlanMigrationRequest.schema_string)
+    pub schema_string: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:PlanMigrationRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PlanMigrationRequest {
+    fn default() -> &'a PlanMigrationRequest {
+        <PlanMigrationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlanMigrationRequest {
+    pub fn new() -> PlanMigrationRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "schema_string",
+            |m: &PlanMigrationRequest| { &m.schema_string },
+            |m: &mut PlanMigrationRequest| { &mut m.schema_string },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PlanMigrationRequest>(
+            "PlanMigrationRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PlanMigrationRequest {
+    const NAME: &'static str = "PlanMigrationRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.schema_string = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.schema_string.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.schema_string);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.schema_string.is_empty() {
+            os.write_string(1, &self.schema_string)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PlanMigrationRequest {
+        PlanMigrationRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.schema_string.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PlanMigrationRequest {
+        static instance: PlanMigrationRequest = PlanMigrationRequest {
+            schema_string: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PlanMigrationRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PlanMigrationRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PlanMigrationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlanMigrationRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DescribeTableRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DescribeTableRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:DescribeTableRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DescribeTableRequest {
+    fn default() -> &'a DescribeTableRequest {
+        <DescribeTableRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DescribeTableRequest {
+    pub fn new() -> DescribeTableRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DescribeTableRequest>(
+            "DescribeTableRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DescribeTableRequest {
+    const NAME: &'static str = "DescribeTableRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DescribeTableRequest {
+        DescribeTableRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DescribeTableRequest {
+        static instance: DescribeTableRequest = DescribeTableRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DescribeTableRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DescribeTableRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DescribeTableRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DescribeTableRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:HealthRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HealthRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:HealthRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HealthRequest {
+    fn default() -> &'a HealthRequest {
+        <HealthRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HealthRequest {
+    pub fn new() -> HealthRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HealthRequest>(
+            "HealthRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HealthRequest {
+    const NAME: &'static str = "HealthRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HealthRequest {
+        HealthRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HealthRequest {
+        static instance: HealthRequest = HealthRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HealthRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HealthRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HealthRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReadyRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReadyRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:ReadyRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReadyRequest {
+    fn default() -> &'a ReadyRequest {
+        <ReadyRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadyRequest {
+    pub fn new() -> ReadyRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReadyRequest>(
+            "ReadyRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReadyRequest {
+    const NAME: &'static str = "ReadyRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReadyRequest {
+        ReadyRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReadyRequest {
+        static instance: ReadyRequest = ReadyRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReadyRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReadyRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReadyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadyRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DescribeServerRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DescribeServerRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:DescribeServerRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DescribeServerRequest {
+    fn default() -> &'a DescribeServerRequest {
+        <DescribeServerRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DescribeServerRequest {
+    pub fn new() -> DescribeServerRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DescribeServerRequest>(
+            "DescribeServerRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DescribeServerRequest {
+    const NAME: &'static str = "DescribeServerRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DescribeServerRequest {
+        DescribeServerRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DescribeServerRequest {
+        static instance: DescribeServerRequest = DescribeServerRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DescribeServerRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DescribeServerRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DescribeServerRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DescribeServerRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PartitionMapRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PartitionMapRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:PartitionMapRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PartitionMapRequest {
+    fn default() -> &'a PartitionMapRequest {
+        <PartitionMapRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PartitionMapRequest {
+    pub fn new() -> PartitionMapRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PartitionMapRequest>(
+            "PartitionMapRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PartitionMapRequest {
+    const NAME: &'static str = "PartitionMapRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PartitionMapRequest {
+        PartitionMapRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PartitionMapRequest {
+        static instance: PartitionMapRequest = PartitionMapRequest {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PartitionMapRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PartitionMapRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PartitionMapRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PartitionMapRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:MigrateBucketRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MigrateBucketRequest {
+    // message fields
+    // @@protoc_insertion_point(field:MigrateBucketRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:MigrateBucketRequest.target_partition)
+    pub target_partition: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:MigrateBucketRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MigrateBucketRequest {
+    fn default() -> &'a MigrateBucketRequest {
+        <MigrateBucketRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MigrateBucketRequest {
+    pub fn new() -> MigrateBucketRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &MigrateBucketRequest| { &m.hash_key },
+            |m: &mut MigrateBucketRequest| { &mut m.hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target_partition",
+            |m: &MigrateBucketRequest| { &m.target_partition },
+            |m: &mut MigrateBucketRequest| { &mut m.target_partition },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MigrateBucketRequest>(
+            "MigrateBucketRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MigrateBucketRequest {
+    const NAME: &'static str = "MigrateBucketRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                16 => {
+                    self.target_partition = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if self.target_partition != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.target_partition);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if self.target_partition != 0 {
+            os.write_uint32(2, self.target_partition)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MigrateBucketRequest {
+        MigrateBucketRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.target_partition = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MigrateBucketRequest {
+        static instance: MigrateBucketRequest = MigrateBucketRequest {
+            hash_key: ::std::string::String::new(),
+            target_partition: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MigrateBucketRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MigrateBucketRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MigrateBucketRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MigrateBucketRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:QueryByIndexRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct QueryByIndexRequest {
+    // message fields
+    // @@protoc_insertion_point(field:QueryByIndexRequest.column)
+    pub column: ::std::string::String,
+    // @@protoc_insertion_point(field:QueryByIndexRequest.start)
+    pub start: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:QueryByIndexRequest.start_inclusive)
+    pub start_inclusive: bool,
+    // @@protoc_insertion_point(field:QueryByIndexRequest.end)
+    pub end: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:QueryByIndexRequest.end_inclusive)
+    pub end_inclusive: bool,
+    // @@protoc_insertion_point(field:QueryByIndexRequest.limit)
+    pub limit: u32,
+    // @@protoc_insertion_point(field:QueryByIndexRequest.continuation_token)
+    pub continuation_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:QueryByIndexRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a QueryByIndexRequest {
+    fn default() -> &'a QueryByIndexRequest {
+        <QueryByIndexRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryByIndexRequest {
+    pub fn new() -> QueryByIndexRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "column",
+            |m: &QueryByIndexRequest| { &m.column },
+            |m: &mut QueryByIndexRequest| { &mut m.column },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "start",
+            |m: &QueryByIndexRequest| { &m.start },
+            |m: &mut QueryByIndexRequest| { &mut m.start },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "start_inclusive",
+            |m: &QueryByIndexRequest| { &m.start_inclusive },
+            |m: &mut QueryByIndexRequest| { &mut m.start_inclusive },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "end",
+            |m: &QueryByIndexRequest| { &m.end },
+            |m: &mut QueryByIndexRequest| { &mut m.end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end_inclusive",
+            |m: &QueryByIndexRequest| { &m.end_inclusive },
+            |m: &mut QueryByIndexRequest| { &mut m.end_inclusive },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "limit",
+            |m: &QueryByIndexRequest| { &m.limit },
+            |m: &mut QueryByIndexRequest| { &mut m.limit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "continuation_token",
+            |m: &QueryByIndexRequest| { &m.continuation_token },
+            |m: &mut QueryByIndexRequest| { &mut m.continuation_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<QueryByIndexRequest>(
+            "QueryByIndexRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for QueryByIndexRequest {
+    const NAME: &'static str = "QueryByIndexRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.column = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.start)?;
+                },
+                24 => {
+                    self.start_inclusive = is.read_bool()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.end)?;
+                },
+                40 => {
+                    self.end_inclusive = is.read_bool()?;
+                },
+                48 => {
+                    self.limit = is.read_uint32()?;
+                },
+                58 => {
+                    self.continuation_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.column.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.column);
+        }
+        if let Some(v) = self.start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.start_inclusive != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.end_inclusive != false {
+            my_size += 1 + 1;
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.limit);
+        }
+        if !self.continuation_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.continuation_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.column.is_empty() {
+            os.write_string(1, &self.column)?;
+        }
+        if let Some(v) = self.start.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.start_inclusive != false {
+            os.write_bool(3, self.start_inclusive)?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.end_inclusive != false {
+            os.write_bool(5, self.end_inclusive)?;
+        }
+        if self.limit != 0 {
+            os.write_uint32(6, self.limit)?;
+        }
+        if !self.continuation_token.is_empty() {
+            os.write_string(7, &self.continuation_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> QueryByIndexRequest {
+        QueryByIndexRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.column.clear();
+        self.start.clear();
+        self.start_inclusive = false;
+        self.end.clear();
+        self.end_inclusive = false;
+        self.limit = 0;
+        self.continuation_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static QueryByIndexRequest {
+        static instance: QueryByIndexRequest = QueryByIndexRequest {
+            column: ::std::string::String::new(),
+            start: ::protobuf::MessageField::none(),
+            start_inclusive: false,
+            end: ::protobuf::MessageField::none(),
+            end_inclusive: false,
+            limit: 0,
+            continuation_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for QueryByIndexRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("QueryByIndexRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for QueryByIndexRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryByIndexRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DeleteWhereRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeleteWhereRequest {
+    // message fields
+    // @@protoc_insertion_point(field:DeleteWhereRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:DeleteWhereRequest.start)
+    pub start: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:DeleteWhereRequest.start_inclusive)
+    pub start_inclusive: bool,
+    // @@protoc_insertion_point(field:DeleteWhereRequest.end)
+    pub end: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:DeleteWhereRequest.end_inclusive)
+    pub end_inclusive: bool,
+    // @@protoc_insertion_point(field:DeleteWhereRequest.filter_column)
+    pub filter_column: ::std::option::Option<::std::string::String>,
+    // @@protoc_insertion_point(field:DeleteWhereRequest.filter_value)
+    pub filter_value: ::protobuf::MessageField<super::common::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeleteWhereRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteWhereRequest {
+    fn default() -> &'a DeleteWhereRequest {
+        <DeleteWhereRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteWhereRequest {
+    pub fn new() -> DeleteWhereRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &DeleteWhereRequest| { &m.hash_key },
+            |m: &mut DeleteWhereRequest| { &mut m.hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "start",
+            |m: &DeleteWhereRequest| { &m.start },
+            |m: &mut DeleteWhereRequest| { &mut m.start },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "start_inclusive",
+            |m: &DeleteWhereRequest| { &m.start_inclusive },
+            |m: &mut DeleteWhereRequest| { &mut m.start_inclusive },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "end",
+            |m: &DeleteWhereRequest| { &m.end },
+            |m: &mut DeleteWhereRequest| { &mut m.end },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "end_inclusive",
+            |m: &DeleteWhereRequest| { &m.end_inclusive },
+            |m: &mut DeleteWhereRequest| { &mut m.end_inclusive },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "filter_column",
+            |m: &DeleteWhereRequest| { &m.filter_column },
+            |m: &mut DeleteWhereRequest| { &mut m.filter_column },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "filter_value",
+            |m: &DeleteWhereRequest| { &m.filter_value },
+            |m: &mut DeleteWhereRequest| { &mut m.filter_value },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteWhereRequest>(
+            "DeleteWhereRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteWhereRequest {
+    const NAME: &'static str = "DeleteWhereRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.start)?;
+                },
+                24 => {
+                    self.start_inclusive = is.read_bool()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.end)?;
+                },
+                40 => {
+                    self.end_inclusive = is.read_bool()?;
+                },
+                50 => {
+                    self.filter_column = ::std::option::Option::Some(is.read_string()?);
+                },
+                58 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.filter_value)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if let Some(v) = self.start.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.start_inclusive != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.end.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.end_inclusive != false {
+            my_size += 1 + 1;
+        }
+        if let Some(v) = self.filter_column.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(v) = self.filter_value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if let Some(v) = self.start.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if self.start_inclusive != false {
+            os.write_bool(3, self.start_inclusive)?;
+        }
+        if let Some(v) = self.end.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if self.end_inclusive != false {
+            os.write_bool(5, self.end_inclusive)?;
+        }
+        if let Some(v) = self.filter_column.as_ref() {
+            os.write_string(6, v)?;
+        }
+        if let Some(v) = self.filter_value.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteWhereRequest {
+        DeleteWhereRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.start.clear();
+        self.start_inclusive = false;
+        self.end.clear();
+        self.end_inclusive = false;
+        self.filter_column = ::std::option::Option::None;
+        self.filter_value.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteWhereRequest {
+        static instance: DeleteWhereRequest = DeleteWhereRequest {
+            hash_key: ::std::string::String::new(),
+            start: ::protobuf::MessageField::none(),
+            start_inclusive: false,
+            end: ::protobuf::MessageField::none(),
+            end_inclusive: false,
+            filter_column: ::std::option::Option::None,
+            filter_value: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteWhereRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteWhereRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteWhereRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteWhereRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:MoveRowRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MoveRowRequest {
+    // message fields
+    // @@protoc_insertion_point(field:MoveRowRequest.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:MoveRowRequest.sort_key)
+    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:MoveRowRequest.new_hash_key)
+    pub new_hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:MoveRowRequest.new_sort_key)
+    pub new_sort_key: ::protobuf::MessageField<super::common::Value>,
+    // special fields
+    // @@protoc_insertion_point(special_field:MoveRowRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MoveRowRequest {
+    fn default() -> &'a MoveRowRequest {
+        <MoveRowRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveRowRequest {
+    pub fn new() -> MoveRowRequest {
+        ::std::default::Default::default()
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(12);
-        let mut oneofs = ::std::vec::Vec::with_capacity(1);
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetRequest>(
-            "get",
-            Request::has_get,
-            Request::get,
-            Request::mut_get,
-            Request::set_get,
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &MoveRowRequest| { &m.hash_key },
+            |m: &mut MoveRowRequest| { &mut m.hash_key },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, InsertRequest>(
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "sort_key",
+            |m: &MoveRowRequest| { &m.sort_key },
+            |m: &mut MoveRowRequest| { &mut m.sort_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "new_hash_key",
+            |m: &MoveRowRequest| { &m.new_hash_key },
+            |m: &mut MoveRowRequest| { &mut m.new_hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "new_sort_key",
+            |m: &MoveRowRequest| { &m.new_sort_key },
+            |m: &mut MoveRowRequest| { &mut m.new_sort_key },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MoveRowRequest>(
+            "MoveRowRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MoveRowRequest {
+    const NAME: &'static str = "MoveRowRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                },
+                26 => {
+                    self.new_hash_key = is.read_string()?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.new_sort_key)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.new_hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.new_hash_key);
+        }
+        if let Some(v) = self.new_sort_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if !self.new_hash_key.is_empty() {
+            os.write_string(3, &self.new_hash_key)?;
+        }
+        if let Some(v) = self.new_sort_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MoveRowRequest {
+        MoveRowRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.sort_key.clear();
+        self.new_hash_key.clear();
+        self.new_sort_key.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MoveRowRequest {
+        static instance: MoveRowRequest = MoveRowRequest {
+            hash_key: ::std::string::String::new(),
+            sort_key: ::protobuf::MessageField::none(),
+            new_hash_key: ::std::string::String::new(),
+            new_sort_key: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MoveRowRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MoveRowRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MoveRowRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveRowRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SaveRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SaveRequest {
+    // message fields
+    // @@protoc_insertion_point(field:SaveRequest.insert)
+    pub insert: ::protobuf::MessageField<InsertRequest>,
+    // @@protoc_insertion_point(field:SaveRequest.version)
+    pub version: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:SaveRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SaveRequest {
+    fn default() -> &'a SaveRequest {
+        <SaveRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SaveRequest {
+    pub fn new() -> SaveRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, InsertRequest>(
             "insert",
-            Request::has_insert,
-            Request::insert,
-            Request::mut_insert,
-            Request::set_insert,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteRequest>(
-            "delete",
-            Request::has_delete,
-            Request::delete,
-            Request::mut_delete,
-            Request::set_delete,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetManyRequest>(
-            "get_many",
-            Request::has_get_many,
-            Request::get_many,
-            Request::mut_get_many,
-            Request::set_get_many,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, BatchRequest>(
-            "batch",
-            Request::has_batch,
-            Request::batch,
-            Request::mut_batch,
-            Request::set_batch,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, BeginTransaction>(
-            "begin_transaction",
-            Request::has_begin_transaction,
-            Request::begin_transaction,
-            Request::mut_begin_transaction,
-            Request::set_begin_transaction,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, CommitTransaction>(
-            "commit_transaction",
-            Request::has_commit_transaction,
-            Request::commit_transaction,
-            Request::mut_commit_transaction,
-            Request::set_commit_transaction,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AbortTransaction>(
-            "abort_transaction",
-            Request::has_abort_transaction,
-            Request::abort_transaction,
-            Request::mut_abort_transaction,
-            Request::set_abort_transaction,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SyncModelRequest>(
-            "sync_model",
-            Request::has_sync_model,
-            Request::sync_model,
-            Request::mut_sync_model,
-            Request::set_sync_model,
+            |m: &SaveRequest| { &m.insert },
+            |m: &mut SaveRequest| { &mut m.insert },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DropTableRequest>(
-            "drop_table",
-            Request::has_drop_table,
-            Request::drop_table,
-            Request::mut_drop_table,
-            Request::set_drop_table,
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &SaveRequest| { &m.version },
+            |m: &mut SaveRequest| { &mut m.version },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
-            "transaction_id",
-            |m: &Request| { &m.transaction_id },
-            |m: &mut Request| { &mut m.transaction_id },
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SaveRequest>(
+            "SaveRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SaveRequest {
+    const NAME: &'static str = "SaveRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.insert)?;
+                },
+                16 => {
+                    self.version = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.insert.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.insert.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(2, self.version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SaveRequest {
+        SaveRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.insert.clear();
+        self.version = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SaveRequest {
+        static instance: SaveRequest = SaveRequest {
+            insert: ::protobuf::MessageField::none(),
+            version: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SaveRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SaveRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SaveRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SaveRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReplicateRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReplicateRequest {
+    // message fields
+    // @@protoc_insertion_point(field:ReplicateRequest.epoch)
+    pub epoch: u64,
+    // @@protoc_insertion_point(field:ReplicateRequest.sequence)
+    pub sequence: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:ReplicateRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplicateRequest {
+    fn default() -> &'a ReplicateRequest {
+        <ReplicateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplicateRequest {
+    pub fn new() -> ReplicateRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "epoch",
+            |m: &ReplicateRequest| { &m.epoch },
+            |m: &mut ReplicateRequest| { &mut m.epoch },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "table",
-            |m: &Request| { &m.table },
-            |m: &mut Request| { &mut m.table },
+            "sequence",
+            |m: &ReplicateRequest| { &m.sequence },
+            |m: &mut ReplicateRequest| { &mut m.sequence },
         ));
-        oneofs.push(request::Data::generated_oneof_descriptor_data());
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Request>(
-            "Request",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplicateRequest>(
+            "ReplicateRequest",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplicateRequest {
+    const NAME: &'static str = "ReplicateRequest";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.epoch = is.read_uint64()?;
+                },
+                16 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.epoch != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.epoch);
+        }
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.sequence);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.epoch != 0 {
+            os.write_uint64(1, self.epoch)?;
+        }
+        if self.sequence != 0 {
+            os.write_uint64(2, self.sequence)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplicateRequest {
+        ReplicateRequest::new()
+    }
+
+    fn clear(&mut self) {
+        self.epoch = 0;
+        self.sequence = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplicateRequest {
+        static instance: ReplicateRequest = ReplicateRequest {
+            epoch: 0,
+            sequence: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplicateRequest {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplicateRequest").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplicateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplicateRequest {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PromoteRequest)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PromoteRequest {
+    // special fields
+    // @@protoc_insertion_point(special_field:PromoteRequest.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PromoteRequest {
+    fn default() -> &'a PromoteRequest {
+        <PromoteRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PromoteRequest {
+    pub fn new() -> PromoteRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PromoteRequest>(
+            "PromoteRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for Request {
-    const NAME: &'static str = "Request";
+impl ::protobuf::Message for PromoteRequest {
+    const NAME: &'static str = "PromoteRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -643,42 +7165,6 @@ impl ::protobuf::Message for Request {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.data = ::std::option::Option::Some(request::Data::Get(is.read_message()?));
-                },
-                18 => {
-                    self.data = ::std::option::Option::Some(request::Data::Insert(is.read_message()?));
-                },
-                26 => {
-                    self.data = ::std::option::Option::Some(request::Data::Delete(is.read_message()?));
-                },
-                34 => {
-                    self.data = ::std::option::Option::Some(request::Data::GetMany(is.read_message()?));
-                },
-                42 => {
-                    self.data = ::std::option::Option::Some(request::Data::Batch(is.read_message()?));
-                },
-                50 => {
-                    self.data = ::std::option::Option::Some(request::Data::BeginTransaction(is.read_message()?));
-                },
-                58 => {
-                    self.data = ::std::option::Option::Some(request::Data::CommitTransaction(is.read_message()?));
-                },
-                66 => {
-                    self.data = ::std::option::Option::Some(request::Data::AbortTransaction(is.read_message()?));
-                },
-                74 => {
-                    self.data = ::std::option::Option::Some(request::Data::SyncModel(is.read_message()?));
-                },
-                82 => {
-                    self.data = ::std::option::Option::Some(request::Data::DropTable(is.read_message()?));
-                },
-                88 => {
-                    self.transaction_id = ::std::option::Option::Some(is.read_uint64()?);
-                },
-                98 => {
-                    self.table = is.read_string()?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -691,102 +7177,12 @@ impl ::protobuf::Message for Request {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if let Some(v) = self.transaction_id {
-            my_size += ::protobuf::rt::uint64_size(11, v);
-        }
-        if !self.table.is_empty() {
-            my_size += ::protobuf::rt::string_size(12, &self.table);
-        }
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &request::Data::Get(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::Insert(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::Delete(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::GetMany(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::Batch(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::BeginTransaction(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::CommitTransaction(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::AbortTransaction(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::SyncModel(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &request::Data::DropTable(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-            };
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let Some(v) = self.transaction_id {
-            os.write_uint64(11, v)?;
-        }
-        if !self.table.is_empty() {
-            os.write_string(12, &self.table)?;
-        }
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &request::Data::Get(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-                },
-                &request::Data::Insert(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-                },
-                &request::Data::Delete(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
-                },
-                &request::Data::GetMany(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
-                },
-                &request::Data::Batch(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
-                },
-                &request::Data::BeginTransaction(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
-                },
-                &request::Data::CommitTransaction(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
-                },
-                &request::Data::AbortTransaction(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
-                },
-                &request::Data::SyncModel(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
-                },
-                &request::Data::DropTable(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
-                },
-            };
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -799,147 +7195,233 @@ impl ::protobuf::Message for Request {
         &mut self.special_fields
     }
 
-    fn new() -> Request {
-        Request::new()
+    fn new() -> PromoteRequest {
+        PromoteRequest::new()
     }
 
     fn clear(&mut self) {
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.transaction_id = ::std::option::Option::None;
-        self.table.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static Request {
-        static instance: Request = Request {
-            transaction_id: ::std::option::Option::None,
-            table: ::std::string::String::new(),
-            data: ::std::option::Option::None,
+    fn default_instance() -> &'static PromoteRequest {
+        static instance: PromoteRequest = PromoteRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for Request {
+impl ::protobuf::MessageFull for PromoteRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Request").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PromoteRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Request {
+impl ::std::fmt::Display for PromoteRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Request {
+impl ::protobuf::reflect::ProtobufValue for PromoteRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `Request`
-pub mod request {
+// @@protoc_insertion_point(message:RaftLogPosition)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RaftLogPosition {
+    // message fields
+    // @@protoc_insertion_point(field:RaftLogPosition.index)
+    pub index: u64,
+    // @@protoc_insertion_point(field:RaftLogPosition.term)
+    pub term: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:RaftLogPosition.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
 
-    #[derive(Clone,PartialEq,Debug)]
-    #[non_exhaustive]
-    // @@protoc_insertion_point(oneof:Request.data)
-    pub enum Data {
-        // @@protoc_insertion_point(oneof_field:Request.get)
-        Get(super::GetRequest),
-        // @@protoc_insertion_point(oneof_field:Request.insert)
-        Insert(super::InsertRequest),
-        // @@protoc_insertion_point(oneof_field:Request.delete)
-        Delete(super::DeleteRequest),
-        // @@protoc_insertion_point(oneof_field:Request.get_many)
-        GetMany(super::GetManyRequest),
-        // @@protoc_insertion_point(oneof_field:Request.batch)
-        Batch(super::BatchRequest),
-        // @@protoc_insertion_point(oneof_field:Request.begin_transaction)
-        BeginTransaction(super::BeginTransaction),
-        // @@protoc_insertion_point(oneof_field:Request.commit_transaction)
-        CommitTransaction(super::CommitTransaction),
-        // @@protoc_insertion_point(oneof_field:Request.abort_transaction)
-        AbortTransaction(super::AbortTransaction),
-        // @@protoc_insertion_point(oneof_field:Request.sync_model)
-        SyncModel(super::SyncModelRequest),
-        // @@protoc_insertion_point(oneof_field:Request.drop_table)
-        DropTable(super::DropTableRequest),
+impl<'a> ::std::default::Default for &'a RaftLogPosition {
+    fn default() -> &'a RaftLogPosition {
+        <RaftLogPosition as ::protobuf::Message>::default_instance()
     }
+}
 
-    impl ::protobuf::Oneof for Data {
+impl RaftLogPosition {
+    pub fn new() -> RaftLogPosition {
+        ::std::default::Default::default()
     }
 
-    impl ::protobuf::OneofFull for Data {
-        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
-            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| <super::Request as ::protobuf::MessageFull>::descriptor().oneof_by_name("data").unwrap()).clone()
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "index",
+            |m: &RaftLogPosition| { &m.index },
+            |m: &mut RaftLogPosition| { &mut m.index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftLogPosition| { &m.term },
+            |m: &mut RaftLogPosition| { &mut m.term },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftLogPosition>(
+            "RaftLogPosition",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftLogPosition {
+    const NAME: &'static str = "RaftLogPosition";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.index = is.read_uint64()?;
+                },
+                16 => {
+                    self.term = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.index);
         }
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.term);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.index != 0 {
+            os.write_uint64(1, self.index)?;
+        }
+        if self.term != 0 {
+            os.write_uint64(2, self.term)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftLogPosition {
+        RaftLogPosition::new()
+    }
+
+    fn clear(&mut self) {
+        self.index = 0;
+        self.term = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftLogPosition {
+        static instance: RaftLogPosition = RaftLogPosition {
+            index: 0,
+            term: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftLogPosition {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftLogPosition").unwrap()).clone()
     }
+}
 
-    impl Data {
-        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
-            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Data>("data")
-        }
+impl ::std::fmt::Display for RaftLogPosition {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
 }
 
-// @@protoc_insertion_point(message:GetRequest)
+impl ::protobuf::reflect::ProtobufValue for RaftLogPosition {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:RaftVoteRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetRequest {
+pub struct RaftVoteRequest {
     // message fields
-    // @@protoc_insertion_point(field:GetRequest.hash_key)
-    pub hash_key: ::std::string::String,
-    // @@protoc_insertion_point(field:GetRequest.sort_key)
-    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:RaftVoteRequest.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:RaftVoteRequest.candidate_id)
+    pub candidate_id: u64,
+    // @@protoc_insertion_point(field:RaftVoteRequest.last_positions)
+    pub last_positions: ::std::vec::Vec<RaftLogPosition>,
     // special fields
-    // @@protoc_insertion_point(special_field:GetRequest.special_fields)
+    // @@protoc_insertion_point(special_field:RaftVoteRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetRequest {
-    fn default() -> &'a GetRequest {
-        <GetRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RaftVoteRequest {
+    fn default() -> &'a RaftVoteRequest {
+        <RaftVoteRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetRequest {
-    pub fn new() -> GetRequest {
+impl RaftVoteRequest {
+    pub fn new() -> RaftVoteRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "hash_key",
-            |m: &GetRequest| { &m.hash_key },
-            |m: &mut GetRequest| { &mut m.hash_key },
+            "term",
+            |m: &RaftVoteRequest| { &m.term },
+            |m: &mut RaftVoteRequest| { &mut m.term },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
-            "sort_key",
-            |m: &GetRequest| { &m.sort_key },
-            |m: &mut GetRequest| { &mut m.sort_key },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "candidate_id",
+            |m: &RaftVoteRequest| { &m.candidate_id },
+            |m: &mut RaftVoteRequest| { &mut m.candidate_id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetRequest>(
-            "GetRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "last_positions",
+            |m: &RaftVoteRequest| { &m.last_positions },
+            |m: &mut RaftVoteRequest| { &mut m.last_positions },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftVoteRequest>(
+            "RaftVoteRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetRequest {
-    const NAME: &'static str = "GetRequest";
+impl ::protobuf::Message for RaftVoteRequest {
+    const NAME: &'static str = "RaftVoteRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -948,11 +7430,14 @@ impl ::protobuf::Message for GetRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.hash_key = is.read_string()?;
+                8 => {
+                    self.term = is.read_uint64()?;
                 },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                16 => {
+                    self.candidate_id = is.read_uint64()?;
+                },
+                26 => {
+                    self.last_positions.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -966,25 +7451,31 @@ impl ::protobuf::Message for GetRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.hash_key.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
         }
-        if let Some(v) = self.sort_key.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.candidate_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.candidate_id);
         }
+        for value in &self.last_positions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.hash_key.is_empty() {
-            os.write_string(1, &self.hash_key)?;
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
         }
-        if let Some(v) = self.sort_key.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if self.candidate_id != 0 {
+            os.write_uint64(2, self.candidate_id)?;
         }
+        for v in &self.last_positions {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -997,97 +7488,106 @@ impl ::protobuf::Message for GetRequest {
         &mut self.special_fields
     }
 
-    fn new() -> GetRequest {
-        GetRequest::new()
+    fn new() -> RaftVoteRequest {
+        RaftVoteRequest::new()
     }
 
     fn clear(&mut self) {
-        self.hash_key.clear();
-        self.sort_key.clear();
+        self.term = 0;
+        self.candidate_id = 0;
+        self.last_positions.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetRequest {
-        static instance: GetRequest = GetRequest {
-            hash_key: ::std::string::String::new(),
-            sort_key: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static RaftVoteRequest {
+        static instance: RaftVoteRequest = RaftVoteRequest {
+            term: 0,
+            candidate_id: 0,
+            last_positions: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetRequest {
+impl ::protobuf::MessageFull for RaftVoteRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftVoteRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetRequest {
+impl ::std::fmt::Display for RaftVoteRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetRequest {
+impl ::protobuf::reflect::ProtobufValue for RaftVoteRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:InsertRequest)
+// @@protoc_insertion_point(message:RaftEntry)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct InsertRequest {
+pub struct RaftEntry {
     // message fields
-    // @@protoc_insertion_point(field:InsertRequest.hash_key)
-    pub hash_key: ::std::string::String,
-    // @@protoc_insertion_point(field:InsertRequest.sort_key)
-    pub sort_key: ::protobuf::MessageField<super::common::Value>,
-    // @@protoc_insertion_point(field:InsertRequest.values)
-    pub values: ::std::collections::HashMap<::std::string::String, super::common::Value>,
+    // @@protoc_insertion_point(field:RaftEntry.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:RaftEntry.index)
+    pub index: u64,
+    // @@protoc_insertion_point(field:RaftEntry.table)
+    pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:RaftEntry.records)
+    pub records: ::std::vec::Vec<u8>,
     // special fields
-    // @@protoc_insertion_point(special_field:InsertRequest.special_fields)
+    // @@protoc_insertion_point(special_field:RaftEntry.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a InsertRequest {
-    fn default() -> &'a InsertRequest {
-        <InsertRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RaftEntry {
+    fn default() -> &'a RaftEntry {
+        <RaftEntry as ::protobuf::Message>::default_instance()
     }
 }
 
-impl InsertRequest {
-    pub fn new() -> InsertRequest {
+impl RaftEntry {
+    pub fn new() -> RaftEntry {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "hash_key",
-            |m: &InsertRequest| { &m.hash_key },
-            |m: &mut InsertRequest| { &mut m.hash_key },
+            "term",
+            |m: &RaftEntry| { &m.term },
+            |m: &mut RaftEntry| { &mut m.term },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
-            "sort_key",
-            |m: &InsertRequest| { &m.sort_key },
-            |m: &mut InsertRequest| { &mut m.sort_key },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "index",
+            |m: &RaftEntry| { &m.index },
+            |m: &mut RaftEntry| { &mut m.index },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
-            "values",
-            |m: &InsertRequest| { &m.values },
-            |m: &mut InsertRequest| { &mut m.values },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table",
+            |m: &RaftEntry| { &m.table },
+            |m: &mut RaftEntry| { &mut m.table },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InsertRequest>(
-            "InsertRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &RaftEntry| { &m.records },
+            |m: &mut RaftEntry| { &mut m.records },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftEntry>(
+            "RaftEntry",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for InsertRequest {
-    const NAME: &'static str = "InsertRequest";
+impl ::protobuf::Message for RaftEntry {
+    const NAME: &'static str = "RaftEntry";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1096,26 +7596,17 @@ impl ::protobuf::Message for InsertRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.hash_key = is.read_string()?;
+                8 => {
+                    self.term = is.read_uint64()?;
                 },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                16 => {
+                    self.index = is.read_uint64()?;
                 },
                 26 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
-                        match tag {
-                            10 => key = is.read_string()?,
-                            18 => value = is.read_message()?,
-                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
-                        };
-                    }
-                    is.pop_limit(old_limit);
-                    self.values.insert(key, value);
+                    self.table = is.read_string()?;
+                },
+                34 => {
+                    self.records = is.read_bytes()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1129,42 +7620,36 @@ impl ::protobuf::Message for InsertRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.hash_key.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
         }
-        if let Some(v) = self.sort_key.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.index);
+        }
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.table);
+        }
+        if !self.records.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.records);
         }
-        for (k, v) in &self.values {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            let len = v.compute_size();
-            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.hash_key.is_empty() {
-            os.write_string(1, &self.hash_key)?;
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
         }
-        if let Some(v) = self.sort_key.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if self.index != 0 {
+            os.write_uint64(2, self.index)?;
+        }
+        if !self.table.is_empty() {
+            os.write_string(3, &self.table)?;
+        }
+        if !self.records.is_empty() {
+            os.write_bytes(4, &self.records)?;
         }
-        for (k, v) in &self.values {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            let len = v.cached_size() as u64;
-            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-            os.write_raw_varint32(26)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_string(1, &k)?;
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1177,87 +7662,136 @@ impl ::protobuf::Message for InsertRequest {
         &mut self.special_fields
     }
 
-    fn new() -> InsertRequest {
-        InsertRequest::new()
+    fn new() -> RaftEntry {
+        RaftEntry::new()
     }
 
     fn clear(&mut self) {
-        self.hash_key.clear();
-        self.sort_key.clear();
-        self.values.clear();
+        self.term = 0;
+        self.index = 0;
+        self.table.clear();
+        self.records.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static InsertRequest {
-        static instance: ::protobuf::rt::Lazy<InsertRequest> = ::protobuf::rt::Lazy::new();
-        instance.get(InsertRequest::new)
+    fn default_instance() -> &'static RaftEntry {
+        static instance: RaftEntry = RaftEntry {
+            term: 0,
+            index: 0,
+            table: ::std::string::String::new(),
+            records: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for InsertRequest {
+impl ::protobuf::MessageFull for RaftEntry {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("InsertRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftEntry").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for InsertRequest {
+impl ::std::fmt::Display for RaftEntry {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for InsertRequest {
+impl ::protobuf::reflect::ProtobufValue for RaftEntry {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:DeleteRequest)
+// @@protoc_insertion_point(message:RaftAppendRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DeleteRequest {
+pub struct RaftAppendRequest {
     // message fields
-    // @@protoc_insertion_point(field:DeleteRequest.hash_key)
-    pub hash_key: ::std::string::String,
-    // @@protoc_insertion_point(field:DeleteRequest.sort_key)
-    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:RaftAppendRequest.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:RaftAppendRequest.leader_id)
+    pub leader_id: u64,
+    // @@protoc_insertion_point(field:RaftAppendRequest.prev_index)
+    pub prev_index: u64,
+    // @@protoc_insertion_point(field:RaftAppendRequest.prev_term)
+    pub prev_term: u64,
+    // @@protoc_insertion_point(field:RaftAppendRequest.entries)
+    pub entries: ::std::vec::Vec<RaftEntry>,
+    // @@protoc_insertion_point(field:RaftAppendRequest.leader_commit)
+    pub leader_commit: u64,
+    // @@protoc_insertion_point(field:RaftAppendRequest.schema_strings)
+    pub schema_strings: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:RaftAppendRequest.partition_map)
+    pub partition_map: ::std::string::String,
     // special fields
-    // @@protoc_insertion_point(special_field:DeleteRequest.special_fields)
+    // @@protoc_insertion_point(special_field:RaftAppendRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteRequest {
-    fn default() -> &'a DeleteRequest {
-        <DeleteRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RaftAppendRequest {
+    fn default() -> &'a RaftAppendRequest {
+        <RaftAppendRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteRequest {
-    pub fn new() -> DeleteRequest {
+impl RaftAppendRequest {
+    pub fn new() -> RaftAppendRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(8);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "hash_key",
-            |m: &DeleteRequest| { &m.hash_key },
-            |m: &mut DeleteRequest| { &mut m.hash_key },
+            "term",
+            |m: &RaftAppendRequest| { &m.term },
+            |m: &mut RaftAppendRequest| { &mut m.term },
         ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
-            "sort_key",
-            |m: &DeleteRequest| { &m.sort_key },
-            |m: &mut DeleteRequest| { &mut m.sort_key },
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_id",
+            |m: &RaftAppendRequest| { &m.leader_id },
+            |m: &mut RaftAppendRequest| { &mut m.leader_id },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteRequest>(
-            "DeleteRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_index",
+            |m: &RaftAppendRequest| { &m.prev_index },
+            |m: &mut RaftAppendRequest| { &mut m.prev_index },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "prev_term",
+            |m: &RaftAppendRequest| { &m.prev_term },
+            |m: &mut RaftAppendRequest| { &mut m.prev_term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &RaftAppendRequest| { &m.entries },
+            |m: &mut RaftAppendRequest| { &mut m.entries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader_commit",
+            |m: &RaftAppendRequest| { &m.leader_commit },
+            |m: &mut RaftAppendRequest| { &mut m.leader_commit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "schema_strings",
+            |m: &RaftAppendRequest| { &m.schema_strings },
+            |m: &mut RaftAppendRequest| { &mut m.schema_strings },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partition_map",
+            |m: &RaftAppendRequest| { &m.partition_map },
+            |m: &mut RaftAppendRequest| { &mut m.partition_map },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftAppendRequest>(
+            "RaftAppendRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DeleteRequest {
-    const NAME: &'static str = "DeleteRequest";
+impl ::protobuf::Message for RaftAppendRequest {
+    const NAME: &'static str = "RaftAppendRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1266,11 +7800,29 @@ impl ::protobuf::Message for DeleteRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.hash_key = is.read_string()?;
+                8 => {
+                    self.term = is.read_uint64()?;
                 },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                16 => {
+                    self.leader_id = is.read_uint64()?;
+                },
+                24 => {
+                    self.prev_index = is.read_uint64()?;
+                },
+                32 => {
+                    self.prev_term = is.read_uint64()?;
+                },
+                42 => {
+                    self.entries.push(is.read_message()?);
+                },
+                48 => {
+                    self.leader_commit = is.read_uint64()?;
+                },
+                58 => {
+                    self.schema_strings.push(is.read_string()?);
+                },
+                66 => {
+                    self.partition_map = is.read_string()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1284,12 +7836,30 @@ impl ::protobuf::Message for DeleteRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.hash_key.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
         }
-        if let Some(v) = self.sort_key.as_ref() {
-            let len = v.compute_size();
+        if self.leader_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.leader_id);
+        }
+        if self.prev_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.prev_index);
+        }
+        if self.prev_term != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.prev_term);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.leader_commit != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.leader_commit);
+        }
+        for value in &self.schema_strings {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if !self.partition_map.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.partition_map);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1297,11 +7867,29 @@ impl ::protobuf::Message for DeleteRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.hash_key.is_empty() {
-            os.write_string(1, &self.hash_key)?;
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
         }
-        if let Some(v) = self.sort_key.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        if self.leader_id != 0 {
+            os.write_uint64(2, self.leader_id)?;
+        }
+        if self.prev_index != 0 {
+            os.write_uint64(3, self.prev_index)?;
+        }
+        if self.prev_term != 0 {
+            os.write_uint64(4, self.prev_term)?;
+        }
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if self.leader_commit != 0 {
+            os.write_uint64(6, self.leader_commit)?;
+        }
+        for v in &self.schema_strings {
+            os.write_string(7, &v)?;
+        };
+        if !self.partition_map.is_empty() {
+            os.write_string(8, &self.partition_map)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1315,83 +7903,87 @@ impl ::protobuf::Message for DeleteRequest {
         &mut self.special_fields
     }
 
-    fn new() -> DeleteRequest {
-        DeleteRequest::new()
+    fn new() -> RaftAppendRequest {
+        RaftAppendRequest::new()
     }
 
     fn clear(&mut self) {
-        self.hash_key.clear();
-        self.sort_key.clear();
+        self.term = 0;
+        self.leader_id = 0;
+        self.prev_index = 0;
+        self.prev_term = 0;
+        self.entries.clear();
+        self.leader_commit = 0;
+        self.schema_strings.clear();
+        self.partition_map.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DeleteRequest {
-        static instance: DeleteRequest = DeleteRequest {
-            hash_key: ::std::string::String::new(),
-            sort_key: ::protobuf::MessageField::none(),
+    fn default_instance() -> &'static RaftAppendRequest {
+        static instance: RaftAppendRequest = RaftAppendRequest {
+            term: 0,
+            leader_id: 0,
+            prev_index: 0,
+            prev_term: 0,
+            entries: ::std::vec::Vec::new(),
+            leader_commit: 0,
+            schema_strings: ::std::vec::Vec::new(),
+            partition_map: ::std::string::String::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for DeleteRequest {
+impl ::protobuf::MessageFull for RaftAppendRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftAppendRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DeleteRequest {
+impl ::std::fmt::Display for RaftAppendRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteRequest {
+impl ::protobuf::reflect::ProtobufValue for RaftAppendRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:GetManyRequest)
+// @@protoc_insertion_point(message:KeyDistributionRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetManyRequest {
-    // message fields
-    // @@protoc_insertion_point(field:GetManyRequest.items)
-    pub items: ::std::vec::Vec<GetRequest>,
+pub struct KeyDistributionRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:GetManyRequest.special_fields)
+    // @@protoc_insertion_point(special_field:KeyDistributionRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetManyRequest {
-    fn default() -> &'a GetManyRequest {
-        <GetManyRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a KeyDistributionRequest {
+    fn default() -> &'a KeyDistributionRequest {
+        <KeyDistributionRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetManyRequest {
-    pub fn new() -> GetManyRequest {
+impl KeyDistributionRequest {
+    pub fn new() -> KeyDistributionRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "items",
-            |m: &GetManyRequest| { &m.items },
-            |m: &mut GetManyRequest| { &mut m.items },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetManyRequest>(
-            "GetManyRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyDistributionRequest>(
+            "KeyDistributionRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetManyRequest {
-    const NAME: &'static str = "GetManyRequest";
+impl ::protobuf::Message for KeyDistributionRequest {
+    const NAME: &'static str = "KeyDistributionRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1400,9 +7992,6 @@ impl ::protobuf::Message for GetManyRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.items.push(is.read_message()?);
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1415,19 +8004,12 @@ impl ::protobuf::Message for GetManyRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.items {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.items {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1440,81 +8022,71 @@ impl ::protobuf::Message for GetManyRequest {
         &mut self.special_fields
     }
 
-    fn new() -> GetManyRequest {
-        GetManyRequest::new()
+    fn new() -> KeyDistributionRequest {
+        KeyDistributionRequest::new()
     }
 
     fn clear(&mut self) {
-        self.items.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetManyRequest {
-        static instance: GetManyRequest = GetManyRequest {
-            items: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static KeyDistributionRequest {
+        static instance: KeyDistributionRequest = KeyDistributionRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetManyRequest {
+impl ::protobuf::MessageFull for KeyDistributionRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetManyRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("KeyDistributionRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetManyRequest {
+impl ::std::fmt::Display for KeyDistributionRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetManyRequest {
+impl ::protobuf::reflect::ProtobufValue for KeyDistributionRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:BatchRequest)
+// @@protoc_insertion_point(message:PartitionLoadRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BatchRequest {
-    // message fields
-    // @@protoc_insertion_point(field:BatchRequest.items)
-    pub items: ::std::vec::Vec<BatchItem>,
+pub struct PartitionLoadRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:BatchRequest.special_fields)
+    // @@protoc_insertion_point(special_field:PartitionLoadRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BatchRequest {
-    fn default() -> &'a BatchRequest {
-        <BatchRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PartitionLoadRequest {
+    fn default() -> &'a PartitionLoadRequest {
+        <PartitionLoadRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BatchRequest {
-    pub fn new() -> BatchRequest {
+impl PartitionLoadRequest {
+    pub fn new() -> PartitionLoadRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "items",
-            |m: &BatchRequest| { &m.items },
-            |m: &mut BatchRequest| { &mut m.items },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchRequest>(
-            "BatchRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PartitionLoadRequest>(
+            "PartitionLoadRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BatchRequest {
-    const NAME: &'static str = "BatchRequest";
+impl ::protobuf::Message for PartitionLoadRequest {
+    const NAME: &'static str = "PartitionLoadRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1523,9 +8095,6 @@ impl ::protobuf::Message for BatchRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.items.push(is.read_message()?);
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1538,19 +8107,12 @@ impl ::protobuf::Message for BatchRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.items {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.items {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1563,188 +8125,71 @@ impl ::protobuf::Message for BatchRequest {
         &mut self.special_fields
     }
 
-    fn new() -> BatchRequest {
-        BatchRequest::new()
+    fn new() -> PartitionLoadRequest {
+        PartitionLoadRequest::new()
     }
 
     fn clear(&mut self) {
-        self.items.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BatchRequest {
-        static instance: BatchRequest = BatchRequest {
-            items: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static PartitionLoadRequest {
+        static instance: PartitionLoadRequest = PartitionLoadRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BatchRequest {
+impl ::protobuf::MessageFull for PartitionLoadRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PartitionLoadRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BatchRequest {
+impl ::std::fmt::Display for PartitionLoadRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BatchRequest {
+impl ::protobuf::reflect::ProtobufValue for PartitionLoadRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:BatchItem)
+// @@protoc_insertion_point(message:TableAccessRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BatchItem {
-    // message oneof groups
-    pub item: ::std::option::Option<batch_item::Item>,
+pub struct TableAccessRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:BatchItem.special_fields)
+    // @@protoc_insertion_point(special_field:TableAccessRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BatchItem {
-    fn default() -> &'a BatchItem {
-        <BatchItem as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TableAccessRequest {
+    fn default() -> &'a TableAccessRequest {
+        <TableAccessRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BatchItem {
-    pub fn new() -> BatchItem {
+impl TableAccessRequest {
+    pub fn new() -> TableAccessRequest {
         ::std::default::Default::default()
     }
 
-    // .InsertRequest insert = 1;
-
-    pub fn insert(&self) -> &InsertRequest {
-        match self.item {
-            ::std::option::Option::Some(batch_item::Item::Insert(ref v)) => v,
-            _ => <InsertRequest as ::protobuf::Message>::default_instance(),
-        }
-    }
-
-    pub fn clear_insert(&mut self) {
-        self.item = ::std::option::Option::None;
-    }
-
-    pub fn has_insert(&self) -> bool {
-        match self.item {
-            ::std::option::Option::Some(batch_item::Item::Insert(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_insert(&mut self, v: InsertRequest) {
-        self.item = ::std::option::Option::Some(batch_item::Item::Insert(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_insert(&mut self) -> &mut InsertRequest {
-        if let ::std::option::Option::Some(batch_item::Item::Insert(_)) = self.item {
-        } else {
-            self.item = ::std::option::Option::Some(batch_item::Item::Insert(InsertRequest::new()));
-        }
-        match self.item {
-            ::std::option::Option::Some(batch_item::Item::Insert(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_insert(&mut self) -> InsertRequest {
-        if self.has_insert() {
-            match self.item.take() {
-                ::std::option::Option::Some(batch_item::Item::Insert(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            InsertRequest::new()
-        }
-    }
-
-    // .DeleteRequest delete = 2;
-
-    pub fn delete(&self) -> &DeleteRequest {
-        match self.item {
-            ::std::option::Option::Some(batch_item::Item::Delete(ref v)) => v,
-            _ => <DeleteRequest as ::protobuf::Message>::default_instance(),
-        }
-    }
-
-    pub fn clear_delete(&mut self) {
-        self.item = ::std::option::Option::None;
-    }
-
-    pub fn has_delete(&self) -> bool {
-        match self.item {
-            ::std::option::Option::Some(batch_item::Item::Delete(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_delete(&mut self, v: DeleteRequest) {
-        self.item = ::std::option::Option::Some(batch_item::Item::Delete(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_delete(&mut self) -> &mut DeleteRequest {
-        if let ::std::option::Option::Some(batch_item::Item::Delete(_)) = self.item {
-        } else {
-            self.item = ::std::option::Option::Some(batch_item::Item::Delete(DeleteRequest::new()));
-        }
-        match self.item {
-            ::std::option::Option::Some(batch_item::Item::Delete(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_delete(&mut self) -> DeleteRequest {
-        if self.has_delete() {
-            match self.item.take() {
-                ::std::option::Option::Some(batch_item::Item::Delete(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            DeleteRequest::new()
-        }
-    }
-
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
-        let mut oneofs = ::std::vec::Vec::with_capacity(1);
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, InsertRequest>(
-            "insert",
-            BatchItem::has_insert,
-            BatchItem::insert,
-            BatchItem::mut_insert,
-            BatchItem::set_insert,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteRequest>(
-            "delete",
-            BatchItem::has_delete,
-            BatchItem::delete,
-            BatchItem::mut_delete,
-            BatchItem::set_delete,
-        ));
-        oneofs.push(batch_item::Item::generated_oneof_descriptor_data());
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchItem>(
-            "BatchItem",
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TableAccessRequest>(
+            "TableAccessRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BatchItem {
-    const NAME: &'static str = "BatchItem";
+impl ::protobuf::Message for TableAccessRequest {
+    const NAME: &'static str = "TableAccessRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1753,12 +8198,6 @@ impl ::protobuf::Message for BatchItem {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.item = ::std::option::Option::Some(batch_item::Item::Insert(is.read_message()?));
-                },
-                18 => {
-                    self.item = ::std::option::Option::Some(batch_item::Item::Delete(is.read_message()?));
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1768,37 +8207,15 @@ impl ::protobuf::Message for BatchItem {
     }
 
     // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u64 {
-        let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.item {
-            match v {
-                &batch_item::Item::Insert(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &batch_item::Item::Delete(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-            };
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-        self.special_fields.cached_size().set(my_size as u32);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let ::std::option::Option::Some(ref v) = self.item {
-            match v {
-                &batch_item::Item::Insert(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-                },
-                &batch_item::Item::Delete(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-                },
-            };
-        }
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1811,104 +8228,71 @@ impl ::protobuf::Message for BatchItem {
         &mut self.special_fields
     }
 
-    fn new() -> BatchItem {
-        BatchItem::new()
+    fn new() -> TableAccessRequest {
+        TableAccessRequest::new()
     }
 
     fn clear(&mut self) {
-        self.item = ::std::option::Option::None;
-        self.item = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BatchItem {
-        static instance: BatchItem = BatchItem {
-            item: ::std::option::Option::None,
+    fn default_instance() -> &'static TableAccessRequest {
+        static instance: TableAccessRequest = TableAccessRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BatchItem {
+impl ::protobuf::MessageFull for TableAccessRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchItem").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TableAccessRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BatchItem {
+impl ::std::fmt::Display for TableAccessRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BatchItem {
+impl ::protobuf::reflect::ProtobufValue for TableAccessRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `BatchItem`
-pub mod batch_item {
-
-    #[derive(Clone,PartialEq,Debug)]
-    #[non_exhaustive]
-    // @@protoc_insertion_point(oneof:BatchItem.item)
-    pub enum Item {
-        // @@protoc_insertion_point(oneof_field:BatchItem.insert)
-        Insert(super::InsertRequest),
-        // @@protoc_insertion_point(oneof_field:BatchItem.delete)
-        Delete(super::DeleteRequest),
-    }
-
-    impl ::protobuf::Oneof for Item {
-    }
-
-    impl ::protobuf::OneofFull for Item {
-        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
-            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| <super::BatchItem as ::protobuf::MessageFull>::descriptor().oneof_by_name("item").unwrap()).clone()
-        }
-    }
-
-    impl Item {
-        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
-            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Item>("item")
-        }
-    }
-}
-
-// @@protoc_insertion_point(message:BeginTransaction)
+// @@protoc_insertion_point(message:ListTransactionsRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BeginTransaction {
+pub struct ListTransactionsRequest {
     // special fields
-    // @@protoc_insertion_point(special_field:BeginTransaction.special_fields)
+    // @@protoc_insertion_point(special_field:ListTransactionsRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BeginTransaction {
-    fn default() -> &'a BeginTransaction {
-        <BeginTransaction as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ListTransactionsRequest {
+    fn default() -> &'a ListTransactionsRequest {
+        <ListTransactionsRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BeginTransaction {
-    pub fn new() -> BeginTransaction {
+impl ListTransactionsRequest {
+    pub fn new() -> ListTransactionsRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BeginTransaction>(
-            "BeginTransaction",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListTransactionsRequest>(
+            "ListTransactionsRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BeginTransaction {
-    const NAME: &'static str = "BeginTransaction";
+impl ::protobuf::Message for ListTransactionsRequest {
+    const NAME: &'static str = "ListTransactionsRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1947,71 +8331,79 @@ impl ::protobuf::Message for BeginTransaction {
         &mut self.special_fields
     }
 
-    fn new() -> BeginTransaction {
-        BeginTransaction::new()
+    fn new() -> ListTransactionsRequest {
+        ListTransactionsRequest::new()
     }
 
     fn clear(&mut self) {
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BeginTransaction {
-        static instance: BeginTransaction = BeginTransaction {
+    fn default_instance() -> &'static ListTransactionsRequest {
+        static instance: ListTransactionsRequest = ListTransactionsRequest {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BeginTransaction {
+impl ::protobuf::MessageFull for ListTransactionsRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BeginTransaction").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListTransactionsRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BeginTransaction {
+impl ::std::fmt::Display for ListTransactionsRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BeginTransaction {
+impl ::protobuf::reflect::ProtobufValue for ListTransactionsRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:CommitTransaction)
+// @@protoc_insertion_point(message:KillTransactionRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct CommitTransaction {
+pub struct KillTransactionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:KillTransactionRequest.transaction_id)
+    pub transaction_id: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:CommitTransaction.special_fields)
+    // @@protoc_insertion_point(special_field:KillTransactionRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a CommitTransaction {
-    fn default() -> &'a CommitTransaction {
-        <CommitTransaction as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a KillTransactionRequest {
+    fn default() -> &'a KillTransactionRequest {
+        <KillTransactionRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CommitTransaction {
-    pub fn new() -> CommitTransaction {
+impl KillTransactionRequest {
+    pub fn new() -> KillTransactionRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CommitTransaction>(
-            "CommitTransaction",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transaction_id",
+            |m: &KillTransactionRequest| { &m.transaction_id },
+            |m: &mut KillTransactionRequest| { &mut m.transaction_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KillTransactionRequest>(
+            "KillTransactionRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for CommitTransaction {
-    const NAME: &'static str = "CommitTransaction";
+impl ::protobuf::Message for KillTransactionRequest {
+    const NAME: &'static str = "KillTransactionRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2020,6 +8412,9 @@ impl ::protobuf::Message for CommitTransaction {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                8 => {
+                    self.transaction_id = is.read_uint64()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -2032,12 +8427,18 @@ impl ::protobuf::Message for CommitTransaction {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if self.transaction_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.transaction_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.transaction_id != 0 {
+            os.write_uint64(1, self.transaction_id)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2050,71 +8451,109 @@ impl ::protobuf::Message for CommitTransaction {
         &mut self.special_fields
     }
 
-    fn new() -> CommitTransaction {
-        CommitTransaction::new()
+    fn new() -> KillTransactionRequest {
+        KillTransactionRequest::new()
     }
 
     fn clear(&mut self) {
+        self.transaction_id = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static CommitTransaction {
-        static instance: CommitTransaction = CommitTransaction {
+    fn default_instance() -> &'static KillTransactionRequest {
+        static instance: KillTransactionRequest = KillTransactionRequest {
+            transaction_id: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for CommitTransaction {
+impl ::protobuf::MessageFull for KillTransactionRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("CommitTransaction").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("KillTransactionRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for CommitTransaction {
+impl ::std::fmt::Display for KillTransactionRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CommitTransaction {
+impl ::protobuf::reflect::ProtobufValue for KillTransactionRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:AbortTransaction)
+// @@protoc_insertion_point(message:SetSessionRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct AbortTransaction {
+pub struct SetSessionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:SetSessionRequest.timeout_millis)
+    pub timeout_millis: u64,
+    // @@protoc_insertion_point(field:SetSessionRequest.consistency)
+    pub consistency: ::protobuf::EnumOrUnknown<Consistency>,
+    // @@protoc_insertion_point(field:SetSessionRequest.keyspace)
+    pub keyspace: ::std::string::String,
+    // @@protoc_insertion_point(field:SetSessionRequest.read_as_of_millis)
+    pub read_as_of_millis: u64,
+    // @@protoc_insertion_point(field:SetSessionRequest.latency_sample_every)
+    pub latency_sample_every: u32,
     // special fields
-    // @@protoc_insertion_point(special_field:AbortTransaction.special_fields)
+    // @@protoc_insertion_point(special_field:SetSessionRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a AbortTransaction {
-    fn default() -> &'a AbortTransaction {
-        <AbortTransaction as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetSessionRequest {
+    fn default() -> &'a SetSessionRequest {
+        <SetSessionRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl AbortTransaction {
-    pub fn new() -> AbortTransaction {
+impl SetSessionRequest {
+    pub fn new() -> SetSessionRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AbortTransaction>(
-            "AbortTransaction",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timeout_millis",
+            |m: &SetSessionRequest| { &m.timeout_millis },
+            |m: &mut SetSessionRequest| { &mut m.timeout_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "consistency",
+            |m: &SetSessionRequest| { &m.consistency },
+            |m: &mut SetSessionRequest| { &mut m.consistency },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "keyspace",
+            |m: &SetSessionRequest| { &m.keyspace },
+            |m: &mut SetSessionRequest| { &mut m.keyspace },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "read_as_of_millis",
+            |m: &SetSessionRequest| { &m.read_as_of_millis },
+            |m: &mut SetSessionRequest| { &mut m.read_as_of_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "latency_sample_every",
+            |m: &SetSessionRequest| { &m.latency_sample_every },
+            |m: &mut SetSessionRequest| { &mut m.latency_sample_every },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetSessionRequest>(
+            "SetSessionRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for AbortTransaction {
-    const NAME: &'static str = "AbortTransaction";
+impl ::protobuf::Message for SetSessionRequest {
+    const NAME: &'static str = "SetSessionRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2123,6 +8562,21 @@ impl ::protobuf::Message for AbortTransaction {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                8 => {
+                    self.timeout_millis = is.read_uint64()?;
+                },
+                16 => {
+                    self.consistency = is.read_enum_or_unknown()?;
+                },
+                26 => {
+                    self.keyspace = is.read_string()?;
+                },
+                32 => {
+                    self.read_as_of_millis = is.read_uint64()?;
+                },
+                40 => {
+                    self.latency_sample_every = is.read_uint32()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -2135,12 +8589,42 @@ impl ::protobuf::Message for AbortTransaction {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if self.timeout_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.timeout_millis);
+        }
+        if self.consistency != ::protobuf::EnumOrUnknown::new(Consistency::DEFAULT) {
+            my_size += ::protobuf::rt::int32_size(2, self.consistency.value());
+        }
+        if !self.keyspace.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.keyspace);
+        }
+        if self.read_as_of_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.read_as_of_millis);
+        }
+        if self.latency_sample_every != 0 {
+            my_size += ::protobuf::rt::uint32_size(5, self.latency_sample_every);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.timeout_millis != 0 {
+            os.write_uint64(1, self.timeout_millis)?;
+        }
+        if self.consistency != ::protobuf::EnumOrUnknown::new(Consistency::DEFAULT) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.consistency))?;
+        }
+        if !self.keyspace.is_empty() {
+            os.write_string(3, &self.keyspace)?;
+        }
+        if self.read_as_of_millis != 0 {
+            os.write_uint64(4, self.read_as_of_millis)?;
+        }
+        if self.latency_sample_every != 0 {
+            os.write_uint32(5, self.latency_sample_every)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2153,58 +8637,68 @@ impl ::protobuf::Message for AbortTransaction {
         &mut self.special_fields
     }
 
-    fn new() -> AbortTransaction {
-        AbortTransaction::new()
+    fn new() -> SetSessionRequest {
+        SetSessionRequest::new()
     }
 
     fn clear(&mut self) {
+        self.timeout_millis = 0;
+        self.consistency = ::protobuf::EnumOrUnknown::new(Consistency::DEFAULT);
+        self.keyspace.clear();
+        self.read_as_of_millis = 0;
+        self.latency_sample_every = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static AbortTransaction {
-        static instance: AbortTransaction = AbortTransaction {
+    fn default_instance() -> &'static SetSessionRequest {
+        static instance: SetSessionRequest = SetSessionRequest {
+            timeout_millis: 0,
+            consistency: ::protobuf::EnumOrUnknown::from_i32(0),
+            keyspace: ::std::string::String::new(),
+            read_as_of_millis: 0,
+            latency_sample_every: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for AbortTransaction {
+impl ::protobuf::MessageFull for SetSessionRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("AbortTransaction").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetSessionRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for AbortTransaction {
+impl ::std::fmt::Display for SetSessionRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for AbortTransaction {
+impl ::protobuf::reflect::ProtobufValue for SetSessionRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:SyncModelRequest)
+// @@protoc_insertion_point(message:SetReadOnlyRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct SyncModelRequest {
+pub struct SetReadOnlyRequest {
     // message fields
-    // @@protoc_insertion_point(field:SyncModelRequest.schema_string)
-    pub schema_string: ::std::string::String,
+    // @@protoc_insertion_point(field:SetReadOnlyRequest.read_only)
+    pub read_only: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:SyncModelRequest.special_fields)
+    // @@protoc_insertion_point(special_field:SetReadOnlyRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SyncModelRequest {
-    fn default() -> &'a SyncModelRequest {
-        <SyncModelRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetReadOnlyRequest {
+    fn default() -> &'a SetReadOnlyRequest {
+        <SetReadOnlyRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SyncModelRequest {
-    pub fn new() -> SyncModelRequest {
+impl SetReadOnlyRequest {
+    pub fn new() -> SetReadOnlyRequest {
         ::std::default::Default::default()
     }
 
@@ -2212,20 +8706,20 @@ impl SyncModelRequest {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "schema_string",
-            |m: &SyncModelRequest| { &m.schema_string },
-            |m: &mut SyncModelRequest| { &mut m.schema_string },
+            "read_only",
+            |m: &SetReadOnlyRequest| { &m.read_only },
+            |m: &mut SetReadOnlyRequest| { &mut m.read_only },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SyncModelRequest>(
-            "SyncModelRequest",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetReadOnlyRequest>(
+            "SetReadOnlyRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SyncModelRequest {
-    const NAME: &'static str = "SyncModelRequest";
+impl ::protobuf::Message for SetReadOnlyRequest {
+    const NAME: &'static str = "SetReadOnlyRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2234,8 +8728,8 @@ impl ::protobuf::Message for SyncModelRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.schema_string = is.read_string()?;
+                8 => {
+                    self.read_only = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -2249,8 +8743,8 @@ impl ::protobuf::Message for SyncModelRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.schema_string.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.schema_string);
+        if self.read_only != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -2258,8 +8752,8 @@ impl ::protobuf::Message for SyncModelRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.schema_string.is_empty() {
-            os.write_string(1, &self.schema_string)?;
+        if self.read_only != false {
+            os.write_bool(1, self.read_only)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2273,73 +8767,116 @@ impl ::protobuf::Message for SyncModelRequest {
         &mut self.special_fields
     }
 
-    fn new() -> SyncModelRequest {
-        SyncModelRequest::new()
+    fn new() -> SetReadOnlyRequest {
+        SetReadOnlyRequest::new()
     }
 
     fn clear(&mut self) {
-        self.schema_string.clear();
+        self.read_only = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SyncModelRequest {
-        static instance: SyncModelRequest = SyncModelRequest {
-            schema_string: ::std::string::String::new(),
+    fn default_instance() -> &'static SetReadOnlyRequest {
+        static instance: SetReadOnlyRequest = SetReadOnlyRequest {
+            read_only: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SyncModelRequest {
+impl ::protobuf::MessageFull for SetReadOnlyRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SyncModelRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetReadOnlyRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SyncModelRequest {
+impl ::std::fmt::Display for SetReadOnlyRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SyncModelRequest {
+impl ::protobuf::reflect::ProtobufValue for SetReadOnlyRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:DropTableRequest)
+// @@protoc_insertion_point(message:CompactionRequest)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DropTableRequest {
+pub struct CompactionRequest {
+    // message fields
+    // @@protoc_insertion_point(field:CompactionRequest.bucket_low)
+    pub bucket_low: ::std::option::Option<f64>,
+    // @@protoc_insertion_point(field:CompactionRequest.bucket_high)
+    pub bucket_high: ::std::option::Option<f64>,
+    // @@protoc_insertion_point(field:CompactionRequest.sstable_min_size)
+    pub sstable_min_size: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:CompactionRequest.interval_millis)
+    pub interval_millis: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:CompactionRequest.max_concurrent_compactions)
+    pub max_concurrent_compactions: ::std::option::Option<u32>,
+    // @@protoc_insertion_point(field:CompactionRequest.io_rate_limit_mb)
+    pub io_rate_limit_mb: ::std::option::Option<u64>,
     // special fields
-    // @@protoc_insertion_point(special_field:DropTableRequest.special_fields)
+    // @@protoc_insertion_point(special_field:CompactionRequest.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DropTableRequest {
-    fn default() -> &'a DropTableRequest {
-        <DropTableRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CompactionRequest {
+    fn default() -> &'a CompactionRequest {
+        <CompactionRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DropTableRequest {
-    pub fn new() -> DropTableRequest {
+impl CompactionRequest {
+    pub fn new() -> CompactionRequest {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(6);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DropTableRequest>(
-            "DropTableRequest",
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "bucket_low",
+            |m: &CompactionRequest| { &m.bucket_low },
+            |m: &mut CompactionRequest| { &mut m.bucket_low },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "bucket_high",
+            |m: &CompactionRequest| { &m.bucket_high },
+            |m: &mut CompactionRequest| { &mut m.bucket_high },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "sstable_min_size",
+            |m: &CompactionRequest| { &m.sstable_min_size },
+            |m: &mut CompactionRequest| { &mut m.sstable_min_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "interval_millis",
+            |m: &CompactionRequest| { &m.interval_millis },
+            |m: &mut CompactionRequest| { &mut m.interval_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "max_concurrent_compactions",
+            |m: &CompactionRequest| { &m.max_concurrent_compactions },
+            |m: &mut CompactionRequest| { &mut m.max_concurrent_compactions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "io_rate_limit_mb",
+            |m: &CompactionRequest| { &m.io_rate_limit_mb },
+            |m: &mut CompactionRequest| { &mut m.io_rate_limit_mb },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CompactionRequest>(
+            "CompactionRequest",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DropTableRequest {
-    const NAME: &'static str = "DropTableRequest";
+impl ::protobuf::Message for CompactionRequest {
+    const NAME: &'static str = "CompactionRequest";
 
     fn is_initialized(&self) -> bool {
         true
@@ -2348,6 +8885,24 @@ impl ::protobuf::Message for DropTableRequest {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                9 => {
+                    self.bucket_low = ::std::option::Option::Some(is.read_double()?);
+                },
+                17 => {
+                    self.bucket_high = ::std::option::Option::Some(is.read_double()?);
+                },
+                24 => {
+                    self.sstable_min_size = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                32 => {
+                    self.interval_millis = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                40 => {
+                    self.max_concurrent_compactions = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                48 => {
+                    self.io_rate_limit_mb = ::std::option::Option::Some(is.read_uint64()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -2360,12 +8915,48 @@ impl ::protobuf::Message for DropTableRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if let Some(v) = self.bucket_low {
+            my_size += 1 + 8;
+        }
+        if let Some(v) = self.bucket_high {
+            my_size += 1 + 8;
+        }
+        if let Some(v) = self.sstable_min_size {
+            my_size += ::protobuf::rt::uint64_size(3, v);
+        }
+        if let Some(v) = self.interval_millis {
+            my_size += ::protobuf::rt::uint64_size(4, v);
+        }
+        if let Some(v) = self.max_concurrent_compactions {
+            my_size += ::protobuf::rt::uint32_size(5, v);
+        }
+        if let Some(v) = self.io_rate_limit_mb {
+            my_size += ::protobuf::rt::uint64_size(6, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.bucket_low {
+            os.write_double(1, v)?;
+        }
+        if let Some(v) = self.bucket_high {
+            os.write_double(2, v)?;
+        }
+        if let Some(v) = self.sstable_min_size {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.interval_millis {
+            os.write_uint64(4, v)?;
+        }
+        if let Some(v) = self.max_concurrent_compactions {
+            os.write_uint32(5, v)?;
+        }
+        if let Some(v) = self.io_rate_limit_mb {
+            os.write_uint64(6, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2378,41 +8969,115 @@ impl ::protobuf::Message for DropTableRequest {
         &mut self.special_fields
     }
 
-    fn new() -> DropTableRequest {
-        DropTableRequest::new()
+    fn new() -> CompactionRequest {
+        CompactionRequest::new()
     }
 
     fn clear(&mut self) {
+        self.bucket_low = ::std::option::Option::None;
+        self.bucket_high = ::std::option::Option::None;
+        self.sstable_min_size = ::std::option::Option::None;
+        self.interval_millis = ::std::option::Option::None;
+        self.max_concurrent_compactions = ::std::option::Option::None;
+        self.io_rate_limit_mb = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DropTableRequest {
-        static instance: DropTableRequest = DropTableRequest {
+    fn default_instance() -> &'static CompactionRequest {
+        static instance: CompactionRequest = CompactionRequest {
+            bucket_low: ::std::option::Option::None,
+            bucket_high: ::std::option::Option::None,
+            sstable_min_size: ::std::option::Option::None,
+            interval_millis: ::std::option::Option::None,
+            max_concurrent_compactions: ::std::option::Option::None,
+            io_rate_limit_mb: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for DropTableRequest {
+impl ::protobuf::MessageFull for CompactionRequest {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DropTableRequest").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CompactionRequest").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DropTableRequest {
+impl ::std::fmt::Display for CompactionRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DropTableRequest {
+impl ::protobuf::reflect::ProtobufValue for CompactionRequest {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:Consistency)
+pub enum Consistency {
+    // @@protoc_insertion_point(enum_value:Consistency.DEFAULT)
+    DEFAULT = 0,
+    // @@protoc_insertion_point(enum_value:Consistency.DURABLE)
+    DURABLE = 1,
+}
+
+impl ::protobuf::Enum for Consistency {
+    const NAME: &'static str = "Consistency";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Consistency> {
+        match value {
+            0 => ::std::option::Option::Some(Consistency::DEFAULT),
+            1 => ::std::option::Option::Some(Consistency::DURABLE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<Consistency> {
+        match str {
+            "DEFAULT" => ::std::option::Option::Some(Consistency::DEFAULT),
+            "DURABLE" => ::std::option::Option::Some(Consistency::DURABLE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [Consistency] = &[
+        Consistency::DEFAULT,
+        Consistency::DURABLE,
+    ];
+}
+
+impl ::protobuf::EnumFull for Consistency {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("Consistency").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for Consistency {
+    fn default() -> Self {
+        Consistency::DEFAULT
+    }
+}
+
+impl Consistency {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<Consistency>("Consistency")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\rrequest.proto\x1a\x0ccommon.proto\"\xe1\x04\n\x07Request\x12\x1f\n\
+    \n\rrequest.proto\x1a\x0ccommon.proto\"\x90\x14\n\x07Request\x12\x1f\n\
     \x03get\x18\x01\x20\x01(\x0b2\x0b.GetRequestH\0R\x03get\x12(\n\x06insert\
     \x18\x02\x20\x01(\x0b2\x0e.InsertRequestH\0R\x06insert\x12(\n\x06delete\
     \x18\x03\x20\x01(\x0b2\x0e.DeleteRequestH\0R\x06delete\x12,\n\x08get_man\
@@ -2423,26 +9088,143 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ionH\0R\x11commitTransaction\x12@\n\x11abort_transaction\x18\x08\x20\x01\
     (\x0b2\x11.AbortTransactionH\0R\x10abortTransaction\x122\n\nsync_model\
     \x18\t\x20\x01(\x0b2\x11.SyncModelRequestH\0R\tsyncModel\x122\n\ndrop_ta\
-    ble\x18\n\x20\x01(\x0b2\x11.DropTableRequestH\0R\tdropTable\x12*\n\x0etr\
-    ansaction_id\x18\x0b\x20\x01(\x04H\x01R\rtransactionId\x88\x01\x01\x12\
-    \x14\n\x05table\x18\x0c\x20\x01(\tR\x05tableB\x06\n\x04dataB\x11\n\x0f_t\
-    ransaction_id\"J\n\nGetRequest\x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\
-    \x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b2\x06.ValueR\x07sortK\
-    ey\"\xc4\x01\n\rInsertRequest\x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\
-    \x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b2\x06.ValueR\x07sortK\
-    ey\x122\n\x06values\x18\x03\x20\x03(\x0b2\x1a.InsertRequest.ValuesEntryR\
-    \x06values\x1aA\n\x0bValuesEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
-    \x03key\x12\x1c\n\x05value\x18\x02\x20\x01(\x0b2\x06.ValueR\x05value:\
-    \x028\x01\"M\n\rDeleteRequest\x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\
-    \x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b2\x06.ValueR\x07sortK\
-    ey\"3\n\x0eGetManyRequest\x12!\n\x05items\x18\x01\x20\x03(\x0b2\x0b.GetR\
-    equestR\x05items\"0\n\x0cBatchRequest\x12\x20\n\x05items\x18\x01\x20\x03\
-    (\x0b2\n.BatchItemR\x05items\"g\n\tBatchItem\x12(\n\x06insert\x18\x01\
-    \x20\x01(\x0b2\x0e.InsertRequestH\0R\x06insert\x12(\n\x06delete\x18\x02\
-    \x20\x01(\x0b2\x0e.DeleteRequestH\0R\x06deleteB\x06\n\x04item\"\x12\n\
-    \x10BeginTransaction\"\x13\n\x11CommitTransaction\"\x12\n\x10AbortTransa\
-    ction\"7\n\x10SyncModelRequest\x12#\n\rschema_string\x18\x01\x20\x01(\tR\
-    \x0cschemaString\"\x12\n\x10DropTableRequestb\x06proto3\
+    ble\x18\n\x20\x01(\x0b2\x11.DropTableRequestH\0R\tdropTable\x12>\n\x0epl\
+    an_migration\x18\r\x20\x01(\x0b2\x15.PlanMigrationRequestH\0R\rplanMigra\
+    tion\x128\n\x0cget_sequence\x18\x0e\x20\x01(\x0b2\x13.GetSequenceRequest\
+    H\0R\x0bgetSequence\x12;\n\racquire_lease\x18\x0f\x20\x01(\x0b2\x14.Acqu\
+    ireLeaseRequestH\0R\x0cacquireLease\x125\n\x0brenew_lease\x18\x10\x20\
+    \x01(\x0b2\x12.RenewLeaseRequestH\0R\nrenewLease\x12;\n\rrelease_lease\
+    \x18\x11\x20\x01(\x0b2\x14.ReleaseLeaseRequestH\0R\x0creleaseLease\x12/\
+    \n\trow_count\x18\x12\x20\x01(\x0b2\x10.RowCountRequestH\0R\x08rowCount\
+    \x12>\n\x0edescribe_table\x18\x13\x20\x01(\x0b2\x15.DescribeTableRequest\
+    H\0R\rdescribeTable\x12(\n\x06health\x18\x14\x20\x01(\x0b2\x0e.HealthReq\
+    uestH\0R\x06health\x12%\n\x05ready\x18\x15\x20\x01(\x0b2\r.ReadyRequestH\
+    \0R\x05ready\x12;\n\rpartition_map\x18\x16\x20\x01(\x0b2\x14.PartitionMa\
+    pRequestH\0R\x0cpartitionMap\x12>\n\x0emigrate_bucket\x18\x17\x20\x01(\
+    \x0b2\x15.MigrateBucketRequestH\0R\rmigrateBucket\x12D\n\x10key_distribu\
+    tion\x18\x18\x20\x01(\x0b2\x17.KeyDistributionRequestH\0R\x0fkeyDistribu\
+    tion\x12G\n\x11list_transactions\x18\x19\x20\x01(\x0b2\x18.ListTransacti\
+    onsRequestH\0R\x10listTransactions\x12D\n\x10kill_transaction\x18\x1a\
+    \x20\x01(\x0b2\x17.KillTransactionRequestH\0R\x0fkillTransaction\x125\n\
+    \x0bset_session\x18\x1b\x20\x01(\x0b2\x12.SetSessionRequestH\0R\nsetSess\
+    ion\x129\n\rset_read_only\x18\x1c\x20\x01(\x0b2\x13.SetReadOnlyRequestH\
+    \0R\x0bsetReadOnly\x124\n\ncompaction\x18\x1e\x20\x01(\x0b2\x12.Compacti\
+    onRequestH\0R\ncompaction\x125\n\x0bsync_models\x18\x20\x20\x01(\x0b2\
+    \x12.SyncModelsRequestH\0R\nsyncModels\x12A\n\x0fdescribe_server\x18\"\
+    \x20\x01(\x0b2\x16.DescribeServerRequestH\0R\x0edescribeServer\x12>\n\
+    \x0epartition_load\x18$\x20\x01(\x0b2\x15.PartitionLoadRequestH\0R\rpart\
+    itionLoad\x12<\n\x0equery_by_index\x18'\x20\x01(\x0b2\x14.QueryByIndexRe\
+    questH\0R\x0cqueryByIndex\x128\n\x0ctable_access\x18(\x20\x01(\x0b2\x13.\
+    TableAccessRequestH\0R\x0btableAccess\x128\n\x0cdelete_where\x18)\x20\
+    \x01(\x0b2\x13.DeleteWhereRequestH\0R\x0bdeleteWhere\x12,\n\x08move_row\
+    \x18*\x20\x01(\x0b2\x0f.MoveRowRequestH\0R\x07moveRow\x12\"\n\x04save\
+    \x18+\x20\x01(\x0b2\x0c.SaveRequestH\0R\x04save\x121\n\treplicate\x18,\
+    \x20\x01(\x0b2\x11.ReplicateRequestH\0R\treplicate\x12+\n\x07promote\x18\
+    -\x20\x01(\x0b2\x0f.PromoteRequestH\0R\x07promote\x12/\n\traft_vote\x18.\
+    \x20\x01(\x0b2\x10.RaftVoteRequestH\0R\x08raftVote\x125\n\x0braft_append\
+    \x18/\x20\x01(\x0b2\x12.RaftAppendRequestH\0R\nraftAppend\x12*\n\x0etran\
+    saction_id\x18\x0b\x20\x01(\x04H\x01R\rtransactionId\x88\x01\x01\x12\x14\
+    \n\x05table\x18\x0c\x20\x01(\tR\x05table\x12\x1a\n\x08database\x18\x1d\
+    \x20\x01(\tR\x08database\x122\n\x15min_consistency_token\x18\x1f\x20\x01\
+    (\tR\x13minConsistencyToken\x12\x18\n\x07explain\x18!\x20\x01(\x08R\x07e\
+    xplain\x12-\n\x12begins_transaction\x18#\x20\x01(\x08R\x11beginsTransact\
+    ion\x12%\n\x0eschema_version\x18%\x20\x01(\x04R\rschemaVersion\x12?\n\rt\
+    race_context\x18&\x20\x03(\x0b2\x1a.Request.TraceContextEntryR\x0ctraceC\
+    ontext\x1a?\n\x11TraceContextEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\
+    \x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01B\x06\n\
+    \x04dataB\x11\n\x0f_transaction_id\"J\n\nGetRequest\x12\x19\n\x08hash_ke\
+    y\x18\x01\x20\x01(\tR\x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b\
+    2\x06.ValueR\x07sortKey\"\xf1\x01\n\rInsertRequest\x12\x19\n\x08hash_key\
+    \x18\x01\x20\x01(\tR\x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b2\
+    \x06.ValueR\x07sortKey\x122\n\x06values\x18\x03\x20\x03(\x0b2\x1a.Insert\
+    Request.ValuesEntryR\x06values\x12+\n\rcolumn_values\x18\x04\x20\x03(\
+    \x0b2\x06.ValueR\x0ccolumnValues\x1aA\n\x0bValuesEntry\x12\x10\n\x03key\
+    \x18\x01\x20\x01(\tR\x03key\x12\x1c\n\x05value\x18\x02\x20\x01(\x0b2\x06\
+    .ValueR\x05value:\x028\x01\"M\n\rDeleteRequest\x12\x19\n\x08hash_key\x18\
+    \x01\x20\x01(\tR\x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b2\x06\
+    .ValueR\x07sortKey\"/\n\x12GetSequenceRequest\x12\x19\n\x08hash_key\x18\
+    \x01\x20\x01(\tR\x07hashKey\"H\n\x13AcquireLeaseRequest\x12\x12\n\x04nam\
+    e\x18\x01\x20\x01(\tR\x04name\x12\x1d\n\nttl_millis\x18\x02\x20\x01(\x04\
+    R\tttlMillis\"`\n\x11RenewLeaseRequest\x12\x12\n\x04name\x18\x01\x20\x01\
+    (\tR\x04name\x12\x18\n\x07version\x18\x02\x20\x01(\x04R\x07version\x12\
+    \x1d\n\nttl_millis\x18\x03\x20\x01(\x04R\tttlMillis\"C\n\x13ReleaseLease\
+    Request\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x18\n\x07versi\
+    on\x18\x02\x20\x01(\x04R\x07version\",\n\x0fRowCountRequest\x12\x19\n\
+    \x08hash_key\x18\x01\x20\x01(\tR\x07hashKey\"\xc6\x01\n\x0eGetManyReques\
+    t\x12!\n\x05items\x18\x01\x20\x03(\x0b2\x0b.GetRequestR\x05items\x12\x1e\
+    \n\nconsistent\x18\x02\x20\x01(\x08R\nconsistent\x12\x14\n\x05limit\x18\
+    \x03\x20\x01(\rR\x05limit\x12,\n\x12max_response_bytes\x18\x04\x20\x01(\
+    \x04R\x10maxResponseBytes\x12-\n\x12continuation_token\x18\x05\x20\x01(\
+    \tR\x11continuationToken\"0\n\x0cBatchRequest\x12\x20\n\x05items\x18\x01\
+    \x20\x03(\x0b2\n.BatchItemR\x05items\"g\n\tBatchItem\x12(\n\x06insert\
+    \x18\x01\x20\x01(\x0b2\x0e.InsertRequestH\0R\x06insert\x12(\n\x06delete\
+    \x18\x02\x20\x01(\x0b2\x0e.DeleteRequestH\0R\x06deleteB\x06\n\x04item\"\
+    \x12\n\x10BeginTransaction\"\x13\n\x11CommitTransaction\"\x12\n\x10Abort\
+    Transaction\"7\n\x10SyncModelRequest\x12#\n\rschema_string\x18\x01\x20\
+    \x01(\tR\x0cschemaString\":\n\x11SyncModelsRequest\x12%\n\x0eschema_stri\
+    ngs\x18\x01\x20\x03(\tR\rschemaStrings\"\x12\n\x10DropTableRequest\";\n\
+    \x14PlanMigrationRequest\x12#\n\rschema_string\x18\x01\x20\x01(\tR\x0csc\
+    hemaString\"\x16\n\x14DescribeTableRequest\"\x0f\n\rHealthRequest\"\x0e\
+    \n\x0cReadyRequest\"\x17\n\x15DescribeServerRequest\"\x15\n\x13Partition\
+    MapRequest\"\\\n\x14MigrateBucketRequest\x12\x19\n\x08hash_key\x18\x01\
+    \x20\x01(\tR\x07hashKey\x12)\n\x10target_partition\x18\x02\x20\x01(\rR\
+    \x0ftargetPartition\"\xf8\x01\n\x13QueryByIndexRequest\x12\x16\n\x06colu\
+    mn\x18\x01\x20\x01(\tR\x06column\x12\x1c\n\x05start\x18\x02\x20\x01(\x0b\
+    2\x06.ValueR\x05start\x12'\n\x0fstart_inclusive\x18\x03\x20\x01(\x08R\
+    \x0estartInclusive\x12\x18\n\x03end\x18\x04\x20\x01(\x0b2\x06.ValueR\x03\
+    end\x12#\n\rend_inclusive\x18\x05\x20\x01(\x08R\x0cendInclusive\x12\x14\
+    \n\x05limit\x18\x06\x20\x01(\rR\x05limit\x12-\n\x12continuation_token\
+    \x18\x07\x20\x01(\tR\x11continuationToken\"\x9c\x02\n\x12DeleteWhereRequ\
+    est\x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\x07hashKey\x12\x1c\n\x05st\
+    art\x18\x02\x20\x01(\x0b2\x06.ValueR\x05start\x12'\n\x0fstart_inclusive\
+    \x18\x03\x20\x01(\x08R\x0estartInclusive\x12\x18\n\x03end\x18\x04\x20\
+    \x01(\x0b2\x06.ValueR\x03end\x12#\n\rend_inclusive\x18\x05\x20\x01(\x08R\
+    \x0cendInclusive\x12(\n\rfilter_column\x18\x06\x20\x01(\tH\0R\x0cfilterC\
+    olumn\x88\x01\x01\x12)\n\x0cfilter_value\x18\x07\x20\x01(\x0b2\x06.Value\
+    R\x0bfilterValueB\x10\n\x0e_filter_column\"\x9a\x01\n\x0eMoveRowRequest\
+    \x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\x07hashKey\x12!\n\x08sort_key\
+    \x18\x02\x20\x01(\x0b2\x06.ValueR\x07sortKey\x12\x20\n\x0cnew_hash_key\
+    \x18\x03\x20\x01(\tR\nnewHashKey\x12(\n\x0cnew_sort_key\x18\x04\x20\x01(\
+    \x0b2\x06.ValueR\nnewSortKey\"O\n\x0bSaveRequest\x12&\n\x06insert\x18\
+    \x01\x20\x01(\x0b2\x0e.InsertRequestR\x06insert\x12\x18\n\x07version\x18\
+    \x02\x20\x01(\x04R\x07version\"D\n\x10ReplicateRequest\x12\x14\n\x05epoc\
+    h\x18\x01\x20\x01(\x04R\x05epoch\x12\x1a\n\x08sequence\x18\x02\x20\x01(\
+    \x04R\x08sequence\"\x10\n\x0ePromoteRequest\";\n\x0fRaftLogPosition\x12\
+    \x14\n\x05index\x18\x01\x20\x01(\x04R\x05index\x12\x12\n\x04term\x18\x02\
+    \x20\x01(\x04R\x04term\"\x81\x01\n\x0fRaftVoteRequest\x12\x12\n\x04term\
+    \x18\x01\x20\x01(\x04R\x04term\x12!\n\x0ccandidate_id\x18\x02\x20\x01(\
+    \x04R\x0bcandidateId\x127\n\x0elast_positions\x18\x03\x20\x03(\x0b2\x10.\
+    RaftLogPositionR\rlastPositions\"e\n\tRaftEntry\x12\x12\n\x04term\x18\
+    \x01\x20\x01(\x04R\x04term\x12\x14\n\x05index\x18\x02\x20\x01(\x04R\x05i\
+    ndex\x12\x14\n\x05table\x18\x03\x20\x01(\tR\x05table\x12\x18\n\x07record\
+    s\x18\x04\x20\x01(\x0cR\x07records\"\x97\x02\n\x11RaftAppendRequest\x12\
+    \x12\n\x04term\x18\x01\x20\x01(\x04R\x04term\x12\x1b\n\tleader_id\x18\
+    \x02\x20\x01(\x04R\x08leaderId\x12\x1d\n\nprev_index\x18\x03\x20\x01(\
+    \x04R\tprevIndex\x12\x1b\n\tprev_term\x18\x04\x20\x01(\x04R\x08prevTerm\
+    \x12$\n\x07entries\x18\x05\x20\x03(\x0b2\n.RaftEntryR\x07entries\x12#\n\
+    \rleader_commit\x18\x06\x20\x01(\x04R\x0cleaderCommit\x12%\n\x0eschema_s\
+    trings\x18\x07\x20\x03(\tR\rschemaStrings\x12#\n\rpartition_map\x18\x08\
+    \x20\x01(\tR\x0cpartitionMap\"\x18\n\x16KeyDistributionRequest\"\x16\n\
+    \x14PartitionLoadRequest\"\x14\n\x12TableAccessRequest\"\x19\n\x17ListTr\
+    ansactionsRequest\"?\n\x16KillTransactionRequest\x12%\n\x0etransaction_i\
+    d\x18\x01\x20\x01(\x04R\rtransactionId\"\xe3\x01\n\x11SetSessionRequest\
+    \x12%\n\x0etimeout_millis\x18\x01\x20\x01(\x04R\rtimeoutMillis\x12.\n\
+    \x0bconsistency\x18\x02\x20\x01(\x0e2\x0c.ConsistencyR\x0bconsistency\
+    \x12\x1a\n\x08keyspace\x18\x03\x20\x01(\tR\x08keyspace\x12)\n\x11read_as\
+    _of_millis\x18\x04\x20\x01(\x04R\x0ereadAsOfMillis\x120\n\x14latency_sam\
+    ple_every\x18\x05\x20\x01(\rR\x12latencySampleEvery\"1\n\x12SetReadOnlyR\
+    equest\x12\x1b\n\tread_only\x18\x01\x20\x01(\x08R\x08readOnly\"\xa7\x03\
+    \n\x11CompactionRequest\x12\"\n\nbucket_low\x18\x01\x20\x01(\x01H\0R\tbu\
+    cketLow\x88\x01\x01\x12$\n\x0bbucket_high\x18\x02\x20\x01(\x01H\x01R\nbu\
+    cketHigh\x88\x01\x01\x12-\n\x10sstable_min_size\x18\x03\x20\x01(\x04H\
+    \x02R\x0esstableMinSize\x88\x01\x01\x12,\n\x0finterval_millis\x18\x04\
+    \x20\x01(\x04H\x03R\x0eintervalMillis\x88\x01\x01\x12A\n\x1amax_concurre\
+    nt_compactions\x18\x05\x20\x01(\rH\x04R\x18maxConcurrentCompactions\x88\
+    \x01\x01\x12,\n\x10io_rate_limit_mb\x18\x06\x20\x01(\x04H\x05R\rioRateLi\
+    mitMb\x88\x01\x01B\r\n\x0b_bucket_lowB\x0e\n\x0c_bucket_highB\x13\n\x11_\
+    sstable_min_sizeB\x12\n\x10_interval_millisB\x1d\n\x1b_max_concurrent_co\
+    mpactionsB\x13\n\x11_io_rate_limit_mb*'\n\x0bConsistency\x12\x0b\n\x07DE\
+    FAULT\x10\0\x12\x0b\n\x07DURABLE\x10\x01b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -2461,11 +9243,16 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(super::common::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(12);
+            let mut messages = ::std::vec::Vec::with_capacity(43);
             messages.push(Request::generated_message_descriptor_data());
             messages.push(GetRequest::generated_message_descriptor_data());
             messages.push(InsertRequest::generated_message_descriptor_data());
             messages.push(DeleteRequest::generated_message_descriptor_data());
+            messages.push(GetSequenceRequest::generated_message_descriptor_data());
+            messages.push(AcquireLeaseRequest::generated_message_descriptor_data());
+            messages.push(RenewLeaseRequest::generated_message_descriptor_data());
+            messages.push(ReleaseLeaseRequest::generated_message_descriptor_data());
+            messages.push(RowCountRequest::generated_message_descriptor_data());
             messages.push(GetManyRequest::generated_message_descriptor_data());
             messages.push(BatchRequest::generated_message_descriptor_data());
             messages.push(BatchItem::generated_message_descriptor_data());
@@ -2473,8 +9260,35 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
             messages.push(CommitTransaction::generated_message_descriptor_data());
             messages.push(AbortTransaction::generated_message_descriptor_data());
             messages.push(SyncModelRequest::generated_message_descriptor_data());
+            messages.push(SyncModelsRequest::generated_message_descriptor_data());
             messages.push(DropTableRequest::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            messages.push(PlanMigrationRequest::generated_message_descriptor_data());
+            messages.push(DescribeTableRequest::generated_message_descriptor_data());
+            messages.push(HealthRequest::generated_message_descriptor_data());
+            messages.push(ReadyRequest::generated_message_descriptor_data());
+            messages.push(DescribeServerRequest::generated_message_descriptor_data());
+            messages.push(PartitionMapRequest::generated_message_descriptor_data());
+            messages.push(MigrateBucketRequest::generated_message_descriptor_data());
+            messages.push(QueryByIndexRequest::generated_message_descriptor_data());
+            messages.push(DeleteWhereRequest::generated_message_descriptor_data());
+            messages.push(MoveRowRequest::generated_message_descriptor_data());
+            messages.push(SaveRequest::generated_message_descriptor_data());
+            messages.push(ReplicateRequest::generated_message_descriptor_data());
+            messages.push(PromoteRequest::generated_message_descriptor_data());
+            messages.push(RaftLogPosition::generated_message_descriptor_data());
+            messages.push(RaftVoteRequest::generated_message_descriptor_data());
+            messages.push(RaftEntry::generated_message_descriptor_data());
+            messages.push(RaftAppendRequest::generated_message_descriptor_data());
+            messages.push(KeyDistributionRequest::generated_message_descriptor_data());
+            messages.push(PartitionLoadRequest::generated_message_descriptor_data());
+            messages.push(TableAccessRequest::generated_message_descriptor_data());
+            messages.push(ListTransactionsRequest::generated_message_descriptor_data());
+            messages.push(KillTransactionRequest::generated_message_descriptor_data());
+            messages.push(SetSessionRequest::generated_message_descriptor_data());
+            messages.push(SetReadOnlyRequest::generated_message_descriptor_data());
+            messages.push(CompactionRequest::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(Consistency::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
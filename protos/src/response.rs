@@ -28,6 +28,11 @@ const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_3_0;
 // @@protoc_insertion_point(message:Response)
 #[derive(PartialEq,Clone,Default,Debug)]
 pub struct Response {
+    // message fields
+    // @@protoc_insertion_point(field:Response.latency)
+    pub latency: ::protobuf::MessageField<LatencyBreakdown>,
+    // @@protoc_insertion_point(field:Response.read_explanations)
+    pub read_explanations: ::std::vec::Vec<ReadExplanation>,
     // message oneof groups
     pub data: ::std::option::Option<response::Data>,
     // special fields
@@ -536,256 +541,1898 @@ impl Response {
         }
     }
 
-    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(10);
-        let mut oneofs = ::std::vec::Vec::with_capacity(1);
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetResponse>(
-            "get",
-            Response::has_get,
-            Response::get,
-            Response::mut_get,
-            Response::set_get,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, InsertResponse>(
-            "insert",
-            Response::has_insert,
-            Response::insert,
-            Response::mut_insert,
-            Response::set_insert,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteResponse>(
-            "delete",
-            Response::has_delete,
-            Response::delete,
-            Response::mut_delete,
-            Response::set_delete,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetManyResponse>(
-            "get_many",
-            Response::has_get_many,
-            Response::get_many,
-            Response::mut_get_many,
-            Response::set_get_many,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, BatchResponse>(
-            "batch",
-            Response::has_batch,
-            Response::batch,
-            Response::mut_batch,
-            Response::set_batch,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TransactionResponse>(
-            "transaction",
-            Response::has_transaction,
-            Response::transaction,
-            Response::mut_transaction,
-            Response::set_transaction,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SyncModelResponse>(
-            "model",
-            Response::has_model,
-            Response::model,
-            Response::mut_model,
-            Response::set_model,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DropTableResponse>(
-            "drop_table",
-            Response::has_drop_table,
-            Response::drop_table,
-            Response::mut_drop_table,
-            Response::set_drop_table,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ClientError>(
-            "client_error",
-            Response::has_client_error,
-            Response::client_error,
-            Response::mut_client_error,
-            Response::set_client_error,
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ServerError>(
-            "server_error",
-            Response::has_server_error,
-            Response::server_error,
-            Response::mut_server_error,
-            Response::set_server_error,
-        ));
-        oneofs.push(response::Data::generated_oneof_descriptor_data());
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Response>(
-            "Response",
-            fields,
-            oneofs,
-        )
-    }
-}
+    // .PlanMigrationResponse plan_migration = 11;
 
-impl ::protobuf::Message for Response {
-    const NAME: &'static str = "Response";
+    pub fn plan_migration(&self) -> &PlanMigrationResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::PlanMigration(ref v)) => v,
+            _ => <PlanMigrationResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
 
-    fn is_initialized(&self) -> bool {
-        true
+    pub fn clear_plan_migration(&mut self) {
+        self.data = ::std::option::Option::None;
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
-        while let Some(tag) = is.read_raw_tag_or_eof()? {
-            match tag {
-                10 => {
-                    self.data = ::std::option::Option::Some(response::Data::Get(is.read_message()?));
-                },
-                18 => {
-                    self.data = ::std::option::Option::Some(response::Data::Insert(is.read_message()?));
-                },
-                26 => {
-                    self.data = ::std::option::Option::Some(response::Data::Delete(is.read_message()?));
-                },
-                34 => {
-                    self.data = ::std::option::Option::Some(response::Data::GetMany(is.read_message()?));
-                },
-                42 => {
-                    self.data = ::std::option::Option::Some(response::Data::Batch(is.read_message()?));
-                },
-                50 => {
-                    self.data = ::std::option::Option::Some(response::Data::Transaction(is.read_message()?));
-                },
-                58 => {
-                    self.data = ::std::option::Option::Some(response::Data::Model(is.read_message()?));
-                },
-                66 => {
-                    self.data = ::std::option::Option::Some(response::Data::DropTable(is.read_message()?));
-                },
-                74 => {
-                    self.data = ::std::option::Option::Some(response::Data::ClientError(is.read_message()?));
-                },
-                82 => {
-                    self.data = ::std::option::Option::Some(response::Data::ServerError(is.read_message()?));
-                },
-                tag => {
-                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
-                },
-            };
+    pub fn has_plan_migration(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::PlanMigration(..)) => true,
+            _ => false,
         }
-        ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u64 {
-        let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &response::Data::Get(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::Insert(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::Delete(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::GetMany(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::Batch(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::Transaction(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::Model(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::DropTable(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::ClientError(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-                &response::Data::ServerError(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-                },
-            };
+    // Param is passed by value, moved
+    pub fn set_plan_migration(&mut self, v: PlanMigrationResponse) {
+        self.data = ::std::option::Option::Some(response::Data::PlanMigration(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_plan_migration(&mut self) -> &mut PlanMigrationResponse {
+        if let ::std::option::Option::Some(response::Data::PlanMigration(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::PlanMigration(PlanMigrationResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::PlanMigration(ref mut v)) => v,
+            _ => panic!(),
         }
-        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
-        self.special_fields.cached_size().set(my_size as u32);
-        my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &response::Data::Get(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-                },
-                &response::Data::Insert(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-                },
-                &response::Data::Delete(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
-                },
-                &response::Data::GetMany(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
-                },
-                &response::Data::Batch(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
-                },
-                &response::Data::Transaction(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
-                },
-                &response::Data::Model(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
-                },
-                &response::Data::DropTable(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
-                },
-                &response::Data::ClientError(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
-                },
-                &response::Data::ServerError(ref v) => {
-                    ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
-                },
-            };
+    // Take field
+    pub fn take_plan_migration(&mut self) -> PlanMigrationResponse {
+        if self.has_plan_migration() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::PlanMigration(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PlanMigrationResponse::new()
         }
-        os.write_unknown_fields(self.special_fields.unknown_fields())?;
-        ::std::result::Result::Ok(())
     }
 
-    fn special_fields(&self) -> &::protobuf::SpecialFields {
-        &self.special_fields
+    // .SequenceResponse sequence = 12;
+
+    pub fn sequence(&self) -> &SequenceResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Sequence(ref v)) => v,
+            _ => <SequenceResponse as ::protobuf::Message>::default_instance(),
+        }
     }
 
-    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
-        &mut self.special_fields
+    pub fn clear_sequence(&mut self) {
+        self.data = ::std::option::Option::None;
     }
 
-    fn new() -> Response {
-        Response::new()
+    pub fn has_sequence(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Sequence(..)) => true,
+            _ => false,
+        }
     }
 
-    fn clear(&mut self) {
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.data = ::std::option::Option::None;
-        self.special_fields.clear();
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: SequenceResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Sequence(v))
     }
 
-    fn default_instance() -> &'static Response {
-        static instance: Response = Response {
+    // Mutable pointer to the field.
+    pub fn mut_sequence(&mut self) -> &mut SequenceResponse {
+        if let ::std::option::Option::Some(response::Data::Sequence(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Sequence(SequenceResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Sequence(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_sequence(&mut self) -> SequenceResponse {
+        if self.has_sequence() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Sequence(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SequenceResponse::new()
+        }
+    }
+
+    // .LeaseResponse lease = 13;
+
+    pub fn lease(&self) -> &LeaseResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Lease(ref v)) => v,
+            _ => <LeaseResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_lease(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_lease(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Lease(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lease(&mut self, v: LeaseResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Lease(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_lease(&mut self) -> &mut LeaseResponse {
+        if let ::std::option::Option::Some(response::Data::Lease(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Lease(LeaseResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Lease(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_lease(&mut self) -> LeaseResponse {
+        if self.has_lease() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Lease(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            LeaseResponse::new()
+        }
+    }
+
+    // .RowCountResponse row_count = 14;
+
+    pub fn row_count(&self) -> &RowCountResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::RowCount(ref v)) => v,
+            _ => <RowCountResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_row_count(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_row_count(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::RowCount(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_row_count(&mut self, v: RowCountResponse) {
+        self.data = ::std::option::Option::Some(response::Data::RowCount(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_row_count(&mut self) -> &mut RowCountResponse {
+        if let ::std::option::Option::Some(response::Data::RowCount(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::RowCount(RowCountResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::RowCount(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_row_count(&mut self) -> RowCountResponse {
+        if self.has_row_count() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::RowCount(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RowCountResponse::new()
+        }
+    }
+
+    // .DescribeTableResponse describe_table = 15;
+
+    pub fn describe_table(&self) -> &DescribeTableResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::DescribeTable(ref v)) => v,
+            _ => <DescribeTableResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_describe_table(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_describe_table(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::DescribeTable(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_describe_table(&mut self, v: DescribeTableResponse) {
+        self.data = ::std::option::Option::Some(response::Data::DescribeTable(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_describe_table(&mut self) -> &mut DescribeTableResponse {
+        if let ::std::option::Option::Some(response::Data::DescribeTable(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::DescribeTable(DescribeTableResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::DescribeTable(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_describe_table(&mut self) -> DescribeTableResponse {
+        if self.has_describe_table() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::DescribeTable(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DescribeTableResponse::new()
+        }
+    }
+
+    // .HealthResponse health = 16;
+
+    pub fn health(&self) -> &HealthResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Health(ref v)) => v,
+            _ => <HealthResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_health(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_health(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Health(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_health(&mut self, v: HealthResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Health(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_health(&mut self) -> &mut HealthResponse {
+        if let ::std::option::Option::Some(response::Data::Health(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Health(HealthResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Health(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_health(&mut self) -> HealthResponse {
+        if self.has_health() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Health(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            HealthResponse::new()
+        }
+    }
+
+    // .ReadyResponse ready = 17;
+
+    pub fn ready(&self) -> &ReadyResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Ready(ref v)) => v,
+            _ => <ReadyResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_ready(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_ready(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Ready(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready(&mut self, v: ReadyResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Ready(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ready(&mut self) -> &mut ReadyResponse {
+        if let ::std::option::Option::Some(response::Data::Ready(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Ready(ReadyResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Ready(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ready(&mut self) -> ReadyResponse {
+        if self.has_ready() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Ready(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReadyResponse::new()
+        }
+    }
+
+    // .PartitionMapResponse partition_map = 18;
+
+    pub fn partition_map(&self) -> &PartitionMapResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::PartitionMap(ref v)) => v,
+            _ => <PartitionMapResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_partition_map(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_partition_map(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::PartitionMap(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_partition_map(&mut self, v: PartitionMapResponse) {
+        self.data = ::std::option::Option::Some(response::Data::PartitionMap(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_partition_map(&mut self) -> &mut PartitionMapResponse {
+        if let ::std::option::Option::Some(response::Data::PartitionMap(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::PartitionMap(PartitionMapResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::PartitionMap(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_partition_map(&mut self) -> PartitionMapResponse {
+        if self.has_partition_map() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::PartitionMap(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PartitionMapResponse::new()
+        }
+    }
+
+    // .KeyDistributionResponse key_distribution = 19;
+
+    pub fn key_distribution(&self) -> &KeyDistributionResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::KeyDistribution(ref v)) => v,
+            _ => <KeyDistributionResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_key_distribution(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_key_distribution(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::KeyDistribution(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_distribution(&mut self, v: KeyDistributionResponse) {
+        self.data = ::std::option::Option::Some(response::Data::KeyDistribution(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_distribution(&mut self) -> &mut KeyDistributionResponse {
+        if let ::std::option::Option::Some(response::Data::KeyDistribution(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::KeyDistribution(KeyDistributionResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::KeyDistribution(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_distribution(&mut self) -> KeyDistributionResponse {
+        if self.has_key_distribution() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::KeyDistribution(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyDistributionResponse::new()
+        }
+    }
+
+    // .ListTransactionsResponse list_transactions = 20;
+
+    pub fn list_transactions(&self) -> &ListTransactionsResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::ListTransactions(ref v)) => v,
+            _ => <ListTransactionsResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_list_transactions(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_list_transactions(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::ListTransactions(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_transactions(&mut self, v: ListTransactionsResponse) {
+        self.data = ::std::option::Option::Some(response::Data::ListTransactions(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_transactions(&mut self) -> &mut ListTransactionsResponse {
+        if let ::std::option::Option::Some(response::Data::ListTransactions(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::ListTransactions(ListTransactionsResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::ListTransactions(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_transactions(&mut self) -> ListTransactionsResponse {
+        if self.has_list_transactions() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::ListTransactions(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ListTransactionsResponse::new()
+        }
+    }
+
+    // .SessionResponse session = 21;
+
+    pub fn session(&self) -> &SessionResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Session(ref v)) => v,
+            _ => <SessionResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_session(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_session(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Session(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session(&mut self, v: SessionResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Session(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_session(&mut self) -> &mut SessionResponse {
+        if let ::std::option::Option::Some(response::Data::Session(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Session(SessionResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Session(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_session(&mut self) -> SessionResponse {
+        if self.has_session() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Session(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SessionResponse::new()
+        }
+    }
+
+    // .SetReadOnlyResponse set_read_only = 22;
+
+    pub fn set_read_only(&self) -> &SetReadOnlyResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::SetReadOnly(ref v)) => v,
+            _ => <SetReadOnlyResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_set_read_only(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_set_read_only(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::SetReadOnly(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set_read_only(&mut self, v: SetReadOnlyResponse) {
+        self.data = ::std::option::Option::Some(response::Data::SetReadOnly(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_set_read_only(&mut self) -> &mut SetReadOnlyResponse {
+        if let ::std::option::Option::Some(response::Data::SetReadOnly(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::SetReadOnly(SetReadOnlyResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::SetReadOnly(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_set_read_only(&mut self) -> SetReadOnlyResponse {
+        if self.has_set_read_only() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::SetReadOnly(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetReadOnlyResponse::new()
+        }
+    }
+
+    // .CompactionResponse compaction = 23;
+
+    pub fn compaction(&self) -> &CompactionResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Compaction(ref v)) => v,
+            _ => <CompactionResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_compaction(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_compaction(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Compaction(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_compaction(&mut self, v: CompactionResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Compaction(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_compaction(&mut self) -> &mut CompactionResponse {
+        if let ::std::option::Option::Some(response::Data::Compaction(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Compaction(CompactionResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Compaction(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_compaction(&mut self) -> CompactionResponse {
+        if self.has_compaction() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Compaction(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CompactionResponse::new()
+        }
+    }
+
+    // .DescribeServerResponse describe_server = 26;
+
+    pub fn describe_server(&self) -> &DescribeServerResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::DescribeServer(ref v)) => v,
+            _ => <DescribeServerResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_describe_server(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_describe_server(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::DescribeServer(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_describe_server(&mut self, v: DescribeServerResponse) {
+        self.data = ::std::option::Option::Some(response::Data::DescribeServer(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_describe_server(&mut self) -> &mut DescribeServerResponse {
+        if let ::std::option::Option::Some(response::Data::DescribeServer(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::DescribeServer(DescribeServerResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::DescribeServer(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_describe_server(&mut self) -> DescribeServerResponse {
+        if self.has_describe_server() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::DescribeServer(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DescribeServerResponse::new()
+        }
+    }
+
+    // .PartitionLoadResponse partition_load = 27;
+
+    pub fn partition_load(&self) -> &PartitionLoadResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::PartitionLoad(ref v)) => v,
+            _ => <PartitionLoadResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_partition_load(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_partition_load(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::PartitionLoad(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_partition_load(&mut self, v: PartitionLoadResponse) {
+        self.data = ::std::option::Option::Some(response::Data::PartitionLoad(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_partition_load(&mut self) -> &mut PartitionLoadResponse {
+        if let ::std::option::Option::Some(response::Data::PartitionLoad(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::PartitionLoad(PartitionLoadResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::PartitionLoad(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_partition_load(&mut self) -> PartitionLoadResponse {
+        if self.has_partition_load() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::PartitionLoad(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PartitionLoadResponse::new()
+        }
+    }
+
+    // .QueryByIndexResponse query_by_index = 28;
+
+    pub fn query_by_index(&self) -> &QueryByIndexResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::QueryByIndex(ref v)) => v,
+            _ => <QueryByIndexResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_query_by_index(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_query_by_index(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::QueryByIndex(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query_by_index(&mut self, v: QueryByIndexResponse) {
+        self.data = ::std::option::Option::Some(response::Data::QueryByIndex(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_query_by_index(&mut self) -> &mut QueryByIndexResponse {
+        if let ::std::option::Option::Some(response::Data::QueryByIndex(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::QueryByIndex(QueryByIndexResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::QueryByIndex(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_query_by_index(&mut self) -> QueryByIndexResponse {
+        if self.has_query_by_index() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::QueryByIndex(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryByIndexResponse::new()
+        }
+    }
+
+    // .TableAccessResponse table_access = 29;
+
+    pub fn table_access(&self) -> &TableAccessResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::TableAccess(ref v)) => v,
+            _ => <TableAccessResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_table_access(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_table_access(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::TableAccess(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_table_access(&mut self, v: TableAccessResponse) {
+        self.data = ::std::option::Option::Some(response::Data::TableAccess(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_table_access(&mut self) -> &mut TableAccessResponse {
+        if let ::std::option::Option::Some(response::Data::TableAccess(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::TableAccess(TableAccessResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::TableAccess(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_table_access(&mut self) -> TableAccessResponse {
+        if self.has_table_access() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::TableAccess(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TableAccessResponse::new()
+        }
+    }
+
+    // .DeleteWhereResponse delete_where = 30;
+
+    pub fn delete_where(&self) -> &DeleteWhereResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::DeleteWhere(ref v)) => v,
+            _ => <DeleteWhereResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_delete_where(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_delete_where(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::DeleteWhere(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete_where(&mut self, v: DeleteWhereResponse) {
+        self.data = ::std::option::Option::Some(response::Data::DeleteWhere(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_delete_where(&mut self) -> &mut DeleteWhereResponse {
+        if let ::std::option::Option::Some(response::Data::DeleteWhere(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::DeleteWhere(DeleteWhereResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::DeleteWhere(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_delete_where(&mut self) -> DeleteWhereResponse {
+        if self.has_delete_where() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::DeleteWhere(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DeleteWhereResponse::new()
+        }
+    }
+
+    // .MoveRowResponse move_row = 31;
+
+    pub fn move_row(&self) -> &MoveRowResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::MoveRow(ref v)) => v,
+            _ => <MoveRowResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_move_row(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_move_row(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::MoveRow(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_move_row(&mut self, v: MoveRowResponse) {
+        self.data = ::std::option::Option::Some(response::Data::MoveRow(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_move_row(&mut self) -> &mut MoveRowResponse {
+        if let ::std::option::Option::Some(response::Data::MoveRow(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::MoveRow(MoveRowResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::MoveRow(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_move_row(&mut self) -> MoveRowResponse {
+        if self.has_move_row() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::MoveRow(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            MoveRowResponse::new()
+        }
+    }
+
+    // .SaveResponse save = 32;
+
+    pub fn save(&self) -> &SaveResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Save(ref v)) => v,
+            _ => <SaveResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_save(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_save(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Save(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_save(&mut self, v: SaveResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Save(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_save(&mut self) -> &mut SaveResponse {
+        if let ::std::option::Option::Some(response::Data::Save(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Save(SaveResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Save(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_save(&mut self) -> SaveResponse {
+        if self.has_save() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Save(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SaveResponse::new()
+        }
+    }
+
+    // .ReplicateResponse replicate = 33;
+
+    pub fn replicate(&self) -> &ReplicateResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Replicate(ref v)) => v,
+            _ => <ReplicateResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_replicate(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_replicate(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Replicate(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replicate(&mut self, v: ReplicateResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Replicate(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_replicate(&mut self) -> &mut ReplicateResponse {
+        if let ::std::option::Option::Some(response::Data::Replicate(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Replicate(ReplicateResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Replicate(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_replicate(&mut self) -> ReplicateResponse {
+        if self.has_replicate() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Replicate(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ReplicateResponse::new()
+        }
+    }
+
+    // .PromoteResponse promote = 34;
+
+    pub fn promote(&self) -> &PromoteResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Promote(ref v)) => v,
+            _ => <PromoteResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_promote(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_promote(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::Promote(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_promote(&mut self, v: PromoteResponse) {
+        self.data = ::std::option::Option::Some(response::Data::Promote(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_promote(&mut self) -> &mut PromoteResponse {
+        if let ::std::option::Option::Some(response::Data::Promote(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::Promote(PromoteResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::Promote(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_promote(&mut self) -> PromoteResponse {
+        if self.has_promote() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::Promote(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PromoteResponse::new()
+        }
+    }
+
+    // .RaftVoteResponse raft_vote = 35;
+
+    pub fn raft_vote(&self) -> &RaftVoteResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::RaftVote(ref v)) => v,
+            _ => <RaftVoteResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_raft_vote(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_raft_vote(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::RaftVote(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raft_vote(&mut self, v: RaftVoteResponse) {
+        self.data = ::std::option::Option::Some(response::Data::RaftVote(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_raft_vote(&mut self) -> &mut RaftVoteResponse {
+        if let ::std::option::Option::Some(response::Data::RaftVote(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::RaftVote(RaftVoteResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::RaftVote(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_raft_vote(&mut self) -> RaftVoteResponse {
+        if self.has_raft_vote() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::RaftVote(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RaftVoteResponse::new()
+        }
+    }
+
+    // .RaftAppendResponse raft_append = 36;
+
+    pub fn raft_append(&self) -> &RaftAppendResponse {
+        match self.data {
+            ::std::option::Option::Some(response::Data::RaftAppend(ref v)) => v,
+            _ => <RaftAppendResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_raft_append(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_raft_append(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(response::Data::RaftAppend(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_raft_append(&mut self, v: RaftAppendResponse) {
+        self.data = ::std::option::Option::Some(response::Data::RaftAppend(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_raft_append(&mut self) -> &mut RaftAppendResponse {
+        if let ::std::option::Option::Some(response::Data::RaftAppend(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(response::Data::RaftAppend(RaftAppendResponse::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(response::Data::RaftAppend(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_raft_append(&mut self) -> RaftAppendResponse {
+        if self.has_raft_append() {
+            match self.data.take() {
+                ::std::option::Option::Some(response::Data::RaftAppend(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RaftAppendResponse::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(36);
+        let mut oneofs = ::std::vec::Vec::with_capacity(1);
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetResponse>(
+            "get",
+            Response::has_get,
+            Response::get,
+            Response::mut_get,
+            Response::set_get,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, InsertResponse>(
+            "insert",
+            Response::has_insert,
+            Response::insert,
+            Response::mut_insert,
+            Response::set_insert,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteResponse>(
+            "delete",
+            Response::has_delete,
+            Response::delete,
+            Response::mut_delete,
+            Response::set_delete,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, GetManyResponse>(
+            "get_many",
+            Response::has_get_many,
+            Response::get_many,
+            Response::mut_get_many,
+            Response::set_get_many,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, BatchResponse>(
+            "batch",
+            Response::has_batch,
+            Response::batch,
+            Response::mut_batch,
+            Response::set_batch,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TransactionResponse>(
+            "transaction",
+            Response::has_transaction,
+            Response::transaction,
+            Response::mut_transaction,
+            Response::set_transaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SyncModelResponse>(
+            "model",
+            Response::has_model,
+            Response::model,
+            Response::mut_model,
+            Response::set_model,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DropTableResponse>(
+            "drop_table",
+            Response::has_drop_table,
+            Response::drop_table,
+            Response::mut_drop_table,
+            Response::set_drop_table,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ClientError>(
+            "client_error",
+            Response::has_client_error,
+            Response::client_error,
+            Response::mut_client_error,
+            Response::set_client_error,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ServerError>(
+            "server_error",
+            Response::has_server_error,
+            Response::server_error,
+            Response::mut_server_error,
+            Response::set_server_error,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PlanMigrationResponse>(
+            "plan_migration",
+            Response::has_plan_migration,
+            Response::plan_migration,
+            Response::mut_plan_migration,
+            Response::set_plan_migration,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SequenceResponse>(
+            "sequence",
+            Response::has_sequence,
+            Response::sequence,
+            Response::mut_sequence,
+            Response::set_sequence,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, LeaseResponse>(
+            "lease",
+            Response::has_lease,
+            Response::lease,
+            Response::mut_lease,
+            Response::set_lease,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RowCountResponse>(
+            "row_count",
+            Response::has_row_count,
+            Response::row_count,
+            Response::mut_row_count,
+            Response::set_row_count,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DescribeTableResponse>(
+            "describe_table",
+            Response::has_describe_table,
+            Response::describe_table,
+            Response::mut_describe_table,
+            Response::set_describe_table,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, HealthResponse>(
+            "health",
+            Response::has_health,
+            Response::health,
+            Response::mut_health,
+            Response::set_health,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ReadyResponse>(
+            "ready",
+            Response::has_ready,
+            Response::ready,
+            Response::mut_ready,
+            Response::set_ready,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PartitionMapResponse>(
+            "partition_map",
+            Response::has_partition_map,
+            Response::partition_map,
+            Response::mut_partition_map,
+            Response::set_partition_map,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, KeyDistributionResponse>(
+            "key_distribution",
+            Response::has_key_distribution,
+            Response::key_distribution,
+            Response::mut_key_distribution,
+            Response::set_key_distribution,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ListTransactionsResponse>(
+            "list_transactions",
+            Response::has_list_transactions,
+            Response::list_transactions,
+            Response::mut_list_transactions,
+            Response::set_list_transactions,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SessionResponse>(
+            "session",
+            Response::has_session,
+            Response::session,
+            Response::mut_session,
+            Response::set_session,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SetReadOnlyResponse>(
+            "set_read_only",
+            Response::has_set_read_only,
+            Response::set_read_only,
+            Response::mut_set_read_only,
+            Response::set_set_read_only,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, CompactionResponse>(
+            "compaction",
+            Response::has_compaction,
+            Response::compaction,
+            Response::mut_compaction,
+            Response::set_compaction,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DescribeServerResponse>(
+            "describe_server",
+            Response::has_describe_server,
+            Response::describe_server,
+            Response::mut_describe_server,
+            Response::set_describe_server,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PartitionLoadResponse>(
+            "partition_load",
+            Response::has_partition_load,
+            Response::partition_load,
+            Response::mut_partition_load,
+            Response::set_partition_load,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, QueryByIndexResponse>(
+            "query_by_index",
+            Response::has_query_by_index,
+            Response::query_by_index,
+            Response::mut_query_by_index,
+            Response::set_query_by_index,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TableAccessResponse>(
+            "table_access",
+            Response::has_table_access,
+            Response::table_access,
+            Response::mut_table_access,
+            Response::set_table_access,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DeleteWhereResponse>(
+            "delete_where",
+            Response::has_delete_where,
+            Response::delete_where,
+            Response::mut_delete_where,
+            Response::set_delete_where,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, MoveRowResponse>(
+            "move_row",
+            Response::has_move_row,
+            Response::move_row,
+            Response::mut_move_row,
+            Response::set_move_row,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SaveResponse>(
+            "save",
+            Response::has_save,
+            Response::save,
+            Response::mut_save,
+            Response::set_save,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ReplicateResponse>(
+            "replicate",
+            Response::has_replicate,
+            Response::replicate,
+            Response::mut_replicate,
+            Response::set_replicate,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, PromoteResponse>(
+            "promote",
+            Response::has_promote,
+            Response::promote,
+            Response::mut_promote,
+            Response::set_promote,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RaftVoteResponse>(
+            "raft_vote",
+            Response::has_raft_vote,
+            Response::raft_vote,
+            Response::mut_raft_vote,
+            Response::set_raft_vote,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RaftAppendResponse>(
+            "raft_append",
+            Response::has_raft_append,
+            Response::raft_append,
+            Response::mut_raft_append,
+            Response::set_raft_append,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, LatencyBreakdown>(
+            "latency",
+            |m: &Response| { &m.latency },
+            |m: &mut Response| { &mut m.latency },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "read_explanations",
+            |m: &Response| { &m.read_explanations },
+            |m: &mut Response| { &mut m.read_explanations },
+        ));
+        oneofs.push(response::Data::generated_oneof_descriptor_data());
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Response>(
+            "Response",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Response {
+    const NAME: &'static str = "Response";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.data = ::std::option::Option::Some(response::Data::Get(is.read_message()?));
+                },
+                18 => {
+                    self.data = ::std::option::Option::Some(response::Data::Insert(is.read_message()?));
+                },
+                26 => {
+                    self.data = ::std::option::Option::Some(response::Data::Delete(is.read_message()?));
+                },
+                34 => {
+                    self.data = ::std::option::Option::Some(response::Data::GetMany(is.read_message()?));
+                },
+                42 => {
+                    self.data = ::std::option::Option::Some(response::Data::Batch(is.read_message()?));
+                },
+                50 => {
+                    self.data = ::std::option::Option::Some(response::Data::Transaction(is.read_message()?));
+                },
+                58 => {
+                    self.data = ::std::option::Option::Some(response::Data::Model(is.read_message()?));
+                },
+                66 => {
+                    self.data = ::std::option::Option::Some(response::Data::DropTable(is.read_message()?));
+                },
+                74 => {
+                    self.data = ::std::option::Option::Some(response::Data::ClientError(is.read_message()?));
+                },
+                82 => {
+                    self.data = ::std::option::Option::Some(response::Data::ServerError(is.read_message()?));
+                },
+                90 => {
+                    self.data = ::std::option::Option::Some(response::Data::PlanMigration(is.read_message()?));
+                },
+                98 => {
+                    self.data = ::std::option::Option::Some(response::Data::Sequence(is.read_message()?));
+                },
+                106 => {
+                    self.data = ::std::option::Option::Some(response::Data::Lease(is.read_message()?));
+                },
+                114 => {
+                    self.data = ::std::option::Option::Some(response::Data::RowCount(is.read_message()?));
+                },
+                122 => {
+                    self.data = ::std::option::Option::Some(response::Data::DescribeTable(is.read_message()?));
+                },
+                130 => {
+                    self.data = ::std::option::Option::Some(response::Data::Health(is.read_message()?));
+                },
+                138 => {
+                    self.data = ::std::option::Option::Some(response::Data::Ready(is.read_message()?));
+                },
+                146 => {
+                    self.data = ::std::option::Option::Some(response::Data::PartitionMap(is.read_message()?));
+                },
+                154 => {
+                    self.data = ::std::option::Option::Some(response::Data::KeyDistribution(is.read_message()?));
+                },
+                162 => {
+                    self.data = ::std::option::Option::Some(response::Data::ListTransactions(is.read_message()?));
+                },
+                170 => {
+                    self.data = ::std::option::Option::Some(response::Data::Session(is.read_message()?));
+                },
+                178 => {
+                    self.data = ::std::option::Option::Some(response::Data::SetReadOnly(is.read_message()?));
+                },
+                186 => {
+                    self.data = ::std::option::Option::Some(response::Data::Compaction(is.read_message()?));
+                },
+                210 => {
+                    self.data = ::std::option::Option::Some(response::Data::DescribeServer(is.read_message()?));
+                },
+                218 => {
+                    self.data = ::std::option::Option::Some(response::Data::PartitionLoad(is.read_message()?));
+                },
+                226 => {
+                    self.data = ::std::option::Option::Some(response::Data::QueryByIndex(is.read_message()?));
+                },
+                234 => {
+                    self.data = ::std::option::Option::Some(response::Data::TableAccess(is.read_message()?));
+                },
+                242 => {
+                    self.data = ::std::option::Option::Some(response::Data::DeleteWhere(is.read_message()?));
+                },
+                250 => {
+                    self.data = ::std::option::Option::Some(response::Data::MoveRow(is.read_message()?));
+                },
+                258 => {
+                    self.data = ::std::option::Option::Some(response::Data::Save(is.read_message()?));
+                },
+                266 => {
+                    self.data = ::std::option::Option::Some(response::Data::Replicate(is.read_message()?));
+                },
+                274 => {
+                    self.data = ::std::option::Option::Some(response::Data::Promote(is.read_message()?));
+                },
+                282 => {
+                    self.data = ::std::option::Option::Some(response::Data::RaftVote(is.read_message()?));
+                },
+                290 => {
+                    self.data = ::std::option::Option::Some(response::Data::RaftAppend(is.read_message()?));
+                },
+                194 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.latency)?;
+                },
+                202 => {
+                    self.read_explanations.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.latency.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for value in &self.read_explanations {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &response::Data::Get(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Insert(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Delete(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::GetMany(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Batch(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Transaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Model(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::DropTable(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::ClientError(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::ServerError(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::PlanMigration(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Sequence(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Lease(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::RowCount(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::DescribeTable(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Health(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Ready(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::PartitionMap(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::KeyDistribution(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::ListTransactions(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Session(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::SetReadOnly(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Compaction(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::DescribeServer(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::PartitionLoad(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::QueryByIndex(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::TableAccess(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::DeleteWhere(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::MoveRow(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Save(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Replicate(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::Promote(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::RaftVote(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+                &response::Data::RaftAppend(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.latency.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(24, v, os)?;
+        }
+        for v in &self.read_explanations {
+            ::protobuf::rt::write_message_field_with_cached_size(25, v, os)?;
+        };
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &response::Data::Get(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &response::Data::Insert(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &response::Data::Delete(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &response::Data::GetMany(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &response::Data::Batch(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &response::Data::Transaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &response::Data::Model(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+                &response::Data::DropTable(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+                },
+                &response::Data::ClientError(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(9, v, os)?;
+                },
+                &response::Data::ServerError(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(10, v, os)?;
+                },
+                &response::Data::PlanMigration(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(11, v, os)?;
+                },
+                &response::Data::Sequence(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(12, v, os)?;
+                },
+                &response::Data::Lease(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(13, v, os)?;
+                },
+                &response::Data::RowCount(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(14, v, os)?;
+                },
+                &response::Data::DescribeTable(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(15, v, os)?;
+                },
+                &response::Data::Health(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(16, v, os)?;
+                },
+                &response::Data::Ready(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(17, v, os)?;
+                },
+                &response::Data::PartitionMap(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(18, v, os)?;
+                },
+                &response::Data::KeyDistribution(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(19, v, os)?;
+                },
+                &response::Data::ListTransactions(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(20, v, os)?;
+                },
+                &response::Data::Session(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(21, v, os)?;
+                },
+                &response::Data::SetReadOnly(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(22, v, os)?;
+                },
+                &response::Data::Compaction(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(23, v, os)?;
+                },
+                &response::Data::DescribeServer(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(26, v, os)?;
+                },
+                &response::Data::PartitionLoad(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(27, v, os)?;
+                },
+                &response::Data::QueryByIndex(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(28, v, os)?;
+                },
+                &response::Data::TableAccess(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(29, v, os)?;
+                },
+                &response::Data::DeleteWhere(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(30, v, os)?;
+                },
+                &response::Data::MoveRow(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(31, v, os)?;
+                },
+                &response::Data::Save(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(32, v, os)?;
+                },
+                &response::Data::Replicate(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(33, v, os)?;
+                },
+                &response::Data::Promote(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(34, v, os)?;
+                },
+                &response::Data::RaftVote(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(35, v, os)?;
+                },
+                &response::Data::RaftAppend(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(36, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Response {
+        Response::new()
+    }
+
+    fn clear(&mut self) {
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.data = ::std::option::Option::None;
+        self.latency.clear();
+        self.read_explanations.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Response {
+        static instance: Response = Response {
+            latency: ::protobuf::MessageField::none(),
+            read_explanations: ::std::vec::Vec::new(),
             data: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
@@ -793,130 +2440,5769 @@ impl ::protobuf::Message for Response {
     }
 }
 
-impl ::protobuf::MessageFull for Response {
+impl ::protobuf::MessageFull for Response {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Response").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Response {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Response {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `Response`
+pub mod response {
+
+    #[derive(Clone,PartialEq,Debug)]
+    #[non_exhaustive]
+    // @@protoc_insertion_point(oneof:Response.data)
+    pub enum Data {
+        // @@protoc_insertion_point(oneof_field:Response.get)
+        Get(super::GetResponse),
+        // @@protoc_insertion_point(oneof_field:Response.insert)
+        Insert(super::InsertResponse),
+        // @@protoc_insertion_point(oneof_field:Response.delete)
+        Delete(super::DeleteResponse),
+        // @@protoc_insertion_point(oneof_field:Response.get_many)
+        GetMany(super::GetManyResponse),
+        // @@protoc_insertion_point(oneof_field:Response.batch)
+        Batch(super::BatchResponse),
+        // @@protoc_insertion_point(oneof_field:Response.transaction)
+        Transaction(super::TransactionResponse),
+        // @@protoc_insertion_point(oneof_field:Response.model)
+        Model(super::SyncModelResponse),
+        // @@protoc_insertion_point(oneof_field:Response.drop_table)
+        DropTable(super::DropTableResponse),
+        // @@protoc_insertion_point(oneof_field:Response.client_error)
+        ClientError(super::ClientError),
+        // @@protoc_insertion_point(oneof_field:Response.server_error)
+        ServerError(super::ServerError),
+        // @@protoc_insertion_point(oneof_field:Response.plan_migration)
+        PlanMigration(super::PlanMigrationResponse),
+        // @@protoc_insertion_point(oneof_field:Response.sequence)
+        Sequence(super::SequenceResponse),
+        // @@protoc_insertion_point(oneof_field:Response.lease)
+        Lease(super::LeaseResponse),
+        // @@protoc_insertion_point(oneof_field:Response.row_count)
+        RowCount(super::RowCountResponse),
+        // @@protoc_insertion_point(oneof_field:Response.describe_table)
+        DescribeTable(super::DescribeTableResponse),
+        // @@protoc_insertion_point(oneof_field:Response.health)
+        Health(super::HealthResponse),
+        // @@protoc_insertion_point(oneof_field:Response.ready)
+        Ready(super::ReadyResponse),
+        // @@protoc_insertion_point(oneof_field:Response.partition_map)
+        PartitionMap(super::PartitionMapResponse),
+        // @@protoc_insertion_point(oneof_field:Response.key_distribution)
+        KeyDistribution(super::KeyDistributionResponse),
+        // @@protoc_insertion_point(oneof_field:Response.list_transactions)
+        ListTransactions(super::ListTransactionsResponse),
+        // @@protoc_insertion_point(oneof_field:Response.session)
+        Session(super::SessionResponse),
+        // @@protoc_insertion_point(oneof_field:Response.set_read_only)
+        SetReadOnly(super::SetReadOnlyResponse),
+        // @@protoc_insertion_point(oneof_field:Response.compaction)
+        Compaction(super::CompactionResponse),
+        // @@protoc_insertion_point(oneof_field:Response.describe_server)
+        DescribeServer(super::DescribeServerResponse),
+        // @@protoc_insertion_point(oneof_field:Response.partition_load)
+        PartitionLoad(super::PartitionLoadResponse),
+        // @@protoc_insertion_point(oneof_field:Response.query_by_index)
+        QueryByIndex(super::QueryByIndexResponse),
+        // @@protoc_insertion_point(oneof_field:Response.table_access)
+        TableAccess(super::TableAccessResponse),
+        // @@protoc_insertion_point(oneof_field:Response.delete_where)
+        DeleteWhere(super::DeleteWhereResponse),
+        // @@protoc_insertion_point(oneof_field:Response.move_row)
+        MoveRow(super::MoveRowResponse),
+        // @@protoc_insertion_point(oneof_field:Response.save)
+        Save(super::SaveResponse),
+        // @@protoc_insertion_point(oneof_field:Response.replicate)
+        Replicate(super::ReplicateResponse),
+        // @@protoc_insertion_point(oneof_field:Response.promote)
+        Promote(super::PromoteResponse),
+        // @@protoc_insertion_point(oneof_field:Response.raft_vote)
+        RaftVote(super::RaftVoteResponse),
+        // @@protoc_insertion_point(oneof_field:Response.raft_append)
+        RaftAppend(super::RaftAppendResponse),
+    }
+
+    impl ::protobuf::Oneof for Data {
+    }
+
+    impl ::protobuf::OneofFull for Data {
+        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
+            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
+            descriptor.get(|| <super::Response as ::protobuf::MessageFull>::descriptor().oneof_by_name("data").unwrap()).clone()
+        }
+    }
+
+    impl Data {
+        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
+            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Data>("data")
+        }
+    }
+}
+
+// @@protoc_insertion_point(message:LatencyBreakdown)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LatencyBreakdown {
+    // message fields
+    // @@protoc_insertion_point(field:LatencyBreakdown.total_micros)
+    pub total_micros: u64,
+    // @@protoc_insertion_point(field:LatencyBreakdown.queueing_micros)
+    pub queueing_micros: u64,
+    // @@protoc_insertion_point(field:LatencyBreakdown.parsing_micros)
+    pub parsing_micros: u64,
+    // @@protoc_insertion_point(field:LatencyBreakdown.validation_micros)
+    pub validation_micros: u64,
+    // @@protoc_insertion_point(field:LatencyBreakdown.memtable_micros)
+    pub memtable_micros: u64,
+    // @@protoc_insertion_point(field:LatencyBreakdown.sstable_io_micros)
+    pub sstable_io_micros: u64,
+    // @@protoc_insertion_point(field:LatencyBreakdown.commit_log_micros)
+    pub commit_log_micros: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:LatencyBreakdown.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LatencyBreakdown {
+    fn default() -> &'a LatencyBreakdown {
+        <LatencyBreakdown as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LatencyBreakdown {
+    pub fn new() -> LatencyBreakdown {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "total_micros",
+            |m: &LatencyBreakdown| { &m.total_micros },
+            |m: &mut LatencyBreakdown| { &mut m.total_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "queueing_micros",
+            |m: &LatencyBreakdown| { &m.queueing_micros },
+            |m: &mut LatencyBreakdown| { &mut m.queueing_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "parsing_micros",
+            |m: &LatencyBreakdown| { &m.parsing_micros },
+            |m: &mut LatencyBreakdown| { &mut m.parsing_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "validation_micros",
+            |m: &LatencyBreakdown| { &m.validation_micros },
+            |m: &mut LatencyBreakdown| { &mut m.validation_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "memtable_micros",
+            |m: &LatencyBreakdown| { &m.memtable_micros },
+            |m: &mut LatencyBreakdown| { &mut m.memtable_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sstable_io_micros",
+            |m: &LatencyBreakdown| { &m.sstable_io_micros },
+            |m: &mut LatencyBreakdown| { &mut m.sstable_io_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "commit_log_micros",
+            |m: &LatencyBreakdown| { &m.commit_log_micros },
+            |m: &mut LatencyBreakdown| { &mut m.commit_log_micros },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LatencyBreakdown>(
+            "LatencyBreakdown",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LatencyBreakdown {
+    const NAME: &'static str = "LatencyBreakdown";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.total_micros = is.read_uint64()?;
+                },
+                16 => {
+                    self.queueing_micros = is.read_uint64()?;
+                },
+                24 => {
+                    self.parsing_micros = is.read_uint64()?;
+                },
+                32 => {
+                    self.validation_micros = is.read_uint64()?;
+                },
+                40 => {
+                    self.memtable_micros = is.read_uint64()?;
+                },
+                48 => {
+                    self.sstable_io_micros = is.read_uint64()?;
+                },
+                56 => {
+                    self.commit_log_micros = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.total_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.total_micros);
+        }
+        if self.queueing_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.queueing_micros);
+        }
+        if self.parsing_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.parsing_micros);
+        }
+        if self.validation_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.validation_micros);
+        }
+        if self.memtable_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.memtable_micros);
+        }
+        if self.sstable_io_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.sstable_io_micros);
+        }
+        if self.commit_log_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.commit_log_micros);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.total_micros != 0 {
+            os.write_uint64(1, self.total_micros)?;
+        }
+        if self.queueing_micros != 0 {
+            os.write_uint64(2, self.queueing_micros)?;
+        }
+        if self.parsing_micros != 0 {
+            os.write_uint64(3, self.parsing_micros)?;
+        }
+        if self.validation_micros != 0 {
+            os.write_uint64(4, self.validation_micros)?;
+        }
+        if self.memtable_micros != 0 {
+            os.write_uint64(5, self.memtable_micros)?;
+        }
+        if self.sstable_io_micros != 0 {
+            os.write_uint64(6, self.sstable_io_micros)?;
+        }
+        if self.commit_log_micros != 0 {
+            os.write_uint64(7, self.commit_log_micros)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LatencyBreakdown {
+        LatencyBreakdown::new()
+    }
+
+    fn clear(&mut self) {
+        self.total_micros = 0;
+        self.queueing_micros = 0;
+        self.parsing_micros = 0;
+        self.validation_micros = 0;
+        self.memtable_micros = 0;
+        self.sstable_io_micros = 0;
+        self.commit_log_micros = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LatencyBreakdown {
+        static instance: LatencyBreakdown = LatencyBreakdown {
+            total_micros: 0,
+            queueing_micros: 0,
+            parsing_micros: 0,
+            validation_micros: 0,
+            memtable_micros: 0,
+            sstable_io_micros: 0,
+            commit_log_micros: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LatencyBreakdown {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LatencyBreakdown").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LatencyBreakdown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LatencyBreakdown {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReadExplanation)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReadExplanation {
+    // message fields
+    // @@protoc_insertion_point(field:ReadExplanation.primary_key)
+    pub primary_key: ::std::string::String,
+    // @@protoc_insertion_point(field:ReadExplanation.memtable_hit)
+    pub memtable_hit: bool,
+    // @@protoc_insertion_point(field:ReadExplanation.memtable_micros)
+    pub memtable_micros: u64,
+    // @@protoc_insertion_point(field:ReadExplanation.sstables)
+    pub sstables: ::std::vec::Vec<SSTableReadExplanation>,
+    // @@protoc_insertion_point(field:ReadExplanation.sstables_not_consulted)
+    pub sstables_not_consulted: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:ReadExplanation.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReadExplanation {
+    fn default() -> &'a ReadExplanation {
+        <ReadExplanation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadExplanation {
+    pub fn new() -> ReadExplanation {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "primary_key",
+            |m: &ReadExplanation| { &m.primary_key },
+            |m: &mut ReadExplanation| { &mut m.primary_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "memtable_hit",
+            |m: &ReadExplanation| { &m.memtable_hit },
+            |m: &mut ReadExplanation| { &mut m.memtable_hit },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "memtable_micros",
+            |m: &ReadExplanation| { &m.memtable_micros },
+            |m: &mut ReadExplanation| { &mut m.memtable_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "sstables",
+            |m: &ReadExplanation| { &m.sstables },
+            |m: &mut ReadExplanation| { &mut m.sstables },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sstables_not_consulted",
+            |m: &ReadExplanation| { &m.sstables_not_consulted },
+            |m: &mut ReadExplanation| { &mut m.sstables_not_consulted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReadExplanation>(
+            "ReadExplanation",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReadExplanation {
+    const NAME: &'static str = "ReadExplanation";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.primary_key = is.read_string()?;
+                },
+                16 => {
+                    self.memtable_hit = is.read_bool()?;
+                },
+                24 => {
+                    self.memtable_micros = is.read_uint64()?;
+                },
+                34 => {
+                    self.sstables.push(is.read_message()?);
+                },
+                40 => {
+                    self.sstables_not_consulted = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.primary_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.primary_key);
+        }
+        if self.memtable_hit != false {
+            my_size += 1 + 1;
+        }
+        if self.memtable_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.memtable_micros);
+        }
+        for value in &self.sstables {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.sstables_not_consulted != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.sstables_not_consulted);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.primary_key.is_empty() {
+            os.write_string(1, &self.primary_key)?;
+        }
+        if self.memtable_hit != false {
+            os.write_bool(2, self.memtable_hit)?;
+        }
+        if self.memtable_micros != 0 {
+            os.write_uint64(3, self.memtable_micros)?;
+        }
+        for v in &self.sstables {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        };
+        if self.sstables_not_consulted != 0 {
+            os.write_uint64(5, self.sstables_not_consulted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReadExplanation {
+        ReadExplanation::new()
+    }
+
+    fn clear(&mut self) {
+        self.primary_key.clear();
+        self.memtable_hit = false;
+        self.memtable_micros = 0;
+        self.sstables.clear();
+        self.sstables_not_consulted = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReadExplanation {
+        static instance: ReadExplanation = ReadExplanation {
+            primary_key: ::std::string::String::new(),
+            memtable_hit: false,
+            memtable_micros: 0,
+            sstables: ::std::vec::Vec::new(),
+            sstables_not_consulted: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReadExplanation {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReadExplanation").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReadExplanation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadExplanation {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SSTableReadExplanation)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SSTableReadExplanation {
+    // message fields
+    // @@protoc_insertion_point(field:SSTableReadExplanation.file_name)
+    pub file_name: ::std::string::String,
+    // @@protoc_insertion_point(field:SSTableReadExplanation.partition_skipped)
+    pub partition_skipped: bool,
+    // @@protoc_insertion_point(field:SSTableReadExplanation.rows_read)
+    pub rows_read: u64,
+    // @@protoc_insertion_point(field:SSTableReadExplanation.bytes_read)
+    pub bytes_read: u64,
+    // @@protoc_insertion_point(field:SSTableReadExplanation.found)
+    pub found: bool,
+    // @@protoc_insertion_point(field:SSTableReadExplanation.micros)
+    pub micros: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:SSTableReadExplanation.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SSTableReadExplanation {
+    fn default() -> &'a SSTableReadExplanation {
+        <SSTableReadExplanation as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SSTableReadExplanation {
+    pub fn new() -> SSTableReadExplanation {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "file_name",
+            |m: &SSTableReadExplanation| { &m.file_name },
+            |m: &mut SSTableReadExplanation| { &mut m.file_name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partition_skipped",
+            |m: &SSTableReadExplanation| { &m.partition_skipped },
+            |m: &mut SSTableReadExplanation| { &mut m.partition_skipped },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rows_read",
+            |m: &SSTableReadExplanation| { &m.rows_read },
+            |m: &mut SSTableReadExplanation| { &mut m.rows_read },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes_read",
+            |m: &SSTableReadExplanation| { &m.bytes_read },
+            |m: &mut SSTableReadExplanation| { &mut m.bytes_read },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "found",
+            |m: &SSTableReadExplanation| { &m.found },
+            |m: &mut SSTableReadExplanation| { &mut m.found },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "micros",
+            |m: &SSTableReadExplanation| { &m.micros },
+            |m: &mut SSTableReadExplanation| { &mut m.micros },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SSTableReadExplanation>(
+            "SSTableReadExplanation",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SSTableReadExplanation {
+    const NAME: &'static str = "SSTableReadExplanation";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.file_name = is.read_string()?;
+                },
+                16 => {
+                    self.partition_skipped = is.read_bool()?;
+                },
+                24 => {
+                    self.rows_read = is.read_uint64()?;
+                },
+                32 => {
+                    self.bytes_read = is.read_uint64()?;
+                },
+                40 => {
+                    self.found = is.read_bool()?;
+                },
+                48 => {
+                    self.micros = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.file_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.file_name);
+        }
+        if self.partition_skipped != false {
+            my_size += 1 + 1;
+        }
+        if self.rows_read != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.rows_read);
+        }
+        if self.bytes_read != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.bytes_read);
+        }
+        if self.found != false {
+            my_size += 1 + 1;
+        }
+        if self.micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.micros);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.file_name.is_empty() {
+            os.write_string(1, &self.file_name)?;
+        }
+        if self.partition_skipped != false {
+            os.write_bool(2, self.partition_skipped)?;
+        }
+        if self.rows_read != 0 {
+            os.write_uint64(3, self.rows_read)?;
+        }
+        if self.bytes_read != 0 {
+            os.write_uint64(4, self.bytes_read)?;
+        }
+        if self.found != false {
+            os.write_bool(5, self.found)?;
+        }
+        if self.micros != 0 {
+            os.write_uint64(6, self.micros)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SSTableReadExplanation {
+        SSTableReadExplanation::new()
+    }
+
+    fn clear(&mut self) {
+        self.file_name.clear();
+        self.partition_skipped = false;
+        self.rows_read = 0;
+        self.bytes_read = 0;
+        self.found = false;
+        self.micros = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SSTableReadExplanation {
+        static instance: SSTableReadExplanation = SSTableReadExplanation {
+            file_name: ::std::string::String::new(),
+            partition_skipped: false,
+            rows_read: 0,
+            bytes_read: 0,
+            found: false,
+            micros: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SSTableReadExplanation {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SSTableReadExplanation").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SSTableReadExplanation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SSTableReadExplanation {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:GetResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetResponse {
+    // message fields
+    // @@protoc_insertion_point(field:GetResponse.hash_key)
+    pub hash_key: ::std::string::String,
+    // @@protoc_insertion_point(field:GetResponse.sort_key)
+    pub sort_key: ::protobuf::MessageField<super::common::Value>,
+    // @@protoc_insertion_point(field:GetResponse.values)
+    pub values: ::std::collections::HashMap<::std::string::String, super::common::Value>,
+    // @@protoc_insertion_point(field:GetResponse.table)
+    pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:GetResponse.column_values)
+    pub column_values: ::std::vec::Vec<super::common::Value>,
+    // @@protoc_insertion_point(field:GetResponse.version)
+    pub version: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetResponse {
+    fn default() -> &'a GetResponse {
+        <GetResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetResponse {
+    pub fn new() -> GetResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key",
+            |m: &GetResponse| { &m.hash_key },
+            |m: &mut GetResponse| { &mut m.hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
+            "sort_key",
+            |m: &GetResponse| { &m.sort_key },
+            |m: &mut GetResponse| { &mut m.sort_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "values",
+            |m: &GetResponse| { &m.values },
+            |m: &mut GetResponse| { &mut m.values },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table",
+            |m: &GetResponse| { &m.table },
+            |m: &mut GetResponse| { &mut m.table },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "column_values",
+            |m: &GetResponse| { &m.column_values },
+            |m: &mut GetResponse| { &mut m.column_values },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &GetResponse| { &m.version },
+            |m: &mut GetResponse| { &mut m.version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetResponse>(
+            "GetResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetResponse {
+    const NAME: &'static str = "GetResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.hash_key = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
+                },
+                26 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_message()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.values.insert(key, value);
+                },
+                34 => {
+                    self.table = is.read_string()?;
+                },
+                42 => {
+                    self.column_values.push(is.read_message()?);
+                },
+                48 => {
+                    self.version = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.hash_key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        for (k, v) in &self.values {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.compute_size();
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.table);
+        }
+        for value in &self.column_values {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.hash_key.is_empty() {
+            os.write_string(1, &self.hash_key)?;
+        }
+        if let Some(v) = self.sort_key.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        for (k, v) in &self.values {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            let len = v.cached_size() as u64;
+            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+            os.write_raw_varint32(26)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if !self.table.is_empty() {
+            os.write_string(4, &self.table)?;
+        }
+        for v in &self.column_values {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if self.version != 0 {
+            os.write_uint64(6, self.version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetResponse {
+        GetResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.hash_key.clear();
+        self.sort_key.clear();
+        self.values.clear();
+        self.table.clear();
+        self.column_values.clear();
+        self.version = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetResponse {
+        static instance: ::protobuf::rt::Lazy<GetResponse> = ::protobuf::rt::Lazy::new();
+        instance.get(GetResponse::new)
+    }
+}
+
+impl ::protobuf::MessageFull for GetResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:InsertResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct InsertResponse {
+    // message fields
+    // @@protoc_insertion_point(field:InsertResponse.consistency_token)
+    pub consistency_token: ::std::string::String,
+    // @@protoc_insertion_point(field:InsertResponse.sequence)
+    pub sequence: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:InsertResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a InsertResponse {
+    fn default() -> &'a InsertResponse {
+        <InsertResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InsertResponse {
+    pub fn new() -> InsertResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "consistency_token",
+            |m: &InsertResponse| { &m.consistency_token },
+            |m: &mut InsertResponse| { &mut m.consistency_token },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &InsertResponse| { &m.sequence },
+            |m: &mut InsertResponse| { &mut m.sequence },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InsertResponse>(
+            "InsertResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for InsertResponse {
+    const NAME: &'static str = "InsertResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.consistency_token = is.read_string()?;
+                },
+                16 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.consistency_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.consistency_token);
+        }
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.sequence);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.consistency_token.is_empty() {
+            os.write_string(1, &self.consistency_token)?;
+        }
+        if self.sequence != 0 {
+            os.write_uint64(2, self.sequence)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> InsertResponse {
+        InsertResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.consistency_token.clear();
+        self.sequence = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static InsertResponse {
+        static instance: InsertResponse = InsertResponse {
+            consistency_token: ::std::string::String::new(),
+            sequence: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for InsertResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("InsertResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for InsertResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InsertResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DeleteResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeleteResponse {
+    // message fields
+    // @@protoc_insertion_point(field:DeleteResponse.okay)
+    pub okay: bool,
+    // @@protoc_insertion_point(field:DeleteResponse.consistency_token)
+    pub consistency_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeleteResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteResponse {
+    fn default() -> &'a DeleteResponse {
+        <DeleteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteResponse {
+    pub fn new() -> DeleteResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "okay",
+            |m: &DeleteResponse| { &m.okay },
+            |m: &mut DeleteResponse| { &mut m.okay },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "consistency_token",
+            |m: &DeleteResponse| { &m.consistency_token },
+            |m: &mut DeleteResponse| { &mut m.consistency_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteResponse>(
+            "DeleteResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteResponse {
+    const NAME: &'static str = "DeleteResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.okay = is.read_bool()?;
+                },
+                18 => {
+                    self.consistency_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.okay != false {
+            my_size += 1 + 1;
+        }
+        if !self.consistency_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.consistency_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.okay != false {
+            os.write_bool(1, self.okay)?;
+        }
+        if !self.consistency_token.is_empty() {
+            os.write_string(2, &self.consistency_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteResponse {
+        DeleteResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.okay = false;
+        self.consistency_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteResponse {
+        static instance: DeleteResponse = DeleteResponse {
+            okay: false,
+            consistency_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SequenceResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SequenceResponse {
+    // message fields
+    // @@protoc_insertion_point(field:SequenceResponse.value)
+    pub value: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:SequenceResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SequenceResponse {
+    fn default() -> &'a SequenceResponse {
+        <SequenceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SequenceResponse {
+    pub fn new() -> SequenceResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "value",
+            |m: &SequenceResponse| { &m.value },
+            |m: &mut SequenceResponse| { &mut m.value },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SequenceResponse>(
+            "SequenceResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SequenceResponse {
+    const NAME: &'static str = "SequenceResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.value = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.value != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.value != 0 {
+            os.write_uint64(1, self.value)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SequenceResponse {
+        SequenceResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.value = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SequenceResponse {
+        static instance: SequenceResponse = SequenceResponse {
+            value: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SequenceResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SequenceResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SequenceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SequenceResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:LeaseResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LeaseResponse {
+    // message fields
+    // @@protoc_insertion_point(field:LeaseResponse.version)
+    pub version: u64,
+    // @@protoc_insertion_point(field:LeaseResponse.expires_at)
+    pub expires_at: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:LeaseResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LeaseResponse {
+    fn default() -> &'a LeaseResponse {
+        <LeaseResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LeaseResponse {
+    pub fn new() -> LeaseResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &LeaseResponse| { &m.version },
+            |m: &mut LeaseResponse| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "expires_at",
+            |m: &LeaseResponse| { &m.expires_at },
+            |m: &mut LeaseResponse| { &mut m.expires_at },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LeaseResponse>(
+            "LeaseResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LeaseResponse {
+    const NAME: &'static str = "LeaseResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.version = is.read_uint64()?;
+                },
+                16 => {
+                    self.expires_at = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.version);
+        }
+        if self.expires_at != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.expires_at);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.version != 0 {
+            os.write_uint64(1, self.version)?;
+        }
+        if self.expires_at != 0 {
+            os.write_uint64(2, self.expires_at)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LeaseResponse {
+        LeaseResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.version = 0;
+        self.expires_at = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LeaseResponse {
+        static instance: LeaseResponse = LeaseResponse {
+            version: 0,
+            expires_at: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LeaseResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LeaseResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LeaseResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LeaseResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:RowCountResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RowCountResponse {
+    // message fields
+    // @@protoc_insertion_point(field:RowCountResponse.count)
+    pub count: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:RowCountResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RowCountResponse {
+    fn default() -> &'a RowCountResponse {
+        <RowCountResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RowCountResponse {
+    pub fn new() -> RowCountResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &RowCountResponse| { &m.count },
+            |m: &mut RowCountResponse| { &mut m.count },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RowCountResponse>(
+            "RowCountResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RowCountResponse {
+    const NAME: &'static str = "RowCountResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.count = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.count);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.count != 0 {
+            os.write_uint64(1, self.count)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RowCountResponse {
+        RowCountResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RowCountResponse {
+        static instance: RowCountResponse = RowCountResponse {
+            count: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RowCountResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RowCountResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RowCountResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RowCountResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:GetManyResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GetManyResponse {
+    // message fields
+    // @@protoc_insertion_point(field:GetManyResponse.items)
+    pub items: ::std::vec::Vec<GetResponse>,
+    // @@protoc_insertion_point(field:GetManyResponse.found)
+    pub found: ::std::vec::Vec<bool>,
+    // @@protoc_insertion_point(field:GetManyResponse.continuation_token)
+    pub continuation_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:GetManyResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GetManyResponse {
+    fn default() -> &'a GetManyResponse {
+        <GetManyResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetManyResponse {
+    pub fn new() -> GetManyResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &GetManyResponse| { &m.items },
+            |m: &mut GetManyResponse| { &mut m.items },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "found",
+            |m: &GetManyResponse| { &m.found },
+            |m: &mut GetManyResponse| { &mut m.found },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "continuation_token",
+            |m: &GetManyResponse| { &m.continuation_token },
+            |m: &mut GetManyResponse| { &mut m.continuation_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetManyResponse>(
+            "GetManyResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GetManyResponse {
+    const NAME: &'static str = "GetManyResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                18 => {
+                    is.read_repeated_packed_bool_into(&mut self.found)?;
+                },
+                16 => {
+                    self.found.push(is.read_bool()?);
+                },
+                26 => {
+                    self.continuation_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::vec_packed_bool_size(2, &self.found);
+        if !self.continuation_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.continuation_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_repeated_packed_bool(2, &self.found)?;
+        if !self.continuation_token.is_empty() {
+            os.write_string(3, &self.continuation_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GetManyResponse {
+        GetManyResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.found.clear();
+        self.continuation_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GetManyResponse {
+        static instance: GetManyResponse = GetManyResponse {
+            items: ::std::vec::Vec::new(),
+            found: ::std::vec::Vec::new(),
+            continuation_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GetManyResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetManyResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GetManyResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetManyResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:QueryByIndexResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct QueryByIndexResponse {
+    // message fields
+    // @@protoc_insertion_point(field:QueryByIndexResponse.items)
+    pub items: ::std::vec::Vec<GetResponse>,
+    // @@protoc_insertion_point(field:QueryByIndexResponse.continuation_token)
+    pub continuation_token: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:QueryByIndexResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a QueryByIndexResponse {
+    fn default() -> &'a QueryByIndexResponse {
+        <QueryByIndexResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryByIndexResponse {
+    pub fn new() -> QueryByIndexResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "items",
+            |m: &QueryByIndexResponse| { &m.items },
+            |m: &mut QueryByIndexResponse| { &mut m.items },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "continuation_token",
+            |m: &QueryByIndexResponse| { &m.continuation_token },
+            |m: &mut QueryByIndexResponse| { &mut m.continuation_token },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<QueryByIndexResponse>(
+            "QueryByIndexResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for QueryByIndexResponse {
+    const NAME: &'static str = "QueryByIndexResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.items.push(is.read_message()?);
+                },
+                18 => {
+                    self.continuation_token = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if !self.continuation_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.continuation_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.items {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if !self.continuation_token.is_empty() {
+            os.write_string(2, &self.continuation_token)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> QueryByIndexResponse {
+        QueryByIndexResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.continuation_token.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static QueryByIndexResponse {
+        static instance: QueryByIndexResponse = QueryByIndexResponse {
+            items: ::std::vec::Vec::new(),
+            continuation_token: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for QueryByIndexResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("QueryByIndexResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for QueryByIndexResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryByIndexResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DeleteWhereResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DeleteWhereResponse {
+    // message fields
+    // @@protoc_insertion_point(field:DeleteWhereResponse.deleted)
+    pub deleted: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:DeleteWhereResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DeleteWhereResponse {
+    fn default() -> &'a DeleteWhereResponse {
+        <DeleteWhereResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DeleteWhereResponse {
+    pub fn new() -> DeleteWhereResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "deleted",
+            |m: &DeleteWhereResponse| { &m.deleted },
+            |m: &mut DeleteWhereResponse| { &mut m.deleted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteWhereResponse>(
+            "DeleteWhereResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DeleteWhereResponse {
+    const NAME: &'static str = "DeleteWhereResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.deleted = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.deleted != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.deleted);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.deleted != 0 {
+            os.write_uint64(1, self.deleted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DeleteWhereResponse {
+        DeleteWhereResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.deleted = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DeleteWhereResponse {
+        static instance: DeleteWhereResponse = DeleteWhereResponse {
+            deleted: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DeleteWhereResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteWhereResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DeleteWhereResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteWhereResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:MoveRowResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct MoveRowResponse {
+    // message fields
+    // @@protoc_insertion_point(field:MoveRowResponse.moved)
+    pub moved: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:MoveRowResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a MoveRowResponse {
+    fn default() -> &'a MoveRowResponse {
+        <MoveRowResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MoveRowResponse {
+    pub fn new() -> MoveRowResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "moved",
+            |m: &MoveRowResponse| { &m.moved },
+            |m: &mut MoveRowResponse| { &mut m.moved },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MoveRowResponse>(
+            "MoveRowResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for MoveRowResponse {
+    const NAME: &'static str = "MoveRowResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.moved = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.moved != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.moved != false {
+            os.write_bool(1, self.moved)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> MoveRowResponse {
+        MoveRowResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.moved = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static MoveRowResponse {
+        static instance: MoveRowResponse = MoveRowResponse {
+            moved: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for MoveRowResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("MoveRowResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for MoveRowResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveRowResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SaveResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SaveResponse {
+    // message fields
+    // @@protoc_insertion_point(field:SaveResponse.version)
+    pub version: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:SaveResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SaveResponse {
+    fn default() -> &'a SaveResponse {
+        <SaveResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SaveResponse {
+    pub fn new() -> SaveResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &SaveResponse| { &m.version },
+            |m: &mut SaveResponse| { &mut m.version },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SaveResponse>(
+            "SaveResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SaveResponse {
+    const NAME: &'static str = "SaveResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.version = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.version != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.version);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.version != 0 {
+            os.write_uint64(1, self.version)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SaveResponse {
+        SaveResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.version = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SaveResponse {
+        static instance: SaveResponse = SaveResponse {
+            version: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SaveResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SaveResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SaveResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SaveResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReplicatedRecords)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReplicatedRecords {
+    // message fields
+    // @@protoc_insertion_point(field:ReplicatedRecords.sequence)
+    pub sequence: u64,
+    // @@protoc_insertion_point(field:ReplicatedRecords.table)
+    pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:ReplicatedRecords.records)
+    pub records: ::std::vec::Vec<u8>,
+    // special fields
+    // @@protoc_insertion_point(special_field:ReplicatedRecords.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplicatedRecords {
+    fn default() -> &'a ReplicatedRecords {
+        <ReplicatedRecords as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplicatedRecords {
+    pub fn new() -> ReplicatedRecords {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sequence",
+            |m: &ReplicatedRecords| { &m.sequence },
+            |m: &mut ReplicatedRecords| { &mut m.sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table",
+            |m: &ReplicatedRecords| { &m.table },
+            |m: &mut ReplicatedRecords| { &mut m.table },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "records",
+            |m: &ReplicatedRecords| { &m.records },
+            |m: &mut ReplicatedRecords| { &mut m.records },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplicatedRecords>(
+            "ReplicatedRecords",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplicatedRecords {
+    const NAME: &'static str = "ReplicatedRecords";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sequence = is.read_uint64()?;
+                },
+                18 => {
+                    self.table = is.read_string()?;
+                },
+                26 => {
+                    self.records = is.read_bytes()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.sequence);
+        }
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.table);
+        }
+        if !self.records.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.records);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sequence != 0 {
+            os.write_uint64(1, self.sequence)?;
+        }
+        if !self.table.is_empty() {
+            os.write_string(2, &self.table)?;
+        }
+        if !self.records.is_empty() {
+            os.write_bytes(3, &self.records)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplicatedRecords {
+        ReplicatedRecords::new()
+    }
+
+    fn clear(&mut self) {
+        self.sequence = 0;
+        self.table.clear();
+        self.records.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplicatedRecords {
+        static instance: ReplicatedRecords = ReplicatedRecords {
+            sequence: 0,
+            table: ::std::string::String::new(),
+            records: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplicatedRecords {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplicatedRecords").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplicatedRecords {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplicatedRecords {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReplicateResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReplicateResponse {
+    // message fields
+    // @@protoc_insertion_point(field:ReplicateResponse.epoch)
+    pub epoch: u64,
+    // @@protoc_insertion_point(field:ReplicateResponse.records)
+    pub records: ::std::vec::Vec<ReplicatedRecords>,
+    // @@protoc_insertion_point(field:ReplicateResponse.next_sequence)
+    pub next_sequence: u64,
+    // @@protoc_insertion_point(field:ReplicateResponse.schema_strings)
+    pub schema_strings: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:ReplicateResponse.partition_map)
+    pub partition_map: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:ReplicateResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReplicateResponse {
+    fn default() -> &'a ReplicateResponse {
+        <ReplicateResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplicateResponse {
+    pub fn new() -> ReplicateResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "epoch",
+            |m: &ReplicateResponse| { &m.epoch },
+            |m: &mut ReplicateResponse| { &mut m.epoch },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "records",
+            |m: &ReplicateResponse| { &m.records },
+            |m: &mut ReplicateResponse| { &mut m.records },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "next_sequence",
+            |m: &ReplicateResponse| { &m.next_sequence },
+            |m: &mut ReplicateResponse| { &mut m.next_sequence },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "schema_strings",
+            |m: &ReplicateResponse| { &m.schema_strings },
+            |m: &mut ReplicateResponse| { &mut m.schema_strings },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partition_map",
+            |m: &ReplicateResponse| { &m.partition_map },
+            |m: &mut ReplicateResponse| { &mut m.partition_map },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReplicateResponse>(
+            "ReplicateResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReplicateResponse {
+    const NAME: &'static str = "ReplicateResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.epoch = is.read_uint64()?;
+                },
+                18 => {
+                    self.records.push(is.read_message()?);
+                },
+                24 => {
+                    self.next_sequence = is.read_uint64()?;
+                },
+                34 => {
+                    self.schema_strings.push(is.read_string()?);
+                },
+                42 => {
+                    self.partition_map = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.epoch != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.epoch);
+        }
+        for value in &self.records {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.next_sequence != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.next_sequence);
+        }
+        for value in &self.schema_strings {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        if !self.partition_map.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.partition_map);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.epoch != 0 {
+            os.write_uint64(1, self.epoch)?;
+        }
+        for v in &self.records {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if self.next_sequence != 0 {
+            os.write_uint64(3, self.next_sequence)?;
+        }
+        for v in &self.schema_strings {
+            os.write_string(4, &v)?;
+        };
+        if !self.partition_map.is_empty() {
+            os.write_string(5, &self.partition_map)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReplicateResponse {
+        ReplicateResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.epoch = 0;
+        self.records.clear();
+        self.next_sequence = 0;
+        self.schema_strings.clear();
+        self.partition_map.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReplicateResponse {
+        static instance: ReplicateResponse = ReplicateResponse {
+            epoch: 0,
+            records: ::std::vec::Vec::new(),
+            next_sequence: 0,
+            schema_strings: ::std::vec::Vec::new(),
+            partition_map: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReplicateResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReplicateResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReplicateResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplicateResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PromoteResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PromoteResponse {
+    // message fields
+    // @@protoc_insertion_point(field:PromoteResponse.promoted)
+    pub promoted: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:PromoteResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PromoteResponse {
+    fn default() -> &'a PromoteResponse {
+        <PromoteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PromoteResponse {
+    pub fn new() -> PromoteResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "promoted",
+            |m: &PromoteResponse| { &m.promoted },
+            |m: &mut PromoteResponse| { &mut m.promoted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PromoteResponse>(
+            "PromoteResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PromoteResponse {
+    const NAME: &'static str = "PromoteResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.promoted = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.promoted != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.promoted != false {
+            os.write_bool(1, self.promoted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PromoteResponse {
+        PromoteResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.promoted = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PromoteResponse {
+        static instance: PromoteResponse = PromoteResponse {
+            promoted: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PromoteResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PromoteResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PromoteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PromoteResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:RaftVoteResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RaftVoteResponse {
+    // message fields
+    // @@protoc_insertion_point(field:RaftVoteResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:RaftVoteResponse.granted)
+    pub granted: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:RaftVoteResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftVoteResponse {
+    fn default() -> &'a RaftVoteResponse {
+        <RaftVoteResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftVoteResponse {
+    pub fn new() -> RaftVoteResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftVoteResponse| { &m.term },
+            |m: &mut RaftVoteResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "granted",
+            |m: &RaftVoteResponse| { &m.granted },
+            |m: &mut RaftVoteResponse| { &mut m.granted },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftVoteResponse>(
+            "RaftVoteResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftVoteResponse {
+    const NAME: &'static str = "RaftVoteResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.granted = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.granted != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.granted != false {
+            os.write_bool(2, self.granted)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftVoteResponse {
+        RaftVoteResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.granted = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftVoteResponse {
+        static instance: RaftVoteResponse = RaftVoteResponse {
+            term: 0,
+            granted: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftVoteResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftVoteResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftVoteResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftVoteResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:RaftAppendResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct RaftAppendResponse {
+    // message fields
+    // @@protoc_insertion_point(field:RaftAppendResponse.term)
+    pub term: u64,
+    // @@protoc_insertion_point(field:RaftAppendResponse.success)
+    pub success: bool,
+    // @@protoc_insertion_point(field:RaftAppendResponse.last_index)
+    pub last_index: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:RaftAppendResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a RaftAppendResponse {
+    fn default() -> &'a RaftAppendResponse {
+        <RaftAppendResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RaftAppendResponse {
+    pub fn new() -> RaftAppendResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "term",
+            |m: &RaftAppendResponse| { &m.term },
+            |m: &mut RaftAppendResponse| { &mut m.term },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "success",
+            |m: &RaftAppendResponse| { &m.success },
+            |m: &mut RaftAppendResponse| { &mut m.success },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "last_index",
+            |m: &RaftAppendResponse| { &m.last_index },
+            |m: &mut RaftAppendResponse| { &mut m.last_index },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RaftAppendResponse>(
+            "RaftAppendResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for RaftAppendResponse {
+    const NAME: &'static str = "RaftAppendResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.term = is.read_uint64()?;
+                },
+                16 => {
+                    self.success = is.read_bool()?;
+                },
+                24 => {
+                    self.last_index = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.term != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.term);
+        }
+        if self.success != false {
+            my_size += 1 + 1;
+        }
+        if self.last_index != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.last_index);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.term != 0 {
+            os.write_uint64(1, self.term)?;
+        }
+        if self.success != false {
+            os.write_bool(2, self.success)?;
+        }
+        if self.last_index != 0 {
+            os.write_uint64(3, self.last_index)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> RaftAppendResponse {
+        RaftAppendResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.term = 0;
+        self.success = false;
+        self.last_index = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static RaftAppendResponse {
+        static instance: RaftAppendResponse = RaftAppendResponse {
+            term: 0,
+            success: false,
+            last_index: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for RaftAppendResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("RaftAppendResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for RaftAppendResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RaftAppendResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:BatchResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct BatchResponse {
+    // message fields
+    // @@protoc_insertion_point(field:BatchResponse.okay)
+    pub okay: bool,
+    // @@protoc_insertion_point(field:BatchResponse.sequences)
+    pub sequences: ::std::vec::Vec<u64>,
+    // special fields
+    // @@protoc_insertion_point(special_field:BatchResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a BatchResponse {
+    fn default() -> &'a BatchResponse {
+        <BatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchResponse {
+    pub fn new() -> BatchResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "okay",
+            |m: &BatchResponse| { &m.okay },
+            |m: &mut BatchResponse| { &mut m.okay },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "sequences",
+            |m: &BatchResponse| { &m.sequences },
+            |m: &mut BatchResponse| { &mut m.sequences },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchResponse>(
+            "BatchResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for BatchResponse {
+    const NAME: &'static str = "BatchResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.okay = is.read_bool()?;
+                },
+                18 => {
+                    is.read_repeated_packed_uint64_into(&mut self.sequences)?;
+                },
+                16 => {
+                    self.sequences.push(is.read_uint64()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.okay != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::vec_packed_uint64_size(2, &self.sequences);
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.okay != false {
+            os.write_bool(1, self.okay)?;
+        }
+        os.write_repeated_packed_uint64(2, &self.sequences)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> BatchResponse {
+        BatchResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.okay = false;
+        self.sequences.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static BatchResponse {
+        static instance: BatchResponse = BatchResponse {
+            okay: false,
+            sequences: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for BatchResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for BatchResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:TransactionResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TransactionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:TransactionResponse.okay)
+    pub okay: bool,
+    // @@protoc_insertion_point(field:TransactionResponse.transaction_id)
+    pub transaction_id: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:TransactionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TransactionResponse {
+    fn default() -> &'a TransactionResponse {
+        <TransactionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TransactionResponse {
+    pub fn new() -> TransactionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "okay",
+            |m: &TransactionResponse| { &m.okay },
+            |m: &mut TransactionResponse| { &mut m.okay },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transaction_id",
+            |m: &TransactionResponse| { &m.transaction_id },
+            |m: &mut TransactionResponse| { &mut m.transaction_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TransactionResponse>(
+            "TransactionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TransactionResponse {
+    const NAME: &'static str = "TransactionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.okay = is.read_bool()?;
+                },
+                16 => {
+                    self.transaction_id = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.okay != false {
+            my_size += 1 + 1;
+        }
+        if self.transaction_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.transaction_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.okay != false {
+            os.write_bool(1, self.okay)?;
+        }
+        if self.transaction_id != 0 {
+            os.write_uint64(2, self.transaction_id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TransactionResponse {
+        TransactionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.okay = false;
+        self.transaction_id = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TransactionResponse {
+        static instance: TransactionResponse = TransactionResponse {
+            okay: false,
+            transaction_id: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TransactionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TransactionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TransactionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TransactionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:SyncModelResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct SyncModelResponse {
+    // special fields
+    // @@protoc_insertion_point(special_field:SyncModelResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a SyncModelResponse {
+    fn default() -> &'a SyncModelResponse {
+        <SyncModelResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SyncModelResponse {
+    pub fn new() -> SyncModelResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SyncModelResponse>(
+            "SyncModelResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for SyncModelResponse {
+    const NAME: &'static str = "SyncModelResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> SyncModelResponse {
+        SyncModelResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static SyncModelResponse {
+        static instance: SyncModelResponse = SyncModelResponse {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for SyncModelResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SyncModelResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for SyncModelResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SyncModelResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DropTableResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DropTableResponse {
+    // special fields
+    // @@protoc_insertion_point(special_field:DropTableResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DropTableResponse {
+    fn default() -> &'a DropTableResponse {
+        <DropTableResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DropTableResponse {
+    pub fn new() -> DropTableResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DropTableResponse>(
+            "DropTableResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DropTableResponse {
+    const NAME: &'static str = "DropTableResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DropTableResponse {
+        DropTableResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DropTableResponse {
+        static instance: DropTableResponse = DropTableResponse {
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DropTableResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DropTableResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DropTableResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DropTableResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PlanMigrationResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PlanMigrationResponse {
+    // message fields
+    // @@protoc_insertion_point(field:PlanMigrationResponse.steps)
+    pub steps: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:PlanMigrationResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PlanMigrationResponse {
+    fn default() -> &'a PlanMigrationResponse {
+        <PlanMigrationResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlanMigrationResponse {
+    pub fn new() -> PlanMigrationResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "steps",
+            |m: &PlanMigrationResponse| { &m.steps },
+            |m: &mut PlanMigrationResponse| { &mut m.steps },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PlanMigrationResponse>(
+            "PlanMigrationResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PlanMigrationResponse {
+    const NAME: &'static str = "PlanMigrationResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.steps.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.steps {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.steps {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PlanMigrationResponse {
+        PlanMigrationResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.steps.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PlanMigrationResponse {
+        static instance: PlanMigrationResponse = PlanMigrationResponse {
+            steps: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PlanMigrationResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PlanMigrationResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PlanMigrationResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlanMigrationResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DescribeTableResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DescribeTableResponse {
+    // message fields
+    // @@protoc_insertion_point(field:DescribeTableResponse.schema_string)
+    pub schema_string: ::std::string::String,
+    // @@protoc_insertion_point(field:DescribeTableResponse.statistics)
+    pub statistics: ::protobuf::MessageField<TableStatistics>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DescribeTableResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DescribeTableResponse {
+    fn default() -> &'a DescribeTableResponse {
+        <DescribeTableResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DescribeTableResponse {
+    pub fn new() -> DescribeTableResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "schema_string",
+            |m: &DescribeTableResponse| { &m.schema_string },
+            |m: &mut DescribeTableResponse| { &mut m.schema_string },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, TableStatistics>(
+            "statistics",
+            |m: &DescribeTableResponse| { &m.statistics },
+            |m: &mut DescribeTableResponse| { &mut m.statistics },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DescribeTableResponse>(
+            "DescribeTableResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DescribeTableResponse {
+    const NAME: &'static str = "DescribeTableResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.schema_string = is.read_string()?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.statistics)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.schema_string.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.schema_string);
+        }
+        if let Some(v) = self.statistics.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.schema_string.is_empty() {
+            os.write_string(1, &self.schema_string)?;
+        }
+        if let Some(v) = self.statistics.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DescribeTableResponse {
+        DescribeTableResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.schema_string.clear();
+        self.statistics.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DescribeTableResponse {
+        static instance: DescribeTableResponse = DescribeTableResponse {
+            schema_string: ::std::string::String::new(),
+            statistics: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DescribeTableResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DescribeTableResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DescribeTableResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DescribeTableResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:TableStatistics)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TableStatistics {
+    // message fields
+    // @@protoc_insertion_point(field:TableStatistics.sstables)
+    pub sstables: u32,
+    // @@protoc_insertion_point(field:TableStatistics.row_count_estimate)
+    pub row_count_estimate: u64,
+    // @@protoc_insertion_point(field:TableStatistics.average_row_size)
+    pub average_row_size: u64,
+    // @@protoc_insertion_point(field:TableStatistics.hash_key_cardinality)
+    pub hash_key_cardinality: u64,
+    // @@protoc_insertion_point(field:TableStatistics.rows_per_hash_key)
+    pub rows_per_hash_key: ::std::vec::Vec<u64>,
+    // @@protoc_insertion_point(field:TableStatistics.disk_usage)
+    pub disk_usage: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:TableStatistics.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TableStatistics {
+    fn default() -> &'a TableStatistics {
+        <TableStatistics as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TableStatistics {
+    pub fn new() -> TableStatistics {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sstables",
+            |m: &TableStatistics| { &m.sstables },
+            |m: &mut TableStatistics| { &mut m.sstables },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "row_count_estimate",
+            |m: &TableStatistics| { &m.row_count_estimate },
+            |m: &mut TableStatistics| { &mut m.row_count_estimate },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "average_row_size",
+            |m: &TableStatistics| { &m.average_row_size },
+            |m: &mut TableStatistics| { &mut m.average_row_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hash_key_cardinality",
+            |m: &TableStatistics| { &m.hash_key_cardinality },
+            |m: &mut TableStatistics| { &mut m.hash_key_cardinality },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "rows_per_hash_key",
+            |m: &TableStatistics| { &m.rows_per_hash_key },
+            |m: &mut TableStatistics| { &mut m.rows_per_hash_key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "disk_usage",
+            |m: &TableStatistics| { &m.disk_usage },
+            |m: &mut TableStatistics| { &mut m.disk_usage },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TableStatistics>(
+            "TableStatistics",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TableStatistics {
+    const NAME: &'static str = "TableStatistics";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.sstables = is.read_uint32()?;
+                },
+                16 => {
+                    self.row_count_estimate = is.read_uint64()?;
+                },
+                24 => {
+                    self.average_row_size = is.read_uint64()?;
+                },
+                32 => {
+                    self.hash_key_cardinality = is.read_uint64()?;
+                },
+                42 => {
+                    is.read_repeated_packed_uint64_into(&mut self.rows_per_hash_key)?;
+                },
+                40 => {
+                    self.rows_per_hash_key.push(is.read_uint64()?);
+                },
+                48 => {
+                    self.disk_usage = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.sstables != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.sstables);
+        }
+        if self.row_count_estimate != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.row_count_estimate);
+        }
+        if self.average_row_size != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.average_row_size);
+        }
+        if self.hash_key_cardinality != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.hash_key_cardinality);
+        }
+        my_size += ::protobuf::rt::vec_packed_uint64_size(5, &self.rows_per_hash_key);
+        if self.disk_usage != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.disk_usage);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.sstables != 0 {
+            os.write_uint32(1, self.sstables)?;
+        }
+        if self.row_count_estimate != 0 {
+            os.write_uint64(2, self.row_count_estimate)?;
+        }
+        if self.average_row_size != 0 {
+            os.write_uint64(3, self.average_row_size)?;
+        }
+        if self.hash_key_cardinality != 0 {
+            os.write_uint64(4, self.hash_key_cardinality)?;
+        }
+        os.write_repeated_packed_uint64(5, &self.rows_per_hash_key)?;
+        if self.disk_usage != 0 {
+            os.write_uint64(6, self.disk_usage)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TableStatistics {
+        TableStatistics::new()
+    }
+
+    fn clear(&mut self) {
+        self.sstables = 0;
+        self.row_count_estimate = 0;
+        self.average_row_size = 0;
+        self.hash_key_cardinality = 0;
+        self.rows_per_hash_key.clear();
+        self.disk_usage = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TableStatistics {
+        static instance: TableStatistics = TableStatistics {
+            sstables: 0,
+            row_count_estimate: 0,
+            average_row_size: 0,
+            hash_key_cardinality: 0,
+            rows_per_hash_key: ::std::vec::Vec::new(),
+            disk_usage: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TableStatistics {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TableStatistics").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TableStatistics {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TableStatistics {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:HealthResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct HealthResponse {
+    // message fields
+    // @@protoc_insertion_point(field:HealthResponse.degraded)
+    pub degraded: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:HealthResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a HealthResponse {
+    fn default() -> &'a HealthResponse {
+        <HealthResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl HealthResponse {
+    pub fn new() -> HealthResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "degraded",
+            |m: &HealthResponse| { &m.degraded },
+            |m: &mut HealthResponse| { &mut m.degraded },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<HealthResponse>(
+            "HealthResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for HealthResponse {
+    const NAME: &'static str = "HealthResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.degraded.push(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.degraded {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.degraded {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> HealthResponse {
+        HealthResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.degraded.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static HealthResponse {
+        static instance: HealthResponse = HealthResponse {
+            degraded: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for HealthResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("HealthResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for HealthResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for HealthResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:ReadyResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ReadyResponse {
+    // message fields
+    // @@protoc_insertion_point(field:ReadyResponse.ready)
+    pub ready: bool,
+    // @@protoc_insertion_point(field:ReadyResponse.detail)
+    pub detail: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:ReadyResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ReadyResponse {
+    fn default() -> &'a ReadyResponse {
+        <ReadyResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReadyResponse {
+    pub fn new() -> ReadyResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ready",
+            |m: &ReadyResponse| { &m.ready },
+            |m: &mut ReadyResponse| { &mut m.ready },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "detail",
+            |m: &ReadyResponse| { &m.detail },
+            |m: &mut ReadyResponse| { &mut m.detail },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ReadyResponse>(
+            "ReadyResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ReadyResponse {
+    const NAME: &'static str = "ReadyResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.ready = is.read_bool()?;
+                },
+                18 => {
+                    self.detail = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.ready != false {
+            my_size += 1 + 1;
+        }
+        if !self.detail.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.detail);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.ready != false {
+            os.write_bool(1, self.ready)?;
+        }
+        if !self.detail.is_empty() {
+            os.write_string(2, &self.detail)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ReadyResponse {
+        ReadyResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.ready = false;
+        self.detail.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ReadyResponse {
+        static instance: ReadyResponse = ReadyResponse {
+            ready: false,
+            detail: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ReadyResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ReadyResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ReadyResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReadyResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DescribeServerResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DescribeServerResponse {
+    // message fields
+    // @@protoc_insertion_point(field:DescribeServerResponse.version)
+    pub version: ::std::string::String,
+    // @@protoc_insertion_point(field:DescribeServerResponse.uptime_millis)
+    pub uptime_millis: u64,
+    // @@protoc_insertion_point(field:DescribeServerResponse.number_of_threads)
+    pub number_of_threads: u32,
+    // @@protoc_insertion_point(field:DescribeServerResponse.config)
+    pub config: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    // @@protoc_insertion_point(field:DescribeServerResponse.features)
+    pub features: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:DescribeServerResponse.databases)
+    pub databases: ::std::vec::Vec<DatabaseDescription>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DescribeServerResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DescribeServerResponse {
+    fn default() -> &'a DescribeServerResponse {
+        <DescribeServerResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DescribeServerResponse {
+    pub fn new() -> DescribeServerResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "version",
+            |m: &DescribeServerResponse| { &m.version },
+            |m: &mut DescribeServerResponse| { &mut m.version },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "uptime_millis",
+            |m: &DescribeServerResponse| { &m.uptime_millis },
+            |m: &mut DescribeServerResponse| { &mut m.uptime_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "number_of_threads",
+            |m: &DescribeServerResponse| { &m.number_of_threads },
+            |m: &mut DescribeServerResponse| { &mut m.number_of_threads },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "config",
+            |m: &DescribeServerResponse| { &m.config },
+            |m: &mut DescribeServerResponse| { &mut m.config },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "features",
+            |m: &DescribeServerResponse| { &m.features },
+            |m: &mut DescribeServerResponse| { &mut m.features },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "databases",
+            |m: &DescribeServerResponse| { &m.databases },
+            |m: &mut DescribeServerResponse| { &mut m.databases },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DescribeServerResponse>(
+            "DescribeServerResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DescribeServerResponse {
+    const NAME: &'static str = "DescribeServerResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.version = is.read_string()?;
+                },
+                16 => {
+                    self.uptime_millis = is.read_uint64()?;
+                },
+                24 => {
+                    self.number_of_threads = is.read_uint32()?;
+                },
+                34 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.config.insert(key, value);
+                },
+                42 => {
+                    self.features.push(is.read_string()?);
+                },
+                50 => {
+                    self.databases.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.version);
+        }
+        if self.uptime_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.uptime_millis);
+        }
+        if self.number_of_threads != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.number_of_threads);
+        }
+        for (k, v) in &self.config {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
+        for value in &self.features {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        for value in &self.databases {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.version.is_empty() {
+            os.write_string(1, &self.version)?;
+        }
+        if self.uptime_millis != 0 {
+            os.write_uint64(2, self.uptime_millis)?;
+        }
+        if self.number_of_threads != 0 {
+            os.write_uint32(3, self.number_of_threads)?;
+        }
+        for (k, v) in &self.config {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(34)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
+        for v in &self.features {
+            os.write_string(5, &v)?;
+        };
+        for v in &self.databases {
+            ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DescribeServerResponse {
+        DescribeServerResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.version.clear();
+        self.uptime_millis = 0;
+        self.number_of_threads = 0;
+        self.config.clear();
+        self.features.clear();
+        self.databases.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DescribeServerResponse {
+        static instance: ::protobuf::rt::Lazy<DescribeServerResponse> = ::protobuf::rt::Lazy::new();
+        instance.get(DescribeServerResponse::new)
+    }
+}
+
+impl ::protobuf::MessageFull for DescribeServerResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DescribeServerResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DescribeServerResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DescribeServerResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:DatabaseDescription)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct DatabaseDescription {
+    // message fields
+    // @@protoc_insertion_point(field:DatabaseDescription.name)
+    pub name: ::std::string::String,
+    // @@protoc_insertion_point(field:DatabaseDescription.data_dir)
+    pub data_dir: ::std::string::String,
+    // @@protoc_insertion_point(field:DatabaseDescription.sstables_dir)
+    pub sstables_dir: ::std::string::String,
+    // @@protoc_insertion_point(field:DatabaseDescription.commit_logs_dir)
+    pub commit_logs_dir: ::std::string::String,
+    // @@protoc_insertion_point(field:DatabaseDescription.table_schemas_file)
+    pub table_schemas_file: ::std::string::String,
+    // @@protoc_insertion_point(field:DatabaseDescription.partition_threads)
+    pub partition_threads: ::std::vec::Vec<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:DatabaseDescription.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a DatabaseDescription {
+    fn default() -> &'a DatabaseDescription {
+        <DatabaseDescription as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DatabaseDescription {
+    pub fn new() -> DatabaseDescription {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &DatabaseDescription| { &m.name },
+            |m: &mut DatabaseDescription| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data_dir",
+            |m: &DatabaseDescription| { &m.data_dir },
+            |m: &mut DatabaseDescription| { &mut m.data_dir },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sstables_dir",
+            |m: &DatabaseDescription| { &m.sstables_dir },
+            |m: &mut DatabaseDescription| { &mut m.sstables_dir },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "commit_logs_dir",
+            |m: &DatabaseDescription| { &m.commit_logs_dir },
+            |m: &mut DatabaseDescription| { &mut m.commit_logs_dir },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table_schemas_file",
+            |m: &DatabaseDescription| { &m.table_schemas_file },
+            |m: &mut DatabaseDescription| { &mut m.table_schemas_file },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "partition_threads",
+            |m: &DatabaseDescription| { &m.partition_threads },
+            |m: &mut DatabaseDescription| { &mut m.partition_threads },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DatabaseDescription>(
+            "DatabaseDescription",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for DatabaseDescription {
+    const NAME: &'static str = "DatabaseDescription";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.name = is.read_string()?;
+                },
+                18 => {
+                    self.data_dir = is.read_string()?;
+                },
+                26 => {
+                    self.sstables_dir = is.read_string()?;
+                },
+                34 => {
+                    self.commit_logs_dir = is.read_string()?;
+                },
+                42 => {
+                    self.table_schemas_file = is.read_string()?;
+                },
+                50 => {
+                    is.read_repeated_packed_uint32_into(&mut self.partition_threads)?;
+                },
+                48 => {
+                    self.partition_threads.push(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.data_dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.data_dir);
+        }
+        if !self.sstables_dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.sstables_dir);
+        }
+        if !self.commit_logs_dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.commit_logs_dir);
+        }
+        if !self.table_schemas_file.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.table_schemas_file);
+        }
+        my_size += ::protobuf::rt::vec_packed_uint32_size(6, &self.partition_threads);
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.data_dir.is_empty() {
+            os.write_string(2, &self.data_dir)?;
+        }
+        if !self.sstables_dir.is_empty() {
+            os.write_string(3, &self.sstables_dir)?;
+        }
+        if !self.commit_logs_dir.is_empty() {
+            os.write_string(4, &self.commit_logs_dir)?;
+        }
+        if !self.table_schemas_file.is_empty() {
+            os.write_string(5, &self.table_schemas_file)?;
+        }
+        os.write_repeated_packed_uint32(6, &self.partition_threads)?;
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> DatabaseDescription {
+        DatabaseDescription::new()
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+        self.data_dir.clear();
+        self.sstables_dir.clear();
+        self.commit_logs_dir.clear();
+        self.table_schemas_file.clear();
+        self.partition_threads.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static DatabaseDescription {
+        static instance: DatabaseDescription = DatabaseDescription {
+            name: ::std::string::String::new(),
+            data_dir: ::std::string::String::new(),
+            sstables_dir: ::std::string::String::new(),
+            commit_logs_dir: ::std::string::String::new(),
+            table_schemas_file: ::std::string::String::new(),
+            partition_threads: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for DatabaseDescription {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("DatabaseDescription").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for DatabaseDescription {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DatabaseDescription {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PartitionMapResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PartitionMapResponse {
+    // message fields
+    // @@protoc_insertion_point(field:PartitionMapResponse.partition_map)
+    pub partition_map: ::std::string::String,
+    // @@protoc_insertion_point(field:PartitionMapResponse.leader)
+    pub leader: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:PartitionMapResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PartitionMapResponse {
+    fn default() -> &'a PartitionMapResponse {
+        <PartitionMapResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PartitionMapResponse {
+    pub fn new() -> PartitionMapResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partition_map",
+            |m: &PartitionMapResponse| { &m.partition_map },
+            |m: &mut PartitionMapResponse| { &mut m.partition_map },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "leader",
+            |m: &PartitionMapResponse| { &m.leader },
+            |m: &mut PartitionMapResponse| { &mut m.leader },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PartitionMapResponse>(
+            "PartitionMapResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PartitionMapResponse {
+    const NAME: &'static str = "PartitionMapResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.partition_map = is.read_string()?;
+                },
+                18 => {
+                    self.leader = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.partition_map.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.partition_map);
+        }
+        if !self.leader.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.leader);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.partition_map.is_empty() {
+            os.write_string(1, &self.partition_map)?;
+        }
+        if !self.leader.is_empty() {
+            os.write_string(2, &self.leader)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PartitionMapResponse {
+        PartitionMapResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.partition_map.clear();
+        self.leader.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PartitionMapResponse {
+        static instance: PartitionMapResponse = PartitionMapResponse {
+            partition_map: ::std::string::String::new(),
+            leader: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PartitionMapResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PartitionMapResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PartitionMapResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PartitionMapResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PartitionKeyDistribution)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PartitionKeyDistribution {
+    // message fields
+    // @@protoc_insertion_point(field:PartitionKeyDistribution.partition)
+    pub partition: u32,
+    // @@protoc_insertion_point(field:PartitionKeyDistribution.memtable_rows)
+    pub memtable_rows: u64,
+    // @@protoc_insertion_point(field:PartitionKeyDistribution.sstable_rows)
+    pub sstable_rows: u64,
+    // @@protoc_insertion_point(field:PartitionKeyDistribution.bytes)
+    pub bytes: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:PartitionKeyDistribution.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PartitionKeyDistribution {
+    fn default() -> &'a PartitionKeyDistribution {
+        <PartitionKeyDistribution as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PartitionKeyDistribution {
+    pub fn new() -> PartitionKeyDistribution {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partition",
+            |m: &PartitionKeyDistribution| { &m.partition },
+            |m: &mut PartitionKeyDistribution| { &mut m.partition },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "memtable_rows",
+            |m: &PartitionKeyDistribution| { &m.memtable_rows },
+            |m: &mut PartitionKeyDistribution| { &mut m.memtable_rows },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sstable_rows",
+            |m: &PartitionKeyDistribution| { &m.sstable_rows },
+            |m: &mut PartitionKeyDistribution| { &mut m.sstable_rows },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bytes",
+            |m: &PartitionKeyDistribution| { &m.bytes },
+            |m: &mut PartitionKeyDistribution| { &mut m.bytes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PartitionKeyDistribution>(
+            "PartitionKeyDistribution",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PartitionKeyDistribution {
+    const NAME: &'static str = "PartitionKeyDistribution";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.partition = is.read_uint32()?;
+                },
+                16 => {
+                    self.memtable_rows = is.read_uint64()?;
+                },
+                24 => {
+                    self.sstable_rows = is.read_uint64()?;
+                },
+                32 => {
+                    self.bytes = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.partition != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.partition);
+        }
+        if self.memtable_rows != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.memtable_rows);
+        }
+        if self.sstable_rows != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.sstable_rows);
+        }
+        if self.bytes != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.bytes);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.partition != 0 {
+            os.write_uint32(1, self.partition)?;
+        }
+        if self.memtable_rows != 0 {
+            os.write_uint64(2, self.memtable_rows)?;
+        }
+        if self.sstable_rows != 0 {
+            os.write_uint64(3, self.sstable_rows)?;
+        }
+        if self.bytes != 0 {
+            os.write_uint64(4, self.bytes)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PartitionKeyDistribution {
+        PartitionKeyDistribution::new()
+    }
+
+    fn clear(&mut self) {
+        self.partition = 0;
+        self.memtable_rows = 0;
+        self.sstable_rows = 0;
+        self.bytes = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PartitionKeyDistribution {
+        static instance: PartitionKeyDistribution = PartitionKeyDistribution {
+            partition: 0,
+            memtable_rows: 0,
+            sstable_rows: 0,
+            bytes: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PartitionKeyDistribution {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PartitionKeyDistribution").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PartitionKeyDistribution {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PartitionKeyDistribution {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:KeyDistributionResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct KeyDistributionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:KeyDistributionResponse.partitions)
+    pub partitions: ::std::vec::Vec<PartitionKeyDistribution>,
+    // special fields
+    // @@protoc_insertion_point(special_field:KeyDistributionResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a KeyDistributionResponse {
+    fn default() -> &'a KeyDistributionResponse {
+        <KeyDistributionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyDistributionResponse {
+    pub fn new() -> KeyDistributionResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "partitions",
+            |m: &KeyDistributionResponse| { &m.partitions },
+            |m: &mut KeyDistributionResponse| { &mut m.partitions },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyDistributionResponse>(
+            "KeyDistributionResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyDistributionResponse {
+    const NAME: &'static str = "KeyDistributionResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.partitions.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.partitions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.partitions {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> KeyDistributionResponse {
+        KeyDistributionResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.partitions.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static KeyDistributionResponse {
+        static instance: KeyDistributionResponse = KeyDistributionResponse {
+            partitions: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for KeyDistributionResponse {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("KeyDistributionResponse").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for KeyDistributionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyDistributionResponse {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PartitionLoadStats)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PartitionLoadStats {
+    // message fields
+    // @@protoc_insertion_point(field:PartitionLoadStats.partition)
+    pub partition: u32,
+    // @@protoc_insertion_point(field:PartitionLoadStats.requests_per_second)
+    pub requests_per_second: f64,
+    // @@protoc_insertion_point(field:PartitionLoadStats.average_latency_micros)
+    pub average_latency_micros: u64,
+    // @@protoc_insertion_point(field:PartitionLoadStats.skew)
+    pub skew: f64,
+    // @@protoc_insertion_point(field:PartitionLoadStats.hot)
+    pub hot: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:PartitionLoadStats.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PartitionLoadStats {
+    fn default() -> &'a PartitionLoadStats {
+        <PartitionLoadStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PartitionLoadStats {
+    pub fn new() -> PartitionLoadStats {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "partition",
+            |m: &PartitionLoadStats| { &m.partition },
+            |m: &mut PartitionLoadStats| { &mut m.partition },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "requests_per_second",
+            |m: &PartitionLoadStats| { &m.requests_per_second },
+            |m: &mut PartitionLoadStats| { &mut m.requests_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "average_latency_micros",
+            |m: &PartitionLoadStats| { &m.average_latency_micros },
+            |m: &mut PartitionLoadStats| { &mut m.average_latency_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "skew",
+            |m: &PartitionLoadStats| { &m.skew },
+            |m: &mut PartitionLoadStats| { &mut m.skew },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "hot",
+            |m: &PartitionLoadStats| { &m.hot },
+            |m: &mut PartitionLoadStats| { &mut m.hot },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PartitionLoadStats>(
+            "PartitionLoadStats",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PartitionLoadStats {
+    const NAME: &'static str = "PartitionLoadStats";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.partition = is.read_uint32()?;
+                },
+                17 => {
+                    self.requests_per_second = is.read_double()?;
+                },
+                24 => {
+                    self.average_latency_micros = is.read_uint64()?;
+                },
+                33 => {
+                    self.skew = is.read_double()?;
+                },
+                40 => {
+                    self.hot = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.partition != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.partition);
+        }
+        if self.requests_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.average_latency_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.average_latency_micros);
+        }
+        if self.skew != 0. {
+            my_size += 1 + 8;
+        }
+        if self.hot != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.partition != 0 {
+            os.write_uint32(1, self.partition)?;
+        }
+        if self.requests_per_second != 0. {
+            os.write_double(2, self.requests_per_second)?;
+        }
+        if self.average_latency_micros != 0 {
+            os.write_uint64(3, self.average_latency_micros)?;
+        }
+        if self.skew != 0. {
+            os.write_double(4, self.skew)?;
+        }
+        if self.hot != false {
+            os.write_bool(5, self.hot)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PartitionLoadStats {
+        PartitionLoadStats::new()
+    }
+
+    fn clear(&mut self) {
+        self.partition = 0;
+        self.requests_per_second = 0.;
+        self.average_latency_micros = 0;
+        self.skew = 0.;
+        self.hot = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PartitionLoadStats {
+        static instance: PartitionLoadStats = PartitionLoadStats {
+            partition: 0,
+            requests_per_second: 0.,
+            average_latency_micros: 0,
+            skew: 0.,
+            hot: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PartitionLoadStats {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PartitionLoadStats").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for PartitionLoadStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PartitionLoadStats {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:PartitionLoadResponse)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct PartitionLoadResponse {
+    // message fields
+    // @@protoc_insertion_point(field:PartitionLoadResponse.partitions)
+    pub partitions: ::std::vec::Vec<PartitionLoadStats>,
+    // special fields
+    // @@protoc_insertion_point(special_field:PartitionLoadResponse.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a PartitionLoadResponse {
+    fn default() -> &'a PartitionLoadResponse {
+        <PartitionLoadResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PartitionLoadResponse {
+    pub fn new() -> PartitionLoadResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "partitions",
+            |m: &PartitionLoadResponse| { &m.partitions },
+            |m: &mut PartitionLoadResponse| { &mut m.partitions },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PartitionLoadResponse>(
+            "PartitionLoadResponse",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for PartitionLoadResponse {
+    const NAME: &'static str = "PartitionLoadResponse";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.partitions.push(is.read_message()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.partitions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        for v in &self.partitions {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> PartitionLoadResponse {
+        PartitionLoadResponse::new()
+    }
+
+    fn clear(&mut self) {
+        self.partitions.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static PartitionLoadResponse {
+        static instance: PartitionLoadResponse = PartitionLoadResponse {
+            partitions: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for PartitionLoadResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("Response").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("PartitionLoadResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for Response {
+impl ::std::fmt::Display for PartitionLoadResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Response {
+impl ::protobuf::reflect::ProtobufValue for PartitionLoadResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `Response`
-pub mod response {
+// @@protoc_insertion_point(message:TableAccessStats)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TableAccessStats {
+    // message fields
+    // @@protoc_insertion_point(field:TableAccessStats.table)
+    pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:TableAccessStats.reads_per_second)
+    pub reads_per_second: f64,
+    // @@protoc_insertion_point(field:TableAccessStats.writes_per_second)
+    pub writes_per_second: f64,
+    // @@protoc_insertion_point(field:TableAccessStats.read_bytes_per_second)
+    pub read_bytes_per_second: f64,
+    // @@protoc_insertion_point(field:TableAccessStats.written_bytes_per_second)
+    pub written_bytes_per_second: f64,
+    // @@protoc_insertion_point(field:TableAccessStats.read_p99_latency_micros)
+    pub read_p99_latency_micros: u64,
+    // @@protoc_insertion_point(field:TableAccessStats.write_p99_latency_micros)
+    pub write_p99_latency_micros: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:TableAccessStats.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
 
-    #[derive(Clone,PartialEq,Debug)]
-    #[non_exhaustive]
-    // @@protoc_insertion_point(oneof:Response.data)
-    pub enum Data {
-        // @@protoc_insertion_point(oneof_field:Response.get)
-        Get(super::GetResponse),
-        // @@protoc_insertion_point(oneof_field:Response.insert)
-        Insert(super::InsertResponse),
-        // @@protoc_insertion_point(oneof_field:Response.delete)
-        Delete(super::DeleteResponse),
-        // @@protoc_insertion_point(oneof_field:Response.get_many)
-        GetMany(super::GetManyResponse),
-        // @@protoc_insertion_point(oneof_field:Response.batch)
-        Batch(super::BatchResponse),
-        // @@protoc_insertion_point(oneof_field:Response.transaction)
-        Transaction(super::TransactionResponse),
-        // @@protoc_insertion_point(oneof_field:Response.model)
-        Model(super::SyncModelResponse),
-        // @@protoc_insertion_point(oneof_field:Response.drop_table)
-        DropTable(super::DropTableResponse),
-        // @@protoc_insertion_point(oneof_field:Response.client_error)
-        ClientError(super::ClientError),
-        // @@protoc_insertion_point(oneof_field:Response.server_error)
-        ServerError(super::ServerError),
+impl<'a> ::std::default::Default for &'a TableAccessStats {
+    fn default() -> &'a TableAccessStats {
+        <TableAccessStats as ::protobuf::Message>::default_instance()
     }
+}
 
-    impl ::protobuf::Oneof for Data {
+impl TableAccessStats {
+    pub fn new() -> TableAccessStats {
+        ::std::default::Default::default()
     }
 
-    impl ::protobuf::OneofFull for Data {
-        fn descriptor() -> ::protobuf::reflect::OneofDescriptor {
-            static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::OneofDescriptor> = ::protobuf::rt::Lazy::new();
-            descriptor.get(|| <super::Response as ::protobuf::MessageFull>::descriptor().oneof_by_name("data").unwrap()).clone()
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table",
+            |m: &TableAccessStats| { &m.table },
+            |m: &mut TableAccessStats| { &mut m.table },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reads_per_second",
+            |m: &TableAccessStats| { &m.reads_per_second },
+            |m: &mut TableAccessStats| { &mut m.reads_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "writes_per_second",
+            |m: &TableAccessStats| { &m.writes_per_second },
+            |m: &mut TableAccessStats| { &mut m.writes_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "read_bytes_per_second",
+            |m: &TableAccessStats| { &m.read_bytes_per_second },
+            |m: &mut TableAccessStats| { &mut m.read_bytes_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "written_bytes_per_second",
+            |m: &TableAccessStats| { &m.written_bytes_per_second },
+            |m: &mut TableAccessStats| { &mut m.written_bytes_per_second },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "read_p99_latency_micros",
+            |m: &TableAccessStats| { &m.read_p99_latency_micros },
+            |m: &mut TableAccessStats| { &mut m.read_p99_latency_micros },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "write_p99_latency_micros",
+            |m: &TableAccessStats| { &m.write_p99_latency_micros },
+            |m: &mut TableAccessStats| { &mut m.write_p99_latency_micros },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TableAccessStats>(
+            "TableAccessStats",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TableAccessStats {
+    const NAME: &'static str = "TableAccessStats";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    self.table = is.read_string()?;
+                },
+                17 => {
+                    self.reads_per_second = is.read_double()?;
+                },
+                25 => {
+                    self.writes_per_second = is.read_double()?;
+                },
+                33 => {
+                    self.read_bytes_per_second = is.read_double()?;
+                },
+                41 => {
+                    self.written_bytes_per_second = is.read_double()?;
+                },
+                48 => {
+                    self.read_p99_latency_micros = is.read_uint64()?;
+                },
+                56 => {
+                    self.write_p99_latency_micros = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    impl Data {
-        pub(in super) fn generated_oneof_descriptor_data() -> ::protobuf::reflect::GeneratedOneofDescriptorData {
-            ::protobuf::reflect::GeneratedOneofDescriptorData::new::<Data>("data")
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.table);
+        }
+        if self.reads_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.writes_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.read_bytes_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.written_bytes_per_second != 0. {
+            my_size += 1 + 8;
+        }
+        if self.read_p99_latency_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(6, self.read_p99_latency_micros);
+        }
+        if self.write_p99_latency_micros != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.write_p99_latency_micros);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.table.is_empty() {
+            os.write_string(1, &self.table)?;
+        }
+        if self.reads_per_second != 0. {
+            os.write_double(2, self.reads_per_second)?;
+        }
+        if self.writes_per_second != 0. {
+            os.write_double(3, self.writes_per_second)?;
+        }
+        if self.read_bytes_per_second != 0. {
+            os.write_double(4, self.read_bytes_per_second)?;
         }
+        if self.written_bytes_per_second != 0. {
+            os.write_double(5, self.written_bytes_per_second)?;
+        }
+        if self.read_p99_latency_micros != 0 {
+            os.write_uint64(6, self.read_p99_latency_micros)?;
+        }
+        if self.write_p99_latency_micros != 0 {
+            os.write_uint64(7, self.write_p99_latency_micros)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TableAccessStats {
+        TableAccessStats::new()
+    }
+
+    fn clear(&mut self) {
+        self.table.clear();
+        self.reads_per_second = 0.;
+        self.writes_per_second = 0.;
+        self.read_bytes_per_second = 0.;
+        self.written_bytes_per_second = 0.;
+        self.read_p99_latency_micros = 0;
+        self.write_p99_latency_micros = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TableAccessStats {
+        static instance: TableAccessStats = TableAccessStats {
+            table: ::std::string::String::new(),
+            reads_per_second: 0.,
+            writes_per_second: 0.,
+            read_bytes_per_second: 0.,
+            written_bytes_per_second: 0.,
+            read_p99_latency_micros: 0,
+            write_p99_latency_micros: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-// @@protoc_insertion_point(message:GetResponse)
+impl ::protobuf::MessageFull for TableAccessStats {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TableAccessStats").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TableAccessStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TableAccessStats {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:TableAccessResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetResponse {
+pub struct TableAccessResponse {
     // message fields
-    // @@protoc_insertion_point(field:GetResponse.hash_key)
-    pub hash_key: ::std::string::String,
-    // @@protoc_insertion_point(field:GetResponse.sort_key)
-    pub sort_key: ::protobuf::MessageField<super::common::Value>,
-    // @@protoc_insertion_point(field:GetResponse.values)
-    pub values: ::std::collections::HashMap<::std::string::String, super::common::Value>,
-    // @@protoc_insertion_point(field:GetResponse.table)
-    pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:TableAccessResponse.tables)
+    pub tables: ::std::vec::Vec<TableAccessStats>,
     // special fields
-    // @@protoc_insertion_point(special_field:GetResponse.special_fields)
+    // @@protoc_insertion_point(special_field:TableAccessResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetResponse {
-    fn default() -> &'a GetResponse {
-        <GetResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TableAccessResponse {
+    fn default() -> &'a TableAccessResponse {
+        <TableAccessResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetResponse {
-    pub fn new() -> GetResponse {
+impl TableAccessResponse {
+    pub fn new() -> TableAccessResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "hash_key",
-            |m: &GetResponse| { &m.hash_key },
-            |m: &mut GetResponse| { &mut m.hash_key },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::common::Value>(
-            "sort_key",
-            |m: &GetResponse| { &m.sort_key },
-            |m: &mut GetResponse| { &mut m.sort_key },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
-            "values",
-            |m: &GetResponse| { &m.values },
-            |m: &mut GetResponse| { &mut m.values },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "table",
-            |m: &GetResponse| { &m.table },
-            |m: &mut GetResponse| { &mut m.table },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tables",
+            |m: &TableAccessResponse| { &m.tables },
+            |m: &mut TableAccessResponse| { &mut m.tables },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetResponse>(
-            "GetResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TableAccessResponse>(
+            "TableAccessResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetResponse {
-    const NAME: &'static str = "GetResponse";
+impl ::protobuf::Message for TableAccessResponse {
+    const NAME: &'static str = "TableAccessResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -924,30 +8210,9 @@ impl ::protobuf::Message for GetResponse {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
-            match tag {
-                10 => {
-                    self.hash_key = is.read_string()?;
-                },
-                18 => {
-                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.sort_key)?;
-                },
-                26 => {
-                    let len = is.read_raw_varint32()?;
-                    let old_limit = is.push_limit(len as u64)?;
-                    let mut key = ::std::default::Default::default();
-                    let mut value = ::std::default::Default::default();
-                    while let Some(tag) = is.read_raw_tag_or_eof()? {
-                        match tag {
-                            10 => key = is.read_string()?,
-                            18 => value = is.read_message()?,
-                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
-                        };
-                    }
-                    is.pop_limit(old_limit);
-                    self.values.insert(key, value);
-                },
-                34 => {
-                    self.table = is.read_string()?;
+            match tag {
+                10 => {
+                    self.tables.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -961,48 +8226,19 @@ impl ::protobuf::Message for GetResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if !self.hash_key.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hash_key);
-        }
-        if let Some(v) = self.sort_key.as_ref() {
-            let len = v.compute_size();
+        for value in &self.tables {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        }
-        for (k, v) in &self.values {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            let len = v.compute_size();
-            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
         };
-        if !self.table.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.table);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if !self.hash_key.is_empty() {
-            os.write_string(1, &self.hash_key)?;
-        }
-        if let Some(v) = self.sort_key.as_ref() {
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
-        }
-        for (k, v) in &self.values {
-            let mut entry_size = 0;
-            entry_size += ::protobuf::rt::string_size(1, &k);
-            let len = v.cached_size() as u64;
-            entry_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-            os.write_raw_varint32(26)?; // Tag.
-            os.write_raw_varint32(entry_size as u32)?;
-            os.write_string(1, &k)?;
-            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        for v in &self.tables {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
         };
-        if !self.table.is_empty() {
-            os.write_string(4, &self.table)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1015,73 +8251,102 @@ impl ::protobuf::Message for GetResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetResponse {
-        GetResponse::new()
+    fn new() -> TableAccessResponse {
+        TableAccessResponse::new()
     }
 
     fn clear(&mut self) {
-        self.hash_key.clear();
-        self.sort_key.clear();
-        self.values.clear();
-        self.table.clear();
+        self.tables.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetResponse {
-        static instance: ::protobuf::rt::Lazy<GetResponse> = ::protobuf::rt::Lazy::new();
-        instance.get(GetResponse::new)
+    fn default_instance() -> &'static TableAccessResponse {
+        static instance: TableAccessResponse = TableAccessResponse {
+            tables: ::std::vec::Vec::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetResponse {
+impl ::protobuf::MessageFull for TableAccessResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TableAccessResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetResponse {
+impl ::std::fmt::Display for TableAccessResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetResponse {
+impl ::protobuf::reflect::ProtobufValue for TableAccessResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:InsertResponse)
+// @@protoc_insertion_point(message:ActiveTransaction)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct InsertResponse {
+pub struct ActiveTransaction {
+    // message fields
+    // @@protoc_insertion_point(field:ActiveTransaction.transaction_id)
+    pub transaction_id: u64,
+    // @@protoc_insertion_point(field:ActiveTransaction.age_millis)
+    pub age_millis: u64,
+    // @@protoc_insertion_point(field:ActiveTransaction.tables)
+    pub tables: ::std::vec::Vec<::std::string::String>,
+    // @@protoc_insertion_point(field:ActiveTransaction.coordinator_partition)
+    pub coordinator_partition: ::std::option::Option<u32>,
     // special fields
-    // @@protoc_insertion_point(special_field:InsertResponse.special_fields)
+    // @@protoc_insertion_point(special_field:ActiveTransaction.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a InsertResponse {
-    fn default() -> &'a InsertResponse {
-        <InsertResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ActiveTransaction {
+    fn default() -> &'a ActiveTransaction {
+        <ActiveTransaction as ::protobuf::Message>::default_instance()
     }
 }
 
-impl InsertResponse {
-    pub fn new() -> InsertResponse {
+impl ActiveTransaction {
+    pub fn new() -> ActiveTransaction {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(4);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InsertResponse>(
-            "InsertResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "transaction_id",
+            |m: &ActiveTransaction| { &m.transaction_id },
+            |m: &mut ActiveTransaction| { &mut m.transaction_id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "age_millis",
+            |m: &ActiveTransaction| { &m.age_millis },
+            |m: &mut ActiveTransaction| { &mut m.age_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tables",
+            |m: &ActiveTransaction| { &m.tables },
+            |m: &mut ActiveTransaction| { &mut m.tables },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "coordinator_partition",
+            |m: &ActiveTransaction| { &m.coordinator_partition },
+            |m: &mut ActiveTransaction| { &mut m.coordinator_partition },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ActiveTransaction>(
+            "ActiveTransaction",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for InsertResponse {
-    const NAME: &'static str = "InsertResponse";
+impl ::protobuf::Message for ActiveTransaction {
+    const NAME: &'static str = "ActiveTransaction";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1090,6 +8355,18 @@ impl ::protobuf::Message for InsertResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                8 => {
+                    self.transaction_id = is.read_uint64()?;
+                },
+                16 => {
+                    self.age_millis = is.read_uint64()?;
+                },
+                26 => {
+                    self.tables.push(is.read_string()?);
+                },
+                32 => {
+                    self.coordinator_partition = ::std::option::Option::Some(is.read_uint32()?);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1102,12 +8379,36 @@ impl ::protobuf::Message for InsertResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if self.transaction_id != 0 {
+            my_size += ::protobuf::rt::uint64_size(1, self.transaction_id);
+        }
+        if self.age_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.age_millis);
+        }
+        for value in &self.tables {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let Some(v) = self.coordinator_partition {
+            my_size += ::protobuf::rt::uint32_size(4, v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.transaction_id != 0 {
+            os.write_uint64(1, self.transaction_id)?;
+        }
+        if self.age_millis != 0 {
+            os.write_uint64(2, self.age_millis)?;
+        }
+        for v in &self.tables {
+            os.write_string(3, &v)?;
+        };
+        if let Some(v) = self.coordinator_partition {
+            os.write_uint32(4, v)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1120,79 +8421,87 @@ impl ::protobuf::Message for InsertResponse {
         &mut self.special_fields
     }
 
-    fn new() -> InsertResponse {
-        InsertResponse::new()
+    fn new() -> ActiveTransaction {
+        ActiveTransaction::new()
     }
 
     fn clear(&mut self) {
+        self.transaction_id = 0;
+        self.age_millis = 0;
+        self.tables.clear();
+        self.coordinator_partition = ::std::option::Option::None;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static InsertResponse {
-        static instance: InsertResponse = InsertResponse {
+    fn default_instance() -> &'static ActiveTransaction {
+        static instance: ActiveTransaction = ActiveTransaction {
+            transaction_id: 0,
+            age_millis: 0,
+            tables: ::std::vec::Vec::new(),
+            coordinator_partition: ::std::option::Option::None,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for InsertResponse {
+impl ::protobuf::MessageFull for ActiveTransaction {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("InsertResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ActiveTransaction").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for InsertResponse {
+impl ::std::fmt::Display for ActiveTransaction {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for InsertResponse {
+impl ::protobuf::reflect::ProtobufValue for ActiveTransaction {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:DeleteResponse)
+// @@protoc_insertion_point(message:ListTransactionsResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DeleteResponse {
+pub struct ListTransactionsResponse {
     // message fields
-    // @@protoc_insertion_point(field:DeleteResponse.okay)
-    pub okay: bool,
+    // @@protoc_insertion_point(field:ListTransactionsResponse.transactions)
+    pub transactions: ::std::vec::Vec<ActiveTransaction>,
     // special fields
-    // @@protoc_insertion_point(special_field:DeleteResponse.special_fields)
+    // @@protoc_insertion_point(special_field:ListTransactionsResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteResponse {
-    fn default() -> &'a DeleteResponse {
-        <DeleteResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ListTransactionsResponse {
+    fn default() -> &'a ListTransactionsResponse {
+        <ListTransactionsResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteResponse {
-    pub fn new() -> DeleteResponse {
+impl ListTransactionsResponse {
+    pub fn new() -> ListTransactionsResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::with_capacity(1);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "okay",
-            |m: &DeleteResponse| { &m.okay },
-            |m: &mut DeleteResponse| { &mut m.okay },
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "transactions",
+            |m: &ListTransactionsResponse| { &m.transactions },
+            |m: &mut ListTransactionsResponse| { &mut m.transactions },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DeleteResponse>(
-            "DeleteResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ListTransactionsResponse>(
+            "ListTransactionsResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DeleteResponse {
-    const NAME: &'static str = "DeleteResponse";
+impl ::protobuf::Message for ListTransactionsResponse {
+    const NAME: &'static str = "ListTransactionsResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1201,8 +8510,8 @@ impl ::protobuf::Message for DeleteResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.okay = is.read_bool()?;
+                10 => {
+                    self.transactions.push(is.read_message()?);
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1216,18 +8525,19 @@ impl ::protobuf::Message for DeleteResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.okay != false {
-            my_size += 1 + 1;
-        }
+        for value in &self.transactions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.okay != false {
-            os.write_bool(1, self.okay)?;
-        }
+        for v in &self.transactions {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1240,81 +8550,73 @@ impl ::protobuf::Message for DeleteResponse {
         &mut self.special_fields
     }
 
-    fn new() -> DeleteResponse {
-        DeleteResponse::new()
+    fn new() -> ListTransactionsResponse {
+        ListTransactionsResponse::new()
     }
 
     fn clear(&mut self) {
-        self.okay = false;
+        self.transactions.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DeleteResponse {
-        static instance: DeleteResponse = DeleteResponse {
-            okay: false,
+    fn default_instance() -> &'static ListTransactionsResponse {
+        static instance: ListTransactionsResponse = ListTransactionsResponse {
+            transactions: ::std::vec::Vec::new(),
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for DeleteResponse {
+impl ::protobuf::MessageFull for ListTransactionsResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DeleteResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ListTransactionsResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DeleteResponse {
+impl ::std::fmt::Display for ListTransactionsResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteResponse {
+impl ::protobuf::reflect::ProtobufValue for ListTransactionsResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:GetManyResponse)
+// @@protoc_insertion_point(message:SessionResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct GetManyResponse {
-    // message fields
-    // @@protoc_insertion_point(field:GetManyResponse.items)
-    pub items: ::std::vec::Vec<GetResponse>,
+pub struct SessionResponse {
     // special fields
-    // @@protoc_insertion_point(special_field:GetManyResponse.special_fields)
+    // @@protoc_insertion_point(special_field:SessionResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a GetManyResponse {
-    fn default() -> &'a GetManyResponse {
-        <GetManyResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SessionResponse {
+    fn default() -> &'a SessionResponse {
+        <SessionResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl GetManyResponse {
-    pub fn new() -> GetManyResponse {
+impl SessionResponse {
+    pub fn new() -> SessionResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
-            "items",
-            |m: &GetManyResponse| { &m.items },
-            |m: &mut GetManyResponse| { &mut m.items },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GetManyResponse>(
-            "GetManyResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SessionResponse>(
+            "SessionResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for GetManyResponse {
-    const NAME: &'static str = "GetManyResponse";
+impl ::protobuf::Message for SessionResponse {
+    const NAME: &'static str = "SessionResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1323,9 +8625,6 @@ impl ::protobuf::Message for GetManyResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                10 => {
-                    self.items.push(is.read_message()?);
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1338,19 +8637,12 @@ impl ::protobuf::Message for GetManyResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        for value in &self.items {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        for v in &self.items {
-            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
-        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1363,81 +8655,71 @@ impl ::protobuf::Message for GetManyResponse {
         &mut self.special_fields
     }
 
-    fn new() -> GetManyResponse {
-        GetManyResponse::new()
+    fn new() -> SessionResponse {
+        SessionResponse::new()
     }
 
     fn clear(&mut self) {
-        self.items.clear();
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static GetManyResponse {
-        static instance: GetManyResponse = GetManyResponse {
-            items: ::std::vec::Vec::new(),
+    fn default_instance() -> &'static SessionResponse {
+        static instance: SessionResponse = SessionResponse {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for GetManyResponse {
+impl ::protobuf::MessageFull for SessionResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("GetManyResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SessionResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for GetManyResponse {
+impl ::std::fmt::Display for SessionResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for GetManyResponse {
+impl ::protobuf::reflect::ProtobufValue for SessionResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:BatchResponse)
+// @@protoc_insertion_point(message:SetReadOnlyResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct BatchResponse {
-    // message fields
-    // @@protoc_insertion_point(field:BatchResponse.okay)
-    pub okay: bool,
+pub struct SetReadOnlyResponse {
     // special fields
-    // @@protoc_insertion_point(special_field:BatchResponse.special_fields)
+    // @@protoc_insertion_point(special_field:SetReadOnlyResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a BatchResponse {
-    fn default() -> &'a BatchResponse {
-        <BatchResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SetReadOnlyResponse {
+    fn default() -> &'a SetReadOnlyResponse {
+        <SetReadOnlyResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl BatchResponse {
-    pub fn new() -> BatchResponse {
+impl SetReadOnlyResponse {
+    pub fn new() -> SetReadOnlyResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(0);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "okay",
-            |m: &BatchResponse| { &m.okay },
-            |m: &mut BatchResponse| { &mut m.okay },
-        ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<BatchResponse>(
-            "BatchResponse",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetReadOnlyResponse>(
+            "SetReadOnlyResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for BatchResponse {
-    const NAME: &'static str = "BatchResponse";
+impl ::protobuf::Message for SetReadOnlyResponse {
+    const NAME: &'static str = "SetReadOnlyResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1446,9 +8728,6 @@ impl ::protobuf::Message for BatchResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
-                8 => {
-                    self.okay = is.read_bool()?;
-                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1461,18 +8740,12 @@ impl ::protobuf::Message for BatchResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.okay != false {
-            my_size += 1 + 1;
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.okay != false {
-            os.write_bool(1, self.okay)?;
-        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1485,88 +8758,93 @@ impl ::protobuf::Message for BatchResponse {
         &mut self.special_fields
     }
 
-    fn new() -> BatchResponse {
-        BatchResponse::new()
+    fn new() -> SetReadOnlyResponse {
+        SetReadOnlyResponse::new()
     }
 
     fn clear(&mut self) {
-        self.okay = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static BatchResponse {
-        static instance: BatchResponse = BatchResponse {
-            okay: false,
+    fn default_instance() -> &'static SetReadOnlyResponse {
+        static instance: SetReadOnlyResponse = SetReadOnlyResponse {
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for BatchResponse {
+impl ::protobuf::MessageFull for SetReadOnlyResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("BatchResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("SetReadOnlyResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for BatchResponse {
+impl ::std::fmt::Display for SetReadOnlyResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BatchResponse {
+impl ::protobuf::reflect::ProtobufValue for SetReadOnlyResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:TransactionResponse)
+// @@protoc_insertion_point(message:CompactionBucket)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct TransactionResponse {
+pub struct CompactionBucket {
     // message fields
-    // @@protoc_insertion_point(field:TransactionResponse.okay)
-    pub okay: bool,
-    // @@protoc_insertion_point(field:TransactionResponse.transaction_id)
-    pub transaction_id: u64,
+    // @@protoc_insertion_point(field:CompactionBucket.sstables)
+    pub sstables: u32,
+    // @@protoc_insertion_point(field:CompactionBucket.bytes)
+    pub bytes: u64,
+    // @@protoc_insertion_point(field:CompactionBucket.pending)
+    pub pending: bool,
     // special fields
-    // @@protoc_insertion_point(special_field:TransactionResponse.special_fields)
+    // @@protoc_insertion_point(special_field:CompactionBucket.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a TransactionResponse {
-    fn default() -> &'a TransactionResponse {
-        <TransactionResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CompactionBucket {
+    fn default() -> &'a CompactionBucket {
+        <CompactionBucket as ::protobuf::Message>::default_instance()
     }
 }
 
-impl TransactionResponse {
-    pub fn new() -> TransactionResponse {
+impl CompactionBucket {
+    pub fn new() -> CompactionBucket {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut fields = ::std::vec::Vec::with_capacity(3);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "okay",
-            |m: &TransactionResponse| { &m.okay },
-            |m: &mut TransactionResponse| { &mut m.okay },
+            "sstables",
+            |m: &CompactionBucket| { &m.sstables },
+            |m: &mut CompactionBucket| { &mut m.sstables },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "transaction_id",
-            |m: &TransactionResponse| { &m.transaction_id },
-            |m: &mut TransactionResponse| { &mut m.transaction_id },
+            "bytes",
+            |m: &CompactionBucket| { &m.bytes },
+            |m: &mut CompactionBucket| { &mut m.bytes },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TransactionResponse>(
-            "TransactionResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pending",
+            |m: &CompactionBucket| { &m.pending },
+            |m: &mut CompactionBucket| { &mut m.pending },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CompactionBucket>(
+            "CompactionBucket",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for TransactionResponse {
-    const NAME: &'static str = "TransactionResponse";
+impl ::protobuf::Message for CompactionBucket {
+    const NAME: &'static str = "CompactionBucket";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1576,10 +8854,13 @@ impl ::protobuf::Message for TransactionResponse {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
                 8 => {
-                    self.okay = is.read_bool()?;
+                    self.sstables = is.read_uint32()?;
                 },
                 16 => {
-                    self.transaction_id = is.read_uint64()?;
+                    self.bytes = is.read_uint64()?;
+                },
+                24 => {
+                    self.pending = is.read_bool()?;
                 },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
@@ -1593,11 +8874,14 @@ impl ::protobuf::Message for TransactionResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
-        if self.okay != false {
-            my_size += 1 + 1;
+        if self.sstables != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.sstables);
         }
-        if self.transaction_id != 0 {
-            my_size += ::protobuf::rt::uint64_size(2, self.transaction_id);
+        if self.bytes != 0 {
+            my_size += ::protobuf::rt::uint64_size(2, self.bytes);
+        }
+        if self.pending != false {
+            my_size += 1 + 1;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
@@ -1605,11 +8889,14 @@ impl ::protobuf::Message for TransactionResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
-        if self.okay != false {
-            os.write_bool(1, self.okay)?;
+        if self.sstables != 0 {
+            os.write_uint32(1, self.sstables)?;
         }
-        if self.transaction_id != 0 {
-            os.write_uint64(2, self.transaction_id)?;
+        if self.bytes != 0 {
+            os.write_uint64(2, self.bytes)?;
+        }
+        if self.pending != false {
+            os.write_bool(3, self.pending)?;
         }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1623,75 +8910,113 @@ impl ::protobuf::Message for TransactionResponse {
         &mut self.special_fields
     }
 
-    fn new() -> TransactionResponse {
-        TransactionResponse::new()
+    fn new() -> CompactionBucket {
+        CompactionBucket::new()
     }
 
     fn clear(&mut self) {
-        self.okay = false;
-        self.transaction_id = 0;
+        self.sstables = 0;
+        self.bytes = 0;
+        self.pending = false;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static TransactionResponse {
-        static instance: TransactionResponse = TransactionResponse {
-            okay: false,
-            transaction_id: 0,
+    fn default_instance() -> &'static CompactionBucket {
+        static instance: CompactionBucket = CompactionBucket {
+            sstables: 0,
+            bytes: 0,
+            pending: false,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for TransactionResponse {
+impl ::protobuf::MessageFull for CompactionBucket {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("TransactionResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CompactionBucket").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for TransactionResponse {
+impl ::std::fmt::Display for CompactionBucket {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for TransactionResponse {
+impl ::protobuf::reflect::ProtobufValue for CompactionBucket {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:SyncModelResponse)
+// @@protoc_insertion_point(message:TableCompaction)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct SyncModelResponse {
+pub struct TableCompaction {
+    // message fields
+    // @@protoc_insertion_point(field:TableCompaction.table)
+    pub table: ::std::string::String,
+    // @@protoc_insertion_point(field:TableCompaction.buckets)
+    pub buckets: ::std::vec::Vec<CompactionBucket>,
+    // @@protoc_insertion_point(field:TableCompaction.pending_sstables)
+    pub pending_sstables: u32,
+    // @@protoc_insertion_point(field:TableCompaction.last_duration_millis)
+    pub last_duration_millis: ::std::option::Option<u64>,
+    // @@protoc_insertion_point(field:TableCompaction.priority)
+    pub priority: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:SyncModelResponse.special_fields)
+    // @@protoc_insertion_point(special_field:TableCompaction.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a SyncModelResponse {
-    fn default() -> &'a SyncModelResponse {
-        <SyncModelResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TableCompaction {
+    fn default() -> &'a TableCompaction {
+        <TableCompaction as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SyncModelResponse {
-    pub fn new() -> SyncModelResponse {
+impl TableCompaction {
+    pub fn new() -> TableCompaction {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SyncModelResponse>(
-            "SyncModelResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "table",
+            |m: &TableCompaction| { &m.table },
+            |m: &mut TableCompaction| { &mut m.table },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "buckets",
+            |m: &TableCompaction| { &m.buckets },
+            |m: &mut TableCompaction| { &mut m.buckets },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "pending_sstables",
+            |m: &TableCompaction| { &m.pending_sstables },
+            |m: &mut TableCompaction| { &mut m.pending_sstables },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "last_duration_millis",
+            |m: &TableCompaction| { &m.last_duration_millis },
+            |m: &mut TableCompaction| { &mut m.last_duration_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "priority",
+            |m: &TableCompaction| { &m.priority },
+            |m: &mut TableCompaction| { &mut m.priority },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TableCompaction>(
+            "TableCompaction",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for SyncModelResponse {
-    const NAME: &'static str = "SyncModelResponse";
+impl ::protobuf::Message for TableCompaction {
+    const NAME: &'static str = "TableCompaction";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1700,6 +9025,21 @@ impl ::protobuf::Message for SyncModelResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                10 => {
+                    self.table = is.read_string()?;
+                },
+                18 => {
+                    self.buckets.push(is.read_message()?);
+                },
+                24 => {
+                    self.pending_sstables = is.read_uint32()?;
+                },
+                32 => {
+                    self.last_duration_millis = ::std::option::Option::Some(is.read_uint64()?);
+                },
+                40 => {
+                    self.priority = is.read_uint64()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1712,12 +9052,43 @@ impl ::protobuf::Message for SyncModelResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if !self.table.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.table);
+        }
+        for value in &self.buckets {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.pending_sstables != 0 {
+            my_size += ::protobuf::rt::uint32_size(3, self.pending_sstables);
+        }
+        if let Some(v) = self.last_duration_millis {
+            my_size += ::protobuf::rt::uint64_size(4, v);
+        }
+        if self.priority != 0 {
+            my_size += ::protobuf::rt::uint64_size(5, self.priority);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.table.is_empty() {
+            os.write_string(1, &self.table)?;
+        }
+        for v in &self.buckets {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        if self.pending_sstables != 0 {
+            os.write_uint32(3, self.pending_sstables)?;
+        }
+        if let Some(v) = self.last_duration_millis {
+            os.write_uint64(4, v)?;
+        }
+        if self.priority != 0 {
+            os.write_uint64(5, self.priority)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1730,71 +9101,131 @@ impl ::protobuf::Message for SyncModelResponse {
         &mut self.special_fields
     }
 
-    fn new() -> SyncModelResponse {
-        SyncModelResponse::new()
+    fn new() -> TableCompaction {
+        TableCompaction::new()
     }
 
     fn clear(&mut self) {
+        self.table.clear();
+        self.buckets.clear();
+        self.pending_sstables = 0;
+        self.last_duration_millis = ::std::option::Option::None;
+        self.priority = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static SyncModelResponse {
-        static instance: SyncModelResponse = SyncModelResponse {
+    fn default_instance() -> &'static TableCompaction {
+        static instance: TableCompaction = TableCompaction {
+            table: ::std::string::String::new(),
+            buckets: ::std::vec::Vec::new(),
+            pending_sstables: 0,
+            last_duration_millis: ::std::option::Option::None,
+            priority: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for SyncModelResponse {
+impl ::protobuf::MessageFull for TableCompaction {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("SyncModelResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TableCompaction").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for SyncModelResponse {
+impl ::std::fmt::Display for TableCompaction {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SyncModelResponse {
+impl ::protobuf::reflect::ProtobufValue for TableCompaction {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
-// @@protoc_insertion_point(message:DropTableResponse)
+// @@protoc_insertion_point(message:CompactionResponse)
 #[derive(PartialEq,Clone,Default,Debug)]
-pub struct DropTableResponse {
+pub struct CompactionResponse {
+    // message fields
+    // @@protoc_insertion_point(field:CompactionResponse.bucket_low)
+    pub bucket_low: f64,
+    // @@protoc_insertion_point(field:CompactionResponse.bucket_high)
+    pub bucket_high: f64,
+    // @@protoc_insertion_point(field:CompactionResponse.sstable_min_size)
+    pub sstable_min_size: u64,
+    // @@protoc_insertion_point(field:CompactionResponse.interval_millis)
+    pub interval_millis: u64,
+    // @@protoc_insertion_point(field:CompactionResponse.tables)
+    pub tables: ::std::vec::Vec<TableCompaction>,
+    // @@protoc_insertion_point(field:CompactionResponse.max_concurrent_compactions)
+    pub max_concurrent_compactions: u32,
+    // @@protoc_insertion_point(field:CompactionResponse.io_rate_limit_mb)
+    pub io_rate_limit_mb: u64,
     // special fields
-    // @@protoc_insertion_point(special_field:DropTableResponse.special_fields)
+    // @@protoc_insertion_point(special_field:CompactionResponse.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
 }
 
-impl<'a> ::std::default::Default for &'a DropTableResponse {
-    fn default() -> &'a DropTableResponse {
-        <DropTableResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CompactionResponse {
+    fn default() -> &'a CompactionResponse {
+        <CompactionResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DropTableResponse {
-    pub fn new() -> DropTableResponse {
+impl CompactionResponse {
+    pub fn new() -> CompactionResponse {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(0);
+        let mut fields = ::std::vec::Vec::with_capacity(7);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DropTableResponse>(
-            "DropTableResponse",
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bucket_low",
+            |m: &CompactionResponse| { &m.bucket_low },
+            |m: &mut CompactionResponse| { &mut m.bucket_low },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "bucket_high",
+            |m: &CompactionResponse| { &m.bucket_high },
+            |m: &mut CompactionResponse| { &mut m.bucket_high },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sstable_min_size",
+            |m: &CompactionResponse| { &m.sstable_min_size },
+            |m: &mut CompactionResponse| { &mut m.sstable_min_size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "interval_millis",
+            |m: &CompactionResponse| { &m.interval_millis },
+            |m: &mut CompactionResponse| { &mut m.interval_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "tables",
+            |m: &CompactionResponse| { &m.tables },
+            |m: &mut CompactionResponse| { &mut m.tables },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "max_concurrent_compactions",
+            |m: &CompactionResponse| { &m.max_concurrent_compactions },
+            |m: &mut CompactionResponse| { &mut m.max_concurrent_compactions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "io_rate_limit_mb",
+            |m: &CompactionResponse| { &m.io_rate_limit_mb },
+            |m: &mut CompactionResponse| { &mut m.io_rate_limit_mb },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<CompactionResponse>(
+            "CompactionResponse",
             fields,
             oneofs,
         )
     }
 }
 
-impl ::protobuf::Message for DropTableResponse {
-    const NAME: &'static str = "DropTableResponse";
+impl ::protobuf::Message for CompactionResponse {
+    const NAME: &'static str = "CompactionResponse";
 
     fn is_initialized(&self) -> bool {
         true
@@ -1803,6 +9234,27 @@ impl ::protobuf::Message for DropTableResponse {
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
         while let Some(tag) = is.read_raw_tag_or_eof()? {
             match tag {
+                9 => {
+                    self.bucket_low = is.read_double()?;
+                },
+                17 => {
+                    self.bucket_high = is.read_double()?;
+                },
+                24 => {
+                    self.sstable_min_size = is.read_uint64()?;
+                },
+                32 => {
+                    self.interval_millis = is.read_uint64()?;
+                },
+                42 => {
+                    self.tables.push(is.read_message()?);
+                },
+                48 => {
+                    self.max_concurrent_compactions = is.read_uint32()?;
+                },
+                56 => {
+                    self.io_rate_limit_mb = is.read_uint64()?;
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1815,12 +9267,55 @@ impl ::protobuf::Message for DropTableResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u64 {
         let mut my_size = 0;
+        if self.bucket_low != 0. {
+            my_size += 1 + 8;
+        }
+        if self.bucket_high != 0. {
+            my_size += 1 + 8;
+        }
+        if self.sstable_min_size != 0 {
+            my_size += ::protobuf::rt::uint64_size(3, self.sstable_min_size);
+        }
+        if self.interval_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.interval_millis);
+        }
+        for value in &self.tables {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        };
+        if self.max_concurrent_compactions != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.max_concurrent_compactions);
+        }
+        if self.io_rate_limit_mb != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.io_rate_limit_mb);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.bucket_low != 0. {
+            os.write_double(1, self.bucket_low)?;
+        }
+        if self.bucket_high != 0. {
+            os.write_double(2, self.bucket_high)?;
+        }
+        if self.sstable_min_size != 0 {
+            os.write_uint64(3, self.sstable_min_size)?;
+        }
+        if self.interval_millis != 0 {
+            os.write_uint64(4, self.interval_millis)?;
+        }
+        for v in &self.tables {
+            ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+        };
+        if self.max_concurrent_compactions != 0 {
+            os.write_uint32(6, self.max_concurrent_compactions)?;
+        }
+        if self.io_rate_limit_mb != 0 {
+            os.write_uint64(7, self.io_rate_limit_mb)?;
+        }
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1833,36 +9328,50 @@ impl ::protobuf::Message for DropTableResponse {
         &mut self.special_fields
     }
 
-    fn new() -> DropTableResponse {
-        DropTableResponse::new()
+    fn new() -> CompactionResponse {
+        CompactionResponse::new()
     }
 
     fn clear(&mut self) {
+        self.bucket_low = 0.;
+        self.bucket_high = 0.;
+        self.sstable_min_size = 0;
+        self.interval_millis = 0;
+        self.tables.clear();
+        self.max_concurrent_compactions = 0;
+        self.io_rate_limit_mb = 0;
         self.special_fields.clear();
     }
 
-    fn default_instance() -> &'static DropTableResponse {
-        static instance: DropTableResponse = DropTableResponse {
+    fn default_instance() -> &'static CompactionResponse {
+        static instance: CompactionResponse = CompactionResponse {
+            bucket_low: 0.,
+            bucket_high: 0.,
+            sstable_min_size: 0,
+            interval_millis: 0,
+            tables: ::std::vec::Vec::new(),
+            max_concurrent_compactions: 0,
+            io_rate_limit_mb: 0,
             special_fields: ::protobuf::SpecialFields::new(),
         };
         &instance
     }
 }
 
-impl ::protobuf::MessageFull for DropTableResponse {
+impl ::protobuf::MessageFull for CompactionResponse {
     fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
-        descriptor.get(|| file_descriptor().message_by_package_relative_name("DropTableResponse").unwrap()).clone()
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("CompactionResponse").unwrap()).clone()
     }
 }
 
-impl ::std::fmt::Display for DropTableResponse {
+impl ::std::fmt::Display for CompactionResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DropTableResponse {
+impl ::protobuf::reflect::ProtobufValue for CompactionResponse {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
@@ -1872,6 +9381,14 @@ pub struct ClientError {
     // message fields
     // @@protoc_insertion_point(field:ClientError.detail)
     pub detail: ::std::string::String,
+    // @@protoc_insertion_point(field:ClientError.code)
+    pub code: ::protobuf::EnumOrUnknown<ErrorCode>,
+    // @@protoc_insertion_point(field:ClientError.retryable)
+    pub retryable: bool,
+    // @@protoc_insertion_point(field:ClientError.retry_after_millis)
+    pub retry_after_millis: u64,
+    // @@protoc_insertion_point(field:ClientError.metadata)
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:ClientError.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -1889,13 +9406,33 @@ impl ClientError {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "detail",
             |m: &ClientError| { &m.detail },
             |m: &mut ClientError| { &mut m.detail },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &ClientError| { &m.code },
+            |m: &mut ClientError| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "retryable",
+            |m: &ClientError| { &m.retryable },
+            |m: &mut ClientError| { &mut m.retryable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "retry_after_millis",
+            |m: &ClientError| { &m.retry_after_millis },
+            |m: &mut ClientError| { &mut m.retry_after_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "metadata",
+            |m: &ClientError| { &m.metadata },
+            |m: &mut ClientError| { &mut m.metadata },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ClientError>(
             "ClientError",
             fields,
@@ -1917,6 +9454,30 @@ impl ::protobuf::Message for ClientError {
                 10 => {
                     self.detail = is.read_string()?;
                 },
+                16 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.retryable = is.read_bool()?;
+                },
+                32 => {
+                    self.retry_after_millis = is.read_uint64()?;
+                },
+                42 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.metadata.insert(key, value);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -1932,6 +9493,21 @@ impl ::protobuf::Message for ClientError {
         if !self.detail.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.detail);
         }
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(2, self.code.value());
+        }
+        if self.retryable != false {
+            my_size += 1 + 1;
+        }
+        if self.retry_after_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.retry_after_millis);
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -1941,6 +9517,24 @@ impl ::protobuf::Message for ClientError {
         if !self.detail.is_empty() {
             os.write_string(1, &self.detail)?;
         }
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::UNKNOWN) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if self.retryable != false {
+            os.write_bool(3, self.retryable)?;
+        }
+        if self.retry_after_millis != 0 {
+            os.write_uint64(4, self.retry_after_millis)?;
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(42)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1959,15 +9553,16 @@ impl ::protobuf::Message for ClientError {
 
     fn clear(&mut self) {
         self.detail.clear();
+        self.code = ::protobuf::EnumOrUnknown::new(ErrorCode::UNKNOWN);
+        self.retryable = false;
+        self.retry_after_millis = 0;
+        self.metadata.clear();
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static ClientError {
-        static instance: ClientError = ClientError {
-            detail: ::std::string::String::new(),
-            special_fields: ::protobuf::SpecialFields::new(),
-        };
-        &instance
+        static instance: ::protobuf::rt::Lazy<ClientError> = ::protobuf::rt::Lazy::new();
+        instance.get(ClientError::new)
     }
 }
 
@@ -1994,6 +9589,14 @@ pub struct ServerError {
     // message fields
     // @@protoc_insertion_point(field:ServerError.detail)
     pub detail: ::std::string::String,
+    // @@protoc_insertion_point(field:ServerError.code)
+    pub code: ::protobuf::EnumOrUnknown<ErrorCode>,
+    // @@protoc_insertion_point(field:ServerError.retryable)
+    pub retryable: bool,
+    // @@protoc_insertion_point(field:ServerError.retry_after_millis)
+    pub retry_after_millis: u64,
+    // @@protoc_insertion_point(field:ServerError.metadata)
+    pub metadata: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
     // special fields
     // @@protoc_insertion_point(special_field:ServerError.special_fields)
     pub special_fields: ::protobuf::SpecialFields,
@@ -2011,13 +9614,33 @@ impl ServerError {
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
-        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut fields = ::std::vec::Vec::with_capacity(5);
         let mut oneofs = ::std::vec::Vec::with_capacity(0);
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
             "detail",
             |m: &ServerError| { &m.detail },
             |m: &mut ServerError| { &mut m.detail },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &ServerError| { &m.code },
+            |m: &mut ServerError| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "retryable",
+            |m: &ServerError| { &m.retryable },
+            |m: &mut ServerError| { &mut m.retryable },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "retry_after_millis",
+            |m: &ServerError| { &m.retry_after_millis },
+            |m: &mut ServerError| { &mut m.retry_after_millis },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_map_simpler_accessor::<_, _, _>(
+            "metadata",
+            |m: &ServerError| { &m.metadata },
+            |m: &mut ServerError| { &mut m.metadata },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ServerError>(
             "ServerError",
             fields,
@@ -2039,6 +9662,30 @@ impl ::protobuf::Message for ServerError {
                 10 => {
                     self.detail = is.read_string()?;
                 },
+                16 => {
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                24 => {
+                    self.retryable = is.read_bool()?;
+                },
+                32 => {
+                    self.retry_after_millis = is.read_uint64()?;
+                },
+                42 => {
+                    let len = is.read_raw_varint32()?;
+                    let old_limit = is.push_limit(len as u64)?;
+                    let mut key = ::std::default::Default::default();
+                    let mut value = ::std::default::Default::default();
+                    while let Some(tag) = is.read_raw_tag_or_eof()? {
+                        match tag {
+                            10 => key = is.read_string()?,
+                            18 => value = is.read_string()?,
+                            _ => ::protobuf::rt::skip_field_for_tag(tag, is)?,
+                        };
+                    }
+                    is.pop_limit(old_limit);
+                    self.metadata.insert(key, value);
+                },
                 tag => {
                     ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
                 },
@@ -2054,6 +9701,21 @@ impl ::protobuf::Message for ServerError {
         if !self.detail.is_empty() {
             my_size += ::protobuf::rt::string_size(1, &self.detail);
         }
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::UNKNOWN) {
+            my_size += ::protobuf::rt::int32_size(2, self.code.value());
+        }
+        if self.retryable != false {
+            my_size += 1 + 1;
+        }
+        if self.retry_after_millis != 0 {
+            my_size += ::protobuf::rt::uint64_size(4, self.retry_after_millis);
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(entry_size) + entry_size
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
         self.special_fields.cached_size().set(my_size as u32);
         my_size
@@ -2063,6 +9725,24 @@ impl ::protobuf::Message for ServerError {
         if !self.detail.is_empty() {
             os.write_string(1, &self.detail)?;
         }
+        if self.code != ::protobuf::EnumOrUnknown::new(ErrorCode::UNKNOWN) {
+            os.write_enum(2, ::protobuf::EnumOrUnknown::value(&self.code))?;
+        }
+        if self.retryable != false {
+            os.write_bool(3, self.retryable)?;
+        }
+        if self.retry_after_millis != 0 {
+            os.write_uint64(4, self.retry_after_millis)?;
+        }
+        for (k, v) in &self.metadata {
+            let mut entry_size = 0;
+            entry_size += ::protobuf::rt::string_size(1, &k);
+            entry_size += ::protobuf::rt::string_size(2, &v);
+            os.write_raw_varint32(42)?; // Tag.
+            os.write_raw_varint32(entry_size as u32)?;
+            os.write_string(1, &k)?;
+            os.write_string(2, &v)?;
+        };
         os.write_unknown_fields(self.special_fields.unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2081,15 +9761,16 @@ impl ::protobuf::Message for ServerError {
 
     fn clear(&mut self) {
         self.detail.clear();
+        self.code = ::protobuf::EnumOrUnknown::new(ErrorCode::UNKNOWN);
+        self.retryable = false;
+        self.retry_after_millis = 0;
+        self.metadata.clear();
         self.special_fields.clear();
     }
 
     fn default_instance() -> &'static ServerError {
-        static instance: ServerError = ServerError {
-            detail: ::std::string::String::new(),
-            special_fields: ::protobuf::SpecialFields::new(),
-        };
-        &instance
+        static instance: ::protobuf::rt::Lazy<ServerError> = ::protobuf::rt::Lazy::new();
+        instance.get(ServerError::new)
     }
 }
 
@@ -2110,8 +9791,130 @@ impl ::protobuf::reflect::ProtobufValue for ServerError {
     type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
 }
 
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:ErrorCode)
+pub enum ErrorCode {
+    // @@protoc_insertion_point(enum_value:ErrorCode.UNKNOWN)
+    UNKNOWN = 0,
+    // @@protoc_insertion_point(enum_value:ErrorCode.INVALID_REQUEST)
+    INVALID_REQUEST = 1,
+    // @@protoc_insertion_point(enum_value:ErrorCode.NOT_FOUND)
+    NOT_FOUND = 2,
+    // @@protoc_insertion_point(enum_value:ErrorCode.CONFLICT)
+    CONFLICT = 3,
+    // @@protoc_insertion_point(enum_value:ErrorCode.UNAVAILABLE)
+    UNAVAILABLE = 4,
+    // @@protoc_insertion_point(enum_value:ErrorCode.TIMEOUT)
+    TIMEOUT = 5,
+    // @@protoc_insertion_point(enum_value:ErrorCode.INTERNAL)
+    INTERNAL = 6,
+    // @@protoc_insertion_point(enum_value:ErrorCode.HASH_KEY_TOO_LONG)
+    HASH_KEY_TOO_LONG = 7,
+    // @@protoc_insertion_point(enum_value:ErrorCode.WRONG_PARTITION)
+    WRONG_PARTITION = 8,
+    // @@protoc_insertion_point(enum_value:ErrorCode.SCHEMA_MISMATCH)
+    SCHEMA_MISMATCH = 9,
+    // @@protoc_insertion_point(enum_value:ErrorCode.QUOTA_EXCEEDED)
+    QUOTA_EXCEEDED = 10,
+    // @@protoc_insertion_point(enum_value:ErrorCode.SCHEMA_VERSION_MISMATCH)
+    SCHEMA_VERSION_MISMATCH = 11,
+    // @@protoc_insertion_point(enum_value:ErrorCode.VERSION_CONFLICT)
+    VERSION_CONFLICT = 12,
+    // @@protoc_insertion_point(enum_value:ErrorCode.NOT_LEADER)
+    NOT_LEADER = 13,
+}
+
+impl ::protobuf::Enum for ErrorCode {
+    const NAME: &'static str = "ErrorCode";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ErrorCode> {
+        match value {
+            0 => ::std::option::Option::Some(ErrorCode::UNKNOWN),
+            1 => ::std::option::Option::Some(ErrorCode::INVALID_REQUEST),
+            2 => ::std::option::Option::Some(ErrorCode::NOT_FOUND),
+            3 => ::std::option::Option::Some(ErrorCode::CONFLICT),
+            4 => ::std::option::Option::Some(ErrorCode::UNAVAILABLE),
+            5 => ::std::option::Option::Some(ErrorCode::TIMEOUT),
+            6 => ::std::option::Option::Some(ErrorCode::INTERNAL),
+            7 => ::std::option::Option::Some(ErrorCode::HASH_KEY_TOO_LONG),
+            8 => ::std::option::Option::Some(ErrorCode::WRONG_PARTITION),
+            9 => ::std::option::Option::Some(ErrorCode::SCHEMA_MISMATCH),
+            10 => ::std::option::Option::Some(ErrorCode::QUOTA_EXCEEDED),
+            11 => ::std::option::Option::Some(ErrorCode::SCHEMA_VERSION_MISMATCH),
+            12 => ::std::option::Option::Some(ErrorCode::VERSION_CONFLICT),
+            13 => ::std::option::Option::Some(ErrorCode::NOT_LEADER),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<ErrorCode> {
+        match str {
+            "UNKNOWN" => ::std::option::Option::Some(ErrorCode::UNKNOWN),
+            "INVALID_REQUEST" => ::std::option::Option::Some(ErrorCode::INVALID_REQUEST),
+            "NOT_FOUND" => ::std::option::Option::Some(ErrorCode::NOT_FOUND),
+            "CONFLICT" => ::std::option::Option::Some(ErrorCode::CONFLICT),
+            "UNAVAILABLE" => ::std::option::Option::Some(ErrorCode::UNAVAILABLE),
+            "TIMEOUT" => ::std::option::Option::Some(ErrorCode::TIMEOUT),
+            "INTERNAL" => ::std::option::Option::Some(ErrorCode::INTERNAL),
+            "HASH_KEY_TOO_LONG" => ::std::option::Option::Some(ErrorCode::HASH_KEY_TOO_LONG),
+            "WRONG_PARTITION" => ::std::option::Option::Some(ErrorCode::WRONG_PARTITION),
+            "SCHEMA_MISMATCH" => ::std::option::Option::Some(ErrorCode::SCHEMA_MISMATCH),
+            "QUOTA_EXCEEDED" => ::std::option::Option::Some(ErrorCode::QUOTA_EXCEEDED),
+            "SCHEMA_VERSION_MISMATCH" => ::std::option::Option::Some(ErrorCode::SCHEMA_VERSION_MISMATCH),
+            "VERSION_CONFLICT" => ::std::option::Option::Some(ErrorCode::VERSION_CONFLICT),
+            "NOT_LEADER" => ::std::option::Option::Some(ErrorCode::NOT_LEADER),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [ErrorCode] = &[
+        ErrorCode::UNKNOWN,
+        ErrorCode::INVALID_REQUEST,
+        ErrorCode::NOT_FOUND,
+        ErrorCode::CONFLICT,
+        ErrorCode::UNAVAILABLE,
+        ErrorCode::TIMEOUT,
+        ErrorCode::INTERNAL,
+        ErrorCode::HASH_KEY_TOO_LONG,
+        ErrorCode::WRONG_PARTITION,
+        ErrorCode::SCHEMA_MISMATCH,
+        ErrorCode::QUOTA_EXCEEDED,
+        ErrorCode::SCHEMA_VERSION_MISMATCH,
+        ErrorCode::VERSION_CONFLICT,
+        ErrorCode::NOT_LEADER,
+    ];
+}
+
+impl ::protobuf::EnumFull for ErrorCode {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("ErrorCode").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for ErrorCode {
+    fn default() -> Self {
+        ErrorCode::UNKNOWN
+    }
+}
+
+impl ErrorCode {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<ErrorCode>("ErrorCode")
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0eresponse.proto\x1a\x0ccommon.proto\"\xe2\x03\n\x08Response\x12\x20\
+    \n\x0eresponse.proto\x1a\x0ccommon.proto\"\xf8\x0e\n\x08Response\x12\x20\
     \n\x03get\x18\x01\x20\x01(\x0b2\x0c.GetResponseH\0R\x03get\x12)\n\x06ins\
     ert\x18\x02\x20\x01(\x0b2\x0f.InsertResponseH\0R\x06insert\x12)\n\x06del\
     ete\x18\x03\x20\x01(\x0b2\x0f.DeleteResponseH\0R\x06delete\x12-\n\x08get\
@@ -2122,21 +9925,186 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x05model\x123\n\ndrop_table\x18\x08\x20\x01(\x0b2\x12.DropTableResponse\
     H\0R\tdropTable\x121\n\x0cclient_error\x18\t\x20\x01(\x0b2\x0c.ClientErr\
     orH\0R\x0bclientError\x121\n\x0cserver_error\x18\n\x20\x01(\x0b2\x0c.Ser\
-    verErrorH\0R\x0bserverErrorB\x06\n\x04data\"\xd6\x01\n\x0bGetResponse\
-    \x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\x07hashKey\x12!\n\x08sort_key\
-    \x18\x02\x20\x01(\x0b2\x06.ValueR\x07sortKey\x120\n\x06values\x18\x03\
-    \x20\x03(\x0b2\x18.GetResponse.ValuesEntryR\x06values\x12\x14\n\x05table\
-    \x18\x04\x20\x01(\tR\x05table\x1aA\n\x0bValuesEntry\x12\x10\n\x03key\x18\
-    \x01\x20\x01(\tR\x03key\x12\x1c\n\x05value\x18\x02\x20\x01(\x0b2\x06.Val\
-    ueR\x05value:\x028\x01\"\x10\n\x0eInsertResponse\"$\n\x0eDeleteResponse\
-    \x12\x12\n\x04okay\x18\x01\x20\x01(\x08R\x04okay\"5\n\x0fGetManyResponse\
-    \x12\"\n\x05items\x18\x01\x20\x03(\x0b2\x0c.GetResponseR\x05items\"#\n\r\
-    BatchResponse\x12\x12\n\x04okay\x18\x01\x20\x01(\x08R\x04okay\"P\n\x13Tr\
-    ansactionResponse\x12\x12\n\x04okay\x18\x01\x20\x01(\x08R\x04okay\x12%\n\
-    \x0etransaction_id\x18\x02\x20\x01(\x04R\rtransactionId\"\x13\n\x11SyncM\
-    odelResponse\"\x13\n\x11DropTableResponse\"%\n\x0bClientError\x12\x16\n\
-    \x06detail\x18\x01\x20\x01(\tR\x06detail\"%\n\x0bServerError\x12\x16\n\
-    \x06detail\x18\x01\x20\x01(\tR\x06detailb\x06proto3\
+    verErrorH\0R\x0bserverError\x12?\n\x0eplan_migration\x18\x0b\x20\x01(\
+    \x0b2\x16.PlanMigrationResponseH\0R\rplanMigration\x12/\n\x08sequence\
+    \x18\x0c\x20\x01(\x0b2\x11.SequenceResponseH\0R\x08sequence\x12&\n\x05le\
+    ase\x18\r\x20\x01(\x0b2\x0e.LeaseResponseH\0R\x05lease\x120\n\trow_count\
+    \x18\x0e\x20\x01(\x0b2\x11.RowCountResponseH\0R\x08rowCount\x12?\n\x0ede\
+    scribe_table\x18\x0f\x20\x01(\x0b2\x16.DescribeTableResponseH\0R\rdescri\
+    beTable\x12)\n\x06health\x18\x10\x20\x01(\x0b2\x0f.HealthResponseH\0R\
+    \x06health\x12&\n\x05ready\x18\x11\x20\x01(\x0b2\x0e.ReadyResponseH\0R\
+    \x05ready\x12<\n\rpartition_map\x18\x12\x20\x01(\x0b2\x15.PartitionMapRe\
+    sponseH\0R\x0cpartitionMap\x12E\n\x10key_distribution\x18\x13\x20\x01(\
+    \x0b2\x18.KeyDistributionResponseH\0R\x0fkeyDistribution\x12H\n\x11list_\
+    transactions\x18\x14\x20\x01(\x0b2\x19.ListTransactionsResponseH\0R\x10l\
+    istTransactions\x12,\n\x07session\x18\x15\x20\x01(\x0b2\x10.SessionRespo\
+    nseH\0R\x07session\x12:\n\rset_read_only\x18\x16\x20\x01(\x0b2\x14.SetRe\
+    adOnlyResponseH\0R\x0bsetReadOnly\x125\n\ncompaction\x18\x17\x20\x01(\
+    \x0b2\x13.CompactionResponseH\0R\ncompaction\x12B\n\x0fdescribe_server\
+    \x18\x1a\x20\x01(\x0b2\x17.DescribeServerResponseH\0R\x0edescribeServer\
+    \x12?\n\x0epartition_load\x18\x1b\x20\x01(\x0b2\x16.PartitionLoadRespons\
+    eH\0R\rpartitionLoad\x12=\n\x0equery_by_index\x18\x1c\x20\x01(\x0b2\x15.\
+    QueryByIndexResponseH\0R\x0cqueryByIndex\x129\n\x0ctable_access\x18\x1d\
+    \x20\x01(\x0b2\x14.TableAccessResponseH\0R\x0btableAccess\x129\n\x0cdele\
+    te_where\x18\x1e\x20\x01(\x0b2\x14.DeleteWhereResponseH\0R\x0bdeleteWher\
+    e\x12-\n\x08move_row\x18\x1f\x20\x01(\x0b2\x10.MoveRowResponseH\0R\x07mo\
+    veRow\x12#\n\x04save\x18\x20\x20\x01(\x0b2\r.SaveResponseH\0R\x04save\
+    \x122\n\treplicate\x18!\x20\x01(\x0b2\x12.ReplicateResponseH\0R\treplica\
+    te\x12,\n\x07promote\x18\"\x20\x01(\x0b2\x10.PromoteResponseH\0R\x07prom\
+    ote\x120\n\traft_vote\x18#\x20\x01(\x0b2\x11.RaftVoteResponseH\0R\x08raf\
+    tVote\x126\n\x0braft_append\x18$\x20\x01(\x0b2\x13.RaftAppendResponseH\0\
+    R\nraftAppend\x12+\n\x07latency\x18\x18\x20\x01(\x0b2\x11.LatencyBreakdo\
+    wnR\x07latency\x12=\n\x11read_explanations\x18\x19\x20\x03(\x0b2\x10.Rea\
+    dExplanationR\x10readExplanationsB\x06\n\x04data\"\xb3\x02\n\x10LatencyB\
+    reakdown\x12!\n\x0ctotal_micros\x18\x01\x20\x01(\x04R\x0btotalMicros\x12\
+    '\n\x0fqueueing_micros\x18\x02\x20\x01(\x04R\x0equeueingMicros\x12%\n\
+    \x0eparsing_micros\x18\x03\x20\x01(\x04R\rparsingMicros\x12+\n\x11valida\
+    tion_micros\x18\x04\x20\x01(\x04R\x10validationMicros\x12'\n\x0fmemtable\
+    _micros\x18\x05\x20\x01(\x04R\x0ememtableMicros\x12*\n\x11sstable_io_mic\
+    ros\x18\x06\x20\x01(\x04R\x0fsstableIoMicros\x12*\n\x11commit_log_micros\
+    \x18\x07\x20\x01(\x04R\x0fcommitLogMicros\"\xe9\x01\n\x0fReadExplanation\
+    \x12\x1f\n\x0bprimary_key\x18\x01\x20\x01(\tR\nprimaryKey\x12!\n\x0cmemt\
+    able_hit\x18\x02\x20\x01(\x08R\x0bmemtableHit\x12'\n\x0fmemtable_micros\
+    \x18\x03\x20\x01(\x04R\x0ememtableMicros\x123\n\x08sstables\x18\x04\x20\
+    \x03(\x0b2\x17.SSTableReadExplanationR\x08sstables\x124\n\x16sstables_no\
+    t_consulted\x18\x05\x20\x01(\x04R\x14sstablesNotConsulted\"\xcc\x01\n\
+    \x16SSTableReadExplanation\x12\x1b\n\tfile_name\x18\x01\x20\x01(\tR\x08f\
+    ileName\x12+\n\x11partition_skipped\x18\x02\x20\x01(\x08R\x10partitionSk\
+    ipped\x12\x1b\n\trows_read\x18\x03\x20\x01(\x04R\x08rowsRead\x12\x1d\n\n\
+    bytes_read\x18\x04\x20\x01(\x04R\tbytesRead\x12\x14\n\x05found\x18\x05\
+    \x20\x01(\x08R\x05found\x12\x16\n\x06micros\x18\x06\x20\x01(\x04R\x06mic\
+    ros\"\x9d\x02\n\x0bGetResponse\x12\x19\n\x08hash_key\x18\x01\x20\x01(\tR\
+    \x07hashKey\x12!\n\x08sort_key\x18\x02\x20\x01(\x0b2\x06.ValueR\x07sortK\
+    ey\x120\n\x06values\x18\x03\x20\x03(\x0b2\x18.GetResponse.ValuesEntryR\
+    \x06values\x12\x14\n\x05table\x18\x04\x20\x01(\tR\x05table\x12+\n\rcolum\
+    n_values\x18\x05\x20\x03(\x0b2\x06.ValueR\x0ccolumnValues\x12\x18\n\x07v\
+    ersion\x18\x06\x20\x01(\x04R\x07version\x1aA\n\x0bValuesEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x1c\n\x05value\x18\x02\x20\x01(\
+    \x0b2\x06.ValueR\x05value:\x028\x01\"Y\n\x0eInsertResponse\x12+\n\x11con\
+    sistency_token\x18\x01\x20\x01(\tR\x10consistencyToken\x12\x1a\n\x08sequ\
+    ence\x18\x02\x20\x01(\x04R\x08sequence\"Q\n\x0eDeleteResponse\x12\x12\n\
+    \x04okay\x18\x01\x20\x01(\x08R\x04okay\x12+\n\x11consistency_token\x18\
+    \x02\x20\x01(\tR\x10consistencyToken\"(\n\x10SequenceResponse\x12\x14\n\
+    \x05value\x18\x01\x20\x01(\x04R\x05value\"H\n\rLeaseResponse\x12\x18\n\
+    \x07version\x18\x01\x20\x01(\x04R\x07version\x12\x1d\n\nexpires_at\x18\
+    \x02\x20\x01(\x04R\texpiresAt\"(\n\x10RowCountResponse\x12\x14\n\x05coun\
+    t\x18\x01\x20\x01(\x04R\x05count\"z\n\x0fGetManyResponse\x12\"\n\x05item\
+    s\x18\x01\x20\x03(\x0b2\x0c.GetResponseR\x05items\x12\x14\n\x05found\x18\
+    \x02\x20\x03(\x08R\x05found\x12-\n\x12continuation_token\x18\x03\x20\x01\
+    (\tR\x11continuationToken\"i\n\x14QueryByIndexResponse\x12\"\n\x05items\
+    \x18\x01\x20\x03(\x0b2\x0c.GetResponseR\x05items\x12-\n\x12continuation_\
+    token\x18\x02\x20\x01(\tR\x11continuationToken\"/\n\x13DeleteWhereRespon\
+    se\x12\x18\n\x07deleted\x18\x01\x20\x01(\x04R\x07deleted\"'\n\x0fMoveRow\
+    Response\x12\x14\n\x05moved\x18\x01\x20\x01(\x08R\x05moved\"(\n\x0cSaveR\
+    esponse\x12\x18\n\x07version\x18\x01\x20\x01(\x04R\x07version\"_\n\x11Re\
+    plicatedRecords\x12\x1a\n\x08sequence\x18\x01\x20\x01(\x04R\x08sequence\
+    \x12\x14\n\x05table\x18\x02\x20\x01(\tR\x05table\x12\x18\n\x07records\
+    \x18\x03\x20\x01(\x0cR\x07records\"\xc8\x01\n\x11ReplicateResponse\x12\
+    \x14\n\x05epoch\x18\x01\x20\x01(\x04R\x05epoch\x12,\n\x07records\x18\x02\
+    \x20\x03(\x0b2\x12.ReplicatedRecordsR\x07records\x12#\n\rnext_sequence\
+    \x18\x03\x20\x01(\x04R\x0cnextSequence\x12%\n\x0eschema_strings\x18\x04\
+    \x20\x03(\tR\rschemaStrings\x12#\n\rpartition_map\x18\x05\x20\x01(\tR\
+    \x0cpartitionMap\"-\n\x0fPromoteResponse\x12\x1a\n\x08promoted\x18\x01\
+    \x20\x01(\x08R\x08promoted\"@\n\x10RaftVoteResponse\x12\x12\n\x04term\
+    \x18\x01\x20\x01(\x04R\x04term\x12\x18\n\x07granted\x18\x02\x20\x01(\x08\
+    R\x07granted\"a\n\x12RaftAppendResponse\x12\x12\n\x04term\x18\x01\x20\
+    \x01(\x04R\x04term\x12\x18\n\x07success\x18\x02\x20\x01(\x08R\x07success\
+    \x12\x1d\n\nlast_index\x18\x03\x20\x01(\x04R\tlastIndex\"A\n\rBatchRespo\
+    nse\x12\x12\n\x04okay\x18\x01\x20\x01(\x08R\x04okay\x12\x1c\n\tsequences\
+    \x18\x02\x20\x03(\x04R\tsequences\"P\n\x13TransactionResponse\x12\x12\n\
+    \x04okay\x18\x01\x20\x01(\x08R\x04okay\x12%\n\x0etransaction_id\x18\x02\
+    \x20\x01(\x04R\rtransactionId\"\x13\n\x11SyncModelResponse\"\x13\n\x11Dr\
+    opTableResponse\"-\n\x15PlanMigrationResponse\x12\x14\n\x05steps\x18\x01\
+    \x20\x03(\tR\x05steps\"n\n\x15DescribeTableResponse\x12#\n\rschema_strin\
+    g\x18\x01\x20\x01(\tR\x0cschemaString\x120\n\nstatistics\x18\x02\x20\x01\
+    (\x0b2\x10.TableStatisticsR\nstatistics\"\x81\x02\n\x0fTableStatistics\
+    \x12\x1a\n\x08sstables\x18\x01\x20\x01(\rR\x08sstables\x12,\n\x12row_cou\
+    nt_estimate\x18\x02\x20\x01(\x04R\x10rowCountEstimate\x12(\n\x10average_\
+    row_size\x18\x03\x20\x01(\x04R\x0eaverageRowSize\x120\n\x14hash_key_card\
+    inality\x18\x04\x20\x01(\x04R\x12hashKeyCardinality\x12)\n\x11rows_per_h\
+    ash_key\x18\x05\x20\x03(\x04R\x0erowsPerHashKey\x12\x1d\n\ndisk_usage\
+    \x18\x06\x20\x01(\x04R\tdiskUsage\",\n\x0eHealthResponse\x12\x1a\n\x08de\
+    graded\x18\x01\x20\x03(\tR\x08degraded\"=\n\rReadyResponse\x12\x14\n\x05\
+    ready\x18\x01\x20\x01(\x08R\x05ready\x12\x16\n\x06detail\x18\x02\x20\x01\
+    (\tR\x06detail\"\xcb\x02\n\x16DescribeServerResponse\x12\x18\n\x07versio\
+    n\x18\x01\x20\x01(\tR\x07version\x12#\n\ruptime_millis\x18\x02\x20\x01(\
+    \x04R\x0cuptimeMillis\x12*\n\x11number_of_threads\x18\x03\x20\x01(\rR\
+    \x0fnumberOfThreads\x12;\n\x06config\x18\x04\x20\x03(\x0b2#.DescribeServ\
+    erResponse.ConfigEntryR\x06config\x12\x1a\n\x08features\x18\x05\x20\x03(\
+    \tR\x08features\x122\n\tdatabases\x18\x06\x20\x03(\x0b2\x14.DatabaseDesc\
+    riptionR\tdatabases\x1a9\n\x0bConfigEntry\x12\x10\n\x03key\x18\x01\x20\
+    \x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\tR\x05value:\x028\
+    \x01\"\xea\x01\n\x13DatabaseDescription\x12\x12\n\x04name\x18\x01\x20\
+    \x01(\tR\x04name\x12\x19\n\x08data_dir\x18\x02\x20\x01(\tR\x07dataDir\
+    \x12!\n\x0csstables_dir\x18\x03\x20\x01(\tR\x0bsstablesDir\x12&\n\x0fcom\
+    mit_logs_dir\x18\x04\x20\x01(\tR\rcommitLogsDir\x12,\n\x12table_schemas_\
+    file\x18\x05\x20\x01(\tR\x10tableSchemasFile\x12+\n\x11partition_threads\
+    \x18\x06\x20\x03(\rR\x10partitionThreads\"S\n\x14PartitionMapResponse\
+    \x12#\n\rpartition_map\x18\x01\x20\x01(\tR\x0cpartitionMap\x12\x16\n\x06\
+    leader\x18\x02\x20\x01(\tR\x06leader\"\x96\x01\n\x18PartitionKeyDistribu\
+    tion\x12\x1c\n\tpartition\x18\x01\x20\x01(\rR\tpartition\x12#\n\rmemtabl\
+    e_rows\x18\x02\x20\x01(\x04R\x0cmemtableRows\x12!\n\x0csstable_rows\x18\
+    \x03\x20\x01(\x04R\x0bsstableRows\x12\x14\n\x05bytes\x18\x04\x20\x01(\
+    \x04R\x05bytes\"T\n\x17KeyDistributionResponse\x129\n\npartitions\x18\
+    \x01\x20\x03(\x0b2\x19.PartitionKeyDistributionR\npartitions\"\xbe\x01\n\
+    \x12PartitionLoadStats\x12\x1c\n\tpartition\x18\x01\x20\x01(\rR\tpartiti\
+    on\x12.\n\x13requests_per_second\x18\x02\x20\x01(\x01R\x11requestsPerSec\
+    ond\x124\n\x16average_latency_micros\x18\x03\x20\x01(\x04R\x14averageLat\
+    encyMicros\x12\x12\n\x04skew\x18\x04\x20\x01(\x01R\x04skew\x12\x10\n\x03\
+    hot\x18\x05\x20\x01(\x08R\x03hot\"L\n\x15PartitionLoadResponse\x123\n\np\
+    artitions\x18\x01\x20\x03(\x0b2\x13.PartitionLoadStatsR\npartitions\"\
+    \xda\x02\n\x10TableAccessStats\x12\x14\n\x05table\x18\x01\x20\x01(\tR\
+    \x05table\x12(\n\x10reads_per_second\x18\x02\x20\x01(\x01R\x0ereadsPerSe\
+    cond\x12*\n\x11writes_per_second\x18\x03\x20\x01(\x01R\x0fwritesPerSecon\
+    d\x121\n\x15read_bytes_per_second\x18\x04\x20\x01(\x01R\x12readBytesPerS\
+    econd\x127\n\x18written_bytes_per_second\x18\x05\x20\x01(\x01R\x15writte\
+    nBytesPerSecond\x125\n\x17read_p99_latency_micros\x18\x06\x20\x01(\x04R\
+    \x14readP99LatencyMicros\x127\n\x18write_p99_latency_micros\x18\x07\x20\
+    \x01(\x04R\x15writeP99LatencyMicros\"@\n\x13TableAccessResponse\x12)\n\
+    \x06tables\x18\x01\x20\x03(\x0b2\x11.TableAccessStatsR\x06tables\"\xc5\
+    \x01\n\x11ActiveTransaction\x12%\n\x0etransaction_id\x18\x01\x20\x01(\
+    \x04R\rtransactionId\x12\x1d\n\nage_millis\x18\x02\x20\x01(\x04R\tageMil\
+    lis\x12\x16\n\x06tables\x18\x03\x20\x03(\tR\x06tables\x128\n\x15coordina\
+    tor_partition\x18\x04\x20\x01(\rH\0R\x14coordinatorPartition\x88\x01\x01\
+    B\x18\n\x16_coordinator_partition\"R\n\x18ListTransactionsResponse\x126\
+    \n\x0ctransactions\x18\x01\x20\x03(\x0b2\x12.ActiveTransactionR\x0ctrans\
+    actions\"\x11\n\x0fSessionResponse\"\x15\n\x13SetReadOnlyResponse\"^\n\
+    \x10CompactionBucket\x12\x1a\n\x08sstables\x18\x01\x20\x01(\rR\x08sstabl\
+    es\x12\x14\n\x05bytes\x18\x02\x20\x01(\x04R\x05bytes\x12\x18\n\x07pendin\
+    g\x18\x03\x20\x01(\x08R\x07pending\"\xeb\x01\n\x0fTableCompaction\x12\
+    \x14\n\x05table\x18\x01\x20\x01(\tR\x05table\x12+\n\x07buckets\x18\x02\
+    \x20\x03(\x0b2\x11.CompactionBucketR\x07buckets\x12)\n\x10pending_sstabl\
+    es\x18\x03\x20\x01(\rR\x0fpendingSstables\x125\n\x14last_duration_millis\
+    \x18\x04\x20\x01(\x04H\0R\x12lastDurationMillis\x88\x01\x01\x12\x1a\n\
+    \x08priority\x18\x05\x20\x01(\x04R\x08priorityB\x17\n\x15_last_duration_\
+    millis\"\xb8\x02\n\x12CompactionResponse\x12\x1d\n\nbucket_low\x18\x01\
+    \x20\x01(\x01R\tbucketLow\x12\x1f\n\x0bbucket_high\x18\x02\x20\x01(\x01R\
+    \nbucketHigh\x12(\n\x10sstable_min_size\x18\x03\x20\x01(\x04R\x0esstable\
+    MinSize\x12'\n\x0finterval_millis\x18\x04\x20\x01(\x04R\x0eintervalMilli\
+    s\x12(\n\x06tables\x18\x05\x20\x03(\x0b2\x10.TableCompactionR\x06tables\
+    \x12<\n\x1amax_concurrent_compactions\x18\x06\x20\x01(\rR\x18maxConcurre\
+    ntCompactions\x12'\n\x10io_rate_limit_mb\x18\x07\x20\x01(\x04R\rioRateLi\
+    mitMb\"\x86\x02\n\x0bClientError\x12\x16\n\x06detail\x18\x01\x20\x01(\tR\
+    \x06detail\x12\x1e\n\x04code\x18\x02\x20\x01(\x0e2\n.ErrorCodeR\x04code\
+    \x12\x1c\n\tretryable\x18\x03\x20\x01(\x08R\tretryable\x12,\n\x12retry_a\
+    fter_millis\x18\x04\x20\x01(\x04R\x10retryAfterMillis\x126\n\x08metadata\
+    \x18\x05\x20\x03(\x0b2\x1a.ClientError.MetadataEntryR\x08metadata\x1a;\n\
+    \rMetadataEntry\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\
+    \x05value\x18\x02\x20\x01(\tR\x05value:\x028\x01\"\x86\x02\n\x0bServerEr\
+    ror\x12\x16\n\x06detail\x18\x01\x20\x01(\tR\x06detail\x12\x1e\n\x04code\
+    \x18\x02\x20\x01(\x0e2\n.ErrorCodeR\x04code\x12\x1c\n\tretryable\x18\x03\
+    \x20\x01(\x08R\tretryable\x12,\n\x12retry_after_millis\x18\x04\x20\x01(\
+    \x04R\x10retryAfterMillis\x126\n\x08metadata\x18\x05\x20\x03(\x0b2\x1a.S\
+    erverError.MetadataEntryR\x08metadata\x1a;\n\rMetadataEntry\x12\x10\n\
+    \x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\t\
+    R\x05value:\x028\x01*\x8e\x02\n\tErrorCode\x12\x0b\n\x07UNKNOWN\x10\0\
+    \x12\x13\n\x0fINVALID_REQUEST\x10\x01\x12\r\n\tNOT_FOUND\x10\x02\x12\x0c\
+    \n\x08CONFLICT\x10\x03\x12\x0f\n\x0bUNAVAILABLE\x10\x04\x12\x0b\n\x07TIM\
+    EOUT\x10\x05\x12\x0c\n\x08INTERNAL\x10\x06\x12\x15\n\x11HASH_KEY_TOO_LON\
+    G\x10\x07\x12\x13\n\x0fWRONG_PARTITION\x10\x08\x12\x13\n\x0fSCHEMA_MISMA\
+    TCH\x10\t\x12\x12\n\x0eQUOTA_EXCEEDED\x10\n\x12\x1b\n\x17SCHEMA_VERSION_\
+    MISMATCH\x10\x0b\x12\x14\n\x10VERSION_CONFLICT\x10\x0c\x12\x0e\n\nNOT_LE\
+    ADER\x10\rb\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -2155,19 +10123,56 @@ pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
         let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
             let mut deps = ::std::vec::Vec::with_capacity(1);
             deps.push(super::common::file_descriptor().clone());
-            let mut messages = ::std::vec::Vec::with_capacity(11);
+            let mut messages = ::std::vec::Vec::with_capacity(47);
             messages.push(Response::generated_message_descriptor_data());
+            messages.push(LatencyBreakdown::generated_message_descriptor_data());
+            messages.push(ReadExplanation::generated_message_descriptor_data());
+            messages.push(SSTableReadExplanation::generated_message_descriptor_data());
             messages.push(GetResponse::generated_message_descriptor_data());
             messages.push(InsertResponse::generated_message_descriptor_data());
             messages.push(DeleteResponse::generated_message_descriptor_data());
+            messages.push(SequenceResponse::generated_message_descriptor_data());
+            messages.push(LeaseResponse::generated_message_descriptor_data());
+            messages.push(RowCountResponse::generated_message_descriptor_data());
             messages.push(GetManyResponse::generated_message_descriptor_data());
+            messages.push(QueryByIndexResponse::generated_message_descriptor_data());
+            messages.push(DeleteWhereResponse::generated_message_descriptor_data());
+            messages.push(MoveRowResponse::generated_message_descriptor_data());
+            messages.push(SaveResponse::generated_message_descriptor_data());
+            messages.push(ReplicatedRecords::generated_message_descriptor_data());
+            messages.push(ReplicateResponse::generated_message_descriptor_data());
+            messages.push(PromoteResponse::generated_message_descriptor_data());
+            messages.push(RaftVoteResponse::generated_message_descriptor_data());
+            messages.push(RaftAppendResponse::generated_message_descriptor_data());
             messages.push(BatchResponse::generated_message_descriptor_data());
             messages.push(TransactionResponse::generated_message_descriptor_data());
             messages.push(SyncModelResponse::generated_message_descriptor_data());
             messages.push(DropTableResponse::generated_message_descriptor_data());
+            messages.push(PlanMigrationResponse::generated_message_descriptor_data());
+            messages.push(DescribeTableResponse::generated_message_descriptor_data());
+            messages.push(TableStatistics::generated_message_descriptor_data());
+            messages.push(HealthResponse::generated_message_descriptor_data());
+            messages.push(ReadyResponse::generated_message_descriptor_data());
+            messages.push(DescribeServerResponse::generated_message_descriptor_data());
+            messages.push(DatabaseDescription::generated_message_descriptor_data());
+            messages.push(PartitionMapResponse::generated_message_descriptor_data());
+            messages.push(PartitionKeyDistribution::generated_message_descriptor_data());
+            messages.push(KeyDistributionResponse::generated_message_descriptor_data());
+            messages.push(PartitionLoadStats::generated_message_descriptor_data());
+            messages.push(PartitionLoadResponse::generated_message_descriptor_data());
+            messages.push(TableAccessStats::generated_message_descriptor_data());
+            messages.push(TableAccessResponse::generated_message_descriptor_data());
+            messages.push(ActiveTransaction::generated_message_descriptor_data());
+            messages.push(ListTransactionsResponse::generated_message_descriptor_data());
+            messages.push(SessionResponse::generated_message_descriptor_data());
+            messages.push(SetReadOnlyResponse::generated_message_descriptor_data());
+            messages.push(CompactionBucket::generated_message_descriptor_data());
+            messages.push(TableCompaction::generated_message_descriptor_data());
+            messages.push(CompactionResponse::generated_message_descriptor_data());
             messages.push(ClientError::generated_message_descriptor_data());
             messages.push(ServerError::generated_message_descriptor_data());
-            let mut enums = ::std::vec::Vec::with_capacity(0);
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(ErrorCode::generated_enum_descriptor_data());
             ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
                 file_descriptor_proto(),
                 deps,
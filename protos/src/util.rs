@@ -1,7 +1,11 @@
-use crate::{ClientError, ProtoResponse, ProtoResponseData, ProtoValue, ProtoValueData};
+use crate::{
+    ClientError, ErrorCode, ProtoResponse, ProtoResponseData, ProtoValue, ProtoValueData,
+    ServerError,
+};
 use common::value::Value;
 use common::value::Value::*;
-use protobuf::MessageField;
+use protobuf::{EnumOrUnknown, MessageField};
+use std::collections::HashMap;
 
 pub fn parse_value_from_proto(value: ProtoValue) -> Value {
     match value.data {
@@ -46,11 +50,40 @@ pub fn parse_value_from_message_field(message_field: MessageField<ProtoValue>) -
     parse_value_from_proto(message_field.unwrap())
 }
 
-pub fn client_error_to_proto_response(error: String) -> ProtoResponse {
+pub fn client_error_to_proto_response(error: String, code: ErrorCode) -> ProtoResponse {
+    client_error_with_metadata_to_proto_response(error, code, HashMap::new())
+}
+
+// metadata carries details of error, so that clients do not have to parse them out of detail
+pub fn client_error_with_metadata_to_proto_response(
+    error: String,
+    code: ErrorCode,
+    metadata: HashMap<String, String>,
+) -> ProtoResponse {
     let mut client_response = ClientError::new();
     client_response.detail = error;
+    client_response.code = EnumOrUnknown::new(code);
+    client_response.metadata = metadata;
 
     let mut proto_response = ProtoResponse::new();
     proto_response.data = Some(ProtoResponseData::ClientError(client_response));
     proto_response
 }
+
+// retry_after_millis of 0 means client may retry right away
+pub fn server_error_to_proto_response(
+    detail: String,
+    code: ErrorCode,
+    retryable: bool,
+    retry_after_millis: u64,
+) -> ProtoResponse {
+    let mut server_error = ServerError::new();
+    server_error.detail = detail;
+    server_error.code = EnumOrUnknown::new(code);
+    server_error.retryable = retryable;
+    server_error.retry_after_millis = retry_after_millis;
+
+    let mut proto_response = ProtoResponse::new();
+    proto_response.data = Some(ProtoResponseData::ServerError(server_error));
+    proto_response
+}
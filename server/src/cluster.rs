@@ -0,0 +1,996 @@
+use crate::database::{Database, DatabaseConfig};
+use crate::handlers::HandlerError;
+use crate::peer::PeerConnection;
+use crate::raft::{is_up_to_date, majority, AppendCheck, LogPosition, RaftEntry, RaftLog};
+use crate::replication::{
+    apply_leader_records, parse_address, role_access, standby_config, RoleAccess,
+};
+use crate::thread_channels::{Command, Response};
+use futures::future::join_all;
+use futures::lock::Mutex;
+use protos::{
+    ProtoRequest, ProtoRequestData, ProtoResponseData, RaftAppendRequest, RaftLogPosition,
+    RaftVoteRequest,
+};
+use rand::Rng;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use storage::data_dir::DataDir;
+use storage::log_syncer::sync_commit_logs_of;
+use storage::replication::{read_replicated, replication_log_end, replication_log_max_bytes};
+use storage::MEGABYTE;
+
+static RAFT_TICK: Duration = Duration::from_millis(5);
+// leader without new entries for a follower only sends it that often
+static HEARTBEAT_INTERVAL: Duration = Duration::from_millis(100);
+static PEER_REQUEST_TIMEOUT: Duration = Duration::from_millis(500);
+static RAFT_APPEND_MAX_BYTES: usize = 4 * MEGABYTE;
+// write not acknowledged by a majority of nodes in that time fails, though it may still get there
+static MAJORITY_TIMEOUT: Duration = Duration::from_secs(5);
+// entries are kept in replication log until leader appends them to its raft log, so it has to
+// keep records even if standby servers are not used
+pub(crate) static CLUSTER_LOG_MIN_BYTES: usize = 64 * MEGABYTE;
+
+static CLUSTER: RwLock<Option<ClusterConfig>> = RwLock::new(None);
+// by database name, every database elects its leader on its own
+static ELECTIONS: std::sync::Mutex<BTreeMap<String, Election>> =
+    std::sync::Mutex::new(BTreeMap::new());
+// why a thread stopped following or leading, by database name and thread
+static CLUSTER_FAILURES: std::sync::Mutex<BTreeMap<(String, usize), String>> =
+    std::sync::Mutex::new(BTreeMap::new());
+
+thread_local! {
+    // by database name, every thread keeps raft log of its own commit logs
+    static RAFT_LOGS: RefCell<HashMap<String, Rc<Mutex<RaftLog>>>> = RefCell::new(HashMap::new());
+}
+
+// nodes of a cluster elect a leader per database, which takes writes and acknowledges them once a
+// majority of nodes has them. Every node runs the same number of threads and partitions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterConfig {
+    pub node_id: u64,
+    pub nodes: Vec<ClusterNode>,
+    // reads are rejected by followers otherwise, as they may be behind the leader
+    pub follower_reads: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterNode {
+    pub id: u64,
+    pub host: String,
+    // port of the first thread of the node
+    pub port: usize,
+}
+
+impl ClusterNode {
+    fn address(&self, thread_number: usize) -> String {
+        format!("{}:{}", self.host, self.port + thread_number)
+    }
+}
+
+impl ClusterConfig {
+    fn peer_ids(&self) -> Vec<u64> {
+        self.nodes
+            .iter()
+            .map(|node| node.id)
+            .filter(|id| *id != self.node_id)
+            .collect()
+    }
+
+    fn node(&self, id: u64) -> Option<&ClusterNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+}
+
+// nodes are "id=host[:port],id=host[:port]" with every node listed, this one included, empty
+// nodes mean server is not part of a cluster
+pub fn parse_cluster_config(
+    nodes: &str,
+    node_id: &str,
+    follower_reads: &str,
+) -> Result<Option<ClusterConfig>, String> {
+    if nodes.trim().is_empty() {
+        return Ok(None);
+    }
+    let follower_reads = match follower_reads.trim() {
+        "" | "false" => false,
+        "true" => true,
+        follower_reads => {
+            return Err(format!(
+                "Invalid follower reads '{}', expected true or false",
+                follower_reads
+            ))
+        }
+    };
+
+    let mut cluster_nodes: Vec<ClusterNode> = Vec::new();
+    for node in nodes.split(',') {
+        let (id, address) = node
+            .split_once('=')
+            .ok_or(format!("Invalid cluster node '{}', expected id=host", node))?;
+        let id = match id.trim().parse() {
+            Ok(id) if id > 0 => id,
+            _ => return Err(format!("Invalid id of cluster node '{}'", node)),
+        };
+        if cluster_nodes
+            .iter()
+            .any(|cluster_node| cluster_node.id == id)
+        {
+            return Err(format!("Cluster node {} is configured more than once", id));
+        }
+        let (host, port) = parse_address(address.trim())?;
+        cluster_nodes.push(ClusterNode { id, host, port });
+    }
+
+    let node_id = node_id
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid cluster node id '{}'", node_id))?;
+    if !cluster_nodes.iter().any(|node| node.id == node_id) {
+        return Err(format!("Node {} is not one of cluster nodes", node_id));
+    }
+    Ok(Some(ClusterConfig {
+        node_id,
+        nodes: cluster_nodes,
+        follower_reads,
+    }))
+}
+
+pub(crate) fn cluster_config() -> Option<ClusterConfig> {
+    CLUSTER.read().unwrap().clone()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+impl Role {
+    fn name(&self) -> &'static str {
+        match self {
+            Role::Follower => "follower",
+            Role::Candidate => "candidate",
+            Role::Leader => "leader",
+        }
+    }
+}
+
+// term and vote are persisted before they are acted on, so that a restarted node does not vote
+// twice in a term
+struct Election {
+    root: String,
+    current_term: u64,
+    voted_for: Option<u64>,
+    role: Role,
+    leader_id: Option<u64>,
+    // leader takes it as the last time a majority of nodes answered it
+    last_heard: Instant,
+    election_timeout: Duration,
+    // last entry of raft log of every thread
+    positions: Vec<LogPosition>,
+    // leader only, writes handled on the thread before it reached a majority of nodes
+    committed_through: Vec<Option<Instant>>,
+}
+
+impl Election {
+    fn persist(&self, term: u64, voted_for: Option<u64>) -> Result<(), String> {
+        std::fs::write(
+            format!("{}/raft_term", self.root),
+            format!("{}:{}", term, voted_for.unwrap_or(0)),
+        )
+        .map_err(|e| format!("failed to persist raft term: {}", e))
+    }
+
+    fn reset_timer(&mut self) {
+        self.last_heard = Instant::now();
+        self.election_timeout = random_election_timeout();
+    }
+
+    // node that sees a later term follows whoever leads it
+    fn observe_term(&mut self, term: u64) -> Result<(), String> {
+        if term <= self.current_term {
+            return Ok(());
+        }
+        self.persist(term, None)?;
+        self.current_term = term;
+        self.voted_for = None;
+        self.role = Role::Follower;
+        self.leader_id = None;
+        Ok(())
+    }
+
+    fn follow(&mut self, term: u64, leader_id: u64) -> Result<(), String> {
+        self.observe_term(term)?;
+        if term == self.current_term {
+            self.role = Role::Follower;
+            self.leader_id = Some(leader_id);
+            self.reset_timer();
+        }
+        Ok(())
+    }
+}
+
+fn random_election_timeout() -> Duration {
+    Duration::from_millis(rand::thread_rng().gen_range(1500..3000))
+}
+
+fn with_election<T>(database_name: &str, f: impl FnOnce(&mut Election) -> T) -> Option<T> {
+    ELECTIONS.lock().unwrap().get_mut(database_name).map(f)
+}
+
+fn raft_position_file_path(data_dir: &DataDir, thread_number: usize) -> String {
+    format!("{}/raft_position_{}", data_dir.root, thread_number)
+}
+
+fn read_raft_position(data_dir: &DataDir, thread_number: usize) -> LogPosition {
+    std::fs::read_to_string(raft_position_file_path(data_dir, thread_number))
+        .ok()
+        .and_then(|position| LogPosition::from_string(&position))
+        .unwrap_or_default()
+}
+
+// node restarts as follower of the term it persisted, raft logs start from positions persisted by
+// every thread
+pub(crate) fn start_cluster(
+    cluster: Option<ClusterConfig>,
+    database_configs: &[DatabaseConfig],
+    num_of_threads: usize,
+) {
+    let mut elections = ELECTIONS.lock().unwrap();
+    elections.clear();
+    if cluster.is_some() {
+        for database_config in database_configs {
+            let data_dir = &database_config.data_dir;
+            let (current_term, voted_for) =
+                std::fs::read_to_string(format!("{}/raft_term", data_dir.root))
+                    .ok()
+                    .and_then(|term| {
+                        let (term, voted_for) = term.trim().split_once(':')?;
+                        Some((term.parse().ok()?, voted_for.parse().ok()?))
+                    })
+                    .unwrap_or((0, 0));
+            let positions = (0..num_of_threads)
+                .map(|thread_number| read_raft_position(data_dir, thread_number))
+                .collect();
+            elections.insert(
+                database_config.name.clone(),
+                Election {
+                    root: data_dir.root.clone(),
+                    current_term,
+                    voted_for: (voted_for > 0).then_some(voted_for),
+                    role: Role::Follower,
+                    leader_id: None,
+                    last_heard: Instant::now(),
+                    election_timeout: random_election_timeout(),
+                    positions,
+                    committed_through: vec![None; num_of_threads],
+                },
+            );
+        }
+    }
+    *CLUSTER.write().unwrap() = cluster;
+}
+
+// log of a panicked thread may have entries it did not persist the position of
+pub(crate) fn reset_raft_logs() {
+    RAFT_LOGS.with(|raft_logs| raft_logs.borrow_mut().clear());
+}
+
+fn thread_raft_log(database_name: &str, database: &Database) -> Rc<Mutex<RaftLog>> {
+    let thread_context = &database.thread_context;
+    RAFT_LOGS.with(|raft_logs| {
+        raft_logs
+            .borrow_mut()
+            .entry(database_name.to_string())
+            .or_insert_with(|| {
+                Rc::new(Mutex::new(RaftLog::new(read_raft_position(
+                    &thread_context.data_dir,
+                    thread_context.current_thread_number,
+                ))))
+            })
+            .clone()
+    })
+}
+
+// position is persisted once entries reached synced commit logs, as candidate is elected by it
+fn persist_raft_position(
+    database_name: &str,
+    database: &Database,
+    position: LogPosition,
+) -> Result<(), String> {
+    let thread_context = &database.thread_context;
+    let thread_number = thread_context.current_thread_number;
+    std::fs::write(
+        raft_position_file_path(&thread_context.data_dir, thread_number),
+        position.to_string(),
+    )
+    .map_err(|e| format!("failed to persist raft position: {}", e))?;
+    with_election(database_name, |election| {
+        election.positions[thread_number] = position
+    });
+    Ok(())
+}
+
+pub(crate) fn cluster_failures() -> Vec<String> {
+    CLUSTER_FAILURES
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|((database_name, thread_number), failure)| {
+            format!(
+                "raft of database '{}' failed on thread {}: {}",
+                database_name, thread_number, failure
+            )
+        })
+        .collect()
+}
+
+// logged once per failure, as leader keeps running into the same one on every tick
+fn report_cluster_failure(database_name: &str, thread_number: usize, failure: String) {
+    let previous = CLUSTER_FAILURES
+        .lock()
+        .unwrap()
+        .insert((database_name.to_string(), thread_number), failure.clone());
+    if previous.as_ref() != Some(&failure) {
+        tracing::error!(
+            "Raft of database '{}' failed on thread {}: {}",
+            database_name,
+            thread_number,
+            failure
+        );
+    }
+}
+
+// "host:port" of the first thread of the server writes go to, empty if it is this one or the
+// leader is not known yet
+pub(crate) fn leader_hint(database_name: &str) -> String {
+    if let Some(standby) = standby_config() {
+        return standby.leader_address(0);
+    }
+    let Some(cluster) = cluster_config() else {
+        return String::new();
+    };
+    with_election(database_name, |election| election.leader_id)
+        .flatten()
+        .filter(|leader_id| *leader_id != cluster.node_id)
+        .and_then(|leader_id| cluster.node(leader_id))
+        .map(|node| node.address(0))
+        .unwrap_or_default()
+}
+
+// raft role of the database as described by server, none if server is not part of a cluster
+pub(crate) fn cluster_role(database_name: &str) -> Option<&'static str> {
+    cluster_config()?;
+    with_election(database_name, |election| election.role.name())
+}
+
+// followers reject writes and serve reads only if configured to. Term is returned for writes
+// the leader has to get to a majority of nodes before they are acknowledged
+pub(crate) fn check_cluster_role(
+    database_name: &str,
+    command: &Command,
+    transaction_id: Option<u64>,
+) -> Result<Option<u64>, HandlerError> {
+    let Some(cluster) = cluster_config() else {
+        return Ok(None);
+    };
+    let Some((role, term)) = with_election(database_name, |election| {
+        (election.role, election.current_term)
+    }) else {
+        return Ok(None);
+    };
+
+    match (role_access(command, transaction_id), role) {
+        (RoleAccess::Admin, _) => Ok(None),
+        (RoleAccess::Write, Role::Leader) => Ok(Some(term)),
+        (RoleAccess::Read, Role::Leader) => Ok(None),
+        (RoleAccess::Read, _) if cluster.follower_reads => Ok(None),
+        (RoleAccess::Read | RoleAccess::Write, _) => {
+            let leader = leader_hint(database_name);
+            Err(HandlerError::NotLeader {
+                leader: (!leader.is_empty()).then_some(leader),
+            })
+        }
+    }
+}
+
+// every thread has to have appended and committed records it held once write was handled, as
+// writes of batches and transactions reach commit logs of other threads as well
+pub(crate) async fn wait_for_majority(
+    database_name: &str,
+    term: u64,
+    written_at: Instant,
+) -> Result<(), HandlerError> {
+    let wait = async {
+        loop {
+            let committed = with_election(database_name, |election| {
+                if election.role != Role::Leader || election.current_term != term {
+                    return Err(HandlerError::Server(
+                        "Leadership was lost before write reached a majority of nodes, it may or \
+                         may not be applied"
+                            .to_string(),
+                    ));
+                }
+                Ok(election
+                    .committed_through
+                    .iter()
+                    .all(|committed_at| committed_at.is_some_and(|at| at >= written_at)))
+            });
+            match committed {
+                Some(Ok(false)) => monoio::time::sleep(RAFT_TICK).await,
+                Some(Err(error)) => return Err(error),
+                Some(Ok(true)) | None => return Ok(()),
+            }
+        }
+    };
+    monoio::time::timeout(MAJORITY_TIMEOUT, wait)
+        .await
+        .unwrap_or(Err(HandlerError::Timeout(MAJORITY_TIMEOUT)))
+}
+
+pub(crate) async fn handle_raft_request(
+    request: ProtoRequest,
+    databases: &mut HashMap<String, Database>,
+) -> Result<Response, HandlerError> {
+    if cluster_config().is_none() {
+        return Err(HandlerError::Client(
+            "Server is not part of a cluster".to_string(),
+        ));
+    }
+    let database = databases.get_mut(&request.database).ok_or_else(|| {
+        HandlerError::NotFound(format!("Database named '{}' not found", request.database))
+    })?;
+
+    let response = match request.data {
+        Some(ProtoRequestData::RaftVote(vote_request)) => {
+            handle_vote(&request.database, vote_request)
+        }
+        Some(ProtoRequestData::RaftAppend(append_request)) => {
+            handle_append(&request.database, append_request, database).await
+        }
+        _ => return Err(HandlerError::Client("Invalid raft request".to_string())),
+    };
+    response.map_err(HandlerError::Server)
+}
+
+// vote goes to the first candidate of a term whose log is at least as up to date on every thread
+fn handle_vote(database_name: &str, vote_request: RaftVoteRequest) -> Result<Response, String> {
+    let candidate_positions: Vec<_> = vote_request
+        .last_positions
+        .iter()
+        .map(|position| LogPosition {
+            term: position.term,
+            index: position.index,
+        })
+        .collect();
+
+    with_election(database_name, |election| {
+        election.observe_term(vote_request.term)?;
+        let granted = vote_request.term == election.current_term
+            && election.role != Role::Leader
+            && election
+                .voted_for
+                .is_none_or(|voted_for| voted_for == vote_request.candidate_id)
+            && is_up_to_date(&candidate_positions, &election.positions);
+        if granted {
+            election.persist(election.current_term, Some(vote_request.candidate_id))?;
+            election.voted_for = Some(vote_request.candidate_id);
+            election.reset_timer();
+        }
+        Ok(Response::RaftVote(election.current_term, granted))
+    })
+    .unwrap_or_else(|| {
+        Err(format!(
+            "Database '{}' is not part of cluster",
+            database_name
+        ))
+    })
+}
+
+// entries are applied as soon as they are appended, follower that diverged from the leader rejects
+// every append until it is seeded again
+async fn handle_append(
+    database_name: &str,
+    append_request: RaftAppendRequest,
+    database: &mut Database,
+) -> Result<Response, String> {
+    let thread_number = database.thread_context.current_thread_number;
+    let current_term = with_election(database_name, |election| {
+        if append_request.term >= election.current_term {
+            election.follow(append_request.term, append_request.leader_id)?;
+        }
+        Ok::<_, String>(election.current_term)
+    })
+    .ok_or_else(|| format!("Database '{}' is not part of cluster", database_name))??;
+
+    let raft_log = thread_raft_log(database_name, database);
+    let mut raft_log = raft_log.lock().await;
+    let last_index = raft_log.last_position().index;
+    if append_request.term < current_term || raft_log.diverged.is_some() {
+        return Ok(Response::RaftAppend(current_term, false, last_index));
+    }
+
+    let prev = LogPosition {
+        term: append_request.prev_term,
+        index: append_request.prev_index,
+    };
+    let entries: Vec<_> = append_request
+        .entries
+        .into_iter()
+        .map(|entry| RaftEntry {
+            term: entry.term,
+            index: entry.index,
+            table_name: entry.table,
+            records: entry.records,
+        })
+        .collect();
+    let new_entries = match raft_log.check_append(prev, &entries) {
+        Ok(AppendCheck::Append(new_entries)) => new_entries,
+        Ok(AppendCheck::Missing) => {
+            return Ok(Response::RaftAppend(current_term, false, last_index))
+        }
+        Err(error) => {
+            report_cluster_failure(database_name, thread_number, error.clone());
+            raft_log.diverged = Some(error);
+            return Ok(Response::RaftAppend(current_term, false, last_index));
+        }
+    };
+
+    let records = new_entries
+        .iter()
+        .filter(|entry| !entry.table_name.is_empty())
+        .map(|entry| (entry.table_name.clone(), entry.records.clone()))
+        .collect();
+    if let Err(error) = apply_leader_records(
+        records,
+        append_request.schema_strings,
+        &append_request.partition_map,
+        database,
+    )
+    .await
+    {
+        report_cluster_failure(database_name, thread_number, error.clone());
+        raft_log.diverged = Some(error);
+        return Ok(Response::RaftAppend(current_term, false, last_index));
+    }
+
+    let max_bytes = replication_log_max_bytes();
+    for entry in new_entries {
+        raft_log.push(entry, max_bytes);
+    }
+    let last_position = raft_log.last_position();
+    persist_raft_position(database_name, database, last_position)?;
+    raft_log.commit_index = append_request.leader_commit.min(last_position.index);
+    Ok(Response::RaftAppend(
+        current_term,
+        true,
+        last_position.index,
+    ))
+}
+
+// state of a thread while its node leads the database
+struct Leadership {
+    term: u64,
+    // epoch and sequence of the next record of replication log to append to raft log
+    cursor: (u64, u64),
+    // last entry once all records were appended, with the time appending them started
+    drains: VecDeque<(u64, Instant)>,
+    last_sent: Option<Instant>,
+}
+
+// connection is dropped after any failure and connected again by the next request
+async fn send_to_peer(
+    connection: &mut Option<PeerConnection>,
+    address: String,
+    request: ProtoRequest,
+    number_of_threads: usize,
+) -> Result<ProtoResponseData, String> {
+    let result = monoio::time::timeout(PEER_REQUEST_TIMEOUT, async {
+        if connection.is_none() {
+            let peer = PeerConnection::connect(&address).await?;
+            if peer.number_of_threads != number_of_threads {
+                return Err(format!(
+                    "node '{}' runs {} threads, this one runs {}",
+                    address, peer.number_of_threads, number_of_threads
+                ));
+            }
+            *connection = Some(peer);
+        }
+        connection.as_mut().unwrap().request(request).await
+    })
+    .await
+    .unwrap_or_else(|_| Err(format!("node '{}' did not answer in time", address)));
+
+    match result.map(|response| response.data) {
+        Ok(Some(ProtoResponseData::ClientError(client_error))) => Err(client_error.detail),
+        Ok(Some(ProtoResponseData::ServerError(server_error))) => Err(server_error.detail),
+        Ok(Some(data)) => Ok(data),
+        Ok(None) => Err(format!("node '{}' sent an empty response", address)),
+        Err(error) => {
+            *connection = None;
+            Err(error)
+        }
+    }
+}
+
+// thread 0 runs elections of the database for the whole node, so that batches and transactions
+// spanning threads are led by a single node. Every thread replicates its own commit logs
+pub(crate) async fn raft_main(database_name: String, database: Database) {
+    let Some(cluster) = cluster_config() else {
+        return;
+    };
+    let thread_number = database.thread_context.current_thread_number;
+    let raft_log = thread_raft_log(&database_name, &database);
+    let mut peers: HashMap<u64, Option<PeerConnection>> = cluster
+        .peer_ids()
+        .into_iter()
+        .map(|peer_id| (peer_id, None))
+        .collect();
+    let mut leadership: Option<Leadership> = None;
+
+    loop {
+        monoio::time::sleep(RAFT_TICK).await;
+        if thread_number == 0 {
+            run_election_if_due(&database_name, &database, &cluster, &mut peers).await;
+        }
+
+        let leader_term = with_election(&database_name, |election| {
+            (election.role == Role::Leader).then_some(election.current_term)
+        })
+        .flatten();
+        let Some(term) = leader_term else {
+            leadership = None;
+            continue;
+        };
+        if leadership.as_ref().map(|leadership| leadership.term) != Some(term) {
+            match start_leading(&database_name, &database, &cluster, &raft_log, term).await {
+                Ok(started) => leadership = Some(started),
+                Err(error) => {
+                    report_cluster_failure(&database_name, thread_number, error);
+                    continue;
+                }
+            }
+        }
+
+        let leadership = leadership.as_mut().unwrap();
+        let lead = lead(
+            &database_name,
+            &database,
+            &cluster,
+            &raft_log,
+            leadership,
+            &mut peers,
+        );
+        if let Err(error) = lead.await {
+            report_cluster_failure(&database_name, thread_number, error);
+        }
+    }
+}
+
+// follower that did not hear from a leader in time runs for leadership, leader that did not hear
+// from a majority of nodes in time steps down, so that clients go to the newly elected one
+async fn run_election_if_due(
+    database_name: &str,
+    database: &Database,
+    cluster: &ClusterConfig,
+    peers: &mut HashMap<u64, Option<PeerConnection>>,
+) {
+    let candidacy = with_election(database_name, |election| {
+        if election.last_heard.elapsed() < election.election_timeout {
+            return Ok(None);
+        }
+        if election.role == Role::Leader {
+            tracing::warn!(
+                "Leader of database '{}' lost majority of nodes, stepping down",
+                database_name
+            );
+            election.role = Role::Follower;
+            election.leader_id = None;
+            election.reset_timer();
+            return Ok(None);
+        }
+        let term = election.current_term + 1;
+        election.persist(term, Some(cluster.node_id))?;
+        election.current_term = term;
+        election.voted_for = Some(cluster.node_id);
+        election.role = Role::Candidate;
+        election.leader_id = None;
+        election.reset_timer();
+        Ok::<_, String>(Some((term, election.positions.clone())))
+    })
+    .unwrap_or(Ok(None));
+    let thread_number = database.thread_context.current_thread_number;
+    let (term, positions) = match candidacy {
+        Ok(Some(candidacy)) => candidacy,
+        Ok(None) => return,
+        Err(error) => return report_cluster_failure(database_name, thread_number, error),
+    };
+
+    let mut vote_request = RaftVoteRequest::new();
+    vote_request.term = term;
+    vote_request.candidate_id = cluster.node_id;
+    vote_request.last_positions = positions
+        .iter()
+        .map(|position| {
+            let mut last_position = RaftLogPosition::new();
+            last_position.term = position.term;
+            last_position.index = position.index;
+            last_position
+        })
+        .collect();
+    let mut request = ProtoRequest::new();
+    request.database = database_name.to_string();
+    request.data = Some(ProtoRequestData::RaftVote(vote_request));
+
+    let number_of_threads = database.thread_context.number_of_threads;
+    let responses = join_all(peers.iter_mut().map(|(peer_id, connection)| {
+        let address = cluster.node(*peer_id).unwrap().address(0);
+        send_to_peer(connection, address, request.clone(), number_of_threads)
+    }))
+    .await;
+
+    let mut votes = 1;
+    for response in responses {
+        match response {
+            Ok(ProtoResponseData::RaftVote(vote)) if vote.term > term => {
+                with_election(database_name, |election| election.observe_term(vote.term));
+                return;
+            }
+            Ok(ProtoResponseData::RaftVote(vote)) if vote.granted => votes += 1,
+            Ok(_) => {}
+            Err(error) => tracing::debug!("Vote of database '{}' failed: {}", database_name, error),
+        }
+    }
+    if votes < majority(cluster.nodes.len()) {
+        return;
+    }
+    with_election(database_name, |election| {
+        if election.current_term == term && election.role == Role::Candidate {
+            tracing::warn!(
+                "Node {} leads database '{}' in term {}",
+                cluster.node_id,
+                database_name,
+                term
+            );
+            election.role = Role::Leader;
+            election.leader_id = Some(cluster.node_id);
+            election.committed_through.fill(None);
+            election.reset_timer();
+        }
+    });
+}
+
+// records appended before leadership were taken from the previous leader, entry without records
+// starts the term, so that earlier entries get committed along with it
+async fn start_leading(
+    database_name: &str,
+    database: &Database,
+    cluster: &ClusterConfig,
+    raft_log: &Rc<Mutex<RaftLog>>,
+    term: u64,
+) -> Result<Leadership, String> {
+    let cursor = replication_log_end(&database.thread_context.data_dir.commit_logs);
+    let mut raft_log = raft_log.lock().await;
+    raft_log.become_leader(&cluster.peer_ids());
+    let index = raft_log.append(term, String::new(), Vec::new(), replication_log_max_bytes());
+    persist_raft_position(database_name, database, raft_log.last_position())?;
+
+    Ok(Leadership {
+        term,
+        cursor,
+        drains: VecDeque::from([(index, Instant::now())]),
+        last_sent: None,
+    })
+}
+
+// records appended to commit logs of the thread since the last tick are synced and sent to every
+// follower, writes handled before they were read count as committed once a majority has them
+async fn lead(
+    database_name: &str,
+    database: &Database,
+    cluster: &ClusterConfig,
+    raft_log: &Rc<Mutex<RaftLog>>,
+    leadership: &mut Leadership,
+    peers: &mut HashMap<u64, Option<PeerConnection>>,
+) -> Result<(), String> {
+    let thread_context = &database.thread_context;
+    let thread_number = thread_context.current_thread_number;
+    let read_at = Instant::now();
+    let (epoch, sequence) = leadership.cursor;
+    let batch = read_replicated(
+        &thread_context.data_dir.commit_logs,
+        epoch,
+        sequence,
+        RAFT_APPEND_MAX_BYTES,
+    )?;
+    let drained =
+        batch.next_sequence == replication_log_end(&thread_context.data_dir.commit_logs).1;
+    leadership.cursor = (batch.epoch, batch.next_sequence);
+
+    // records of tables dropped since are left out, as drops are not replicated
+    let tables = database.tables.lock().await;
+    let records: Vec<_> = batch
+        .records
+        .into_iter()
+        .filter(|records| tables.contains_key(&records.table_name))
+        .collect();
+    let table_names: HashSet<_> = records
+        .iter()
+        .map(|records| records.table_name.clone())
+        .collect();
+    let commit_logs: Vec<_> = table_names
+        .iter()
+        .map(|table_name| tables[table_name].commit_log.clone())
+        .collect();
+    drop(tables);
+    sync_commit_logs_of(&commit_logs).await?;
+
+    let mut log = raft_log.lock().await;
+    if !records.is_empty() {
+        let max_bytes = replication_log_max_bytes();
+        for records in records {
+            log.append(
+                leadership.term,
+                records.table_name,
+                records.bytes,
+                max_bytes,
+            );
+        }
+        persist_raft_position(database_name, database, log.last_position())?;
+    }
+    let last_index = log.last_position().index;
+    if drained {
+        match leadership.drains.back_mut() {
+            Some((index, drained_at)) if *index == last_index => *drained_at = read_at,
+            _ => leadership.drains.push_back((last_index, read_at)),
+        }
+    }
+
+    let heartbeat_due = leadership
+        .last_sent
+        .is_none_or(|last_sent| last_sent.elapsed() >= HEARTBEAT_INTERVAL);
+    let mut sent_entries = Vec::new();
+    for peer_id in cluster.peer_ids() {
+        match log.entries_for(peer_id, RAFT_APPEND_MAX_BYTES) {
+            Some((prev, entries)) if !entries.is_empty() || heartbeat_due => {
+                sent_entries.push((peer_id, prev, entries))
+            }
+            Some(_) => {}
+            None => report_cluster_failure(
+                database_name,
+                thread_number,
+                format!(
+                    "node {} is behind entries no longer kept, it has to be seeded again",
+                    peer_id
+                ),
+            ),
+        }
+    }
+    let leader_commit = log.commit_index;
+    drop(log);
+    if heartbeat_due {
+        leadership.last_sent = Some(Instant::now());
+    }
+
+    let partition_map = thread_context.partition_map().to_string();
+    let tables = database.tables.lock().await;
+    let mut requests = HashMap::new();
+    for (peer_id, prev, entries) in sent_entries {
+        let entry_tables: HashSet<_> = entries.iter().map(|entry| &entry.table_name).collect();
+        let mut append_request = RaftAppendRequest::new();
+        append_request.term = leadership.term;
+        append_request.leader_id = cluster.node_id;
+        append_request.prev_index = prev.index;
+        append_request.prev_term = prev.term;
+        append_request.leader_commit = leader_commit;
+        append_request.schema_strings = entry_tables
+            .into_iter()
+            .filter_map(|table_name| tables.get(table_name))
+            .map(|table| table.table_schema.to_string())
+            .collect();
+        append_request.partition_map = partition_map.clone();
+        append_request.entries = entries
+            .into_iter()
+            .map(|entry| {
+                let mut raft_entry = protos::RaftEntry::new();
+                raft_entry.term = entry.term;
+                raft_entry.index = entry.index;
+                raft_entry.table = entry.table_name;
+                raft_entry.records = entry.records;
+                raft_entry
+            })
+            .collect();
+
+        let mut request = ProtoRequest::new();
+        request.database = database_name.to_string();
+        request.data = Some(ProtoRequestData::RaftAppend(append_request));
+        requests.insert(peer_id, request);
+    }
+    drop(tables);
+
+    let number_of_threads = thread_context.number_of_threads;
+    let responses = join_all(peers.iter_mut().filter_map(|(peer_id, connection)| {
+        let request = requests.remove(peer_id)?;
+        let address = cluster.node(*peer_id).unwrap().address(thread_number);
+        let response = send_to_peer(connection, address, request, number_of_threads);
+        Some(async move { (*peer_id, response.await) })
+    }))
+    .await;
+
+    let mut log = raft_log.lock().await;
+    let mut answered = 1;
+    for (peer_id, response) in responses {
+        match response {
+            Ok(ProtoResponseData::RaftAppend(append)) if append.term > leadership.term => {
+                with_election(database_name, |election| election.observe_term(append.term))
+                    .transpose()?;
+                return Ok(());
+            }
+            Ok(ProtoResponseData::RaftAppend(append)) => {
+                log.record_response(peer_id, append.success, append.last_index);
+                answered += 1;
+            }
+            Ok(_) => {}
+            Err(error) => tracing::debug!("Append to node {} failed: {}", peer_id, error),
+        }
+    }
+    log.advance_commit(leadership.term, cluster.nodes.len());
+    let commit_index = log.commit_index;
+    drop(log);
+
+    let mut committed_at = None;
+    while let Some(&(index, drained_at)) = leadership.drains.front() {
+        if index > commit_index {
+            break;
+        }
+        committed_at = Some(drained_at);
+        leadership.drains.pop_front();
+    }
+    let heard_from_majority = thread_number == 0 && answered >= majority(cluster.nodes.len());
+    with_election(database_name, |election| {
+        if election.current_term != leadership.term {
+            return;
+        }
+        if committed_at.is_some() {
+            election.committed_through[thread_number] = committed_at;
+        }
+        if heard_from_majority {
+            election.last_heard = Instant::now();
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::listener::TCP_STARTING_PORT;
+
+    #[test]
+    fn cluster_config_is_parsed() {
+        assert_eq!(parse_cluster_config("", "", ""), Ok(None));
+        let cluster = parse_cluster_config("1=10.0.0.1, 2=10.0.0.2:30800,3=node3", "2", "true")
+            .unwrap()
+            .unwrap();
+        assert_eq!(cluster.node_id, 2);
+        assert!(cluster.follower_reads);
+        assert_eq!(cluster.peer_ids(), vec![1, 3]);
+        assert_eq!(
+            cluster.node(1).unwrap().address(1),
+            format!("10.0.0.1:{}", TCP_STARTING_PORT + 1)
+        );
+        assert_eq!(cluster.node(2).unwrap().address(0), "10.0.0.2:30800");
+
+        assert!(parse_cluster_config("1=node1,1=node2", "1", "").is_err());
+        assert!(parse_cluster_config("0=node1", "0", "").is_err());
+        assert!(parse_cluster_config("1=node1,2=node2", "3", "").is_err());
+        assert!(parse_cluster_config("1=node1", "", "").is_err());
+        assert!(parse_cluster_config("node1", "1", "").is_err());
+        assert!(parse_cluster_config("1=node1", "1", "yes").is_err());
+    }
+}
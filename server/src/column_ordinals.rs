@@ -0,0 +1,130 @@
+use crate::handlers::HandlerError;
+use futures::lock::Mutex;
+use protos::{
+    BatchItemData, InsertRequest, ProtoRequest, ProtoRequestData, ProtoResponse, ProtoResponseData,
+};
+use std::collections::HashMap;
+use std::rc::Rc;
+use storage::table::{Table, TableSchema};
+
+// clients knowing schema of a table send values by column ordinal instead of repeating column
+// names with every row, schema is returned when rows of responses have to be sent by ordinal too
+pub(crate) async fn resolve_column_ordinals(
+    request: &mut ProtoRequest,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+) -> Result<Option<TableSchema>, HandlerError> {
+    if request.schema_version == 0 {
+        return Ok(None);
+    }
+    // missing table is reported when request is executed
+    let Some(table_schema) = tables
+        .lock()
+        .await
+        .get(&request.table)
+        .map(|table| table.table_schema.clone())
+    else {
+        return Ok(None);
+    };
+
+    let schema_version_matches = table_schema.schema_version() == request.schema_version;
+    let inserts = request_inserts(request);
+    if !schema_version_matches {
+        // reads fall back to names, ordinals of inserts cannot be told apart
+        return match inserts
+            .iter()
+            .any(|insert_request| !insert_request.column_values.is_empty())
+        {
+            true => Err(HandlerError::SchemaVersionMismatch {
+                table_name: table_schema.name.clone(),
+                schema_version: table_schema.schema_version(),
+            }),
+            false => Ok(None),
+        };
+    }
+
+    for insert_request in inserts {
+        let column_values = std::mem::take(&mut insert_request.column_values);
+        if column_values.len() > table_schema.columns.len() {
+            return Err(HandlerError::Client(format!(
+                "Got {} values by column, table '{}' has {} columns",
+                column_values.len(),
+                table_schema.name,
+                table_schema.columns.len()
+            )));
+        }
+        insert_request
+            .values
+            .extend(table_schema.columns.keys().cloned().zip(column_values));
+    }
+    Ok(Some(table_schema))
+}
+
+fn request_inserts(request: &mut ProtoRequest) -> Vec<&mut InsertRequest> {
+    match request.data.as_mut() {
+        Some(ProtoRequestData::Insert(insert_request)) => vec![insert_request],
+        Some(ProtoRequestData::Batch(batch_request)) => batch_request
+            .items
+            .iter_mut()
+            .filter_map(|batch_item| match batch_item.item.as_mut() {
+                Some(BatchItemData::Insert(insert_request)) => Some(insert_request),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+// columns missing from a row are sent as null
+pub(crate) fn send_rows_by_ordinal(proto_response: &mut ProtoResponse, table_schema: &TableSchema) {
+    let get_responses = match proto_response.data.as_mut() {
+        Some(ProtoResponseData::Get(get_response)) => vec![get_response],
+        Some(ProtoResponseData::GetMany(get_many_response)) => {
+            get_many_response.items.iter_mut().collect()
+        }
+        Some(ProtoResponseData::QueryByIndex(query_by_index_response)) => {
+            query_by_index_response.items.iter_mut().collect()
+        }
+        _ => return,
+    };
+    for get_response in get_responses {
+        let mut values = std::mem::take(&mut get_response.values);
+        get_response.column_values = table_schema
+            .columns
+            .keys()
+            .map(|name| values.remove(name).unwrap_or_default())
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::value::Value::{Null, Varchar};
+    use protos::util::{parse_proto_from_value, parse_value_from_proto};
+    use protos::GetResponse;
+
+    #[test]
+    fn rows_are_sent_by_ordinal_of_schema_columns() {
+        let table_schema =
+            TableSchema::from_string("users>sort_key:INT32;age:INT32?;name:VARCHAR(10)").unwrap();
+        let mut get_response = GetResponse::new();
+        get_response.values.insert(
+            "name".to_string(),
+            parse_proto_from_value(Varchar("ann".to_string())),
+        );
+        let mut proto_response = ProtoResponse::new();
+        proto_response.data = Some(ProtoResponseData::Get(get_response));
+
+        send_rows_by_ordinal(&mut proto_response, &table_schema);
+        let Some(ProtoResponseData::Get(get_response)) = proto_response.data else {
+            panic!("Invalid proto response type");
+        };
+        assert!(get_response.values.is_empty());
+        let column_values: Vec<_> = get_response
+            .column_values
+            .into_iter()
+            .map(parse_value_from_proto)
+            .collect();
+        assert_eq!(column_values, vec![Null, Varchar("ann".to_string())]);
+    }
+}
@@ -1,9 +1,122 @@
+use crate::partition_load::PartitionLoad;
+use crate::table_access::TableAccess;
+use crate::write_batcher::WriteBatcher;
+use common::partition::{get_partition_thread, PartitionMap};
+use futures::channel::mpsc;
 use std::collections::HashSet;
+use std::io::ErrorKind;
+use std::sync::atomic::AtomicUsize;
+use std::sync::{Arc, RwLock};
+use storage::data_dir::DataDir;
+use storage::sstable::CompactionMessage;
+use storage::table::TableSchema;
 
 #[derive(Clone)]
 pub struct ThreadContext {
     pub partitions: HashSet<usize>,
-    pub total_number_of_partitions: usize,
+    // shared between threads and compaction thread, buckets can be reassigned at runtime
+    pub partition_map: Arc<RwLock<PartitionMap>>,
     pub current_thread_number: usize,
     pub number_of_threads: usize,
+    // shared between threads, incremented once thread finished recovery and started listening
+    pub listening_threads: Arc<AtomicUsize>,
+    pub compaction_sender: mpsc::Sender<CompactionMessage>,
+    // non-transactional single inserts go through it
+    pub write_batcher: WriteBatcher,
+    // database the context belongs to, every database has its own threads state
+    pub data_dir: DataDir,
+    // shared between threads, requests to every partition of the database
+    pub partition_load: Arc<PartitionLoad>,
+    // shared between threads, requests to every table of the database
+    pub table_access: Arc<TableAccess>,
+    // shared between threads and compaction thread, schemas change as tables are synced or dropped
+    pub table_schemas: Arc<RwLock<Vec<TableSchema>>>,
+}
+
+impl ThreadContext {
+    pub fn partition_map(&self) -> PartitionMap {
+        self.partition_map.read().unwrap().clone()
+    }
+
+    pub fn get_partition(&self, hash_key: &str) -> usize {
+        self.partition_map.read().unwrap().get_partition(hash_key)
+    }
+
+    pub fn get_partition_thread(&self, partition: usize) -> usize {
+        get_partition_thread(partition, self.number_of_threads)
+    }
+}
+
+fn num_of_partitions_file_path(data_dir: &DataDir) -> String {
+    format!("{}/num_of_partitions", data_dir.root)
+}
+
+// partition count is chosen on first boot and kept for the lifetime of data directory, as sstables
+// are indexed by partition, number of threads can change between restarts
+pub fn read_num_of_partitions(
+    configured: Option<usize>,
+    num_of_threads: usize,
+    data_dir: &DataDir,
+) -> Result<usize, String> {
+    let file_path = num_of_partitions_file_path(data_dir);
+    let persisted = match std::fs::read_to_string(&file_path) {
+        Ok(num_of_partitions) => Some(num_of_partitions.trim().parse::<usize>().map_err(|_| {
+            format!(
+                "Invalid number of partitions '{}'",
+                num_of_partitions.trim()
+            )
+        })?),
+        Err(error) if error.kind() == ErrorKind::NotFound => None,
+        Err(error) => return Err(error.to_string()),
+    };
+
+    let num_of_partitions = match (persisted, configured) {
+        (Some(persisted), Some(configured)) if persisted != configured => {
+            return Err(format!(
+                "Data directory has {} partitions, {} were configured",
+                persisted, configured
+            ))
+        }
+        (Some(persisted), _) => persisted,
+        // data directories from before partition count was kept were partitioned by threads
+        (None, configured) => configured.unwrap_or(num_of_threads),
+    };
+    if num_of_partitions < num_of_threads {
+        return Err(format!(
+            "{} partitions cannot be spread over {} threads",
+            num_of_partitions, num_of_threads
+        ));
+    }
+
+    if persisted.is_none() {
+        std::fs::write(&file_path, num_of_partitions.to_string()).map_err(|e| e.to_string())?;
+    }
+    Ok(num_of_partitions)
+}
+
+fn partition_map_file_path(data_dir: &DataDir) -> String {
+    format!("{}/partition_map", data_dir.root)
+}
+
+// map saved for different number of partitions is discarded, as partitions no longer match
+pub fn read_partition_map(num_of_partitions: usize, data_dir: &DataDir) -> PartitionMap {
+    let partition_map = std::fs::read_to_string(partition_map_file_path(data_dir))
+        .ok()
+        .and_then(|partition_map_string| PartitionMap::from_string(&partition_map_string).ok());
+
+    match partition_map {
+        Some(partition_map) if partition_map.num_of_partitions == num_of_partitions => {
+            partition_map
+        }
+        Some(_) => {
+            tracing::warn!("Discarding partition map saved for different number of partitions");
+            PartitionMap::new(num_of_partitions)
+        }
+        None => PartitionMap::new(num_of_partitions),
+    }
+}
+
+pub fn write_partition_map(partition_map: &PartitionMap, data_dir: &DataDir) -> Result<(), String> {
+    std::fs::write(partition_map_file_path(data_dir), partition_map.to_string())
+        .map_err(|e| e.to_string())
 }
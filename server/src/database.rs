@@ -0,0 +1,79 @@
+use crate::context::ThreadContext;
+use crate::thread_channels::OperationSender;
+use crate::transaction_manager::TransactionManager;
+use futures::lock::Mutex;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use storage::data_dir::DataDir;
+use storage::table::Table;
+
+// requests without database field are routed here
+pub static DEFAULT_DATABASE: &str = "";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatabaseConfig {
+    pub name: String,
+    pub data_dir: DataDir,
+    // used when data directory is created, None means one partition per thread
+    pub num_of_partitions: Option<usize>,
+}
+
+// format is "name=path,name=path", default database is always hosted under default data dir
+pub fn parse_database_configs(configs: &str) -> Result<Vec<DatabaseConfig>, String> {
+    let mut database_configs = vec![DatabaseConfig {
+        name: DEFAULT_DATABASE.to_string(),
+        data_dir: DataDir::default(),
+        num_of_partitions: None,
+    }];
+
+    for config in configs
+        .split(',')
+        .filter(|config| !config.trim().is_empty())
+    {
+        let (name, path) = config.split_once('=').ok_or(format!(
+            "Invalid database config '{}', expected name=path",
+            config
+        ))?;
+        let (name, path) = (name.trim(), path.trim());
+
+        if name.is_empty() || path.is_empty() {
+            return Err(format!(
+                "Invalid database config '{}', name and path cannot be empty",
+                config
+            ));
+        }
+        if database_configs
+            .iter()
+            .any(|database| database.name == name)
+        {
+            return Err(format!("Database '{}' is configured more than once", name));
+        }
+        if database_configs
+            .iter()
+            .any(|database| database.data_dir.root == path.trim_end_matches('/'))
+        {
+            return Err(format!(
+                "Data directory '{}' is used by more than one database",
+                path
+            ));
+        }
+
+        database_configs.push(DatabaseConfig {
+            name: name.to_string(),
+            data_dir: DataDir::new(path),
+            num_of_partitions: None,
+        });
+    }
+
+    Ok(database_configs)
+}
+
+// state of one database on a thread, databases share threads and ports but nothing else
+#[derive(Clone)]
+pub struct Database {
+    pub thread_context: ThreadContext,
+    pub senders: Vec<OperationSender>,
+    pub tables: Rc<Mutex<HashMap<String, Table>>>,
+    pub transaction_manager: Arc<Mutex<TransactionManager>>,
+}
@@ -1,92 +1,174 @@
-use crate::context::ThreadContext;
+use crate::cluster::{
+    check_cluster_role, cluster_failures, handle_raft_request, leader_hint, wait_for_majority,
+};
+use crate::column_ordinals::{resolve_column_ordinals, send_rows_by_ordinal};
+use crate::context::{write_partition_map, ThreadContext};
+use crate::database::{Database, DEFAULT_DATABASE};
+use crate::latency::{LatencyBudget, Phase, SLOW_REQUEST_THRESHOLD};
 use crate::proto_parsing::{parse_command_from_request, parse_request_from_bytes};
-use crate::thread_channels::Operation::{Delete, Get, Insert};
+use crate::replication::{check_standby, promote, replicate, standby_failures};
+use crate::server_info::describe_server;
+use crate::session::{Consistency, Session};
+use crate::table_access::AccessKind;
+use crate::telemetry::continue_client_trace;
+use crate::thread_channels::Operation::{
+    AcquireLease, Delete, Get, GetRowCount, GetSequence, Insert, ReleaseLease, RenewLease,
+};
 use crate::thread_channels::{
-    send_drop_table, send_sync_model, send_transaction_aborted, send_transaction_begun,
-    send_transaction_committed, send_transaction_prepare, Command, Operation, OperationResponse,
-    OperationSender, Response,
+    read_trace_to_proto, send_adopt_bucket, send_count_memtable_rows, send_drop_table,
+    send_kill_transaction, send_list_transactions, send_query_index, send_reference_checks,
+    send_release_bucket, send_set_read_only, send_sync_models, send_transaction_aborted,
+    send_transaction_begun, send_transaction_committed, send_transaction_prepare, Command,
+    Operation, OperationResponse, OperationSender, PartitionRows, ReferenceCheck, Response,
 };
+use crate::thread_monitor::thread_panics;
 use crate::transaction_manager::TransactionManager;
-use common::partition::get_hash_key_target_partition;
+use common::partition::{get_hash_key_bucket, PartitionMap};
+use futures::channel::oneshot;
+use futures::future::ready;
 use futures::lock::Mutex;
-use monoio::io::{AsyncReadRentExt, AsyncWriteRentExt};
+use futures::stream::{self, StreamExt};
+use futures::SinkExt;
+use monoio::io::{AsyncReadRentExt, AsyncWriteRentExt, OwnedReadHalf, OwnedWriteHalf, Splitable};
 use monoio::net::TcpStream;
-use protobuf::Message;
-use protos::util::client_error_to_proto_response;
-use protos::{ProtoResponse, ProtoResponseData, ServerError};
+use monoio::time::timeout;
+use protobuf::{Message, MessageField};
+use protos::util::{
+    client_error_to_proto_response, client_error_with_metadata_to_proto_response,
+    server_error_to_proto_response,
+};
+use protos::{ErrorCode, ProtoRequest, ProtoRequestData, ProtoResponse, ProtoResponseData};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::future::Future;
 use std::io::ErrorKind;
+use std::rc::Rc;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
-use storage::sstable::{read_row_from_sstable, SSTABLES_DIR};
-use storage::table::{drop_table, sync_model, Table, TABLE_SCHEMAS_FILE_PATH};
+use std::time::{Duration, Instant};
+use storage::auth::AuthContext;
+use storage::commit_log::ConsistencyToken;
+use storage::delete_where::{rows_to_delete, write_tombstones, DeleteWhere};
+use storage::disk_usage::{check_disk_quota, DiskQuotaExceeded};
+use storage::history::read_row_as_of;
+use storage::index::{find_unique_violation, query_index, sort_rows_by_index, IndexRange};
+use storage::log_syncer::sync_commit_logs;
+use storage::migration::{plan_migration, MigrationPlan};
+use storage::move_row::{read_visible_row, write_move, MoveRow};
+use storage::prepare_log::{decide_transaction, release_abort_decision, release_commit_decision};
+use storage::sstable::{
+    count_sstable_rows_per_partition, trace_row_read_from_sstable, CompactionMessage, ReadTrace,
+};
+use storage::statistics::get_table_statistics;
+use storage::supervisor::degraded_tables;
+use storage::table::{
+    adopt_bucket, drop_table, release_bucket, set_read_only, sync_model, sync_models, Table,
+    TableSchema,
+};
+use storage::table_options::Durability;
 use storage::transaction::Transaction;
-use storage::validation::validate_values_against_schema;
-use storage::{Row, HASH_KEY_BYTE_SIZE};
-
-pub async fn handle_tcp_stream(
-    mut stream: TcpStream,
-    thread_context: ThreadContext,
-    mut senders: Vec<OperationSender>,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
-    transaction_manager: Arc<Mutex<TransactionManager>>,
-) {
+use storage::validation::{
+    transform_values, validate_delete_where, validate_index_range, validate_move_row,
+    validate_values_against_schema,
+};
+use storage::{encode_primary_key, millis_from_epoch, Row, HASH_KEY_BYTE_SIZE};
+use tracing::{Instrument, Span};
+
+pub async fn handle_tcp_stream(mut stream: TcpStream, mut databases: HashMap<String, Database>) {
     tracing::info!("Accepting connection on thread");
 
-    let num_of_threads_bytes = (thread_context.number_of_threads as u32)
-        .to_be_bytes()
-        .to_vec();
+    let number_of_threads = databases[DEFAULT_DATABASE].thread_context.number_of_threads;
+    let num_of_threads_bytes = (number_of_threads as u32).to_be_bytes().to_vec();
 
     if let (Err(error), _) = stream.write_all(num_of_threads_bytes).await {
         tracing::error!("Failed to send num_of_threads: {}", error.to_string());
         return;
     }
 
+    let (mut reader, writer) = stream.into_split();
+    // shared by requests handled at once, every response is written whole
+    let writer = Rc::new(Mutex::new(writer));
+    let mut session = Session::default();
+    let mut requests_received: u64 = 0;
+    // requests are handled concurrently, so that a slow one does not hold up the rest, clients
+    // match responses by correlation id
     loop {
-        let response_bytes = match handle_tcp_request(
-            &mut stream,
-            &thread_context,
-            &mut senders,
-            tables.clone(),
-            transaction_manager.clone(),
-        )
-        .await
-        {
-            Ok(proto_response) => proto_response.write_to_bytes().unwrap(),
-            Err(handler_error) => match handler_error {
-                HandlerError::Client(client_error) => {
-                    tracing::warn!("Invalid request");
-
-                    let proto_response = client_error_to_proto_response(client_error);
-                    proto_response.write_to_bytes().unwrap()
+        let correlation_id = match reader.read_u64().await {
+            Ok(correlation_id) => correlation_id,
+            Err(error) => {
+                match error.kind() {
+                    ErrorKind::UnexpectedEof | ErrorKind::ConnectionReset => {
+                        tracing::warn!("Client disconnected")
+                    }
+                    _ => tracing::error!("Failed to read correlation id: {}", error),
                 }
-                HandlerError::Server(server_error) => {
-                    tracing::error!("Internal server error: {}", server_error);
-
-                    let mut server_error = ServerError::new();
-                    server_error.detail = "Internal server error".to_string();
+                return;
+            }
+        };
 
-                    let mut proto_response = ProtoResponse::new();
-                    proto_response.data = Some(ProtoResponseData::ServerError(server_error));
-                    proto_response.write_to_bytes().unwrap()
-                }
-                HandlerError::Disconnected => {
-                    tracing::warn!("Client disconnected");
-                    return;
+        session.latency = LatencyBudget::start();
+        let request_span =
+            tracing::info_span!("request", correlation_id, table = tracing::field::Empty);
+        requests_received += 1;
+        let sampled = session
+            .latency_sample_every
+            .is_some_and(|sample_every| requests_received.is_multiple_of(sample_every as u64));
+        let request = match read_tcp_request(&mut reader, &mut session, &request_span).await {
+            Ok(request) => request,
+            Err(handler_error) => match handler_error_to_proto_response(handler_error) {
+                Some(proto_response) => {
+                    let response_bytes = proto_response.write_to_bytes().unwrap();
+                    write_to_tcp(&writer, correlation_id, response_bytes).await;
+                    continue;
                 }
+                None => return,
             },
         };
-        write_to_tcp(&mut stream, response_bytes).await;
+
+        // session settings apply to requests sent after them, so they are handled before next
+        // request is read
+        if let Some(ProtoRequestData::SetSession(_)) = request.data {
+            let response = handle_request(request, &mut session, &mut databases)
+                .instrument(request_span.clone())
+                .await;
+            respond(
+                &writer,
+                correlation_id,
+                response,
+                &session,
+                sampled,
+                &request_span,
+            )
+            .await;
+            continue;
+        }
+
+        let mut request_session = session.clone();
+        let mut databases = databases.clone();
+        let writer = writer.clone();
+        monoio::spawn(async move {
+            let response = handle_request(request, &mut request_session, &mut databases)
+                .instrument(request_span.clone())
+                .await;
+            respond(
+                &writer,
+                correlation_id,
+                response,
+                &request_session,
+                sampled,
+                &request_span,
+            )
+            .await;
+        });
     }
 }
 
-async fn handle_tcp_request(
-    stream: &mut TcpStream,
-    thread_context: &ThreadContext,
-    senders: &mut Vec<OperationSender>,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
-    transaction_manager: Arc<Mutex<TransactionManager>>,
-) -> Result<ProtoResponse, HandlerError> {
-    let request_size = stream
+async fn read_tcp_request(
+    reader: &mut OwnedReadHalf<TcpStream>,
+    session: &mut Session,
+    request_span: &Span,
+) -> Result<ProtoRequest, HandlerError> {
+    let request_size = reader
         .read_u32()
         .await
         .map_err(|error| match error.kind() {
@@ -99,67 +181,585 @@ async fn handle_tcp_request(
         })?;
 
     let buffer = vec![0u8; request_size as usize];
-    let (result, mut buffer) = stream.read_exact(buffer).await;
+    let (result, mut buffer) = reader.read_exact(buffer).await;
     result.map_err(|e| HandlerError::Server(e.to_string()))?;
 
-    let request =
-        parse_request_from_bytes(&mut buffer).map_err(|e| client_error_from_string(&e))?;
+    let request = session
+        .latency
+        .measure(Phase::Parsing, || parse_request_from_bytes(&mut buffer))
+        .map_err(|e| client_error_from_string(&e))?;
+    continue_client_trace(request_span, &request);
+    request_span.record("table", request.table.as_str());
+    Ok(request)
+}
+
+async fn respond(
+    writer: &Mutex<OwnedWriteHalf<TcpStream>>,
+    correlation_id: u64,
+    response: Result<ProtoResponse, HandlerError>,
+    session: &Session,
+    sampled: bool,
+    request_span: &Span,
+) {
+    let response = match response {
+        Ok(proto_response) => Some(proto_response),
+        Err(handler_error) => handler_error_to_proto_response(handler_error),
+    };
+    let mut proto_response = match response {
+        Some(proto_response) => proto_response,
+        None => return,
+    };
+
+    if session.latency.total() >= SLOW_REQUEST_THRESHOLD {
+        tracing::warn!("Slow request {}: {}", correlation_id, session.latency);
+    }
+    if sampled {
+        proto_response.latency = MessageField::some(session.latency.to_proto());
+    }
+    if let Some(read_traces) = &session.read_traces {
+        proto_response.read_explanations = read_traces
+            .take()
+            .into_iter()
+            .map(read_trace_to_proto)
+            .collect();
+    }
+
+    let response_bytes = proto_response.write_to_bytes().unwrap();
+    write_to_tcp(writer, correlation_id, response_bytes)
+        .instrument(tracing::info_span!(parent: request_span, "respond"))
+        .await;
+}
+
+// none when client is gone and there is no one to respond to
+pub(crate) fn handler_error_to_proto_response(
+    handler_error: HandlerError,
+) -> Option<ProtoResponse> {
+    match handler_error {
+        HandlerError::Client(client_error) => {
+            tracing::warn!("Invalid request");
+
+            let proto_response =
+                client_error_to_proto_response(client_error, ErrorCode::INVALID_REQUEST);
+            Some(proto_response)
+        }
+        HandlerError::NotFound(not_found_error) => {
+            tracing::warn!("Not found: {}", not_found_error);
+
+            let proto_response =
+                client_error_to_proto_response(not_found_error, ErrorCode::NOT_FOUND);
+            Some(proto_response)
+        }
+        HandlerError::Server(server_error) => {
+            tracing::error!("Internal server error: {}", server_error);
+
+            let proto_response = server_error_to_proto_response(
+                "Internal server error".to_string(),
+                ErrorCode::INTERNAL,
+                false,
+                0,
+            );
+            Some(proto_response)
+        }
+        HandlerError::Conflict(conflict_error) => {
+            tracing::warn!("Conflict: {}", conflict_error);
+
+            let proto_response =
+                server_error_to_proto_response(conflict_error, ErrorCode::CONFLICT, true, 0);
+            Some(proto_response)
+        }
+        HandlerError::Timeout(timeout) => {
+            tracing::warn!("Request timed out after {:?}", timeout);
+
+            let proto_response = server_error_to_proto_response(
+                format!("Request timed out after {:?}", timeout),
+                ErrorCode::TIMEOUT,
+                true,
+                0,
+            );
+            Some(proto_response)
+        }
+        HandlerError::HashKeyTooLong { length, limit } => {
+            tracing::warn!("Hash key of {} bytes exceeds limit of {}", length, limit);
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                format!("Hash key cannot be longer than {} bytes", limit),
+                ErrorCode::HASH_KEY_TOO_LONG,
+                HashMap::from([
+                    ("length".to_string(), length.to_string()),
+                    ("limit".to_string(), limit.to_string()),
+                ]),
+            );
+            Some(proto_response)
+        }
+        HandlerError::SchemaMismatch { table_name, detail } => {
+            tracing::warn!("Schema mismatch: {}", detail);
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                detail,
+                ErrorCode::SCHEMA_MISMATCH,
+                HashMap::from([("table".to_string(), table_name)]),
+            );
+            Some(proto_response)
+        }
+        HandlerError::SchemaVersionMismatch {
+            table_name,
+            schema_version,
+        } => {
+            tracing::warn!(
+                "Values sent by column of another schema version of table '{}'",
+                table_name
+            );
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                format!(
+                    "Table '{}' has schema version {}, values have to be sent by name",
+                    table_name, schema_version
+                ),
+                ErrorCode::SCHEMA_VERSION_MISMATCH,
+                HashMap::from([
+                    ("table".to_string(), table_name),
+                    ("schema_version".to_string(), schema_version.to_string()),
+                ]),
+            );
+            Some(proto_response)
+        }
+        HandlerError::QuotaExceeded {
+            table_name,
+            usage,
+            limit,
+        } => {
+            tracing::warn!(
+                "Table '{}' takes {} bytes, over its quota of {}",
+                table_name,
+                usage,
+                limit
+            );
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                format!(
+                    "Table '{}' takes {} bytes, over its quota of {} bytes",
+                    table_name, usage, limit
+                ),
+                ErrorCode::QUOTA_EXCEEDED,
+                HashMap::from([
+                    ("usage".to_string(), usage.to_string()),
+                    ("limit".to_string(), limit.to_string()),
+                ]),
+            );
+            Some(proto_response)
+        }
+        HandlerError::VersionConflict {
+            table_name,
+            primary_key,
+            expected,
+            actual,
+        } => {
+            tracing::warn!("Save of '{}' expected version {}", primary_key, expected);
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                format!(
+                    "Row '{}' of table '{}' has version {}, save expected {}",
+                    primary_key, table_name, actual, expected
+                ),
+                ErrorCode::VERSION_CONFLICT,
+                HashMap::from([
+                    ("expected".to_string(), expected.to_string()),
+                    ("actual".to_string(), actual.to_string()),
+                ]),
+            );
+            Some(proto_response)
+        }
+        HandlerError::Stale(stale_error) => {
+            tracing::warn!("Stale read: {}", stale_error);
+
+            let proto_response =
+                server_error_to_proto_response(stale_error, ErrorCode::UNAVAILABLE, true, 0);
+            Some(proto_response)
+        }
+        HandlerError::WrongPartition {
+            partition,
+            partition_map,
+        } => {
+            tracing::warn!("Request sent to wrong partition, owned by {}", partition);
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                format!("Hash key is owned by partition {}", partition),
+                ErrorCode::WRONG_PARTITION,
+                HashMap::from([
+                    ("partition".to_string(), partition.to_string()),
+                    ("partition_map".to_string(), partition_map),
+                ]),
+            );
+            Some(proto_response)
+        }
+        HandlerError::NotLeader { leader } => {
+            tracing::warn!("Request rejected by server that does not lead");
+
+            let proto_response = client_error_with_metadata_to_proto_response(
+                "Server is not the leader, writes go to its leader".to_string(),
+                ErrorCode::NOT_LEADER,
+                leader
+                    .map(|leader| HashMap::from([("leader".to_string(), leader)]))
+                    .unwrap_or_default(),
+            );
+            Some(proto_response)
+        }
+        HandlerError::Disconnected => {
+            tracing::warn!("Client disconnected");
+            None
+        }
+    }
+}
+
+pub(crate) async fn handle_request(
+    mut request: ProtoRequest,
+    session: &mut Session,
+    databases: &mut HashMap<String, Database>,
+) -> Result<ProtoResponse, HandlerError> {
+    // server wide, so answered before request gets to any one database
+    if let Some(ProtoRequestData::DescribeServer(_)) = request.data {
+        return Ok(Response::DescribeServer(describe_server(databases)).to_proto_response());
+    }
+    if let Some(ProtoRequestData::Promote(_)) = request.data {
+        return Ok(Response::Promote(promote(databases)?).to_proto_response());
+    }
+    // sent by other nodes of the cluster, on behalf of the node rather than a client
+    if let Some(ProtoRequestData::RaftVote(_) | ProtoRequestData::RaftAppend(_)) = request.data {
+        return Ok(handle_raft_request(request, databases)
+            .await?
+            .to_proto_response());
+    }
+    request.table = session.resolve_table(request.table);
+
+    let database = databases.get_mut(&request.database).ok_or_else(|| {
+        HandlerError::NotFound(format!("Database named '{}' not found", request.database))
+    })?;
+    let thread_context = &database.thread_context;
+    let senders = &mut database.senders;
+    let tables = database.tables.clone();
+    let transaction_manager = database.transaction_manager.clone();
 
+    let database_name = request.database.clone();
     let transaction_id = request.transaction_id;
-    let command = parse_command_from_request(request).map_err(|e| client_error_from_string(&e))?;
+    let begins_transaction = request.begins_transaction;
+    session.read_traces = request.explain.then(RefCell::default);
+    let min_consistency_token = std::mem::take(&mut request.min_consistency_token);
+    let ordinal_schema = resolve_column_ordinals(&mut request, &tables).await?;
+    let request_bytes = request.compute_size();
+    let mut command = tracing::info_span!("parse")
+        .in_scope(|| {
+            session
+                .latency
+                .measure(Phase::Parsing, || parse_command_from_request(request))
+        })
+        .map_err(|e| client_error_from_string(&e))?;
+    transform_inserted_values(&mut command, &tables).await;
+    // recorded once request is answered, so that its latency counts towards partitions it touched
+    let _partition_load = thread_context
+        .partition_load
+        .track(command_partitions(&command, thread_context));
+    let mut table_access = command_access(&command).map(|(table_name, kind)| {
+        thread_context
+            .table_access
+            .track(table_name, kind, request_bytes)
+    });
 
-    let proto_response = match command {
+    check_standby(&command, transaction_id)?;
+    let majority_term = check_cluster_role(&database_name, &command, transaction_id)?;
+    if session.read_as_of.is_some() {
+        check_readable_as_of(&command, transaction_id)?;
+    }
+    if !min_consistency_token.is_empty() {
+        if let Command::Single(Get(_, _), table_name)
+        | Command::GetMany(_, _, table_name)
+        | Command::GetManyConsistent(_, _, table_name) = &command
+        {
+            check_consistency_token(&min_consistency_token, table_name, &tables, thread_context)
+                .await?;
+        }
+    }
+    if begins_transaction && (transaction_id.is_none() || !matches!(command, Command::Single(_, _)))
+    {
+        return Err(HandlerError::Client(
+            "Transaction can only be begun by a single operation with transaction id".to_string(),
+        ));
+    }
+
+    let mut proto_response = match command {
         Command::Single(operation, table_name) => {
-            validate_hash_key_size(&operation.hash_key())?;
-            validate_hash_key_partition(&operation.hash_key(), thread_context)?;
+            let validation = async {
+                let hash_key_size = table_hash_key_size(&table_name, &tables).await;
+                validate_hash_key_size(&operation.hash_key(), hash_key_size)?;
+                validate_hash_key_partition(&operation.hash_key(), thread_context)?;
+                validate_sort_key(&operation).map_err(HandlerError::Client)?;
+                check_writable(std::slice::from_ref(&operation), &table_name, &tables).await?;
+                check_references(
+                    std::slice::from_ref(&operation),
+                    &table_name,
+                    &tables,
+                    thread_context,
+                )
+                .await
+            };
+            let reference_checks = session
+                .latency
+                .measure_async(Phase::Validation, validation)
+                .instrument(tracing::info_span!("validate"))
+                .await?;
+            // begun only once request is known to be on the owning thread
+            if begins_transaction {
+                begin_transaction(
+                    transaction_id,
+                    &transaction_manager,
+                    senders,
+                    thread_context,
+                )
+                .await?;
+            }
+            let written_hash_key = match (&operation, transaction_id) {
+                (Insert(hash_key, _, _) | Delete(hash_key, _), None) => Some(hash_key.clone()),
+                _ => None,
+            };
 
-            let operation_response = handle_operation(
-                operation,
-                table_name,
+            let operation_response = match (operation, transaction_id) {
+                (Insert(hash_key, sort_key, values), None) => {
+                    let row = Row::new(hash_key, sort_key, values);
+                    let started_at = Instant::now();
+                    let (sequence, batch_latency) = thread_context
+                        .write_batcher
+                        .insert(table_name.clone(), row, session.auth.clone())
+                        .instrument(tracing::info_span!("storage"))
+                        .await?;
+                    let budget = &session.latency;
+                    budget.record(Phase::Memtable, batch_latency.memtable);
+                    budget.record(Phase::CommitLog, batch_latency.commit_log);
+                    budget.record(
+                        Phase::Queueing,
+                        started_at
+                            .elapsed()
+                            .saturating_sub(batch_latency.memtable + batch_latency.commit_log),
+                    );
+                    sync_for_durable_session(session, &table_name, &tables).await?;
+                    OperationResponse::Insert(sequence)
+                }
+                (operation, None) if session.read_as_of.is_some() => {
+                    let responses = read_as_of(
+                        vec![operation],
+                        &table_name,
+                        &tables,
+                        thread_context,
+                        session,
+                    );
+                    let mut responses = with_session_timeout(session, responses).await?;
+                    responses.remove(0)
+                }
+                (operation, _) => {
+                    let is_read = matches!(operation, Get(_, _));
+                    let operation_response = handle_operation(
+                        operation,
+                        table_name.clone(),
+                        tables.clone(),
+                        transaction_id,
+                        transaction_manager.clone(),
+                        thread_context,
+                        session,
+                    );
+                    match is_read {
+                        true => with_session_timeout(session, operation_response).await?,
+                        false => operation_response.await?,
+                    }
+                }
+            };
+            send_reference_checks(reference_checks, senders);
+            let mut proto_response = Response::Single(operation_response).to_proto_response();
+            if let Some(hash_key) = written_hash_key {
+                let token =
+                    consistency_token(&hash_key, &table_name, &tables, thread_context).await;
+                match &mut proto_response.data {
+                    Some(ProtoResponseData::Insert(insert_response)) => {
+                        insert_response.consistency_token = token
+                    }
+                    Some(ProtoResponseData::Delete(delete_response)) => {
+                        delete_response.consistency_token = token
+                    }
+                    _ => {}
+                }
+            }
+            proto_response
+        }
+        // reads as of past time already see a single point in time
+        Command::GetMany(operations, page, table_name)
+        | Command::GetManyConsistent(operations, page, table_name)
+            if session.read_as_of.is_some() =>
+        {
+            let responses = read_as_of(operations, &table_name, &tables, thread_context, session);
+            let responses = with_session_timeout(session, responses).await?;
+            Response::GetMany(responses, page).to_proto_response()
+        }
+        Command::GetMany(operations, page, table_name) if transaction_id.is_none() => {
+            let responses = with_session_timeout(
+                session,
+                get_many(
+                    operations,
+                    table_name,
+                    tables.clone(),
+                    thread_context,
+                    session,
+                ),
+            )
+            .await?;
+            Response::GetMany(responses, page).to_proto_response()
+        }
+        Command::GetMany(operations, page, table_name) => {
+            let responses = with_session_timeout(
+                session,
+                handle_operations(
+                    operations,
+                    table_name,
+                    tables.clone(),
+                    transaction_id,
+                    transaction_manager.clone(),
+                    thread_context,
+                    session,
+                ),
+            )
+            .await?;
+            Response::GetMany(responses, page).to_proto_response()
+        }
+        Command::GetManyConsistent(operations, page, table_name) => {
+            validate_single_partition(&operations, thread_context)?;
+            let responses = with_session_timeout(
+                session,
+                handle_operations_atomically(
+                    operations,
+                    table_name,
+                    tables.clone(),
+                    transaction_id,
+                    transaction_manager.clone(),
+                    thread_context,
+                    session,
+                ),
+            )
+            .await?;
+            Response::GetMany(responses, page).to_proto_response()
+        }
+        Command::Batch(operations, table_name) => {
+            let validation = async {
+                check_writable(&operations, &table_name, &tables).await?;
+                check_references(&operations, &table_name, &tables, thread_context).await
+            };
+            let reference_checks = session
+                .latency
+                .measure_async(Phase::Validation, validation)
+                .instrument(tracing::info_span!("validate"))
+                .await?;
+            let responses = handle_operations_atomically(
+                operations,
+                table_name.clone(),
                 tables.clone(),
                 transaction_id,
                 transaction_manager.clone(),
                 thread_context,
+                session,
             )
             .await?;
-            Response::Single(operation_response).to_proto_response()
+            if transaction_id.is_none() {
+                sync_for_durable_session(session, &table_name, &tables).await?;
+            }
+            send_reference_checks(reference_checks, senders);
+            Response::Batch(responses).to_proto_response()
         }
-        Command::GetMany(operations, table_name) => {
-            let responses = handle_operations(
-                operations,
-                table_name,
+        Command::DeleteWhere(delete_where, table_name) => {
+            if transaction_id.is_some() {
+                return Err(HandlerError::Client(
+                    "Rows cannot be deleted by range within a transaction".to_string(),
+                ));
+            }
+            let hash_key_size = table_hash_key_size(&table_name, &tables).await;
+            validate_hash_key_size(&delete_where.hash_key, hash_key_size)?;
+            validate_hash_key_partition(&delete_where.hash_key, thread_context)?;
+            let partition = thread_context.get_partition(&delete_where.hash_key);
+            let deleted = delete_rows_where(
+                &delete_where,
+                partition,
+                &table_name,
+                tables.clone(),
+                &session.auth,
+            )
+            .instrument(tracing::info_span!("storage"))
+            .await?;
+            sync_for_durable_session(session, &table_name, &tables).await?;
+            Response::DeleteWhere(deleted).to_proto_response()
+        }
+        Command::MoveRow(move_row, table_name) => {
+            if transaction_id.is_some() {
+                return Err(HandlerError::Client(
+                    "Rows are moved within a transaction by an insert and a delete".to_string(),
+                ));
+            }
+            let hash_key_size = table_hash_key_size(&table_name, &tables).await;
+            validate_hash_key_size(&move_row.hash_key, hash_key_size)?;
+            validate_hash_key_size(&move_row.new_hash_key, hash_key_size)?;
+            validate_hash_key_partition(&move_row.hash_key, thread_context)?;
+            let partition = thread_context.get_partition(&move_row.hash_key);
+            if thread_context.get_partition(&move_row.new_hash_key) != partition {
+                return Err(HandlerError::Client(format!(
+                    "Hash key '{}' is not in partition of '{}', move it by a transaction instead",
+                    move_row.new_hash_key, move_row.hash_key
+                )));
+            }
+            let moved = move_row_to_new_key(
+                &move_row,
+                partition,
+                &table_name,
                 tables.clone(),
-                transaction_id,
-                transaction_manager.clone(),
                 thread_context,
+                &session.auth,
             )
+            .instrument(tracing::info_span!("storage"))
             .await?;
-            Response::GetMany(responses).to_proto_response()
+            sync_for_durable_session(session, &table_name, &tables).await?;
+            Response::MoveRow(moved).to_proto_response()
         }
-        Command::Batch(operations, table_name) => {
-            let responses = handle_operations(
-                operations,
-                table_name,
+        Command::Save(operation, version, table_name) => {
+            if transaction_id.is_some() {
+                return Err(HandlerError::Client(
+                    "Rows are not saved by version within a transaction, it checks their versions itself"
+                        .to_string(),
+                ));
+            }
+            let hash_key_size = table_hash_key_size(&table_name, &tables).await;
+            validate_hash_key_size(&operation.hash_key(), hash_key_size)?;
+            validate_hash_key_partition(&operation.hash_key(), thread_context)?;
+            validate_sort_key(&operation).map_err(HandlerError::Client)?;
+            let operations = std::slice::from_ref(&operation);
+            check_writable(operations, &table_name, &tables).await?;
+            let reference_checks =
+                check_references(operations, &table_name, &tables, thread_context).await?;
+            let partition = thread_context.get_partition(&operation.hash_key());
+            let saved_version = save_row(
+                operation,
+                version,
+                partition,
+                &table_name,
                 tables.clone(),
-                transaction_id,
-                transaction_manager.clone(),
                 thread_context,
+                &session.auth,
             )
+            .instrument(tracing::info_span!("storage"))
             .await?;
-            Response::Batch(responses).to_proto_response()
+            sync_for_durable_session(session, &table_name, &tables).await?;
+            send_reference_checks(reference_checks, senders);
+            Response::Save(saved_version).to_proto_response()
         }
         Command::BeginTransaction => {
-            let mut manager = transaction_manager.lock().await;
-
-            let transaction_id = manager.add_coordinated();
-            manager.add(transaction_id);
-
-            send_transaction_begun(
+            let transaction_id = begin_transaction(
                 transaction_id,
+                &transaction_manager,
                 senders,
-                thread_context.current_thread_number,
+                thread_context,
             )
-            .await;
+            .await?;
             Response::Transaction(transaction_id).to_proto_response()
         }
         Command::CommitTransaction => {
@@ -169,15 +769,35 @@ async fn handle_tcp_request(
             ))?;
 
             manager.remove_coordinated(transaction_id)?;
-            let transaction = manager.remove(transaction_id).unwrap();
+            // aborted on this thread by a drop of a table it touched
+            let Some(mut transaction) = manager.remove(transaction_id) else {
+                send_transaction_aborted(
+                    transaction_id,
+                    senders,
+                    thread_context.current_thread_number,
+                )
+                .await;
+                return Err(HandlerError::Conflict(format!(
+                    "Transaction with id '{}' failed to commit and got aborted",
+                    transaction_id
+                )));
+            };
 
+            // every thread keeps keys of the transaction reserved from prepare until commit or abort
             if !(send_transaction_prepare(
                 transaction_id,
                 senders,
                 thread_context.current_thread_number,
             )
             .await
-                && transaction.can_commit(tables.clone()).await)
+                && transaction
+                    .prepare(
+                        tables.clone(),
+                        &thread_context.data_dir,
+                        &thread_context.partitions,
+                        &thread_context.partition_map(),
+                    )
+                    .await)
             {
                 send_transaction_aborted(
                     transaction_id,
@@ -186,18 +806,60 @@ async fn handle_tcp_request(
                 )
                 .await;
 
-                return Err(HandlerError::Server(format!(
+                return Err(HandlerError::Conflict(format!(
                     "Transaction with id '{}' failed to commit and got aborted",
                     transaction_id
                 )));
             }
 
+            // participants left in doubt by a crash commit only if the decision outlived it, those
+            // which gave up waiting meanwhile decided to abort first
+            match decide_transaction(&thread_context.data_dir, transaction_id, true).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    send_transaction_aborted(
+                        transaction_id,
+                        senders,
+                        thread_context.current_thread_number,
+                    )
+                    .await;
+                    transaction.release_intents(tables.clone()).await;
+                    release_abort_decision(&thread_context.data_dir, transaction_id);
+                    return Err(HandlerError::Conflict(format!(
+                        "Transaction with id '{}' got aborted by a participant which gave up waiting for it",
+                        transaction_id
+                    )));
+                }
+                Err(error) => {
+                    send_transaction_aborted(
+                        transaction_id,
+                        senders,
+                        thread_context.current_thread_number,
+                    )
+                    .await;
+                    transaction.release_intents(tables.clone()).await;
+                    return Err(HandlerError::Server(format!(
+                        "Transaction with id '{}' got aborted, failed to persist its commit: {}",
+                        transaction_id, error
+                    )));
+                }
+            }
+
             send_transaction_committed(
                 transaction_id,
                 senders,
                 thread_context.current_thread_number,
             )
             .await;
+            // writes done on coordinator's own partitions are part of the transaction too
+            transaction
+                .commit(
+                    tables.clone(),
+                    &thread_context.partitions,
+                    &thread_context.partition_map(),
+                )
+                .await;
+            release_commit_decision(&thread_context.data_dir, transaction_id);
             Response::Transaction(transaction_id).to_proto_response()
         }
         Command::AbortTransaction => {
@@ -218,32 +880,240 @@ async fn handle_tcp_request(
 
             Response::Transaction(transaction_id).to_proto_response()
         }
+        Command::ListTransactions => {
+            let transaction_infos = transaction_manager
+                .lock()
+                .await
+                .list(thread_context.current_thread_number);
+            let transaction_infos = send_list_transactions(
+                transaction_infos,
+                senders,
+                thread_context.current_thread_number,
+            )
+            .await;
+
+            Response::ListTransactions(transaction_infos).to_proto_response()
+        }
+        Command::KillTransaction(transaction_id) => {
+            let killed = transaction_manager
+                .lock()
+                .await
+                .kill(transaction_id, tables.clone())
+                .await;
+            let killed = send_kill_transaction(
+                transaction_id,
+                senders,
+                thread_context.current_thread_number,
+            )
+            .await
+                || killed;
+
+            if !killed {
+                return Err(HandlerError::NotFound(format!(
+                    "Transaction with id '{}' does not exist",
+                    transaction_id
+                )));
+            }
+            tracing::warn!("Transaction with id '{}' got killed", transaction_id);
+
+            Response::Transaction(transaction_id).to_proto_response()
+        }
         Command::SyncModel(schema_string) => {
             sync_model(
                 schema_string.clone(),
                 tables.clone(),
                 &thread_context.partitions,
-                TABLE_SCHEMAS_FILE_PATH,
+                &thread_context.data_dir,
             )
             .await
             .map_err(|e| HandlerError::Client(e))?;
-            send_sync_model(schema_string, senders, thread_context.current_thread_number).await;
+            share_table_schemas(&tables, thread_context).await;
+            send_sync_models(
+                vec![schema_string],
+                senders,
+                thread_context.current_thread_number,
+            )
+            .await;
             Response::SyncModel.to_proto_response()
         }
-        Command::DropTable(table_name) => {
-            drop_table(
-                table_name.clone(),
+        Command::SyncModels(schema_strings) => {
+            sync_models(
+                schema_strings.clone(),
                 tables.clone(),
-                TABLE_SCHEMAS_FILE_PATH,
-                SSTABLES_DIR,
+                &thread_context.partitions,
+                &thread_context.data_dir,
             )
             .await
-            .map_err(|e| HandlerError::Client(e))?;
+            .map_err(HandlerError::Client)?;
+            share_table_schemas(&tables, thread_context).await;
+            send_sync_models(
+                schema_strings,
+                senders,
+                thread_context.current_thread_number,
+            )
+            .await;
+            Response::SyncModel.to_proto_response()
+        }
+        Command::DropTable(table_name) => {
+            {
+                // transactions cannot prepare while table is dropped, as that holds the manager lock
+                let mut manager = transaction_manager.lock().await;
+                abort_transactions_touching(&table_name, &mut manager, tables.clone()).await;
+                drop_table(
+                    table_name.clone(),
+                    tables.clone(),
+                    &thread_context.data_dir.table_schemas_file,
+                    &thread_context.data_dir.sstables,
+                )
+                .await
+                .map_err(HandlerError::Client)?;
+            }
+            share_table_schemas(&tables, thread_context).await;
             send_drop_table(table_name, senders, thread_context.current_thread_number).await;
             Response::DropTable.to_proto_response()
         }
+        Command::PlanMigration(schema_string) => {
+            let table_schema =
+                TableSchema::from_string(&schema_string).map_err(HandlerError::Client)?;
+
+            let tables = tables.lock().await;
+            let migration_plan = match tables.get(&table_schema.name) {
+                Some(table) => plan_migration(&table.table_schema, &table_schema)
+                    .map_err(HandlerError::Client)?,
+                None => MigrationPlan::create_table(&table_schema),
+            };
+
+            let steps = migration_plan
+                .steps
+                .iter()
+                .map(|step| step.to_string())
+                .collect();
+            Response::PlanMigration(steps).to_proto_response()
+        }
+        Command::Health => {
+            let degraded = thread_panics()
+                .iter()
+                .map(|thread_panic| thread_panic.to_string())
+                .chain(degraded_tables().iter().map(|failure| failure.to_string()))
+                .chain(standby_failures())
+                .chain(cluster_failures())
+                .collect();
+            Response::Health(degraded).to_proto_response()
+        }
+        Command::Ready => Response::Ready(check_readiness(thread_context)).to_proto_response(),
+        Command::PartitionMap => Response::PartitionMap(
+            thread_context.partition_map().to_string(),
+            leader_hint(&database_name),
+        )
+        .to_proto_response(),
+        Command::MigrateBucket(hash_key, target_partition) => {
+            let partition_map =
+                migrate_bucket(&hash_key, target_partition, thread_context, senders, tables)
+                    .await?;
+            Response::PartitionMap(partition_map.to_string(), leader_hint(&database_name))
+                .to_proto_response()
+        }
+        Command::DescribeTable(table_name) => {
+            let tables = tables.lock().await;
+            let table = tables
+                .get(&table_name)
+                .ok_or(HandlerError::NotFound(format!(
+                    "Table named '{}' not found",
+                    table_name
+                )))?;
+
+            let statistics = get_table_statistics(&table_name, &table.data_dir.sstables);
+            Response::DescribeTable(table.table_schema.to_string(), statistics).to_proto_response()
+        }
+        Command::SetSession(new_session) => {
+            *session = Session {
+                auth: session.auth.clone(),
+                latency: session.latency.clone(),
+                ..new_session
+            };
+            Response::Session.to_proto_response()
+        }
+        Command::SetReadOnly(table_name, read_only) => {
+            set_read_only(
+                &table_name,
+                read_only,
+                tables.clone(),
+                &thread_context.data_dir.table_schemas_file,
+            )
+            .await
+            .map_err(HandlerError::NotFound)?;
+            share_table_schemas(&tables, thread_context).await;
+            send_set_read_only(
+                table_name.clone(),
+                read_only,
+                senders,
+                thread_context.current_thread_number,
+            )
+            .await;
+            tracing::warn!(
+                "Table '{}' set to {}",
+                table_name,
+                if read_only { "read-only" } else { "writable" }
+            );
+            Response::SetReadOnly.to_proto_response()
+        }
+        Command::Compaction(update) => {
+            let (sender, receiver) = oneshot::channel();
+            thread_context
+                .compaction_sender
+                .clone()
+                .send(CompactionMessage::Inspect(update, sender))
+                .await
+                .map_err(|e| HandlerError::Server(e.to_string()))?;
+            let compaction_state = receiver
+                .await
+                .map_err(|e| HandlerError::Server(e.to_string()))?
+                .map_err(HandlerError::Client)?;
+            Response::Compaction(compaction_state).to_proto_response()
+        }
+        Command::KeyDistribution(table_name) => {
+            let partitions_rows =
+                count_rows_per_partition(&table_name, thread_context, senders, tables).await?;
+            Response::KeyDistribution(partitions_rows).to_proto_response()
+        }
+        Command::QueryByIndex(index_range, page, table_name) => {
+            let rows = query_by_index(
+                &index_range,
+                &table_name,
+                thread_context,
+                senders,
+                tables.clone(),
+                &session.auth,
+            )
+            .instrument(tracing::info_span!("storage"));
+            let rows = with_session_timeout(session, rows).await?;
+            let (rows, continuation_token) = page.cut(rows, &index_range.column);
+            Response::QueryByIndex(rows, continuation_token).to_proto_response()
+        }
+        // load of every partition is shared by threads, so any thread answers for all of them
+        Command::PartitionLoad => {
+            Response::PartitionLoad(thread_context.partition_load.report()).to_proto_response()
+        }
+        // access of every table is shared by threads as well
+        Command::TableAccess => {
+            Response::TableAccess(thread_context.table_access.report()).to_proto_response()
+        }
+        Command::Replicate(epoch, sequence) => replicate(epoch, sequence, thread_context, &tables)
+            .await?
+            .to_proto_response(),
     };
 
+    // write is acknowledged once records it appended to commit logs reached a majority of nodes
+    if let Some(term) = majority_term {
+        wait_for_majority(&database_name, term, Instant::now()).await?;
+    }
+    if let Some(table_schema) = ordinal_schema {
+        send_rows_by_ordinal(&mut proto_response, &table_schema);
+    }
+    if let Some(table_access) = table_access.as_mut() {
+        table_access.respond(&proto_response);
+    }
+
     tracing::info!(
         "Request on thread {} handled",
         thread_context.current_thread_number
@@ -251,29 +1121,495 @@ async fn handle_tcp_request(
     Ok(proto_response)
 }
 
-fn validate_hash_key_size(hash_key: &str) -> Result<(), HandlerError> {
-    if hash_key.as_bytes().len() > HASH_KEY_BYTE_SIZE {
+// rows are found and deleted under a single tables lock, so that no write to the range gets in
+// between, rows or keys of the range reserved by a prepared transaction fail the whole delete
+async fn delete_rows_where(
+    delete_where: &DeleteWhere,
+    partition: usize,
+    table_name: &str,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    auth: &AuthContext,
+) -> Result<u64, HandlerError> {
+    let mut tables = tables.lock().await;
+    let table = tables
+        .get_mut(table_name)
+        .ok_or(HandlerError::NotFound(format!(
+            "Table named '{}' not found",
+            table_name
+        )))?;
+    if table.table_schema.options.read_only {
         return Err(HandlerError::Client(format!(
-            "Hash key cannot be longer than {} bytes",
-            HASH_KEY_BYTE_SIZE
+            "Table '{}' is read-only",
+            table_name
         )));
     }
-
-    Ok(())
-}
-
-fn validate_hash_key_partition(
-    hash_key: &str,
-    thread_context: &ThreadContext,
-) -> Result<(), HandlerError> {
-    if !thread_context
-        .partitions
-        .contains(&get_hash_key_target_partition(
-            hash_key,
-            thread_context.total_number_of_partitions,
-        ))
+    if table.table_schema.options.append_only {
+        return Err(append_only_delete_error(table_name));
+    }
+    if !table
+        .table_schema
+        .options
+        .allows_row(&delete_where.hash_key, auth)
     {
-        return Err(HandlerError::Client("Invalid partition".to_string()));
+        return Err(row_policy_error(&delete_where.hash_key, table_name));
+    }
+    validate_delete_where(delete_where, &table.table_schema).map_err(HandlerError::Client)?;
+
+    let rows = rows_to_delete(table, delete_where, partition).await;
+    for row in &rows {
+        check_write_intent(table, &row.primary_key)?;
+    }
+    // range tombstone would shadow rows of the range a prepared transaction commits later too
+    if let Some(range_tombstone) = delete_where.range_tombstone(millis_from_epoch()) {
+        for primary_key in table.write_intents.keys() {
+            if range_tombstone.contains(primary_key) {
+                check_write_intent(table, primary_key)?;
+            }
+        }
+    }
+    let deleted = rows.len() as u64;
+    write_tombstones(table, delete_where, rows, auth).await;
+    Ok(deleted)
+}
+
+// both keys are read and written under a single tables lock, so that no write gets in between,
+// an existing row under the new key is not overwritten
+async fn move_row_to_new_key(
+    move_row: &MoveRow,
+    partition: usize,
+    table_name: &str,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+    auth: &AuthContext,
+) -> Result<bool, HandlerError> {
+    let mut tables = tables.lock().await;
+    let table = tables
+        .get_mut(table_name)
+        .ok_or(HandlerError::NotFound(format!(
+            "Table named '{}' not found",
+            table_name
+        )))?;
+    if table.table_schema.options.read_only {
+        return Err(HandlerError::Client(format!(
+            "Table '{}' is read-only",
+            table_name
+        )));
+    }
+    if table.table_schema.options.append_only {
+        return Err(append_only_delete_error(table_name));
+    }
+    for hash_key in [&move_row.hash_key, &move_row.new_hash_key] {
+        if !table.table_schema.options.allows_row(hash_key, auth) {
+            return Err(row_policy_error(hash_key, table_name));
+        }
+    }
+    validate_move_row(move_row, &table.table_schema).map_err(HandlerError::Client)?;
+    let (primary_key, new_primary_key) = (move_row.primary_key(), move_row.new_primary_key());
+    if primary_key == new_primary_key {
+        return Err(HandlerError::Client(
+            "Row cannot be moved to its own key".to_string(),
+        ));
+    }
+
+    let Some(row) = read_visible_row(table, &primary_key, partition).await else {
+        return Ok(false);
+    };
+    if read_visible_row(table, &new_primary_key, partition)
+        .await
+        .is_some()
+    {
+        return Err(HandlerError::Client(format!(
+            "Row '{}' of table '{}' already exists, it is not overwritten by a move",
+            new_primary_key, table_name
+        )));
+    }
+    check_write_intent(table, &primary_key)?;
+    check_write_intent(table, &new_primary_key)?;
+
+    write_move(table, row, move_row, &thread_context.partition_map(), auth).await;
+    table.evict_least_recently_used().await;
+    let synced = match table.table_schema.options.durability {
+        Durability::Always => sync_commit_logs(&table.commit_log).await,
+        Durability::Periodic => Ok(()),
+    };
+    table
+        .flush_memtable_if_needed(&thread_context.partitions, &thread_context.partition_map())
+        .await;
+    // row is moved either way, client only learns it may not be durable
+    synced.map_err(HandlerError::Server)?;
+    Ok(true)
+}
+
+// stored row is read and overwritten under a single tables lock, so that no write gets in between,
+// returns version of saved row
+async fn save_row(
+    operation: Operation,
+    expected_version: u64,
+    partition: usize,
+    table_name: &str,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+    auth: &AuthContext,
+) -> Result<u64, HandlerError> {
+    let Insert(hash_key, sort_key, values) = operation else {
+        return Err(HandlerError::Client(
+            "Only inserts are saved by version".to_string(),
+        ));
+    };
+    let mut tables = tables.lock().await;
+    let table = tables
+        .get_mut(table_name)
+        .ok_or(HandlerError::NotFound(format!(
+            "Table named '{}' not found",
+            table_name
+        )))?;
+    if !table.table_schema.options.allows_row(&hash_key, auth) {
+        return Err(row_policy_error(&hash_key, table_name));
+    }
+    validate_values_against_schema(&sort_key, &values, &table.table_schema).map_err(|detail| {
+        HandlerError::SchemaMismatch {
+            table_name: table_name.to_string(),
+            detail,
+        }
+    })?;
+
+    let mut row = Row::new(hash_key, sort_key, values);
+    let primary_key = row.primary_key.clone();
+    let version = read_visible_row(table, &primary_key, partition)
+        .await
+        .map_or(0, |row| row.visible_version());
+    if version != expected_version {
+        return Err(HandlerError::VersionConflict {
+            table_name: table_name.to_string(),
+            primary_key,
+            expected: expected_version,
+            actual: version,
+        });
+    }
+    check_write_intent(table, &primary_key)?;
+    check_unique_values(table, &row, thread_context).await?;
+
+    // memtable bumps version of rows it still has, rows kept only in sstables get it from here
+    row.version = (version + 1) as u32;
+    table.commit_log.lock().await.write_insert(&row).await;
+    table
+        .count_insert(&row, &thread_context.partition_map())
+        .await;
+    table.track_access(&row);
+    table.apply_insert(row, auth);
+    let saved_version = table
+        .memtable
+        .get(&primary_key)
+        .map_or(version + 1, |row| row.visible_version());
+
+    table.evict_least_recently_used().await;
+    let synced = match table.table_schema.options.durability {
+        Durability::Always => sync_commit_logs(&table.commit_log).await,
+        Durability::Periodic => Ok(()),
+    };
+    table
+        .flush_memtable_if_needed(&thread_context.partitions, &thread_context.partition_map())
+        .await;
+    // row is saved either way, client only learns it may not be durable
+    synced.map_err(HandlerError::Server)?;
+    Ok(saved_version)
+}
+
+// id is generated unless client chose it, e.g. to begin transaction along with its first operation
+async fn begin_transaction(
+    transaction_id: Option<u64>,
+    transaction_manager: &Mutex<TransactionManager>,
+    senders: &mut Vec<OperationSender>,
+    thread_context: &ThreadContext,
+) -> Result<u64, HandlerError> {
+    let mut manager = transaction_manager.lock().await;
+
+    let transaction_id = match transaction_id {
+        Some(transaction_id) => {
+            if !manager.add_coordinated_with(transaction_id)? {
+                return Ok(transaction_id);
+            }
+            transaction_id
+        }
+        None => manager.add_coordinated(),
+    };
+    manager.add(transaction_id);
+
+    send_transaction_begun(
+        transaction_id,
+        senders,
+        thread_context.current_thread_number,
+    )
+    .await;
+    Ok(transaction_id)
+}
+
+// counts rows of every partition held in memtables and sstables, so that skewed hash keys can be spotted
+async fn count_rows_per_partition(
+    table_name: &str,
+    thread_context: &ThreadContext,
+    senders: &mut [OperationSender],
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+) -> Result<Vec<PartitionRows>, HandlerError> {
+    let (mut memtable_rows, row_byte_size) = {
+        let tables = tables.lock().await;
+        let table = tables
+            .get(table_name)
+            .ok_or(HandlerError::NotFound(format!(
+                "Table named '{}' not found",
+                table_name
+            )))?;
+        (
+            table
+                .memtable
+                .count_rows_per_partition(&thread_context.partition_map()),
+            table.table_schema.row_byte_size(),
+        )
+    };
+
+    for (partition, rows) in
+        send_count_memtable_rows(table_name, senders, thread_context.current_thread_number).await
+    {
+        *memtable_rows.entry(partition).or_insert(0) += rows;
+    }
+    let mut sstable_rows =
+        count_sstable_rows_per_partition(table_name, &thread_context.data_dir.sstables).await;
+
+    let num_of_partitions = thread_context.partition_map().num_of_partitions;
+    Ok((0..num_of_partitions)
+        .map(|partition| {
+            let memtable_rows = memtable_rows.remove(&partition).unwrap_or(0);
+            let sstable_rows = sstable_rows.remove(&partition).unwrap_or(0);
+            PartitionRows {
+                partition,
+                memtable_rows,
+                sstable_rows,
+                bytes: (memtable_rows + sstable_rows) * row_byte_size,
+            }
+        })
+        .collect())
+}
+
+// tables of every thread have the same schemas, compaction thread gets them from the one changing them
+pub(crate) async fn share_table_schemas(
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+) {
+    let table_schemas = tables
+        .lock()
+        .await
+        .values()
+        .map(|table| table.table_schema.clone())
+        .collect();
+    *thread_context.table_schemas.write().unwrap() = table_schemas;
+}
+
+// every thread reads rows of partitions it owns, rows outside of row policy are left out
+async fn query_by_index(
+    index_range: &IndexRange,
+    table_name: &str,
+    thread_context: &ThreadContext,
+    senders: &mut [OperationSender],
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    auth: &AuthContext,
+) -> Result<Vec<Row>, HandlerError> {
+    let (mut rows, table_options) = {
+        let tables = tables.lock().await;
+        let table = tables
+            .get(table_name)
+            .ok_or(HandlerError::NotFound(format!(
+                "Table named '{}' not found",
+                table_name
+            )))?;
+        validate_index_range(index_range, &table.table_schema).map_err(HandlerError::Client)?;
+        let rows = query_index(
+            table,
+            index_range,
+            &thread_context.partitions,
+            &thread_context.partition_map(),
+        )
+        .await;
+        (rows, table.table_schema.options.clone())
+    };
+
+    rows.extend(
+        send_query_index(
+            table_name,
+            index_range,
+            senders,
+            thread_context.current_thread_number,
+        )
+        .await,
+    );
+    rows.retain(|row| table_options.allows_row(&row.hash_key, auth));
+    sort_rows_by_index(&mut rows, &index_range.column);
+    Ok(rows)
+}
+
+fn command_partitions(command: &Command, thread_context: &ThreadContext) -> Vec<usize> {
+    match command {
+        Command::Single(operation, _) => vec![thread_context.get_partition(&operation.hash_key())],
+        Command::GetMany(operations, _, _)
+        | Command::GetManyConsistent(operations, _, _)
+        | Command::Batch(operations, _) => operations
+            .iter()
+            .map(|operation| thread_context.get_partition(&operation.hash_key()))
+            .collect(),
+        Command::DeleteWhere(delete_where, _) => {
+            vec![thread_context.get_partition(&delete_where.hash_key)]
+        }
+        Command::MoveRow(move_row, _) => vec![thread_context.get_partition(&move_row.hash_key)],
+        Command::Save(operation, _, _) => vec![thread_context.get_partition(&operation.hash_key())],
+        _ => Vec::new(),
+    }
+}
+
+// reads are sized by their response and writes by their request, sequences and leases count as writes
+fn command_access(command: &Command) -> Option<(String, AccessKind)> {
+    match command {
+        Command::Single(Get(_, _) | GetRowCount(_), table_name)
+        | Command::GetMany(_, _, table_name)
+        | Command::GetManyConsistent(_, _, table_name)
+        | Command::QueryByIndex(_, _, table_name) => Some((table_name.clone(), AccessKind::Read)),
+        Command::Single(_, table_name)
+        | Command::Batch(_, table_name)
+        | Command::DeleteWhere(_, table_name)
+        | Command::MoveRow(_, table_name)
+        | Command::Save(_, _, table_name) => Some((table_name.clone(), AccessKind::Write)),
+        _ => None,
+    }
+}
+
+fn check_readiness(thread_context: &ThreadContext) -> Result<(), String> {
+    let listening_threads = thread_context.listening_threads.load(Ordering::SeqCst);
+    if listening_threads < thread_context.number_of_threads {
+        return Err(format!(
+            "{} out of {} threads are listening",
+            listening_threads, thread_context.number_of_threads
+        ));
+    }
+
+    let probe_file_path = format!(
+        "{}/ready_probe_{}",
+        thread_context.data_dir.root, thread_context.current_thread_number
+    );
+    std::fs::write(&probe_file_path, b"ready")
+        .and_then(|_| std::fs::remove_file(&probe_file_path))
+        .map_err(|error| format!("Data directory is not writable: {}", error))
+}
+
+// Bucket of given hash key is moved to target partition. Partition map is updated first, so that new
+// requests get routed to target partition, then every thread flushes its memtables and hands over
+// sequences, leases and row counts of the bucket. After sstables get reindexed with the new map,
+// target partition adopts handed over state. Until then, reads on target partition may miss rows
+// of the bucket stored in sstables.
+async fn migrate_bucket(
+    hash_key: &str,
+    target_partition: usize,
+    thread_context: &ThreadContext,
+    senders: &mut [OperationSender],
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+) -> Result<PartitionMap, HandlerError> {
+    let bucket = get_hash_key_bucket(hash_key);
+    let partition_map = {
+        let mut partition_map = thread_context.partition_map.write().unwrap();
+        partition_map
+            .reassign_bucket(bucket, target_partition)
+            .map_err(HandlerError::Client)?;
+        partition_map.clone()
+    };
+    write_partition_map(&partition_map, &thread_context.data_dir).map_err(HandlerError::Server)?;
+
+    let mut released_states =
+        send_release_bucket(bucket, senders, thread_context.current_thread_number).await;
+    released_states.extend(
+        release_bucket(
+            bucket,
+            tables.clone(),
+            &thread_context.partitions,
+            &partition_map,
+        )
+        .await,
+    );
+
+    let table_schemas: Vec<_> = tables
+        .lock()
+        .await
+        .values()
+        .map(|table| table.table_schema.clone())
+        .collect();
+    let mut compaction_sender = thread_context.compaction_sender.clone();
+    for table_schema in table_schemas {
+        let (sender, receiver) = oneshot::channel();
+        compaction_sender
+            .send(CompactionMessage::RewritePartitions(
+                Box::new(table_schema),
+                sender,
+            ))
+            .await
+            .map_err(|e| HandlerError::Server(e.to_string()))?;
+        receiver
+            .await
+            .map_err(|e| HandlerError::Server(e.to_string()))?;
+    }
+
+    let target_thread_number = thread_context.get_partition_thread(target_partition);
+    if target_thread_number == thread_context.current_thread_number {
+        adopt_bucket(
+            released_states,
+            tables,
+            &thread_context.partitions,
+            &partition_map,
+        )
+        .await;
+    } else {
+        send_adopt_bucket(released_states, senders, target_thread_number).await;
+    }
+
+    Ok(partition_map)
+}
+
+fn validate_hash_key_size(hash_key: &str, limit: usize) -> Result<(), HandlerError> {
+    let length = hash_key.len();
+    if length > limit {
+        return Err(HandlerError::HashKeyTooLong { length, limit });
+    }
+
+    Ok(())
+}
+
+async fn table_hash_key_size(
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+) -> usize {
+    // missing table is reported when operation is executed
+    tables
+        .lock()
+        .await
+        .get(table_name)
+        .map(|table| table.table_schema.hash_key_size())
+        .unwrap_or(HASH_KEY_BYTE_SIZE)
+}
+
+fn validate_sort_key(operation: &Operation) -> Result<(), String> {
+    match operation.sort_key() {
+        Some(sort_key) => sort_key.validate_key(),
+        None => Ok(()),
+    }
+}
+
+fn validate_hash_key_partition(
+    hash_key: &str,
+    thread_context: &ThreadContext,
+) -> Result<(), HandlerError> {
+    let partition_map = thread_context.partition_map.read().unwrap();
+    let partition = partition_map.get_partition(hash_key);
+    if !thread_context.partitions.contains(&partition) {
+        // client refreshes its partition map from response and retries on the owner
+        return Err(HandlerError::WrongPartition {
+            partition,
+            partition_map: partition_map.to_string(),
+        });
     }
 
     Ok(())
@@ -282,10 +1618,313 @@ fn validate_hash_key_partition(
 #[derive(Debug, Clone)]
 pub enum HandlerError {
     Client(String),
+    NotFound(String),
     Server(String),
+    // transaction lost to concurrent write, retrying it may succeed
+    Conflict(String),
+    Timeout(Duration),
+    // hash key is owned by another partition, its partition map is sent along
+    WrongPartition {
+        partition: usize,
+        partition_map: String,
+    },
+    HashKeyTooLong {
+        length: usize,
+        limit: usize,
+    },
+    // values were sent by column ordinal of another schema version than the table has
+    SchemaVersionMismatch {
+        table_name: String,
+        schema_version: u64,
+    },
+    // values do not match table schema, client drops schema of the table it has cached
+    SchemaMismatch {
+        table_name: String,
+        detail: String,
+    },
+    QuotaExceeded {
+        table_name: String,
+        usage: u64,
+        limit: u64,
+    },
+    // row has another version than save expected, 0 if there is none, retrying needs a new read
+    VersionConflict {
+        table_name: String,
+        primary_key: String,
+        expected: u64,
+        actual: u64,
+    },
+    // state is older than client's consistency token, e.g. writes were lost in a crash
+    Stale(String),
+    // server is a standby or a cluster follower, address of its leader is sent along if known
+    NotLeader {
+        leader: Option<String>,
+    },
     Disconnected,
 }
 
+// inserted values are normalized before anything else looks at them, validation included
+async fn transform_inserted_values(
+    command: &mut Command,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+) {
+    let (operations, table_name) = match command {
+        Command::Single(operation @ Insert(_, _, _), table_name)
+        | Command::Save(operation, _, table_name) => (std::slice::from_mut(operation), table_name),
+        Command::Batch(operations, table_name) => (operations.as_mut_slice(), table_name),
+        _ => return,
+    };
+
+    let tables = tables.lock().await;
+    let table_schema = match tables.get(table_name.as_str()) {
+        Some(table) => &table.table_schema,
+        None => return,
+    };
+    for operation in operations {
+        if let Insert(_, _, values) = operation {
+            transform_values(values, table_schema);
+        }
+    }
+}
+
+async fn check_writable(
+    operations: &[Operation],
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+) -> Result<(), HandlerError> {
+    let has_writes = operations
+        .iter()
+        .any(|operation| matches!(operation, Insert(_, _, _) | Delete(_, _)));
+    if !has_writes {
+        return Ok(());
+    }
+
+    let tables = tables.lock().await;
+    let table = match tables.get(table_name) {
+        Some(table) => table,
+        None => return Ok(()),
+    };
+    if table.table_schema.options.read_only {
+        return Err(HandlerError::Client(format!(
+            "Table '{}' is read-only",
+            table_name
+        )));
+    }
+
+    // deletes are let through, they are how table gets back under its quota
+    let has_inserts = operations
+        .iter()
+        .any(|operation| matches!(operation, Insert(_, _, _)));
+    if has_inserts {
+        check_disk_quota(&table.table_schema, &table.data_dir.sstables).map_err(
+            |DiskQuotaExceeded { usage, limit }| HandlerError::QuotaExceeded {
+                table_name: table_name.to_string(),
+                usage,
+                limit,
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+// references to hash keys of this partition are checked before the write,
+// the rest is returned to be checked by owning partitions once the write is done
+async fn check_references(
+    operations: &[Operation],
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+) -> Result<Vec<(usize, ReferenceCheck)>, HandlerError> {
+    let tables = tables.lock().await;
+    let table_schema = match tables.get(table_name) {
+        Some(table) if !table.table_schema.options.references.is_empty() => &table.table_schema,
+        _ => return Ok(Vec::new()),
+    };
+
+    let mut remote_reference_checks = Vec::new();
+    for operation in operations {
+        let values = match operation {
+            Insert(_, _, values) => values,
+            _ => continue,
+        };
+
+        for (reference, hash_key) in table_schema.options.referenced_hash_keys(values) {
+            let thread_number =
+                thread_context.get_partition_thread(thread_context.get_partition(&hash_key));
+            let reference_check = ReferenceCheck {
+                table_name: table_name.to_string(),
+                column: reference.column.clone(),
+                referenced_table: reference.table.clone(),
+                hash_key,
+            };
+
+            if thread_number != thread_context.current_thread_number {
+                remote_reference_checks.push((thread_number, reference_check));
+            } else if let Some(violation) = find_reference_violation(&tables, &reference_check) {
+                return Err(HandlerError::Client(violation));
+            }
+        }
+    }
+
+    Ok(remote_reference_checks)
+}
+
+pub fn find_reference_violation(
+    tables: &HashMap<String, Table>,
+    reference_check: &ReferenceCheck,
+) -> Option<String> {
+    let referenced_table = match tables.get(&reference_check.referenced_table) {
+        Some(referenced_table) => referenced_table,
+        None => {
+            return Some(format!(
+                "Table '{}' referenced by column '{}' of table '{}' does not exist",
+                reference_check.referenced_table,
+                reference_check.column,
+                reference_check.table_name
+            ))
+        }
+    };
+
+    match referenced_table.row_count(&reference_check.hash_key) {
+        Ok(0) => Some(format!(
+            "Value '{}' of column '{}' of table '{}' references missing hash key in table '{}'",
+            reference_check.hash_key,
+            reference_check.column,
+            reference_check.table_name,
+            reference_check.referenced_table
+        )),
+        Ok(_) => None,
+        Err(error) => Some(error),
+    }
+}
+
+// only reads are abandoned on timeout, writes may already be logged
+async fn with_session_timeout<T>(
+    session: &Session,
+    future: impl Future<Output = Result<T, HandlerError>>,
+) -> Result<T, HandlerError> {
+    match session.timeout {
+        Some(duration) => timeout(duration, future)
+            .await
+            .map_err(|_| HandlerError::Timeout(duration))?,
+        None => future.await,
+    }
+}
+
+// tables syncing on every write were already synced before the write got acknowledged
+async fn sync_for_durable_session(
+    session: &Session,
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+) -> Result<(), HandlerError> {
+    if session.consistency != Consistency::Durable {
+        return Ok(());
+    }
+
+    let commit_log = match tables.lock().await.get(table_name) {
+        Some(table) if table.table_schema.options.durability == Durability::Periodic => {
+            table.commit_log.clone()
+        }
+        _ => return Ok(()),
+    };
+    sync_commit_logs(&commit_log)
+        .await
+        .map_err(HandlerError::Server)
+}
+
+// taken after the write got applied, so that a read reaching it sees the write
+async fn consistency_token(
+    hash_key: &str,
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+) -> String {
+    let commit_log = match tables.lock().await.get(table_name) {
+        Some(table) => table.commit_log.clone(),
+        None => return String::new(),
+    };
+    let (segment, offset) = commit_log.lock().await.position();
+
+    ConsistencyToken {
+        partition: thread_context.get_partition(hash_key),
+        segment,
+        offset,
+    }
+    .to_string()
+}
+
+// tokens of partitions owned by other threads cannot be compared with commit logs of this one,
+// every acknowledged write of a single node is visible there anyway
+async fn check_consistency_token(
+    token: &str,
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+) -> Result<(), HandlerError> {
+    let token = ConsistencyToken::parse(token).map_err(HandlerError::Client)?;
+    if !thread_context.partitions.contains(&token.partition) {
+        return Ok(());
+    }
+
+    let commit_log = match tables.lock().await.get(table_name) {
+        Some(table) => table.commit_log.clone(),
+        None => return Ok(()),
+    };
+    let position = commit_log.lock().await.position();
+    match token.is_reached_by(position) {
+        true => Ok(()),
+        false => Err(HandlerError::Stale(format!(
+            "Table '{}' has not reached consistency token '{}' yet",
+            table_name, token
+        ))),
+    }
+}
+
+pub fn row_policy_error(hash_key: &str, table_name: &str) -> HandlerError {
+    HandlerError::Client(format!(
+        "Hash key '{}' is not accessible under row policy of table '{}'",
+        hash_key, table_name
+    ))
+}
+
+// rows of append-only tables are only ever removed by retention
+fn append_only_delete_error(table_name: &str) -> HandlerError {
+    HandlerError::Client(format!(
+        "Rows of append-only table '{}' cannot be deleted, they expire by retention",
+        table_name
+    ))
+}
+
+// writes outside of transactions are rejected rather than applied between prepare and commit
+// of transaction which reserved the key, caller can retry once it is done
+pub fn check_write_intent(table: &Table, primary_key: &str) -> Result<(), HandlerError> {
+    match table.write_intent_holder(primary_key) {
+        Some(transaction_id) => Err(HandlerError::Conflict(format!(
+            "Row '{}' of table '{}' is being committed by transaction with id '{}'",
+            primary_key, table.table_schema.name, transaction_id
+        ))),
+        None => Ok(()),
+    }
+}
+
+// checked with tables locked until the row is written, so that no other insert takes the value meanwhile
+pub async fn check_unique_values(
+    table: &Table,
+    row: &Row,
+    thread_context: &ThreadContext,
+) -> Result<(), HandlerError> {
+    if table.table_schema.options.unique_indexes.is_empty() {
+        return Ok(());
+    }
+    let partition_map = thread_context.partition_map();
+    let partition = partition_map.get_partition(&row.hash_key);
+    match find_unique_violation(table, row, partition, &partition_map).await {
+        Some(violation) => Err(HandlerError::Conflict(violation)),
+        None => Ok(()),
+    }
+}
+
 fn client_error_from_string(error: &str) -> HandlerError {
     tracing::warn!("Invalid request: {}", error);
     HandlerError::Client(format!("Invalid request: {}", error))
@@ -294,20 +1933,26 @@ fn client_error_from_string(error: &str) -> HandlerError {
 async fn handle_operation(
     operation: Operation,
     table_name: String,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
     transaction_id: Option<u64>,
     transaction_manager: Arc<Mutex<TransactionManager>>,
     thread_context: &ThreadContext,
+    session: &Session,
 ) -> Result<OperationResponse, HandlerError> {
-    let mut manager = transaction_manager.lock().await;
+    let budget = &session.latency;
+    let mut manager = budget
+        .measure_async(Phase::Queueing, transaction_manager.lock())
+        .await;
     let mut transaction = get_transaction_by_id(transaction_id, &mut manager)?;
 
+    let mut tables = budget.measure_async(Phase::Queueing, tables.lock()).await;
     execute_operation(
         operation,
         table_name,
-        tables.clone(),
+        &mut tables,
         &mut transaction,
         thread_context,
+        session,
     )
     .await
 }
@@ -315,27 +1960,187 @@ async fn handle_operation(
 async fn handle_operations(
     operations: Vec<Operation>,
     table_name: String,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
     transaction_id: Option<u64>,
     transaction_manager: Arc<Mutex<TransactionManager>>,
     thread_context: &ThreadContext,
+    session: &Session,
 ) -> Result<Vec<OperationResponse>, HandlerError> {
-    let mut manager = transaction_manager.lock().await;
+    let budget = &session.latency;
+    let validation = validate_operations(&operations, &table_name, tables.clone(), thread_context);
+    budget.measure_async(Phase::Validation, validation).await?;
+
+    let mut manager = budget
+        .measure_async(Phase::Queueing, transaction_manager.lock())
+        .await;
     let mut transaction = get_transaction_by_id(transaction_id, &mut manager)?;
 
     let mut responses = Vec::with_capacity(operations.len());
 
+    // every operation takes tables lock on its own, so that long reads do not hold up writes
     for operation in operations {
-        validate_hash_key_size(&operation.hash_key())?;
-        validate_hash_key_partition(&operation.hash_key(), thread_context)?;
+        let mut tables = budget.measure_async(Phase::Queueing, tables.lock()).await;
+        responses.push(
+            execute_operation(
+                operation,
+                table_name.clone(),
+                &mut tables,
+                &mut transaction,
+                thread_context,
+                session,
+            )
+            .await?,
+        );
+    }
+
+    Ok(responses)
+}
+
+// sstables of that many gets of a get_many are read at once
+static GET_MANY_CONCURRENT_READS: usize = 16;
+
+struct PendingGet {
+    primary_key: String,
+    partition: usize,
+    // not found in memtable and visible under row policy
+    read_sstables: bool,
+    row: Option<Row>,
+    read_trace: ReadTrace,
+}
+
+// gets outside of transactions read sstables concurrently and without tables lock held, rows
+// are looked up in memtable and tracked under the lock, as single gets are
+#[tracing::instrument(name = "storage", skip_all)]
+async fn get_many(
+    operations: Vec<Operation>,
+    table_name: String,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+    session: &Session,
+) -> Result<Vec<OperationResponse>, HandlerError> {
+    let budget = &session.latency;
+    let validation = validate_operations(&operations, &table_name, tables.clone(), thread_context);
+    budget.measure_async(Phase::Validation, validation).await?;
+
+    let (table_schema, sstable_dir, mut pending_gets) = {
+        let tables = budget.measure_async(Phase::Queueing, tables.lock()).await;
+        let table = tables
+            .get(&table_name)
+            .ok_or(HandlerError::NotFound(format!(
+                "Table named '{}' not found",
+                table_name
+            )))?;
+
+        let mut pending_gets = Vec::with_capacity(operations.len());
+        for operation in operations {
+            let Get(hash_key, sort_key) = operation else {
+                return Err(HandlerError::Client(
+                    "Only gets can be part of get_many".to_string(),
+                ));
+            };
+            let primary_key = encode_primary_key(&hash_key, &sort_key);
+            let mut read_trace = ReadTrace {
+                primary_key: primary_key.clone(),
+                ..ReadTrace::default()
+            };
+            // rows outside of row policy look missing
+            let visible = table
+                .table_schema
+                .options
+                .allows_row(&hash_key, &session.auth);
 
+            let started_at = Instant::now();
+            let row = match visible {
+                true => table.memtable.get_entry(&primary_key),
+                false => None,
+            };
+            read_trace.memtable_time = started_at.elapsed();
+            read_trace.memtable_hit = row.is_some();
+            budget.record(Phase::Memtable, read_trace.memtable_time);
+
+            pending_gets.push(PendingGet {
+                primary_key,
+                partition: thread_context.get_partition(&hash_key),
+                read_sstables: visible && row.is_none(),
+                row,
+                read_trace,
+            });
+        }
+        (
+            table.table_schema.clone(),
+            table.data_dir.sstables.clone(),
+            pending_gets,
+        )
+    };
+
+    // rows are written back in place, so responses keep order of gets
+    let sstable_reads = stream::iter(pending_gets.iter_mut())
+        .filter(|pending_get| ready(pending_get.read_sstables))
+        .map(|pending_get| async {
+            pending_get.row = trace_row_read_from_sstable(
+                &pending_get.primary_key,
+                pending_get.partition,
+                &table_schema,
+                &sstable_dir,
+                &mut pending_get.read_trace,
+            )
+            .await;
+        })
+        .buffer_unordered(GET_MANY_CONCURRENT_READS)
+        .collect::<()>();
+    budget.measure_async(Phase::SSTableIo, sstable_reads).await;
+
+    let mut tables = budget.measure_async(Phase::Queueing, tables.lock()).await;
+    let now = millis_from_epoch();
+    let mut responses = Vec::with_capacity(pending_gets.len());
+    for pending_get in pending_gets {
+        // deleted and expired rows stay on disk until compacted or purged, but are not visible
+        let row = pending_get.row.filter(|row| {
+            !row.marked_for_deletion && !table_schema.options.is_row_expired(row, now)
+        });
+        if let (Some(row), Some(table)) = (&row, tables.get_mut(&table_name)) {
+            table.track_access(row);
+        }
+        if let Some(read_traces) = &session.read_traces {
+            read_traces.borrow_mut().push(pending_get.read_trace);
+        }
+        responses.push(OperationResponse::Get(row));
+    }
+
+    Ok(responses)
+}
+
+// operations are executed under a single tables lock, so that no other request of the thread
+// observes or changes tables in between, e.g. consistent reads never see half of a batch
+async fn handle_operations_atomically(
+    operations: Vec<Operation>,
+    table_name: String,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    transaction_id: Option<u64>,
+    transaction_manager: Arc<Mutex<TransactionManager>>,
+    thread_context: &ThreadContext,
+    session: &Session,
+) -> Result<Vec<OperationResponse>, HandlerError> {
+    let budget = &session.latency;
+    let validation = validate_operations(&operations, &table_name, tables.clone(), thread_context);
+    budget.measure_async(Phase::Validation, validation).await?;
+
+    let mut manager = budget
+        .measure_async(Phase::Queueing, transaction_manager.lock())
+        .await;
+    let mut transaction = get_transaction_by_id(transaction_id, &mut manager)?;
+
+    let mut tables = budget.measure_async(Phase::Queueing, tables.lock()).await;
+    let mut responses = Vec::with_capacity(operations.len());
+    for operation in operations {
         responses.push(
             execute_operation(
                 operation,
                 table_name.clone(),
-                tables.clone(),
+                &mut tables,
                 &mut transaction,
                 thread_context,
+                session,
             )
             .await?,
         );
@@ -344,37 +2149,203 @@ async fn handle_operations(
     Ok(responses)
 }
 
+// other partitions of the thread are not locked out, so only keys of one partition are consistent
+fn validate_single_partition(
+    operations: &[Operation],
+    thread_context: &ThreadContext,
+) -> Result<(), HandlerError> {
+    let partition_map = thread_context.partition_map.read().unwrap();
+    let mut partitions = operations
+        .iter()
+        .map(|operation| partition_map.get_partition(&operation.hash_key()));
+    let first_partition = partitions.next();
+    if partitions.any(|partition| Some(partition) != first_partition) {
+        return Err(HandlerError::Client(
+            "Keys of consistent get_many have to be in a single partition".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+// sessions reading as of past time are for inspecting data, so they cannot change it
+fn check_readable_as_of(
+    command: &Command,
+    transaction_id: Option<u64>,
+) -> Result<(), HandlerError> {
+    match command {
+        Command::Single(Get(_, _), _)
+        | Command::GetMany(_, _, _)
+        | Command::GetManyConsistent(_, _, _)
+            if transaction_id.is_none() =>
+        {
+            Ok(())
+        }
+        Command::Single(_, _)
+        | Command::GetMany(_, _, _)
+        | Command::GetManyConsistent(_, _, _)
+        | Command::Batch(_, _)
+        | Command::DeleteWhere(_, _)
+        | Command::MoveRow(_, _)
+        | Command::Save(_, _, _)
+        | Command::BeginTransaction => Err(HandlerError::Client(
+            "Session reads as of past time, writes and transactions are not allowed".to_string(),
+        )),
+        Command::QueryByIndex(_, _, _) => Err(HandlerError::Client(
+            "Session reads as of past time, queries by index only read current rows".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+// gets of sessions reading as of past time, rows outside of row policy are not found
+#[tracing::instrument(name = "storage", skip_all)]
+async fn read_as_of(
+    operations: Vec<Operation>,
+    table_name: &str,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+    session: &Session,
+) -> Result<Vec<OperationResponse>, HandlerError> {
+    validate_operations(&operations, table_name, tables.clone(), thread_context).await?;
+    let as_of = session.read_as_of.unwrap_or_else(millis_from_epoch);
+
+    let tables = tables.lock().await;
+    let table = tables
+        .get(table_name)
+        .ok_or(HandlerError::NotFound(format!(
+            "Table named '{}' not found",
+            table_name
+        )))?;
+
+    let mut responses = Vec::with_capacity(operations.len());
+    for operation in operations {
+        let row = match operation {
+            Get(hash_key, _)
+                if !table
+                    .table_schema
+                    .options
+                    .allows_row(&hash_key, &session.auth) =>
+            {
+                None
+            }
+            Get(hash_key, sort_key) => {
+                let primary_key = encode_primary_key(&hash_key, &sort_key);
+                let partition = thread_context.get_partition(&hash_key);
+                read_row_as_of(&primary_key, partition, table, as_of).await
+            }
+            _ => None,
+        };
+        responses.push(OperationResponse::Get(row));
+    }
+
+    Ok(responses)
+}
+
+#[tracing::instrument(name = "validate", skip_all)]
+async fn validate_operations(
+    operations: &[Operation],
+    table_name: &str,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+) -> Result<(), HandlerError> {
+    let tables = tables.lock().await;
+    let table = tables
+        .get(table_name)
+        .ok_or(HandlerError::NotFound(format!(
+            "Table named '{}' not found",
+            table_name
+        )))?;
+
+    for (item_number, operation) in operations.iter().enumerate() {
+        validate_hash_key_size(&operation.hash_key(), table.table_schema.hash_key_size())?;
+        validate_hash_key_partition(&operation.hash_key(), thread_context)?;
+        validate_sort_key(operation)
+            .map_err(|e| HandlerError::Client(format!("Item {}: {}", item_number, e)))?;
+
+        if let Insert(_, sort_key, values) = operation {
+            validate_values_against_schema(sort_key, values, &table.table_schema).map_err(|e| {
+                HandlerError::SchemaMismatch {
+                    table_name: table.table_schema.name.clone(),
+                    detail: format!("Item {}: {}", item_number, e),
+                }
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(name = "storage", skip_all)]
 async fn execute_operation(
     operation: Operation,
     table_name: String,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
+    tables: &mut HashMap<String, Table>,
     transaction: &mut Option<&mut Transaction>,
     thread_context: &ThreadContext,
+    session: &Session,
 ) -> Result<OperationResponse, HandlerError> {
-    let mut tables = tables.lock().await;
+    let auth = &session.auth;
+    let budget = &session.latency;
     let table = tables
         .get_mut(&table_name)
-        .ok_or(HandlerError::Client(format!(
+        .ok_or(HandlerError::NotFound(format!(
             "Table named '{}' not found",
             table_name
         )))?;
 
+    // rows outside of row policy look missing to reads, the rest of operations on them is rejected
+    if !table
+        .table_schema
+        .options
+        .allows_row(&operation.hash_key(), auth)
+    {
+        match operation {
+            Get(_, _) => return Ok(OperationResponse::Get(None)),
+            Insert(_, _, _) | Delete(_, _) | GetSequence(_) | GetRowCount(_) => {
+                return Err(row_policy_error(&operation.hash_key(), &table_name))
+            }
+            AcquireLease(_, _) | RenewLease(_, _, _) | ReleaseLease(_, _) => {}
+        }
+    }
+
     match operation {
         Get(hash_key, sort_key) => {
-            let primary_key = format!("{}:{}", hash_key, sort_key);
-            let mut val = table.memtable.get(&primary_key).cloned();
+            let primary_key = encode_primary_key(&hash_key, &sort_key);
+            let mut read_trace = ReadTrace {
+                primary_key: primary_key.clone(),
+                ..ReadTrace::default()
+            };
+            let started_at = Instant::now();
+            let mut val = table.memtable.get_entry(&primary_key);
+            read_trace.memtable_time = started_at.elapsed();
+            read_trace.memtable_hit = val.is_some();
+            budget.record(Phase::Memtable, read_trace.memtable_time);
 
             if val.is_none() {
-                val = read_row_from_sstable(
+                let sstable_read = trace_row_read_from_sstable(
                     &primary_key,
-                    get_hash_key_target_partition(
-                        &hash_key,
-                        thread_context.total_number_of_partitions,
-                    ),
-                    &table,
-                    SSTABLES_DIR,
-                )
-                .await;
+                    thread_context.get_partition(&hash_key),
+                    &table.table_schema,
+                    &table.data_dir.sstables,
+                    &mut read_trace,
+                );
+                val = budget.measure_async(Phase::SSTableIo, sstable_read).await;
+            }
+            if let Some(read_traces) = &session.read_traces {
+                read_traces.borrow_mut().push(read_trace);
+            }
+
+            // deleted and expired rows stay on disk until compacted or purged, but are not visible
+            let val = val.filter(|row| {
+                !row.marked_for_deletion
+                    && !table
+                        .table_schema
+                        .options
+                        .is_row_expired(row, millis_from_epoch())
+            });
+            if let Some(row) = &val {
+                table.track_access(row);
             }
 
             if let Some(transaction) = transaction.as_mut() {
@@ -384,49 +2355,133 @@ async fn execute_operation(
             Ok(OperationResponse::Get(val))
         }
         Insert(hash_key, sort_key, values) => {
-            validate_values_against_schema(&sort_key, &values, &table.table_schema)
-                .map_err(|e| HandlerError::Client(e))?;
+            validate_values_against_schema(&sort_key, &values, &table.table_schema).map_err(
+                |detail| HandlerError::SchemaMismatch {
+                    table_name: table.table_schema.name.clone(),
+                    detail,
+                },
+            )?;
 
-            let row = Row::new(hash_key, sort_key, values);
+            let mut row = Row::new(hash_key, sort_key, values);
+            let sequence = table.assign_append_sort_key(&mut row).await;
+            check_unique_values(table, &row, thread_context).await?;
 
             match transaction {
-                Some(transaction) => transaction.insert(row, &table),
+                Some(transaction) => transaction.insert(row, table, auth),
+                None if table.is_duplicate_insert(&row) => table.track_access(&row),
                 None => {
+                    check_write_intent(table, &row.primary_key)?;
+                    let started_at = Instant::now();
                     {
                         let mut commit_log = table.commit_log.lock().await;
                         commit_log.write_insert(&row).await;
                     }
+                    budget.record(Phase::CommitLog, started_at.elapsed());
 
-                    table.memtable.insert(row, false);
-                    if table.memtable.max_size_reached() {
-                        table
-                            .flush_memtable_to_disk(
-                                &thread_context.partitions,
-                                thread_context.total_number_of_partitions,
-                            )
-                            .await;
-                    }
+                    let started_at = Instant::now();
+                    table
+                        .count_insert(&row, &thread_context.partition_map())
+                        .await;
+                    table.track_access(&row);
+
+                    table.apply_insert(row, auth);
+                    table.evict_least_recently_used().await;
+                    budget.record(Phase::Memtable, started_at.elapsed());
+                    let synced = match table.table_schema.options.durability {
+                        Durability::Always => {
+                            let sync = sync_commit_logs(&table.commit_log);
+                            budget.measure_async(Phase::CommitLog, sync).await
+                        }
+                        Durability::Periodic => Ok(()),
+                    };
+                    table
+                        .flush_memtable_if_needed(
+                            &thread_context.partitions,
+                            &thread_context.partition_map(),
+                        )
+                        .await;
+                    // row is applied either way, client only learns it may not be durable
+                    synced.map_err(HandlerError::Server)?;
                 }
             }
 
-            Ok(OperationResponse::Insert)
+            Ok(OperationResponse::Insert(sequence))
+        }
+        Delete(_, _) if table.table_schema.options.append_only => {
+            Err(append_only_delete_error(&table_name))
         }
         Delete(hash_key, sort_key) => {
-            let primary_key = format!("{}:{}", hash_key, sort_key);
+            let primary_key = encode_primary_key(&hash_key, &sort_key);
 
             let val = match transaction {
-                Some(transaction) => transaction.delete(primary_key, &table),
-                None => table.memtable.delete(&primary_key, None),
+                Some(transaction) => {
+                    let partition = thread_context.get_partition(&hash_key);
+                    transaction
+                        .delete(primary_key, partition, table, auth)
+                        .await
+                }
+                None => {
+                    check_write_intent(table, &primary_key)?;
+                    let deleted = budget.measure(Phase::Memtable, || {
+                        table.memtable.delete(&primary_key, None)
+                    });
+                    if deleted {
+                        {
+                            let mut commit_log = table.commit_log.lock().await;
+                            commit_log.write_delete(&primary_key).await;
+                        }
+                        table.on_row_deleted(&hash_key, &primary_key, auth).await;
+                    }
+                    deleted
+                }
             };
 
             Ok(OperationResponse::Delete(val))
         }
+        GetSequence(hash_key) => {
+            let sequence = table
+                .next_sequence(&hash_key)
+                .await
+                .map_err(HandlerError::Server)?;
+            Ok(OperationResponse::Sequence(sequence))
+        }
+        AcquireLease(name, ttl_millis) => {
+            let lease = table
+                .acquire_lease(&name, ttl_millis)
+                .await
+                .map_err(HandlerError::Client)?;
+            Ok(OperationResponse::Lease(lease))
+        }
+        RenewLease(name, version, ttl_millis) => {
+            let lease = table
+                .renew_lease(&name, version, ttl_millis)
+                .await
+                .map_err(HandlerError::Client)?;
+            Ok(OperationResponse::Lease(lease))
+        }
+        ReleaseLease(name, version) => {
+            let lease = table
+                .release_lease(&name, version)
+                .await
+                .map_err(HandlerError::Client)?;
+            Ok(OperationResponse::Lease(lease))
+        }
+        GetRowCount(hash_key) => {
+            let count = table.row_count(&hash_key).map_err(HandlerError::Client)?;
+            Ok(OperationResponse::RowCount(count))
+        }
     }
 }
 
-async fn write_to_tcp(stream: &mut TcpStream, bytes: Vec<u8>) {
-    let response_size_prefix = (bytes.len() as u32).to_be_bytes().to_vec();
-    if let (Err(error), _) = stream.write_all(response_size_prefix).await {
+async fn write_to_tcp(
+    writer: &Mutex<OwnedWriteHalf<TcpStream>>,
+    correlation_id: u64,
+    bytes: Vec<u8>,
+) {
+    let mut response_prefix = correlation_id.to_be_bytes().to_vec();
+    response_prefix.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    let mut stream = writer.lock().await;
+    if let (Err(error), _) = stream.write_all(response_prefix).await {
         tracing::error!("Couldn't write response to tcp, {}", error);
     }
 
@@ -435,6 +2490,20 @@ async fn write_to_tcp(stream: &mut TcpStream, bytes: Vec<u8>) {
     }
 }
 
+pub(crate) async fn abort_transactions_touching(
+    table_name: &str,
+    manager: &mut TransactionManager,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+) {
+    for transaction_id in manager.abort_touching(table_name, tables).await {
+        tracing::warn!(
+            "Transaction with id '{}' got aborted, table '{}' is dropped",
+            transaction_id,
+            table_name
+        );
+    }
+}
+
 fn get_transaction_by_id(
     transaction_id: Option<u64>,
     manager: &mut TransactionManager,
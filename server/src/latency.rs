@@ -0,0 +1,123 @@
+use protos::LatencyBreakdown;
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+// requests taking longer are logged along with where their time went
+pub static SLOW_REQUEST_THRESHOLD: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Phase {
+    // waiting for locks of tables and transactions, or for write batch to fill up
+    Queueing,
+    Parsing,
+    Validation,
+    Memtable,
+    SSTableIo,
+    CommitLog,
+}
+
+static PHASES: [(Phase, &str); 6] = [
+    (Phase::Queueing, "queueing"),
+    (Phase::Parsing, "parsing"),
+    (Phase::Validation, "validation"),
+    (Phase::Memtable, "memtable"),
+    (Phase::SSTableIo, "sstable_io"),
+    (Phase::CommitLog, "commit_log"),
+];
+
+// time spent by a single request in each phase, every request handled gets a session of its own,
+// so budget is only ever recorded into by the request it belongs to
+#[derive(Debug, Clone)]
+pub struct LatencyBudget {
+    started_at: Instant,
+    phases: [Cell<Duration>; 6],
+}
+
+impl LatencyBudget {
+    pub fn start() -> LatencyBudget {
+        LatencyBudget {
+            started_at: Instant::now(),
+            phases: Default::default(),
+        }
+    }
+
+    pub fn record(&self, phase: Phase, duration: Duration) {
+        let spent = &self.phases[phase as usize];
+        spent.set(spent.get() + duration);
+    }
+
+    pub fn measure<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let started_at = Instant::now();
+        let result = f();
+        self.record(phase, started_at.elapsed());
+        result
+    }
+
+    pub async fn measure_async<T>(&self, phase: Phase, future: impl Future<Output = T>) -> T {
+        let started_at = Instant::now();
+        let result = future.await;
+        self.record(phase, started_at.elapsed());
+        result
+    }
+
+    pub fn spent(&self, phase: Phase) -> Duration {
+        self.phases[phase as usize].get()
+    }
+
+    pub fn total(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    pub fn to_proto(&self) -> LatencyBreakdown {
+        let micros = |phase| self.spent(phase).as_micros() as u64;
+        let mut latency_breakdown = LatencyBreakdown::new();
+        latency_breakdown.total_micros = self.total().as_micros() as u64;
+        latency_breakdown.queueing_micros = micros(Phase::Queueing);
+        latency_breakdown.parsing_micros = micros(Phase::Parsing);
+        latency_breakdown.validation_micros = micros(Phase::Validation);
+        latency_breakdown.memtable_micros = micros(Phase::Memtable);
+        latency_breakdown.sstable_io_micros = micros(Phase::SSTableIo);
+        latency_breakdown.commit_log_micros = micros(Phase::CommitLog);
+        latency_breakdown
+    }
+}
+
+impl Default for LatencyBudget {
+    fn default() -> LatencyBudget {
+        LatencyBudget::start()
+    }
+}
+
+// time not accounted for by any phase went to executing the rest of the request
+impl Display for LatencyBudget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "total={:?}", self.total())?;
+        for (phase, name) in PHASES {
+            write!(f, " {}={:?}", name, self.spent(phase))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_add_up() {
+        let budget = LatencyBudget::start();
+        budget.record(Phase::SSTableIo, Duration::from_micros(300));
+        budget.record(Phase::SSTableIo, Duration::from_micros(200));
+        let sum = budget.measure(Phase::Validation, || 2 + 2);
+
+        assert_eq!(sum, 4);
+        assert_eq!(budget.spent(Phase::SSTableIo), Duration::from_micros(500));
+        assert_eq!(budget.spent(Phase::CommitLog), Duration::ZERO);
+
+        let latency_breakdown = budget.to_proto();
+        assert_eq!(latency_breakdown.sstable_io_micros, 500);
+        assert!(budget.to_string().contains(" sstable_io=500µs"));
+    }
+}
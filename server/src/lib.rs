@@ -1,8 +1,28 @@
+mod cluster;
+mod column_ordinals;
 mod context;
+mod database;
 mod handlers;
+mod latency;
 mod listener;
+mod partition_load;
+mod peer;
 mod proto_parsing;
+mod raft;
+mod replication;
+mod server_info;
+mod session;
+#[cfg(feature = "simulation")]
+pub mod simulation;
+mod table_access;
+mod telemetry;
 mod thread_channels;
+mod thread_monitor;
 mod transaction_manager;
+mod write_batcher;
 
-pub use listener::run_listener_threads;
+pub use cluster::{parse_cluster_config, ClusterConfig, ClusterNode};
+pub use database::{parse_database_configs, DatabaseConfig};
+pub use listener::{run_listener_threads, DEFAULT_COMPACTION_THREADS};
+pub use replication::{parse_replication_config, ReplicationConfig, StandbyConfig};
+pub use telemetry::{parse_trace_export, TraceExport};
@@ -1,59 +1,272 @@
-use crate::context::ThreadContext;
-use crate::handlers::handle_tcp_stream;
-use crate::thread_channels::{OperationReceiver, OperationSender, ThreadMessage};
+use crate::cluster::{
+    cluster_config, raft_main, reset_raft_logs, start_cluster, CLUSTER_LOG_MIN_BYTES,
+};
+use crate::context::{read_num_of_partitions, read_partition_map, ThreadContext};
+use crate::database::{Database, DatabaseConfig};
+use crate::handlers::{abort_transactions_touching, find_reference_violation, handle_tcp_stream};
+use crate::partition_load::{partition_load_main, PartitionLoad};
+use crate::replication::{
+    check_standby_start, standby_config, standby_main, start_as_standby, ReplicationConfig,
+};
+use crate::server_info::report_server_started;
+use crate::table_access::{table_access_main, TableAccess};
+use crate::telemetry::{trace_export_layer, TraceExport};
+use crate::thread_channels::{
+    next_thread_message, OperationSender, SharedOperationReceiver, ThreadMessage,
+};
+use crate::thread_monitor::{report_thread_listening, report_thread_panic, THREAD_MAX_RESTARTS};
 use crate::transaction_manager::TransactionManager;
+use crate::write_batcher::{write_batcher_main, WriteBatchReceiver, WriteBatcher};
+use common::partition::{get_partition_thread, PartitionMap};
 use futures::channel::{mpsc, oneshot};
+use futures::future::join_all;
 use futures::lock::Mutex;
-use futures::{SinkExt, StreamExt};
+use futures::SinkExt;
 use monoio::net::TcpListener;
 use monoio::utils::CtrlC;
 use monoio::FusionDriver;
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 use std::thread;
+use std::time::Duration;
 use storage::commit_log::{replay_commit_logs, CommitLog};
-use storage::sstable::{compaction_main, flush_memtable_to_sstable, SSTABLES_DIR};
+use storage::index::query_index;
+use storage::log_syncer::{log_syncer_main, reset_log_syncer};
+use storage::prepare_log::{decide_transaction, remove_abandoned_decisions};
+use storage::replication::{reset_replication_logs, set_replication_log_max_bytes};
+use storage::sstable::{
+    compaction_main, flush_memtable_to_sstable, rewrite_legacy_sstables, CompactionMessage,
+};
+use storage::supervisor::panic_message;
 use storage::table::{
-    drop_table, read_table_schemas, sync_model, Table, TableSchema, TABLE_SCHEMAS_DIR,
-    TABLE_SCHEMAS_FILE_PATH,
+    adopt_bucket, check_schema_compatibility, drop_table, read_table_schemas, release_bucket,
+    set_read_only, sync_models, Table, TableSchema,
 };
+use storage::transaction::{recover_prepared_transactions, WRITE_INTENT_TIMEOUT_MILLIS};
 use storage::Memtable;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{filter, Layer};
 
-static TCP_STARTING_PORT: usize = 29800;
+pub(crate) static TCP_STARTING_PORT: usize = 29800;
+// compaction is throttled per disk, so few threads are enough for all databases
+pub static DEFAULT_COMPACTION_THREADS: usize = 2;
+
+// what a thread needs to start serving one of the databases
+pub(crate) struct DatabaseSetup {
+    name: String,
+    thread_context: ThreadContext,
+    senders: Vec<OperationSender>,
+    receiver: SharedOperationReceiver,
+    write_batch_receiver: WriteBatchReceiver,
+    table_schemas: Vec<TableSchema>,
+}
+
+impl DatabaseSetup {
+    // inserts queued in write batcher of panicked thread die with it
+    fn for_restart(&self) -> DatabaseSetup {
+        let (write_batcher, write_batch_receiver) = WriteBatcher::new();
+        DatabaseSetup {
+            name: self.name.clone(),
+            thread_context: ThreadContext {
+                write_batcher,
+                ..self.thread_context.clone()
+            },
+            senders: self.senders.clone(),
+            receiver: self.receiver.clone(),
+            write_batch_receiver,
+            table_schemas: self.table_schemas.clone(),
+        }
+    }
+
+    // schemas could have changed since server started
+    async fn reload_table_schemas(&mut self) {
+        self.table_schemas = read_table_schemas(&self.thread_context.data_dir.table_schemas_file)
+            .await
+            .unwrap();
+    }
+}
 
-pub async fn run_listener_threads(num_of_threads: usize) {
-    std::fs::create_dir_all(TABLE_SCHEMAS_DIR).unwrap();
-    std::fs::create_dir_all(SSTABLES_DIR).unwrap();
+pub async fn run_listener_threads(
+    num_of_threads: usize,
+    compaction_threads: usize,
+    database_configs: Vec<DatabaseConfig>,
+    trace_export: Option<TraceExport>,
+    replication: ReplicationConfig,
+) {
+    let (trace_export_layer, trace_export_error) = match trace_export_layer(trace_export) {
+        Ok(trace_export_layer) => (trace_export_layer, None),
+        Err(error) => (None, Some(error)),
+    };
     tracing_subscriber::registry()
         .with(tracing_subscriber::fmt::layer().with_filter(filter::LevelFilter::WARN))
+        .with(trace_export_layer)
         .init();
+    if let Some(error) = trace_export_error {
+        tracing::error!("Spans of requests are not exported: {}", error);
+    }
+    report_server_started(compaction_threads);
+    let data_dirs: Vec<_> = database_configs
+        .iter()
+        .map(|database_config| &database_config.data_dir)
+        .collect();
+    check_standby_start(&replication.standby, &data_dirs)
+        .unwrap_or_else(|error| panic!("Refusing to start: {}", error));
+    if replication.standby.is_some() && replication.cluster.is_some() {
+        panic!("Refusing to start: cluster node cannot be a standby, its leader is elected");
+    }
+    let log_max_bytes = match replication.cluster {
+        Some(_) => replication.log_max_bytes.max(CLUSTER_LOG_MIN_BYTES),
+        None => replication.log_max_bytes,
+    };
+    set_replication_log_max_bytes(log_max_bytes);
+    start_as_standby(replication.standby);
 
     let num_of_threads = match num_of_threads > 0 {
         true => num_of_threads,
         false => 1,
     };
+    start_cluster(replication.cluster, &database_configs, num_of_threads);
 
-    let num_of_partitions = 1000usize;
-    let table_schemas = read_table_schemas(TABLE_SCHEMAS_FILE_PATH).await.unwrap();
+    let listening_threads = Arc::new(AtomicUsize::new(0));
+    let mut setups_per_thread: Vec<Vec<DatabaseSetup>> =
+        (0..num_of_threads).map(|_| Vec::new()).collect();
+    let mut all_senders = Vec::new();
+    let mut compaction_thread_senders = Vec::new();
+    let mut compaction_tasks_per_thread: Vec<Vec<CompactionTask>> =
+        (0..compaction_threads.max(1)).map(|_| Vec::new()).collect();
 
-    let (mut compaction_thread_sender, compaction_thread_receiver) = mpsc::channel(16);
+    for (database_number, database_config) in database_configs.into_iter().enumerate() {
+        let prepared_database =
+            prepare_database(database_config, num_of_threads, listening_threads.clone()).await;
 
-    let table_schemas2 = table_schemas.clone();
-    thread::spawn(move || {
-        let mut runtime = monoio::RuntimeBuilder::<FusionDriver>::new()
-            .build()
+        let compaction_thread = database_number % compaction_tasks_per_thread.len();
+        compaction_tasks_per_thread[compaction_thread].push(prepared_database.compaction_task);
+
+        for (setups, setup) in setups_per_thread.iter_mut().zip(prepared_database.setups) {
+            setups.push(setup);
+        }
+        all_senders.push(prepared_database.senders);
+        compaction_thread_senders.push(prepared_database.compaction_sender);
+    }
+
+    for compaction_tasks in compaction_tasks_per_thread {
+        if compaction_tasks.is_empty() {
+            continue;
+        }
+        thread::spawn(move || {
+            let mut runtime = monoio::RuntimeBuilder::<FusionDriver>::new()
+                .build()
+                .unwrap();
+
+            runtime.block_on(join_all(
+                compaction_tasks.into_iter().map(CompactionTask::run),
+            ));
+        });
+    }
+
+    for (thread_num, setups) in setups_per_thread.into_iter().enumerate() {
+        thread::spawn(move || run_thread(thread_num, setups));
+    }
+
+    let ctrl_c = CtrlC::new().unwrap();
+
+    ctrl_c.await;
+    tracing::info!("Shutting down...");
+
+    let mut ctrl_c_receivers = Vec::new();
+    for senders in all_senders.iter_mut() {
+        for sender in senders.iter_mut() {
+            let (ctrl_c_sender, receiver) = oneshot::channel();
+            ctrl_c_receivers.push(receiver);
+            sender
+                .send(ThreadMessage::CtrlC(ctrl_c_sender))
+                .await
+                .unwrap();
+        }
+    }
+
+    for compaction_thread_sender in compaction_thread_senders.iter_mut() {
+        let (sender, receiver) = oneshot::channel();
+        ctrl_c_receivers.push(receiver);
+        compaction_thread_sender
+            .send(CompactionMessage::CtrlC(sender))
+            .await
             .unwrap();
+    }
 
-        runtime.block_on(compaction_main(
-            compaction_thread_receiver,
-            table_schemas2,
-            num_of_partitions,
-            SSTABLES_DIR,
-        ));
+    for ctrl_c_receiver in ctrl_c_receivers {
+        ctrl_c_receiver.await.unwrap();
+    }
+}
+
+pub(crate) struct PreparedDatabase {
+    // one per thread, in order of thread numbers
+    pub setups: Vec<DatabaseSetup>,
+    pub senders: Vec<OperationSender>,
+    pub compaction_sender: mpsc::Sender<CompactionMessage>,
+    pub compaction_task: CompactionTask,
+}
+
+pub(crate) struct CompactionTask {
+    receiver: mpsc::Receiver<CompactionMessage>,
+    table_schemas: Arc<RwLock<Vec<TableSchema>>>,
+    partition_map: Arc<RwLock<PartitionMap>>,
+    sstable_dir: String,
+}
+
+impl CompactionTask {
+    pub async fn run(self) {
+        compaction_main(
+            self.receiver,
+            self.table_schemas,
+            self.partition_map,
+            &self.sstable_dir,
+        )
+        .await;
+    }
+}
+
+// reads schemas and partition map of a database and splits its partitions between threads
+pub(crate) async fn prepare_database(
+    database_config: DatabaseConfig,
+    num_of_threads: usize,
+    listening_threads: Arc<AtomicUsize>,
+) -> PreparedDatabase {
+    let data_dir = database_config.data_dir;
+    data_dir.create_dirs().unwrap();
+    remove_abandoned_decisions(&data_dir);
+    let num_of_partitions =
+        read_num_of_partitions(database_config.num_of_partitions, num_of_threads, &data_dir)
+            .unwrap_or_else(|error| {
+                panic!(
+                    "Refusing to start database '{}': {}",
+                    database_config.name, error
+                )
+            });
+
+    let table_schemas = read_table_schemas(&data_dir.table_schemas_file)
+        .await
+        .unwrap();
+    check_schema_compatibility(&table_schemas, &data_dir).unwrap_or_else(|error| {
+        panic!(
+            "Refusing to start database '{}': {}",
+            database_config.name, error
+        )
     });
+    let partition_map = Arc::new(RwLock::new(read_partition_map(
+        num_of_partitions,
+        &data_dir,
+    )));
+    let current_partition_map = partition_map.read().unwrap().clone();
+    for table_schema in &table_schemas {
+        rewrite_legacy_sstables(table_schema, &current_partition_map, &data_dir.sstables).await;
+    }
+
+    let (compaction_thread_sender, compaction_thread_receiver) = mpsc::channel(16);
 
     let mut senders = Vec::with_capacity(num_of_threads);
     let mut receivers = Vec::with_capacity(num_of_threads);
@@ -62,159 +275,456 @@ pub async fn run_listener_threads(num_of_threads: usize) {
         let (command_sender, command_receiver) = mpsc::unbounded();
 
         senders.push(command_sender);
-        receivers.push(Some(command_receiver));
+        receivers.push(command_receiver);
     }
 
     let mut partitions_per_thread = HashMap::new();
     for partition in 0..num_of_partitions {
         partitions_per_thread
-            .entry(partition % num_of_threads)
+            .entry(get_partition_thread(partition, num_of_threads))
             .or_insert(HashSet::new())
             .insert(partition);
     }
 
-    for thread_num in 0..num_of_threads {
-        let table_schemas = table_schemas.clone();
-        let senders = senders.clone();
-        let receiver = receivers[thread_num].take().unwrap();
+    let partition_load = Arc::new(PartitionLoad::new(num_of_partitions));
+    let table_access = Arc::new(TableAccess::default());
+    let shared_table_schemas = Arc::new(RwLock::new(table_schemas.clone()));
+    let mut setups = Vec::with_capacity(num_of_threads);
+    for (thread_num, receiver) in receivers.into_iter().enumerate() {
         let thread_partitions = partitions_per_thread.remove(&thread_num).unwrap();
+        let (write_batcher, write_batch_receiver) = WriteBatcher::new();
         let thread_context = ThreadContext {
             partitions: thread_partitions,
-            total_number_of_partitions: num_of_threads,
+            partition_map: partition_map.clone(),
             current_thread_number: thread_num,
             number_of_threads: num_of_threads,
+            listening_threads: listening_threads.clone(),
+            compaction_sender: compaction_thread_sender.clone(),
+            write_batcher,
+            data_dir: data_dir.clone(),
+            partition_load: partition_load.clone(),
+            table_access: table_access.clone(),
+            table_schemas: shared_table_schemas.clone(),
         };
 
-        thread::spawn(move || {
-            // TODO: make sure thread is pinned to core
-            let mut runtime = monoio::RuntimeBuilder::<FusionDriver>::new()
-                .build()
-                .unwrap();
-
-            runtime.block_on(thread_main(
-                thread_context,
-                senders,
-                receiver,
-                table_schemas.clone(),
-            ));
+        setups.push(DatabaseSetup {
+            name: database_config.name.clone(),
+            thread_context,
+            senders: senders.clone(),
+            receiver: Arc::new(std::sync::Mutex::new(receiver)),
+            write_batch_receiver,
+            table_schemas: table_schemas.clone(),
         });
     }
 
-    let ctrl_c = CtrlC::new().unwrap();
-
-    ctrl_c.await;
-    tracing::info!("Shutting down...");
-
-    let mut ctrl_c_receivers = Vec::new();
-    for thread_num in 0..num_of_threads {
-        let (sender, receiver) = oneshot::channel();
-        ctrl_c_receivers.push(receiver);
-        senders[thread_num]
-            .send(ThreadMessage::CtrlC(sender))
-            .await
-            .unwrap();
-    }
-
-    let (sender, receiver) = oneshot::channel();
-    ctrl_c_receivers.push(receiver);
-    compaction_thread_sender.send(sender).await.unwrap();
-
-    for ctrl_c_receiver in ctrl_c_receivers {
-        ctrl_c_receiver.await.unwrap();
+    PreparedDatabase {
+        setups,
+        senders,
+        compaction_sender: compaction_thread_sender,
+        compaction_task: CompactionTask {
+            receiver: compaction_thread_receiver,
+            table_schemas: shared_table_schemas,
+            partition_map,
+            sstable_dir: data_dir.sstables.clone(),
+        },
     }
 }
 
-async fn thread_main(
-    thread_context: ThreadContext,
-    senders: Vec<OperationSender>,
-    mut receiver: OperationReceiver,
-    table_schemas: Vec<TableSchema>,
-) {
+// replays commit logs of thread's partitions and starts handling messages of other threads
+pub(crate) async fn open_database(setup: DatabaseSetup) -> Database {
+    let thread_context = setup.thread_context;
     let mut tables = HashMap::new();
-    for table_schema in table_schemas {
-        replay_commit_logs(
+    for table_schema in setup.table_schemas {
+        let replayed_state = replay_commit_logs(
             &table_schema,
             &thread_context.partitions,
-            thread_context.total_number_of_partitions,
+            &thread_context.partition_map(),
+            &thread_context.data_dir,
         )
         .await;
         let memtable = Memtable::default();
 
-        let commit_log = CommitLog::open_new(&table_schema, &thread_context.partitions).await;
+        let commit_log = CommitLog::open_new(
+            &table_schema,
+            &thread_context.partitions,
+            &thread_context.data_dir.commit_logs,
+        )
+        .await;
 
-        tables.insert(
-            table_schema.name.clone(),
-            Table::new(memtable, commit_log, table_schema),
+        let mut table = Table::new(
+            memtable,
+            commit_log,
+            table_schema,
+            thread_context.data_dir.clone(),
         );
+        table.restore_state(replayed_state).await;
+        table
+            .restore_access_times(&thread_context.partitions, &thread_context.partition_map())
+            .await;
+        tables.insert(table.table_schema.name.clone(), table);
+    }
+
+    let database = Database {
+        thread_context: thread_context.clone(),
+        senders: setup.senders,
+        tables: Rc::new(Mutex::new(tables)),
+        transaction_manager: Arc::new(Mutex::new(TransactionManager::new())),
+    };
+    // recovered before messages are handled, so that commit or abort sent meanwhile finds them
+    let in_doubt = recover_prepared_transactions(
+        database.tables.clone(),
+        &thread_context.data_dir,
+        &thread_context.partitions,
+        &thread_context.partition_map(),
+    )
+    .await;
+    if !in_doubt.is_empty() {
+        let transaction_ids = in_doubt.iter().map(|transaction| transaction.id).collect();
+        let mut manager = database.transaction_manager.lock().await;
+        for transaction in in_doubt {
+            manager.add_prepared(transaction);
+        }
+        drop(manager);
+        monoio::spawn(resolve_in_doubt_transactions(
+            database.clone(),
+            transaction_ids,
+        ));
+    }
+    monoio::spawn(write_batcher_main(
+        setup.write_batch_receiver,
+        database.tables.clone(),
+        thread_context,
+    ));
+    monoio::spawn(database_main(database.clone(), setup.receiver));
+    if database.thread_context.current_thread_number == 0 {
+        monoio::spawn(partition_load_main(
+            database.thread_context.partition_load.clone(),
+        ));
+        monoio::spawn(table_access_main(
+            database.thread_context.table_access.clone(),
+        ));
     }
 
-    let tables = Arc::new(Mutex::new(tables));
-    let transaction_manager = Arc::new(Mutex::new(TransactionManager::new()));
+    database
+}
+
+// coordinator which crashed along with this thread never sends commit or abort, so transactions
+// still in doubt once their intents would time out are resolved by a persisted decision. the one
+// to abort races with coordinator still deciding to commit, whichever came first holds for both
+async fn resolve_in_doubt_transactions(database: Database, mut transaction_ids: Vec<u64>) {
+    let Database {
+        thread_context,
+        tables,
+        transaction_manager,
+        ..
+    } = database;
+    while !transaction_ids.is_empty() {
+        monoio::time::sleep(Duration::from_millis(WRITE_INTENT_TIMEOUT_MILLIS as u64)).await;
+
+        let mut manager = transaction_manager.lock().await;
+        let mut still_in_doubt = Vec::new();
+        for transaction_id in transaction_ids {
+            let Some(mut transaction) = manager.remove(transaction_id) else {
+                continue;
+            };
+            match decide_transaction(&thread_context.data_dir, transaction_id, false).await {
+                Ok(true) => {
+                    transaction
+                        .commit(
+                            tables.clone(),
+                            &thread_context.partitions,
+                            &thread_context.partition_map(),
+                        )
+                        .await
+                }
+                Ok(false) => {
+                    tracing::warn!(
+                        "Aborting transaction with id '{}' left in doubt",
+                        transaction_id
+                    );
+                    transaction.release_intents(tables.clone()).await;
+                }
+                // intents stay in place until the decision can be persisted
+                Err(error) => {
+                    tracing::error!(
+                        "Failed to decide on transaction with id '{}' left in doubt: {}",
+                        transaction_id,
+                        error
+                    );
+                    manager.add_prepared(transaction);
+                    still_in_doubt.push(transaction_id);
+                }
+            }
+        }
+        transaction_ids = still_in_doubt;
+    }
+}
+
+// panicked thread is restarted on a new runtime and recovers its partitions from commit logs,
+// messages other threads sent in the meantime wait in its receivers
+fn run_thread(thread_num: usize, mut setups: Vec<DatabaseSetup>) {
+    let mut restarted = false;
+    loop {
+        // TODO: make sure thread is pinned to core
+        let mut runtime = monoio::RuntimeBuilder::<FusionDriver>::new()
+            .build()
+            .unwrap();
+
+        let restart_setups = setups.iter().map(DatabaseSetup::for_restart).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            runtime.block_on(async {
+                if restarted {
+                    reset_log_syncer();
+                    reset_replication_logs();
+                    reset_raft_logs();
+                    for setup in setups.iter_mut() {
+                        setup.reload_table_schemas().await;
+                    }
+                }
+                thread_main(thread_num, setups).await;
+            })
+        }));
+        let panic = match result {
+            Ok(()) => return,
+            Err(panic) => panic_message(panic),
+        };
+
+        let panics = report_thread_panic(thread_num, panic.clone());
+        if panics > THREAD_MAX_RESTARTS {
+            tracing::error!(
+                "Thread {} panicked {} times, last panic: {}, shutting down",
+                thread_num,
+                panics,
+                panic
+            );
+            std::process::exit(1);
+        }
+        tracing::error!("Thread {} panicked: {}, restarting", thread_num, panic);
+        setups = restart_setups;
+        restarted = true;
+    }
+}
+
+// decremented when thread panics, so that readiness fails until it listens again
+struct ListeningGuard(Arc<AtomicUsize>);
+
+impl Drop for ListeningGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+async fn thread_main(thread_num: usize, setups: Vec<DatabaseSetup>) {
+    monoio::spawn(log_syncer_main(Duration::from_secs(10)));
+
+    let mut databases = HashMap::new();
+    let mut listening_threads = None;
+    for setup in setups {
+        listening_threads = Some(setup.thread_context.listening_threads.clone());
+        let name = setup.name.clone();
+        databases.insert(name, open_database(setup).await);
+    }
+    if standby_config().is_some() {
+        for (name, database) in &databases {
+            monoio::spawn(standby_main(name.clone(), database.clone()));
+        }
+    }
+    if cluster_config().is_some() {
+        for (name, database) in &databases {
+            monoio::spawn(raft_main(name.clone(), database.clone()));
+        }
+    }
 
-    let tcp_port = TCP_STARTING_PORT + thread_context.current_thread_number;
+    let tcp_port = TCP_STARTING_PORT + thread_num;
     let tcp_listener = TcpListener::bind(format!("0.0.0.0:{}", tcp_port.to_string())).unwrap();
-    tracing::info!(
-        "Listening on port {} on thread {}",
-        tcp_port,
-        thread_context.current_thread_number
-    );
+    tracing::info!("Listening on port {} on thread {}", tcp_port, thread_num);
+    let _listening_guard = listening_threads.map(|listening_threads| {
+        listening_threads.fetch_add(1, Ordering::SeqCst);
+        ListeningGuard(listening_threads)
+    });
+    report_thread_listening(thread_num);
 
     loop {
-        monoio::select! {
-            stream = tcp_listener.accept() => {
-                monoio::spawn(handle_tcp_stream(
-                    stream.unwrap().0,
-                    thread_context.clone(),
-                    senders.clone(),
+        let (stream, _) = tcp_listener.accept().await.unwrap();
+        monoio::spawn(handle_tcp_stream(stream, databases.clone()));
+    }
+}
+
+// handles messages other threads send to this thread's part of the database
+async fn database_main(database: Database, receiver: SharedOperationReceiver) {
+    let Database {
+        thread_context,
+        tables,
+        transaction_manager,
+        ..
+    } = database;
+
+    while let Some(thread_message) = next_thread_message(&receiver).await {
+        #[cfg(feature = "simulation")]
+        if crate::simulation::drops_thread_message(
+            thread_context.current_thread_number,
+            &thread_message,
+        ) {
+            continue;
+        }
+
+        match thread_message {
+            ThreadMessage::TransactionBegun(transaction_id) => {
+                let mut manager = transaction_manager.lock().await;
+                manager.add(transaction_id);
+            }
+            ThreadMessage::TransactionPrepare(transaction_id, response_sender) => {
+                let mut manager = transaction_manager.lock().await;
+                // transaction killed on this thread cannot commit
+                let can_commit = match manager.transactions.get_mut(&transaction_id) {
+                    Some(transaction) => {
+                        transaction
+                            .prepare(
+                                tables.clone(),
+                                &thread_context.data_dir,
+                                &thread_context.partitions,
+                                &thread_context.partition_map(),
+                            )
+                            .await
+                    }
+                    None => false,
+                };
+                response_sender.send(can_commit).unwrap();
+            }
+            ThreadMessage::TransactionCommit(transaction_id) => {
+                let mut manager = transaction_manager.lock().await;
+                if let Some(mut transaction) = manager.transactions.remove(&transaction_id) {
+                    transaction
+                        .commit(
+                            tables.clone(),
+                            &thread_context.partitions,
+                            &thread_context.partition_map(),
+                        )
+                        .await;
+                }
+            }
+            ThreadMessage::TransactionAborted(transaction_id) => {
+                let mut manager = transaction_manager.lock().await;
+                if let Some(transaction) = manager.remove(transaction_id) {
+                    transaction.release_intents(tables.clone()).await;
+                }
+            }
+            ThreadMessage::SyncModels(schema_strings) => {
+                sync_models(
+                    schema_strings,
                     tables.clone(),
-                    transaction_manager.clone()
+                    &thread_context.partitions,
+                    &thread_context.data_dir,
                 )
-                );
+                .await
+                .unwrap();
             }
-            Some(thread_message) = receiver.next() => {
-                match thread_message {
-                    ThreadMessage::TransactionBegun(transaction_id) => {
-                        let mut manager = transaction_manager.lock().await;
-                        manager.add(transaction_id);
-                    }
-                    ThreadMessage::TransactionPrepare(transaction_id, response_sender) => {
-                        let manager = transaction_manager.lock().await;
-                        let transaction = manager.transactions.get(&transaction_id).unwrap();
-                        response_sender.send(transaction.can_commit(tables.clone()).await).unwrap();
-                    }
-                    ThreadMessage::TransactionCommit(transaction_id) => {
-                        let mut manager = transaction_manager.lock().await;
-                        let mut transaction = manager.transactions.remove(&transaction_id).unwrap();
-                        transaction.commit(tables.clone(), thread_context.total_number_of_partitions).await;
-                    }
-                    ThreadMessage::TransactionAborted(transaction_id) => {
-                        let mut manager = transaction_manager.lock().await;
-                        manager.remove(transaction_id);
-                    }
-                    ThreadMessage::SyncModel(schema_string) => {
-                        sync_model(schema_string, tables.clone(), &thread_context.partitions, TABLE_SCHEMAS_FILE_PATH).await.unwrap();
-                    }
-                    ThreadMessage::DropTable(table_name) => {
-                        drop_table(table_name, tables.clone(), TABLE_SCHEMAS_FILE_PATH, SSTABLES_DIR).await.unwrap();
-                    }
-                    ThreadMessage::CtrlC(sender) => {
-                        tracing::info!("Shutting down database thread, flushing memtables...");
-                        let mut tables = tables.lock().await;
-                        for (_, table) in tables.iter_mut() {
-                            let mut memtable = Memtable::default();
-                            std::mem::swap(&mut table.memtable, &mut memtable);
-
-                            flush_memtable_to_sstable(
-                                memtable,
-                                table.commit_log.clone(),
-                                table.table_schema.clone(),
-                                thread_context.total_number_of_partitions
-                            )
-                            .await;
-                        }
-                        sender.send(()).unwrap();
+            ThreadMessage::DropTable(table_name) => {
+                let mut manager = transaction_manager.lock().await;
+                abort_transactions_touching(&table_name, &mut manager, tables.clone()).await;
+                let dropped = drop_table(
+                    table_name,
+                    tables.clone(),
+                    &thread_context.data_dir.table_schemas_file,
+                    &thread_context.data_dir.sstables,
+                )
+                .await;
+                if let Err(error) = dropped {
+                    tracing::error!("Failed to drop table: {}", error);
+                }
+            }
+            ThreadMessage::SetReadOnly(table_name, read_only) => {
+                set_read_only(
+                    &table_name,
+                    read_only,
+                    tables.clone(),
+                    &thread_context.data_dir.table_schemas_file,
+                )
+                .await
+                .unwrap();
+            }
+            ThreadMessage::ReleaseBucket(bucket, sender) => {
+                let released_states = release_bucket(
+                    bucket,
+                    tables.clone(),
+                    &thread_context.partitions,
+                    &thread_context.partition_map(),
+                )
+                .await;
+                sender.send(released_states).unwrap();
+            }
+            ThreadMessage::AdoptBucket(released_states, sender) => {
+                adopt_bucket(
+                    released_states,
+                    tables.clone(),
+                    &thread_context.partitions,
+                    &thread_context.partition_map(),
+                )
+                .await;
+                sender.send(()).unwrap();
+            }
+            ThreadMessage::ListTransactions(sender) => {
+                let manager = transaction_manager.lock().await;
+                sender
+                    .send(manager.list(thread_context.current_thread_number))
+                    .unwrap();
+            }
+            ThreadMessage::KillTransaction(transaction_id, sender) => {
+                let mut manager = transaction_manager.lock().await;
+                let killed = manager.kill(transaction_id, tables.clone()).await;
+                sender.send(killed).unwrap();
+            }
+            ThreadMessage::CheckReference(reference_check) => {
+                let tables = tables.lock().await;
+                if let Some(violation) = find_reference_violation(&tables, &reference_check) {
+                    tracing::warn!("Reference violation: {}", violation);
+                }
+            }
+            ThreadMessage::QueryIndex(table_name, index_range, sender) => {
+                let tables = tables.lock().await;
+                let rows = match tables.get(&table_name) {
+                    Some(table) => {
+                        query_index(
+                            table,
+                            &index_range,
+                            &thread_context.partitions,
+                            &thread_context.partition_map(),
+                        )
+                        .await
                     }
+                    None => Vec::new(),
+                };
+                sender.send(rows).unwrap();
+            }
+            ThreadMessage::CountMemtableRows(table_name, sender) => {
+                let tables = tables.lock().await;
+                let rows_per_partition = tables
+                    .get(&table_name)
+                    .map(|table| {
+                        table
+                            .memtable
+                            .count_rows_per_partition(&thread_context.partition_map())
+                    })
+                    .unwrap_or_default();
+                sender.send(rows_per_partition).unwrap();
+            }
+            ThreadMessage::CtrlC(sender) => {
+                tracing::info!("Shutting down database thread, flushing memtables...");
+                let mut tables = tables.lock().await;
+                for (_, table) in tables.iter_mut() {
+                    let memtable = table.take_memtable();
+                    let old_commit_log = table.rotate_commit_log(&thread_context.partitions).await;
+
+                    flush_memtable_to_sstable(
+                        memtable,
+                        old_commit_log,
+                        table.table_schema.clone(),
+                        thread_context.data_dir.sstables.clone(),
+                        thread_context.partition_map(),
+                    )
+                    .await;
                 }
+                sender.send(()).unwrap();
             }
         }
     }
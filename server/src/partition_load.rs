@@ -0,0 +1,201 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// partition getting that many times more requests than the median partition is skewed
+static HOT_PARTITION_SKEW: f64 = 10.0;
+// skewed for that many windows in a row, so that a short burst is not reported as hot
+static HOT_PARTITION_WINDOWS: u32 = 3;
+// partitions getting fewer requests are never hot, as any traffic skews a mostly idle database
+static HOT_PARTITION_MIN_REQUESTS_PER_SECOND: f64 = 10.0;
+static PARTITION_LOAD_WINDOW: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default)]
+struct PartitionCounters {
+    requests: AtomicU64,
+    micros: AtomicU64,
+}
+
+// load of a partition over the last finished window
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartitionLoadReport {
+    pub partition: usize,
+    pub requests_per_second: f64,
+    pub average_latency: Duration,
+    // requests relative to the median partition
+    pub skew: f64,
+    // windows in a row partition was skewed in
+    pub skewed_windows: u32,
+    pub hot: bool,
+}
+
+// shared by threads of a database, every thread records requests to partitions it owns and
+// thread 0 closes windows
+#[derive(Debug)]
+pub struct PartitionLoad {
+    counters: Vec<PartitionCounters>,
+    last_window: Mutex<Vec<PartitionLoadReport>>,
+}
+
+impl PartitionLoad {
+    pub fn new(num_of_partitions: usize) -> PartitionLoad {
+        PartitionLoad {
+            counters: (0..num_of_partitions)
+                .map(|_| PartitionCounters::default())
+                .collect(),
+            last_window: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn record(&self, partition: usize, latency: Duration) {
+        if let Some(counters) = self.counters.get(partition) {
+            counters.requests.fetch_add(1, Ordering::Relaxed);
+            counters
+                .micros
+                .fetch_add(latency.as_micros() as u64, Ordering::Relaxed);
+        }
+    }
+
+    // every operation of a request counts as a request to its partition, failed ones included
+    pub fn track(self: &Arc<Self>, partitions: Vec<usize>) -> PartitionLoadGuard {
+        PartitionLoadGuard {
+            partition_load: self.clone(),
+            partitions,
+            started_at: Instant::now(),
+        }
+    }
+
+    pub fn report(&self) -> Vec<PartitionLoadReport> {
+        self.last_window.lock().unwrap().clone()
+    }
+
+    pub fn finish_window(&self, window: Duration) {
+        let requests: Vec<_> = self
+            .counters
+            .iter()
+            .map(|counters| {
+                (
+                    counters.requests.swap(0, Ordering::Relaxed),
+                    counters.micros.swap(0, Ordering::Relaxed),
+                )
+            })
+            .collect();
+        let mut sorted_requests: Vec<_> = requests.iter().map(|(requests, _)| *requests).collect();
+        sorted_requests.sort_unstable();
+        let median = sorted_requests
+            .get(sorted_requests.len() / 2)
+            .copied()
+            .unwrap_or(0)
+            .max(1);
+
+        let mut last_window = self.last_window.lock().unwrap();
+        let reports: Vec<_> = requests
+            .into_iter()
+            .enumerate()
+            .map(|(partition, (requests, micros))| {
+                let requests_per_second = requests as f64 / window.as_secs_f64();
+                let skew = requests as f64 / median as f64;
+                let skewed = skew >= HOT_PARTITION_SKEW
+                    && requests_per_second >= HOT_PARTITION_MIN_REQUESTS_PER_SECOND;
+                let previous = last_window.get(partition);
+                let skewed_windows = match skewed {
+                    true => previous.map_or(0, |previous| previous.skewed_windows) + 1,
+                    false => 0,
+                };
+                let report = PartitionLoadReport {
+                    partition,
+                    requests_per_second,
+                    average_latency: Duration::from_micros(micros.checked_div(requests).unwrap_or(0)),
+                    skew,
+                    skewed_windows,
+                    hot: skewed_windows >= HOT_PARTITION_WINDOWS,
+                };
+
+                let was_hot = previous.is_some_and(|previous| previous.hot);
+                if report.hot && !was_hot {
+                    tracing::warn!(
+                        "Partition {} is hot, {:.1} requests/s, {:.1}x the median partition, average latency {:?}",
+                        partition,
+                        report.requests_per_second,
+                        report.skew,
+                        report.average_latency
+                    );
+                } else if !report.hot && was_hot {
+                    tracing::info!("Partition {} is no longer hot", partition);
+                }
+                report
+            })
+            .collect();
+        *last_window = reports;
+    }
+}
+
+pub struct PartitionLoadGuard {
+    partition_load: Arc<PartitionLoad>,
+    partitions: Vec<usize>,
+    started_at: Instant,
+}
+
+impl Drop for PartitionLoadGuard {
+    fn drop(&mut self) {
+        let latency = self.started_at.elapsed();
+        for partition in &self.partitions {
+            self.partition_load.record(*partition, latency);
+        }
+    }
+}
+
+pub(crate) async fn partition_load_main(partition_load: Arc<PartitionLoad>) {
+    let mut window_started_at = Instant::now();
+    loop {
+        monoio::time::sleep(PARTITION_LOAD_WINDOW).await;
+        partition_load.finish_window(window_started_at.elapsed());
+        window_started_at = Instant::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_window(partition_load: &PartitionLoad, requests: &[u64]) -> Vec<PartitionLoadReport> {
+        for (partition, requests) in requests.iter().enumerate() {
+            for _ in 0..*requests {
+                partition_load.record(partition, Duration::from_micros(200));
+            }
+        }
+        partition_load.finish_window(Duration::from_secs(1));
+        partition_load.report()
+    }
+
+    #[test]
+    fn partition_is_hot_once_skewed_for_consecutive_windows() {
+        let partition_load = PartitionLoad::new(4);
+        let skewed = [20, 20, 20, 500];
+
+        for _ in 1..HOT_PARTITION_WINDOWS {
+            let reports = load_window(&partition_load, &skewed);
+            assert!(reports.iter().all(|report| !report.hot));
+        }
+        let reports = load_window(&partition_load, &skewed);
+        assert!(reports[3].hot);
+        assert_eq!(reports[3].skew, 25.0);
+        assert_eq!(reports[3].requests_per_second, 500.0);
+        assert_eq!(reports[3].average_latency, Duration::from_micros(200));
+        assert!(!reports[0].hot);
+
+        // a single even window resets the streak
+        let reports = load_window(&partition_load, &[20, 20, 20, 20]);
+        assert_eq!(reports[3].skewed_windows, 0);
+        assert!(!reports[3].hot);
+    }
+
+    #[test]
+    fn idle_database_has_no_hot_partitions() {
+        let partition_load = PartitionLoad::new(4);
+        for _ in 0..HOT_PARTITION_WINDOWS {
+            let reports = load_window(&partition_load, &[0, 0, 0, 5]);
+            assert_eq!(reports[3].skewed_windows, 0);
+        }
+    }
+}
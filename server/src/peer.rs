@@ -0,0 +1,68 @@
+use monoio::io::{AsyncReadRentExt, AsyncWriteRentExt};
+use monoio::net::TcpStream;
+use protobuf::Message;
+use protos::{ProtoRequest, ProtoResponse};
+
+// connection to a thread of another server, speaking the same protocol clients do. Requests are
+// sent one at a time, so that response is simply the next frame read
+pub(crate) struct PeerConnection {
+    stream: TcpStream,
+    pub number_of_threads: usize,
+    next_correlation_id: u64,
+}
+
+impl PeerConnection {
+    pub async fn connect(address: &str) -> Result<PeerConnection, String> {
+        let mut stream = TcpStream::connect(address)
+            .await
+            .map_err(|e| format!("Failed to connect to '{}': {}", address, e))?;
+        let number_of_threads = stream
+            .read_u32()
+            .await
+            .map_err(|e| format!("Failed to read number of threads of '{}': {}", address, e))?;
+
+        Ok(PeerConnection {
+            stream,
+            number_of_threads: number_of_threads as usize,
+            next_correlation_id: 0,
+        })
+    }
+
+    // connection is left broken after an error, it has to be connected again
+    pub async fn request(&mut self, request: ProtoRequest) -> Result<ProtoResponse, String> {
+        let request_bytes = request.write_to_bytes().map_err(|e| e.to_string())?;
+        let correlation_id = self.next_correlation_id;
+        self.next_correlation_id += 1;
+
+        let mut frame = Vec::with_capacity(12 + request_bytes.len());
+        frame.extend_from_slice(&correlation_id.to_be_bytes());
+        frame.extend_from_slice(&(request_bytes.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&request_bytes);
+        let (result, _) = self.stream.write_all(frame).await;
+        result.map_err(|e| format!("Failed to send request to peer: {}", e))?;
+
+        let response_correlation_id = self
+            .stream
+            .read_u64()
+            .await
+            .map_err(|e| format!("Failed to read response of peer: {}", e))?;
+        let response_size = self
+            .stream
+            .read_u32()
+            .await
+            .map_err(|e| format!("Failed to read response of peer: {}", e))?;
+        let (result, response_bytes) = self
+            .stream
+            .read_exact(vec![0u8; response_size as usize])
+            .await;
+        result.map_err(|e| format!("Failed to read response of peer: {}", e))?;
+        if response_correlation_id != correlation_id {
+            return Err(format!(
+                "Peer answered request {} instead of {}",
+                response_correlation_id, correlation_id
+            ));
+        }
+
+        ProtoResponse::parse_from_bytes(&response_bytes).map_err(|e| e.to_string())
+    }
+}
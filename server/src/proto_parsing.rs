@@ -1,8 +1,21 @@
-use crate::thread_channels::{Command, Operation};
-use protobuf::Message;
+use crate::latency::LatencyBudget;
+use crate::session::{Consistency, Session};
+use crate::thread_channels::{Command, GetManyPage, IndexPage, Operation};
+use common::value::Value;
+use protobuf::{Message, MessageField};
 use protos::util::parse_value_from_proto;
-use protos::{BatchItemData, ProtoRequest, ProtoRequestData};
+use protos::{
+    BatchItemData, Consistency as ProtoConsistency, InsertRequest, ProtoRequest, ProtoRequestData,
+    ProtoValue,
+};
 use std::collections::HashMap;
+use std::ops::Bound;
+use std::time::Duration;
+use storage::auth::AuthContext;
+use storage::delete_where::DeleteWhere;
+use storage::index::IndexRange;
+use storage::move_row::MoveRow;
+use storage::sstable::CompactionSettingsUpdate;
 
 pub fn parse_request_from_bytes(buffer: &mut Vec<u8>) -> Result<ProtoRequest, String> {
     let request = ProtoRequest::parse_from_bytes(&buffer).map_err(|err| err.to_string());
@@ -11,6 +24,29 @@ pub fn parse_request_from_bytes(buffer: &mut Vec<u8>) -> Result<ProtoRequest, St
     request
 }
 
+// unset value leaves range open
+fn parse_bound(value: MessageField<ProtoValue>, inclusive: bool) -> Bound<Value> {
+    match value.into_option() {
+        Some(value) if inclusive => Bound::Included(parse_value_from_proto(value)),
+        Some(value) => Bound::Excluded(parse_value_from_proto(value)),
+        None => Bound::Unbounded,
+    }
+}
+
+// sort key is left unset for append-only tables, server assigns it
+fn parse_insert(insert: InsertRequest) -> Operation {
+    let sort_key = insert
+        .sort_key
+        .into_option()
+        .map_or(Value::Null, parse_value_from_proto);
+    let values: HashMap<_, _> = insert
+        .values
+        .into_iter()
+        .map(|(key, value)| (key, parse_value_from_proto(value)))
+        .collect();
+    Operation::Insert(insert.hash_key, sort_key, values)
+}
+
 pub fn parse_command_from_request(request: ProtoRequest) -> Result<Command, String> {
     let request_data = request.data.ok_or("Invalid request data".to_string())?;
 
@@ -23,16 +59,7 @@ pub fn parse_command_from_request(request: ProtoRequest) -> Result<Command, Stri
             ))
         }
         ProtoRequestData::Insert(insert) => {
-            let sort_key = parse_value_from_proto(insert.sort_key.unwrap());
-            let values: HashMap<_, _> = insert
-                .values
-                .into_iter()
-                .map(|(key, value)| (key, parse_value_from_proto(value)))
-                .collect();
-            Ok(Command::Single(
-                Operation::Insert(insert.hash_key, sort_key, values),
-                request.table,
-            ))
+            Ok(Command::Single(parse_insert(insert), request.table))
         }
         ProtoRequestData::Delete(delete) => {
             let sort_key = parse_value_from_proto(delete.sort_key.unwrap());
@@ -41,34 +68,63 @@ pub fn parse_command_from_request(request: ProtoRequest) -> Result<Command, Stri
                 request.table,
             ))
         }
+        ProtoRequestData::GetSequence(get_sequence) => Ok(Command::Single(
+            Operation::GetSequence(get_sequence.hash_key),
+            request.table,
+        )),
+        ProtoRequestData::AcquireLease(acquire_lease) => Ok(Command::Single(
+            Operation::AcquireLease(acquire_lease.name, acquire_lease.ttl_millis),
+            request.table,
+        )),
+        ProtoRequestData::RenewLease(renew_lease) => Ok(Command::Single(
+            Operation::RenewLease(
+                renew_lease.name,
+                renew_lease.version,
+                renew_lease.ttl_millis,
+            ),
+            request.table,
+        )),
+        ProtoRequestData::ReleaseLease(release_lease) => Ok(Command::Single(
+            Operation::ReleaseLease(release_lease.name, release_lease.version),
+            request.table,
+        )),
+        ProtoRequestData::RowCount(row_count) => Ok(Command::Single(
+            Operation::GetRowCount(row_count.hash_key),
+            request.table,
+        )),
         ProtoRequestData::GetMany(get_many) => {
+            let limit = match get_many.limit {
+                0 => usize::MAX,
+                limit => limit as usize,
+            };
+            let page = GetManyPage::new(&get_many.continuation_token, get_many.max_response_bytes)?;
+            if page.offset > get_many.items.len() {
+                return Err(format!(
+                    "Continuation token '{}' is past {} items",
+                    get_many.continuation_token,
+                    get_many.items.len()
+                ));
+            }
             let operations: Vec<_> = get_many
                 .items
                 .into_iter()
+                .skip(page.offset)
+                .take(limit)
                 .map(|get| {
                     let sort_key = parse_value_from_proto(get.sort_key.unwrap());
                     Operation::Get(get.hash_key, sort_key)
                 })
                 .collect();
-            Ok(Command::GetMany(operations, request.table))
+            match get_many.consistent {
+                true => Ok(Command::GetManyConsistent(operations, page, request.table)),
+                false => Ok(Command::GetMany(operations, page, request.table)),
+            }
         }
         ProtoRequestData::Batch(batch) => {
             let mut operations = Vec::with_capacity(batch.items.len());
             for item in batch.items {
                 let operation = match item.item.unwrap() {
-                    BatchItemData::Insert(insert) => {
-                        let sort_key = parse_value_from_proto(insert.sort_key.unwrap());
-                        let values: HashMap<_, _> = insert
-                            .values
-                            .into_iter()
-                            .map(|(key, value)| (key, parse_value_from_proto(value)))
-                            .collect();
-                        Ok::<Operation, String>(Operation::Insert(
-                            insert.hash_key,
-                            sort_key,
-                            values,
-                        ))
-                    }
+                    BatchItemData::Insert(insert) => Ok::<Operation, String>(parse_insert(insert)),
                     BatchItemData::Delete(delete) => {
                         let sort_key = parse_value_from_proto(delete.sort_key.unwrap());
                         Ok(Operation::Delete(delete.hash_key, sort_key))
@@ -82,10 +138,129 @@ pub fn parse_command_from_request(request: ProtoRequest) -> Result<Command, Stri
         ProtoRequestData::BeginTransaction(_) => Ok(Command::BeginTransaction),
         ProtoRequestData::CommitTransaction(_) => Ok(Command::CommitTransaction),
         ProtoRequestData::AbortTransaction(_) => Ok(Command::AbortTransaction),
+        ProtoRequestData::ListTransactions(_) => Ok(Command::ListTransactions),
+        ProtoRequestData::KillTransaction(kill_transaction_request) => Ok(
+            Command::KillTransaction(kill_transaction_request.transaction_id),
+        ),
         ProtoRequestData::SyncModel(sync_model_request) => {
             Ok(Command::SyncModel(sync_model_request.schema_string))
         }
+        ProtoRequestData::SyncModels(sync_models_request) => {
+            Ok(Command::SyncModels(sync_models_request.schema_strings))
+        }
         ProtoRequestData::DropTable(_) => Ok(Command::DropTable(request.table)),
+        ProtoRequestData::PlanMigration(plan_migration_request) => {
+            Ok(Command::PlanMigration(plan_migration_request.schema_string))
+        }
+        ProtoRequestData::DescribeTable(_) => Ok(Command::DescribeTable(request.table)),
+        ProtoRequestData::Health(_) => Ok(Command::Health),
+        ProtoRequestData::Ready(_) => Ok(Command::Ready),
+        ProtoRequestData::PartitionMap(_) => Ok(Command::PartitionMap),
+        ProtoRequestData::MigrateBucket(migrate_bucket_request) => Ok(Command::MigrateBucket(
+            migrate_bucket_request.hash_key,
+            migrate_bucket_request.target_partition as usize,
+        )),
+        ProtoRequestData::KeyDistribution(_) => Ok(Command::KeyDistribution(request.table)),
+        ProtoRequestData::PartitionLoad(_) => Ok(Command::PartitionLoad),
+        ProtoRequestData::TableAccess(_) => Ok(Command::TableAccess),
+        ProtoRequestData::Replicate(replicate_request) => Ok(Command::Replicate(
+            replicate_request.epoch,
+            replicate_request.sequence,
+        )),
+        ProtoRequestData::QueryByIndex(query_by_index) => {
+            let index_range = IndexRange {
+                start: parse_bound(query_by_index.start, query_by_index.start_inclusive),
+                end: parse_bound(query_by_index.end, query_by_index.end_inclusive),
+                column: query_by_index.column,
+            };
+            let page = IndexPage::new(&query_by_index.continuation_token, query_by_index.limit)?;
+            Ok(Command::QueryByIndex(index_range, page, request.table))
+        }
+        ProtoRequestData::DeleteWhere(delete_where_request) => {
+            // unset filter value matches null values
+            let filter = delete_where_request.filter_column.map(|column| {
+                let value = delete_where_request
+                    .filter_value
+                    .into_option()
+                    .map_or(Value::Null, parse_value_from_proto);
+                (column, value)
+            });
+            let delete_where = DeleteWhere {
+                hash_key: delete_where_request.hash_key,
+                start: parse_bound(
+                    delete_where_request.start,
+                    delete_where_request.start_inclusive,
+                ),
+                end: parse_bound(delete_where_request.end, delete_where_request.end_inclusive),
+                filter,
+            };
+            Ok(Command::DeleteWhere(delete_where, request.table))
+        }
+        ProtoRequestData::MoveRow(move_row_request) => {
+            // missing sort keys are rejected by validation
+            let move_row = MoveRow {
+                hash_key: move_row_request.hash_key,
+                sort_key: move_row_request
+                    .sort_key
+                    .into_option()
+                    .map_or(Value::Null, parse_value_from_proto),
+                new_hash_key: move_row_request.new_hash_key,
+                new_sort_key: move_row_request
+                    .new_sort_key
+                    .into_option()
+                    .map_or(Value::Null, parse_value_from_proto),
+            };
+            Ok(Command::MoveRow(move_row, request.table))
+        }
+        ProtoRequestData::Save(save_request) => {
+            let insert = save_request
+                .insert
+                .into_option()
+                .ok_or("Save request has no insert".to_string())?;
+            Ok(Command::Save(
+                parse_insert(insert),
+                save_request.version,
+                request.table,
+            ))
+        }
+        ProtoRequestData::SetReadOnly(set_read_only_request) => Ok(Command::SetReadOnly(
+            request.table,
+            set_read_only_request.read_only,
+        )),
+        ProtoRequestData::Compaction(compaction_request) => {
+            Ok(Command::Compaction(CompactionSettingsUpdate {
+                bucket_low: compaction_request.bucket_low,
+                bucket_high: compaction_request.bucket_high,
+                sstable_min_size: compaction_request.sstable_min_size,
+                interval: compaction_request
+                    .interval_millis
+                    .map(Duration::from_millis),
+                max_concurrent_compactions: compaction_request
+                    .max_concurrent_compactions
+                    .map(|max_concurrent_compactions| max_concurrent_compactions as usize),
+                io_rate_limit_mb: compaction_request.io_rate_limit_mb,
+            }))
+        }
+        ProtoRequestData::SetSession(set_session_request) => Ok(Command::SetSession(Session {
+            timeout: match set_session_request.timeout_millis {
+                0 => None,
+                millis => Some(Duration::from_millis(millis)),
+            },
+            consistency: match set_session_request.consistency.enum_value_or_default() {
+                ProtoConsistency::DEFAULT => Consistency::Default,
+                ProtoConsistency::DURABLE => Consistency::Durable,
+            },
+            keyspace: Some(set_session_request.keyspace).filter(|keyspace| !keyspace.is_empty()),
+            auth: AuthContext::anonymous(),
+            read_as_of: match set_session_request.read_as_of_millis {
+                0 => None,
+                millis => Some(millis as u128),
+            },
+            latency_sample_every: Some(set_session_request.latency_sample_every)
+                .filter(|sample_every| *sample_every > 0),
+            latency: LatencyBudget::start(),
+            read_traces: None,
+        })),
         _ => panic!("Invalid proto request data type"),
     }
 }
@@ -0,0 +1,348 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Formatter};
+
+// position of an entry in raft log of a thread, terms are compared first, (0, 0) precedes any entry
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct LogPosition {
+    pub term: u64,
+    pub index: u64,
+}
+
+impl LogPosition {
+    pub fn from_string(position: &str) -> Option<LogPosition> {
+        let (index, term) = position.trim().split_once(':')?;
+        Some(LogPosition {
+            index: index.parse().ok()?,
+            term: term.parse().ok()?,
+        })
+    }
+}
+
+impl Display for LogPosition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.index, self.term)
+    }
+}
+
+// records of a single append to commit log of a table on the leader, entries without table start
+// a term and carry no records
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RaftEntry {
+    pub term: u64,
+    pub index: u64,
+    pub table_name: String,
+    pub records: Vec<u8>,
+}
+
+impl RaftEntry {
+    pub fn position(&self) -> LogPosition {
+        LogPosition {
+            term: self.term,
+            index: self.index,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum AppendCheck {
+    // follower misses entries preceding the ones sent, leader goes back to its last one
+    Missing,
+    // entries follower does not have yet
+    Append(Vec<RaftEntry>),
+}
+
+// raft log of a thread, entries are applied to tables as soon as they are appended rather than
+// once committed, so that an entry that turns out to differ from leader's cannot be rolled back
+// and leaves the node diverged until it is seeded again
+pub(crate) struct RaftLog {
+    // last entry no longer kept in memory, as log outgrew its limit or node restarted
+    snapshot: LogPosition,
+    entries: VecDeque<RaftEntry>,
+    bytes: usize,
+    pub commit_index: u64,
+    pub diverged: Option<String>,
+    // leader only, next entry to send to every follower and last one it is known to have
+    next_index: HashMap<u64, u64>,
+    match_index: HashMap<u64, u64>,
+}
+
+impl RaftLog {
+    pub fn new(snapshot: LogPosition) -> RaftLog {
+        RaftLog {
+            snapshot,
+            entries: VecDeque::new(),
+            bytes: 0,
+            commit_index: 0,
+            diverged: None,
+            next_index: HashMap::new(),
+            match_index: HashMap::new(),
+        }
+    }
+
+    pub fn last_position(&self) -> LogPosition {
+        self.entries
+            .back()
+            .map_or(self.snapshot, RaftEntry::position)
+    }
+
+    // none for entries no longer kept
+    pub fn term_at(&self, index: u64) -> Option<u64> {
+        if index == self.snapshot.index {
+            return Some(self.snapshot.term);
+        }
+        let offset = index.checked_sub(self.snapshot.index + 1)?;
+        self.entries.get(offset as usize).map(|entry| entry.term)
+    }
+
+    // oldest entries are dropped once log outgrows its limit, followers behind them have to be
+    // seeded again
+    pub fn push(&mut self, entry: RaftEntry, max_bytes: usize) {
+        self.bytes += entry.records.len();
+        self.entries.push_back(entry);
+        while self.bytes > max_bytes {
+            let Some(dropped) = self.entries.pop_front() else {
+                break;
+            };
+            self.bytes -= dropped.records.len();
+            self.snapshot = dropped.position();
+        }
+    }
+
+    pub fn append(
+        &mut self,
+        term: u64,
+        table_name: String,
+        records: Vec<u8>,
+        max_bytes: usize,
+    ) -> u64 {
+        let index = self.last_position().index + 1;
+        let entry = RaftEntry {
+            term,
+            index,
+            table_name,
+            records,
+        };
+        self.push(entry, max_bytes);
+        index
+    }
+
+    pub fn become_leader(&mut self, peers: &[u64]) {
+        let next_index = self.last_position().index + 1;
+        self.next_index = peers.iter().map(|peer| (*peer, next_index)).collect();
+        self.match_index = peers.iter().map(|peer| (*peer, 0)).collect();
+    }
+
+    // entries from next index of the follower on, along with the entry preceding them, none once
+    // they were dropped
+    pub fn entries_for(
+        &self,
+        peer: u64,
+        max_bytes: usize,
+    ) -> Option<(LogPosition, Vec<RaftEntry>)> {
+        let last_index = self.last_position().index;
+        let next_index = self
+            .next_index
+            .get(&peer)
+            .copied()
+            .unwrap_or(last_index + 1);
+        let prev = LogPosition {
+            index: next_index - 1,
+            term: self.term_at(next_index - 1)?,
+        };
+
+        let mut bytes = 0;
+        let entries = self
+            .entries
+            .iter()
+            .skip((next_index - self.snapshot.index - 1) as usize)
+            .take_while(|entry| {
+                let taken = bytes == 0 || bytes + entry.records.len() <= max_bytes;
+                bytes += entry.records.len();
+                taken
+            })
+            .cloned()
+            .collect();
+        Some((prev, entries))
+    }
+
+    // rejected entries are sent again from after the last entry of the follower, or from one
+    // entry earlier if the follower has all of them
+    pub fn record_response(&mut self, peer: u64, success: bool, last_index: u64) {
+        let next_index = self.next_index.entry(peer).or_insert(1);
+        match success {
+            true => {
+                self.match_index.insert(peer, last_index);
+                *next_index = last_index + 1;
+            }
+            false => *next_index = (*next_index - 1).min(last_index + 1).max(1),
+        }
+    }
+
+    // highest entry on a majority of nodes, leader counts itself. Only entries of current term are
+    // committed by counting, earlier ones get committed along with them
+    pub fn advance_commit(&mut self, term: u64, cluster_size: usize) -> bool {
+        let mut indexes: Vec<u64> = self.match_index.values().copied().collect();
+        indexes.push(self.last_position().index);
+        indexes.sort_unstable_by(|first, second| second.cmp(first));
+
+        let Some(&majority_index) = indexes.get(majority(cluster_size) - 1) else {
+            return false;
+        };
+        if majority_index > self.commit_index && self.term_at(majority_index) == Some(term) {
+            self.commit_index = majority_index;
+            return true;
+        }
+        false
+    }
+
+    // entries follower kept have to match those of the leader, as they were already applied
+    pub fn check_append(
+        &self,
+        prev: LogPosition,
+        entries: &[RaftEntry],
+    ) -> Result<AppendCheck, String> {
+        let last_index = self.last_position().index;
+        if prev.index > last_index {
+            return Ok(AppendCheck::Missing);
+        }
+
+        for position in std::iter::once(prev).chain(entries.iter().map(RaftEntry::position)) {
+            if position.index > last_index {
+                break;
+            }
+            match self.term_at(position.index) {
+                Some(term) if term != position.term => {
+                    return Err(format!(
+                        "entry {} of term {} differs from entry of term {} on leader, node has to \
+                         be seeded again",
+                        position.index, term, position.term
+                    ))
+                }
+                _ => {}
+            }
+        }
+        let new_entries = entries
+            .iter()
+            .filter(|entry| entry.index > last_index)
+            .cloned()
+            .collect();
+        Ok(AppendCheck::Append(new_entries))
+    }
+}
+
+pub(crate) fn majority(cluster_size: usize) -> usize {
+    cluster_size / 2 + 1
+}
+
+// every thread keeps its own log, so candidate has to be up to date on each of them
+pub(crate) fn is_up_to_date(candidate: &[LogPosition], own: &[LogPosition]) -> bool {
+    candidate.len() == own.len()
+        && candidate
+            .iter()
+            .zip(own)
+            .all(|(candidate, own)| candidate >= own)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(term: u64, index: u64) -> RaftEntry {
+        RaftEntry {
+            term,
+            index,
+            table_name: "accounts".to_string(),
+            records: vec![0; 10],
+        }
+    }
+
+    fn position(term: u64, index: u64) -> LogPosition {
+        LogPosition { term, index }
+    }
+
+    #[test]
+    fn leader_commits_entries_of_its_term_once_majority_has_them() {
+        let mut raft_log = RaftLog::new(position(0, 0));
+        for _ in 0..3 {
+            raft_log.append(1, "accounts".to_string(), vec![0], 1024);
+        }
+        raft_log.become_leader(&[2, 3]);
+        assert_eq!(raft_log.append(2, "accounts".to_string(), vec![1], 1024), 4);
+        assert_eq!(raft_log.append(2, "accounts".to_string(), vec![2], 1024), 5);
+
+        let (prev, entries) = raft_log.entries_for(2, 1024).unwrap();
+        assert_eq!(prev, position(1, 3));
+        assert_eq!(entries.len(), 2);
+
+        // follower is one entry behind what leader assumed
+        raft_log.record_response(2, false, 2);
+        let (prev, entries) = raft_log.entries_for(2, 1024).unwrap();
+        assert_eq!(prev, position(1, 2));
+        assert_eq!(entries[0].index, 3);
+        assert!(!raft_log.advance_commit(2, 3));
+
+        raft_log.record_response(3, true, 4);
+        assert!(raft_log.advance_commit(2, 3));
+        assert_eq!(raft_log.commit_index, 4);
+
+        // entries of earlier terms are not committed by counting
+        let mut raft_log = RaftLog::new(position(1, 3));
+        raft_log.become_leader(&[2, 3]);
+        raft_log.record_response(2, true, 3);
+        assert!(!raft_log.advance_commit(2, 3));
+    }
+
+    #[test]
+    fn follower_appends_missing_entries_and_detects_divergence() {
+        let mut raft_log = RaftLog::new(position(0, 0));
+        for index in 1..=3 {
+            raft_log.push(entry(1, index), 1024);
+        }
+
+        assert_eq!(
+            raft_log.check_append(position(1, 5), &[entry(1, 6)]),
+            Ok(AppendCheck::Missing)
+        );
+        assert_eq!(
+            raft_log.check_append(position(1, 2), &[entry(1, 3), entry(2, 4)]),
+            Ok(AppendCheck::Append(vec![entry(2, 4)]))
+        );
+        assert!(raft_log
+            .check_append(position(1, 2), &[entry(2, 3), entry(2, 4)])
+            .is_err());
+        assert!(raft_log.check_append(position(2, 3), &[]).is_err());
+    }
+
+    #[test]
+    fn entries_dropped_from_memory_are_not_sent() {
+        let mut raft_log = RaftLog::new(position(0, 0));
+        raft_log.become_leader(&[2]);
+        for _ in 0..3 {
+            raft_log.append(1, "accounts".to_string(), vec![0; 10], 25);
+        }
+        assert_eq!(raft_log.last_position(), position(1, 3));
+        assert_eq!(raft_log.term_at(1), Some(1));
+        assert_eq!(raft_log.term_at(0), None);
+        assert_eq!(raft_log.entries_for(2, 1024), None);
+
+        raft_log.record_response(2, true, 2);
+        let (prev, entries) = raft_log.entries_for(2, 1024).unwrap();
+        assert_eq!(prev, position(1, 2));
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn candidate_has_to_be_up_to_date_on_every_thread() {
+        let own = [position(2, 10), position(1, 4)];
+        assert!(is_up_to_date(&[position(2, 10), position(2, 1)], &own));
+        assert!(is_up_to_date(&[position(2, 11), position(1, 4)], &own));
+        assert!(!is_up_to_date(&[position(3, 1), position(1, 3)], &own));
+        assert!(!is_up_to_date(&[position(3, 1)], &own));
+        assert_eq!(
+            LogPosition::from_string(&position(3, 12).to_string()),
+            Some(position(3, 12))
+        );
+        assert_eq!(majority(1), 1);
+        assert_eq!(majority(4), 3);
+    }
+}
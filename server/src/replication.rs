@@ -0,0 +1,561 @@
+use crate::cluster::ClusterConfig;
+use crate::context::ThreadContext;
+use crate::database::Database;
+use crate::handlers::{share_table_schemas, HandlerError};
+use crate::listener::TCP_STARTING_PORT;
+use crate::peer::PeerConnection;
+use crate::thread_channels::Operation::{Get, GetRowCount};
+use crate::thread_channels::{send_set_read_only, send_sync_models, Command, Response};
+use futures::lock::Mutex;
+use protos::{
+    ProtoRequest, ProtoRequestData, ProtoResponseData, ReplicateRequest, ReplicateResponse,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::RwLock;
+use std::time::Duration;
+use storage::data_dir::DataDir;
+use storage::log_syncer::sync_commit_logs_of;
+use storage::replication::{apply_replicated_records, read_replicated, ReplicatedBatch};
+use storage::table::{set_read_only, sync_models, Table, TableSchema};
+use storage::MEGABYTE;
+
+// standby waits that long for new records once it caught up
+static STANDBY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+static STANDBY_RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+static REPLICATE_RESPONSE_MAX_BYTES: usize = 4 * MEGABYTE;
+
+static STANDBY: RwLock<Option<StandbyConfig>> = RwLock::new(None);
+// why standby of a database stopped applying records on a thread, by database name and thread
+static STANDBY_FAILURES: std::sync::Mutex<BTreeMap<(String, usize), String>> =
+    std::sync::Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplicationConfig {
+    // records appended to commit logs are kept in memory up to that size per thread and database,
+    // for standby servers to pull, 0 keeps none
+    pub log_max_bytes: usize,
+    pub standby: Option<StandbyConfig>,
+    pub cluster: Option<ClusterConfig>,
+}
+
+// server started as standby applies records of its leader until promoted, every thread pulls them
+// from the thread of the same number, so that both servers have to run the same number of threads
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandbyConfig {
+    pub leader_host: String,
+    // port of the first thread of the leader
+    pub leader_port: usize,
+    // reads are rejected otherwise, as standby is behind its leader
+    pub serves_reads: bool,
+}
+
+impl StandbyConfig {
+    pub(crate) fn leader_address(&self, thread_number: usize) -> String {
+        format!("{}:{}", self.leader_host, self.leader_port + thread_number)
+    }
+}
+
+// leader is "host" or "host:port", empty leader means the server is not a standby
+pub fn parse_replication_config(
+    log_max_mb: &str,
+    leader: &str,
+    serves_reads: &str,
+) -> Result<ReplicationConfig, String> {
+    let log_max_bytes = match log_max_mb.trim() {
+        "" => 0,
+        log_max_mb => {
+            log_max_mb.parse::<usize>().map_err(|_| {
+                format!("Invalid size of replication log '{}' megabytes", log_max_mb)
+            })? * MEGABYTE
+        }
+    };
+    let serves_reads = match serves_reads.trim() {
+        "" | "false" => false,
+        "true" => true,
+        serves_reads => {
+            return Err(format!(
+                "Invalid standby reads '{}', expected true or false",
+                serves_reads
+            ))
+        }
+    };
+
+    let standby = match leader.trim() {
+        "" => None,
+        leader => {
+            let (leader_host, leader_port) = parse_address(leader)?;
+            Some(StandbyConfig {
+                leader_host,
+                leader_port,
+                serves_reads,
+            })
+        }
+    };
+
+    // set from its own variables, as cluster nodes are not standby servers
+    Ok(ReplicationConfig {
+        log_max_bytes,
+        standby,
+        cluster: None,
+    })
+}
+
+// "host" or "host:port", port of the first thread defaults to the one server listens on
+pub(crate) fn parse_address(address: &str) -> Result<(String, usize), String> {
+    let (host, port) = match address.rsplit_once(':') {
+        Some((host, port)) => (
+            host,
+            port.parse()
+                .map_err(|_| format!("Invalid port of '{}'", address))?,
+        ),
+        None => (address, TCP_STARTING_PORT),
+    };
+    if host.is_empty() {
+        return Err(format!(
+            "Invalid address '{}', host cannot be empty",
+            address
+        ));
+    }
+    Ok((host.to_string(), port))
+}
+
+fn promoted_file_path(data_dir: &DataDir) -> String {
+    format!("{}/promoted", data_dir.root)
+}
+
+fn standby_position_file_path(data_dir: &DataDir, thread_number: usize) -> String {
+    format!("{}/standby_position_{}", data_dir.root, thread_number)
+}
+
+// data directory of a promoted standby has writes its old leader never saw, so it cannot follow
+// that leader again
+pub(crate) fn check_standby_start(
+    standby: &Option<StandbyConfig>,
+    data_dirs: &[&DataDir],
+) -> Result<(), String> {
+    if standby.is_none() {
+        return Ok(());
+    }
+    match data_dirs
+        .iter()
+        .find(|data_dir| std::path::Path::new(&promoted_file_path(data_dir)).exists())
+    {
+        Some(data_dir) => Err(format!(
+            "Data directory '{}' was promoted to leader, it cannot be a standby again",
+            data_dir.root
+        )),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn start_as_standby(standby: Option<StandbyConfig>) {
+    *STANDBY.write().unwrap() = standby;
+}
+
+pub(crate) fn standby_config() -> Option<StandbyConfig> {
+    STANDBY.read().unwrap().clone()
+}
+
+// true if server was a standby, pulls of its threads stop before their next batch
+pub(crate) fn promote(databases: &HashMap<String, Database>) -> Result<bool, HandlerError> {
+    let mut standby = STANDBY.write().unwrap();
+    if standby.is_none() {
+        return Ok(false);
+    }
+    for database in databases.values() {
+        std::fs::write(promoted_file_path(&database.thread_context.data_dir), "")
+            .map_err(|e| HandlerError::Server(format!("Failed to persist promotion: {}", e)))?;
+    }
+    *standby = None;
+    tracing::warn!("Standby promoted to leader, writes are accepted");
+    Ok(true)
+}
+
+// what a request does to tables, servers that do not lead reject writes and serve reads only if
+// configured to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum RoleAccess {
+    Read,
+    Write,
+    Admin,
+}
+
+pub(crate) fn role_access(command: &Command, transaction_id: Option<u64>) -> RoleAccess {
+    match command {
+        // reads of transaction take part in its commit
+        _ if transaction_id.is_some() => RoleAccess::Write,
+        Command::Single(Get(_, _) | GetRowCount(_), _)
+        | Command::GetMany(_, _, _)
+        | Command::GetManyConsistent(_, _, _)
+        | Command::QueryByIndex(_, _, _) => RoleAccess::Read,
+        Command::Single(_, _)
+        | Command::Batch(_, _)
+        | Command::DeleteWhere(_, _)
+        | Command::MoveRow(_, _)
+        | Command::Save(_, _, _)
+        | Command::BeginTransaction
+        | Command::CommitTransaction
+        | Command::AbortTransaction
+        | Command::KillTransaction(_)
+        | Command::SyncModel(_)
+        | Command::SyncModels(_)
+        | Command::DropTable(_)
+        | Command::MigrateBucket(_, _)
+        | Command::SetReadOnly(_, _) => RoleAccess::Write,
+        _ => RoleAccess::Admin,
+    }
+}
+
+// standby only takes writes through records of its leader, reads only if configured to serve them
+pub(crate) fn check_standby(
+    command: &Command,
+    transaction_id: Option<u64>,
+) -> Result<(), HandlerError> {
+    let Some(standby) = standby_config() else {
+        return Ok(());
+    };
+
+    let allowed = match role_access(command, transaction_id) {
+        RoleAccess::Read => standby.serves_reads,
+        RoleAccess::Write => false,
+        RoleAccess::Admin => true,
+    };
+    match allowed {
+        true => Ok(()),
+        false => Err(HandlerError::NotLeader {
+            leader: Some(standby.leader_address(0)),
+        }),
+    }
+}
+
+pub(crate) fn standby_failures() -> Vec<String> {
+    STANDBY_FAILURES
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|((database_name, thread_number), failure)| {
+            format!(
+                "standby of database '{}' stopped on thread {}: {}",
+                database_name, thread_number, failure
+            )
+        })
+        .collect()
+}
+
+fn report_standby_failure(database_name: &str, thread_number: usize, failure: String) {
+    tracing::error!(
+        "Standby of database '{}' stopped on thread {}: {}",
+        database_name,
+        thread_number,
+        failure
+    );
+    STANDBY_FAILURES
+        .lock()
+        .unwrap()
+        .insert((database_name.to_string(), thread_number), failure);
+}
+
+// records of this thread along with schemas of their tables, records of tables dropped since they
+// were appended are left out, as drops are not replicated
+pub(crate) async fn replicate(
+    epoch: u64,
+    sequence: u64,
+    thread_context: &ThreadContext,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+) -> Result<Response, HandlerError> {
+    let mut batch = read_replicated(
+        &thread_context.data_dir.commit_logs,
+        epoch,
+        sequence,
+        REPLICATE_RESPONSE_MAX_BYTES,
+    )
+    .map_err(HandlerError::Client)?;
+
+    let tables = tables.lock().await;
+    batch
+        .records
+        .retain(|records| tables.contains_key(&records.table_name));
+    let table_names: HashSet<_> = batch
+        .records
+        .iter()
+        .map(|records| &records.table_name)
+        .collect();
+    let schema_strings = table_names
+        .into_iter()
+        .map(|table_name| tables[table_name].table_schema.to_string())
+        .collect();
+
+    Ok(Response::Replicate(
+        batch,
+        schema_strings,
+        thread_context.partition_map().to_string(),
+    ))
+}
+
+pub(crate) fn replicate_response(
+    batch: ReplicatedBatch,
+    schema_strings: Vec<String>,
+    partition_map: String,
+) -> ReplicateResponse {
+    let mut replicate_response = ReplicateResponse::new();
+    replicate_response.epoch = batch.epoch;
+    replicate_response.next_sequence = batch.next_sequence;
+    replicate_response.records = batch
+        .records
+        .into_iter()
+        .map(|records| {
+            let mut replicated_records = protos::ReplicatedRecords::new();
+            replicated_records.sequence = records.sequence;
+            replicated_records.table = records.table_name;
+            replicated_records.records = records.bytes;
+            replicated_records
+        })
+        .collect();
+    replicate_response.schema_strings = schema_strings;
+    replicate_response.partition_map = partition_map;
+    replicate_response
+}
+
+// epoch and sequence of the next record to pull, standby seeded with a copy of data directory
+// starts from the beginning of leader's epoch
+fn read_standby_position(data_dir: &DataDir, thread_number: usize) -> (u64, u64) {
+    std::fs::read_to_string(standby_position_file_path(data_dir, thread_number))
+        .ok()
+        .and_then(|position| {
+            let (epoch, sequence) = position.trim().split_once(':')?;
+            Some((epoch.parse().ok()?, sequence.parse().ok()?))
+        })
+        .unwrap_or((0, 0))
+}
+
+// pulls records of the thread of the same number on the leader until server gets promoted, or
+// until records cannot follow the leader anymore, e.g. when it restarted and lost its records
+pub(crate) async fn standby_main(database_name: String, mut database: Database) {
+    let thread_number = database.thread_context.current_thread_number;
+    let mut position = read_standby_position(&database.thread_context.data_dir, thread_number);
+    let mut connection = None;
+
+    while let Some(standby) = standby_config() {
+        let peer = match connection.as_mut() {
+            Some(peer) => peer,
+            None => match PeerConnection::connect(&standby.leader_address(thread_number)).await {
+                Ok(peer) if peer.number_of_threads != database.thread_context.number_of_threads => {
+                    let failure = format!(
+                        "leader runs {} threads, standby runs {}",
+                        peer.number_of_threads, database.thread_context.number_of_threads
+                    );
+                    report_standby_failure(&database_name, thread_number, failure);
+                    return;
+                }
+                Ok(peer) => connection.insert(peer),
+                Err(error) => {
+                    tracing::warn!("{}", error);
+                    monoio::time::sleep(STANDBY_RECONNECT_INTERVAL).await;
+                    continue;
+                }
+            },
+        };
+
+        let mut replicate_request = ReplicateRequest::new();
+        (replicate_request.epoch, replicate_request.sequence) = position;
+        let mut request = ProtoRequest::new();
+        request.database = database_name.clone();
+        request.data = Some(ProtoRequestData::Replicate(replicate_request));
+
+        let replicate_response = match peer.request(request).await.map(|response| response.data) {
+            Ok(Some(ProtoResponseData::Replicate(replicate_response))) => replicate_response,
+            Ok(Some(ProtoResponseData::ClientError(client_error))) => {
+                report_standby_failure(&database_name, thread_number, client_error.detail);
+                return;
+            }
+            Ok(_) | Err(_) => {
+                connection = None;
+                monoio::time::sleep(STANDBY_RECONNECT_INTERVAL).await;
+                continue;
+            }
+        };
+
+        let caught_up = replicate_response.records.is_empty();
+        match apply_replicate_response(replicate_response, &mut database).await {
+            Ok(Some(next_position)) => position = next_position,
+            // promoted in the middle of the batch
+            Ok(None) => return,
+            Err(error) => {
+                report_standby_failure(&database_name, thread_number, error);
+                return;
+            }
+        }
+        if caught_up {
+            monoio::time::sleep(STANDBY_POLL_INTERVAL).await;
+        }
+    }
+}
+
+// position is persisted once records reached synced commit logs, records applied again after a
+// crash in between only bump versions of their rows
+async fn apply_replicate_response(
+    replicate_response: ReplicateResponse,
+    database: &mut Database,
+) -> Result<Option<(u64, u64)>, String> {
+    if standby_config().is_none() {
+        return Ok(None);
+    }
+    let records = replicate_response
+        .records
+        .into_iter()
+        .map(|replicated_records| (replicated_records.table, replicated_records.records))
+        .collect();
+    apply_leader_records(
+        records,
+        replicate_response.schema_strings,
+        &replicate_response.partition_map,
+        database,
+    )
+    .await?;
+
+    let thread_context = &database.thread_context;
+    let position = (replicate_response.epoch, replicate_response.next_sequence);
+    std::fs::write(
+        standby_position_file_path(
+            &thread_context.data_dir,
+            thread_context.current_thread_number,
+        ),
+        format!("{}:{}", position.0, position.1),
+    )
+    .map_err(|e| format!("failed to persist standby position: {}", e))?;
+    Ok(Some(position))
+}
+
+// records of the leader by table they belong to, applied once schemas of leader's tables are synced
+// and written to synced commit logs. Records of tables neither side has are left out, they were
+// dropped on the leader after it appended them
+pub(crate) async fn apply_leader_records(
+    records: Vec<(String, Vec<u8>)>,
+    schema_strings: Vec<String>,
+    partition_map: &str,
+    database: &mut Database,
+) -> Result<(), String> {
+    let thread_context = database.thread_context.clone();
+    if partition_map != thread_context.partition_map().to_string() {
+        return Err("partition map of leader changed, server has to be seeded again".to_string());
+    }
+    sync_leader_schemas(schema_strings, database).await?;
+
+    let mut commit_logs = Vec::new();
+    let mut tables = database.tables.lock().await;
+    for (table_name, records) in records {
+        let Some(table) = tables.get_mut(&table_name) else {
+            continue;
+        };
+        apply_replicated_records(
+            table,
+            &records,
+            &thread_context.partitions,
+            &thread_context.partition_map(),
+        )
+        .await?;
+        commit_logs.push(table.commit_log.clone());
+    }
+    drop(tables);
+    sync_commit_logs_of(&commit_logs).await
+}
+
+// tables created on the leader are created on every thread of the follower, schemas of existing tables
+// cannot change but for metadata and read-only flag
+async fn sync_leader_schemas(
+    schema_strings: Vec<String>,
+    database: &mut Database,
+) -> Result<(), String> {
+    let thread_context = &database.thread_context;
+    for schema_string in schema_strings {
+        let table_schema = TableSchema::from_string(&schema_string)?;
+        let current_schema = database
+            .tables
+            .lock()
+            .await
+            .get(&table_schema.name)
+            .map(|table| table.table_schema.clone());
+        if current_schema
+            .as_ref()
+            .is_some_and(|current_schema| current_schema.to_string() == schema_string)
+        {
+            continue;
+        }
+
+        sync_models(
+            vec![schema_string.clone()],
+            database.tables.clone(),
+            &thread_context.partitions,
+            &thread_context.data_dir,
+        )
+        .await?;
+        send_sync_models(
+            vec![schema_string],
+            &mut database.senders,
+            thread_context.current_thread_number,
+        )
+        .await;
+        if current_schema.is_some_and(|current_schema| {
+            current_schema.options.read_only != table_schema.options.read_only
+        }) {
+            let read_only = table_schema.options.read_only;
+            set_read_only(
+                &table_schema.name,
+                read_only,
+                database.tables.clone(),
+                &thread_context.data_dir.table_schemas_file,
+            )
+            .await?;
+            send_set_read_only(
+                table_schema.name,
+                read_only,
+                &mut database.senders,
+                thread_context.current_thread_number,
+            )
+            .await;
+        }
+        share_table_schemas(&database.tables, thread_context).await;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replication_config_is_parsed() {
+        assert_eq!(
+            parse_replication_config("", "", ""),
+            Ok(ReplicationConfig {
+                log_max_bytes: 0,
+                standby: None,
+                cluster: None,
+            })
+        );
+        assert_eq!(
+            parse_replication_config("64", "10.0.0.1", "true"),
+            Ok(ReplicationConfig {
+                log_max_bytes: 64 * MEGABYTE,
+                standby: Some(StandbyConfig {
+                    leader_host: "10.0.0.1".to_string(),
+                    leader_port: TCP_STARTING_PORT,
+                    serves_reads: true,
+                }),
+                cluster: None,
+            })
+        );
+        let standby = parse_replication_config("", "leader:30800", "")
+            .unwrap()
+            .standby
+            .unwrap();
+        assert_eq!(standby.leader_address(2), "leader:30802");
+        assert!(!standby.serves_reads);
+
+        assert!(parse_replication_config("lots", "", "").is_err());
+        assert!(parse_replication_config("", "leader:port", "").is_err());
+        assert!(parse_replication_config("", ":29800", "").is_err());
+        assert!(parse_replication_config("", "leader", "yes").is_err());
+    }
+}
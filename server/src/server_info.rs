@@ -0,0 +1,147 @@
+use crate::cluster::{cluster_config, cluster_role};
+use crate::database::{Database, DEFAULT_DATABASE};
+use crate::latency::SLOW_REQUEST_THRESHOLD;
+use crate::listener::TCP_STARTING_PORT;
+use crate::replication::standby_config;
+use crate::thread_monitor::THREAD_MAX_RESTARTS;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use storage::data_dir::DataDir;
+use storage::replication::replication_log_max_bytes;
+use storage::transaction::WRITE_INTENT_TIMEOUT_MILLIS;
+
+// settings server was started with, that threads do not keep on their own
+#[derive(Debug, Clone)]
+struct ServerStart {
+    started_at: Instant,
+    compaction_threads: usize,
+}
+
+static SERVER_START: OnceLock<ServerStart> = OnceLock::new();
+
+pub(crate) fn report_server_started(compaction_threads: usize) {
+    let _ = SERVER_START.set(ServerStart {
+        started_at: Instant::now(),
+        compaction_threads,
+    });
+}
+
+// state of the whole deployment, as support and tooling capture it in one call
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServerDescription {
+    pub version: String,
+    pub uptime: Duration,
+    pub number_of_threads: usize,
+    pub config: BTreeMap<String, String>,
+    pub features: Vec<String>,
+    pub databases: Vec<DatabaseDescription>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatabaseDescription {
+    pub name: String,
+    pub data_dir: DataDir,
+    // thread serving every partition, indexed by partition
+    pub partition_threads: Vec<usize>,
+}
+
+pub(crate) fn describe_server(databases: &HashMap<String, Database>) -> ServerDescription {
+    let server_start = SERVER_START.get();
+    let mut config = BTreeMap::from([
+        (
+            "slow_request_threshold_millis",
+            SLOW_REQUEST_THRESHOLD.as_millis().to_string(),
+        ),
+        (
+            "write_intent_timeout_millis",
+            WRITE_INTENT_TIMEOUT_MILLIS.to_string(),
+        ),
+        ("thread_max_restarts", THREAD_MAX_RESTARTS.to_string()),
+        ("tcp_starting_port", TCP_STARTING_PORT.to_string()),
+    ]);
+    if let Some(server_start) = server_start {
+        config.insert(
+            "compaction_threads",
+            server_start.compaction_threads.to_string(),
+        );
+    }
+    config.insert(
+        "replication_log_max_bytes",
+        replication_log_max_bytes().to_string(),
+    );
+    match standby_config() {
+        Some(standby) => {
+            config.insert("role", "standby".to_string());
+            config.insert(
+                "standby_of",
+                format!("{}:{}", standby.leader_host, standby.leader_port),
+            );
+            config.insert("standby_serves_reads", standby.serves_reads.to_string());
+        }
+        None => {
+            config.insert("role", "leader".to_string());
+        }
+    }
+    // role is the one node has for the default database, every database elects its own leader
+    if let Some(cluster) = cluster_config() {
+        if let Some(role) = cluster_role(DEFAULT_DATABASE) {
+            config.insert("role", role.to_string());
+        }
+        config.insert("cluster_node_id", cluster.node_id.to_string());
+        let nodes: Vec<_> = cluster
+            .nodes
+            .iter()
+            .map(|node| format!("{}={}:{}", node.id, node.host, node.port))
+            .collect();
+        config.insert("cluster_nodes", nodes.join(","));
+        config.insert("follower_reads", cluster.follower_reads.to_string());
+    }
+
+    let number_of_threads = databases
+        .values()
+        .next()
+        .map(|database| database.thread_context.number_of_threads)
+        .unwrap_or(0);
+    let mut databases: Vec<_> = databases
+        .iter()
+        .map(|(name, database)| {
+            let thread_context = &database.thread_context;
+            let num_of_partitions = thread_context.partition_map().num_of_partitions;
+            DatabaseDescription {
+                name: name.clone(),
+                data_dir: thread_context.data_dir.clone(),
+                partition_threads: (0..num_of_partitions)
+                    .map(|partition| thread_context.get_partition_thread(partition))
+                    .collect(),
+            }
+        })
+        .collect();
+    databases.sort_by(|first, second| first.name.cmp(&second.name));
+
+    ServerDescription {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        // servers run by simulation never report starting
+        uptime: server_start
+            .map(|server_start| server_start.started_at.elapsed())
+            .unwrap_or_default(),
+        number_of_threads,
+        config: config
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+        features: enabled_features(),
+        databases,
+    }
+}
+
+fn enabled_features() -> Vec<String> {
+    let mut features = Vec::new();
+    if cfg!(feature = "simulation") {
+        features.push("simulation".to_string());
+    }
+    if cfg!(feature = "opentelemetry") {
+        features.push("opentelemetry".to_string());
+    }
+    features
+}
@@ -0,0 +1,44 @@
+use crate::latency::LatencyBudget;
+use std::cell::RefCell;
+use std::time::Duration;
+use storage::auth::AuthContext;
+use storage::sstable::ReadTrace;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Consistency {
+    // writes are acknowledged according to table's durability
+    #[default]
+    Default,
+    // writes are acknowledged once commit log is synced, whatever table's durability
+    Durable,
+}
+
+// settings negotiated once per connection, applied to every request sent over it
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    // reads taking longer are abandoned and answered with timeout error
+    pub timeout: Option<Duration>,
+    pub consistency: Consistency,
+    // table used by requests that do not name one
+    pub keyspace: Option<String>,
+    // anonymous until connection authenticates, kept when session settings change
+    pub auth: AuthContext,
+    // gets see rows as of that time, including history of compacted sstables, for inspecting
+    // data after bad writes, writes are rejected
+    pub read_as_of: Option<u128>,
+    // every n-th response carries latency breakdown, for debugging tail latency
+    pub latency_sample_every: Option<u32>,
+    // phases of request being handled, copied into every request handled concurrently
+    pub latency: LatencyBudget,
+    // set for requests asking to explain their reads, gets push what they consulted
+    pub read_traces: Option<RefCell<Vec<ReadTrace>>>,
+}
+
+impl Session {
+    pub fn resolve_table(&self, table_name: String) -> String {
+        match (&self.keyspace, table_name.is_empty()) {
+            (Some(keyspace), true) => keyspace.clone(),
+            _ => table_name,
+        }
+    }
+}
@@ -0,0 +1,1000 @@
+use crate::database::{Database, DatabaseConfig, DEFAULT_DATABASE};
+use crate::handlers::{handle_request, handler_error_to_proto_response};
+use crate::listener::{open_database, prepare_database};
+use crate::session::Session;
+use crate::thread_channels::ThreadMessage;
+use monoio::FusionDriver;
+use protos::{ProtoRequest, ProtoRequestData, ProtoResponse};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::poll_fn;
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::Duration;
+use storage::data_dir::DataDir;
+use storage::io::{clear_io_faults, inject_io_fault, IoFault};
+use storage::simulation::{
+    advance_clock, next_random_u64, set_flush_delay, start_simulation, stop_simulation,
+};
+
+// virtual clock starts at fixed time, so that row timestamps are the same in every run
+static SIMULATION_START_MILLIS: u128 = 1_700_000_000_000;
+// times every step yields to the runtime, so that messages it sent to other threads get handled
+static SETTLE_YIELDS: usize = 64;
+
+thread_local! {
+    static FAULTS: RefCell<Option<FaultState>> = const { RefCell::new(None) };
+}
+
+struct FaultState {
+    drop_thread_message_probability: f64,
+    crashed_threads: HashSet<usize>,
+}
+
+// faults are drawn from seeded rng, so the same seed and workload reproduce the same run
+#[derive(Debug, Clone, Default)]
+pub struct Faults {
+    // chance of losing a message between threads that nobody waits an answer for,
+    // e.g. transaction begun or committed
+    pub drop_thread_message_probability: f64,
+    // flushed memtables reach sstables only once virtual clock passes that delay
+    pub flush_delay: Duration,
+    // failing or stalling file operations, e.g. commit log appends or fsyncs
+    pub io_faults: Vec<IoFault>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    pub seed: u64,
+    pub number_of_threads: usize,
+    // None means one partition per thread
+    pub num_of_partitions: Option<usize>,
+    // has to be empty, every run starts from scratch
+    pub data_dir: DataDir,
+    pub faults: Faults,
+}
+
+#[derive(Debug, Clone)]
+pub enum Step {
+    // sent over connection of given client to thread owning request's hash key, or to thread 0
+    Request {
+        client: usize,
+        request: ProtoRequest,
+    },
+    AdvanceClock(Duration),
+    // thread stops handling requests and messages of other threads, as if it panicked
+    CrashThread(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepOutcome {
+    Response(ProtoResponse),
+    ThreadCrashed(usize),
+    Done,
+}
+
+// every thread of the server runs on a single runtime, so steps and faults happen in the same order
+pub fn run_simulation(config: SimulationConfig, workload: Vec<Step>) -> Vec<StepOutcome> {
+    let mut runtime = monoio::RuntimeBuilder::<FusionDriver>::new()
+        .enable_timer()
+        .build()
+        .unwrap();
+
+    runtime.block_on(simulate(config, workload))
+}
+
+async fn simulate(config: SimulationConfig, workload: Vec<Step>) -> Vec<StepOutcome> {
+    start_simulation(config.seed, SIMULATION_START_MILLIS);
+    set_flush_delay(config.faults.flush_delay);
+    for io_fault in &config.faults.io_faults {
+        inject_io_fault(io_fault.clone());
+    }
+    FAULTS.with(|faults| {
+        *faults.borrow_mut() = Some(FaultState {
+            drop_thread_message_probability: config.faults.drop_thread_message_probability,
+            crashed_threads: HashSet::new(),
+        })
+    });
+
+    let prepared_database = prepare_database(
+        DatabaseConfig {
+            name: DEFAULT_DATABASE.to_string(),
+            data_dir: config.data_dir,
+            num_of_partitions: config.num_of_partitions,
+        },
+        config.number_of_threads.max(1),
+        Arc::new(AtomicUsize::new(0)),
+    )
+    .await;
+    monoio::spawn(prepared_database.compaction_task.run());
+
+    let mut threads: Vec<HashMap<String, Database>> = Vec::new();
+    for setup in prepared_database.setups {
+        let database = open_database(setup).await;
+        threads.push(HashMap::from([(DEFAULT_DATABASE.to_string(), database)]));
+    }
+
+    let mut sessions: HashMap<(usize, usize), Session> = HashMap::new();
+    let mut outcomes = Vec::with_capacity(workload.len());
+    for step in workload {
+        let outcome = match step {
+            Step::Request { client, request } => {
+                let thread = request_thread(&request, &threads[0][DEFAULT_DATABASE]);
+                match is_thread_crashed(thread) {
+                    true => StepOutcome::ThreadCrashed(thread),
+                    false => {
+                        let session = sessions.entry((client, thread)).or_default();
+                        match handle_request(request, session, &mut threads[thread]).await {
+                            Ok(proto_response) => StepOutcome::Response(proto_response),
+                            // only reading from tcp stream can find client disconnected
+                            Err(handler_error) => StepOutcome::Response(
+                                handler_error_to_proto_response(handler_error).unwrap(),
+                            ),
+                        }
+                    }
+                }
+            }
+            Step::AdvanceClock(duration) => {
+                advance_clock(duration);
+                StepOutcome::Done
+            }
+            Step::CrashThread(thread) => {
+                FAULTS.with(|faults| {
+                    if let Some(state) = faults.borrow_mut().as_mut() {
+                        state.crashed_threads.insert(thread);
+                    }
+                });
+                StepOutcome::Done
+            }
+        };
+        outcomes.push(outcome);
+        settle().await;
+    }
+
+    FAULTS.with(|faults| *faults.borrow_mut() = None);
+    for io_fault in &config.faults.io_faults {
+        clear_io_faults(&io_fault.path_pattern);
+    }
+    stop_simulation();
+    outcomes
+}
+
+// tasks woken by the step run before simulation gets polled again,
+// file io is still done on real disk, so that only bounds how long a step is waited for
+async fn settle() {
+    for _ in 0..SETTLE_YIELDS {
+        let mut yielded = false;
+        poll_fn(|context| match yielded {
+            true => Poll::Ready(()),
+            false => {
+                yielded = true;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await;
+    }
+}
+
+fn request_thread(request: &ProtoRequest, database: &Database) -> usize {
+    let hash_key = match &request.data {
+        Some(ProtoRequestData::Get(get_request)) => &get_request.hash_key,
+        Some(ProtoRequestData::Insert(insert_request)) => &insert_request.hash_key,
+        Some(ProtoRequestData::Delete(delete_request)) => &delete_request.hash_key,
+        Some(ProtoRequestData::GetSequence(sequence_request)) => &sequence_request.hash_key,
+        Some(ProtoRequestData::RowCount(row_count_request)) => &row_count_request.hash_key,
+        Some(ProtoRequestData::DeleteWhere(delete_where_request)) => &delete_where_request.hash_key,
+        Some(ProtoRequestData::MoveRow(move_row_request)) => &move_row_request.hash_key,
+        Some(ProtoRequestData::Save(save_request)) => &save_request.insert.hash_key,
+        _ => return 0,
+    };
+    let thread_context = &database.thread_context;
+    thread_context.get_partition_thread(thread_context.get_partition(hash_key))
+}
+
+fn is_thread_crashed(thread: usize) -> bool {
+    FAULTS.with(|faults| {
+        faults
+            .borrow()
+            .as_ref()
+            .is_some_and(|state| state.crashed_threads.contains(&thread))
+    })
+}
+
+// true if message is lost before reaching the thread, outside of simulation nothing is lost
+pub(crate) fn drops_thread_message(thread: usize, thread_message: &ThreadMessage) -> bool {
+    if is_thread_crashed(thread) {
+        return true;
+    }
+
+    let probability = FAULTS.with(|faults| {
+        faults
+            .borrow()
+            .as_ref()
+            .map(|state| state.drop_thread_message_probability)
+            .unwrap_or_default()
+    });
+    let expects_answer = !matches!(
+        thread_message,
+        ThreadMessage::TransactionBegun(_)
+            | ThreadMessage::TransactionCommit(_)
+            | ThreadMessage::TransactionAborted(_)
+            | ThreadMessage::CheckReference(_)
+    );
+    if probability <= 0.0 || expects_answer {
+        return false;
+    }
+
+    (next_random_u64() as f64 / u64::MAX as f64) < probability
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::handle_tcp_stream;
+    use common::partition::PartitionMap;
+    use common::value::Value;
+    use monoio::io::{AsyncReadRentExt, AsyncWriteRentExt};
+    use monoio::net::{TcpListener, TcpStream};
+    use protobuf::Message;
+    use protos::util::parse_proto_from_value;
+    use protos::{
+        BeginTransaction, CommitTransaction, DeleteRequest, DeleteWhereRequest,
+        DescribeServerRequest, DropTableRequest, ErrorCode, GetManyRequest, GetRequest,
+        InsertRequest, MoveRowRequest, ProtoResponseData, QueryByIndexRequest, SaveRequest,
+        SyncModelRequest,
+    };
+    use storage::commit_log::ConsistencyToken;
+    use storage::io::{IoFaultEffect, IoOperation};
+
+    fn request(data: ProtoRequestData, transaction_id: Option<u64>) -> ProtoRequest {
+        let mut request = ProtoRequest::new();
+        request.table = "account".to_string();
+        request.transaction_id = transaction_id;
+        request.data = Some(data);
+        request
+    }
+
+    fn insert(hash_key: &str, transaction_id: Option<u64>) -> Step {
+        let mut insert_request = InsertRequest::new();
+        insert_request.hash_key = hash_key.to_string();
+        insert_request.sort_key = Some(parse_proto_from_value(Value::Int32(-1))).into();
+        Step::Request {
+            client: 0,
+            request: request(ProtoRequestData::Insert(insert_request), transaction_id),
+        }
+    }
+
+    fn get(hash_key: &str) -> Step {
+        let mut get_request = GetRequest::new();
+        get_request.hash_key = hash_key.to_string();
+        get_request.sort_key = Some(parse_proto_from_value(Value::Int32(-1))).into();
+        Step::Request {
+            client: 0,
+            request: request(ProtoRequestData::Get(get_request), None),
+        }
+    }
+
+    // hash keys owned by different threads, so that transaction spans both
+    fn hash_keys_on_two_threads() -> (String, String) {
+        let partition_map = PartitionMap::new(2);
+        let mut hash_keys = (0..).map(|number| format!("account_{}", number));
+        let first = hash_keys.next().unwrap();
+        let second = hash_keys
+            .find(|hash_key| {
+                partition_map.get_partition(hash_key) != partition_map.get_partition(&first)
+            })
+            .unwrap();
+        (first, second)
+    }
+
+    fn run(seed: u64, faults: Faults, workload: Vec<Step>) -> Vec<StepOutcome> {
+        run_with_partitions(seed, None, faults, workload)
+    }
+
+    fn run_with_partitions(
+        seed: u64,
+        num_of_partitions: Option<usize>,
+        faults: Faults,
+        workload: Vec<Step>,
+    ) -> Vec<StepOutcome> {
+        let data_dir = format!("/tmp/yard_simulation_{}_{}", seed, std::process::id());
+        let _ = std::fs::remove_dir_all(&data_dir);
+        let outcomes = run_simulation(
+            SimulationConfig {
+                seed,
+                number_of_threads: 2,
+                num_of_partitions,
+                data_dir: DataDir::new(&data_dir),
+                faults,
+            },
+            workload,
+        );
+        std::fs::remove_dir_all(&data_dir).unwrap();
+        outcomes
+    }
+
+    // transaction id is drawn from seeded rng, so the workload can refer to it upfront
+    fn transfer_workload(seed: u64) -> Vec<Step> {
+        start_simulation(seed, SIMULATION_START_MILLIS);
+        let transaction_id = next_random_u64();
+        stop_simulation();
+
+        let (first, second) = hash_keys_on_two_threads();
+        let mut sync_model_request = SyncModelRequest::new();
+        sync_model_request.schema_string = "account>sort_key:INT32".to_string();
+
+        vec![
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            },
+            Step::Request {
+                client: 0,
+                request: request(
+                    ProtoRequestData::BeginTransaction(BeginTransaction::new()),
+                    None,
+                ),
+            },
+            insert(&first, Some(transaction_id)),
+            insert(&second, Some(transaction_id)),
+            Step::Request {
+                client: 0,
+                request: request(
+                    ProtoRequestData::CommitTransaction(CommitTransaction::new()),
+                    Some(transaction_id),
+                ),
+            },
+            Step::AdvanceClock(Duration::from_secs(1)),
+            get(&first),
+            get(&second),
+        ]
+    }
+
+    fn found_rows(outcomes: &[StepOutcome]) -> Vec<bool> {
+        outcomes[outcomes.len() - 2..]
+            .iter()
+            .map(|outcome| match outcome {
+                StepOutcome::Response(response) => {
+                    matches!(response.data, Some(ProtoResponseData::Get(_)))
+                }
+                _ => false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn transaction_spanning_threads_commits_without_faults() {
+        let outcomes = run(1, Faults::default(), transfer_workload(1));
+        assert_eq!(found_rows(&outcomes), vec![true, true]);
+    }
+
+    #[test]
+    fn more_partitions_than_threads_are_routed_to_owning_threads() {
+        let outcomes = run_with_partitions(11, Some(8), Faults::default(), transfer_workload(11));
+        assert_eq!(found_rows(&outcomes), vec![true, true]);
+    }
+
+    #[test]
+    fn consistent_get_many_is_limited_to_single_partition() {
+        let (first, second) = hash_keys_on_two_threads();
+        let consistent_get_many = |hash_keys: &[&String]| {
+            let mut get_many_request = GetManyRequest::new();
+            get_many_request.consistent = true;
+            for hash_key in hash_keys {
+                if let Step::Request { request, .. } = get(hash_key) {
+                    if let Some(ProtoRequestData::Get(get_request)) = request.data {
+                        get_many_request.items.push(get_request);
+                    }
+                }
+            }
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::GetMany(get_many_request), None),
+            }
+        };
+
+        let mut workload = transfer_workload(13);
+        workload.push(consistent_get_many(&[&first, &first]));
+        workload.push(consistent_get_many(&[&first, &second]));
+        let outcomes = run(13, Faults::default(), workload);
+
+        let response_data = |outcome: &StepOutcome| match outcome {
+            StepOutcome::Response(response) => response.data.clone(),
+            _ => None,
+        };
+        match response_data(&outcomes[outcomes.len() - 2]) {
+            Some(ProtoResponseData::GetMany(get_many_response)) => {
+                assert_eq!(get_many_response.items.len(), 2)
+            }
+            data => panic!("Unexpected response {:?}", data),
+        }
+        assert!(matches!(
+            response_data(&outcomes[outcomes.len() - 1]),
+            Some(ProtoResponseData::ClientError(_))
+        ));
+    }
+
+    #[test]
+    fn same_seed_reproduces_dropped_thread_messages() {
+        let faults = Faults {
+            drop_thread_message_probability: 0.5,
+            ..Faults::default()
+        };
+
+        let outcomes = run(7, faults.clone(), transfer_workload(7));
+        assert_eq!(outcomes, run(7, faults, transfer_workload(7)));
+    }
+
+    #[test]
+    fn crashed_thread_stops_answering() {
+        let (first, _) = hash_keys_on_two_threads();
+        let thread = PartitionMap::new(2).get_partition(&first);
+
+        let outcomes = run(
+            3,
+            Faults::default(),
+            vec![Step::CrashThread(thread), get(&first)],
+        );
+        assert_eq!(outcomes[1], StepOutcome::ThreadCrashed(thread));
+    }
+
+    #[test]
+    fn read_behind_consistency_token_is_rejected() {
+        let (first, _) = hash_keys_on_two_threads();
+        let mut workload = transfer_workload(5)[..1].to_vec();
+        workload.push(insert(&first, None));
+        let outcomes = run(5, Faults::default(), workload.clone());
+        let token = match &outcomes[1] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::Insert(insert_response)) => {
+                    ConsistencyToken::parse(&insert_response.consistency_token).unwrap()
+                }
+                _ => panic!("Insert failed"),
+            },
+            _ => panic!("Insert failed"),
+        };
+
+        let ahead = ConsistencyToken {
+            offset: token.offset + 1,
+            ..token
+        };
+        for min_consistency_token in [token, ahead] {
+            let mut read = get(&first);
+            if let Step::Request { request, .. } = &mut read {
+                request.min_consistency_token = min_consistency_token.to_string();
+            }
+            workload.push(read);
+        }
+
+        let outcomes = run(5, Faults::default(), workload);
+        assert!(matches!(
+            &outcomes[2],
+            StepOutcome::Response(response) if matches!(response.data, Some(ProtoResponseData::Get(_)))
+        ));
+        assert!(matches!(
+            &outcomes[3],
+            StepOutcome::Response(response) if matches!(response.data, Some(ProtoResponseData::ServerError(_)))
+        ));
+    }
+
+    #[test]
+    fn described_server_lists_partition_topology() {
+        let describe_server = Step::Request {
+            client: 0,
+            request: request(
+                ProtoRequestData::DescribeServer(DescribeServerRequest::new()),
+                None,
+            ),
+        };
+        let outcomes = run_with_partitions(17, Some(4), Faults::default(), vec![describe_server]);
+
+        match &outcomes[0] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::DescribeServer(describe_server_response)) => {
+                    assert_eq!(describe_server_response.number_of_threads, 2);
+                    assert!(describe_server_response
+                        .features
+                        .contains(&"simulation".to_string()));
+                    let database = &describe_server_response.databases[0];
+                    assert_eq!(database.name, DEFAULT_DATABASE);
+                    assert_eq!(database.partition_threads, vec![0, 1, 0, 1]);
+                    assert!(database.sstables_dir.starts_with(&database.data_dir));
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn get_many_keeps_order_of_gets() {
+        let (first, _) = hash_keys_on_two_threads();
+        let partition_map = PartitionMap::new(2);
+        let mut same_partition =
+            (0..)
+                .map(|number| format!("account_{}", number))
+                .filter(|hash_key| {
+                    partition_map.get_partition(hash_key) == partition_map.get_partition(&first)
+                });
+        let hash_keys: Vec<String> = (0..3).map(|_| same_partition.next().unwrap()).collect();
+
+        let mut get_many_request = GetManyRequest::new();
+        for hash_key in [&hash_keys[2], &hash_keys[1], &hash_keys[0]] {
+            if let Step::Request { request, .. } = get(hash_key) {
+                if let Some(ProtoRequestData::Get(get_request)) = request.data {
+                    get_many_request.items.push(get_request);
+                }
+            }
+        }
+        let mut limited_request = get_many_request.clone();
+        limited_request.limit = 2;
+        // answering continues past the first item and stops at the first row over the budget
+        let mut budgeted_request = get_many_request.clone();
+        budgeted_request.max_response_bytes = 1;
+        budgeted_request.continuation_token = "1".to_string();
+        let mut workload = transfer_workload(19)[..1].to_vec();
+        workload.push(insert(&hash_keys[0], None));
+        workload.push(insert(&hash_keys[2], None));
+        workload.push(Step::Request {
+            client: 0,
+            request: request(ProtoRequestData::GetMany(get_many_request), None),
+        });
+        workload.push(Step::Request {
+            client: 0,
+            request: request(ProtoRequestData::GetMany(limited_request), None),
+        });
+        workload.push(Step::Request {
+            client: 0,
+            request: request(ProtoRequestData::GetMany(budgeted_request), None),
+        });
+        let outcomes = run(19, Faults::default(), workload);
+
+        match &outcomes[3] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::GetMany(get_many_response)) => {
+                    let found: Vec<_> = get_many_response
+                        .items
+                        .iter()
+                        .map(|item| item.hash_key.clone())
+                        .collect();
+                    assert_eq!(found, vec![hash_keys[2].clone(), hash_keys[0].clone()]);
+                    assert_eq!(get_many_response.found, vec![true, false, true]);
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+        match &outcomes[4] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::GetMany(get_many_response)) => {
+                    assert_eq!(get_many_response.items.len(), 1);
+                    assert_eq!(get_many_response.found, vec![true, false]);
+                    assert!(get_many_response.continuation_token.is_empty());
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+        match &outcomes[5] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::GetMany(get_many_response)) => {
+                    assert!(get_many_response.items.is_empty());
+                    assert_eq!(get_many_response.found, vec![false]);
+                    assert_eq!(get_many_response.continuation_token, "2");
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn query_by_index_gathers_rows_of_every_thread() {
+        let (first, second) = hash_keys_on_two_threads();
+        let mut sync_model_request = SyncModelRequest::new();
+        sync_model_request.schema_string =
+            "account>sort_key:INT32;city:VARCHAR(10)#index=city".to_string();
+        let insert_in = |hash_key: &str, city: &str| {
+            let Step::Request {
+                client,
+                mut request,
+            } = insert(hash_key, None)
+            else {
+                unreachable!()
+            };
+            if let Some(ProtoRequestData::Insert(insert_request)) = request.data.as_mut() {
+                insert_request.values.insert(
+                    "city".to_string(),
+                    parse_proto_from_value(Value::Varchar(city.to_string())),
+                );
+            }
+            Step::Request { client, request }
+        };
+        let mut query_by_index_request = QueryByIndexRequest::new();
+        query_by_index_request.column = "city".to_string();
+        query_by_index_request.start =
+            Some(parse_proto_from_value(Value::Varchar("m".to_string()))).into();
+        query_by_index_request.start_inclusive = true;
+
+        let workload = vec![
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            },
+            insert_in(&first, "rome"),
+            insert_in(&second, "oslo"),
+            insert_in("account_x", "berlin"),
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::QueryByIndex(query_by_index_request), None),
+            },
+        ];
+        let outcomes = run(23, Faults::default(), workload);
+
+        match &outcomes[4] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::QueryByIndex(query_by_index_response)) => {
+                    let found: Vec<_> = query_by_index_response
+                        .items
+                        .iter()
+                        .map(|item| item.hash_key.clone())
+                        .collect();
+                    assert_eq!(found, vec![second, first]);
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn transaction_is_begun_by_its_first_operation() {
+        let (first, second) = hash_keys_on_two_threads();
+        // commit is sent to thread 0, which has to be the one beginning the transaction
+        let (coordinated, other) = match PartitionMap::new(2).get_partition(&first) {
+            0 => (first, second),
+            _ => (second, first),
+        };
+        let transaction_id = 42;
+        let begins_transaction = |step: Step| match step {
+            Step::Request {
+                client,
+                mut request,
+            } => {
+                request.begins_transaction = true;
+                Step::Request { client, request }
+            }
+            step => step,
+        };
+
+        let mut workload = transfer_workload(23)[..1].to_vec();
+        workload.push(begins_transaction(insert(
+            &coordinated,
+            Some(transaction_id),
+        )));
+        // retried operation begins the transaction again, which changes nothing
+        workload.push(begins_transaction(insert(
+            &coordinated,
+            Some(transaction_id),
+        )));
+        workload.push(insert(&other, Some(transaction_id)));
+        workload.push(Step::Request {
+            client: 0,
+            request: request(
+                ProtoRequestData::CommitTransaction(CommitTransaction::new()),
+                Some(transaction_id),
+            ),
+        });
+        workload.push(Step::AdvanceClock(Duration::from_secs(1)));
+        workload.push(get(&coordinated));
+        workload.push(get(&other));
+        let outcomes = run(23, Faults::default(), workload);
+
+        match &outcomes[4] {
+            StepOutcome::Response(response) => assert!(matches!(
+                response.data,
+                Some(ProtoResponseData::Transaction(_))
+            )),
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+        assert_eq!(found_rows(&outcomes), vec![true, true]);
+    }
+
+    #[test]
+    fn delete_where_deletes_rows_on_owning_thread() {
+        let (_, second) = hash_keys_on_two_threads();
+        let mut sync_model_request = SyncModelRequest::new();
+        sync_model_request.schema_string = "account>sort_key:INT32".to_string();
+        let mut delete_where_request = DeleteWhereRequest::new();
+        delete_where_request.hash_key = second.clone();
+        delete_where_request.end = Some(parse_proto_from_value(Value::Int32(-1))).into();
+        delete_where_request.end_inclusive = true;
+
+        let workload = vec![
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            },
+            insert(&second, None),
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::DeleteWhere(delete_where_request), None),
+            },
+            get(&second),
+        ];
+        let outcomes = run(31, Faults::default(), workload);
+
+        match &outcomes[2] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::DeleteWhere(delete_where_response)) => {
+                    assert_eq!(delete_where_response.deleted, 1)
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+        // row is no longer found by a get
+        assert!(!found_rows(&outcomes)[1]);
+    }
+
+    #[test]
+    fn moved_row_is_only_found_under_new_key() {
+        let (_, second) = hash_keys_on_two_threads();
+        let mut sync_model_request = SyncModelRequest::new();
+        sync_model_request.schema_string = "account>sort_key:INT32".to_string();
+        let move_row = |sort_key: i32, new_sort_key: i32| {
+            let mut move_row_request = MoveRowRequest::new();
+            move_row_request.hash_key = second.clone();
+            move_row_request.sort_key = Some(parse_proto_from_value(Value::Int32(sort_key))).into();
+            move_row_request.new_hash_key = second.clone();
+            move_row_request.new_sort_key =
+                Some(parse_proto_from_value(Value::Int32(new_sort_key))).into();
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::MoveRow(move_row_request), None),
+            }
+        };
+
+        let workload = vec![
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            },
+            insert(&second, None),
+            move_row(-1, -2),
+            // nothing is left under old key to move
+            move_row(-1, -3),
+            get(&second),
+        ];
+        let outcomes = run(37, Faults::default(), workload);
+
+        let moved: Vec<_> = outcomes[2..4]
+            .iter()
+            .map(|outcome| match outcome {
+                StepOutcome::Response(response) => match &response.data {
+                    Some(ProtoResponseData::MoveRow(move_row_response)) => move_row_response.moved,
+                    data => panic!("Unexpected response {:?}", data),
+                },
+                outcome => panic!("Unexpected outcome {:?}", outcome),
+            })
+            .collect();
+        assert_eq!(moved, vec![true, false]);
+        assert!(!found_rows(&outcomes)[1]);
+    }
+
+    #[test]
+    fn saves_expecting_stale_version_conflict() {
+        let (_, second) = hash_keys_on_two_threads();
+        let save = |version: u64| {
+            let mut insert_request = InsertRequest::new();
+            insert_request.hash_key = second.clone();
+            insert_request.sort_key = Some(parse_proto_from_value(Value::Int32(-1))).into();
+            let mut save_request = SaveRequest::new();
+            save_request.insert = Some(insert_request).into();
+            save_request.version = version;
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::Save(save_request), None),
+            }
+        };
+
+        let mut sync_model_request = SyncModelRequest::new();
+        sync_model_request.schema_string = "account>sort_key:INT32".to_string();
+        let workload = vec![
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            },
+            save(0),
+            // row already exists
+            save(0),
+            save(1),
+            // version 1 was already saved over
+            save(1),
+        ];
+        let outcomes = run(43, Faults::default(), workload);
+
+        let versions: Vec<_> = outcomes[1..]
+            .iter()
+            .map(|outcome| match outcome {
+                StepOutcome::Response(response) => match &response.data {
+                    Some(ProtoResponseData::Save(save_response)) => Ok(save_response.version),
+                    Some(ProtoResponseData::ClientError(client_error)) => {
+                        Err(client_error.code.enum_value_or_default())
+                    }
+                    data => panic!("Unexpected response {:?}", data),
+                },
+                outcome => panic!("Unexpected outcome {:?}", outcome),
+            })
+            .collect();
+        assert_eq!(
+            versions,
+            vec![
+                Ok(1),
+                Err(ErrorCode::VERSION_CONFLICT),
+                Ok(2),
+                Err(ErrorCode::VERSION_CONFLICT)
+            ]
+        );
+    }
+
+    #[test]
+    fn appended_rows_get_sequence_as_sort_key() {
+        let (_, second) = hash_keys_on_two_threads();
+        let mut sync_model_request = SyncModelRequest::new();
+        sync_model_request.schema_string = "account>sort_key:UNSIGNED64#append_only".to_string();
+        let mut insert_request = InsertRequest::new();
+        insert_request.hash_key = second.clone();
+        let append = Step::Request {
+            client: 0,
+            request: request(ProtoRequestData::Insert(insert_request), None),
+        };
+        let mut delete_request = DeleteRequest::new();
+        delete_request.hash_key = second.clone();
+        delete_request.sort_key = Some(parse_proto_from_value(Value::Unsigned64(1))).into();
+
+        let workload = vec![
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            },
+            append.clone(),
+            append,
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::Delete(delete_request), None),
+            },
+        ];
+        let outcomes = run(41, Faults::default(), workload);
+
+        let sequences: Vec<_> = outcomes[1..3]
+            .iter()
+            .map(|outcome| match outcome {
+                StepOutcome::Response(response) => match &response.data {
+                    Some(ProtoResponseData::Insert(insert_response)) => insert_response.sequence,
+                    data => panic!("Unexpected response {:?}", data),
+                },
+                outcome => panic!("Unexpected outcome {:?}", outcome),
+            })
+            .collect();
+        assert_eq!(sequences, vec![1, 2]);
+        match &outcomes[3] {
+            StepOutcome::Response(response) => assert!(matches!(
+                response.data,
+                Some(ProtoResponseData::ClientError(_))
+            )),
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn dropping_table_aborts_transactions_touching_it() {
+        let mut workload = transfer_workload(29);
+        workload.insert(
+            4,
+            Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::DropTable(DropTableRequest::new()), None),
+            },
+        );
+        let outcomes = run(29, Faults::default(), workload);
+
+        match &outcomes[5] {
+            StepOutcome::Response(response) => match &response.data {
+                Some(ProtoResponseData::ServerError(server_error)) => {
+                    assert_eq!(server_error.code, ErrorCode::CONFLICT.into())
+                }
+                data => panic!("Unexpected response {:?}", data),
+            },
+            outcome => panic!("Unexpected outcome {:?}", outcome),
+        }
+        assert!(!outcomes
+            .iter()
+            .any(|outcome| matches!(outcome, StepOutcome::ThreadCrashed(_))));
+    }
+
+    // sent over socket as client does, answer is matched by correlation id
+    async fn send_over_socket(stream: &mut TcpStream, correlation_id: u64, step: Step) {
+        let Step::Request { request, .. } = step else {
+            unreachable!()
+        };
+        let request_bytes = request.write_to_bytes().unwrap();
+        let mut frame = correlation_id.to_be_bytes().to_vec();
+        frame.extend_from_slice(&(request_bytes.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&request_bytes);
+        stream.write_all(frame).await.0.unwrap();
+    }
+
+    async fn read_correlation_id(stream: &mut TcpStream) -> u64 {
+        let correlation_id = stream.read_u64().await.unwrap();
+        let response_size = stream.read_u32().await.unwrap();
+        let (result, _) = stream.read_exact(vec![0; response_size as usize]).await;
+        result.unwrap();
+        correlation_id
+    }
+
+    #[test]
+    fn slow_request_does_not_hold_up_requests_sent_after_it_over_same_socket() {
+        let data_dir = format!("/tmp/yard_simulation_socket_{}", std::process::id());
+        let _ = std::fs::remove_dir_all(&data_dir);
+        // commit log appends stall, so that insert is answered only after get sent after it
+        inject_io_fault(IoFault {
+            operation: IoOperation::Write,
+            path_pattern: format!("{}/commit_logs", data_dir),
+            effect: IoFaultEffect::Delay(Duration::from_millis(500)),
+            times: None,
+        });
+
+        let mut runtime = monoio::RuntimeBuilder::<FusionDriver>::new()
+            .enable_timer()
+            .build()
+            .unwrap();
+        let answered = runtime.block_on(async {
+            let prepared_database = prepare_database(
+                DatabaseConfig {
+                    name: DEFAULT_DATABASE.to_string(),
+                    data_dir: DataDir::new(&data_dir),
+                    num_of_partitions: None,
+                },
+                1,
+                Arc::new(AtomicUsize::new(0)),
+            )
+            .await;
+            monoio::spawn(prepared_database.compaction_task.run());
+            let mut setups = prepared_database.setups;
+            let database = open_database(setups.remove(0)).await;
+            let databases = HashMap::from([(DEFAULT_DATABASE.to_string(), database)]);
+
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let address = listener.local_addr().unwrap();
+            monoio::spawn(async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                handle_tcp_stream(stream, databases).await
+            });
+            let mut stream = TcpStream::connect(address).await.unwrap();
+            stream.read_u32().await.unwrap();
+
+            let mut sync_model_request = SyncModelRequest::new();
+            sync_model_request.schema_string = "account>sort_key:INT32".to_string();
+            let sync_model = Step::Request {
+                client: 0,
+                request: request(ProtoRequestData::SyncModel(sync_model_request), None),
+            };
+            send_over_socket(&mut stream, 0, sync_model).await;
+            read_correlation_id(&mut stream).await;
+
+            send_over_socket(&mut stream, 1, insert("account_0", None)).await;
+            send_over_socket(&mut stream, 2, get("account_1")).await;
+            vec![
+                read_correlation_id(&mut stream).await,
+                read_correlation_id(&mut stream).await,
+            ]
+        });
+        clear_io_faults(&data_dir);
+        std::fs::remove_dir_all(&data_dir).unwrap();
+
+        assert_eq!(answered, vec![2, 1]);
+    }
+}
@@ -0,0 +1,237 @@
+use protobuf::Message;
+use protos::ProtoResponse;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+static TABLE_ACCESS_WINDOW: Duration = Duration::from_secs(10);
+// statistics slide over that many of the last finished windows, a minute in total
+static TABLE_ACCESS_WINDOWS: usize = 6;
+// latencies are counted in buckets of powers of two of microseconds
+const LATENCY_BUCKETS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccessKind {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Clone, Default)]
+struct AccessCounters {
+    reads: u64,
+    writes: u64,
+    read_bytes: u64,
+    written_bytes: u64,
+    read_latencies: [u64; LATENCY_BUCKETS],
+    write_latencies: [u64; LATENCY_BUCKETS],
+}
+
+impl AccessCounters {
+    fn merge(&mut self, other: &AccessCounters) {
+        self.reads += other.reads;
+        self.writes += other.writes;
+        self.read_bytes += other.read_bytes;
+        self.written_bytes += other.written_bytes;
+        for bucket in 0..LATENCY_BUCKETS {
+            self.read_latencies[bucket] += other.read_latencies[bucket];
+            self.write_latencies[bucket] += other.write_latencies[bucket];
+        }
+    }
+}
+
+// access of a table over the last finished windows, so that hot models can be told apart
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableAccessReport {
+    pub table: String,
+    pub reads_per_second: f64,
+    pub writes_per_second: f64,
+    // of responses to reads
+    pub read_bytes_per_second: f64,
+    // of requests writing
+    pub written_bytes_per_second: f64,
+    // upper bound of latency bucket, so at most twice the actual p99
+    pub read_p99_latency: Duration,
+    pub write_p99_latency: Duration,
+}
+
+// shared by threads of a database, every thread records requests it handles and thread 0
+// closes windows
+#[derive(Debug, Default)]
+pub struct TableAccess {
+    current_window: Mutex<HashMap<String, AccessCounters>>,
+    // oldest first, along with their length
+    finished_windows: Mutex<VecDeque<(Duration, HashMap<String, AccessCounters>)>>,
+}
+
+impl TableAccess {
+    pub fn record(&self, table: &str, kind: AccessKind, bytes: u64, latency: Duration) {
+        let mut current_window = self.current_window.lock().unwrap();
+        let counters = match current_window.get_mut(table) {
+            Some(counters) => counters,
+            None => current_window.entry(table.to_string()).or_default(),
+        };
+        let bucket = latency_bucket(latency);
+        match kind {
+            AccessKind::Read => {
+                counters.reads += 1;
+                counters.read_bytes += bytes;
+                counters.read_latencies[bucket] += 1;
+            }
+            AccessKind::Write => {
+                counters.writes += 1;
+                counters.written_bytes += bytes;
+                counters.write_latencies[bucket] += 1;
+            }
+        }
+    }
+
+    // failed requests are counted too, as they took their share of the table's load
+    pub fn track(
+        self: &Arc<Self>,
+        table: String,
+        kind: AccessKind,
+        request_bytes: u64,
+    ) -> TableAccessGuard {
+        TableAccessGuard {
+            table_access: self.clone(),
+            table,
+            kind,
+            bytes: match kind {
+                AccessKind::Read => 0,
+                AccessKind::Write => request_bytes,
+            },
+            started_at: Instant::now(),
+        }
+    }
+
+    // ordered by table name, tables not accessed within the last windows are left out
+    pub fn report(&self) -> Vec<TableAccessReport> {
+        let finished_windows = self.finished_windows.lock().unwrap();
+        let total_window: Duration = finished_windows.iter().map(|(window, _)| *window).sum();
+        let mut merged: HashMap<&String, AccessCounters> = HashMap::new();
+        for (_, window) in finished_windows.iter() {
+            for (table, counters) in window {
+                merged.entry(table).or_default().merge(counters);
+            }
+        }
+
+        let seconds = total_window.as_secs_f64();
+        let mut reports: Vec<_> = merged
+            .into_iter()
+            .map(|(table, counters)| TableAccessReport {
+                table: table.clone(),
+                reads_per_second: counters.reads as f64 / seconds,
+                writes_per_second: counters.writes as f64 / seconds,
+                read_bytes_per_second: counters.read_bytes as f64 / seconds,
+                written_bytes_per_second: counters.written_bytes as f64 / seconds,
+                read_p99_latency: p99_latency(&counters.read_latencies),
+                write_p99_latency: p99_latency(&counters.write_latencies),
+            })
+            .collect();
+        reports.sort_by(|first, second| first.table.cmp(&second.table));
+        reports
+    }
+
+    pub fn finish_window(&self, window: Duration) {
+        let counters = std::mem::take(&mut *self.current_window.lock().unwrap());
+        let mut finished_windows = self.finished_windows.lock().unwrap();
+        finished_windows.push_back((window, counters));
+        if finished_windows.len() > TABLE_ACCESS_WINDOWS {
+            finished_windows.pop_front();
+        }
+    }
+}
+
+fn latency_bucket(latency: Duration) -> usize {
+    let micros = latency.as_micros().max(1) as u64;
+    (micros.ilog2() as usize).min(LATENCY_BUCKETS - 1)
+}
+
+fn p99_latency(latencies: &[u64; LATENCY_BUCKETS]) -> Duration {
+    let total: u64 = latencies.iter().sum();
+    if total == 0 {
+        return Duration::ZERO;
+    }
+    let p99_rank = total - total / 100;
+    let mut counted = 0;
+    for (bucket, count) in latencies.iter().enumerate() {
+        counted += count;
+        if counted >= p99_rank {
+            return Duration::from_micros(1 << (bucket + 1));
+        }
+    }
+    Duration::from_micros(1 << LATENCY_BUCKETS)
+}
+
+pub struct TableAccessGuard {
+    table_access: Arc<TableAccess>,
+    table: String,
+    kind: AccessKind,
+    bytes: u64,
+    started_at: Instant,
+}
+
+impl TableAccessGuard {
+    pub fn respond(&mut self, proto_response: &ProtoResponse) {
+        if self.kind == AccessKind::Read {
+            self.bytes = proto_response.compute_size();
+        }
+    }
+}
+
+impl Drop for TableAccessGuard {
+    fn drop(&mut self) {
+        self.table_access.record(
+            &self.table,
+            self.kind,
+            self.bytes,
+            self.started_at.elapsed(),
+        );
+    }
+}
+
+pub(crate) async fn table_access_main(table_access: Arc<TableAccess>) {
+    let mut window_started_at = Instant::now();
+    loop {
+        monoio::time::sleep(TABLE_ACCESS_WINDOW).await;
+        table_access.finish_window(window_started_at.elapsed());
+        window_started_at = Instant::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_is_reported_over_sliding_windows() {
+        let table_access = TableAccess::default();
+        for _ in 0..99 {
+            table_access.record("users", AccessKind::Read, 100, Duration::from_micros(300));
+        }
+        table_access.record("users", AccessKind::Read, 100, Duration::from_millis(5));
+        table_access.record("orders", AccessKind::Write, 50, Duration::from_micros(10));
+        assert!(table_access.report().is_empty());
+
+        table_access.finish_window(Duration::from_secs(10));
+        let reports = table_access.report();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].table, "orders");
+        assert_eq!(reports[0].writes_per_second, 0.1);
+        assert_eq!(reports[0].written_bytes_per_second, 5.0);
+        assert_eq!(reports[0].write_p99_latency, Duration::from_micros(16));
+        assert_eq!(reports[1].reads_per_second, 10.0);
+        assert_eq!(reports[1].read_bytes_per_second, 1000.0);
+        assert_eq!(reports[1].read_p99_latency, Duration::from_micros(512));
+        assert_eq!(reports[1].write_p99_latency, Duration::ZERO);
+
+        // accessed tables slide out of report once all of their windows are gone
+        table_access.record("orders", AccessKind::Write, 50, Duration::from_micros(10));
+        for _ in 0..TABLE_ACCESS_WINDOWS {
+            table_access.finish_window(Duration::from_secs(10));
+        }
+        let reports = table_access.report();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].table, "orders");
+    }
+}
@@ -0,0 +1,173 @@
+use protos::ProtoRequest;
+use tracing::{Span, Subscriber};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+#[cfg(feature = "opentelemetry")]
+static SERVICE_NAME: &str = "yard";
+
+// spans of requests are exported to an OTLP collector over http,
+// e.g. "http://localhost:4318/v1/traces"
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceExport {
+    pub endpoint: String,
+    // of traces started by server, traces continued from clients follow sampling of the client
+    pub sample_ratio: f64,
+}
+
+// empty endpoint means spans are not exported, all traces are sampled by default
+pub fn parse_trace_export(
+    endpoint: &str,
+    sample_ratio: &str,
+) -> Result<Option<TraceExport>, String> {
+    let endpoint = endpoint.trim();
+    if endpoint.is_empty() {
+        return Ok(None);
+    }
+    let sample_ratio = match sample_ratio.trim() {
+        "" => 1.0,
+        sample_ratio => sample_ratio
+            .parse()
+            .ok()
+            .filter(|sample_ratio| (0.0..=1.0).contains(sample_ratio))
+            .ok_or(format!(
+                "Invalid trace sample ratio '{}', expected number between 0 and 1",
+                sample_ratio
+            ))?,
+    };
+    Ok(Some(TraceExport {
+        endpoint: endpoint.to_string(),
+        sample_ratio,
+    }))
+}
+
+#[cfg(feature = "opentelemetry")]
+pub(crate) fn trace_export_layer<S>(
+    trace_export: Option<TraceExport>,
+) -> Result<Option<Box<dyn Layer<S> + Send + Sync>>, String>
+where
+    S: Subscriber + Send + Sync + for<'span> LookupSpan<'span>,
+{
+    use opentelemetry::global;
+    use opentelemetry::trace::TracerProvider;
+    use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+    use opentelemetry_sdk::Resource;
+    use tracing_subscriber::filter::LevelFilter;
+
+    let Some(trace_export) = trace_export else {
+        return Ok(None);
+    };
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(trace_export.endpoint)
+        .build()
+        .map_err(|error| error.to_string())?;
+    // exported from a thread of its own, so that storage threads never wait for the collector
+    let tracer_provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            trace_export.sample_ratio,
+        ))))
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .build();
+    let tracer = tracer_provider.tracer(SERVICE_NAME);
+    global::set_tracer_provider(tracer_provider);
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    Ok(Some(
+        tracing_opentelemetry::layer()
+            .with_tracer(tracer)
+            .with_filter(LevelFilter::INFO)
+            .boxed(),
+    ))
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+pub(crate) fn trace_export_layer<S>(
+    trace_export: Option<TraceExport>,
+) -> Result<Option<Box<dyn Layer<S> + Send + Sync>>, String>
+where
+    S: Subscriber + Send + Sync + for<'span> LookupSpan<'span>,
+{
+    match trace_export {
+        Some(_) => Err("server was built without opentelemetry feature".to_string()),
+        None => Ok(None),
+    }
+}
+
+// has to be called before request span is first entered, as that starts its trace
+#[cfg(feature = "opentelemetry")]
+pub(crate) fn continue_client_trace(request_span: &Span, request: &ProtoRequest) {
+    use opentelemetry::global;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    if request.trace_context.is_empty() {
+        return;
+    }
+    let parent =
+        global::get_text_map_propagator(|propagator| propagator.extract(&request.trace_context));
+    // fails only when spans are not exported, so there is no trace to continue
+    let _ = request_span.set_parent(parent);
+}
+
+#[cfg(not(feature = "opentelemetry"))]
+pub(crate) fn continue_client_trace(_request_span: &Span, _request: &ProtoRequest) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_export_is_parsed() {
+        assert_eq!(parse_trace_export("", "0.5"), Ok(None));
+        assert_eq!(
+            parse_trace_export(" http://localhost:4318/v1/traces ", ""),
+            Ok(Some(TraceExport {
+                endpoint: "http://localhost:4318/v1/traces".to_string(),
+                sample_ratio: 1.0,
+            }))
+        );
+        assert_eq!(
+            parse_trace_export("http://collector:4318/v1/traces", "0.1")
+                .unwrap()
+                .unwrap()
+                .sample_ratio,
+            0.1
+        );
+        assert!(parse_trace_export("http://collector:4318/v1/traces", "2").is_err());
+        assert!(parse_trace_export("http://collector:4318/v1/traces", "often").is_err());
+    }
+
+    #[cfg(feature = "opentelemetry")]
+    #[test]
+    fn request_span_continues_trace_of_client() {
+        use opentelemetry::global;
+        use opentelemetry::trace::{TraceContextExt, TraceId, TracerProvider};
+        use opentelemetry_sdk::propagation::TraceContextPropagator;
+        use opentelemetry_sdk::trace::SdkTracerProvider;
+        use tracing_opentelemetry::OpenTelemetrySpanExt;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let tracer = SdkTracerProvider::builder().build().tracer(SERVICE_NAME);
+        let subscriber =
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+        let mut request = ProtoRequest::new();
+        request.trace_context.insert(
+            "traceparent".to_string(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+        );
+        tracing::subscriber::with_default(subscriber, || {
+            let request_span = tracing::info_span!("request");
+            continue_client_trace(&request_span, &request);
+            let trace_id = request_span.context().span().span_context().trace_id();
+            assert_eq!(
+                trace_id,
+                TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+            );
+        });
+    }
+}
@@ -1,37 +1,195 @@
+use crate::partition_load::PartitionLoadReport;
+use crate::replication::replicate_response;
+use crate::server_info::ServerDescription;
+use crate::session::Session;
+use crate::table_access::TableAccessReport;
+use crate::transaction_manager::TransactionInfo;
 use common::value::Value;
 use futures::channel::{mpsc, oneshot};
-use futures::SinkExt;
+use futures::{SinkExt, StreamExt};
+use protobuf::Message;
 use protos::util::{parse_message_field_from_value, parse_proto_from_value};
 use protos::{
-    BatchResponse, DeleteResponse, DropTableResponse, GetManyResponse, GetResponse, InsertResponse,
-    ProtoResponse, ProtoResponseData, SyncModelResponse, TransactionResponse,
+    ActiveTransaction, BatchResponse, CompactionBucket, CompactionResponse, DatabaseDescription,
+    DeleteResponse, DeleteWhereResponse, DescribeServerResponse, DescribeTableResponse,
+    DropTableResponse, GetManyResponse, GetResponse, HealthResponse, InsertResponse,
+    KeyDistributionResponse, LeaseResponse, ListTransactionsResponse, MoveRowResponse,
+    PartitionKeyDistribution, PartitionLoadResponse, PartitionLoadStats, PartitionMapResponse,
+    PlanMigrationResponse, PromoteResponse, ProtoResponse, ProtoResponseData, ProtoTableStatistics,
+    QueryByIndexResponse, RaftAppendResponse, RaftVoteResponse, ReadExplanation, ReadyResponse,
+    RowCountResponse, SSTableReadExplanation, SaveResponse, SequenceResponse, SessionResponse,
+    SetReadOnlyResponse, SyncModelResponse, TableAccessResponse, TableAccessStats, TableCompaction,
+    TransactionResponse,
 };
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use storage::Row;
+use std::future::poll_fn;
+use std::sync::{Arc, Mutex};
+use storage::commit_log::ReplayedState;
+use storage::delete_where::DeleteWhere;
+use storage::index::{encode_index_value, IndexRange};
+use storage::lease::Lease;
+use storage::move_row::MoveRow;
+use storage::replication::ReplicatedBatch;
+use storage::sstable::{CompactionSettingsUpdate, CompactionState, ReadTrace};
+use storage::statistics::TableStatistics;
+use storage::{millis_from_epoch, Row, MEGABYTE};
+
+// responses are framed with u32 size, get_many answering more is paged by client
+static GET_MANY_RESPONSE_MAX_BYTES: usize = 64 * MEGABYTE;
 
 pub enum ThreadMessage {
     TransactionBegun(u64),
     TransactionPrepare(u64, oneshot::Sender<bool>),
     TransactionCommit(u64),
     TransactionAborted(u64),
-    SyncModel(String),
+    ListTransactions(oneshot::Sender<Vec<TransactionInfo>>),
+    // true if thread knew the transaction
+    KillTransaction(u64, oneshot::Sender<bool>),
+    SyncModels(Vec<String>),
     DropTable(String),
+    SetReadOnly(String, bool),
+    ReleaseBucket(u32, oneshot::Sender<Vec<(String, ReplayedState)>>),
+    AdoptBucket(Vec<(String, ReplayedState)>, oneshot::Sender<()>),
+    // reference of a row written on another partition, violations are only reported
+    CheckReference(ReferenceCheck),
+    // memtable row count per partition of given table
+    CountMemtableRows(String, oneshot::Sender<HashMap<usize, usize>>),
+    // rows of thread's partitions in range of indexed column of given table
+    QueryIndex(String, IndexRange, oneshot::Sender<Vec<Row>>),
     CtrlC(oneshot::Sender<()>),
 }
 
+#[derive(Debug, Clone)]
+pub struct ReferenceCheck {
+    pub table_name: String,
+    pub column: String,
+    pub referenced_table: String,
+    pub hash_key: String,
+}
+
+// part of get_many items looked up by a request, continuation token is the offset of the first
+// item left unanswered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GetManyPage {
+    pub offset: usize,
+    pub max_bytes: usize,
+}
+
+impl GetManyPage {
+    pub fn new(continuation_token: &str, max_response_bytes: u64) -> Result<GetManyPage, String> {
+        let offset = match continuation_token {
+            "" => 0,
+            token => token
+                .parse()
+                .map_err(|_| format!("Invalid continuation token '{}'", token))?,
+        };
+        let max_bytes = match max_response_bytes {
+            0 => GET_MANY_RESPONSE_MAX_BYTES,
+            max_bytes => (max_bytes as usize).min(GET_MANY_RESPONSE_MAX_BYTES),
+        };
+        Ok(GetManyPage { offset, max_bytes })
+    }
+}
+
+// part of rows a query by index answers, continuation token is the indexed value and primary key
+// of the last row answered, so that the next page resumes past that row wherever flushes and
+// compactions moved rows in between
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexPage {
+    pub limit: usize,
+    pub after: Option<(String, String)>,
+}
+
+impl IndexPage {
+    pub fn new(continuation_token: &str, limit: u32) -> Result<IndexPage, String> {
+        let limit = match limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+        let after = match continuation_token {
+            "" => None,
+            token => Some(
+                parse_index_token(token)
+                    .ok_or(format!("Invalid continuation token '{}'", token))?,
+            ),
+        };
+        Ok(IndexPage { limit, after })
+    }
+
+    // rows are sorted by index, token is left empty when none of them were cut off by limit
+    pub fn cut(&self, mut rows: Vec<Row>, column: &str) -> (Vec<Row>, String) {
+        let position = |row: &Row| {
+            let value = row.values.get(column).and_then(encode_index_value);
+            (value.unwrap_or_default(), row.primary_key.clone())
+        };
+        if let Some(after) = &self.after {
+            rows.retain(|row| position(row) > *after);
+        }
+        if rows.len() <= self.limit {
+            return (rows, String::new());
+        }
+
+        rows.truncate(self.limit);
+        let (value, primary_key) = position(rows.last().unwrap());
+        (rows, format!("{}:{}{}", value.len(), value, primary_key))
+    }
+}
+
+// length of value goes first, as neither value nor primary key are bound to leave out any character
+fn parse_index_token(token: &str) -> Option<(String, String)> {
+    let (value_length, position) = token.split_once(':')?;
+    let value_length = value_length.parse().ok()?;
+    let value = position.get(..value_length)?;
+    let primary_key = position.get(value_length..)?;
+    Some((value.to_string(), primary_key.to_string()))
+}
+
 pub type OperationSender = mpsc::UnboundedSender<ThreadMessage>;
 pub type OperationReceiver = mpsc::UnboundedReceiver<ThreadMessage>;
+// outlives the thread's runtime, so that messages queued for a panicked thread reach its restart
+pub type SharedOperationReceiver = Arc<Mutex<OperationReceiver>>;
+
+// lock is never held across await, so it cannot be left locked by a panicked thread
+pub async fn next_thread_message(receiver: &SharedOperationReceiver) -> Option<ThreadMessage> {
+    poll_fn(|context| receiver.lock().unwrap().poll_next_unpin(context)).await
+}
 
 #[derive(Debug)]
 pub enum Command {
     Single(Operation, String),
-    GetMany(Vec<Operation>, String),
+    GetMany(Vec<Operation>, GetManyPage, String),
+    // keys of a single partition read under one view of its tables
+    GetManyConsistent(Vec<Operation>, GetManyPage, String),
     Batch(Vec<Operation>, String),
+    DeleteWhere(DeleteWhere, String),
+    MoveRow(MoveRow, String),
+    // insert applied only if row stored under its key has that version, 0 if there must be none
+    Save(Operation, u64, String),
     BeginTransaction,
     CommitTransaction,
     AbortTransaction,
+    ListTransactions,
+    KillTransaction(u64),
     SyncModel(String),
+    SyncModels(Vec<String>),
     DropTable(String),
+    PlanMigration(String),
+    DescribeTable(String),
+    Health,
+    Ready,
+    PartitionMap,
+    MigrateBucket(String, usize),
+    KeyDistribution(String),
+    PartitionLoad,
+    TableAccess,
+    // at most that many rows
+    QueryByIndex(IndexRange, IndexPage, String),
+    SetSession(Session),
+    SetReadOnly(String, bool),
+    Compaction(CompactionSettingsUpdate),
+    // records of this thread from epoch and sequence, pulled by standby
+    Replicate(u64, u64),
 }
 
 #[derive(Debug, Clone)]
@@ -39,6 +197,11 @@ pub enum Operation {
     Get(String, Value),
     Insert(String, Value, HashMap<String, Value>),
     Delete(String, Value),
+    GetSequence(String),
+    AcquireLease(String, u64),
+    RenewLease(String, u64, u64),
+    ReleaseLease(String, u64),
+    GetRowCount(String),
 }
 
 impl Operation {
@@ -47,6 +210,20 @@ impl Operation {
             Operation::Get(hash_key, _) => hash_key.clone(),
             Operation::Insert(hash_key, _, _) => hash_key.clone(),
             Operation::Delete(hash_key, _) => hash_key.clone(),
+            Operation::GetSequence(hash_key) => hash_key.clone(),
+            Operation::AcquireLease(name, _) => name.clone(),
+            Operation::RenewLease(name, _, _) => name.clone(),
+            Operation::ReleaseLease(name, _) => name.clone(),
+            Operation::GetRowCount(hash_key) => hash_key.clone(),
+        }
+    }
+
+    pub fn sort_key(&self) -> Option<&Value> {
+        match self {
+            Operation::Get(_, sort_key) => Some(sort_key),
+            Operation::Insert(_, sort_key, _) => Some(sort_key),
+            Operation::Delete(_, sort_key) => Some(sort_key),
+            _ => None,
         }
     }
 }
@@ -54,18 +231,59 @@ impl Operation {
 #[derive(Debug)]
 pub enum Response {
     Single(OperationResponse),
-    GetMany(Vec<OperationResponse>),
+    GetMany(Vec<OperationResponse>, GetManyPage),
     Batch(Vec<OperationResponse>),
     Transaction(u64),
+    ListTransactions(Vec<TransactionInfo>),
     SyncModel,
     DropTable,
+    PlanMigration(Vec<String>),
+    DescribeTable(String, TableStatistics),
+    Health(Vec<String>),
+    Ready(Result<(), String>),
+    // partition map and where writes go, empty if it is this server
+    PartitionMap(String, String),
+    KeyDistribution(Vec<PartitionRows>),
+    PartitionLoad(Vec<PartitionLoadReport>),
+    TableAccess(Vec<TableAccessReport>),
+    // rows and continuation token
+    QueryByIndex(Vec<Row>, String),
+    // number of rows deleted
+    DeleteWhere(u64),
+    MoveRow(bool),
+    // version of saved row
+    Save(u64),
+    Session,
+    SetReadOnly,
+    Compaction(CompactionState),
+    DescribeServer(ServerDescription),
+    // records along with schemas of their tables and partition map
+    Replicate(ReplicatedBatch, Vec<String>, String),
+    // true if server was a standby
+    Promote(bool),
+    // term of the voter and whether it granted the vote
+    RaftVote(u64, bool),
+    // term of the follower, whether it appended the entries and its last entry
+    RaftAppend(u64, bool, u64),
+}
+
+#[derive(Debug)]
+pub struct PartitionRows {
+    pub partition: usize,
+    pub memtable_rows: usize,
+    pub sstable_rows: usize,
+    pub bytes: usize,
 }
 
 #[derive(Debug)]
 pub enum OperationResponse {
     Get(Option<Row>),
-    Insert,
+    // sort key assigned to row of append-only table
+    Insert(Option<u64>),
     Delete(bool),
+    Sequence(u64),
+    Lease(Lease),
+    RowCount(u64),
 }
 
 impl Response {
@@ -77,37 +295,53 @@ impl Response {
                     let get_response = row_to_get_response(row);
                     ProtoResponseData::Get(get_response)
                 }),
-                OperationResponse::Insert => Some(ProtoResponseData::Insert(InsertResponse::new())),
+                OperationResponse::Insert(sequence) => {
+                    let mut insert_response = InsertResponse::new();
+                    insert_response.sequence = sequence.unwrap_or_default();
+                    Some(ProtoResponseData::Insert(insert_response))
+                }
                 OperationResponse::Delete(result) => {
                     let mut delete_response = DeleteResponse::new();
                     delete_response.okay = result;
                     Some(ProtoResponseData::Delete(delete_response))
                 }
-            },
-            Response::GetMany(operation_responses) => {
-                let mut get_many_response = GetManyResponse::new();
-                for operation_response in operation_responses {
-                    match operation_response {
-                        OperationResponse::Get(row) => {
-                            if let Some(row) = row {
-                                get_many_response.items.push(row_to_get_response(row));
-                            }
-                        }
-                        _ => panic!("Invalid operation response type"),
-                    }
+                OperationResponse::Sequence(value) => {
+                    let mut sequence_response = SequenceResponse::new();
+                    sequence_response.value = value;
+                    Some(ProtoResponseData::Sequence(sequence_response))
                 }
-
-                Some(ProtoResponseData::GetMany(get_many_response))
-            }
+                OperationResponse::Lease(lease) => {
+                    let mut lease_response = LeaseResponse::new();
+                    lease_response.version = lease.version;
+                    lease_response.expires_at = lease.expires_at as u64;
+                    Some(ProtoResponseData::Lease(lease_response))
+                }
+                OperationResponse::RowCount(count) => {
+                    let mut row_count_response = RowCountResponse::new();
+                    row_count_response.count = count;
+                    Some(ProtoResponseData::RowCount(row_count_response))
+                }
+            },
+            Response::GetMany(operation_responses, page) => Some(ProtoResponseData::GetMany(
+                get_many_response(operation_responses, page),
+            )),
             Response::Batch(operation_responses) => {
                 let mut batch_response = BatchResponse::new();
-                batch_response.okay = operation_responses.into_iter().all(|operation_response| {
-                    match operation_response {
-                        OperationResponse::Insert => true,
-                        OperationResponse::Delete(delete) => delete,
-                        _ => panic!("Invalid operation response type"),
-                    }
-                });
+                batch_response.okay =
+                    operation_responses
+                        .iter()
+                        .all(|operation_response| match operation_response {
+                            OperationResponse::Insert(_) => true,
+                            OperationResponse::Delete(delete) => *delete,
+                            _ => panic!("Invalid operation response type"),
+                        });
+                batch_response.sequences = operation_responses
+                    .into_iter()
+                    .filter_map(|operation_response| match operation_response {
+                        OperationResponse::Insert(sequence) => sequence,
+                        _ => None,
+                    })
+                    .collect();
                 Some(ProtoResponseData::Batch(batch_response))
             }
             Response::Transaction(transaction_id) => {
@@ -115,8 +349,240 @@ impl Response {
                 transaction_response.transaction_id = transaction_id;
                 Some(ProtoResponseData::Transaction(transaction_response))
             }
+            Response::ListTransactions(transaction_infos) => {
+                let now = millis_from_epoch();
+                let mut list_transactions_response = ListTransactionsResponse::new();
+                list_transactions_response.transactions = transaction_infos
+                    .into_iter()
+                    .map(|transaction_info| {
+                        let mut active_transaction = ActiveTransaction::new();
+                        active_transaction.transaction_id = transaction_info.id;
+                        active_transaction.age_millis =
+                            now.saturating_sub(transaction_info.started_at) as u64;
+                        active_transaction.tables =
+                            transaction_info.affected_tables.into_iter().collect();
+                        active_transaction.coordinator_partition = transaction_info
+                            .coordinator_partition
+                            .map(|partition| partition as u32);
+                        active_transaction
+                    })
+                    .collect();
+                Some(ProtoResponseData::ListTransactions(
+                    list_transactions_response,
+                ))
+            }
             Response::SyncModel => Some(ProtoResponseData::Model(SyncModelResponse::new())),
             Response::DropTable => Some(ProtoResponseData::DropTable(DropTableResponse::new())),
+            Response::PlanMigration(steps) => {
+                let mut plan_migration_response = PlanMigrationResponse::new();
+                plan_migration_response.steps = steps;
+                Some(ProtoResponseData::PlanMigration(plan_migration_response))
+            }
+            Response::DescribeTable(schema_string, statistics) => {
+                let mut proto_statistics = ProtoTableStatistics::new();
+                proto_statistics.sstables = statistics.sstables as u32;
+                proto_statistics.row_count_estimate = statistics.row_count_estimate;
+                proto_statistics.average_row_size = statistics.average_row_size;
+                proto_statistics.hash_key_cardinality = statistics.hash_key_cardinality;
+                proto_statistics.rows_per_hash_key = statistics.rows_per_hash_key;
+                proto_statistics.disk_usage = statistics.disk_usage;
+
+                let mut describe_table_response = DescribeTableResponse::new();
+                describe_table_response.schema_string = schema_string;
+                describe_table_response.statistics = Some(proto_statistics).into();
+                Some(ProtoResponseData::DescribeTable(describe_table_response))
+            }
+            Response::Health(degraded) => {
+                let mut health_response = HealthResponse::new();
+                health_response.degraded = degraded;
+                Some(ProtoResponseData::Health(health_response))
+            }
+            Response::DescribeServer(server_description) => {
+                let mut describe_server_response = DescribeServerResponse::new();
+                describe_server_response.version = server_description.version;
+                describe_server_response.uptime_millis =
+                    server_description.uptime.as_millis() as u64;
+                describe_server_response.number_of_threads =
+                    server_description.number_of_threads as u32;
+                describe_server_response.config = server_description.config.into_iter().collect();
+                describe_server_response.features = server_description.features;
+                describe_server_response.databases = server_description
+                    .databases
+                    .into_iter()
+                    .map(|database| {
+                        let mut database_description = DatabaseDescription::new();
+                        database_description.name = database.name;
+                        database_description.data_dir = database.data_dir.root;
+                        database_description.sstables_dir = database.data_dir.sstables;
+                        database_description.commit_logs_dir = database.data_dir.commit_logs;
+                        database_description.table_schemas_file =
+                            database.data_dir.table_schemas_file;
+                        database_description.partition_threads = database
+                            .partition_threads
+                            .into_iter()
+                            .map(|thread| thread as u32)
+                            .collect();
+                        database_description
+                    })
+                    .collect();
+                Some(ProtoResponseData::DescribeServer(describe_server_response))
+            }
+            Response::Replicate(batch, schema_strings, partition_map) => {
+                Some(ProtoResponseData::Replicate(replicate_response(
+                    batch,
+                    schema_strings,
+                    partition_map,
+                )))
+            }
+            Response::Promote(promoted) => {
+                let mut promote_response = PromoteResponse::new();
+                promote_response.promoted = promoted;
+                Some(ProtoResponseData::Promote(promote_response))
+            }
+            Response::RaftVote(term, granted) => {
+                let mut raft_vote_response = RaftVoteResponse::new();
+                raft_vote_response.term = term;
+                raft_vote_response.granted = granted;
+                Some(ProtoResponseData::RaftVote(raft_vote_response))
+            }
+            Response::RaftAppend(term, success, last_index) => {
+                let mut raft_append_response = RaftAppendResponse::new();
+                raft_append_response.term = term;
+                raft_append_response.success = success;
+                raft_append_response.last_index = last_index;
+                Some(ProtoResponseData::RaftAppend(raft_append_response))
+            }
+            Response::Ready(result) => {
+                let mut ready_response = ReadyResponse::new();
+                ready_response.ready = result.is_ok();
+                ready_response.detail = result.err().unwrap_or_default();
+                Some(ProtoResponseData::Ready(ready_response))
+            }
+            Response::PartitionMap(partition_map, leader) => {
+                let mut partition_map_response = PartitionMapResponse::new();
+                partition_map_response.partition_map = partition_map;
+                partition_map_response.leader = leader;
+                Some(ProtoResponseData::PartitionMap(partition_map_response))
+            }
+            Response::Session => Some(ProtoResponseData::Session(SessionResponse::new())),
+            Response::SetReadOnly => {
+                Some(ProtoResponseData::SetReadOnly(SetReadOnlyResponse::new()))
+            }
+            Response::Compaction(compaction_state) => {
+                let mut compaction_response = CompactionResponse::new();
+                compaction_response.bucket_low = compaction_state.settings.bucket_low;
+                compaction_response.bucket_high = compaction_state.settings.bucket_high;
+                compaction_response.sstable_min_size = compaction_state.settings.sstable_min_size;
+                compaction_response.interval_millis =
+                    compaction_state.settings.interval.as_millis() as u64;
+                compaction_response.max_concurrent_compactions =
+                    compaction_state.settings.max_concurrent_compactions as u32;
+                compaction_response.io_rate_limit_mb = compaction_state.settings.io_rate_limit_mb;
+                compaction_response.tables = compaction_state
+                    .tables
+                    .into_iter()
+                    .map(|table_state| {
+                        let mut table_compaction = TableCompaction::new();
+                        table_compaction.table = table_state.table_name;
+                        table_compaction.buckets = table_state
+                            .buckets
+                            .into_iter()
+                            .map(|bucket| {
+                                let mut compaction_bucket = CompactionBucket::new();
+                                compaction_bucket.sstables = bucket.sstables as u32;
+                                compaction_bucket.bytes = bucket.bytes;
+                                compaction_bucket.pending = bucket.pending;
+                                compaction_bucket
+                            })
+                            .collect();
+                        table_compaction.pending_sstables = table_state.pending_sstables as u32;
+                        table_compaction.last_duration_millis = table_state
+                            .last_duration
+                            .map(|duration| duration.as_millis() as u64);
+                        table_compaction.priority = table_state.priority as u64;
+                        table_compaction
+                    })
+                    .collect();
+                Some(ProtoResponseData::Compaction(compaction_response))
+            }
+            Response::KeyDistribution(partitions_rows) => {
+                let mut key_distribution_response = KeyDistributionResponse::new();
+                key_distribution_response.partitions = partitions_rows
+                    .into_iter()
+                    .map(|partition_rows| {
+                        let mut partition_key_distribution = PartitionKeyDistribution::new();
+                        partition_key_distribution.partition = partition_rows.partition as u32;
+                        partition_key_distribution.memtable_rows =
+                            partition_rows.memtable_rows as u64;
+                        partition_key_distribution.sstable_rows =
+                            partition_rows.sstable_rows as u64;
+                        partition_key_distribution.bytes = partition_rows.bytes as u64;
+                        partition_key_distribution
+                    })
+                    .collect();
+                Some(ProtoResponseData::KeyDistribution(
+                    key_distribution_response,
+                ))
+            }
+            Response::PartitionLoad(reports) => {
+                let mut partition_load_response = PartitionLoadResponse::new();
+                partition_load_response.partitions = reports
+                    .into_iter()
+                    .map(|report| {
+                        let mut partition_load_stats = PartitionLoadStats::new();
+                        partition_load_stats.partition = report.partition as u32;
+                        partition_load_stats.requests_per_second = report.requests_per_second;
+                        partition_load_stats.average_latency_micros =
+                            report.average_latency.as_micros() as u64;
+                        partition_load_stats.skew = report.skew;
+                        partition_load_stats.hot = report.hot;
+                        partition_load_stats
+                    })
+                    .collect();
+                Some(ProtoResponseData::PartitionLoad(partition_load_response))
+            }
+            Response::TableAccess(reports) => {
+                let mut table_access_response = TableAccessResponse::new();
+                table_access_response.tables = reports
+                    .into_iter()
+                    .map(|report| {
+                        let mut table_access_stats = TableAccessStats::new();
+                        table_access_stats.table = report.table;
+                        table_access_stats.reads_per_second = report.reads_per_second;
+                        table_access_stats.writes_per_second = report.writes_per_second;
+                        table_access_stats.read_bytes_per_second = report.read_bytes_per_second;
+                        table_access_stats.written_bytes_per_second =
+                            report.written_bytes_per_second;
+                        table_access_stats.read_p99_latency_micros =
+                            report.read_p99_latency.as_micros() as u64;
+                        table_access_stats.write_p99_latency_micros =
+                            report.write_p99_latency.as_micros() as u64;
+                        table_access_stats
+                    })
+                    .collect();
+                Some(ProtoResponseData::TableAccess(table_access_response))
+            }
+            Response::DeleteWhere(deleted) => {
+                let mut delete_where_response = DeleteWhereResponse::new();
+                delete_where_response.deleted = deleted;
+                Some(ProtoResponseData::DeleteWhere(delete_where_response))
+            }
+            Response::MoveRow(moved) => {
+                let mut move_row_response = MoveRowResponse::new();
+                move_row_response.moved = moved;
+                Some(ProtoResponseData::MoveRow(move_row_response))
+            }
+            Response::Save(version) => {
+                let mut save_response = SaveResponse::new();
+                save_response.version = version;
+                Some(ProtoResponseData::Save(save_response))
+            }
+            Response::QueryByIndex(rows, continuation_token) => {
+                let mut query_by_index_response = QueryByIndexResponse::new();
+                query_by_index_response.items = rows.into_iter().map(row_to_get_response).collect();
+                query_by_index_response.continuation_token = continuation_token;
+                Some(ProtoResponseData::QueryByIndex(query_by_index_response))
+            }
         };
 
         proto_response.data = proto_response_data;
@@ -197,8 +663,83 @@ pub async fn send_transaction_aborted(
     }
 }
 
-pub async fn send_sync_model(
-    table_schema: String,
+// every thread holds its own view of every transaction, views are merged by id
+pub async fn send_list_transactions(
+    mut transaction_infos: Vec<TransactionInfo>,
+    senders: &mut [OperationSender],
+    current_thread_number: usize,
+) -> Vec<TransactionInfo> {
+    for (partition, sender) in senders.iter_mut().enumerate() {
+        if current_thread_number == partition {
+            continue;
+        }
+        let (list_sender, receiver) = oneshot::channel();
+        sender
+            .send(ThreadMessage::ListTransactions(list_sender))
+            .await
+            .unwrap();
+        transaction_infos.extend(receiver.await.unwrap());
+    }
+
+    let mut merged_transaction_infos: HashMap<u64, TransactionInfo> = HashMap::new();
+    for transaction_info in transaction_infos {
+        match merged_transaction_infos.entry(transaction_info.id) {
+            Entry::Occupied(mut entry) => {
+                let merged = entry.get_mut();
+                merged.started_at = merged.started_at.min(transaction_info.started_at);
+                merged
+                    .affected_tables
+                    .extend(transaction_info.affected_tables);
+                merged.coordinator_partition = merged
+                    .coordinator_partition
+                    .or(transaction_info.coordinator_partition);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(transaction_info);
+            }
+        }
+    }
+
+    let mut transaction_infos: Vec<_> = merged_transaction_infos.into_values().collect();
+    transaction_infos.sort_by_key(|transaction_info| transaction_info.started_at);
+    transaction_infos
+}
+
+pub async fn send_kill_transaction(
+    transaction_id: u64,
+    senders: &mut [OperationSender],
+    current_thread_number: usize,
+) -> bool {
+    let mut killed = false;
+    for (partition, sender) in senders.iter_mut().enumerate() {
+        if current_thread_number == partition {
+            continue;
+        }
+        let (kill_sender, receiver) = oneshot::channel();
+        sender
+            .send(ThreadMessage::KillTransaction(transaction_id, kill_sender))
+            .await
+            .unwrap();
+        killed |= receiver.await.unwrap();
+    }
+
+    killed
+}
+
+// row is already written, so nobody waits for the outcome
+pub fn send_reference_checks(
+    reference_checks: Vec<(usize, ReferenceCheck)>,
+    senders: &[OperationSender],
+) {
+    for (thread_number, reference_check) in reference_checks {
+        senders[thread_number]
+            .unbounded_send(ThreadMessage::CheckReference(reference_check))
+            .unwrap();
+    }
+}
+
+pub async fn send_sync_models(
+    schema_strings: Vec<String>,
     senders: &mut Vec<OperationSender>,
     current_thread_number: usize,
 ) {
@@ -207,7 +748,7 @@ pub async fn send_sync_model(
             continue;
         }
         sender
-            .send(ThreadMessage::SyncModel(table_schema.clone()))
+            .send(ThreadMessage::SyncModels(schema_strings.clone()))
             .await
             .unwrap();
     }
@@ -229,8 +770,169 @@ pub async fn send_drop_table(
     }
 }
 
+pub async fn send_set_read_only(
+    table_name: String,
+    read_only: bool,
+    senders: &mut [OperationSender],
+    current_thread_number: usize,
+) {
+    for (partition, sender) in senders.iter_mut().enumerate() {
+        if current_thread_number == partition {
+            continue;
+        }
+        sender
+            .send(ThreadMessage::SetReadOnly(table_name.clone(), read_only))
+            .await
+            .unwrap();
+    }
+}
+
+pub async fn send_release_bucket(
+    bucket: u32,
+    senders: &mut [OperationSender],
+    current_thread_number: usize,
+) -> Vec<(String, ReplayedState)> {
+    let mut released_states = Vec::new();
+    for (partition, sender) in senders.iter_mut().enumerate() {
+        if current_thread_number == partition {
+            continue;
+        }
+        let (release_sender, receiver) = oneshot::channel();
+        sender
+            .send(ThreadMessage::ReleaseBucket(bucket, release_sender))
+            .await
+            .unwrap();
+        released_states.extend(receiver.await.unwrap());
+    }
+
+    released_states
+}
+
+pub async fn send_adopt_bucket(
+    released_states: Vec<(String, ReplayedState)>,
+    senders: &mut [OperationSender],
+    target_thread_number: usize,
+) {
+    let (adopt_sender, receiver) = oneshot::channel();
+    senders[target_thread_number]
+        .send(ThreadMessage::AdoptBucket(released_states, adopt_sender))
+        .await
+        .unwrap();
+    receiver.await.unwrap();
+}
+
+pub async fn send_count_memtable_rows(
+    table_name: &str,
+    senders: &mut [OperationSender],
+    current_thread_number: usize,
+) -> HashMap<usize, usize> {
+    let mut rows_per_partition = HashMap::new();
+    for (partition, sender) in senders.iter_mut().enumerate() {
+        if current_thread_number == partition {
+            continue;
+        }
+        let (count_sender, receiver) = oneshot::channel();
+        sender
+            .send(ThreadMessage::CountMemtableRows(
+                table_name.to_string(),
+                count_sender,
+            ))
+            .await
+            .unwrap();
+        for (partition, rows) in receiver.await.unwrap() {
+            *rows_per_partition.entry(partition).or_insert(0) += rows;
+        }
+    }
+
+    rows_per_partition
+}
+
+pub async fn send_query_index(
+    table_name: &str,
+    index_range: &IndexRange,
+    senders: &mut [OperationSender],
+    current_thread_number: usize,
+) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for (partition, sender) in senders.iter_mut().enumerate() {
+        if current_thread_number == partition {
+            continue;
+        }
+        let (query_sender, receiver) = oneshot::channel();
+        sender
+            .send(ThreadMessage::QueryIndex(
+                table_name.to_string(),
+                index_range.clone(),
+                query_sender,
+            ))
+            .await
+            .unwrap();
+        rows.extend(receiver.await.unwrap());
+    }
+
+    rows
+}
+
+pub(crate) fn read_trace_to_proto(read_trace: ReadTrace) -> ReadExplanation {
+    let mut read_explanation = ReadExplanation::new();
+    read_explanation.primary_key = read_trace.primary_key;
+    read_explanation.memtable_hit = read_trace.memtable_hit;
+    read_explanation.memtable_micros = read_trace.memtable_time.as_micros() as u64;
+    read_explanation.sstables = read_trace
+        .sstables
+        .into_iter()
+        .map(|sstable_read| {
+            let mut sstable_read_explanation = SSTableReadExplanation::new();
+            sstable_read_explanation.file_name = sstable_read.file_name;
+            sstable_read_explanation.partition_skipped = sstable_read.partition_skipped;
+            sstable_read_explanation.rows_read = sstable_read.rows_read as u64;
+            sstable_read_explanation.bytes_read = sstable_read.bytes_read;
+            sstable_read_explanation.found = sstable_read.found;
+            sstable_read_explanation.micros = sstable_read.time.as_micros() as u64;
+            sstable_read_explanation
+        })
+        .collect();
+    read_explanation.sstables_not_consulted = read_trace.sstables_not_consulted as u64;
+
+    read_explanation
+}
+
+// rows past the page's bytes are left out, so that frame stays far from its u32 size limit, at
+// least one item is always answered, so that client asking for the rest makes progress
+fn get_many_response(
+    operation_responses: Vec<OperationResponse>,
+    page: GetManyPage,
+) -> GetManyResponse {
+    let mut get_many_response = GetManyResponse::new();
+    let mut response_bytes = 0;
+    let items = operation_responses.len();
+    for operation_response in operation_responses {
+        let row = match operation_response {
+            OperationResponse::Get(row) => row,
+            _ => panic!("Invalid operation response type"),
+        };
+        let get_response = row.map(row_to_get_response);
+        let item_bytes = get_response
+            .as_ref()
+            .map(|get_response| get_response.compute_size() as usize)
+            .unwrap_or(0);
+        if !get_many_response.found.is_empty() && response_bytes + item_bytes > page.max_bytes {
+            break;
+        }
+        response_bytes += item_bytes;
+        get_many_response.found.push(get_response.is_some());
+        get_many_response.items.extend(get_response);
+    }
+    if get_many_response.found.len() < items {
+        get_many_response.continuation_token =
+            (page.offset + get_many_response.found.len()).to_string();
+    }
+    get_many_response
+}
+
 fn row_to_get_response(row: Row) -> GetResponse {
     let mut get_response = GetResponse::new();
+    get_response.version = row.visible_version();
     get_response.hash_key = row.hash_key;
     get_response.sort_key = parse_message_field_from_value(row.sort_key);
     get_response.values = row
@@ -241,3 +943,101 @@ fn row_to_get_response(row: Row) -> GetResponse {
 
     get_response
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::value::Value::Int32;
+
+    #[test]
+    fn get_many_page_is_bounded_by_server_limit() {
+        let page = GetManyPage::new("", 0).unwrap();
+        assert_eq!(page.offset, 0);
+        assert_eq!(page.max_bytes, GET_MANY_RESPONSE_MAX_BYTES);
+        let page = GetManyPage::new("12", 1024).unwrap();
+        assert_eq!((page.offset, page.max_bytes), (12, 1024));
+        let page = GetManyPage::new("0", u64::MAX).unwrap();
+        assert_eq!(page.max_bytes, GET_MANY_RESPONSE_MAX_BYTES);
+        assert!(GetManyPage::new("next", 0).is_err());
+    }
+
+    #[test]
+    fn get_many_response_is_cut_off_at_max_bytes() {
+        let row = |hash_key: &str| {
+            let values = HashMap::from([("name".to_string(), Value::Varchar("x".repeat(100)))]);
+            Some(Row::new(hash_key.to_string(), Int32(-1), values))
+        };
+        let operation_responses = || {
+            vec![
+                OperationResponse::Get(row("a")),
+                OperationResponse::Get(None),
+                OperationResponse::Get(row("b")),
+                OperationResponse::Get(row("c")),
+            ]
+        };
+
+        let page = |max_bytes: usize| GetManyPage {
+            offset: 5,
+            max_bytes,
+        };
+
+        let response = get_many_response(operation_responses(), page(usize::MAX));
+        assert_eq!(response.found, vec![true, false, true, true]);
+        assert_eq!(response.items.len(), 3);
+        assert!(response.continuation_token.is_empty());
+
+        // first row is answered even though it does not fit
+        let response = get_many_response(operation_responses(), page(10));
+        assert_eq!(response.found, vec![true]);
+        assert_eq!(response.items[0].hash_key, "a");
+        assert_eq!(response.continuation_token, "6");
+
+        let response = get_many_response(operation_responses(), page(300));
+        assert_eq!(response.found, vec![true, false, true]);
+        assert_eq!(response.continuation_token, "8");
+    }
+
+    #[test]
+    fn index_pages_resume_past_last_row_answered() {
+        let row = |hash_key: &str, city: &str| {
+            let values = HashMap::from([("city".to_string(), Value::Varchar(city.to_string()))]);
+            Row::new(hash_key.to_string(), Int32(-1), values)
+        };
+        // rows of the same value go by primary key, which may have any character in it
+        let rows = || {
+            vec![
+                row("c", "berlin"),
+                row("a", "oslo"),
+                row("b:1", "oslo"),
+                row("d", "rome"),
+            ]
+        };
+        let hash_keys = |rows: &[Row]| {
+            rows.iter()
+                .map(|row| row.hash_key.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut continuation_token = String::new();
+        let mut pages = Vec::new();
+        loop {
+            let page = IndexPage::new(&continuation_token, 2).unwrap();
+            let (rows, token) = page.cut(rows(), "city");
+            pages.push(hash_keys(&rows));
+            if token.is_empty() {
+                break;
+            }
+            continuation_token = token;
+        }
+        assert_eq!(pages, vec![vec!["c", "a"], vec!["b:1", "d"]]);
+
+        let primary_key = row("b:1", "oslo").primary_key;
+        let token = format!("4:oslo{}", primary_key);
+        let page = IndexPage::new(&token, 0).unwrap();
+        assert_eq!(page.limit, usize::MAX);
+        assert_eq!(page.after, Some(("oslo".to_string(), primary_key)));
+        assert_eq!(hash_keys(&page.cut(rows(), "city").0), vec!["d"]);
+        assert!(IndexPage::new("oslo", 0).is_err());
+        assert!(IndexPage::new("9:oslo", 0).is_err());
+    }
+}
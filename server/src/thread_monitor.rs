@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+
+// thread that keeps panicking most likely hits the same data on replay, so process exits instead
+pub(crate) static THREAD_MAX_RESTARTS: usize = 3;
+
+static THREAD_PANICS: Mutex<BTreeMap<usize, ThreadPanic>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadPanic {
+    pub thread: usize,
+    pub panics: usize,
+    pub last_panic: String,
+    // partitions of the thread are unavailable until it replays its commit logs and listens again
+    pub restarting: bool,
+}
+
+impl Display for ThreadPanic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = match self.restarting {
+            true => "restarting",
+            false => "restarted",
+        };
+        write!(
+            f,
+            "thread {} {} after panic: {}, {} panics so far",
+            self.thread, state, self.last_panic, self.panics
+        )
+    }
+}
+
+// returns how many times the thread panicked since server started
+pub(crate) fn report_thread_panic(thread: usize, message: String) -> usize {
+    let mut thread_panics = THREAD_PANICS.lock().unwrap();
+    let thread_panic = thread_panics.entry(thread).or_insert(ThreadPanic {
+        thread,
+        panics: 0,
+        last_panic: String::new(),
+        restarting: true,
+    });
+    thread_panic.panics += 1;
+    thread_panic.last_panic = message;
+    thread_panic.restarting = true;
+    thread_panic.panics
+}
+
+pub(crate) fn report_thread_listening(thread: usize) {
+    if let Some(thread_panic) = THREAD_PANICS.lock().unwrap().get_mut(&thread) {
+        thread_panic.restarting = false;
+    }
+}
+
+// threads stay reported after restart, so that repeated panics are not missed
+pub fn thread_panics() -> Vec<ThreadPanic> {
+    THREAD_PANICS.lock().unwrap().values().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panicked_thread_is_reported_until_it_listens_again() {
+        let thread = 1000;
+        assert_eq!(report_thread_panic(thread, "boom".to_string()), 1);
+        assert_eq!(report_thread_panic(thread, "bang".to_string()), 2);
+
+        let find = || {
+            thread_panics()
+                .into_iter()
+                .find(|thread_panic| thread_panic.thread == thread)
+                .unwrap()
+        };
+        assert!(find().restarting);
+        assert_eq!(find().last_panic, "bang");
+
+        report_thread_listening(thread);
+        assert_eq!(
+            find().to_string(),
+            "thread 1000 restarted after panic: bang, 2 panics so far"
+        );
+    }
+}
@@ -1,8 +1,21 @@
 use crate::handlers::HandlerError;
+use futures::lock::Mutex;
 use rand::{thread_rng, RngCore};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+use storage::table::Table;
 use storage::transaction::Transaction;
 
+// transaction as seen by a single thread, threads' views are merged when listing
+#[derive(Debug, Clone)]
+pub struct TransactionInfo {
+    pub id: u64,
+    pub started_at: u128,
+    pub affected_tables: BTreeSet<String>,
+    // thread that began the transaction and commits or aborts it
+    pub coordinator_partition: Option<usize>,
+}
+
 pub struct TransactionManager {
     pub transactions: HashMap<u64, Transaction>,
     coordinated_transactions: HashSet<u64>,
@@ -17,14 +30,29 @@ impl TransactionManager {
     }
 
     pub fn add_coordinated(&mut self) -> u64 {
-        let mut rng = thread_rng();
-        let transaction_id = rng.next_u64();
+        let transaction_id = new_transaction_id();
         self.coordinated_transactions.insert(transaction_id);
         transaction_id
     }
 
+    // id chosen by client, beginning it again on the same thread is a no-op, so that client
+    // can retry operation which began the transaction
+    pub fn add_coordinated_with(&mut self, transaction_id: u64) -> Result<bool, HandlerError> {
+        if self.coordinated_transactions.contains(&transaction_id) {
+            return Ok(false);
+        }
+        if self.transactions.contains_key(&transaction_id) {
+            return Err(HandlerError::Conflict(format!(
+                "Transaction with id '{}' was already begun by another thread",
+                transaction_id
+            )));
+        }
+        self.coordinated_transactions.insert(transaction_id);
+        Ok(true)
+    }
+
     pub fn remove_coordinated(&mut self, transaction_id: u64) -> Result<(), HandlerError> {
-        if self.coordinated_transactions.remove(&transaction_id) {
+        if !self.coordinated_transactions.remove(&transaction_id) {
             return Err(HandlerError::Client(format!(
                 "Cannot commit non existing transaction with id '{}'",
                 transaction_id
@@ -38,7 +66,76 @@ impl TransactionManager {
             .insert(transaction_id, Transaction::new(transaction_id));
     }
 
+    // prepared before this thread crashed, waits for coordinator to commit or abort it
+    pub fn add_prepared(&mut self, transaction: Transaction) {
+        self.transactions.insert(transaction.id, transaction);
+    }
+
     pub fn remove(&mut self, transaction_id: u64) -> Option<Transaction> {
         self.transactions.remove(&transaction_id)
     }
+
+    pub fn list(&self, current_thread_number: usize) -> Vec<TransactionInfo> {
+        self.transactions
+            .values()
+            .map(|transaction| TransactionInfo {
+                id: transaction.id,
+                started_at: transaction.started_at,
+                affected_tables: transaction.affected_tables(),
+                coordinator_partition: self
+                    .coordinated_transactions
+                    .contains(&transaction.id)
+                    .then_some(current_thread_number),
+            })
+            .collect()
+    }
+
+    // drops transaction without committing, coordinator then rejects its commit
+    pub async fn kill(
+        &mut self,
+        transaction_id: u64,
+        tables: Rc<Mutex<HashMap<String, Table>>>,
+    ) -> bool {
+        let coordinated = self.coordinated_transactions.remove(&transaction_id);
+        match self.transactions.remove(&transaction_id) {
+            Some(transaction) => {
+                transaction.release_intents(tables).await;
+                true
+            }
+            None => coordinated,
+        }
+    }
+
+    // table is about to be dropped, transactions that already voted to commit are left to commit
+    // without it, so that coordinator's decision holds for the rest of their writes
+    pub async fn abort_touching(
+        &mut self,
+        table_name: &str,
+        tables: Rc<Mutex<HashMap<String, Table>>>,
+    ) -> Vec<u64> {
+        let aborted_ids: Vec<_> = self
+            .transactions
+            .values()
+            .filter(|transaction| {
+                !transaction.is_prepared() && transaction.affected_tables().contains(table_name)
+            })
+            .map(|transaction| transaction.id)
+            .collect();
+        for transaction_id in &aborted_ids {
+            if let Some(transaction) = self.transactions.remove(transaction_id) {
+                transaction.release_intents(tables.clone()).await;
+            }
+        }
+        aborted_ids
+    }
+}
+
+fn new_transaction_id() -> u64 {
+    // ids are part of responses, so simulated runs draw them from seeded rng
+    #[cfg(feature = "simulation")]
+    if storage::simulation::is_simulated() {
+        return storage::simulation::next_random_u64();
+    }
+
+    thread_rng().next_u64()
 }
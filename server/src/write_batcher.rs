@@ -0,0 +1,249 @@
+use crate::context::ThreadContext;
+use crate::handlers::{check_unique_values, check_write_intent, row_policy_error, HandlerError};
+use futures::channel::{mpsc, oneshot};
+use futures::lock::Mutex;
+use futures::StreamExt;
+use monoio::time::sleep;
+use std::collections::{HashMap, HashSet};
+use std::pin::pin;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use storage::auth::AuthContext;
+use storage::index::unique_values;
+use storage::log_syncer::sync_commit_logs_of;
+use storage::table::Table;
+use storage::table_options::Durability;
+use storage::validation::validate_values_against_schema;
+use storage::Row;
+
+// inserts arriving within that window after the first one are applied together
+static BATCH_WINDOW: Duration = Duration::from_micros(300);
+static MAX_BATCH_SIZE: usize = 256;
+
+pub struct PendingInsert {
+    table_name: String,
+    row: Row,
+    auth: AuthContext,
+    // sort key assigned to row of append-only table, once the batch is applied
+    sequence: Option<u64>,
+    responder: oneshot::Sender<Result<(Option<u64>, BatchLatency), HandlerError>>,
+}
+
+// time spent on batch insert got applied with, the rest of waiting went to filling up the batch
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BatchLatency {
+    pub memtable: Duration,
+    pub commit_log: Duration,
+}
+
+pub type WriteBatchReceiver = mpsc::UnboundedReceiver<PendingInsert>;
+
+#[derive(Clone)]
+pub struct WriteBatcher {
+    sender: mpsc::UnboundedSender<PendingInsert>,
+}
+
+impl WriteBatcher {
+    pub fn new() -> (WriteBatcher, WriteBatchReceiver) {
+        let (sender, receiver) = mpsc::unbounded();
+        (WriteBatcher { sender }, receiver)
+    }
+
+    // resolves once the row is logged and applied to memtable, with sort key assigned to row of
+    // append-only table
+    pub async fn insert(
+        &self,
+        table_name: String,
+        row: Row,
+        auth: AuthContext,
+    ) -> Result<(Option<u64>, BatchLatency), HandlerError> {
+        let (responder, receiver) = oneshot::channel();
+        self.sender
+            .unbounded_send(PendingInsert {
+                table_name,
+                row,
+                auth,
+                sequence: None,
+                responder,
+            })
+            .map_err(|e| HandlerError::Server(e.to_string()))?;
+
+        receiver
+            .await
+            .map_err(|e| HandlerError::Server(e.to_string()))?
+    }
+}
+
+pub async fn write_batcher_main(
+    mut receiver: WriteBatchReceiver,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: ThreadContext,
+) {
+    while let Some(pending_insert) = receiver.next().await {
+        let mut batch = vec![pending_insert];
+        let mut window = pin!(sleep(BATCH_WINDOW));
+
+        while batch.len() < MAX_BATCH_SIZE {
+            monoio::select! {
+                _ = &mut window => break,
+                pending_insert = receiver.next() => match pending_insert {
+                    Some(pending_insert) => batch.push(pending_insert),
+                    None => break,
+                },
+            }
+        }
+
+        apply_batch(batch, &tables, &thread_context).await;
+    }
+}
+
+// tables map and every commit log are locked once per batch, rows of a table are logged in a single write
+async fn apply_batch(
+    batch: Vec<PendingInsert>,
+    tables: &Rc<Mutex<HashMap<String, Table>>>,
+    thread_context: &ThreadContext,
+) {
+    let mut tables = tables.lock().await;
+
+    let mut inserts_per_table: HashMap<String, Vec<PendingInsert>> = HashMap::new();
+    for pending_insert in batch {
+        let validation_result = match tables.get(&pending_insert.table_name) {
+            Some(table)
+                if !table
+                    .table_schema
+                    .options
+                    .allows_row(&pending_insert.row.hash_key, &pending_insert.auth) =>
+            {
+                Err(row_policy_error(
+                    &pending_insert.row.hash_key,
+                    &pending_insert.table_name,
+                ))
+            }
+            Some(table) => validate_values_against_schema(
+                &pending_insert.row.sort_key,
+                &pending_insert.row.values,
+                &table.table_schema,
+            )
+            .map_err(|detail| HandlerError::SchemaMismatch {
+                table_name: pending_insert.table_name.clone(),
+                detail,
+            })
+            .and_then(|_| check_write_intent(table, &pending_insert.row.primary_key)),
+            None => Err(HandlerError::NotFound(format!(
+                "Table named '{}' not found",
+                pending_insert.table_name
+            ))),
+        };
+
+        match validation_result {
+            Ok(()) => inserts_per_table
+                .entry(pending_insert.table_name.clone())
+                .or_default()
+                .push(pending_insert),
+            Err(error) => {
+                let _ = pending_insert.responder.send(Err(error));
+            }
+        }
+    }
+
+    let partition_map = thread_context.partition_map();
+    let mut synced_responders = Vec::new();
+    for (table_name, batched_inserts) in inserts_per_table {
+        let table = tables.get_mut(&table_name).unwrap();
+        // values taken by earlier inserts of the batch are not written yet, so they are kept aside
+        let mut taken_values = HashSet::new();
+        let mut pending_inserts = Vec::with_capacity(batched_inserts.len());
+        for mut pending_insert in batched_inserts {
+            pending_insert.sequence = table.assign_append_sort_key(&mut pending_insert.row).await;
+            let row = &pending_insert.row;
+            let values = unique_values(&table.table_schema, row);
+            let checked = match values.iter().any(|value| taken_values.contains(value)) {
+                true => Err(HandlerError::Conflict(format!(
+                    "Value of unique column of table '{}' is taken by another insert of row '{}'",
+                    table_name, row.primary_key
+                ))),
+                false => check_unique_values(table, row, thread_context).await,
+            };
+            match checked {
+                Ok(()) => {
+                    taken_values.extend(values);
+                    pending_inserts.push(pending_insert);
+                }
+                Err(error) => {
+                    let _ = pending_insert.responder.send(Err(error));
+                }
+            }
+        }
+        // duplicates are acknowledged along with the rest of the batch, without being written
+        let (duplicates, pending_inserts): (Vec<_>, Vec<_>) = pending_inserts
+            .into_iter()
+            .partition(|pending_insert| table.is_duplicate_insert(&pending_insert.row));
+        let mut latency = BatchLatency::default();
+        {
+            let started_at = Instant::now();
+            let rows: Vec<_> = pending_inserts
+                .iter()
+                .map(|pending_insert| &pending_insert.row)
+                .collect();
+            let mut commit_log = table.commit_log.lock().await;
+            commit_log.write_inserts(&rows).await;
+            latency.commit_log = started_at.elapsed();
+        }
+        // flush below may rotate the log, rows of the batch stay in this one
+        let written_commit_log = table.commit_log.clone();
+
+        let started_at = Instant::now();
+        let mut responders = Vec::with_capacity(duplicates.len() + pending_inserts.len());
+        for duplicate in duplicates {
+            table.track_access(&duplicate.row);
+            responders.push((duplicate.responder, duplicate.sequence));
+        }
+        for pending_insert in pending_inserts {
+            table
+                .count_insert(&pending_insert.row, &partition_map)
+                .await;
+            table.track_access(&pending_insert.row);
+            table.apply_insert(pending_insert.row, &pending_insert.auth);
+            responders.push((pending_insert.responder, pending_insert.sequence));
+        }
+        latency.memtable = started_at.elapsed();
+
+        table.evict_least_recently_used().await;
+        table
+            .flush_memtable_if_needed(&thread_context.partitions, &partition_map)
+            .await;
+
+        match table.table_schema.options.durability {
+            Durability::Always => synced_responders.push((written_commit_log, responders, latency)),
+            Durability::Periodic => {
+                for (responder, sequence) in responders {
+                    let _ = responder.send(Ok((sequence, latency)));
+                }
+            }
+        }
+    }
+    drop(tables);
+
+    // one sync pass covers every table of the batch
+    let started_at = Instant::now();
+    let written_commit_logs: Vec<_> = synced_responders
+        .iter()
+        .map(|(commit_log, _, _)| commit_log.clone())
+        .collect();
+    let synced = match written_commit_logs.is_empty() {
+        true => Ok(()),
+        false => sync_commit_logs_of(&written_commit_logs).await,
+    };
+    let sync_time = started_at.elapsed();
+    for (_, responders, mut latency) in synced_responders {
+        latency.commit_log += sync_time;
+        for (responder, sequence) in responders {
+            let _ = responder.send(
+                synced
+                    .clone()
+                    .map(|_| (sequence, latency))
+                    .map_err(HandlerError::Server),
+            );
+        }
+    }
+}
@@ -0,0 +1,586 @@
+use client::inference::{document_to_values, infer_table_schema, parse_sort_key, row_to_document};
+use client::probe::{check_health, check_ready};
+use client::{Connection, ConnectionError};
+use std::env;
+use std::net::SocketAddrV4;
+use std::process::exit;
+use std::str::FromStr;
+use std::time::Duration;
+use storage::sstable::{CompactionSettingsUpdate, ReadTrace};
+use storage::table::TableSchema;
+
+static USAGE: &str = "Usage:
+    yard-cli plan-migration <address> <schema_string>
+    yard-cli insert-json <address> <table_name> <json_document> [--apply-schema]
+    yard-cli get <address> <table_name> <hash_key> <sort_key> [--explain]
+    yard-cli health <address>
+    yard-cli ready <address>
+    yard-cli describe-server <address>
+    yard-cli promote <address>
+    yard-cli migrate-bucket <address> <hash_key> <target_partition>
+    yard-cli key-distribution <address> <table_name>
+    yard-cli partition-load <address>
+    yard-cli table-access <address>
+    yard-cli statistics <address> <table_name>
+    yard-cli transactions <address>
+    yard-cli kill-transaction <address> <transaction_id>
+    yard-cli read-only <address> <table_name> <on|off>
+    yard-cli compaction <address> [bucket_low=<f64>] [bucket_high=<f64>] [sstable_min_size=<bytes>] [interval_millis=<u64>] [max_concurrent_compactions=<usize>] [io_rate_limit_mb=<u64>]";
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|command| command.as_str()) {
+        Some("plan-migration") if args.len() == 3 => plan_migration(&args[1], &args[2]).await,
+        Some("health") if args.len() == 2 => probe(&args[1], false).await,
+        Some("ready") if args.len() == 2 => probe(&args[1], true).await,
+        Some("describe-server") if args.len() == 2 => describe_server(&args[1]).await,
+        Some("promote") if args.len() == 2 => promote(&args[1]).await,
+        Some("migrate-bucket") if args.len() == 4 => {
+            migrate_bucket(&args[1], &args[2], &args[3]).await
+        }
+        Some("key-distribution") if args.len() == 3 => key_distribution(&args[1], &args[2]).await,
+        Some("partition-load") if args.len() == 2 => partition_load(&args[1]).await,
+        Some("table-access") if args.len() == 2 => table_access(&args[1]).await,
+        Some("statistics") if args.len() == 3 => table_statistics(&args[1], &args[2]).await,
+        Some("transactions") if args.len() == 2 => list_transactions(&args[1]).await,
+        Some("kill-transaction") if args.len() == 3 => kill_transaction(&args[1], &args[2]).await,
+        Some("read-only") if args.len() == 4 => set_read_only(&args[1], &args[2], &args[3]).await,
+        Some("compaction") if args.len() >= 2 => compaction(&args[1], &args[2..]).await,
+        Some("get") if args.len() == 5 => get(&args[1], &args[2], &args[3], &args[4], false).await,
+        Some("get") if args.len() == 6 && args[5] == "--explain" => {
+            get(&args[1], &args[2], &args[3], &args[4], true).await
+        }
+        Some("insert-json") if args.len() == 4 => {
+            insert_json(&args[1], &args[2], &args[3], false).await
+        }
+        Some("insert-json") if args.len() == 5 && args[4] == "--apply-schema" => {
+            insert_json(&args[1], &args[2], &args[3], true).await
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            exit(1);
+        }
+    }
+}
+
+async fn plan_migration(address: &str, schema_string: &str) {
+    let table_schema = TableSchema::from_string(schema_string).unwrap_or_else(|error| {
+        eprintln!("Invalid schema string: {}", error);
+        exit(1);
+    });
+    let connection = connect(address).await;
+
+    match connection.plan_migration(table_schema).await {
+        Ok(steps) if steps.is_empty() => println!("Schema is up to date"),
+        Ok(steps) => {
+            for step in steps {
+                println!("{}", step);
+            }
+        }
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+}
+
+// schema changes inferred from the document are only applied with --apply-schema
+async fn insert_json(address: &str, table_name: &str, json_document: &str, apply_schema: bool) {
+    let document = serde_json::from_str(json_document).unwrap_or_else(|error| {
+        eprintln!("Invalid JSON document: {}", error);
+        exit(1);
+    });
+    let connection = connect(address).await;
+
+    let current_schema = match connection.describe_table(table_name).await {
+        Ok(table_schema) => Some(table_schema),
+        Err(ConnectionError::NotFound(_)) => None,
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    };
+
+    let table_schema = infer_table_schema(table_name, &document, current_schema.as_ref())
+        .unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            exit(1);
+        });
+
+    let schema_changed = match &current_schema {
+        Some(current_schema) => current_schema.to_string() != table_schema.to_string(),
+        None => true,
+    };
+    if schema_changed {
+        let steps = connection
+            .plan_migration(table_schema.clone())
+            .await
+            .unwrap_or_else(|error| {
+                eprintln!("{:?}", error);
+                exit(1);
+            });
+        for step in steps {
+            println!("{}", step);
+        }
+
+        if !apply_schema {
+            eprintln!("Schema changes required, rerun with --apply-schema to apply them");
+            exit(1);
+        }
+
+        if let Err(error) = connection.sync_schema(table_schema.clone()).await {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+
+    let (hash_key, sort_key, values) =
+        document_to_values(&document, &table_schema).unwrap_or_else(|error| {
+            eprintln!("{}", error);
+            exit(1);
+        });
+    if let Err(error) = connection
+        .insert_values(table_name, hash_key, sort_key, values)
+        .await
+    {
+        eprintln!("{:?}", error);
+        exit(1);
+    }
+    println!("Inserted");
+}
+
+// row is printed as JSON document, the same shape insert-json takes
+// explanation goes to stderr, so that stdout stays a json document
+async fn get(address: &str, table_name: &str, hash_key: &str, sort_key: &str, explain: bool) {
+    let connection = connect(address).await;
+
+    let table_schema = connection
+        .describe_table(table_name)
+        .await
+        .unwrap_or_else(|error| {
+            eprintln!("{:?}", error);
+            exit(1);
+        });
+    let sort_key = parse_sort_key(sort_key, &table_schema).unwrap_or_else(|error| {
+        eprintln!("Invalid sort key: {}", error);
+        exit(1);
+    });
+
+    let result = match explain {
+        true => connection
+            .explain_get_row(table_name, hash_key.to_string(), sort_key)
+            .await
+            .map(|(row, read_trace)| {
+                print_read_trace(&read_trace);
+                row
+            }),
+        false => {
+            connection
+                .get_row(table_name, hash_key.to_string(), sort_key)
+                .await
+        }
+    };
+    match result {
+        Ok(Some(row)) => println!("{}", row_to_document(&row)),
+        Ok(None) => {
+            eprintln!("Row not found");
+            exit(1);
+        }
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+}
+
+fn print_read_trace(read_trace: &ReadTrace) {
+    eprintln!(
+        "memtable\t{}\t{:?}",
+        match read_trace.memtable_hit {
+            true => "hit",
+            false => "miss",
+        },
+        read_trace.memtable_time
+    );
+    eprintln!("sstable\tpartition_skipped\trows_read\tbytes_read\tfound\ttime");
+    for sstable_read in &read_trace.sstables {
+        eprintln!(
+            "{}\t{}\t{}\t{}\t{}\t{:?}",
+            sstable_read.file_name,
+            sstable_read.partition_skipped,
+            sstable_read.rows_read,
+            sstable_read.bytes_read,
+            sstable_read.found,
+            sstable_read.time
+        );
+    }
+    eprintln!(
+        "sstables_not_consulted\t{}",
+        read_trace.sstables_not_consulted
+    );
+}
+
+// exit code is what orchestrators look at
+async fn migrate_bucket(address: &str, hash_key: &str, target_partition: &str) {
+    let target_partition = target_partition.parse::<usize>().unwrap_or_else(|_| {
+        eprintln!("Invalid target partition '{}'", target_partition);
+        exit(1);
+    });
+    let connection = connect(address).await;
+
+    match connection
+        .migrate_bucket(hash_key.to_string(), target_partition)
+        .await
+    {
+        Ok(()) => println!("OK"),
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+}
+
+// share of table's rows held by each partition, far above 1/partitions means a hot partition
+async fn key_distribution(address: &str, table_name: &str) {
+    let connection = connect(address).await;
+
+    let partitions_rows = connection
+        .key_distribution(table_name)
+        .await
+        .unwrap_or_else(|error| {
+            eprintln!("{:?}", error);
+            exit(1);
+        });
+
+    let total_rows: u64 = partitions_rows
+        .iter()
+        .map(|partition_rows| partition_rows.memtable_rows + partition_rows.sstable_rows)
+        .sum();
+    println!("partition\tmemtable_rows\tsstable_rows\tbytes\tshare");
+    for partition_rows in partitions_rows {
+        let rows = partition_rows.memtable_rows + partition_rows.sstable_rows;
+        let share = match total_rows {
+            0 => 0.0,
+            _ => rows as f64 * 100.0 / total_rows as f64,
+        };
+        println!(
+            "{}\t{}\t{}\t{}\t{:.1}%",
+            partition_rows.partition,
+            partition_rows.memtable_rows,
+            partition_rows.sstable_rows,
+            partition_rows.bytes,
+            share
+        );
+    }
+}
+
+// requests over the last window the server closed, hot partitions are marked with *
+async fn partition_load(address: &str) {
+    let connection = connect(address).await;
+
+    let partitions_load = connection.partition_load().await.unwrap_or_else(|error| {
+        eprintln!("{:?}", error);
+        exit(1);
+    });
+
+    println!("partition\trequests_per_second\taverage_latency\tskew");
+    for partition_load in partitions_load {
+        println!(
+            "{}{}\t{:.1}\t{:?}\t{:.1}x",
+            partition_load.partition,
+            if partition_load.hot { "*" } else { "" },
+            partition_load.requests_per_second,
+            partition_load.average_latency,
+            partition_load.skew
+        );
+    }
+}
+
+// reads and writes per second over the last minute, bytes and p99 latencies as upper bounds
+async fn table_access(address: &str) {
+    let connection = connect(address).await;
+
+    let tables_access = connection.table_access().await.unwrap_or_else(|error| {
+        eprintln!("{:?}", error);
+        exit(1);
+    });
+
+    println!("table\treads_per_second\twrites_per_second\tread_bytes_per_second\twritten_bytes_per_second\tread_p99\twrite_p99");
+    for table_access in tables_access {
+        println!(
+            "{}\t{:.1}\t{:.1}\t{:.0}\t{:.0}\t{:?}\t{:?}",
+            table_access.table,
+            table_access.reads_per_second,
+            table_access.writes_per_second,
+            table_access.read_bytes_per_second,
+            table_access.written_bytes_per_second,
+            table_access.read_p99_latency,
+            table_access.write_p99_latency
+        );
+    }
+}
+
+// histogram rows are hash keys having 1, 2-3, 4-7, ... rows
+async fn table_statistics(address: &str, table_name: &str) {
+    let connection = connect(address).await;
+
+    let statistics = connection
+        .table_statistics(table_name)
+        .await
+        .unwrap_or_else(|error| {
+            eprintln!("{:?}", error);
+            exit(1);
+        });
+
+    println!("sstables\t{}", statistics.sstables);
+    println!("row_count_estimate\t{}", statistics.row_count_estimate);
+    println!("average_row_size\t{}", statistics.average_row_size);
+    println!("hash_key_cardinality\t{}", statistics.hash_key_cardinality);
+    println!("disk_usage\t{}", statistics.disk_usage);
+    println!("rows_per_hash_key\thash_keys");
+    for (bucket, hash_keys) in statistics.rows_per_hash_key.iter().enumerate() {
+        println!(
+            "{}-{}\t{}",
+            1u64 << bucket,
+            (1u64 << (bucket + 1)) - 1,
+            hash_keys
+        );
+    }
+}
+
+// settings given as name=value are changed before buckets are printed
+async fn compaction(address: &str, settings: &[String]) {
+    let update = parse_compaction_settings(settings).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        exit(1);
+    });
+    let connection = connect(address).await;
+
+    let compaction_state = connection.compaction(update).await.unwrap_or_else(|error| {
+        eprintln!("{:?}", error);
+        exit(1);
+    });
+
+    let settings = compaction_state.settings;
+    println!(
+        "bucket_low={} bucket_high={} sstable_min_size={} interval_millis={} max_concurrent_compactions={} io_rate_limit_mb={}",
+        settings.bucket_low,
+        settings.bucket_high,
+        settings.sstable_min_size,
+        settings.interval.as_millis(),
+        settings.max_concurrent_compactions,
+        settings.io_rate_limit_mb
+    );
+    println!("table\tpriority\tpending_sstables\tlast_duration\tbuckets");
+    for table_state in compaction_state.tables {
+        let last_duration = table_state
+            .last_duration
+            .map(|duration| format!("{:?}", duration))
+            .unwrap_or("-".to_string());
+        let buckets: Vec<_> = table_state
+            .buckets
+            .iter()
+            .map(|bucket| {
+                format!(
+                    "{}x{}B{}",
+                    bucket.sstables,
+                    bucket.bytes,
+                    if bucket.pending { "*" } else { "" }
+                )
+            })
+            .collect();
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            table_state.table_name,
+            table_state.priority,
+            table_state.pending_sstables,
+            last_duration,
+            buckets.join(",")
+        );
+    }
+}
+
+fn parse_compaction_settings(settings: &[String]) -> Result<CompactionSettingsUpdate, String> {
+    let mut update = CompactionSettingsUpdate::default();
+    for setting in settings {
+        let (name, value) = setting.split_once('=').ok_or(format!(
+            "Invalid setting '{}', expected name=value",
+            setting
+        ))?;
+        let invalid_value = || format!("Invalid value '{}' of '{}'", value, name);
+        match name {
+            "bucket_low" => update.bucket_low = Some(value.parse().map_err(|_| invalid_value())?),
+            "bucket_high" => update.bucket_high = Some(value.parse().map_err(|_| invalid_value())?),
+            "sstable_min_size" => {
+                update.sstable_min_size = Some(value.parse().map_err(|_| invalid_value())?)
+            }
+            "interval_millis" => {
+                update.interval = Some(Duration::from_millis(
+                    value.parse().map_err(|_| invalid_value())?,
+                ))
+            }
+            "max_concurrent_compactions" => {
+                update.max_concurrent_compactions =
+                    Some(value.parse().map_err(|_| invalid_value())?)
+            }
+            "io_rate_limit_mb" => {
+                update.io_rate_limit_mb = Some(value.parse().map_err(|_| invalid_value())?)
+            }
+            _ => return Err(format!("Unknown compaction setting '{}'", name)),
+        }
+    }
+
+    Ok(update)
+}
+
+async fn describe_server(address: &str) {
+    let connection = connect(address).await;
+
+    let server_description = connection.describe_server().await.unwrap_or_else(|error| {
+        eprintln!("{:?}", error);
+        exit(1);
+    });
+
+    println!("version\t{}", server_description.version);
+    println!("uptime\t{:?}", server_description.uptime);
+    println!("threads\t{}", server_description.number_of_threads);
+    println!("features\t{}", server_description.features.join(","));
+    for (name, value) in &server_description.config {
+        println!("{}\t{}", name, value);
+    }
+    for database in &server_description.databases {
+        let partition_threads: Vec<_> = database
+            .partition_threads
+            .iter()
+            .map(|thread| thread.to_string())
+            .collect();
+        println!("database\t{}", database.name);
+        println!("  data_dir\t{}", database.data_dir.root);
+        println!("  sstables\t{}", database.data_dir.sstables);
+        println!("  commit_logs\t{}", database.data_dir.commit_logs);
+        println!("  table_schemas\t{}", database.data_dir.table_schemas_file);
+        println!("  partition_threads\t{}", partition_threads.join(","));
+    }
+}
+
+async fn promote(address: &str) {
+    let connection = connect(address).await;
+
+    let promoted = connection.promote().await.unwrap_or_else(|error| {
+        eprintln!("{:?}", error);
+        exit(1);
+    });
+
+    match promoted {
+        true => println!("Standby promoted to leader"),
+        false => println!("Server is not a standby"),
+    }
+}
+
+async fn list_transactions(address: &str) {
+    let connection = connect(address).await;
+
+    let active_transactions = connection
+        .list_transactions()
+        .await
+        .unwrap_or_else(|error| {
+            eprintln!("{:?}", error);
+            exit(1);
+        });
+
+    println!("id\tage\tcoordinator_partition\ttables");
+    for active_transaction in active_transactions {
+        let coordinator_partition = active_transaction
+            .coordinator_partition
+            .map(|partition| partition.to_string())
+            .unwrap_or("-".to_string());
+        println!(
+            "{}\t{:?}\t{}\t{}",
+            active_transaction.id,
+            active_transaction.age,
+            coordinator_partition,
+            active_transaction.tables.join(",")
+        );
+    }
+}
+
+async fn kill_transaction(address: &str, transaction_id: &str) {
+    let transaction_id = transaction_id.parse::<u64>().unwrap_or_else(|_| {
+        eprintln!("Invalid transaction id '{}'", transaction_id);
+        exit(1);
+    });
+    let connection = connect(address).await;
+
+    match connection.kill_transaction(transaction_id).await {
+        Ok(()) => println!("OK"),
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+}
+
+// used around migrations, exports and incidents, reads keep working
+async fn set_read_only(address: &str, table_name: &str, read_only: &str) {
+    let read_only = match read_only {
+        "on" => true,
+        "off" => false,
+        _ => {
+            eprintln!(
+                "Invalid read-only flag '{}', expected 'on' or 'off'",
+                read_only
+            );
+            exit(1);
+        }
+    };
+    let connection = connect(address).await;
+
+    match connection.set_read_only(table_name, read_only).await {
+        Ok(()) => println!("OK"),
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+}
+
+async fn probe(address: &str, readiness: bool) {
+    let addr = parse_address(address);
+    let result = match readiness {
+        true => check_ready(addr).await.map(|_| Vec::new()),
+        false => check_health(addr).await,
+    };
+
+    match result {
+        Ok(degraded) if degraded.is_empty() => println!("OK"),
+        // still alive, restarting would not fix failing disk
+        Ok(degraded) => {
+            println!("DEGRADED");
+            for failure in degraded {
+                println!("{}", failure);
+            }
+        }
+        Err(error) => {
+            eprintln!("{:?}", error);
+            exit(1);
+        }
+    }
+}
+
+fn parse_address(address: &str) -> SocketAddrV4 {
+    SocketAddrV4::from_str(address).unwrap_or_else(|error| {
+        eprintln!("Invalid address: {}", error);
+        exit(1);
+    })
+}
+
+async fn connect(address: &str) -> Connection {
+    let addr = parse_address(address);
+
+    Connection::new(addr).await.unwrap_or_else(|error| {
+        eprintln!("{:?}", error);
+        exit(1);
+    })
+}
@@ -1,5 +1,5 @@
 use client::pool::ConnectionPool;
-use client::Model;
+use client::{register_models, Model};
 use common::value::Value::*;
 use macros::DatabaseModel;
 use protos::util::{
@@ -26,6 +26,11 @@ async fn main() {
         .await
         .unwrap();
 
+    {
+        let connection = connection_pool.acquire().await.unwrap();
+        sync_models(&connection).await.unwrap();
+    }
+
     let mut join_set = JoinSet::new();
     for num in 0..parallelism {
         join_set.spawn(worker(connection_pool.clone(), num, objects_per_future));
@@ -65,6 +70,8 @@ async fn worker(connection_pool: Arc<ConnectionPool>, num: usize, objects_per_fu
     }
 }
 
+register_models!(User);
+
 #[derive(DatabaseModel, Clone, Debug)]
 pub struct User {
     pub hash_key: String,
@@ -1,10 +1,62 @@
-use server::run_listener_threads;
+use server::{
+    parse_cluster_config, parse_database_configs, parse_replication_config, parse_trace_export,
+    run_listener_threads, DEFAULT_COMPACTION_THREADS,
+};
 use std::thread::available_parallelism;
 
 #[monoio::main]
 async fn main() {
     let num_of_threads = available_parallelism().unwrap().get();
-    run_listener_threads(num_of_threads).await;
+    // additional databases as "name=path,name=path", default database is always hosted
+    let mut database_configs =
+        parse_database_configs(&std::env::var("YARD_DATABASES").unwrap_or_default())
+            .unwrap_or_else(|error| panic!("Refusing to start: {}", error));
+    // only read when data directory is created, it has to match the kept count afterwards
+    if let Ok(num_of_partitions) = std::env::var("YARD_PARTITIONS") {
+        let num_of_partitions = num_of_partitions.parse().unwrap_or_else(|_| {
+            panic!(
+                "Refusing to start: invalid number of partitions '{}'",
+                num_of_partitions
+            )
+        });
+        for database_config in database_configs.iter_mut() {
+            database_config.num_of_partitions = Some(num_of_partitions);
+        }
+    }
+    let compaction_threads = std::env::var("YARD_COMPACTION_THREADS")
+        .ok()
+        .and_then(|compaction_threads| compaction_threads.parse().ok())
+        .unwrap_or(DEFAULT_COMPACTION_THREADS);
+    // spans of requests are exported when server is built with opentelemetry feature
+    let trace_export = parse_trace_export(
+        &std::env::var("YARD_TRACE_ENDPOINT").unwrap_or_default(),
+        &std::env::var("YARD_TRACE_SAMPLE_RATIO").unwrap_or_default(),
+    )
+    .unwrap_or_else(|error| panic!("Refusing to start: {}", error));
+    // records are kept in memory for standby servers to pull, standby of "host[:port]" applies
+    // records of that leader and rejects writes until it is promoted
+    let mut replication = parse_replication_config(
+        &std::env::var("YARD_REPLICATION_LOG_MB").unwrap_or_default(),
+        &std::env::var("YARD_STANDBY_OF").unwrap_or_default(),
+        &std::env::var("YARD_STANDBY_READS").unwrap_or_default(),
+    )
+    .unwrap_or_else(|error| panic!("Refusing to start: {}", error));
+    // nodes as "id=host[:port],id=host[:port]", every node is started with the same list and its
+    // own id. Writes go to the elected leader and are acknowledged once a majority of nodes has them
+    replication.cluster = parse_cluster_config(
+        &std::env::var("YARD_CLUSTER").unwrap_or_default(),
+        &std::env::var("YARD_NODE_ID").unwrap_or_default(),
+        &std::env::var("YARD_FOLLOWER_READS").unwrap_or_default(),
+    )
+    .unwrap_or_else(|error| panic!("Refusing to start: {}", error));
+    run_listener_threads(
+        num_of_threads,
+        compaction_threads,
+        database_configs,
+        trace_export,
+        replication,
+    )
+    .await;
 }
 
 // skiplist expected times
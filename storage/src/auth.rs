@@ -0,0 +1,21 @@
+// who performs an operation, passed down to storage hooks and row level checks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuthContext {
+    // none for anonymous connections and for work done by the database itself, e.g. eviction
+    pub user: Option<String>,
+    pub roles: Vec<String>,
+}
+
+impl AuthContext {
+    pub fn anonymous() -> AuthContext {
+        AuthContext::default()
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        self.user.is_none()
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::util::millis_from_epoch;
+use crate::Row;
+use common::value::Value;
+use std::collections::HashMap;
+
+// access times are approximate, row is touched at most once per that period
+static ACCESS_TIME_PRECISION_MILLIS: u128 = 1000;
+
+struct AccessEntry {
+    hash_key: String,
+    sort_key: Value,
+    accessed_at: u128,
+}
+
+// last access time of every row of a cache table, kept only in memory
+#[derive(Default)]
+pub struct AccessTracker {
+    entries: HashMap<String, AccessEntry>,
+}
+
+impl AccessTracker {
+    pub fn touch(&mut self, row: &Row) {
+        self.touch_at(row, millis_from_epoch());
+    }
+
+    pub fn touch_at(&mut self, row: &Row, accessed_at: u128) {
+        match self.entries.get_mut(&row.primary_key) {
+            Some(entry) => {
+                if entry.accessed_at + ACCESS_TIME_PRECISION_MILLIS <= accessed_at {
+                    entry.accessed_at = accessed_at;
+                }
+            }
+            None => {
+                self.entries.insert(
+                    row.primary_key.clone(),
+                    AccessEntry {
+                        hash_key: row.hash_key.clone(),
+                        sort_key: row.sort_key.clone(),
+                        accessed_at,
+                    },
+                );
+            }
+        }
+    }
+
+    pub fn remove(&mut self, primary_key: &str) {
+        self.entries.remove(primary_key);
+    }
+
+    pub fn remove_hash_keys(&mut self, predicate: impl Fn(&str) -> bool) {
+        self.entries.retain(|_, entry| !predicate(&entry.hash_key));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // returns hash keys and sort keys of removed rows
+    pub fn pop_least_recently_used(&mut self, count: usize) -> Vec<(String, Value)> {
+        let mut entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(primary_key, entry)| (entry.accessed_at, primary_key.clone()))
+            .collect();
+        entries.sort();
+
+        entries
+            .into_iter()
+            .take(count)
+            .map(|(_, primary_key)| {
+                let entry = self.entries.remove(&primary_key).unwrap();
+                (entry.hash_key, entry.sort_key)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::value::Value::Int32;
+
+    fn get_row(sort_key: i32) -> Row {
+        Row::new("hash".to_string(), Int32(sort_key), HashMap::new())
+    }
+
+    #[test]
+    fn least_recently_used_rows_are_popped_first() {
+        let mut access_tracker = AccessTracker::default();
+        access_tracker.touch_at(&get_row(1), 1000);
+        access_tracker.touch_at(&get_row(2), 2000);
+        access_tracker.touch_at(&get_row(3), 3000);
+
+        access_tracker.touch_at(&get_row(1), 5000);
+        // within precision window, access time is not updated
+        access_tracker.touch_at(&get_row(2), 2500);
+
+        let evicted = access_tracker.pop_least_recently_used(2);
+        assert_eq!(
+            evicted,
+            vec![
+                ("hash".to_string(), Int32(2)),
+                ("hash".to_string(), Int32(3))
+            ]
+        );
+        assert_eq!(access_tracker.len(), 1);
+    }
+}
@@ -1,18 +1,27 @@
-use crate::sstable::flush_memtable_to_sstable;
+use crate::data_dir::DataDir;
+use crate::io::{remove_file, File, OpenOptions};
+use crate::lease::Lease;
+use crate::range_tombstone::{decode_range_tombstones, encode_range_tombstones, RangeTombstone};
+use crate::replication::record_appended;
+use crate::row::encode_legacy_primary_key;
+use crate::sstable::write_memtable_to_sstable;
 use crate::table::TableSchema;
-use crate::util::{decode_row, encode_row, millis_from_epoch};
+use crate::util::{
+    decode_legacy_row, decode_row, encode_row, millis_from_epoch, parse_table_file_name,
+};
 use crate::{Memtable, Row, MEGABYTE};
-use futures::lock::Mutex;
-use monoio::fs::{File, OpenOptions};
-use monoio::time::sleep;
-use rand::Rng;
-use std::collections::HashSet;
+use common::partition::PartitionMap;
+use rand::seq::IteratorRandom;
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
 use std::fs::read_dir;
+use std::io::{BufRead, BufReader, Read};
 use std::mem::size_of;
-use std::sync::Arc;
-use std::time::Duration;
+use std::path::Path;
 
-static COMMIT_LOG_SEGMENTS_FILE_PATH: &str = "/var/lib/yard/commit_logs";
+pub(crate) static COMMIT_LOG_SEGMENTS_FILE_PATH: &str = "/var/lib/yard/commit_logs";
+static REPLAY_CHUNK_SIZE: usize = 24 * MEGABYTE;
 
 pub struct CommitLog {
     pub(crate) file: Option<File>,
@@ -21,20 +30,46 @@ pub struct CommitLog {
     pub(crate) table_schema: TableSchema,
     pub partition: usize,
     pub closed: bool,
+    pub(crate) partial_flushes: PartialFlushes,
+}
+
+// rows of a partial flush stay in their segment, which is deleted only after its partial flushes
+// finished, and kept for replay if any of them failed
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct PartialFlushes {
+    pub(crate) pending: usize,
+    pub(crate) failed: bool,
+}
+
+// rows logged before the offset with primary keys up to the last flushed one are in sstable of a
+// partial flush, so that replay leaves them out
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlushWatermark {
+    pub offset: u64,
+    pub last_primary_key: String,
 }
 
 impl CommitLog {
-    pub async fn open_new(table_schema: &TableSchema, partitions: &HashSet<usize>) -> CommitLog {
+    pub async fn open_new(
+        table_schema: &TableSchema,
+        partitions: &HashSet<usize>,
+        commit_log_dir: &str,
+    ) -> CommitLog {
         let mut rng = rand::thread_rng();
-        let partition = rng.gen_range(0usize..partitions.iter().max().unwrap().clone());
-
-        let file_path = format!(
-            "{}/{}-{}-{}",
-            COMMIT_LOG_SEGMENTS_FILE_PATH,
-            table_schema.name,
-            partition,
-            millis_from_epoch()
-        );
+        let partition = *partitions.iter().choose(&mut rng).unwrap();
+
+        // log rotated within the same millisecond it was opened in must not reuse its file
+        let mut timestamp = millis_from_epoch();
+        let get_file_path = |timestamp: u128| {
+            format!(
+                "{}/{}-{}-{}",
+                commit_log_dir, table_schema.name, partition, timestamp
+            )
+        };
+        while Path::new(&get_file_path(timestamp)).exists() {
+            timestamp += 1;
+        }
+        let file_path = get_file_path(timestamp);
 
         let file = OpenOptions::new()
             .create(true)
@@ -43,48 +78,82 @@ impl CommitLog {
             .await
             .unwrap();
 
+        let operation_bytes = schema_version_header(table_schema);
+        let file_offset = operation_bytes.len() as u64;
+        file.write_all_at(operation_bytes, 0).await.0.unwrap();
+
         CommitLog {
             file: Some(file),
             file_path,
-            file_offset: 0,
+            file_offset,
             table_schema: table_schema.clone(),
             partition,
             closed: false,
+            partial_flushes: PartialFlushes::default(),
         }
     }
 
     pub async fn write_insert(&mut self, row: &Row) {
+        self.write_inserts(&[row]).await;
+    }
+
+    // rows are logged with a single write
+    pub async fn write_inserts<R: Borrow<Row>>(&mut self, rows: &[R]) {
         let mut operation_bytes = Vec::new();
-        operation_bytes.push(1u8);
+        for row in rows {
+            let row_bytes = encode_row(row.borrow(), &self.table_schema);
+            frame_record(1, &row_bytes, &mut operation_bytes);
+        }
+        self.append(operation_bytes).await;
+    }
 
-        let mut row_bytes = encode_row(row, &self.table_schema);
-        operation_bytes.append(&mut row_bytes);
-        operation_bytes.push(b'\n');
+    pub async fn write_delete(&mut self, primary_key: &str) {
+        let mut delete_bytes = millis_from_epoch().to_be_bytes().to_vec();
+        delete_bytes.extend_from_slice(primary_key.as_bytes());
+        self.write_record(2, &delete_bytes).await;
+    }
 
-        let bytes_len = operation_bytes.len() as u64;
-        self.file
-            .as_ref()
-            .unwrap()
-            .write_all_at(operation_bytes, self.file_offset)
-            .await
-            .0
-            .unwrap();
-        self.file_offset += bytes_len;
+    pub async fn write_sequence(&mut self, hash_key: &str, sequence: u64) {
+        let sequence_string = format!("{}:{}", sequence, hash_key);
+        self.write_record(3, sequence_string.as_bytes()).await;
     }
 
-    pub async fn write_delete(&mut self, primary_key: &str) {
-        let mut operation_bytes = Vec::new();
-        operation_bytes.push(2u8);
+    pub async fn write_lease(&mut self, name: &str, lease: &Lease) {
+        self.write_record(4, lease.to_log_string(name).as_bytes())
+            .await;
+    }
+
+    pub async fn write_row_count(&mut self, hash_key: &str, count: u64) {
+        let count_string = format!("{}:{}", count, hash_key);
+        self.write_record(5, count_string.as_bytes()).await;
+    }
 
-        let mut timestamp_bytes = millis_from_epoch().to_be_bytes().to_vec();
-        operation_bytes.append(&mut timestamp_bytes);
+    pub async fn write_range_tombstone(&mut self, range_tombstone: &RangeTombstone) {
+        let range_tombstone_bytes = encode_range_tombstones(std::slice::from_ref(range_tombstone));
+        self.write_record(10, &range_tombstone_bytes).await;
+    }
 
-        let mut primary_key_bytes = primary_key.as_bytes().to_vec();
-        operation_bytes.append(&mut primary_key_bytes);
+    pub async fn write_flush_watermark(&mut self, watermark: &FlushWatermark) {
+        let mut watermark_bytes = watermark.offset.to_be_bytes().to_vec();
+        watermark_bytes.extend_from_slice(watermark.last_primary_key.as_bytes());
+        self.write_record(9, &watermark_bytes).await;
+    }
 
-        operation_bytes.push(b'\n');
+    // record bytes can be anything, as they are framed with their length
+    async fn write_record(&mut self, operation: u8, record_bytes: &[u8]) {
+        let mut operation_bytes = Vec::with_capacity(record_bytes.len() + 5);
+        frame_record(operation, record_bytes, &mut operation_bytes);
+        self.append(operation_bytes).await;
+    }
 
+    // records pulled from commit logs of the leader, already framed
+    pub(crate) async fn write_replicated(&mut self, operation_bytes: Vec<u8>) {
+        self.append(operation_bytes).await;
+    }
+
+    async fn append(&mut self, operation_bytes: Vec<u8>) {
         let bytes_len = operation_bytes.len() as u64;
+        record_appended(&self.file_path, &self.table_schema.name, &operation_bytes);
         self.file
             .as_ref()
             .unwrap()
@@ -95,27 +164,117 @@ impl CommitLog {
         self.file_offset += bytes_len;
     }
 
-    pub async fn sync(&self) {
-        self.file.as_ref().unwrap().sync_all().await.unwrap();
+    // segments are named after the time they were opened, so later segments come after earlier ones
+    pub fn position(&self) -> (u128, u64) {
+        let segment = self
+            .file_path
+            .rsplit('-')
+            .next()
+            .and_then(|segment| segment.parse().ok())
+            .unwrap_or(0);
+        (segment, self.file_offset)
     }
 
-    pub async fn delete(&mut self) {
-        let file = self.file.take().unwrap();
-        file.close().await.unwrap();
+    pub async fn sync(&self) -> Result<(), String> {
+        self.file
+            .as_ref()
+            .unwrap()
+            .sync_all()
+            .await
+            .map_err(|e| format!("Failed to sync commit log '{}': {}", self.file_path, e))
+    }
+
+    // can be retried, file is closed only once
+    pub async fn delete(&mut self) -> Result<(), String> {
+        if let Some(file) = self.file.take() {
+            file.close()
+                .await
+                .map_err(|e| format!("Failed to close commit log '{}': {}", self.file_path, e))?;
+        }
 
         // ugly, blocking, monoio does not seem to provide a way to do it non-blocking
-        std::fs::remove_file(&self.file_path).unwrap();
+        remove_file(&self.file_path)
+            .map_err(|e| format!("Failed to remove commit log '{}': {}", self.file_path, e))
     }
 }
 
-pub async fn periodically_sync_commit_log(commit_log: Arc<Mutex<CommitLog>>, interval: Duration) {
-    loop {
-        sleep(interval).await;
-        let commit_log = commit_log.lock().await;
-        if !commit_log.closed {
-            commit_log.sync().await;
-        } else {
-            break;
+// position of a write in commit logs of the thread owning its partition, clients attach it to
+// later reads to require state at least that fresh
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConsistencyToken {
+    pub partition: usize,
+    pub segment: u128,
+    pub offset: u64,
+}
+
+impl ConsistencyToken {
+    pub fn parse(token: &str) -> Result<ConsistencyToken, String> {
+        let invalid_token = || format!("Invalid consistency token '{}'", token);
+        let mut parts = token.split(':');
+        let mut next_part = || parts.next().ok_or_else(invalid_token);
+
+        let consistency_token = ConsistencyToken {
+            partition: next_part()?.parse().map_err(|_| invalid_token())?,
+            segment: next_part()?.parse().map_err(|_| invalid_token())?,
+            offset: next_part()?.parse().map_err(|_| invalid_token())?,
+        };
+        match parts.next() {
+            Some(_) => Err(invalid_token()),
+            None => Ok(consistency_token),
+        }
+    }
+
+    pub fn is_reached_by(&self, (segment, offset): (u128, u64)) -> bool {
+        (segment, offset) >= (self.segment, self.offset)
+    }
+}
+
+impl Display for ConsistencyToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.partition, self.segment, self.offset)
+    }
+}
+
+// state that is not stored in sstables and has to be recovered from commit logs
+#[derive(Debug, Default)]
+pub struct ReplayedState {
+    pub sequences: HashMap<String, u64>,
+    pub leases: HashMap<String, Lease>,
+    pub row_counts: HashMap<String, u64>,
+}
+
+impl ReplayedState {
+    // record of a sequence, lease or row count, others are left out
+    pub(crate) fn apply_record(&mut self, operation_bytes: &[u8]) {
+        let record_string = || String::from_utf8(operation_bytes[1..].to_vec()).unwrap();
+        match operation_bytes[0] {
+            3 => {
+                let sequence_string = record_string();
+                let (sequence, hash_key) = sequence_string.split_once(':').unwrap();
+                let sequence = sequence.parse::<u64>().unwrap();
+
+                let current_sequence = self.sequences.entry(hash_key.to_string()).or_insert(0);
+                *current_sequence = sequence.max(*current_sequence);
+            }
+            4 => {
+                let (name, lease) = Lease::from_log_string(&record_string()).unwrap();
+
+                match self.leases.get(&name) {
+                    Some(current_lease) if current_lease.version > lease.version => {}
+                    _ => {
+                        self.leases.insert(name, lease);
+                    }
+                }
+            }
+            5 => {
+                // counts go both ways, so the latest record wins
+                let count_string = record_string();
+                let (count, hash_key) = count_string.split_once(':').unwrap();
+                let count = count.parse::<u64>().unwrap();
+
+                self.row_counts.insert(hash_key.to_string(), count);
+            }
+            _ => {}
         }
     }
 }
@@ -123,24 +282,64 @@ pub async fn periodically_sync_commit_log(commit_log: Arc<Mutex<CommitLog>>, int
 pub async fn replay_commit_logs(
     table_schema: &TableSchema,
     partitions: &HashSet<usize>,
-    total_number_of_partitions: usize,
-) {
-    let commit_logs = open_for_startup(table_schema, partitions).await;
-    let mut buffer = Vec::with_capacity(24 * MEGABYTE);
-
-    for mut commit_log in commit_logs {
-        let (result, mut new_buffer) = commit_log.file.as_ref().unwrap().read_at(buffer, 0).await;
-
-        let bytes_read = result.unwrap();
-        commit_log.file_offset = bytes_read as u64;
+    partition_map: &PartitionMap,
+    data_dir: &DataDir,
+) -> ReplayedState {
+    replay_commit_logs_from(
+        &data_dir.commit_logs,
+        &data_dir.sstables,
+        table_schema,
+        partitions,
+        partition_map,
+    )
+    .await
+}
 
-        let byte_operations = split_by_newline(&new_buffer);
+// every log is flushed and deleted before the next one is replayed, so that a crash during replay
+// leaves remaining logs to be replayed again
+async fn replay_commit_logs_from(
+    commit_log_dir: &str,
+    sstable_dir: &str,
+    table_schema: &TableSchema,
+    partitions: &HashSet<usize>,
+    partition_map: &PartitionMap,
+) -> ReplayedState {
+    let commit_logs = open_for_startup(
+        commit_log_dir,
+        table_schema,
+        partitions,
+        partition_map.num_of_partitions,
+    )
+    .await;
+    let mut chunk = Vec::with_capacity(REPLAY_CHUNK_SIZE);
+    let mut state = ReplayedState::default();
+
+    for (mut commit_log, log_timestamp) in commit_logs {
+        let (log_bytes, read_chunk) =
+            read_commit_log(commit_log.file.as_ref().unwrap(), chunk).await;
+        chunk = read_chunk;
+        commit_log.file_offset = log_bytes.len() as u64;
+
+        let byte_operations = split_records(&log_bytes);
+        let watermarks = flush_watermarks(&byte_operations);
+        let flushed = |position: u64, primary_key: &str| {
+            watermarks.iter().any(|watermark| {
+                position < watermark.offset && primary_key <= watermark.last_primary_key.as_str()
+            })
+        };
         let mut memtable = Memtable::default();
-        for operation_bytes in byte_operations {
+        // segments without schema version have legacy rows as well
+        let mut legacy_rows = true;
+        for (position, operation_bytes) in byte_operations {
             match operation_bytes[0] {
                 1 => {
-                    let row = decode_row(&operation_bytes[1..], table_schema);
-                    memtable.insert(row, true);
+                    let row = match legacy_rows {
+                        true => decode_legacy_row(&operation_bytes[1..], table_schema),
+                        false => decode_row(&operation_bytes[1..], table_schema),
+                    };
+                    if !flushed(position, &row.primary_key) {
+                        memtable.insert(row, true);
+                    }
                 }
                 2 => {
                     let timestamp_size = size_of::<u128>();
@@ -152,58 +351,128 @@ pub async fn replay_commit_logs(
                     );
                     let primary_key =
                         String::from_utf8(operation_bytes[1 + timestamp_size..].to_vec()).unwrap();
-                    memtable.delete(&primary_key, Some(timestamp));
+                    // legacy segments predate encoded sort keys too, so their tombstones would
+                    // not match rows of the same key otherwise
+                    let primary_key = match legacy_rows {
+                        true => {
+                            encode_legacy_primary_key(&primary_key, &table_schema.sort_key_type)
+                                .unwrap_or(primary_key)
+                        }
+                        false => primary_key,
+                    };
+                    if !flushed(position, &primary_key) {
+                        memtable.delete(&primary_key, Some(timestamp));
+                    }
+                }
+                3..=5 => state.apply_record(&operation_bytes),
+                // schema version, checked at startup before replay
+                6 => legacy_rows = true,
+                7 | 8 => legacy_rows = false,
+                // read up front
+                9 => {}
+                // range tombstones stay in memtable after partial flushes, so they are always
+                // replayed, rows already flushed are shadowed in sstables
+                10 => {
+                    let range_tombstones = decode_range_tombstones(&operation_bytes[1..]).unwrap();
+                    for range_tombstone in range_tombstones {
+                        memtable.delete_range(range_tombstone);
+                    }
                 }
                 _ => panic!("Invalid operation code"),
             }
         }
 
-        monoio::spawn(flush_memtable_to_sstable(
-            memtable,
-            Arc::new(Mutex::new(commit_log)),
-            table_schema.clone(),
-            total_number_of_partitions,
-        ));
+        // sstable is named after the log, so that replaying it again overwrites the same file
+        // instead of shadowing sstables flushed after the log was written. Rows logged after the
+        // log was opened are newer than that, sstable is named after the newest of them then,
+        // as rows are never newer than their sstable
+        let sstable_timestamp = memtable
+            .iter()
+            .map(|row| row.timestamp)
+            .chain(
+                memtable
+                    .range_tombstones()
+                    .iter()
+                    .map(|range_tombstone| range_tombstone.timestamp),
+            )
+            .fold(log_timestamp, u128::max);
+        write_memtable_to_sstable(
+            &memtable,
+            table_schema,
+            partition_map,
+            sstable_timestamp,
+            sstable_dir,
+        )
+        .await
+        .unwrap_or_else(|error| {
+            panic!(
+                "Failed to flush replayed commit log '{}': {}",
+                commit_log.file_path, error
+            )
+        });
+        commit_log.delete().await.unwrap();
+    }
+
+    state
+}
 
-        new_buffer.clear();
-        buffer = new_buffer;
+// partially flushed logs outgrow any single read, so they are read chunk by chunk up to their end,
+// chunk is handed back to be reused by the next log
+async fn read_commit_log(file: &File, mut chunk: Vec<u8>) -> (Vec<u8>, Vec<u8>) {
+    let mut log_bytes = Vec::new();
+    loop {
+        let (result, read_chunk) = file.read_at(chunk, log_bytes.len() as u64).await;
+        chunk = read_chunk;
+        let bytes_read = result.unwrap();
+        if bytes_read == 0 {
+            return (log_bytes, chunk);
+        }
+        log_bytes.extend_from_slice(&chunk[..bytes_read]);
     }
 }
 
 async fn open_for_startup(
+    commit_log_dir: &str,
     table_schema: &TableSchema,
     partitions: &HashSet<usize>,
-) -> Vec<CommitLog> {
+    num_of_partitions: usize,
+) -> Vec<(CommitLog, u128)> {
     let mut commit_logs = Vec::new();
+    // logs written before partition count was kept can be tagged with partitions past the count
     let mut commit_log_files =
-        get_commit_logs_filenames_with_metadata(&table_schema.name, partitions);
+        get_commit_logs_filenames_with_metadata(commit_log_dir, &table_schema.name, |partition| {
+            partitions.contains(&(partition % num_of_partitions))
+        });
     commit_log_files.sort_by(|(_, _, timestamp1), (_, _, timestamp2)| timestamp1.cmp(timestamp2));
 
-    for (filename, file_partition, _) in commit_log_files {
-        let file_path = format!("{}/{}", COMMIT_LOG_SEGMENTS_FILE_PATH, filename);
+    for (filename, file_partition, file_timestamp) in commit_log_files {
+        let file_path = format!("{}/{}", commit_log_dir, filename);
         let file = OpenOptions::new()
             .read(true)
             .open(&file_path)
             .await
             .unwrap();
-        commit_logs.push(CommitLog {
+        let commit_log = CommitLog {
             file: Some(file),
             file_path,
             file_offset: 0,
             partition: file_partition,
             table_schema: table_schema.clone(),
             closed: false,
-        });
+            partial_flushes: PartialFlushes::default(),
+        };
+        commit_logs.push((commit_log, file_timestamp));
     }
 
     commit_logs
 }
 
 fn get_commit_logs_filenames_with_metadata(
+    commit_log_dir: &str,
     table_name: &str,
-    partitions: &HashSet<usize>,
+    partition_filter: impl Fn(usize) -> bool,
 ) -> Vec<(String, usize, u128)> {
-    read_dir(COMMIT_LOG_SEGMENTS_FILE_PATH)
+    read_dir(commit_log_dir)
         .unwrap()
         .filter_map(|commit_log_path| {
             let file_name = commit_log_path
@@ -212,20 +481,129 @@ fn get_commit_logs_filenames_with_metadata(
                 .to_str()
                 .unwrap()
                 .to_string();
-            let split_result: Vec<_> = file_name.split("-").collect();
-            let file_table_name = split_result[0];
-            let file_partition = split_result[1].parse::<usize>().unwrap();
-            let file_timestamp = split_result[2].parse::<u128>().unwrap();
+            let (file_table_name, numbers) = parse_table_file_name(&file_name)?;
+            let [file_partition, file_timestamp] = numbers[..] else {
+                return None;
+            };
 
-            if table_name == file_table_name && partitions.contains(&file_partition) {
-                return Some((file_name, file_partition, file_timestamp));
+            if table_name == file_table_name && partition_filter(file_partition as usize) {
+                return Some((file_name, file_partition as usize, file_timestamp));
             }
             None
         })
         .collect()
 }
 
-fn split_by_newline(data: &Vec<u8>) -> Vec<Vec<u8>> {
+// segment starts with version of schema its rows are encoded with, code 6 was used
+// before hash keys were length prefixed and code 7 before records were
+fn schema_version_header(table_schema: &TableSchema) -> Vec<u8> {
+    let mut operation_bytes = vec![8u8];
+    operation_bytes.extend(table_schema.schema_version().to_string().into_bytes());
+    operation_bytes.push(b'\n');
+    operation_bytes
+}
+
+// None for segments written before schema versioning
+fn read_commit_log_schema_version(file_path: &str) -> Option<u64> {
+    let mut first_line = String::new();
+    let file = std::fs::File::open(file_path).ok()?;
+    BufReader::new(file.take(32))
+        .read_line(&mut first_line)
+        .ok()?;
+
+    first_line
+        .strip_prefix(['\u{6}', '\u{7}', '\u{8}'])
+        .and_then(|version| version.trim_end().parse().ok())
+}
+
+pub fn check_commit_logs_schema_version(
+    table_schema: &TableSchema,
+    commit_log_dir: &str,
+) -> Result<(), String> {
+    // segments of every partition, so that thread count change does not hide any
+    for (filename, _, _) in
+        get_commit_logs_filenames_with_metadata(commit_log_dir, &table_schema.name, |_| true)
+    {
+        let file_path = format!("{}/{}", commit_log_dir, filename);
+        if let Some(schema_version) = read_commit_log_schema_version(&file_path) {
+            if schema_version != table_schema.schema_version() {
+                return Err(format!(
+                    "Commit log '{}' was written with schema version {}, table '{}' is at version {}",
+                    file_path,
+                    schema_version,
+                    table_schema.name,
+                    table_schema.schema_version()
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// record is prefixed with its length, so that its bytes, e.g. of hash key length or values,
+// can be anything, newline included
+pub(crate) fn frame_record(operation: u8, record_bytes: &[u8], operation_bytes: &mut Vec<u8>) {
+    operation_bytes.extend_from_slice(&(record_bytes.len() as u32 + 1).to_be_bytes());
+    operation_bytes.push(operation);
+    operation_bytes.extend_from_slice(record_bytes);
+}
+
+// records along with their offsets in segment. Header of segment is a line of text, records after
+// it are length prefixed unless segment was written before they were, record cut off by crash in
+// the middle of its write is left out
+fn split_records(data: &[u8]) -> Vec<(u64, Vec<u8>)> {
+    if data.first() != Some(&8) {
+        return split_by_newline(data)
+            .into_iter()
+            .map(|record| (0, record))
+            .collect();
+    }
+    let header_end = data
+        .iter()
+        .position(|byte| *byte == b'\n')
+        .unwrap_or(data.len());
+    let mut records = vec![(0, data[..header_end].to_vec())];
+
+    let (framed_records, _) = split_framed_records(data, header_end + 1);
+    records.extend(framed_records);
+    records
+}
+
+// length prefixed records from offset on along with their offsets, and offset past the last one
+// that is whole
+pub(crate) fn split_framed_records(data: &[u8], mut offset: usize) -> (Vec<(u64, Vec<u8>)>, usize) {
+    let mut records = Vec::new();
+    let length_size = size_of::<u32>();
+    while offset + length_size <= data.len() {
+        let length = u32::from_be_bytes(data[offset..offset + length_size].try_into().unwrap());
+        let record_offset = offset;
+        if length == 0 || offset + length_size + length as usize > data.len() {
+            break;
+        }
+        offset += length_size;
+        records.push((
+            record_offset as u64,
+            data[offset..offset + length as usize].to_vec(),
+        ));
+        offset += length as usize;
+    }
+    (records, offset)
+}
+
+fn flush_watermarks(records: &[(u64, Vec<u8>)]) -> Vec<FlushWatermark> {
+    let offset_size = size_of::<u64>();
+    records
+        .iter()
+        .filter(|(_, record)| record[0] == 9)
+        .map(|(_, record)| FlushWatermark {
+            offset: u64::from_be_bytes(record[1..1 + offset_size].try_into().unwrap()),
+            last_primary_key: String::from_utf8(record[1 + offset_size..].to_vec()).unwrap(),
+        })
+        .collect()
+}
+
+fn split_by_newline(data: &[u8]) -> Vec<Vec<u8>> {
     let newline_byte = b'\n';
     let mut result = Vec::new();
     let mut current_line = Vec::new();
@@ -247,3 +625,317 @@ fn split_by_newline(data: &Vec<u8>) -> Vec<Vec<u8>> {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sstable::{get_sstables_metadata, read_sstable_range_tombstones, read_sstable_rows};
+    use crate::table::ColumnType;
+    use crate::HASH_KEY_BYTE_SIZE;
+    use common::value::Value::{Int32, Varchar};
+    use std::io::Write;
+    use std::ops::Bound;
+
+    async fn open_test_commit_log(file_path: &str, table_schema: &TableSchema) -> CommitLog {
+        let header = schema_version_header(table_schema);
+        std::fs::write(file_path, &header).unwrap();
+        CommitLog {
+            file: Some(
+                OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .open(file_path)
+                    .await
+                    .unwrap(),
+            ),
+            file_path: file_path.to_string(),
+            file_offset: header.len() as u64,
+            table_schema: table_schema.clone(),
+            partition: 0,
+            closed: false,
+            partial_flushes: PartialFlushes::default(),
+        }
+    }
+
+    #[test]
+    fn consistency_token_is_parsed_back() {
+        let token = ConsistencyToken {
+            partition: 7,
+            segment: 1_700_000_000_000,
+            offset: 42,
+        };
+        assert_eq!(ConsistencyToken::parse(&token.to_string()), Ok(token));
+        assert!(token.is_reached_by((1_700_000_000_000, 42)));
+        assert!(token.is_reached_by((1_700_000_000_001, 0)));
+        assert!(!token.is_reached_by((1_700_000_000_000, 41)));
+
+        for invalid in ["", "7:1", "7:1:2:3", "7:x:2"] {
+            assert!(ConsistencyToken::parse(invalid).is_err());
+        }
+    }
+
+    #[monoio::test]
+    async fn replay_can_be_repeated_after_crash() {
+        let commit_log_dir = "/tmp/replay_can_be_repeated_after_crash/commit_logs";
+        let sstable_dir = "/tmp/replay_can_be_repeated_after_crash/sstables";
+        let _ = std::fs::remove_dir_all("/tmp/replay_can_be_repeated_after_crash");
+        std::fs::create_dir_all(commit_log_dir).unwrap();
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let file_path = format!("{}/table-0-1000", commit_log_dir);
+        let mut commit_log = open_test_commit_log(&file_path, &table_schema).await;
+        let row = Row::new_with_timestamp("a".to_string(), Int32(-7), HashMap::new(), 1);
+        commit_log.write_insert(&row).await;
+        commit_log.sync().await.unwrap();
+        let log_bytes = std::fs::read(&file_path).unwrap();
+
+        let partitions = HashSet::from([0]);
+        let partition_map = PartitionMap::new(1);
+        replay_commit_logs_from(
+            commit_log_dir,
+            sstable_dir,
+            &table_schema,
+            &partitions,
+            &partition_map,
+        )
+        .await;
+        assert!(std::fs::read_dir(commit_log_dir).unwrap().next().is_none());
+
+        // process crashed after flush, before log was deleted
+        std::fs::write(&file_path, log_bytes).unwrap();
+        replay_commit_logs_from(
+            commit_log_dir,
+            sstable_dir,
+            &table_schema,
+            &partitions,
+            &partition_map,
+        )
+        .await;
+        assert!(std::fs::read_dir(commit_log_dir).unwrap().next().is_none());
+
+        let sstables_metadata = get_sstables_metadata("table", sstable_dir);
+        assert_eq!(sstables_metadata.len(), 1);
+        assert_eq!(sstables_metadata[0].number_of_rows, 1);
+        assert_eq!(sstables_metadata[0].timestamp, 1000);
+
+        let rows = read_sstable_rows(&sstables_metadata[0], &table_schema).await;
+        assert_eq!(rows[0].primary_key, row.primary_key);
+    }
+
+    #[monoio::test]
+    async fn logs_larger_than_a_read_chunk_are_replayed_whole() {
+        let root = "/tmp/logs_larger_than_a_read_chunk_are_replayed_whole";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let table_schema =
+            TableSchema::from_string("table>sort_key:INT32;text:VARCHAR(1000)").unwrap();
+        let file_path = format!("{}/table-0-1000", data_dir.commit_logs);
+        let mut commit_log = open_test_commit_log(&file_path, &table_schema).await;
+        let values = HashMap::from([("text".to_string(), Varchar("x".repeat(1000)))]);
+        let mut number_of_rows = 0;
+        while commit_log.file_offset <= REPLAY_CHUNK_SIZE as u64 + MEGABYTE as u64 {
+            let rows: Vec<_> = (0..1000)
+                .map(|_| {
+                    number_of_rows += 1;
+                    let hash_key = format!("user_{}", number_of_rows);
+                    Row::new_with_timestamp(hash_key, Int32(-1), values.clone(), 1)
+                })
+                .collect();
+            commit_log.write_inserts(&rows).await;
+        }
+        commit_log.write_sequence("order", 10).await;
+        commit_log.sync().await.unwrap();
+
+        let state = replay_commit_logs(
+            &table_schema,
+            &HashSet::from([0]),
+            &PartitionMap::new(1),
+            &data_dir,
+        )
+        .await;
+        assert_eq!(state.sequences.get("order"), Some(&10));
+        let sstables_metadata = get_sstables_metadata("table", &data_dir.sstables);
+        assert_eq!(sstables_metadata[0].number_of_rows, number_of_rows);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn records_containing_newline_bytes_are_replayed() {
+        let root = "/tmp/records_containing_newline_bytes_are_replayed";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let table_schema = TableSchema::from_string("table>sort_key:INT32;count:INT32").unwrap();
+        let file_path = format!("{}/table-0-1000", data_dir.commit_logs);
+        let mut commit_log = open_test_commit_log(&file_path, &table_schema).await;
+        // length of hash key and last byte of the value are both 0x0A
+        let values = HashMap::from([("count".to_string(), Int32(-246))]);
+        let get_row = |sort_key: i32| {
+            Row::new_with_timestamp("user_12345".to_string(), Int32(sort_key), values.clone(), 1)
+        };
+        commit_log.write_inserts(&[get_row(-1), get_row(-2)]).await;
+        commit_log.write_delete(&get_row(-2).primary_key).await;
+        commit_log.write_sequence("order\n1", 10).await;
+        commit_log.sync().await.unwrap();
+        // record cut off by crash in the middle of its write
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&file_path)
+            .unwrap();
+        file.write_all(&[0, 0, 0, 50, 1, 0]).unwrap();
+
+        let state = replay_commit_logs(
+            &table_schema,
+            &HashSet::from([0]),
+            &PartitionMap::new(1),
+            &data_dir,
+        )
+        .await;
+        assert_eq!(state.sequences.get("order\n1"), Some(&10));
+
+        let sstables_metadata = get_sstables_metadata("table", &data_dir.sstables);
+        let rows = read_sstable_rows(&sstables_metadata[0], &table_schema).await;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].hash_key, "user_12345");
+        assert_eq!(rows[0].sort_key, Int32(-2));
+        assert!(rows[0].marked_for_deletion);
+        assert_eq!(rows[1].sort_key, Int32(-1));
+        assert_eq!(rows[1].values, values);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn rows_below_flush_watermark_are_not_replayed() {
+        let root = "/tmp/rows_below_flush_watermark_are_not_replayed";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let file_path = format!("{}/table-0-1000", data_dir.commit_logs);
+        let mut commit_log = open_test_commit_log(&file_path, &table_schema).await;
+        let get_row = |hash_key: &str, timestamp: u128| {
+            Row::new_with_timestamp(hash_key.to_string(), Int32(-7), HashMap::new(), timestamp)
+        };
+        commit_log
+            .write_inserts(&[get_row("a", 1001), get_row("b", 1001), get_row("c", 1001)])
+            .await;
+        // "a" and "b" were flushed to sstable, "a" got updated afterwards
+        let watermark = FlushWatermark {
+            offset: commit_log.file_offset,
+            last_primary_key: get_row("b", 1001).primary_key,
+        };
+        commit_log.write_insert(&get_row("a", 1002)).await;
+        commit_log.write_flush_watermark(&watermark).await;
+        commit_log.sync().await.unwrap();
+
+        replay_commit_logs(
+            &table_schema,
+            &HashSet::from([0]),
+            &PartitionMap::new(1),
+            &data_dir,
+        )
+        .await;
+
+        let sstables_metadata = get_sstables_metadata("table", &data_dir.sstables);
+        assert_eq!(sstables_metadata.len(), 1);
+        assert_eq!(sstables_metadata[0].timestamp, 1002);
+        let rows = read_sstable_rows(&sstables_metadata[0], &table_schema).await;
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].hash_key.as_str(), rows[0].timestamp), ("a", 1002));
+        assert_eq!((rows[1].hash_key.as_str(), rows[1].timestamp), ("c", 1001));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn range_tombstones_are_replayed_in_order_with_rows() {
+        let root = "/tmp/range_tombstones_are_replayed_in_order_with_rows";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let get_row = |sort_key: i32, timestamp: u128| {
+            Row::new_with_timestamp("a".to_string(), Int32(sort_key), HashMap::new(), timestamp)
+        };
+        let range_tombstone = RangeTombstone {
+            hash_key: "a".to_string(),
+            start: Bound::Included("a:".to_string()),
+            end: Bound::Excluded("a;".to_string()),
+            timestamp: 1003,
+        };
+        let file_path = format!("{}/table-0-1000", data_dir.commit_logs);
+        let mut commit_log = open_test_commit_log(&file_path, &table_schema).await;
+        commit_log
+            .write_inserts(&[get_row(1, 1003), get_row(2, 1001)])
+            .await;
+        commit_log.write_range_tombstone(&range_tombstone).await;
+        commit_log.write_insert(&get_row(2, 1004)).await;
+        commit_log.sync().await.unwrap();
+
+        replay_commit_logs(
+            &table_schema,
+            &HashSet::from([0]),
+            &PartitionMap::new(1),
+            &data_dir,
+        )
+        .await;
+
+        let sstables_metadata = get_sstables_metadata("table", &data_dir.sstables);
+        assert_eq!(sstables_metadata.len(), 1);
+        assert_eq!(sstables_metadata[0].timestamp, 1004);
+        let rows = read_sstable_rows(&sstables_metadata[0], &table_schema).await;
+        assert_eq!(rows.len(), 2);
+        // row of the same millisecond logged before range tombstone is deleted too
+        assert!(rows[0].marked_for_deletion);
+        assert!(!rows[1].marked_for_deletion);
+        assert_eq!(
+            read_sstable_range_tombstones(&sstables_metadata[0], &table_schema).await,
+            vec![range_tombstone]
+        );
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn legacy_tombstones_delete_rows_of_encoded_keys() {
+        let root = "/tmp/legacy_tombstones_delete_rows_of_encoded_keys";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        // segment written before records were length prefixed and sort keys were encoded
+        let mut log_bytes = vec![6u8];
+        log_bytes.extend(table_schema.schema_version().to_string().into_bytes());
+        log_bytes.push(b'\n');
+        log_bytes.push(1);
+        let mut hash_key_bytes = b"a".to_vec();
+        hash_key_bytes.resize(HASH_KEY_BYTE_SIZE, 0);
+        log_bytes.extend(hash_key_bytes);
+        log_bytes.extend(Int32(-7).to_bytes());
+        log_bytes.extend(1u128.to_be_bytes());
+        log_bytes.extend([0, b'\n', 2]);
+        log_bytes.extend(2u128.to_be_bytes());
+        log_bytes.extend(b"a:-7\n");
+        std::fs::write(format!("{}/table-0-1000", data_dir.commit_logs), log_bytes).unwrap();
+
+        replay_commit_logs(
+            &table_schema,
+            &HashSet::from([0]),
+            &PartitionMap::new(1),
+            &data_dir,
+        )
+        .await;
+
+        let sstables_metadata = get_sstables_metadata("table", &data_dir.sstables);
+        let rows = read_sstable_rows(&sstables_metadata[0], &table_schema).await;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].sort_key, Int32(-7));
+        assert!(rows[0].marked_for_deletion);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+// leads compressed VARCHAR values in encoded rows, it never starts UTF-8 text, nor a null value
+pub(crate) static COMPRESSED_VALUE_FLAG: u8 = 0xFF;
+
+// compressed values are a small lz77 variant, after flag and length of text come control bytes,
+// with high bit clear followed by that many plus one literal bytes, with high bit set by
+// big endian u16 offset of earlier text to copy that many plus MIN_MATCH bytes of
+static MIN_MATCH: usize = 4;
+static MAX_MATCH: usize = 0x7F + MIN_MATCH;
+static MAX_LITERALS: usize = 0x80;
+static MAX_OFFSET: usize = u16::MAX as usize;
+
+// text of tables compressing values above threshold, only if compressing makes it shorter
+pub(crate) fn compress_value_above(
+    value: &str,
+    compress_values_above: Option<usize>,
+) -> Option<Vec<u8>> {
+    match compress_values_above {
+        Some(threshold) if value.len() > threshold => compress_value(value),
+        _ => None,
+    }
+}
+
+pub(crate) fn compress_value(value: &str) -> Option<Vec<u8>> {
+    let text = value.as_bytes();
+    let mut bytes = vec![COMPRESSED_VALUE_FLAG];
+    bytes.extend_from_slice(&(text.len() as u32).to_be_bytes());
+
+    // last position of every 4 bytes seen, positions inside copied matches are skipped
+    let mut last_positions: HashMap<&[u8], usize> = HashMap::new();
+    let mut literals_start = 0;
+    let mut position = 0;
+    while position + MIN_MATCH <= text.len() {
+        let candidate = last_positions.insert(&text[position..position + MIN_MATCH], position);
+        let Some(candidate) = candidate.filter(|candidate| position - candidate <= MAX_OFFSET)
+        else {
+            position += 1;
+            continue;
+        };
+
+        // match may overlap text it copies, it is copied byte by byte then
+        let match_length = text[candidate..]
+            .iter()
+            .zip(&text[position..])
+            .take(MAX_MATCH)
+            .take_while(|(earlier, current)| earlier == current)
+            .count();
+
+        put_literals(&mut bytes, &text[literals_start..position]);
+        bytes.push(0x80 | (match_length - MIN_MATCH) as u8);
+        bytes.extend_from_slice(&((position - candidate) as u16).to_be_bytes());
+        position += match_length;
+        literals_start = position;
+    }
+    put_literals(&mut bytes, &text[literals_start..]);
+
+    (bytes.len() < text.len()).then_some(bytes)
+}
+
+fn put_literals(bytes: &mut Vec<u8>, literals: &[u8]) {
+    for chunk in literals.chunks(MAX_LITERALS) {
+        bytes.push((chunk.len() - 1) as u8);
+        bytes.extend_from_slice(chunk);
+    }
+}
+
+pub(crate) fn is_compressed(bytes: &[u8]) -> bool {
+    bytes.first() == Some(&COMPRESSED_VALUE_FLAG)
+}
+
+// encoded values are zero padded to size of their column, decoding stops once whole text is read
+pub(crate) fn decompress_value(bytes: &[u8]) -> Option<String> {
+    let (&flag, bytes) = bytes.split_first()?;
+    if flag != COMPRESSED_VALUE_FLAG {
+        return None;
+    }
+    let length = u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?) as usize;
+    let mut bytes = &bytes[4..];
+
+    let mut text = Vec::with_capacity(length);
+    while text.len() < length {
+        let (&control, rest) = bytes.split_first()?;
+        if control & 0x80 == 0 {
+            let literals_length = control as usize + 1;
+            text.extend_from_slice(rest.get(..literals_length)?);
+            bytes = &rest[literals_length..];
+        } else {
+            let match_length = (control & 0x7F) as usize + MIN_MATCH;
+            let offset = u16::from_be_bytes(rest.get(..2)?.try_into().ok()?) as usize;
+            bytes = &rest[2..];
+
+            let start = text.len().checked_sub(offset).filter(|_| offset > 0)?;
+            for position in start..start + match_length {
+                text.push(text[position]);
+            }
+        }
+    }
+
+    (text.len() == length)
+        .then_some(text)
+        .and_then(|text| String::from_utf8(text).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_values_are_decompressed_back_from_padded_bytes() {
+        let value = "lorem ipsum dolor sit amet, ".repeat(40) + "zażółć gęślą jaźń";
+
+        let mut bytes = compress_value(&value).unwrap();
+        assert!(bytes.len() < value.len() / 4);
+        assert!(is_compressed(&bytes));
+
+        bytes.resize(value.len(), 0);
+        assert_eq!(decompress_value(&bytes), Some(value));
+    }
+
+    #[test]
+    fn values_are_compressed_only_above_threshold_and_if_it_makes_them_shorter() {
+        let repeated = "a".repeat(300);
+        assert_eq!(compress_value_above(&repeated, None), None);
+        assert_eq!(compress_value_above(&repeated, Some(300)), None);
+        assert!(compress_value_above(&repeated, Some(299)).is_some());
+
+        let distinct: String = (0..200u32)
+            .map(|number| char::from_u32(0x100 + number).unwrap())
+            .collect();
+        assert_eq!(compress_value_above(&distinct, Some(0)), None);
+    }
+
+    #[test]
+    fn truncated_values_are_not_decompressed() {
+        let value = "abcdefgh".repeat(20) + "ijklmnopqrstuvwxyz";
+        let bytes = compress_value(&value).unwrap();
+
+        assert_eq!(decompress_value(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(decompress_value(value.as_bytes()), None);
+    }
+}
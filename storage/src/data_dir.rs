@@ -0,0 +1,43 @@
+use crate::commit_log::COMMIT_LOG_SEGMENTS_FILE_PATH;
+use crate::sstable::SSTABLES_DIR;
+use crate::table::{TABLE_SCHEMAS_DIR, TABLE_SCHEMAS_FILE_PATH};
+
+// every database keeps its schemas, commit logs and sstables under its own root
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataDir {
+    pub root: String,
+    pub sstables: String,
+    pub commit_logs: String,
+    pub table_schemas_file: String,
+}
+
+impl DataDir {
+    pub fn new(root: &str) -> DataDir {
+        let root = root.trim_end_matches('/');
+        DataDir {
+            root: root.to_string(),
+            sstables: format!("{}/sstables", root),
+            commit_logs: format!("{}/commit_logs", root),
+            table_schemas_file: format!("{}/table_schemas", root),
+        }
+    }
+
+    pub fn create_dirs(&self) -> Result<(), String> {
+        for dir in [&self.root, &self.sstables, &self.commit_logs] {
+            std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir, e))?;
+        }
+        Ok(())
+    }
+}
+
+// paths used before databases could be configured, so that existing data is found
+impl Default for DataDir {
+    fn default() -> DataDir {
+        DataDir {
+            root: TABLE_SCHEMAS_DIR.to_string(),
+            sstables: SSTABLES_DIR.to_string(),
+            commit_logs: COMMIT_LOG_SEGMENTS_FILE_PATH.to_string(),
+            table_schemas_file: TABLE_SCHEMAS_FILE_PATH.to_string(),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use crate::auth::AuthContext;
+use crate::range_tombstone::{is_shadowed, RangeTombstone};
+use crate::row::encode_primary_key;
+use crate::sstable::{
+    get_sstables_metadata, read_sstable_range_tombstones, read_sstable_rows_in_range,
+};
+use crate::table::Table;
+use crate::util::millis_from_epoch;
+use crate::Row;
+use common::value::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Bound, RangeBounds};
+
+// rows of a hash key with sort key in range, e.g. DeleteWhere::new("user_1", Int32(0)..Int32(100))
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeleteWhere {
+    pub hash_key: String,
+    pub start: Bound<Value>,
+    pub end: Bound<Value>,
+    // only rows having that value of the column are deleted, null matches missing values
+    pub filter: Option<(String, Value)>,
+}
+
+impl DeleteWhere {
+    pub fn new(hash_key: &str, range: impl RangeBounds<Value>) -> DeleteWhere {
+        DeleteWhere {
+            hash_key: hash_key.to_string(),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+            filter: None,
+        }
+    }
+
+    pub fn with_filter(mut self, column: &str, value: Value) -> DeleteWhere {
+        self.filter = Some((column.to_string(), value));
+        self
+    }
+
+    // rows of the hash key are the ones prefixed with "{hash_key}:", ':' is followed by ';'
+    fn primary_key_range(&self) -> (Bound<String>, Bound<String>) {
+        let start = match &self.start {
+            Bound::Unbounded => Bound::Included(format!("{}:", self.hash_key)),
+            bound => bound
+                .as_ref()
+                .map(|sort_key| encode_primary_key(&self.hash_key, sort_key)),
+        };
+        let end = match &self.end {
+            Bound::Unbounded => Bound::Excluded(format!("{};", self.hash_key)),
+            bound => bound
+                .as_ref()
+                .map(|sort_key| encode_primary_key(&self.hash_key, sort_key)),
+        };
+        (start, end)
+    }
+
+    // rows of the whole range go away when no filter picks them, so a single range tombstone is
+    // written instead of a tombstone per row
+    pub fn range_tombstone(&self, timestamp: u128) -> Option<RangeTombstone> {
+        if self.filter.is_some() {
+            return None;
+        }
+        let (start, end) = self.primary_key_range();
+        Some(RangeTombstone {
+            hash_key: self.hash_key.clone(),
+            start,
+            end,
+            timestamp,
+        })
+    }
+
+    fn matches(&self, row: &Row) -> bool {
+        match &self.filter {
+            Some((column, value)) => row.values.get(column).unwrap_or(&Value::Null) == value,
+            None => true,
+        }
+    }
+}
+
+// latest versions of rows in range matching the filter, rows already deleted or expired are left out
+pub async fn rows_to_delete(
+    table: &Table,
+    delete_where: &DeleteWhere,
+    partition: usize,
+) -> Vec<Row> {
+    let (start, end) = delete_where.primary_key_range();
+    let (start, end) = (
+        start.as_ref().map(String::as_str),
+        end.as_ref().map(String::as_str),
+    );
+
+    // memtable has the latest version of its rows, tombstones included
+    let mut latest_rows: BTreeMap<String, Row> = table
+        .memtable
+        .range(start, end)
+        .map(|row| (row.primary_key.clone(), row))
+        .collect();
+    let mut sstable_rows: HashMap<String, Row> = HashMap::new();
+    let mut range_tombstones = table.memtable.range_tombstones().to_vec();
+    for sstable_metadata in
+        get_sstables_metadata(&table.table_schema.name, &table.data_dir.sstables)
+    {
+        range_tombstones
+            .extend(read_sstable_range_tombstones(&sstable_metadata, &table.table_schema).await);
+        let rows = read_sstable_rows_in_range(
+            start,
+            end,
+            partition,
+            &sstable_metadata,
+            &table.table_schema,
+        )
+        .await;
+        for row in rows {
+            if latest_rows.contains_key(&row.primary_key) {
+                continue;
+            }
+            let newer = sstable_rows
+                .get(&row.primary_key)
+                .is_none_or(|newest_row| row.timestamp > newest_row.timestamp);
+            if newer {
+                sstable_rows.insert(row.primary_key.clone(), row);
+            }
+        }
+    }
+    sstable_rows.retain(|_, row| !is_shadowed(row, &range_tombstones));
+    latest_rows.extend(sstable_rows);
+
+    let now = millis_from_epoch();
+    latest_rows
+        .into_values()
+        .filter(|row| {
+            !row.marked_for_deletion
+                && !table.table_schema.options.is_row_expired(row, now)
+                && delete_where.matches(row)
+        })
+        .collect()
+}
+
+// tombstones are logged like inserts, so that tombstones of rows kept only in sstables outlive
+// a restart and keep shadowing them, range tombstone is logged as a single record
+pub async fn write_tombstones(
+    table: &mut Table,
+    delete_where: &DeleteWhere,
+    rows: Vec<Row>,
+    auth: &AuthContext,
+) {
+    let timestamp = millis_from_epoch();
+    match delete_where.range_tombstone(timestamp) {
+        Some(range_tombstone) => {
+            table
+                .commit_log
+                .lock()
+                .await
+                .write_range_tombstone(&range_tombstone)
+                .await;
+            table.memtable.delete_range(range_tombstone);
+        }
+        None => {
+            let tombstones: Vec<_> = rows
+                .iter()
+                .map(|row| Row {
+                    values: HashMap::new(),
+                    timestamp,
+                    marked_for_deletion: true,
+                    ..row.clone()
+                })
+                .collect();
+            table
+                .commit_log
+                .lock()
+                .await
+                .write_inserts(&tombstones)
+                .await;
+            for tombstone in tombstones {
+                table.memtable.insert(tombstone, false);
+            }
+        }
+    }
+
+    for row in rows {
+        table
+            .on_row_deleted(&row.hash_key, &row.primary_key, auth)
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::AccessTracker;
+    use crate::commit_log::CommitLog;
+    use crate::data_dir::DataDir;
+    use crate::memtable::Memtable;
+    use crate::move_row::read_visible_row;
+    use crate::sstable::write_memtable_to_sstable;
+    use crate::table::TableSchema;
+    use common::partition::PartitionMap;
+    use common::value::Value::{Int32, Varchar};
+    use futures::lock::Mutex;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    fn get_row(hash_key: &str, sort_key: i32, status: &str) -> Row {
+        Row::new(
+            hash_key.to_string(),
+            Int32(sort_key),
+            HashMap::from([("status".to_string(), Varchar(status.to_string()))]),
+        )
+    }
+
+    // sstable holds rows of "user_1" with sort keys -5, -4, -3, -1 and one row of "user_2", all
+    // written a second ago
+    async fn table_with_sstable(root: &str) -> Table {
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let table_schema =
+            TableSchema::from_string("events>sort_key:INT32;status:VARCHAR(10)").unwrap();
+
+        let mut sstable_memtable = Memtable::default();
+        let rows = [(-5, "done"), (-4, "open"), (-3, "done"), (-1, "done")]
+            .map(|(sort_key, status)| get_row("user_1", sort_key, status));
+        for row in rows.into_iter().chain([get_row("user_2", -4, "done")]) {
+            let timestamp = row.timestamp - 1000;
+            sstable_memtable.insert(Row { timestamp, ..row }, false);
+        }
+        write_memtable_to_sstable(
+            &sstable_memtable,
+            &table_schema,
+            &PartitionMap::new(1),
+            millis_from_epoch(),
+            &data_dir.sstables,
+        )
+        .await
+        .unwrap();
+
+        let commit_log =
+            CommitLog::open_new(&table_schema, &HashSet::from([0]), &data_dir.commit_logs).await;
+        Table {
+            memtable: Memtable::default(),
+            commit_log: Arc::new(Mutex::new(commit_log)),
+            table_schema,
+            sequences: HashMap::new(),
+            leases: HashMap::new(),
+            row_counts: HashMap::new(),
+            access_tracker: AccessTracker::default(),
+            data_dir,
+            write_intents: HashMap::new(),
+        }
+    }
+
+    #[monoio::test]
+    async fn rows_in_range_matching_filter_get_tombstones() {
+        let root = "/tmp/test_delete_where";
+        let mut table = table_with_sstable(root).await;
+        // memtable has the latest version of sort key -3, which no longer matches
+        table.memtable.insert(get_row("user_1", -3, "open"), false);
+        table.memtable.insert(get_row("user_1", -2, "done"), false);
+
+        let delete_where = DeleteWhere::new("user_1", Int32(-5)..Int32(-1))
+            .with_filter("status", Varchar("done".to_string()));
+        let rows = rows_to_delete(&table, &delete_where, 0).await;
+        let sort_keys: Vec<_> = rows.iter().map(|row| row.sort_key.clone()).collect();
+        assert_eq!(sort_keys, vec![Int32(-5), Int32(-2)]);
+
+        write_tombstones(&mut table, &delete_where, rows, &AuthContext::anonymous()).await;
+        assert!(rows_to_delete(&table, &delete_where, 0).await.is_empty());
+        let remaining = rows_to_delete(&table, &DeleteWhere::new("user_1", ..), 0).await;
+        let sort_keys: Vec<_> = remaining.iter().map(|row| row.sort_key.clone()).collect();
+        assert_eq!(sort_keys, vec![Int32(-4), Int32(-3), Int32(-1)]);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn unfiltered_delete_writes_range_tombstone() {
+        let root = "/tmp/test_delete_where_range_tombstone";
+        let mut table = table_with_sstable(root).await;
+        table.memtable.insert(get_row("user_1", -2, "done"), false);
+
+        let delete_where = DeleteWhere::new("user_1", Int32(-4)..);
+        let rows = rows_to_delete(&table, &delete_where, 0).await;
+        assert_eq!(rows.len(), 4);
+        write_tombstones(&mut table, &delete_where, rows, &AuthContext::anonymous()).await;
+
+        // rows kept only in sstable get no tombstone of their own
+        assert_eq!(table.memtable.size, 1);
+        assert_eq!(table.memtable.range_tombstones().len(), 1);
+        assert!(rows_to_delete(&table, &delete_where, 0).await.is_empty());
+        for sort_key in [-4, -3, -2, -1] {
+            let primary_key = encode_primary_key("user_1", &Int32(sort_key));
+            assert!(read_visible_row(&table, &primary_key, 0).await.is_none());
+        }
+        let remaining = rows_to_delete(&table, &DeleteWhere::new("user_1", ..), 0).await;
+        let sort_keys: Vec<_> = remaining.iter().map(|row| row.sort_key.clone()).collect();
+        assert_eq!(sort_keys, vec![Int32(-5)]);
+
+        // rows written afterwards are not deleted, also once flushed along with range tombstone
+        table.memtable.insert(get_row("user_1", -3, "open"), false);
+        let memtable = table.take_memtable();
+        write_memtable_to_sstable(
+            &memtable,
+            &table.table_schema,
+            &PartitionMap::new(1),
+            millis_from_epoch(),
+            &table.data_dir.sstables,
+        )
+        .await
+        .unwrap();
+        let primary_key = encode_primary_key("user_1", &Int32(-3));
+        assert!(read_visible_row(&table, &primary_key, 0).await.is_some());
+        let primary_key = encode_primary_key("user_1", &Int32(-4));
+        assert!(read_visible_row(&table, &primary_key, 0).await.is_none());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+use crate::history::sstable_history_dir;
+use crate::table::TableSchema;
+use crate::util::parse_table_file_name;
+use crate::MEGABYTE;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// listing sstables on every write would be too slow, so usage is listed again once it is that old,
+// writes flushed meanwhile can take table somewhat over its quota
+static DISK_USAGE_REFRESH: Duration = Duration::from_secs(1);
+static DISK_USAGES: Mutex<BTreeMap<String, (u64, Instant)>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiskQuotaExceeded {
+    pub usage: u64,
+    pub limit: u64,
+}
+
+// bytes of sstables of the table, together with superseded ones still kept for history
+pub fn table_disk_usage(table_name: &str, sstable_dir: &str) -> u64 {
+    [sstable_dir.to_string(), sstable_history_dir(sstable_dir)]
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            parse_table_file_name(&entry.file_name().to_string_lossy())
+                .is_some_and(|(file_table_name, _)| file_table_name == table_name)
+        })
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+// inserts are rejected while table is over its quota, deletes, retention and compaction bring it back
+pub fn check_disk_quota(
+    table_schema: &TableSchema,
+    sstable_dir: &str,
+) -> Result<(), DiskQuotaExceeded> {
+    let limit = match table_schema.options.max_disk_mb {
+        Some(max_disk_mb) => max_disk_mb * MEGABYTE as u64,
+        None => return Ok(()),
+    };
+
+    let now = Instant::now();
+    let usage = {
+        let mut disk_usages = DISK_USAGES.lock().unwrap();
+        let key = format!("{}/{}", sstable_dir, table_schema.name);
+        match disk_usages.get(&key) {
+            Some((usage, listed_at)) if now.duration_since(*listed_at) < DISK_USAGE_REFRESH => {
+                *usage
+            }
+            _ => {
+                let usage = table_disk_usage(&table_schema.name, sstable_dir);
+                disk_usages.insert(key, (usage, now));
+                usage
+            }
+        }
+    };
+
+    match usage > limit {
+        true => Err(DiskQuotaExceeded { usage, limit }),
+        false => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::ColumnType;
+
+    #[test]
+    fn table_over_quota_is_reported() {
+        let sstable_dir = "/tmp/table_over_quota_is_reported";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+        std::fs::create_dir_all(sstable_history_dir(sstable_dir)).unwrap();
+        std::fs::write(format!("{}/quota-0-1-100", sstable_dir), vec![0; MEGABYTE]).unwrap();
+        std::fs::write(
+            format!("{}/quota-0-1-50-200", sstable_history_dir(sstable_dir)),
+            vec![0; 10],
+        )
+        .unwrap();
+        std::fs::write(format!("{}/other-0-1-100", sstable_dir), vec![0; MEGABYTE]).unwrap();
+
+        assert_eq!(table_disk_usage("quota", sstable_dir), MEGABYTE as u64 + 10);
+
+        let table_schema = TableSchema::new("quota".to_string(), ColumnType::Int32);
+        assert!(check_disk_quota(&table_schema, sstable_dir).is_ok());
+        let table_schema = TableSchema::from_string("quota>sort_key:INT32#max_disk_mb=1").unwrap();
+        assert_eq!(
+            table_schema.to_string(),
+            "quota>sort_key:INT32#max_disk_mb=1"
+        );
+        assert_eq!(
+            check_disk_quota(&table_schema, sstable_dir),
+            Err(DiskQuotaExceeded {
+                usage: MEGABYTE as u64 + 10,
+                limit: MEGABYTE as u64,
+            })
+        );
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+        std::fs::remove_dir_all(sstable_history_dir(sstable_dir)).unwrap();
+    }
+}
@@ -0,0 +1,234 @@
+use crate::io::remove_file;
+use crate::range_tombstone::is_shadowed;
+use crate::sstable::{
+    get_sstables_metadata, read_row_from_sstable_file, read_sstable_range_tombstones,
+    SSTableMetadata,
+};
+use crate::table::{Table, TableSchema};
+use crate::util::millis_from_epoch;
+use crate::Row;
+use std::path::Path;
+
+// superseded sstables are hard linked here as "<sstable file name>-<superseded at>", next to
+// sstables dir, so that listing sstables never sees them
+pub fn sstable_history_dir(sstable_dir: &str) -> String {
+    format!("{}_history", sstable_dir.trim_end_matches('/'))
+}
+
+// called before sstable gets removed, so that snapshots still reading it are not affected
+pub(crate) fn retire_sstable_file(sstable_metadata: &SSTableMetadata, sstable_dir: &str) {
+    let history_dir = sstable_history_dir(sstable_dir);
+    let file_name = Path::new(&sstable_metadata.file_path)
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+    let history_path = format!("{}/{}-{}", history_dir, file_name, millis_from_epoch());
+
+    if let Err(error) = std::fs::create_dir_all(&history_dir)
+        .and_then(|_| std::fs::hard_link(&sstable_metadata.file_path, &history_path))
+    {
+        tracing::error!(
+            "Failed to keep history of sstable '{}': {}",
+            sstable_metadata.file_path,
+            error
+        );
+    }
+}
+
+// superseded sstables with the time they were superseded at
+fn get_history_sstables(table_name: &str, sstable_dir: &str) -> Vec<(SSTableMetadata, u128)> {
+    let history_dir = sstable_history_dir(sstable_dir);
+    if !Path::new(&history_dir).exists() {
+        return Vec::new();
+    }
+
+    get_sstables_metadata(table_name, &history_dir)
+        .into_iter()
+        .map(|sstable_metadata| {
+            let superseded_at = sstable_metadata
+                .file_path
+                .rsplit('-')
+                .next()
+                .and_then(|superseded_at| superseded_at.parse().ok())
+                .unwrap_or(0);
+            (sstable_metadata, superseded_at)
+        })
+        .collect()
+}
+
+// history of tables that no longer keep it is removed as a whole
+pub fn purge_sstable_history(table_schema: &TableSchema, sstable_dir: &str) {
+    let now = millis_from_epoch();
+    for (sstable_metadata, superseded_at) in get_history_sstables(&table_schema.name, sstable_dir) {
+        let expired = match table_schema.options.history {
+            Some(history) => superseded_at + history.as_millis() < now,
+            None => true,
+        };
+        if expired {
+            if let Err(error) = remove_file(&sstable_metadata.file_path) {
+                tracing::error!(
+                    "Failed to purge sstable history '{}': {}",
+                    sstable_metadata.file_path,
+                    error
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn drop_sstable_history(table_name: &str, sstable_dir: &str) {
+    for (sstable_metadata, _) in get_history_sstables(table_name, sstable_dir) {
+        let _ = remove_file(&sstable_metadata.file_path);
+    }
+}
+
+// newest version of the row written at or before given time, looked up in memtable, sstables and
+// their history, versions overwritten in memtable or compacted away before history was enabled
+// are not found
+pub async fn read_row_as_of(
+    primary_key: &str,
+    partition: usize,
+    table: &Table,
+    as_of: u128,
+) -> Option<Row> {
+    let sstable_dir = &table.data_dir.sstables;
+    let sstable_metadatas = get_sstables_metadata(&table.table_schema.name, sstable_dir)
+        .into_iter()
+        .chain(
+            get_history_sstables(&table.table_schema.name, sstable_dir)
+                .into_iter()
+                .map(|(sstable_metadata, _)| sstable_metadata),
+        );
+
+    let mut newest_row = table
+        .memtable
+        .get_entry(&primary_key.to_string())
+        .filter(|row| row.timestamp <= as_of);
+    let mut range_tombstones = table.memtable.range_tombstones().to_vec();
+    for sstable_metadata in sstable_metadatas {
+        // rows of sstable are not newer than the sstable itself
+        if newest_row
+            .as_ref()
+            .is_some_and(|row| row.timestamp >= sstable_metadata.timestamp)
+        {
+            continue;
+        }
+
+        let row = read_row_from_sstable_file(
+            primary_key,
+            partition,
+            &sstable_metadata,
+            &table.table_schema,
+        )
+        .await;
+        range_tombstones
+            .extend(read_sstable_range_tombstones(&sstable_metadata, &table.table_schema).await);
+        if let Some(row) = row.filter(|row| row.timestamp <= as_of) {
+            if newest_row
+                .as_ref()
+                .is_none_or(|newest_row| row.timestamp > newest_row.timestamp)
+            {
+                newest_row = Some(row);
+            }
+        }
+    }
+
+    // range tombstones written after given time did not delete the row yet
+    range_tombstones.retain(|range_tombstone| range_tombstone.timestamp <= as_of);
+    newest_row.filter(|row| {
+        !row.marked_for_deletion
+            && !is_shadowed(row, &range_tombstones)
+            && !table.table_schema.options.is_row_expired(row, as_of)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commit_log::{CommitLog, PartialFlushes};
+    use crate::data_dir::DataDir;
+    use crate::sstable::write_memtable_to_sstable;
+    use crate::table::ColumnType;
+    use crate::Memtable;
+    use common::partition::PartitionMap;
+    use common::value::Value::Int32;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    async fn write_row_version(
+        table_schema: &TableSchema,
+        timestamp: u128,
+        marked_for_deletion: bool,
+        sstable_dir: &str,
+    ) -> SSTableMetadata {
+        let mut row =
+            Row::new_with_timestamp("a".to_string(), Int32(-1), HashMap::new(), timestamp);
+        row.marked_for_deletion = marked_for_deletion;
+        let mut memtable = Memtable::default();
+        memtable.insert(row, false);
+
+        let partition_map = PartitionMap::new(1);
+        write_memtable_to_sstable(
+            &memtable,
+            table_schema,
+            &partition_map,
+            timestamp,
+            sstable_dir,
+        )
+        .await
+        .unwrap();
+        get_sstables_metadata(&table_schema.name, sstable_dir)
+            .into_iter()
+            .find(|sstable_metadata| sstable_metadata.timestamp == timestamp)
+            .unwrap()
+    }
+
+    #[monoio::test]
+    async fn superseded_row_is_read_as_of_past_time() {
+        let root = "/tmp/superseded_row_is_read_as_of_past_time";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let mut table_schema = TableSchema::new("history".to_string(), ColumnType::Int32);
+        table_schema.options.history = Some(Duration::from_secs(3600));
+
+        // first version is superseded by compaction, second one deletes the row
+        let superseded = write_row_version(&table_schema, 100, false, &data_dir.sstables).await;
+        retire_sstable_file(&superseded, &data_dir.sstables);
+        std::fs::remove_file(&superseded.file_path).unwrap();
+        write_row_version(&table_schema, 200, true, &data_dir.sstables).await;
+
+        let commit_log = CommitLog {
+            file: None,
+            file_path: "test".to_string(),
+            file_offset: 0,
+            table_schema: table_schema.clone(),
+            partition: 0,
+            closed: true,
+            partial_flushes: PartialFlushes::default(),
+        };
+        let table = Table::new(
+            Memtable::default(),
+            commit_log,
+            table_schema.clone(),
+            data_dir.clone(),
+        );
+        let primary_key = Row::new("a".to_string(), Int32(-1), HashMap::new()).primary_key;
+
+        assert!(read_row_as_of(&primary_key, 0, &table, 50).await.is_none());
+        let row = read_row_as_of(&primary_key, 0, &table, 150).await.unwrap();
+        assert_eq!(row.timestamp, 100);
+        assert!(read_row_as_of(&primary_key, 0, &table, 250).await.is_none());
+
+        // history window is measured from when sstable got superseded
+        purge_sstable_history(&table_schema, &data_dir.sstables);
+        assert_eq!(get_history_sstables("history", &data_dir.sstables).len(), 1);
+        table_schema.options.history = None;
+        purge_sstable_history(&table_schema, &data_dir.sstables);
+        assert!(get_history_sstables("history", &data_dir.sstables).is_empty());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+use crate::auth::AuthContext;
+use crate::supervisor::BackgroundTask;
+use crate::Row;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+// listeners are notified synchronously on the thread doing the work, so they should only hand events off
+pub trait StorageHooks: Send + Sync {
+    fn on_insert(&self, _table_name: &str, _row: &Row, _auth: &AuthContext) {}
+    fn on_delete(&self, _table_name: &str, _primary_key: &str, _auth: &AuthContext) {}
+    fn on_flush(&self, _table_name: &str, _number_of_rows: usize) {}
+    fn on_compaction(&self, _table_name: &str, _merged_sstables: usize, _number_of_rows: usize) {}
+    fn on_background_event(&self, _event: &BackgroundEvent) {}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackgroundStage {
+    Started,
+    Finished,
+    Failed(String),
+}
+
+// lifecycle of a flush or compaction, so that latency spikes can be matched with background work
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundEvent {
+    pub task: BackgroundTask,
+    pub table_name: String,
+    pub stage: BackgroundStage,
+    // file names of commit logs or sstables read, and of sstables written
+    pub input_segments: Vec<String>,
+    pub output_segments: Vec<String>,
+    pub bytes_read: u64,
+    // only known once task finished
+    pub bytes_written: u64,
+    // zero for started events
+    pub duration: Duration,
+}
+
+impl BackgroundEvent {
+    pub(crate) fn started(
+        task: BackgroundTask,
+        table_name: &str,
+        input_segments: Vec<String>,
+        bytes_read: u64,
+    ) -> BackgroundEvent {
+        BackgroundEvent {
+            task,
+            table_name: table_name.to_string(),
+            stage: BackgroundStage::Started,
+            input_segments,
+            output_segments: Vec::new(),
+            bytes_read,
+            bytes_written: 0,
+            duration: Duration::ZERO,
+        }
+    }
+
+    pub(crate) fn finished(
+        &self,
+        output_segments: Vec<String>,
+        bytes_written: u64,
+        duration: Duration,
+    ) -> BackgroundEvent {
+        BackgroundEvent {
+            stage: BackgroundStage::Finished,
+            output_segments,
+            bytes_written,
+            duration,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn failed(&self, error: &str, duration: Duration) -> BackgroundEvent {
+        BackgroundEvent {
+            stage: BackgroundStage::Failed(error.to_string()),
+            duration,
+            ..self.clone()
+        }
+    }
+}
+
+// shared by all threads, compaction runs on its own thread
+static STORAGE_HOOKS: RwLock<Vec<Arc<dyn StorageHooks>>> = RwLock::new(Vec::new());
+
+pub fn register_storage_hooks(hooks: Arc<dyn StorageHooks>) {
+    STORAGE_HOOKS.write().unwrap().push(hooks);
+}
+
+pub(crate) fn notify_storage_hooks(notify: impl Fn(&dyn StorageHooks)) {
+    for hooks in STORAGE_HOOKS.read().unwrap().iter() {
+        notify(hooks.as_ref());
+    }
+}
+
+// logged with structured fields as well, so that events can be filtered by table or task
+pub(crate) fn publish_background_event(event: BackgroundEvent) {
+    let inputs = event.input_segments.join(",");
+    let outputs = event.output_segments.join(",");
+    let duration_millis = event.duration.as_millis() as u64;
+    match &event.stage {
+        BackgroundStage::Started => tracing::info!(
+            task = %event.task,
+            table = %event.table_name,
+            inputs = %inputs,
+            bytes_read = event.bytes_read,
+            "{} of table '{}' started",
+            event.task,
+            event.table_name
+        ),
+        BackgroundStage::Finished => tracing::info!(
+            task = %event.task,
+            table = %event.table_name,
+            inputs = %inputs,
+            outputs = %outputs,
+            bytes_read = event.bytes_read,
+            bytes_written = event.bytes_written,
+            duration_millis,
+            "{} of table '{}' finished",
+            event.task,
+            event.table_name
+        ),
+        BackgroundStage::Failed(error) => tracing::error!(
+            task = %event.task,
+            table = %event.table_name,
+            inputs = %inputs,
+            bytes_read = event.bytes_read,
+            duration_millis,
+            error = %error,
+            "{} of table '{}' failed",
+            event.task,
+            event.table_name
+        ),
+    }
+    notify_storage_hooks(|hooks| hooks.on_background_event(&event));
+}
@@ -0,0 +1,614 @@
+use crate::io::remove_file;
+use crate::prepare_log::{put_bytes, RecordReader};
+use crate::row::encode_sort_key;
+use crate::sstable::{
+    get_sstables_metadata, read_row_from_sstable, read_sstable_rows, SSTableMetadata,
+};
+use crate::table::{Table, TableSchema};
+use crate::util::{decode_row, millis_from_epoch};
+use crate::Row;
+use common::partition::PartitionMap;
+use common::value::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::{Bound, RangeBounds};
+use std::path::Path;
+
+// range of values of an indexed column, e.g. IndexRange::new("age", Int32(18)..Int32(30))
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexRange {
+    pub column: String,
+    pub start: Bound<Value>,
+    pub end: Bound<Value>,
+}
+
+impl IndexRange {
+    pub fn new(column: &str, range: impl RangeBounds<Value>) -> IndexRange {
+        IndexRange {
+            column: column.to_string(),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    // null values are never in range
+    pub fn contains(&self, value: &Value) -> bool {
+        encode_index_value(value).is_some_and(|value| self.contains_encoded(&value))
+    }
+
+    fn contains_encoded(&self, encoded_value: &str) -> bool {
+        let start = self.start.as_ref().map(encode_sort_key);
+        let end = self.end.as_ref().map(encode_sort_key);
+        let range = (
+            start.as_ref().map(String::as_str),
+            end.as_ref().map(String::as_str),
+        );
+        RangeBounds::<str>::contains(&range, encoded_value)
+    }
+}
+
+// values are encoded like sort keys, so that encoded values of a column compare as values do
+pub fn encode_index_value(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        value => Some(encode_sort_key(value)),
+    }
+}
+
+// entries of indexed columns of memtable rows by encoded value and primary key, tombstones have none
+#[derive(Debug, Default)]
+pub(crate) struct MemtableIndex {
+    columns: HashMap<String, BTreeSet<(String, String)>>,
+}
+
+impl MemtableIndex {
+    pub(crate) fn new(columns: &[String]) -> MemtableIndex {
+        MemtableIndex {
+            columns: columns
+                .iter()
+                .map(|column| (column.clone(), BTreeSet::new()))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn covers(&self, column: &str) -> bool {
+        self.columns.contains_key(column)
+    }
+
+    pub(crate) fn add<'a>(
+        &mut self,
+        primary_key: &str,
+        value_of: impl Fn(&str) -> Option<&'a Value>,
+    ) {
+        for (column, entries) in &mut self.columns {
+            if let Some(value) = value_of(column).and_then(encode_index_value) {
+                entries.insert((value, primary_key.to_string()));
+            }
+        }
+    }
+
+    pub(crate) fn remove<'a>(
+        &mut self,
+        primary_key: &str,
+        value_of: impl Fn(&str) -> Option<&'a Value>,
+    ) {
+        for (column, entries) in &mut self.columns {
+            if let Some(value) = value_of(column).and_then(encode_index_value) {
+                entries.remove(&(value, primary_key.to_string()));
+            }
+        }
+    }
+
+    // ordered by value, empty if the column is not indexed
+    pub(crate) fn primary_keys(&self, index_range: &IndexRange) -> Vec<String> {
+        let Some(entries) = self.columns.get(&index_range.column) else {
+            return Vec::new();
+        };
+        let start = match index_range.start.as_ref().map(encode_sort_key) {
+            Bound::Included(value) | Bound::Excluded(value) => {
+                Bound::Included((value, String::new()))
+            }
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        entries
+            .range((start, Bound::Unbounded))
+            .skip_while(|(value, _)| !index_range.contains_encoded(value))
+            .take_while(|(value, _)| index_range.contains_encoded(value))
+            .map(|(_, primary_key)| primary_key.clone())
+            .collect()
+    }
+}
+
+struct IndexEntry {
+    column: String,
+    value: String,
+    hash_key: String,
+    primary_key: String,
+}
+
+// collected while sstable gets written on flush or compaction, tombstones have no entries
+pub(crate) struct SSTableIndex {
+    columns: Vec<String>,
+    entries: Vec<IndexEntry>,
+}
+
+impl SSTableIndex {
+    // None for tables without indexes
+    pub(crate) fn for_table(table_schema: &TableSchema) -> Option<SSTableIndex> {
+        if table_schema.options.indexes.is_empty() {
+            return None;
+        }
+        Some(SSTableIndex {
+            columns: table_schema.options.indexes.clone(),
+            entries: Vec::new(),
+        })
+    }
+
+    pub(crate) fn add_row(&mut self, row_bytes: &[u8], table_schema: &TableSchema) {
+        let row = decode_row(row_bytes, table_schema);
+        if row.marked_for_deletion {
+            return;
+        }
+        for column in &self.columns {
+            if let Some(value) = row.values.get(column).and_then(encode_index_value) {
+                self.entries.push(IndexEntry {
+                    column: column.clone(),
+                    value,
+                    hash_key: row.hash_key.clone(),
+                    primary_key: row.primary_key.clone(),
+                });
+            }
+        }
+    }
+
+    // counts are written up front, so that a partially written file is not read as a shorter index
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(self.columns.len() as u32).to_be_bytes());
+        for column in &self.columns {
+            put_bytes(&mut bytes, column.as_bytes());
+        }
+        bytes.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+        for entry in &self.entries {
+            put_bytes(&mut bytes, entry.column.as_bytes());
+            put_bytes(&mut bytes, entry.value.as_bytes());
+            put_bytes(&mut bytes, entry.hash_key.as_bytes());
+            put_bytes(&mut bytes, entry.primary_key.as_bytes());
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<SSTableIndex> {
+        let mut reader = RecordReader::new(bytes);
+        let columns = (0..reader.take_u32()?)
+            .map(|_| reader.take_string())
+            .collect::<Option<_>>()?;
+        let entries = (0..reader.take_u32()?)
+            .map(|_| {
+                Some(IndexEntry {
+                    column: reader.take_string()?,
+                    value: reader.take_string()?,
+                    hash_key: reader.take_string()?,
+                    primary_key: reader.take_string()?,
+                })
+            })
+            .collect::<Option<_>>()?;
+        reader
+            .is_empty()
+            .then_some(SSTableIndex { columns, entries })
+    }
+}
+
+// indexes are kept next to sstables dir, so that listing sstables never sees them
+pub fn index_dir(sstable_dir: &str) -> String {
+    format!("{}_indexes", sstable_dir.trim_end_matches('/'))
+}
+
+fn index_path(sstable_file_path: &str, sstable_dir: &str) -> String {
+    let file_name = Path::new(sstable_file_path)
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+    format!("{}/{}", index_dir(sstable_dir), file_name)
+}
+
+// sstable without index is scanned by queries instead, so failing to write it does not fail the write
+pub(crate) fn write_sstable_index(
+    sstable_index: SSTableIndex,
+    sstable_file_path: &str,
+    sstable_dir: &str,
+) {
+    let path = index_path(sstable_file_path, sstable_dir);
+    if let Err(error) = std::fs::create_dir_all(index_dir(sstable_dir))
+        .and_then(|_| std::fs::write(&path, sstable_index.to_bytes()))
+    {
+        tracing::error!("Failed to write index '{}': {}", path, error);
+        let _ = remove_file(&path);
+    }
+}
+
+pub(crate) fn remove_sstable_index(sstable_metadata: &SSTableMetadata, sstable_dir: &str) {
+    let _ = remove_file(index_path(&sstable_metadata.file_path, sstable_dir));
+}
+
+// hash keys by primary keys of sstable rows in range, sstables written before the column was
+// indexed or whose index could not be written are scanned
+async fn sstable_index_candidates(
+    sstable_metadata: &SSTableMetadata,
+    index_range: &IndexRange,
+    table_schema: &TableSchema,
+    sstable_dir: &str,
+) -> Vec<(String, String)> {
+    let sstable_index = std::fs::read(index_path(&sstable_metadata.file_path, sstable_dir))
+        .ok()
+        .and_then(|bytes| SSTableIndex::from_bytes(&bytes))
+        .filter(|sstable_index| sstable_index.columns.contains(&index_range.column));
+    match sstable_index {
+        Some(sstable_index) => sstable_index
+            .entries
+            .into_iter()
+            .filter(|entry| {
+                entry.column == index_range.column && index_range.contains_encoded(&entry.value)
+            })
+            .map(|entry| (entry.primary_key, entry.hash_key))
+            .collect(),
+        None => read_sstable_rows(sstable_metadata, table_schema)
+            .await
+            .into_iter()
+            .filter(|row| {
+                !row.marked_for_deletion
+                    && row
+                        .values
+                        .get(&index_range.column)
+                        .is_some_and(|value| index_range.contains(value))
+            })
+            .map(|row| (row.primary_key, row.hash_key))
+            .collect(),
+    }
+}
+
+// rows of given partitions ordered by value of indexed column, entries of older versions stay in
+// sstables until compaction, so every row found through index is read again and checked
+pub async fn query_index(
+    table: &Table,
+    index_range: &IndexRange,
+    partitions: &HashSet<usize>,
+    partition_map: &PartitionMap,
+) -> Vec<Row> {
+    let table_schema = &table.table_schema;
+    let sstable_dir = &table.data_dir.sstables;
+    let mut rows: Vec<_> = table
+        .memtable
+        .index_primary_keys(index_range)
+        .iter()
+        .filter_map(|primary_key| table.memtable.get(primary_key))
+        .collect();
+
+    // memtable has the latest version of its rows, whether they are in range or not
+    let mut candidates = BTreeMap::new();
+    for sstable_metadata in get_sstables_metadata(&table_schema.name, sstable_dir) {
+        let sstable_candidates =
+            sstable_index_candidates(&sstable_metadata, index_range, table_schema, sstable_dir)
+                .await;
+        for (primary_key, hash_key) in sstable_candidates {
+            if table.memtable.get_entry(&primary_key).is_none() {
+                candidates.insert(primary_key, hash_key);
+            }
+        }
+    }
+    for (primary_key, hash_key) in candidates {
+        let partition = partition_map.get_partition(&hash_key);
+        if !partitions.contains(&partition) {
+            continue;
+        }
+        rows.extend(
+            read_row_from_sstable(&primary_key, partition, table_schema, sstable_dir).await,
+        );
+    }
+
+    let now = millis_from_epoch();
+    rows.retain(|row| {
+        !row.marked_for_deletion
+            && !table_schema.options.is_row_expired(row, now)
+            && row
+                .values
+                .get(&index_range.column)
+                .is_some_and(|value| index_range.contains(value))
+    });
+    sort_rows_by_index(&mut rows, &index_range.column);
+    rows
+}
+
+// values of unique columns the row takes, null values are never taken
+pub fn unique_values(table_schema: &TableSchema, row: &Row) -> Vec<(String, String)> {
+    table_schema
+        .options
+        .unique_indexes
+        .iter()
+        .filter_map(|column| {
+            let value = row.values.get(column).and_then(encode_index_value)?;
+            Some((column.clone(), value))
+        })
+        .collect()
+}
+
+// another visible row of the partition having a value of unique column the row takes, rows of
+// transactions are checked against rows committed by the time they are inserted
+pub async fn find_unique_violation(
+    table: &Table,
+    row: &Row,
+    partition: usize,
+    partition_map: &PartitionMap,
+) -> Option<String> {
+    for column in &table.table_schema.options.unique_indexes {
+        let Some(value) = row
+            .values
+            .get(column)
+            .filter(|value| **value != Value::Null)
+        else {
+            continue;
+        };
+        let index_range = IndexRange::new(column, value.clone()..=value.clone());
+        let partitions = HashSet::from([partition]);
+        let taken_by = query_index(table, &index_range, &partitions, partition_map)
+            .await
+            .into_iter()
+            .find(|taken_by| {
+                taken_by.primary_key != row.primary_key
+                    && partition_map.get_partition(&taken_by.hash_key) == partition
+            });
+        if let Some(taken_by) = taken_by {
+            return Some(format!(
+                "Value of unique column '{}' of table '{}' is already taken by row '{}'",
+                column, table.table_schema.name, taken_by.primary_key
+            ));
+        }
+    }
+    None
+}
+
+// rows of several threads are merged in the order each of them answers in
+pub fn sort_rows_by_index(rows: &mut [Row], column: &str) {
+    rows.sort_by_cached_key(|row| {
+        (
+            row.values.get(column).and_then(encode_index_value),
+            row.primary_key.clone(),
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commit_log::{CommitLog, PartialFlushes};
+    use crate::data_dir::DataDir;
+    use crate::sstable::{sort_rows_by_partition, SSTableSegment};
+    use crate::Memtable;
+    use common::value::Value::{Int32, Null, Varchar};
+
+    #[test]
+    fn memtable_index_answers_ranges_in_value_order() {
+        let mut index = MemtableIndex::new(&["age".to_string()]);
+        for (primary_key, age) in [
+            ("a", Int32(40)),
+            ("b", Int32(-5)),
+            ("c", Int32(30)),
+            ("d", Null),
+        ] {
+            index.add(primary_key, |_| Some(&age));
+        }
+        index.add("e", |_| None);
+
+        let primary_keys = |range: IndexRange| index.primary_keys(&range);
+        assert_eq!(
+            primary_keys(IndexRange::new("age", ..)),
+            vec!["b", "c", "a"]
+        );
+        assert_eq!(
+            primary_keys(IndexRange::new(
+                "age",
+                (Bound::Excluded(Int32(30)), Bound::Unbounded)
+            )),
+            vec!["a"]
+        );
+        assert_eq!(
+            primary_keys(IndexRange::new("age", Int32(-10)..=Int32(30))),
+            vec!["b", "c"]
+        );
+        assert!(primary_keys(IndexRange::new("age", Int32(41)..)).is_empty());
+        assert!(primary_keys(IndexRange::new("name", ..)).is_empty());
+
+        index.remove("c", |_| Some(&Int32(30)));
+        assert_eq!(
+            index.primary_keys(&IndexRange::new("age", Int32(0)..)),
+            vec!["a"]
+        );
+    }
+
+    #[test]
+    fn sstable_index_is_read_back_only_when_complete() {
+        let mut table_schema =
+            TableSchema::from_string("user>sort_key:INT32;name:VARCHAR(10)?").unwrap();
+        table_schema.add_index("name").unwrap();
+        let mut sstable_index = SSTableIndex::for_table(&table_schema).unwrap();
+        for (hash_key, name) in [("user_1", Varchar("ann".to_string())), ("user_2", Null)] {
+            let values = HashMap::from([("name".to_string(), name)]);
+            let row = Row::new(hash_key.to_string(), Int32(-1), values);
+            sstable_index.add_row(&crate::encode_row(&row, &table_schema), &table_schema);
+        }
+
+        let bytes = sstable_index.to_bytes();
+        let read_index = SSTableIndex::from_bytes(&bytes).unwrap();
+        assert_eq!(read_index.columns, vec!["name".to_string()]);
+        assert_eq!(read_index.entries.len(), 1);
+        assert_eq!(read_index.entries[0].hash_key, "user_1");
+        assert_eq!(read_index.entries[0].value, "ann");
+        assert!(SSTableIndex::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+    }
+
+    #[monoio::test]
+    async fn query_reads_latest_version_of_indexed_rows() {
+        let root = "/tmp/query_reads_latest_version_of_indexed_rows";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let mut table_schema =
+            TableSchema::from_string("user>sort_key:INT32;city:VARCHAR(10)?").unwrap();
+        table_schema.add_index("city").unwrap();
+        let partition_map = PartitionMap::new(1);
+        let city = |name: &str| Varchar(name.to_string());
+        let row = |hash_key: &str, city, timestamp| {
+            let values = HashMap::from([("city".to_string(), city)]);
+            Row::new_with_timestamp(hash_key.to_string(), Int32(-1), values, timestamp)
+        };
+
+        // older sstable lost its index, so it is scanned instead
+        let segments = [
+            (
+                100,
+                vec![row("a", city("berlin"), 10), row("d", city("paris"), 10)],
+            ),
+            (
+                200,
+                vec![
+                    row("b", city("oslo"), 20),
+                    row("c", city("rome"), 20),
+                    row("e", Null, 20),
+                ],
+            ),
+        ];
+        for (timestamp, mut rows) in segments {
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_timestamp(timestamp)
+                .write_to_disk(&data_dir.sstables)
+                .await
+                .unwrap();
+        }
+        let older_sstable = get_sstables_metadata("user", &data_dir.sstables)
+            .into_iter()
+            .find(|sstable_metadata| sstable_metadata.timestamp == 100)
+            .unwrap();
+        remove_sstable_index(&older_sstable, &data_dir.sstables);
+
+        let commit_log = CommitLog {
+            file: None,
+            file_path: "test".to_string(),
+            file_offset: 0,
+            table_schema: table_schema.clone(),
+            partition: 0,
+            closed: true,
+            partial_flushes: PartialFlushes::default(),
+        };
+        let mut table = Table::new(
+            Memtable::default(),
+            commit_log,
+            table_schema.clone(),
+            data_dir.clone(),
+        );
+        table.memtable.insert(row("a", city("vienna"), 30), false);
+        table.memtable.insert(row("d", city("athens"), 30), false);
+        let mut tombstone = row("c", Null, 30);
+        tombstone.marked_for_deletion = true;
+        table.memtable.insert(tombstone, false);
+
+        let rows = query_index(
+            &table,
+            &IndexRange::new("city", (Bound::Excluded(city("madrid")), Bound::Unbounded)),
+            &HashSet::from([0]),
+            &partition_map,
+        )
+        .await;
+        let hash_keys: Vec<_> = rows.iter().map(|row| row.hash_key.as_str()).collect();
+        assert_eq!(hash_keys, vec!["b", "a"]);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn unique_values_are_taken_by_rows_of_same_partition_only() {
+        let root = "/tmp/unique_values_are_taken_by_rows_of_same_partition_only";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+
+        let mut table_schema =
+            TableSchema::from_string("user>sort_key:INT32;email:VARCHAR(20)?").unwrap();
+        table_schema.add_unique_index("email").unwrap();
+        let partition_map = PartitionMap::new(4);
+        let email = |email: &str| Varchar(email.to_string());
+        let row = |hash_key: &str, email| {
+            let values = HashMap::from([("email".to_string(), email)]);
+            Row::new(hash_key.to_string(), Int32(-1), values)
+        };
+        let hash_keys: Vec<_> = (0..100).map(|number| format!("user_{}", number)).collect();
+        let partition = partition_map.get_partition(&hash_keys[0]);
+        let mut same_partition = hash_keys
+            .iter()
+            .filter(|hash_key| partition_map.get_partition(hash_key) == partition);
+        let (flushed, buffered, inserted) = (
+            same_partition.next().unwrap(),
+            same_partition.next().unwrap(),
+            same_partition.next().unwrap(),
+        );
+        let other_partition = hash_keys
+            .iter()
+            .find(|hash_key| partition_map.get_partition(hash_key) != partition)
+            .unwrap();
+
+        let mut rows = vec![row(flushed, email("ann@mail.com"))];
+        let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+        SSTableSegment::new(table_schema.clone(), rows, partition_index)
+            .write_to_disk(&data_dir.sstables)
+            .await
+            .unwrap();
+        let commit_log = CommitLog {
+            file: None,
+            file_path: "test".to_string(),
+            file_offset: 0,
+            table_schema: table_schema.clone(),
+            partition,
+            closed: true,
+            partial_flushes: PartialFlushes::default(),
+        };
+        let mut table = Table::new(
+            Memtable::default(),
+            commit_log,
+            table_schema.clone(),
+            data_dir.clone(),
+        );
+        table
+            .memtable
+            .insert(row(buffered, email("bob@mail.com")), false);
+
+        // rows keep their own values, rows of other partitions are checked by threads owning them
+        let cases = [
+            (row(inserted, email("ann@mail.com")), Some(flushed)),
+            (row(inserted, email("bob@mail.com")), Some(buffered)),
+            (row(inserted, email("eve@mail.com")), None),
+            (row(inserted, Null), None),
+            (row(buffered, email("bob@mail.com")), None),
+        ];
+        for (row, taken_by) in cases {
+            let violation = find_unique_violation(&table, &row, partition, &partition_map).await;
+            match taken_by {
+                Some(taken_by) => assert!(violation.unwrap().contains(taken_by.as_str())),
+                None => assert!(violation.is_none()),
+            }
+        }
+        let other = row(other_partition, email("ann@mail.com"));
+        let other_partition = partition_map.get_partition(&other.hash_key);
+        assert!(
+            find_unique_violation(&table, &other, other_partition, &partition_map)
+                .await
+                .is_none()
+        );
+        assert_eq!(
+            unique_values(&table_schema, &row(inserted, email("ann@mail.com"))),
+            vec![("email".to_string(), "ann@mail.com".to_string())]
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
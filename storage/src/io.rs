@@ -0,0 +1,207 @@
+use monoio::BufResult;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// thin layer over monoio::fs, tests and simulation can make chosen operations fail or stall
+pub struct File {
+    file: monoio::fs::File,
+    path: PathBuf,
+}
+
+pub struct OpenOptions {
+    options: monoio::fs::OpenOptions,
+}
+
+impl OpenOptions {
+    pub fn new() -> OpenOptions {
+        OpenOptions {
+            options: monoio::fs::OpenOptions::new(),
+        }
+    }
+
+    pub fn read(&mut self, read: bool) -> &mut OpenOptions {
+        self.options.read(read);
+        self
+    }
+
+    pub fn write(&mut self, write: bool) -> &mut OpenOptions {
+        self.options.write(write);
+        self
+    }
+
+    pub fn create(&mut self, create: bool) -> &mut OpenOptions {
+        self.options.create(create);
+        self
+    }
+
+    pub fn truncate(&mut self, truncate: bool) -> &mut OpenOptions {
+        self.options.truncate(truncate);
+        self
+    }
+
+    pub async fn open(&self, path: impl AsRef<Path>) -> io::Result<File> {
+        let path = path.as_ref().to_path_buf();
+        faults::check(IoOperation::Open, &path).await?;
+
+        Ok(File {
+            file: self.options.open(&path).await?,
+            path,
+        })
+    }
+}
+
+impl Default for OpenOptions {
+    fn default() -> OpenOptions {
+        OpenOptions::new()
+    }
+}
+
+impl File {
+    pub async fn read_at(&self, buffer: Vec<u8>, position: u64) -> BufResult<usize, Vec<u8>> {
+        if let Err(error) = faults::check(IoOperation::Read, &self.path).await {
+            return (Err(error), buffer);
+        }
+        self.file.read_at(buffer, position).await
+    }
+
+    pub async fn read_exact_at(&self, buffer: Vec<u8>, position: u64) -> BufResult<(), Vec<u8>> {
+        if let Err(error) = faults::check(IoOperation::Read, &self.path).await {
+            return (Err(error), buffer);
+        }
+        self.file.read_exact_at(buffer, position).await
+    }
+
+    pub async fn write_all_at(&self, buffer: Vec<u8>, position: u64) -> BufResult<(), Vec<u8>> {
+        if let Err(error) = faults::check(IoOperation::Write, &self.path).await {
+            return (Err(error), buffer);
+        }
+        self.file.write_all_at(buffer, position).await
+    }
+
+    pub async fn sync_all(&self) -> io::Result<()> {
+        faults::check(IoOperation::Sync, &self.path).await?;
+        self.file.sync_all().await
+    }
+
+    pub async fn close(self) -> io::Result<()> {
+        faults::check(IoOperation::Close, &self.path).await?;
+        self.file.close().await
+    }
+}
+
+// blocking, monoio does not provide a way to remove files
+pub fn remove_file(path: impl AsRef<Path>) -> io::Result<()> {
+    faults::check_blocking(IoOperation::Remove, path.as_ref())?;
+    std::fs::remove_file(path)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IoOperation {
+    Open,
+    Read,
+    // commit log appends and sstable writes
+    Write,
+    Sync,
+    Close,
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IoFaultEffect {
+    Fail(io::ErrorKind),
+    // operation is done once delay passes
+    Delay(std::time::Duration),
+}
+
+// applies to operations on files whose path contains given pattern,
+// tests running in parallel should use patterns unique to their directories
+#[derive(Debug, Clone, PartialEq)]
+pub struct IoFault {
+    pub operation: IoOperation,
+    pub path_pattern: String,
+    pub effect: IoFaultEffect,
+    // none means every matching operation is affected until faults are cleared
+    pub times: Option<usize>,
+}
+
+#[cfg(any(test, feature = "simulation"))]
+pub use faults::{clear_io_faults, inject_io_fault};
+
+#[cfg(any(test, feature = "simulation"))]
+mod faults {
+    use super::{IoFault, IoFaultEffect, IoOperation};
+    use std::io;
+    use std::path::Path;
+    use std::sync::RwLock;
+
+    static IO_FAULTS: RwLock<Vec<IoFault>> = RwLock::new(Vec::new());
+
+    pub fn inject_io_fault(fault: IoFault) {
+        IO_FAULTS.write().unwrap().push(fault);
+    }
+
+    pub fn clear_io_faults(path_pattern: &str) {
+        IO_FAULTS
+            .write()
+            .unwrap()
+            .retain(|fault| fault.path_pattern != path_pattern);
+    }
+
+    fn take_effect(operation: IoOperation, path: &Path) -> Option<IoFaultEffect> {
+        let path = path.to_string_lossy();
+        let mut faults = IO_FAULTS.write().unwrap();
+        let index = faults.iter().position(|fault| {
+            fault.operation == operation
+                && path.contains(&fault.path_pattern)
+                && fault.times != Some(0)
+        })?;
+
+        let fault = &mut faults[index];
+        if let Some(times) = fault.times.as_mut() {
+            *times -= 1;
+        }
+        Some(fault.effect.clone())
+    }
+
+    fn injected_error(operation: IoOperation, kind: io::ErrorKind) -> io::Error {
+        io::Error::new(kind, format!("Injected {:?} failure", operation))
+    }
+
+    pub async fn check(operation: IoOperation, path: &Path) -> io::Result<()> {
+        match take_effect(operation, path) {
+            Some(IoFaultEffect::Fail(kind)) => Err(injected_error(operation, kind)),
+            Some(IoFaultEffect::Delay(delay)) => {
+                monoio::time::sleep(delay).await;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn check_blocking(operation: IoOperation, path: &Path) -> io::Result<()> {
+        match take_effect(operation, path) {
+            Some(IoFaultEffect::Fail(kind)) => Err(injected_error(operation, kind)),
+            Some(IoFaultEffect::Delay(delay)) => {
+                std::thread::sleep(delay);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+// faults cannot be injected into regular builds, checks compile to nothing
+#[cfg(not(any(test, feature = "simulation")))]
+mod faults {
+    use super::IoOperation;
+    use std::io;
+    use std::path::Path;
+
+    pub async fn check(_operation: IoOperation, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    pub fn check_blocking(_operation: IoOperation, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+}
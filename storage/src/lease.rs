@@ -0,0 +1,56 @@
+use crate::util::millis_from_epoch;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lease {
+    // fencing token, grows with every acquisition of the lease
+    pub version: u64,
+    pub expires_at: u128,
+}
+
+impl Lease {
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= millis_from_epoch()
+    }
+
+    pub fn to_log_string(&self, name: &str) -> String {
+        format!("{}:{}:{}", self.version, self.expires_at, name)
+    }
+
+    pub fn from_log_string(log_string: &str) -> Result<(String, Lease), String> {
+        let mut parts = log_string.splitn(3, ':');
+        let (Some(version), Some(expires_at), Some(name)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Invalid lease record".to_string());
+        };
+
+        let lease = Lease {
+            version: version
+                .parse()
+                .map_err(|_| "Invalid lease version".to_string())?,
+            expires_at: expires_at
+                .parse()
+                .map_err(|_| "Invalid lease expiration".to_string())?,
+        };
+        Ok((name.to_string(), lease))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lease_log_string_round_trip() {
+        let lease = Lease {
+            version: 3,
+            expires_at: 1700000000000,
+        };
+        let log_string = lease.to_log_string("jobs:cleanup");
+
+        let (name, parsed_lease) = Lease::from_log_string(&log_string).unwrap();
+        assert_eq!(name, "jobs:cleanup");
+        assert_eq!(parsed_lease, lease);
+        assert!(parsed_lease.is_expired());
+    }
+}
@@ -1,13 +1,39 @@
+// default hash key limit, legacy rows have hash keys zero padded to that size
 pub static HASH_KEY_BYTE_SIZE: usize = 128;
 
+pub mod auth;
+pub mod cache;
 pub mod commit_log;
+mod compression;
+pub mod data_dir;
+pub mod delete_where;
+pub mod disk_usage;
+pub mod history;
+pub mod hooks;
+pub mod index;
+pub mod io;
+pub mod lease;
+pub mod log_syncer;
 mod memtable;
+pub mod migration;
+pub mod move_row;
+pub mod prepare_log;
+pub mod range_tombstone;
+pub mod replication;
 mod row;
+#[cfg(feature = "simulation")]
+pub mod simulation;
+pub mod snapshot;
 pub mod sstable;
+pub mod statistics;
+pub mod supervisor;
 pub mod table;
+pub mod table_options;
+pub mod throttle;
 pub mod transaction;
 mod util;
 pub mod validation;
 
-pub use memtable::{Memtable, MEGABYTE};
-pub use row::Row;
+pub use memtable::{Memtable, MemtableIter, MEGABYTE};
+pub use row::{encode_primary_key, Row};
+pub use util::{decode_row, encode_row, millis_from_epoch};
@@ -0,0 +1,209 @@
+use crate::commit_log::CommitLog;
+use crate::supervisor::{report_task_failure, report_task_success, BackgroundTask};
+use futures::channel::{mpsc, oneshot};
+use futures::lock::Mutex;
+use futures::StreamExt;
+use monoio::time::sleep;
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::Duration;
+
+// commit logs are owned by tables of a single thread, so each thread syncs its own logs
+#[derive(Default)]
+struct LogSyncer {
+    // commit log with offset up to which it was synced
+    commit_logs: Vec<(Arc<Mutex<CommitLog>>, u64)>,
+    // told whether every log got synced in the pass
+    waiters: Vec<oneshot::Sender<Result<(), String>>>,
+    wake_sender: Option<mpsc::UnboundedSender<()>>,
+}
+
+thread_local! {
+    static LOG_SYNCER: RefCell<LogSyncer> = RefCell::new(LogSyncer::default());
+}
+
+pub fn register_commit_log(commit_log: Arc<Mutex<CommitLog>>) {
+    LOG_SYNCER.with(|log_syncer| log_syncer.borrow_mut().commit_logs.push((commit_log, 0)));
+}
+
+// logs of a thread that panicked died with it, restarted thread registers its own
+pub fn reset_log_syncer() {
+    LOG_SYNCER.with(|log_syncer| *log_syncer.borrow_mut() = LogSyncer::default());
+}
+
+// waits until all commit logs of the thread are synced, concurrent callers share the same fsyncs
+pub async fn sync_commit_logs(commit_log: &Arc<Mutex<CommitLog>>) -> Result<(), String> {
+    sync_commit_logs_of(std::slice::from_ref(commit_log)).await
+}
+
+// one pass covers logs of several tables, without syncer they are synced one by one. Log deleted
+// after its rows got flushed has nothing left to sync
+pub async fn sync_commit_logs_of(commit_logs: &[Arc<Mutex<CommitLog>>]) -> Result<(), String> {
+    let (waiter, receiver) = oneshot::channel();
+    let woken = LOG_SYNCER.with(|log_syncer| {
+        let mut log_syncer = log_syncer.borrow_mut();
+        match &log_syncer.wake_sender {
+            Some(wake_sender) => {
+                let woken = wake_sender.unbounded_send(()).is_ok();
+                log_syncer.waiters.push(waiter);
+                woken
+            }
+            None => false,
+        }
+    });
+
+    if woken {
+        return receiver
+            .await
+            .map_err(|_| "Log syncer stopped before syncing".to_string())?;
+    }
+    // syncer is not running on this thread
+    for commit_log in commit_logs {
+        let commit_log = commit_log.lock().await;
+        if commit_log.file.is_some() {
+            commit_log.sync().await?;
+        }
+    }
+    Ok(())
+}
+
+// syncs every commit log written to since previous pass, either periodically or when woken by a waiter
+pub async fn log_syncer_main(interval: Duration) {
+    let (wake_sender, mut wake_receiver) = mpsc::unbounded();
+    LOG_SYNCER.with(|log_syncer| log_syncer.borrow_mut().wake_sender = Some(wake_sender));
+
+    loop {
+        monoio::select! {
+            _ = sleep(interval) => {}
+            Some(()) = wake_receiver.next() => {}
+        }
+        // waiters piled up while syncing are handled in one pass
+        while wake_receiver.try_recv().is_ok() {}
+
+        let (commit_logs, waiters) = LOG_SYNCER.with(|log_syncer| {
+            let mut log_syncer = log_syncer.borrow_mut();
+            (
+                std::mem::take(&mut log_syncer.commit_logs),
+                std::mem::take(&mut log_syncer.waiters),
+            )
+        });
+
+        let mut open_commit_logs = Vec::with_capacity(commit_logs.len());
+        let mut sync_result = Ok(());
+        for (commit_log, synced_offset) in commit_logs {
+            let guard = commit_log.lock().await;
+            // rotation can close a log right after writes waiting for this pass, so closed log is
+            // still synced, unless its rows got flushed and the log deleted meanwhile
+            let mut file_offset = guard.file_offset;
+            if file_offset > synced_offset && guard.file.is_some() {
+                let table_name = &guard.table_schema.name;
+                match guard.sync().await {
+                    Ok(()) => report_task_success(BackgroundTask::CommitLogSync, table_name),
+                    Err(error) => {
+                        tracing::error!("{}", error);
+                        report_task_failure(BackgroundTask::CommitLogSync, table_name, &error);
+                        file_offset = synced_offset;
+                        sync_result = Err(error);
+                    }
+                }
+            }
+            // failed log keeps its synced offset, so that the next pass retries it
+            let synced = file_offset == guard.file_offset;
+            let closed = guard.closed;
+            drop(guard);
+            if !closed || !synced {
+                open_commit_logs.push((commit_log, file_offset));
+            }
+        }
+
+        LOG_SYNCER.with(|log_syncer| {
+            // logs registered during the pass are kept
+            let mut log_syncer = log_syncer.borrow_mut();
+            open_commit_logs.append(&mut log_syncer.commit_logs);
+            log_syncer.commit_logs = open_commit_logs;
+        });
+
+        for waiter in waiters {
+            let _ = waiter.send(sync_result.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commit_log::PartialFlushes;
+    use crate::io::OpenOptions;
+    use crate::table::{ColumnType, TableSchema};
+
+    #[monoio::test]
+    async fn closed_log_written_since_last_pass_is_synced() {
+        reset_log_syncer();
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        // syncing a special file fails, so a sync attempt shows up in the result
+        let rotated_commit_log = CommitLog {
+            file: Some(
+                OpenOptions::new()
+                    .write(true)
+                    .open("/dev/null")
+                    .await
+                    .unwrap(),
+            ),
+            file_path: "/dev/null".to_string(),
+            file_offset: 10,
+            table_schema,
+            partition: 0,
+            closed: true,
+            partial_flushes: PartialFlushes::default(),
+        };
+        let rotated_commit_log = Arc::new(Mutex::new(rotated_commit_log));
+        register_commit_log(rotated_commit_log.clone());
+        monoio::spawn(log_syncer_main(Duration::from_secs(60)));
+        sleep(Duration::from_millis(10)).await;
+
+        assert!(sync_commit_logs(&rotated_commit_log).await.is_err());
+        // log deleted after its rows got flushed is no longer synced
+        rotated_commit_log.lock().await.file = None;
+        assert!(sync_commit_logs(&rotated_commit_log).await.is_ok());
+        assert!(LOG_SYNCER.with(|log_syncer| log_syncer.borrow().commit_logs.is_empty()));
+        reset_log_syncer();
+    }
+
+    #[monoio::test]
+    async fn every_log_is_synced_without_syncer() {
+        reset_log_syncer();
+        let root = "/tmp/every_log_is_synced_without_syncer";
+        let _ = std::fs::remove_dir_all(root);
+        std::fs::create_dir_all(root).unwrap();
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let open_log = |file_path: String| {
+            let table_schema = table_schema.clone();
+            async move {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .open(&file_path)
+                    .await
+                    .unwrap();
+                Arc::new(Mutex::new(CommitLog {
+                    file: Some(file),
+                    file_path,
+                    file_offset: 10,
+                    table_schema,
+                    partition: 0,
+                    closed: false,
+                    partial_flushes: PartialFlushes::default(),
+                }))
+            }
+        };
+        let first_commit_log = open_log(format!("{}/table-0-1", root)).await;
+        // syncing a special file fails, so a sync attempt shows up in the result
+        let second_commit_log = open_log("/dev/null".to_string()).await;
+        let commit_logs = [first_commit_log, second_commit_log.clone()];
+
+        assert!(sync_commit_logs_of(&commit_logs).await.is_err());
+        second_commit_log.lock().await.file = None;
+        assert!(sync_commit_logs_of(&commit_logs).await.is_ok());
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
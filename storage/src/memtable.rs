@@ -1,14 +1,25 @@
-use crate::util::millis_from_epoch;
+use crate::compression::{compress_value_above, decompress_value};
+use crate::index::{IndexRange, MemtableIndex};
+use crate::range_tombstone::{newest_covering, RangeTombstone};
+use crate::sstable::{sort_by_partition, sort_rows_by_partition};
+use crate::table::TableSchema;
+use crate::util::{encode_row_values, encode_value, millis_from_epoch};
 use crate::Row;
-use common::partition::get_hash_key_target_partition;
+use common::partition::PartitionMap;
+use common::value::Value;
 use get_size::GetSize;
 use rand::{thread_rng, Rng};
 use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::mem::size_of;
+use std::ops::Bound;
 use std::ptr::NonNull;
 
 pub static MEGABYTE: usize = usize::pow(2, 20);
 static MEMTABLE_MAX_SIZE_MEGABYTES: usize = 16;
+// tables with partial flush flush rows above that before memtable gets full
+static MEMTABLE_PARTIAL_FLUSH_SIZE_MEGABYTES: usize = 12;
+static MEMTABLE_PARTIAL_FLUSH_TARGET_MEGABYTES: usize = 8;
 
 type ListNode = NonNull<Node>;
 
@@ -18,30 +29,201 @@ pub struct Memtable {
     pub level_probability: f64,
     pub memory_size: usize,
     pub size: usize,
+    columns: ColumnNames,
+    index: MemtableIndex,
+    // rows of memtable are turned into tombstones by them right away, they are kept for keys
+    // memtable has no version of, so that reads do not find older versions in sstables
+    range_tombstones: Vec<RangeTombstone>,
+    compress_values_above: Option<usize>,
+}
+
+// names of columns are kept once per memtable, rows keep their values in a vec indexed by
+// column instead of a map holding its own copy of every name, columns are numbered in order
+// they were first inserted, so that rows of older and newer schemas share the same numbering
+#[derive(Debug, Default)]
+struct ColumnNames {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl ColumnNames {
+    fn intern(&mut self, name: String) -> usize {
+        if let Some(index) = self.indices.get(&name) {
+            return *index;
+        }
+        self.names.push(name.clone());
+        self.indices.insert(name, self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    // values of indexed columns are never compressed, so the index finds all of them
+    fn value<'a>(&self, row: &'a MemtableRow, name: &str) -> Option<&'a Value> {
+        let index = *self.indices.get(name)?;
+        match row.values.get(index)?.as_ref()? {
+            MemtableValue::Plain(value) => Some(value),
+            MemtableValue::Compressed(_) => None,
+        }
+    }
+}
+
+// compressed values are kept as they are encoded in rows, flag byte included
+#[derive(Debug, Clone)]
+enum MemtableValue {
+    Plain(Value),
+    Compressed(Vec<u8>),
+}
+
+impl MemtableValue {
+    fn to_value(&self) -> Value {
+        match self {
+            MemtableValue::Plain(value) => value.clone(),
+            MemtableValue::Compressed(bytes) => Value::Varchar(decompress_value(bytes).unwrap()),
+        }
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            MemtableValue::Plain(value) => value,
+            compressed => compressed.to_value(),
+        }
+    }
+
+    fn encode(&self, table_schema: &TableSchema) -> Vec<u8> {
+        match self {
+            MemtableValue::Plain(value) => encode_value(value, table_schema),
+            MemtableValue::Compressed(bytes) => bytes.clone(),
+        }
+    }
+}
+
+impl GetSize for MemtableValue {
+    fn get_size(&self) -> usize {
+        match self {
+            MemtableValue::Plain(value) => value.get_size(),
+            MemtableValue::Compressed(bytes) => bytes.get_size(),
+        }
+    }
+}
+
+// row as kept in memtable, turned back into row whenever it is read
+#[derive(Debug, Clone)]
+pub struct MemtableRow {
+    pub hash_key: String,
+    pub sort_key: Value,
+    pub primary_key: String,
+    // indexed by interned column, None for columns row has no value of
+    values: Vec<Option<MemtableValue>>,
+    pub version: u32,
+    pub timestamp: u128,
+    pub marked_for_deletion: bool,
+}
+
+impl Default for MemtableRow {
+    fn default() -> Self {
+        MemtableRow {
+            hash_key: "".to_string(),
+            sort_key: Value::Varchar("".to_string()),
+            primary_key: "".to_string(),
+            values: Vec::new(),
+            version: 1,
+            timestamp: millis_from_epoch(),
+            marked_for_deletion: false,
+        }
+    }
+}
+
+impl GetSize for MemtableRow {
+    fn get_size(&self) -> usize {
+        self.hash_key.get_size()
+            + self.sort_key.get_size()
+            + self.primary_key.get_size()
+            + size_of::<Vec<Option<MemtableValue>>>()
+            + self
+                .values
+                .iter()
+                .flatten()
+                .map(|value| value.get_size())
+                .sum::<usize>()
+    }
 }
 
 impl Memtable {
     pub fn new(max_level: usize, level_probability: f64) -> Memtable {
         Memtable {
-            head: Node::new(Row::default(), max_level),
+            head: Node::new(MemtableRow::default(), max_level),
             max_level,
             level_probability,
             memory_size: 0,
             size: 0,
+            columns: ColumnNames::default(),
+            index: MemtableIndex::default(),
+            range_tombstones: Vec::new(),
+            compress_values_above: None,
         }
     }
 
     pub fn default() -> Memtable {
         Memtable {
-            head: Node::new(Row::default(), 16),
+            head: Node::new(MemtableRow::default(), 16),
             max_level: 16,
             level_probability: 0.5,
             memory_size: 0,
             size: 0,
+            columns: ColumnNames::default(),
+            index: MemtableIndex::default(),
+            range_tombstones: Vec::new(),
+            compress_values_above: None,
+        }
+    }
+
+    // only the memtable of a table is indexed, memtables being flushed need no index
+    pub fn set_indexed_columns(&mut self, columns: &[String]) {
+        let mut index = MemtableIndex::new(columns);
+        for row in self.entries(Bound::Unbounded, Bound::Unbounded) {
+            if !row.marked_for_deletion {
+                index.add(&row.primary_key, |column| self.columns.value(row, column));
+            }
         }
+        self.index = index;
+    }
+
+    // only rows inserted afterwards are compressed, columns have to be indexed by then
+    pub fn set_value_compression(&mut self, compress_values_above: Option<usize>) {
+        self.compress_values_above = compress_values_above;
+    }
+
+    // primary keys of rows in range ordered by value, tombstones are not indexed
+    pub fn index_primary_keys(&self, index_range: &IndexRange) -> Vec<String> {
+        self.index.primary_keys(index_range)
     }
 
-    pub fn get(&self, primary_key: &String) -> Option<&Row> {
+    pub fn get(&self, primary_key: &String) -> Option<Row> {
+        self.find(primary_key)
+            .filter(|row| !row.marked_for_deletion)
+            .map(|row| self.to_row(row))
+    }
+
+    // unlike get, returns rows marked for deletion, so that callers know not to look further,
+    // keys covered by a range tombstone read as tombstones
+    pub fn get_entry(&self, primary_key: &String) -> Option<Row> {
+        match self.find(primary_key) {
+            Some(row) => Some(self.to_row(row)),
+            None => newest_covering(primary_key, &self.range_tombstones)
+                .map(|range_tombstone| range_tombstone.row_tombstone(primary_key)),
+        }
+    }
+
+    pub fn range_tombstones(&self) -> &[RangeTombstone] {
+        &self.range_tombstones
+    }
+
+    // values are not copied, for callers only asking whether row is there
+    pub fn contains(&self, primary_key: &String) -> bool {
+        self.find(primary_key)
+            .is_some_and(|row| !row.marked_for_deletion)
+    }
+
+    fn find(&self, primary_key: &String) -> Option<&MemtableRow> {
         let mut current = self.head;
 
         unsafe {
@@ -55,11 +237,8 @@ impl Memtable {
                 }
             }
 
-            if let Some(next_node) = (*current.as_ptr()).refs[0].clone() {
-                let node_dereferenced = &(*next_node.as_ptr());
-                if primary_key == &node_dereferenced.row.primary_key
-                    && !node_dereferenced.row.marked_for_deletion
-                {
+            if let Some(next_node) = (*current.as_ptr()).refs[0] {
+                if primary_key == &(*next_node.as_ptr()).row.primary_key {
                     return Some(&(*next_node.as_ptr()).row);
                 }
             }
@@ -68,7 +247,154 @@ impl Memtable {
         None
     }
 
-    pub fn insert(&mut self, mut row: Row, check_timestamp: bool) {
+    fn intern_row(&mut self, row: Row) -> MemtableRow {
+        let mut values = Vec::new();
+        for (name, value) in row.values {
+            let value = self.memtable_value(&name, value);
+            let index = self.columns.intern(name);
+            if values.len() <= index {
+                values.resize(index + 1, None);
+            }
+            values[index] = Some(value);
+        }
+        values.shrink_to_fit();
+
+        MemtableRow {
+            hash_key: row.hash_key,
+            sort_key: row.sort_key,
+            primary_key: row.primary_key,
+            values,
+            version: row.version,
+            timestamp: row.timestamp,
+            marked_for_deletion: row.marked_for_deletion,
+        }
+    }
+
+    fn memtable_value(&self, column: &str, value: Value) -> MemtableValue {
+        let compressed = match &value {
+            Value::Varchar(text) if !self.index.covers(column) => {
+                compress_value_above(text, self.compress_values_above)
+            }
+            _ => None,
+        };
+        match compressed {
+            Some(bytes) => MemtableValue::Compressed(bytes),
+            None => MemtableValue::Plain(value),
+        }
+    }
+
+    fn index_row(&mut self, row: &MemtableRow) {
+        if !row.marked_for_deletion {
+            let columns = &self.columns;
+            self.index
+                .add(&row.primary_key, |column| columns.value(row, column));
+        }
+    }
+
+    fn unindex_row(&mut self, row: &MemtableRow) {
+        let columns = &self.columns;
+        self.index
+            .remove(&row.primary_key, |column| columns.value(row, column));
+    }
+
+    fn to_row(&self, row: &MemtableRow) -> Row {
+        let values = row
+            .values
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| {
+                Some((
+                    self.columns.names[index].clone(),
+                    value.as_ref()?.to_value(),
+                ))
+            })
+            .collect();
+
+        Row {
+            hash_key: row.hash_key.clone(),
+            sort_key: row.sort_key.clone(),
+            primary_key: row.primary_key.clone(),
+            values,
+            version: row.version,
+            timestamp: row.timestamp,
+            marked_for_deletion: row.marked_for_deletion,
+        }
+    }
+
+    fn owned_row(&self, row: MemtableRow) -> Row {
+        let values = row
+            .values
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, value)| {
+                Some((self.columns.names[index].clone(), value?.into_value()))
+            })
+            .collect();
+
+        Row {
+            hash_key: row.hash_key,
+            sort_key: row.sort_key,
+            primary_key: row.primary_key,
+            values,
+            version: row.version,
+            timestamp: row.timestamp,
+            marked_for_deletion: row.marked_for_deletion,
+        }
+    }
+
+    // encoded straight from interned values, rows have to be of this memtable
+    fn encode_row(
+        &self,
+        row: &MemtableRow,
+        column_indices: &[Option<usize>],
+        table_schema: &TableSchema,
+    ) -> Vec<u8> {
+        let values = column_indices.iter().map(|index| {
+            index
+                .and_then(|index| row.values.get(index))
+                .and_then(Option::as_ref)
+                .map(|value| value.encode(table_schema))
+        });
+        encode_row_values(
+            &row.hash_key,
+            &row.sort_key,
+            values,
+            row.version,
+            row.timestamp,
+            row.marked_for_deletion,
+            table_schema,
+        )
+    }
+
+    // rows sorted by partition and encoded one at a time as they are written, along with their
+    // hash keys and partition index, memtable is not copied
+    pub(crate) fn encoded_sstable_rows<'a>(
+        &'a self,
+        table_schema: &'a TableSchema,
+        partition_map: &PartitionMap,
+    ) -> (
+        impl ExactSizeIterator<Item = (&'a str, Vec<u8>)> + 'a,
+        HashMap<usize, usize>,
+    ) {
+        let mut rows: Vec<_> = self.entries(Bound::Unbounded, Bound::Unbounded).collect();
+        let partition_index = sort_by_partition(&mut rows, |row| &row.hash_key, partition_map);
+        let column_indices: Vec<_> = table_schema
+            .columns
+            .keys()
+            .map(|name| self.columns.indices.get(name).copied())
+            .collect();
+
+        let encoded_rows = rows.into_iter().map(move |row| {
+            (
+                row.hash_key.as_str(),
+                self.encode_row(row, &column_indices, table_schema),
+            )
+        });
+        (encoded_rows, partition_index)
+    }
+
+    pub fn insert(&mut self, row: Row, check_timestamp: bool) {
+        let mut row = self.intern_row(row);
         let new_level = self.get_random_level();
         let update_vec = self.get_update_vec(&row.primary_key, new_level);
 
@@ -81,12 +407,15 @@ impl Memtable {
 
                     let version = (*next_node.as_ptr()).row.version;
                     row.version = version + 1;
-                    (*next_node.as_ptr()).row = row;
+                    self.unindex_row(&(*next_node.as_ptr()).row);
+                    self.index_row(&row);
+                    self.replace_row(next_node, row);
 
                     return;
                 }
             }
 
+            self.index_row(&row);
             let new_node = Node::new(row, new_level);
             for (level, placement_node) in update_vec.into_iter().rev().enumerate() {
                 (*new_node.as_ptr()).refs[level] = (*placement_node.as_ptr()).refs[level].clone();
@@ -112,18 +441,17 @@ impl Memtable {
                 }
             }
 
-            if let Some(next_node) = (*current.as_ptr()).refs[0].clone() {
-                if primary_key == &(*next_node.as_ptr()).row.primary_key {
-                    if let Some(timestamp) = timestamp {
-                        if (*next_node.as_ptr()).row.timestamp >= timestamp {
-                            return false;
-                        } else {
-                            (*next_node.as_ptr()).row.timestamp = timestamp;
-                        }
-                    } else {
-                        (*next_node.as_ptr()).row.timestamp = millis_from_epoch();
-                    }
-                    (*next_node.as_ptr()).row.marked_for_deletion = true;
+            if let Some(next_node) = (*current.as_ptr()).refs[0] {
+                let row = &(*next_node.as_ptr()).row;
+                if primary_key == &row.primary_key {
+                    let timestamp = match timestamp {
+                        Some(timestamp) if row.timestamp >= timestamp => return false,
+                        Some(timestamp) => timestamp,
+                        // row already deleted, so that callers do not count it twice
+                        None if row.marked_for_deletion => return false,
+                        None => millis_from_epoch(),
+                    };
+                    self.replace_with_tombstone(next_node, timestamp);
                     return true;
                 }
             }
@@ -132,10 +460,162 @@ impl Memtable {
         false
     }
 
+    // rows in range are turned into tombstones in order they were written, so that a row written
+    // in the same millisecond before the range tombstone is deleted too and one written after stays
+    pub fn delete_range(&mut self, range_tombstone: RangeTombstone) {
+        let mut current = match &range_tombstone.start {
+            Bound::Included(start) => self.first_node_from(start, true),
+            Bound::Excluded(start) => self.first_node_from(start, false),
+            Bound::Unbounded => unsafe { (*self.head.as_ptr()).refs[0] },
+        };
+
+        unsafe {
+            while let Some(node) = current {
+                current = (*node.as_ptr()).refs[0];
+                let row = &(*node.as_ptr()).row;
+                if !range_tombstone.contains(&row.primary_key) {
+                    break;
+                }
+                if !row.marked_for_deletion && row.timestamp <= range_tombstone.timestamp {
+                    self.replace_with_tombstone(node, range_tombstone.timestamp);
+                }
+            }
+        }
+
+        self.memory_size += range_tombstone.get_size();
+        self.range_tombstones.push(range_tombstone);
+    }
+
+    // tombstone only shadows older versions, so its values are freed
+    unsafe fn replace_with_tombstone(&mut self, node: ListNode, timestamp: u128) {
+        let row = &(*node.as_ptr()).row;
+        let tombstone = MemtableRow {
+            hash_key: row.hash_key.clone(),
+            sort_key: row.sort_key.clone(),
+            primary_key: row.primary_key.clone(),
+            values: Vec::new(),
+            version: row.version,
+            timestamp,
+            marked_for_deletion: true,
+        };
+        self.unindex_row(row);
+        self.replace_row(node, tombstone);
+    }
+
+    // memory size is adjusted by difference between versions, so that overwrites do not drift it
+    unsafe fn replace_row(&mut self, node: ListNode, row: MemtableRow) {
+        self.memory_size -= (*node.as_ptr()).get_memory_size();
+        (*node.as_ptr()).row = row;
+        self.memory_size += (*node.as_ptr()).get_memory_size();
+    }
+
     pub fn max_size_reached(&self) -> bool {
         self.memory_size > MEMTABLE_MAX_SIZE_MEGABYTES * MEGABYTE
     }
 
+    pub fn partial_flush_reached(&self) -> bool {
+        self.memory_size > MEMTABLE_PARTIAL_FLUSH_SIZE_MEGABYTES * MEGABYTE
+    }
+
+    pub fn split_off_partial_flush(&mut self) -> Memtable {
+        self.split_off_first(MEMTABLE_PARTIAL_FLUSH_TARGET_MEGABYTES * MEGABYTE)
+    }
+
+    pub fn last_primary_key(&self) -> Option<String> {
+        let mut current = self.head;
+        unsafe {
+            for level in (0..self.max_level).rev() {
+                while let Some(next_node) = (*current.as_ptr()).refs[level] {
+                    current = next_node;
+                }
+            }
+            match current == self.head {
+                true => None,
+                false => Some((*current.as_ptr()).row.primary_key.clone()),
+            }
+        }
+    }
+
+    // rows with lowest primary keys are moved out until memory size drops to given size, range
+    // tombstones stay until the whole memtable is flushed
+    fn split_off_first(&mut self, memory_size: usize) -> Memtable {
+        let mut first_rows = Memtable::new(self.max_level, self.level_probability);
+        while self.memory_size > memory_size {
+            match self.pop_first() {
+                Some(row) => first_rows.insert(row, false),
+                None => break,
+            }
+        }
+
+        first_rows
+    }
+
+    // copies all rows including tombstones, memtable stays untouched
+    pub fn rows(&self) -> Vec<Row> {
+        self.iter().collect()
+    }
+
+    // tombstones are counted too, as they take space until compaction
+    pub fn count_rows_per_partition(&self, partition_map: &PartitionMap) -> HashMap<usize, usize> {
+        let mut rows_per_partition = HashMap::new();
+        for row in self.entries(Bound::Unbounded, Bound::Unbounded) {
+            let partition = partition_map.get_partition(&row.hash_key);
+            *rows_per_partition.entry(partition).or_insert(0) += 1;
+        }
+
+        rows_per_partition
+    }
+
+    // rows ordered by primary key, tombstones included
+    pub fn iter(&self) -> MemtableIter<'_> {
+        self.range(Bound::Unbounded, Bound::Unbounded)
+    }
+
+    pub fn range(&self, start: Bound<&str>, end: Bound<&str>) -> MemtableIter<'_> {
+        MemtableIter {
+            entries: self.entries(start, end),
+            memtable: self,
+        }
+    }
+
+    fn entries(&self, start: Bound<&str>, end: Bound<&str>) -> MemtableEntries<'_> {
+        let current = match start {
+            Bound::Included(start) => self.first_node_from(start, true),
+            Bound::Excluded(start) => self.first_node_from(start, false),
+            Bound::Unbounded => unsafe { (*self.head.as_ptr()).refs[0] },
+        };
+        MemtableEntries {
+            current,
+            end: end.map(str::to_string),
+            _memtable: PhantomData,
+        }
+    }
+
+    // rows of a hash key are found with "{hash_key}:" prefix
+    pub fn prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = Row> + 'a {
+        self.range(Bound::Included(prefix), Bound::Unbounded)
+            .take_while(move |row| row.primary_key.starts_with(prefix))
+    }
+
+    fn first_node_from(&self, primary_key: &str, inclusive: bool) -> Option<ListNode> {
+        let mut current = self.head;
+
+        unsafe {
+            for level in (0..self.max_level).rev() {
+                while let Some(next_node) = (*current.as_ptr()).refs[level] {
+                    let next_key = (*next_node.as_ptr()).row.primary_key.as_str();
+                    if next_key < primary_key || (!inclusive && next_key == primary_key) {
+                        current = next_node;
+                    } else {
+                        break;
+                    }
+                }
+            }
+
+            (*current.as_ptr()).refs[0]
+        }
+    }
+
     fn get_update_vec(&mut self, primary_key: &String, level_limit: usize) -> Vec<ListNode> {
         let mut update_vec = Vec::with_capacity(self.max_level);
 
@@ -171,33 +651,104 @@ impl Memtable {
 
     pub fn to_sstable_rows(
         self,
-        num_of_partitions: usize,
-        is_compaction: bool,
+        partition_map: &PartitionMap,
     ) -> (Vec<Row>, HashMap<usize, usize>) {
-        let mut partition_index = HashMap::new();
-        let mut rows = Vec::with_capacity(self.size);
+        let mut rows: Vec<_> = self.into_iter().collect();
 
-        let mut row_number = 0usize;
+        let partition_index = sort_rows_by_partition(&mut rows, partition_map);
+        (rows, partition_index)
+    }
+
+    // unlinks first node from every level it is on, so that the list stays valid if caller stops halfway
+    fn pop_first(&mut self) -> Option<Row> {
         unsafe {
-            let mut current = (*self.head.as_ptr()).refs[0];
-            while let Some(current_node) = current {
-                let boxed_node = Box::from_raw(current_node.as_ptr());
-                current = boxed_node.refs[0];
+            let first_node = (*self.head.as_ptr()).refs[0]?;
+            let boxed_node = Box::from_raw(first_node.as_ptr());
+            for (level, next_node) in boxed_node.refs.iter().enumerate() {
+                (*self.head.as_ptr()).refs[level] = *next_node;
+            }
 
-                if is_compaction && boxed_node.row.marked_for_deletion {
-                    continue;
-                }
+            self.unindex_row(&boxed_node.row);
+            self.memory_size -= boxed_node.get_memory_size();
+            self.size -= 1;
+            Some(self.owned_row(boxed_node.row))
+        }
+    }
+}
+
+pub struct MemtableIter<'a> {
+    entries: MemtableEntries<'a>,
+    memtable: &'a Memtable,
+}
 
-                let row_partition =
-                    get_hash_key_target_partition(&boxed_node.row.hash_key, num_of_partitions);
-                partition_index.entry(row_partition).or_insert(row_number);
+impl Iterator for MemtableIter<'_> {
+    type Item = Row;
 
-                rows.push(boxed_node.row);
-                row_number += 1;
-            }
+    fn next(&mut self) -> Option<Row> {
+        let row = self.entries.next()?;
+        Some(self.memtable.to_row(row))
+    }
+}
+
+struct MemtableEntries<'a> {
+    current: Option<ListNode>,
+    end: Bound<String>,
+    // nodes are freed only by methods taking memtable mutably, so they outlive the borrow
+    _memtable: PhantomData<&'a Memtable>,
+}
+
+impl<'a> Iterator for MemtableEntries<'a> {
+    type Item = &'a MemtableRow;
+
+    fn next(&mut self) -> Option<&'a MemtableRow> {
+        let current_node = self.current?;
+        let row = unsafe { &(*current_node.as_ptr()).row };
+        let in_range = match &self.end {
+            Bound::Included(end) => &row.primary_key <= end,
+            Bound::Excluded(end) => &row.primary_key < end,
+            Bound::Unbounded => true,
+        };
+        if !in_range {
+            self.current = None;
+            return None;
         }
 
-        (rows, partition_index)
+        self.current = unsafe { (*current_node.as_ptr()).refs[0] };
+        Some(row)
+    }
+}
+
+pub struct MemtableIntoIter {
+    memtable: Memtable,
+}
+
+impl Iterator for MemtableIntoIter {
+    type Item = Row;
+
+    fn next(&mut self) -> Option<Row> {
+        self.memtable.pop_first()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.memtable.size, Some(self.memtable.size))
+    }
+}
+
+impl IntoIterator for Memtable {
+    type Item = Row;
+    type IntoIter = MemtableIntoIter;
+
+    fn into_iter(self) -> MemtableIntoIter {
+        MemtableIntoIter { memtable: self }
+    }
+}
+
+impl<'a> IntoIterator for &'a Memtable {
+    type Item = Row;
+    type IntoIter = MemtableIter<'a>;
+
+    fn into_iter(self) -> MemtableIter<'a> {
+        self.iter()
     }
 }
 
@@ -214,12 +765,12 @@ impl Drop for Memtable {
 }
 
 pub struct Node {
-    pub row: Row,
+    pub row: MemtableRow,
     pub refs: Vec<Option<ListNode>>,
 }
 
 impl Node {
-    pub fn new(row: Row, level: usize) -> ListNode {
+    pub fn new(row: MemtableRow, level: usize) -> ListNode {
         let boxed_node = Box::new(Node {
             row,
             refs: (0..level).map(|_| None).collect(),
@@ -233,3 +784,386 @@ impl Node {
             + size_of::<Option<ListNode>>() * self.refs.capacity()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode_primary_key;
+    use crate::util::encode_row;
+    use common::value::Value::{Int32, Varchar};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::BTreeMap;
+
+    // every level is sorted and contained in the level below, counters match the list
+    fn check_invariants(memtable: &Memtable) {
+        unsafe {
+            for level in 0..memtable.max_level {
+                let mut previous_key: Option<&String> = None;
+                let mut current = (*memtable.head.as_ptr()).refs[level];
+                let mut lower_level = (*memtable.head.as_ptr()).refs[level.saturating_sub(1)];
+
+                while let Some(current_node) = current {
+                    let node = &*current_node.as_ptr();
+                    assert!(node.refs.len() > level);
+                    if let Some(previous_key) = previous_key {
+                        assert!(previous_key < &node.row.primary_key);
+                    }
+                    previous_key = Some(&node.row.primary_key);
+
+                    while lower_level != Some(current_node) {
+                        lower_level = (*lower_level.expect("node missing on lower level").as_ptr())
+                            .refs[level.saturating_sub(1)];
+                    }
+                    current = node.refs[level];
+                }
+            }
+        }
+
+        let mut memory_size: usize = memtable
+            .range_tombstones
+            .iter()
+            .map(|range_tombstone| range_tombstone.get_size())
+            .sum();
+        unsafe {
+            let mut current = (*memtable.head.as_ptr()).refs[0];
+            while let Some(current_node) = current {
+                memory_size += (*current_node.as_ptr()).get_memory_size();
+                current = (*current_node.as_ptr()).refs[0];
+            }
+        }
+        assert_eq!(memtable.iter().count(), memtable.size);
+        assert_eq!(memtable.memory_size, memory_size);
+    }
+
+    fn memtable_with_keys(hash_keys: &[&str]) -> Memtable {
+        let mut memtable = Memtable::default();
+        for hash_key in hash_keys {
+            for sort_key in 0..2 {
+                let row = Row::new(hash_key.to_string(), Int32(sort_key), HashMap::new());
+                memtable.insert(row, false);
+            }
+        }
+        memtable
+    }
+
+    fn key(hash_key: &str, sort_key: i32) -> String {
+        encode_primary_key(hash_key, &Int32(sort_key))
+    }
+
+    fn primary_keys(rows: impl Iterator<Item = Row>) -> Vec<String> {
+        rows.map(|row| row.primary_key).collect()
+    }
+
+    #[test]
+    fn rows_are_iterated_in_order_within_range() {
+        let mut memtable = memtable_with_keys(&["c", "a", "b"]);
+        memtable.delete(&key("b", 0), None);
+
+        assert_eq!(
+            primary_keys(memtable.iter()),
+            vec![
+                key("a", 0),
+                key("a", 1),
+                key("b", 0),
+                key("b", 1),
+                key("c", 0),
+                key("c", 1)
+            ]
+        );
+        assert_eq!(
+            primary_keys(memtable.range(
+                Bound::Included(key("a", 1).as_str()),
+                Bound::Excluded(key("c", 0).as_str())
+            )),
+            vec![key("a", 1), key("b", 0), key("b", 1)]
+        );
+        assert_eq!(
+            primary_keys(memtable.range(
+                Bound::Included(key("a", 1).as_str()),
+                Bound::Included(key("c", 0).as_str())
+            )),
+            vec![key("a", 1), key("b", 0), key("b", 1), key("c", 0)]
+        );
+        assert_eq!(
+            primary_keys(memtable.range(Bound::Excluded(key("b", 0).as_str()), Bound::Unbounded)),
+            vec![key("b", 1), key("c", 0), key("c", 1)]
+        );
+        assert_eq!(
+            primary_keys(memtable.prefix("b:")),
+            vec![key("b", 0), key("b", 1)]
+        );
+        assert!(memtable.iter().any(|row| row.marked_for_deletion));
+        assert_eq!(memtable.prefix("d:").count(), 0);
+    }
+
+    #[test]
+    fn range_tombstone_deletes_rows_written_before_it() {
+        let mut memtable = memtable_with_keys(&["a", "b"]);
+        let timestamp = memtable.get(&key("b", 1)).unwrap().timestamp;
+        memtable.delete_range(RangeTombstone {
+            hash_key: "b".to_string(),
+            start: Bound::Included("b:".to_string()),
+            end: Bound::Excluded("b;".to_string()),
+            timestamp,
+        });
+        check_invariants(&memtable);
+
+        assert!(memtable.get(&key("b", 0)).is_none());
+        assert!(
+            memtable
+                .get_entry(&key("b", 1))
+                .unwrap()
+                .marked_for_deletion
+        );
+        // keys memtable has no version of read as tombstones, so that sstables are not consulted
+        let tombstone = memtable.get_entry(&key("b", 7)).unwrap();
+        assert!(tombstone.marked_for_deletion);
+        assert_eq!(tombstone.timestamp, timestamp);
+        assert!(memtable.get_entry(&key("c", 0)).is_none());
+        assert!(memtable.get(&key("a", 1)).is_some());
+
+        let row = Row::new_with_timestamp("b".to_string(), Int32(0), HashMap::new(), timestamp);
+        memtable.insert(row, false);
+        assert!(memtable.get(&key("b", 0)).is_some());
+        check_invariants(&memtable);
+    }
+
+    #[test]
+    fn consuming_memtable_keeps_remaining_rows_valid() {
+        let memtable = memtable_with_keys(&["a", "b"]);
+        let mut rows = memtable.into_iter();
+
+        assert_eq!(rows.next().unwrap().primary_key, key("a", 0));
+        assert_eq!(rows.size_hint(), (3, Some(3)));
+        // dropped halfway, remaining nodes are freed once
+        drop(rows);
+
+        let memtable = memtable_with_keys(&["a", "b"]);
+        let (rows, _) = memtable.to_sstable_rows(&PartitionMap::new(1));
+        assert_eq!(rows.len(), 4);
+    }
+
+    #[test]
+    fn first_rows_are_split_off_until_memory_size_drops() {
+        let mut memtable = memtable_with_keys(&["c", "a", "b"]);
+        memtable.delete(&key("a", 1), None);
+        let first_nodes_memory_size = unsafe {
+            let first_node = (*memtable.head.as_ptr()).refs[0].unwrap();
+            let second_node = (*first_node.as_ptr()).refs[0].unwrap();
+            (*first_node.as_ptr()).get_memory_size() + (*second_node.as_ptr()).get_memory_size()
+        };
+
+        let first_rows = memtable.split_off_first(memtable.memory_size - first_nodes_memory_size);
+        check_invariants(&memtable);
+        check_invariants(&first_rows);
+
+        assert_eq!(
+            primary_keys(first_rows.iter()),
+            vec![key("a", 0), key("a", 1)]
+        );
+        assert!(first_rows.iter().last().unwrap().marked_for_deletion);
+        assert_eq!(
+            primary_keys(memtable.iter()),
+            vec![key("b", 0), key("b", 1), key("c", 0), key("c", 1)]
+        );
+    }
+
+    #[test]
+    fn index_follows_overwrites_deletes_and_split_off_rows() {
+        let mut memtable = memtable_with_keys(&["a"]);
+        memtable.set_indexed_columns(&["city".to_string()]);
+        let row = |hash_key: &str, sort_key: i32, city: &str| {
+            let values = HashMap::from([("city".to_string(), Varchar(city.to_string()))]);
+            Row::new(hash_key.to_string(), Int32(sort_key), values)
+        };
+        let cities = |memtable: &Memtable| {
+            memtable.index_primary_keys(&IndexRange::new("city", Varchar("m".to_string())..))
+        };
+
+        memtable.insert(row("a", 0, "oslo"), false);
+        memtable.insert(row("b", 0, "rome"), false);
+        memtable.insert(row("c", 0, "paris"), false);
+        assert_eq!(
+            cities(&memtable),
+            vec![key("a", 0), key("c", 0), key("b", 0)]
+        );
+
+        memtable.insert(row("a", 0, "berlin"), false);
+        memtable.delete(&key("c", 0), None);
+        assert_eq!(cities(&memtable), vec![key("b", 0)]);
+
+        let first_rows = memtable.split_off_first(0);
+        assert!(cities(&memtable).is_empty());
+        assert!(cities(&first_rows).is_empty());
+    }
+
+    #[test]
+    fn rows_keep_their_values_under_interned_columns() {
+        let mut memtable = Memtable::default();
+        let first_values = HashMap::from([
+            ("name".to_string(), Varchar("alice".to_string())),
+            ("age".to_string(), Int32(30)),
+        ]);
+        // column unknown to other rows and one of them left out
+        let second_values = HashMap::from([
+            ("name".to_string(), Varchar("bob".to_string())),
+            ("nickname".to_string(), Varchar("b".to_string())),
+        ]);
+        let first_row = Row::new("a".to_string(), Int32(-1), first_values.clone());
+        let second_row = Row::new("b".to_string(), Int32(-1), second_values.clone());
+        // names are not kept by every row
+        let row_size = first_row.get_size();
+        assert!(memtable.intern_row(first_row.clone()).get_size() < row_size);
+        memtable.insert(first_row, false);
+        memtable.insert(second_row, false);
+
+        assert_eq!(memtable.columns.names.len(), 3);
+        assert_eq!(
+            memtable.get(&"a:7fffffff".to_string()).unwrap().values,
+            first_values
+        );
+        assert_eq!(
+            memtable.get(&"b:7fffffff".to_string()).unwrap().values,
+            second_values
+        );
+        assert!(memtable.contains(&"a:7fffffff".to_string()));
+    }
+
+    #[test]
+    fn large_text_of_columns_not_indexed_is_kept_compressed() {
+        let table_schema = TableSchema::from_string(
+            "table>sort_key:INT32;city:VARCHAR(300);bio:VARCHAR(100)#compress_values_above=64,index=city",
+        )
+        .unwrap();
+        let mut memtable = Memtable::default();
+        memtable.set_indexed_columns(&table_schema.options.indexes);
+        memtable.set_value_compression(table_schema.options.compress_values_above);
+
+        let text = "bio text ".repeat(30);
+        let values = HashMap::from([
+            ("city".to_string(), Varchar(text.clone())),
+            ("bio".to_string(), Varchar(text.clone())),
+        ]);
+        let row = Row::new("a".to_string(), Int32(-1), values.clone());
+        let plain_size = Memtable::default().intern_row(row.clone()).get_size();
+        assert!(memtable.intern_row(row.clone()).get_size() < plain_size - text.len() / 2);
+        memtable.insert(row.clone(), false);
+
+        assert_eq!(memtable.get(&row.primary_key).unwrap().values, values);
+        assert_eq!(
+            memtable.index_primary_keys(&IndexRange::new(
+                "city",
+                Varchar(text.clone())..=Varchar(text.clone())
+            )),
+            vec![row.primary_key.clone()]
+        );
+        let partition_map = PartitionMap::new(1);
+        let (mut rows, _) = memtable.encoded_sstable_rows(&table_schema, &partition_map);
+        assert_eq!(rows.next().unwrap().1, encode_row(&row, &table_schema));
+    }
+
+    #[test]
+    fn rows_are_encoded_straight_from_memtable() {
+        let table_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:INT32?;name:VARCHAR(10)").unwrap();
+        let mut memtable = Memtable::default();
+        for (hash_key, age) in [("a", 1), ("b", 2), ("c", 3)] {
+            let values = HashMap::from([
+                ("name".to_string(), Varchar(hash_key.to_string())),
+                ("age".to_string(), Int32(age)),
+            ]);
+            memtable.insert(Row::new(hash_key.to_string(), Int32(-1), values), false);
+        }
+        // tombstones have no values, their columns are left zeroed
+        memtable.delete(&"b:7fffffff".to_string(), None);
+
+        let partition_map = PartitionMap::new(2);
+        let expected_rows: Vec<_> = memtable.rows();
+        let (rows, partition_index) = memtable.encoded_sstable_rows(&table_schema, &partition_map);
+        let rows: Vec<_> = rows.collect();
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(partition_index.values().min(), Some(&0));
+        for (hash_key, row_bytes) in rows {
+            let expected_row = expected_rows
+                .iter()
+                .find(|row| row.hash_key == hash_key)
+                .unwrap();
+            let expected_bytes = match expected_row.marked_for_deletion {
+                true => {
+                    let mut expected_row = expected_row.clone();
+                    expected_row.values = HashMap::from([
+                        ("name".to_string(), Varchar("".to_string())),
+                        ("age".to_string(), Value::Null),
+                    ]);
+                    encode_row(&expected_row, &table_schema)
+                }
+                false => encode_row(expected_row, &table_schema),
+            };
+            assert_eq!(row_bytes, expected_bytes);
+        }
+    }
+
+    #[test]
+    fn random_operations_match_ordered_map() {
+        let mut rng = StdRng::seed_from_u64(1186);
+
+        for _ in 0..20 {
+            let mut memtable = Memtable::new(8, 0.5);
+            // primary key -> value and whether it is deleted
+            let mut expected: BTreeMap<String, (String, bool)> = BTreeMap::new();
+
+            for step in 0..300 {
+                let hash_key = format!("{}", rng.gen_range(0..40));
+                let primary_key = encode_primary_key(&hash_key, &Int32(0));
+                match rng.gen_range(0..3) {
+                    0 | 1 => {
+                        let value = "x".repeat(rng.gen_range(0..32));
+                        let values = HashMap::from([("value".to_string(), Varchar(value.clone()))]);
+                        memtable.insert(Row::new(hash_key, Int32(0), values), false);
+                        expected.insert(primary_key, (value, false));
+                    }
+                    _ => {
+                        let deleted = memtable.delete(&primary_key, None);
+                        let expected_deleted = match expected.get_mut(&primary_key) {
+                            Some((_, deleted)) if !*deleted => {
+                                *deleted = true;
+                                true
+                            }
+                            _ => false,
+                        };
+                        assert_eq!(deleted, expected_deleted);
+                    }
+                }
+
+                if step % 50 == 0 {
+                    check_invariants(&memtable);
+                }
+            }
+            check_invariants(&memtable);
+
+            for (primary_key, (value, deleted)) in &expected {
+                let row = memtable.get_entry(primary_key).unwrap();
+                assert_eq!(row.marked_for_deletion, *deleted);
+                match deleted {
+                    true => assert!(row.values.is_empty()),
+                    false => assert_eq!(row.values["value"], Varchar(value.clone())),
+                }
+                assert_eq!(memtable.get(primary_key).is_some(), !deleted);
+            }
+            assert_eq!(
+                primary_keys(memtable.iter()),
+                expected.keys().cloned().collect::<Vec<_>>()
+            );
+
+            // consuming half of memtable keeps the rest consistent
+            let mut rows = memtable.into_iter();
+            for _ in 0..expected.len() / 2 {
+                rows.next().unwrap();
+            }
+            check_invariants(&rows.memtable);
+            assert_eq!(rows.count(), expected.len() - expected.len() / 2);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::table::{Column, ColumnType, TableSchema};
+use crate::table_options::TableOptions;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationStep {
+    CreateTable(String),
+    AddColumn(String, Column),
+    DropColumn(String),
+    WidenVarchar(String, usize),
+    MakeNullable(String),
+    SetOptions(String),
+}
+
+impl Display for MigrationStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationStep::CreateTable(schema_string) => {
+                write!(f, "create table {}", schema_string)
+            }
+            MigrationStep::AddColumn(name, column) => write!(f, "add column '{}' {}", name, column),
+            MigrationStep::DropColumn(name) => write!(f, "drop column '{}'", name),
+            MigrationStep::WidenVarchar(name, max_length) => {
+                write!(f, "widen column '{}' to VARCHAR({})", name, max_length)
+            }
+            MigrationStep::MakeNullable(name) => write!(f, "make column '{}' nullable", name),
+            MigrationStep::SetOptions(options) => write!(f, "set table options '{}'", options),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationPlan {
+    pub table_name: String,
+    pub steps: Vec<MigrationStep>,
+}
+
+impl MigrationPlan {
+    pub fn create_table(table_schema: &TableSchema) -> MigrationPlan {
+        MigrationPlan {
+            table_name: table_schema.name.clone(),
+            steps: vec![MigrationStep::CreateTable(table_schema.to_string())],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl Display for MigrationPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let steps: Vec<_> = self.steps.iter().map(|step| step.to_string()).collect();
+        write!(f, "{}", steps.join(", "))
+    }
+}
+
+pub fn plan_migration(
+    current_schema: &TableSchema,
+    target_schema: &TableSchema,
+) -> Result<MigrationPlan, String> {
+    if current_schema.name != target_schema.name {
+        return Err(format!(
+            "Cannot migrate table '{}' into table '{}'",
+            &current_schema.name, &target_schema.name
+        ));
+    }
+
+    let mut errors = Vec::new();
+    let mut steps = Vec::new();
+
+    if current_schema.sort_key_type != target_schema.sort_key_type {
+        errors.push(format!(
+            "'sort_key': cannot change type from '{}' to '{}'",
+            &current_schema.sort_key_type, &target_schema.sort_key_type
+        ));
+    }
+
+    // rows of other tables get overwritten and deleted, which append-only tables cannot merge
+    if current_schema.options.append_only != target_schema.options.append_only {
+        errors.push("'append_only': cannot be changed for existing table".to_string());
+    }
+
+    if current_schema.hash_key_size() != target_schema.hash_key_size() {
+        errors.push(format!(
+            "'hash_key': cannot change size from {} to {} bytes",
+            current_schema.hash_key_size(),
+            target_schema.hash_key_size()
+        ));
+    }
+
+    for (column_name, target_column) in &target_schema.columns {
+        let current_column = match current_schema.columns.get(column_name) {
+            Some(current_column) => current_column,
+            None => {
+                if target_column.nullable {
+                    steps.push(MigrationStep::AddColumn(
+                        column_name.clone(),
+                        target_column.clone(),
+                    ));
+                } else {
+                    errors.push(format!(
+                        "'{}': cannot add non nullable column to existing table",
+                        column_name
+                    ));
+                }
+                continue;
+            }
+        };
+
+        match (&current_column.column_type, &target_column.column_type) {
+            (ColumnType::Varchar(current_length), ColumnType::Varchar(target_length)) => {
+                if target_length > current_length {
+                    steps.push(MigrationStep::WidenVarchar(
+                        column_name.clone(),
+                        *target_length,
+                    ));
+                } else if target_length < current_length {
+                    errors.push(format!(
+                        "'{}': cannot narrow from '{}' to '{}'",
+                        column_name, &current_column.column_type, &target_column.column_type
+                    ));
+                }
+            }
+            (current_type, target_type) => {
+                if current_type != target_type {
+                    errors.push(format!(
+                        "'{}': cannot change type from '{}' to '{}'",
+                        column_name, current_type, target_type
+                    ));
+                }
+            }
+        }
+
+        if !current_column.nullable && target_column.nullable {
+            steps.push(MigrationStep::MakeNullable(column_name.clone()));
+        } else if current_column.nullable && !target_column.nullable {
+            errors.push(format!(
+                "'{}': cannot make column non nullable",
+                column_name
+            ));
+        }
+    }
+
+    for column_name in current_schema.columns.keys() {
+        if !target_schema.columns.contains_key(column_name) {
+            steps.push(MigrationStep::DropColumn(column_name.clone()));
+        }
+    }
+
+    // read-only flag is kept as it is, models do not declare it,
+    // metadata does not affect data, so sync model updates it without migration
+    let target_options = TableOptions {
+        read_only: current_schema.options.read_only,
+        metadata: current_schema.options.metadata.clone(),
+        ..target_schema.options.clone()
+    };
+    if current_schema.options != target_options {
+        steps.push(MigrationStep::SetOptions(target_options.to_string()));
+    }
+
+    if !errors.is_empty() {
+        return Err(format!(
+            "Invalid migration for table '{}': {:?}",
+            &current_schema.name, errors
+        ));
+    }
+
+    Ok(MigrationPlan {
+        table_name: current_schema.name.clone(),
+        steps,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_schema_produces_empty_plan() {
+        let schema_string = "table>sort_key:INT32;age:UNSIGNED32?;name:VARCHAR(10)";
+        let current_schema = TableSchema::from_string(schema_string).unwrap();
+        let target_schema = TableSchema::from_string(schema_string).unwrap();
+
+        let plan = plan_migration(&current_schema, &target_schema).unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn read_only_flag_is_kept_by_migration() {
+        let current_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:UNSIGNED32?#read_only").unwrap();
+        let target_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:UNSIGNED32?#row_counts").unwrap();
+
+        let plan = plan_migration(&current_schema, &target_schema).unwrap();
+        assert_eq!(
+            plan.steps,
+            vec![MigrationStep::SetOptions(
+                "row_counts,read_only".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn compatible_changes_produce_steps() {
+        let current_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:UNSIGNED32;name:VARCHAR(10)")
+                .unwrap();
+        let target_schema = TableSchema::from_string(
+            "table>sort_key:INT32;age:UNSIGNED32?;email:VARCHAR(50)?;name:VARCHAR(20)",
+        )
+        .unwrap();
+
+        let plan = plan_migration(&current_schema, &target_schema).unwrap();
+        assert_eq!(
+            plan.steps,
+            vec![
+                MigrationStep::MakeNullable("age".to_string()),
+                MigrationStep::AddColumn(
+                    "email".to_string(),
+                    Column::new(ColumnType::Varchar(50), true)
+                ),
+                MigrationStep::WidenVarchar("name".to_string(), 20),
+            ]
+        );
+        assert_eq!(
+            plan.to_string(),
+            "make column 'age' nullable, add column 'email' VARCHAR(50)?, widen column 'name' to VARCHAR(20)"
+        );
+    }
+
+    #[test]
+    fn hash_key_size_cannot_change() {
+        let current_schema = TableSchema::from_string("table>sort_key:INT32;age:INT32").unwrap();
+        let target_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:INT32#hash_key_size=256").unwrap();
+
+        let error = plan_migration(&current_schema, &target_schema).unwrap_err();
+        assert!(error.contains("'hash_key': cannot change size from 128 to 256 bytes"));
+        assert_ne!(
+            current_schema.schema_version(),
+            target_schema.schema_version()
+        );
+    }
+
+    #[test]
+    fn dropped_column_produces_step() {
+        let current_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:UNSIGNED32;name:VARCHAR(10)")
+                .unwrap();
+        let target_schema =
+            TableSchema::from_string("table>sort_key:INT32;name:VARCHAR(10)").unwrap();
+
+        let plan = plan_migration(&current_schema, &target_schema).unwrap();
+        assert_eq!(
+            plan.steps,
+            vec![MigrationStep::DropColumn("age".to_string())]
+        );
+    }
+
+    #[test]
+    fn incompatible_changes_are_rejected() {
+        let current_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:UNSIGNED32?;name:VARCHAR(10)")
+                .unwrap();
+        let target_schema = TableSchema::from_string(
+            "table>sort_key:INT64;age:UNSIGNED32;email:VARCHAR(50);name:VARCHAR(5)",
+        )
+        .unwrap();
+
+        let error = plan_migration(&current_schema, &target_schema).unwrap_err();
+        assert_eq!(
+            error,
+            "Invalid migration for table 'table': [\"'sort_key': cannot change type from 'INT32' to 'INT64'\", \"'age': cannot make column non nullable\", \"'email': cannot add non nullable column to existing table\", \"'name': cannot narrow from 'VARCHAR(10)' to 'VARCHAR(5)'\"]"
+        );
+    }
+}
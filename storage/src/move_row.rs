@@ -0,0 +1,187 @@
+use crate::auth::AuthContext;
+use crate::row::encode_primary_key;
+use crate::sstable::read_row_from_sstable;
+use crate::table::Table;
+use crate::util::millis_from_epoch;
+use crate::Row;
+use common::partition::PartitionMap;
+use common::value::Value;
+use std::collections::HashMap;
+
+// row of old key is written under new key and deleted under the old one, both keys have to be
+// in the same partition
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveRow {
+    pub hash_key: String,
+    pub sort_key: Value,
+    pub new_hash_key: String,
+    pub new_sort_key: Value,
+}
+
+impl MoveRow {
+    pub fn primary_key(&self) -> String {
+        encode_primary_key(&self.hash_key, &self.sort_key)
+    }
+
+    pub fn new_primary_key(&self) -> String {
+        encode_primary_key(&self.new_hash_key, &self.new_sort_key)
+    }
+}
+
+// latest version of row unless it is deleted or expired, memtable shadows sstables
+pub async fn read_visible_row(table: &Table, primary_key: &str, partition: usize) -> Option<Row> {
+    let row = match table.memtable.get_entry(&primary_key.to_string()) {
+        Some(row) => Some(row),
+        None => {
+            read_row_from_sstable(
+                primary_key,
+                partition,
+                &table.table_schema,
+                &table.data_dir.sstables,
+            )
+            .await
+        }
+    };
+    row.filter(|row| {
+        !row.marked_for_deletion
+            && !table
+                .table_schema
+                .options
+                .is_row_expired(row, millis_from_epoch())
+    })
+}
+
+// moved row keeps values and version, unless new key still has a tombstone in memtable, which
+// bumps it. Both rows go to commit log in a single write, so that replay never sees just one
+pub async fn write_move(
+    table: &mut Table,
+    row: Row,
+    move_row: &MoveRow,
+    partition_map: &PartitionMap,
+    auth: &AuthContext,
+) {
+    let timestamp = millis_from_epoch();
+    let moved_row = Row {
+        hash_key: move_row.new_hash_key.clone(),
+        sort_key: move_row.new_sort_key.clone(),
+        primary_key: move_row.new_primary_key(),
+        timestamp,
+        ..row.clone()
+    };
+    let tombstone = Row {
+        values: HashMap::new(),
+        timestamp,
+        marked_for_deletion: true,
+        ..row
+    };
+    table
+        .commit_log
+        .lock()
+        .await
+        .write_inserts(&[moved_row.clone(), tombstone.clone()])
+        .await;
+
+    table.count_insert(&moved_row, partition_map).await;
+    table.track_access(&moved_row);
+    table.apply_insert(moved_row, auth);
+    let (hash_key, primary_key) = (tombstone.hash_key.clone(), tombstone.primary_key.clone());
+    table.memtable.insert(tombstone, false);
+    table.on_row_deleted(&hash_key, &primary_key, auth).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::AccessTracker;
+    use crate::commit_log::CommitLog;
+    use crate::data_dir::DataDir;
+    use crate::memtable::Memtable;
+    use crate::sstable::write_memtable_to_sstable;
+    use crate::table::TableSchema;
+    use common::value::Value::{Int32, Varchar};
+    use futures::lock::Mutex;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    #[monoio::test]
+    async fn moved_row_keeps_its_values_and_version() {
+        let root = "/tmp/test_move_row";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let table_schema =
+            TableSchema::from_string("files>sort_key:INT32;name:VARCHAR(10)").unwrap();
+
+        let get_row = |sort_key: i32, name: &str| {
+            Row::new(
+                "dir_1".to_string(),
+                Int32(sort_key),
+                HashMap::from([("name".to_string(), Varchar(name.to_string()))]),
+            )
+        };
+        let mut sstable_memtable = Memtable::default();
+        sstable_memtable.insert(get_row(-1, "a.txt"), false);
+        write_memtable_to_sstable(
+            &sstable_memtable,
+            &table_schema,
+            &PartitionMap::new(1),
+            millis_from_epoch(),
+            &data_dir.sstables,
+        )
+        .await
+        .unwrap();
+
+        let commit_log =
+            CommitLog::open_new(&table_schema, &HashSet::from([0]), &data_dir.commit_logs).await;
+        let mut table = Table {
+            memtable: Memtable::default(),
+            commit_log: Arc::new(Mutex::new(commit_log)),
+            table_schema,
+            sequences: HashMap::new(),
+            leases: HashMap::new(),
+            row_counts: HashMap::new(),
+            access_tracker: AccessTracker::default(),
+            data_dir,
+            write_intents: HashMap::new(),
+        };
+        // only memtable keeps versions, so the second insert makes version 2
+        table.memtable.insert(get_row(-3, "b.txt"), false);
+        table.memtable.insert(get_row(-3, "c.txt"), false);
+
+        let mut moved_rows = Vec::new();
+        for (sort_key, new_sort_key) in [(-1, -2), (-3, -4)] {
+            let move_row = MoveRow {
+                hash_key: "dir_1".to_string(),
+                sort_key: Int32(sort_key),
+                new_hash_key: "dir_1".to_string(),
+                new_sort_key: Int32(new_sort_key),
+            };
+            let row = read_visible_row(&table, &move_row.primary_key(), 0)
+                .await
+                .unwrap();
+            let version = row.version;
+            write_move(
+                &mut table,
+                row,
+                &move_row,
+                &PartitionMap::new(1),
+                &AuthContext::anonymous(),
+            )
+            .await;
+
+            assert!(read_visible_row(&table, &move_row.primary_key(), 0)
+                .await
+                .is_none());
+            let moved_row = read_visible_row(&table, &move_row.new_primary_key(), 0)
+                .await
+                .unwrap();
+            assert_eq!(moved_row.version, version);
+            moved_rows.push(moved_row.values["name"].clone());
+        }
+        assert_eq!(
+            moved_rows,
+            vec![Varchar("a.txt".to_string()), Varchar("c.txt".to_string())]
+        );
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
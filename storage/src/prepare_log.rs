@@ -0,0 +1,373 @@
+use crate::auth::AuthContext;
+use crate::data_dir::DataDir;
+use crate::io::{remove_file, OpenOptions};
+use crate::table::Table;
+use crate::transaction::{tombstone_of, Operation};
+use crate::util::{decode_row, encode_row};
+use std::collections::{HashMap, HashSet};
+use std::fs::read_dir;
+use std::path::Path;
+
+// participant keeps writes it voted to commit until commit or abort reaches it, coordinator keeps
+// its decision to commit until no participant is left with a record, so that transactions in doubt
+// when a thread crashed are resolved once it starts again. participant giving up on a transaction
+// persists the decision to abort instead, which coordinator finds once it tries to commit
+pub fn prepared_transactions_dir(data_dir: &DataDir) -> String {
+    format!("{}/prepared_transactions", data_dir.root)
+}
+
+// tagged with a partition of the thread, like commit logs, so that the thread owning the partition
+// after restart finds it
+fn prepare_record_path(dir: &str, transaction_id: u64, partition: usize) -> String {
+    format!("{}/{}-{}.prepared", dir, transaction_id, partition)
+}
+
+fn decision_path(dir: &str, transaction_id: u64) -> String {
+    format!("{}/{}.decided", dir, transaction_id)
+}
+
+const COMMIT_DECISION: &[u8] = b"commit";
+const ABORT_DECISION: &[u8] = b"abort";
+
+pub(crate) struct PrepareRecord {
+    pub transaction_id: u64,
+    pub path: String,
+    pub operations: HashMap<String, Vec<Operation>>,
+}
+
+// returns path of the record, which is durable once this returns
+pub(crate) async fn write_prepare_record(
+    data_dir: &DataDir,
+    transaction_id: u64,
+    partition: usize,
+    bytes: Vec<u8>,
+) -> Result<String, String> {
+    let dir = prepared_transactions_dir(data_dir);
+    let path = prepare_record_path(&dir, transaction_id, partition);
+    write_durably(&dir, &path, bytes).await?;
+    Ok(path)
+}
+
+// first decision made wins, so coordinator committing and participant giving up on its intents
+// cannot both have their way, returns whether transaction commits
+pub async fn decide_transaction(
+    data_dir: &DataDir,
+    transaction_id: u64,
+    commit: bool,
+) -> Result<bool, String> {
+    let dir = prepared_transactions_dir(data_dir);
+    let path = decision_path(&dir, transaction_id);
+    // written aside and linked in place, so that nobody reads a decision half written
+    let pending_path = format!(
+        "{}/{}.{}.deciding",
+        dir,
+        transaction_id,
+        rand::random::<u64>()
+    );
+    let decision = match commit {
+        true => COMMIT_DECISION,
+        false => ABORT_DECISION,
+    };
+    write_durably(&dir, &pending_path, decision.to_vec()).await?;
+    let linked = std::fs::hard_link(&pending_path, &path);
+    let _ = remove_file(&pending_path);
+    match linked {
+        Ok(()) => Ok(commit),
+        Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => read_decision(&path)
+            .ok_or_else(|| format!("{}: decision disappeared while being read", path)),
+        Err(error) => Err(format!("{}: {}", path, error)),
+    }
+}
+
+// none until somebody decided
+fn read_decision(path: &str) -> Option<bool> {
+    std::fs::read(path)
+        .ok()
+        .map(|decision| decision == COMMIT_DECISION)
+}
+
+pub fn is_commit_decided(data_dir: &DataDir, transaction_id: u64) -> bool {
+    read_decision(&decision_path(
+        &prepared_transactions_dir(data_dir),
+        transaction_id,
+    )) == Some(true)
+}
+
+// the last participant to drop its record drops the decision to commit as well, removing either
+// twice is harmless. decision to abort stays until coordinator learns about it
+pub(crate) fn remove_prepare_record(path: &str, transaction_id: u64) {
+    if let Err(error) = remove_file(path) {
+        tracing::error!("Failed to remove prepare record '{}': {}", path, error);
+        return;
+    }
+    if let Some(dir) = Path::new(path).parent() {
+        release_commit_decision_in(&dir.to_string_lossy(), transaction_id);
+    }
+}
+
+// called by coordinator once it committed, for transactions nobody kept a record of
+pub fn release_commit_decision(data_dir: &DataDir, transaction_id: u64) {
+    release_commit_decision_in(&prepared_transactions_dir(data_dir), transaction_id);
+}
+
+// called by coordinator which found transaction aborted, nobody tries to commit it anymore and
+// participants finding no decision abort anyway
+pub fn release_abort_decision(data_dir: &DataDir, transaction_id: u64) {
+    let path = decision_path(&prepared_transactions_dir(data_dir), transaction_id);
+    if read_decision(&path) == Some(false) {
+        let _ = remove_file(path);
+    }
+}
+
+fn release_commit_decision_in(dir: &str, transaction_id: u64) {
+    let path = decision_path(dir, transaction_id);
+    if read_decision(&path) == Some(true) && !has_prepare_records(dir, transaction_id) {
+        let _ = remove_file(path);
+    }
+}
+
+fn has_prepare_records(dir: &str, transaction_id: u64) -> bool {
+    let record_prefix = format!("{}-", transaction_id);
+    read_dir(dir).is_ok_and(|mut entries| {
+        entries.any(|entry| {
+            entry.is_ok_and(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&record_prefix)
+            })
+        })
+    })
+}
+
+// called before threads of database start, coordinators of a previous run are gone by then, so
+// decisions to abort nobody learned about and those torn while being written are dropped
+pub fn remove_abandoned_decisions(data_dir: &DataDir) {
+    let dir = prepared_transactions_dir(data_dir);
+    let Ok(entries) = read_dir(&dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.ends_with(".deciding") {
+            let _ = remove_file(entry.path());
+            continue;
+        }
+        let Some(transaction_id) = file_name
+            .strip_suffix(".decided")
+            .and_then(|transaction_id| transaction_id.parse().ok())
+        else {
+            continue;
+        };
+        release_abort_decision(data_dir, transaction_id);
+        release_commit_decision_in(&dir, transaction_id);
+    }
+}
+
+// records of thread's partitions, records of tables dropped meanwhile are read without their writes
+pub(crate) fn read_prepare_records(
+    data_dir: &DataDir,
+    partitions: &HashSet<usize>,
+    tables: &HashMap<String, Table>,
+) -> Vec<PrepareRecord> {
+    let dir = prepared_transactions_dir(data_dir);
+    let Ok(entries) = read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    let mut records = Vec::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some((transaction_id, partition)) = file_name
+            .strip_suffix(".prepared")
+            .and_then(|name| name.split_once('-'))
+            .and_then(|(transaction_id, partition)| {
+                Some((transaction_id.parse().ok()?, partition.parse().ok()?))
+            })
+        else {
+            continue;
+        };
+        if !partitions.contains(&partition) {
+            continue;
+        }
+
+        let path = prepare_record_path(&dir, transaction_id, partition);
+        let operations = std::fs::read(&path)
+            .ok()
+            .and_then(|bytes| decode_operations(&bytes, tables));
+        match operations {
+            Some(operations) => records.push(PrepareRecord {
+                transaction_id,
+                path,
+                operations,
+            }),
+            // torn while being written, so participant never voted to commit
+            None => {
+                tracing::error!("Dropping unreadable prepare record '{}'", path);
+                let _ = remove_file(&path);
+            }
+        }
+    }
+    records
+}
+
+async fn write_durably(dir: &str, path: &str, bytes: Vec<u8>) -> Result<(), String> {
+    let error = |error: std::io::Error| format!("{}: {}", path, error);
+    std::fs::create_dir_all(dir).map_err(error)?;
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(path)
+        .await
+        .map_err(error)?;
+    file.write_all_at(bytes, 0).await.0.map_err(error)?;
+    file.sync_all().await.map_err(error)?;
+    file.close().await.map_err(error)
+}
+
+pub(crate) fn encode_operations(
+    operations: &HashMap<String, Vec<Operation>>,
+    tables: &HashMap<String, Table>,
+) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for (table_name, operations) in operations {
+        let table_schema = &tables[table_name].table_schema;
+        for operation in operations {
+            put_bytes(&mut bytes, table_name.as_bytes());
+            match operation {
+                Operation::Insert(row, auth) => {
+                    bytes.push(1);
+                    put_auth(&mut bytes, auth);
+                    put_bytes(&mut bytes, &encode_row(row, table_schema));
+                }
+                Operation::Delete(tombstone, auth) => {
+                    bytes.push(3);
+                    put_auth(&mut bytes, auth);
+                    put_bytes(&mut bytes, &encode_row(tombstone, table_schema));
+                }
+            }
+        }
+    }
+    bytes
+}
+
+fn decode_operations(
+    bytes: &[u8],
+    tables: &HashMap<String, Table>,
+) -> Option<HashMap<String, Vec<Operation>>> {
+    let mut reader = RecordReader::new(bytes);
+    let mut operations: HashMap<String, Vec<Operation>> = HashMap::new();
+    while reader.offset < bytes.len() {
+        let table_name = reader.take_string()?;
+        let kind = reader.take(1)?[0];
+        let auth = reader.take_auth()?;
+        let operation = match kind {
+            1 => {
+                let row_bytes = reader.take_bytes()?;
+                match tables.get(&table_name) {
+                    Some(table) => {
+                        Operation::Insert(decode_row(row_bytes, &table.table_schema), auth)
+                    }
+                    None => continue,
+                }
+            }
+            // deletes recorded by key, before they were recorded as tombstones, only found
+            // rows still in memtable
+            2 => {
+                reader.take_string()?;
+                let primary_key = reader.take_string()?;
+                let deleted_row = tables
+                    .get(&table_name)
+                    .and_then(|table| table.memtable.get(&primary_key));
+                match deleted_row {
+                    Some(deleted_row) => Operation::Delete(tombstone_of(deleted_row), auth),
+                    None => continue,
+                }
+            }
+            3 => {
+                let row_bytes = reader.take_bytes()?;
+                match tables.get(&table_name) {
+                    Some(table) => {
+                        Operation::Delete(decode_row(row_bytes, &table.table_schema), auth)
+                    }
+                    None => continue,
+                }
+            }
+            _ => return None,
+        };
+        if tables.contains_key(&table_name) {
+            operations.entry(table_name).or_default().push(operation);
+        }
+    }
+    Some(operations)
+}
+
+pub(crate) fn put_bytes(bytes: &mut Vec<u8>, value: &[u8]) {
+    bytes.extend_from_slice(&(value.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(value);
+}
+
+// anonymous user is written as empty name
+fn put_auth(bytes: &mut Vec<u8>, auth: &AuthContext) {
+    put_bytes(bytes, auth.user.as_deref().unwrap_or_default().as_bytes());
+    bytes.extend_from_slice(&(auth.roles.len() as u32).to_be_bytes());
+    for role in &auth.roles {
+        put_bytes(bytes, role.as_bytes());
+    }
+}
+
+pub(crate) struct RecordReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> RecordReader<'a> {
+        RecordReader { bytes, offset: 0 }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.offset == self.bytes.len()
+    }
+
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        let taken = self
+            .bytes
+            .get(self.offset..self.offset.checked_add(length)?)?;
+        self.offset += length;
+        Some(taken)
+    }
+
+    pub(crate) fn take_u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    pub(crate) fn take_u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    pub(crate) fn take_u128(&mut self) -> Option<u128> {
+        Some(u128::from_be_bytes(self.take(16)?.try_into().ok()?))
+    }
+
+    fn take_bytes(&mut self) -> Option<&'a [u8]> {
+        let length = self.take_u32()? as usize;
+        self.take(length)
+    }
+
+    pub(crate) fn take_string(&mut self) -> Option<String> {
+        String::from_utf8(self.take_bytes()?.to_vec()).ok()
+    }
+
+    fn take_auth(&mut self) -> Option<AuthContext> {
+        let user = self.take_string()?;
+        let roles = (0..self.take_u32()?)
+            .map(|_| self.take_string())
+            .collect::<Option<_>>()?;
+        Some(AuthContext {
+            user: (!user.is_empty()).then_some(user),
+            roles,
+        })
+    }
+}
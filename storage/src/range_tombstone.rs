@@ -0,0 +1,181 @@
+use crate::prepare_log::{put_bytes, RecordReader};
+use crate::Row;
+use common::value::Value;
+use get_size::GetSize;
+use std::collections::HashMap;
+use std::mem::size_of;
+use std::ops::{Bound, RangeBounds};
+
+// deletes versions of rows of a hash key with primary key in range written before it, like row
+// tombstones it only shadows older versions, so that rows written after it stay visible
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeTombstone {
+    pub hash_key: String,
+    pub start: Bound<String>,
+    pub end: Bound<String>,
+    pub timestamp: u128,
+}
+
+impl RangeTombstone {
+    pub fn contains(&self, primary_key: &str) -> bool {
+        let (start, end) = (
+            self.start.as_ref().map(String::as_str),
+            self.end.as_ref().map(String::as_str),
+        );
+        RangeBounds::<str>::contains(&(start, end), primary_key)
+    }
+
+    pub fn shadows(&self, row: &Row) -> bool {
+        row.timestamp < self.timestamp && self.contains(&row.primary_key)
+    }
+
+    // tombstone read in place of a key the range tombstone covers, sort key is not known then
+    pub fn row_tombstone(&self, primary_key: &str) -> Row {
+        Row {
+            hash_key: self.hash_key.clone(),
+            sort_key: Value::Null,
+            primary_key: primary_key.to_string(),
+            values: HashMap::new(),
+            version: 1,
+            timestamp: self.timestamp,
+            marked_for_deletion: true,
+        }
+    }
+
+    fn put_bytes(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.timestamp.to_be_bytes());
+        put_bytes(bytes, self.hash_key.as_bytes());
+        put_bound(bytes, &self.start);
+        put_bound(bytes, &self.end);
+    }
+
+    fn take(reader: &mut RecordReader) -> Option<RangeTombstone> {
+        Some(RangeTombstone {
+            timestamp: reader.take_u128()?,
+            hash_key: reader.take_string()?,
+            start: take_bound(reader)?,
+            end: take_bound(reader)?,
+        })
+    }
+}
+
+impl GetSize for RangeTombstone {
+    fn get_size(&self) -> usize {
+        let bound_size = |bound: &Bound<String>| match bound {
+            Bound::Included(key) | Bound::Excluded(key) => key.get_size(),
+            Bound::Unbounded => 0,
+        };
+        size_of::<RangeTombstone>()
+            + self.hash_key.get_size()
+            + bound_size(&self.start)
+            + bound_size(&self.end)
+    }
+}
+
+pub fn is_shadowed(row: &Row, range_tombstones: &[RangeTombstone]) -> bool {
+    range_tombstones
+        .iter()
+        .any(|range_tombstone| range_tombstone.shadows(row))
+}
+
+// newest range tombstone covering the key, it shadows more versions than older ones
+pub fn newest_covering<'a>(
+    primary_key: &str,
+    range_tombstones: &'a [RangeTombstone],
+) -> Option<&'a RangeTombstone> {
+    range_tombstones
+        .iter()
+        .filter(|range_tombstone| range_tombstone.contains(primary_key))
+        .max_by_key(|range_tombstone| range_tombstone.timestamp)
+}
+
+// count is written up front, so that a partially written list is not read as a shorter one
+pub(crate) fn encode_range_tombstones(range_tombstones: &[RangeTombstone]) -> Vec<u8> {
+    let mut bytes = (range_tombstones.len() as u32).to_be_bytes().to_vec();
+    for range_tombstone in range_tombstones {
+        range_tombstone.put_bytes(&mut bytes);
+    }
+    bytes
+}
+
+pub(crate) fn decode_range_tombstones(bytes: &[u8]) -> Option<Vec<RangeTombstone>> {
+    let mut reader = RecordReader::new(bytes);
+    let range_tombstones = (0..reader.take_u32()?)
+        .map(|_| RangeTombstone::take(&mut reader))
+        .collect::<Option<_>>()?;
+    reader.is_empty().then_some(range_tombstones)
+}
+
+fn put_bound(bytes: &mut Vec<u8>, bound: &Bound<String>) {
+    match bound {
+        Bound::Included(key) => {
+            bytes.push(1);
+            put_bytes(bytes, key.as_bytes());
+        }
+        Bound::Excluded(key) => {
+            bytes.push(2);
+            put_bytes(bytes, key.as_bytes());
+        }
+        Bound::Unbounded => bytes.push(0),
+    }
+}
+
+fn take_bound(reader: &mut RecordReader) -> Option<Bound<String>> {
+    match reader.take_u8()? {
+        0 => Some(Bound::Unbounded),
+        1 => Some(Bound::Included(reader.take_string()?)),
+        2 => Some(Bound::Excluded(reader.take_string()?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::value::Value::Int32;
+
+    #[test]
+    fn range_tombstones_are_decoded_back() {
+        let range_tombstones = vec![
+            RangeTombstone {
+                hash_key: "user_1".to_string(),
+                start: Bound::Included("user_1:a".to_string()),
+                end: Bound::Excluded("user_1;".to_string()),
+                timestamp: 10,
+            },
+            RangeTombstone {
+                hash_key: "user_2".to_string(),
+                start: Bound::Unbounded,
+                end: Bound::Excluded("user_2:b".to_string()),
+                timestamp: 20,
+            },
+        ];
+
+        let bytes = encode_range_tombstones(&range_tombstones);
+        assert_eq!(decode_range_tombstones(&bytes), Some(range_tombstones));
+        assert_eq!(decode_range_tombstones(&bytes[..bytes.len() - 1]), None);
+    }
+
+    #[test]
+    fn only_older_versions_in_range_are_shadowed() {
+        let range_tombstone = RangeTombstone {
+            hash_key: "user_1".to_string(),
+            start: Bound::Included(crate::encode_primary_key("user_1", &Int32(10))),
+            end: Bound::Excluded(crate::encode_primary_key("user_1", &Int32(20))),
+            timestamp: 100,
+        };
+        let row = |sort_key, timestamp| {
+            Row::new_with_timestamp(
+                "user_1".to_string(),
+                Int32(sort_key),
+                HashMap::new(),
+                timestamp,
+            )
+        };
+
+        assert!(range_tombstone.shadows(&row(10, 99)));
+        assert!(!range_tombstone.shadows(&row(10, 100)));
+        assert!(!range_tombstone.shadows(&row(20, 99)));
+        assert!(!range_tombstone.shadows(&row(9, 99)));
+    }
+}
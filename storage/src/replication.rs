@@ -0,0 +1,382 @@
+use crate::commit_log::{frame_record, split_framed_records, ReplayedState};
+use crate::range_tombstone::decode_range_tombstones;
+use crate::table::Table;
+use crate::util::{decode_row, millis_from_epoch};
+use common::partition::PartitionMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem::size_of;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+// 0 means records are not kept, standby servers cannot pull from this server then
+static REPLICATION_LOG_MAX_BYTES: AtomicUsize = AtomicUsize::new(0);
+// epochs only grow, so that a restarted thread never hands out records under an epoch it had
+static LAST_EPOCH: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    // by directory of commit logs, every database of the thread keeps its own
+    static REPLICATION_LOGS: RefCell<HashMap<String, ReplicationLog>> = RefCell::new(HashMap::new());
+}
+
+// records appended to commit logs of a thread, in order they were appended, kept in memory for
+// standby servers to pull. They are lost along with the thread, its next log has another epoch,
+// so that standby knows it missed records and has to be seeded again
+struct ReplicationLog {
+    epoch: u64,
+    first_sequence: u64,
+    records: VecDeque<ReplicatedRecords>,
+    bytes: usize,
+}
+
+impl ReplicationLog {
+    fn new() -> ReplicationLog {
+        let epoch = (millis_from_epoch() as u64).max(LAST_EPOCH.load(Ordering::SeqCst) + 1);
+        LAST_EPOCH.fetch_max(epoch, Ordering::SeqCst);
+        ReplicationLog {
+            epoch,
+            first_sequence: 1,
+            records: VecDeque::new(),
+            bytes: 0,
+        }
+    }
+
+    fn next_sequence(&self) -> u64 {
+        self.first_sequence + self.records.len() as u64
+    }
+}
+
+// records of a single append to commit log of a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplicatedRecords {
+    pub sequence: u64,
+    pub table_name: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplicatedBatch {
+    pub epoch: u64,
+    pub records: Vec<ReplicatedRecords>,
+    // next pull continues from it
+    pub next_sequence: u64,
+}
+
+pub fn set_replication_log_max_bytes(max_bytes: usize) {
+    REPLICATION_LOG_MAX_BYTES.store(max_bytes, Ordering::SeqCst);
+}
+
+pub fn replication_log_max_bytes() -> usize {
+    REPLICATION_LOG_MAX_BYTES.load(Ordering::SeqCst)
+}
+
+// records of a thread that panicked died with it, restarted thread starts a new epoch
+pub fn reset_replication_logs() {
+    REPLICATION_LOGS.with(|replication_logs| replication_logs.borrow_mut().clear());
+}
+
+// oldest records are dropped once log outgrows its limit, standby behind them has to be seeded again
+pub(crate) fn record_appended(commit_log_path: &str, table_name: &str, operation_bytes: &[u8]) {
+    let max_bytes = replication_log_max_bytes();
+    if max_bytes == 0 {
+        return;
+    }
+    let commit_log_dir = commit_log_dir(commit_log_path);
+
+    REPLICATION_LOGS.with(|replication_logs| {
+        let mut replication_logs = replication_logs.borrow_mut();
+        let replication_log = replication_logs
+            .entry(commit_log_dir.to_string())
+            .or_insert_with(ReplicationLog::new);
+        let sequence = replication_log.next_sequence();
+        replication_log.bytes += operation_bytes.len();
+        replication_log.records.push_back(ReplicatedRecords {
+            sequence,
+            table_name: table_name.to_string(),
+            bytes: operation_bytes.to_vec(),
+        });
+
+        while replication_log.bytes > max_bytes {
+            let Some(dropped) = replication_log.records.pop_front() else {
+                break;
+            };
+            replication_log.bytes -= dropped.bytes.len();
+            replication_log.first_sequence += 1;
+        }
+    });
+}
+
+// records from given sequence on, up to about max bytes. Epoch 0 asks for records from the start
+// of current epoch, which standby seeded with a copy of data directory taken before thread started
+pub fn read_replicated(
+    commit_log_dir: &str,
+    epoch: u64,
+    sequence: u64,
+    max_bytes: usize,
+) -> Result<ReplicatedBatch, String> {
+    if replication_log_max_bytes() == 0 {
+        return Err("Replication log is not kept on this server".to_string());
+    }
+
+    REPLICATION_LOGS.with(|replication_logs| {
+        let mut replication_logs = replication_logs.borrow_mut();
+        let replication_log = replication_logs
+            .entry(commit_log_dir.to_string())
+            .or_insert_with(ReplicationLog::new);
+        let sequence = match epoch {
+            0 => sequence.max(1),
+            epoch if epoch == replication_log.epoch => sequence,
+            epoch => {
+                return Err(format!(
+                    "Leader restarted since epoch {}, standby has to be seeded again",
+                    epoch
+                ))
+            }
+        };
+        if sequence < replication_log.first_sequence {
+            return Err(format!(
+                "Records from sequence {} were dropped, standby has to be seeded again",
+                sequence
+            ));
+        }
+        if sequence > replication_log.next_sequence() {
+            return Err(format!(
+                "Sequence {} is past the last record {}",
+                sequence,
+                replication_log.next_sequence() - 1
+            ));
+        }
+
+        let mut bytes = 0;
+        let records: Vec<_> = replication_log
+            .records
+            .iter()
+            .skip((sequence - replication_log.first_sequence) as usize)
+            .take_while(|records| {
+                let taken = bytes == 0 || bytes + records.bytes.len() <= max_bytes;
+                bytes += records.bytes.len();
+                taken
+            })
+            .cloned()
+            .collect();
+        Ok(ReplicatedBatch {
+            epoch: replication_log.epoch,
+            next_sequence: sequence + records.len() as u64,
+            records,
+        })
+    })
+}
+
+// epoch and sequence the next record appended to commit logs of the thread gets
+pub fn replication_log_end(commit_log_dir: &str) -> (u64, u64) {
+    REPLICATION_LOGS.with(|replication_logs| {
+        let mut replication_logs = replication_logs.borrow_mut();
+        let replication_log = replication_logs
+            .entry(commit_log_dir.to_string())
+            .or_insert_with(ReplicationLog::new);
+        (replication_log.epoch, replication_log.next_sequence())
+    })
+}
+
+// records are written to commit log of the table first, so that standby replays them after a
+// restart, and then applied the way leader applied them. Flush watermarks are left out, as they
+// point into segments of the leader
+pub async fn apply_replicated_records(
+    table: &mut Table,
+    bytes: &[u8],
+    partitions: &HashSet<usize>,
+    partition_map: &PartitionMap,
+) -> Result<(), String> {
+    let (records, end) = split_framed_records(bytes, 0);
+    if end != bytes.len() {
+        return Err(format!(
+            "Records replicated to table '{}' are cut off",
+            table.table_schema.name
+        ));
+    }
+
+    let mut kept_bytes = Vec::with_capacity(bytes.len());
+    for (_, record) in records.iter().filter(|(_, record)| record[0] != 9) {
+        frame_record(record[0], &record[1..], &mut kept_bytes);
+    }
+    if !kept_bytes.is_empty() {
+        let mut commit_log = table.commit_log.lock().await;
+        commit_log.write_replicated(kept_bytes).await;
+    }
+
+    let mut state = ReplayedState {
+        sequences: std::mem::take(&mut table.sequences),
+        leases: std::mem::take(&mut table.leases),
+        row_counts: std::mem::take(&mut table.row_counts),
+    };
+    for (_, record) in records {
+        match record[0] {
+            1 => {
+                let row = decode_row(&record[1..], &table.table_schema);
+                table.memtable.insert(row, false);
+            }
+            2 => {
+                let timestamp_size = size_of::<u128>();
+                let timestamp =
+                    u128::from_be_bytes(record[1..1 + timestamp_size].try_into().unwrap());
+                let primary_key = String::from_utf8(record[1 + timestamp_size..].to_vec()).unwrap();
+                table.memtable.delete(&primary_key, Some(timestamp));
+            }
+            3..=5 => state.apply_record(&record),
+            10 => {
+                let range_tombstones = decode_range_tombstones(&record[1..]).unwrap();
+                for range_tombstone in range_tombstones {
+                    table.memtable.delete_range(range_tombstone);
+                }
+            }
+            _ => {}
+        }
+    }
+    table.sequences = state.sequences;
+    table.leases = state.leases;
+    table.row_counts = state.row_counts;
+
+    table
+        .flush_memtable_if_needed(partitions, partition_map)
+        .await;
+    Ok(())
+}
+
+fn commit_log_dir(commit_log_path: &str) -> &str {
+    commit_log_path
+        .rsplit_once('/')
+        .map_or("", |(commit_log_dir, _)| commit_log_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::AuthContext;
+    use crate::commit_log::replay_commit_logs;
+    use crate::data_dir::DataDir;
+    use crate::range_tombstone::RangeTombstone;
+    use crate::table::sync_model;
+    use crate::{encode_primary_key, Row};
+    use common::value::Value;
+    use futures::lock::Mutex;
+    use std::ops::Bound;
+    use std::rc::Rc;
+
+    async fn open_table(root: &str) -> (DataDir, Rc<Mutex<HashMap<String, Table>>>) {
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let tables = Rc::new(Mutex::new(HashMap::new()));
+        sync_model(
+            "accounts>sort_key:INT32".to_string(),
+            tables.clone(),
+            &HashSet::from([0]),
+            &data_dir,
+        )
+        .await
+        .unwrap();
+        (data_dir, tables)
+    }
+
+    fn row(sort_key: i32) -> Row {
+        Row::new("user_1".to_string(), Value::Int32(sort_key), HashMap::new())
+    }
+
+    #[monoio::test]
+    async fn replicated_records_are_applied_and_replayed_by_standby() {
+        set_replication_log_max_bytes(1024 * 1024);
+        reset_replication_logs();
+        let leader_root = "/tmp/replicated_records_are_applied_leader";
+        let standby_root = "/tmp/replicated_records_are_applied_standby";
+        let (leader_dir, leader_tables) = open_table(leader_root).await;
+        let (standby_dir, standby_tables) = open_table(standby_root).await;
+        let partitions = HashSet::from([0]);
+        let partition_map = PartitionMap::new(1);
+
+        {
+            let mut tables = leader_tables.lock().await;
+            let table = tables.get_mut("accounts").unwrap();
+            table.next_sequence("user_1").await.unwrap();
+            for sort_key in [1, 2] {
+                table
+                    .commit_log
+                    .lock()
+                    .await
+                    .write_insert(&row(sort_key))
+                    .await;
+                table.apply_insert(row(sort_key), &AuthContext::default());
+            }
+            let range_tombstone = RangeTombstone {
+                hash_key: "user_1".to_string(),
+                start: Bound::Included(encode_primary_key("user_1", &Value::Int32(2))),
+                end: Bound::Unbounded,
+                timestamp: millis_from_epoch() + 1,
+            };
+            let mut commit_log = table.commit_log.lock().await;
+            commit_log.write_range_tombstone(&range_tombstone).await;
+        }
+
+        let batch = read_replicated(&leader_dir.commit_logs, 0, 0, usize::MAX).unwrap();
+        assert_eq!(batch.next_sequence, 5);
+        assert_eq!(
+            read_replicated(&leader_dir.commit_logs, batch.epoch, 5, usize::MAX)
+                .unwrap()
+                .records,
+            vec![]
+        );
+        {
+            let mut tables = standby_tables.lock().await;
+            let table = tables.get_mut("accounts").unwrap();
+            for records in &batch.records {
+                apply_replicated_records(table, &records.bytes, &partitions, &partition_map)
+                    .await
+                    .unwrap();
+            }
+            assert_eq!(table.sequences.get("user_1"), Some(&1));
+            let primary_key = |sort_key| encode_primary_key("user_1", &Value::Int32(sort_key));
+            let is_deleted = |sort_key| {
+                table
+                    .memtable
+                    .get_entry(&primary_key(sort_key))
+                    .map(|row| row.marked_for_deletion)
+            };
+            assert_eq!(is_deleted(1), Some(false));
+            assert_eq!(is_deleted(2), Some(true));
+            table.commit_log.lock().await.sync().await.unwrap();
+        }
+
+        let table_schema = standby_tables.lock().await["accounts"].table_schema.clone();
+        let state =
+            replay_commit_logs(&table_schema, &partitions, &partition_map, &standby_dir).await;
+        assert_eq!(state.sequences.get("user_1"), Some(&1));
+        reset_replication_logs();
+        std::fs::remove_dir_all(leader_root).unwrap();
+        std::fs::remove_dir_all(standby_root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn standby_behind_dropped_records_or_another_epoch_is_refused() {
+        set_replication_log_max_bytes(1024 * 1024);
+        reset_replication_logs();
+        let commit_log_dir = "/tmp/standby_behind_dropped_records";
+        for _ in 0..3 {
+            record_appended(
+                &format!("{}/accounts-0-1", commit_log_dir),
+                "accounts",
+                &[0; 512 * 1024],
+            );
+        }
+
+        // third record pushed out the first one
+        assert!(read_replicated(commit_log_dir, 0, 1, usize::MAX).is_err());
+        let batch = read_replicated(commit_log_dir, 0, 2, 1).unwrap();
+        assert_eq!(batch.records.len(), 1);
+        assert_eq!(batch.next_sequence, 3);
+        assert!(read_replicated(commit_log_dir, batch.epoch + 1, 3, usize::MAX).is_err());
+
+        reset_replication_logs();
+        let restarted = read_replicated(commit_log_dir, 0, 0, usize::MAX).unwrap();
+        assert!(restarted.epoch > batch.epoch);
+        assert!(read_replicated(commit_log_dir, batch.epoch, 3, usize::MAX).is_err());
+        reset_replication_logs();
+    }
+}
@@ -1,6 +1,7 @@
+use crate::table::ColumnType;
 use crate::util::millis_from_epoch;
 use common::value::Value;
-use common::value::Value::Varchar;
+use common::value::Value::*;
 use get_size::GetSize;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -19,8 +20,7 @@ pub struct Row {
 
 impl Row {
     pub fn new(hash_key: String, sort_key: Value, values: HashMap<String, Value>) -> Row {
-        let sort_key_string = sort_key.to_string();
-        let primary_key = format!("{}:{}", hash_key, sort_key_string);
+        let primary_key = encode_primary_key(&hash_key, &sort_key);
 
         Row {
             hash_key,
@@ -39,8 +39,7 @@ impl Row {
         values: HashMap<String, Value>,
         timestamp: u128,
     ) -> Row {
-        let sort_key_string = sort_key.to_string();
-        let primary_key = format!("{}:{}", hash_key, sort_key_string);
+        let primary_key = encode_primary_key(&hash_key, &sort_key);
 
         Row {
             hash_key,
@@ -52,6 +51,66 @@ impl Row {
             marked_for_deletion: false,
         }
     }
+
+    // rows written before versions were stored read back with version 0, stored row has at least
+    // version 1
+    pub fn visible_version(&self) -> u64 {
+        self.version.max(1) as u64
+    }
+}
+
+// sort key is encoded so that comparing primary keys as strings orders rows by sort key value,
+// numbers are written as fixed width hex with the sign bit flipped, floats follow IEEE-754
+// totalOrder, NaN and infinities never get here as validation rejects them in keys
+pub fn encode_primary_key(hash_key: &str, sort_key: &Value) -> String {
+    format!("{}:{}", hash_key, encode_sort_key(sort_key))
+}
+
+// primary keys written before sort keys were encoded hold them formatted as strings,
+// None if the key does not end with a sort key of given type
+pub(crate) fn encode_legacy_primary_key(
+    primary_key: &str,
+    sort_key_type: &ColumnType,
+) -> Option<String> {
+    let (hash_key, sort_key) = primary_key.rsplit_once(':')?;
+    let sort_key = match sort_key_type {
+        ColumnType::Varchar(_) => return Some(primary_key.to_string()),
+        ColumnType::Int32 => Int32(sort_key.parse().ok()?),
+        ColumnType::Int64 => Int64(sort_key.parse().ok()?),
+        ColumnType::Unsigned32 => Unsigned32(sort_key.parse().ok()?),
+        ColumnType::Unsigned64 => Unsigned64(sort_key.parse().ok()?),
+        ColumnType::Float32 => Float32(sort_key.parse().ok()?),
+        ColumnType::Float64 => Float64(sort_key.parse().ok()?),
+        ColumnType::Boolean => Boolean(sort_key.parse().ok()?),
+    };
+    Some(encode_primary_key(hash_key, &sort_key))
+}
+
+// strings of values compare in the same order as values of the same type do
+pub(crate) fn encode_sort_key(sort_key: &Value) -> String {
+    match sort_key {
+        Varchar(value) => value.clone(),
+        Int32(value) => format!("{:08x}", (*value as u32) ^ (1 << 31)),
+        Int64(value) => format!("{:016x}", (*value as u64) ^ (1 << 63)),
+        Unsigned32(value) => format!("{:08x}", value),
+        Unsigned64(value) => format!("{:016x}", value),
+        Float32(value) => {
+            let bits = value.to_bits();
+            match bits >> 31 {
+                1 => format!("{:08x}", !bits),
+                _ => format!("{:08x}", bits | (1 << 31)),
+            }
+        }
+        Float64(value) => {
+            let bits = value.to_bits();
+            match bits >> 63 {
+                1 => format!("{:016x}", !bits),
+                _ => format!("{:016x}", bits | (1 << 63)),
+            }
+        }
+        Boolean(value) => (*value as u8).to_string(),
+        Null => "".to_string(),
+    }
 }
 
 impl PartialEq for Row {
@@ -89,3 +148,75 @@ impl GetSize for Row {
             + self.values.iter().map(|val| val.get_size()).sum::<usize>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_ordered(sort_keys: Vec<Value>) {
+        let primary_keys: Vec<_> = sort_keys
+            .iter()
+            .map(|sort_key| encode_primary_key("user", sort_key))
+            .collect();
+        for pair in primary_keys.windows(2) {
+            assert!(pair[0] < pair[1], "{} >= {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn primary_keys_follow_numeric_sort_key_order() {
+        assert_ordered(vec![
+            Int32(i32::MIN),
+            Int32(-10),
+            Int32(-9),
+            Int32(0),
+            Int32(9),
+            Int32(10),
+        ]);
+        assert_ordered(vec![
+            Int64(i64::MIN),
+            Int64(-1),
+            Int64(2),
+            Int64(10),
+            Int64(i64::MAX),
+        ]);
+        assert_ordered(vec![Unsigned32(9), Unsigned32(10), Unsigned32(u32::MAX)]);
+        assert_ordered(vec![Unsigned64(9), Unsigned64(10), Unsigned64(u64::MAX)]);
+        assert_ordered(vec![
+            Float32(-10.5),
+            Float32(-1.0),
+            Float32(0.0),
+            Float32(0.5),
+            Float32(9.0),
+            Float32(10.0),
+        ]);
+        assert_ordered(vec![
+            Float64(f64::MIN),
+            Float64(-2.5),
+            Float64(-0.1),
+            Float64(0.0),
+            Float64(1e10),
+        ]);
+        assert_ordered(vec![Boolean(false), Boolean(true)]);
+    }
+
+    #[test]
+    fn legacy_primary_keys_are_encoded() {
+        assert_eq!(
+            encode_legacy_primary_key("user:1:-10", &ColumnType::Int32),
+            Some(encode_primary_key("user:1", &Int32(-10)))
+        );
+        assert_eq!(
+            encode_legacy_primary_key("user:2.5", &ColumnType::Float64),
+            Some(encode_primary_key("user", &Float64(2.5)))
+        );
+        assert_eq!(
+            encode_legacy_primary_key("user:a:b", &ColumnType::Varchar(8)),
+            Some("user:a:b".to_string())
+        );
+        assert_eq!(
+            encode_legacy_primary_key("user:x", &ColumnType::Int64),
+            None
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::cell::RefCell;
+use std::future::poll_fn;
+use std::task::{Poll, Waker};
+use std::time::Duration;
+
+// simulation runs every thread of the server on a single runtime, so its state is thread local,
+// threads outside of simulation keep using real clock
+thread_local! {
+    static SIMULATION: RefCell<Option<SimulationState>> = const { RefCell::new(None) };
+}
+
+struct SimulationState {
+    now_millis: u128,
+    rng: StdRng,
+    flush_delay: Duration,
+    // woken up on every clock advance, futures check their deadline on their own
+    sleepers: Vec<Waker>,
+}
+
+pub fn start_simulation(seed: u64, start_millis: u128) {
+    SIMULATION.with(|simulation| {
+        *simulation.borrow_mut() = Some(SimulationState {
+            now_millis: start_millis,
+            rng: StdRng::seed_from_u64(seed),
+            flush_delay: Duration::ZERO,
+            sleepers: Vec::new(),
+        })
+    });
+}
+
+pub fn stop_simulation() {
+    SIMULATION.with(|simulation| *simulation.borrow_mut() = None);
+}
+
+pub fn is_simulated() -> bool {
+    SIMULATION.with(|simulation| simulation.borrow().is_some())
+}
+
+pub fn simulated_millis() -> Option<u128> {
+    SIMULATION.with(|simulation| simulation.borrow().as_ref().map(|state| state.now_millis))
+}
+
+pub fn advance_clock(duration: Duration) {
+    let sleepers = SIMULATION.with(|simulation| match simulation.borrow_mut().as_mut() {
+        Some(state) => {
+            state.now_millis += duration.as_millis();
+            std::mem::take(&mut state.sleepers)
+        }
+        None => Vec::new(),
+    });
+    for sleeper in sleepers {
+        sleeper.wake();
+    }
+}
+
+// same seed gives the same sequence, panics outside of simulation
+pub fn next_random_u64() -> u64 {
+    SIMULATION.with(|simulation| {
+        simulation
+            .borrow_mut()
+            .as_mut()
+            .expect("Simulation is not running")
+            .rng
+            .next_u64()
+    })
+}
+
+pub fn set_flush_delay(delay: Duration) {
+    SIMULATION.with(|simulation| {
+        if let Some(state) = simulation.borrow_mut().as_mut() {
+            state.flush_delay = delay;
+        }
+    });
+}
+
+// flushes wait for virtual clock to pass the delay, so that reads can race them deterministically
+pub(crate) async fn delay_flush() {
+    let delay = SIMULATION.with(|simulation| {
+        simulation
+            .borrow()
+            .as_ref()
+            .map(|state| state.flush_delay)
+            .unwrap_or_default()
+    });
+    if let (Some(now_millis), false) = (simulated_millis(), delay.is_zero()) {
+        sleep_until(now_millis + delay.as_millis()).await;
+    }
+}
+
+pub async fn sleep_until(deadline_millis: u128) {
+    poll_fn(|context| {
+        SIMULATION.with(|simulation| match simulation.borrow_mut().as_mut() {
+            Some(state) if state.now_millis < deadline_millis => {
+                state.sleepers.push(context.waker().clone());
+                Poll::Pending
+            }
+            _ => Poll::Ready(()),
+        })
+    })
+    .await
+}
@@ -0,0 +1,193 @@
+use crate::io::remove_file;
+use crate::range_tombstone::{is_shadowed, RangeTombstone};
+use crate::sstable::{
+    get_sstables_metadata, read_sstable_range_tombstones, read_sstable_rows, SSTableMetadata,
+};
+use crate::table::{Table, TableSchema};
+use crate::util::millis_from_epoch;
+use crate::Row;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+// sstables are shared between partition threads and compaction thread, so pins are global
+static PINNED_SSTABLES: Mutex<BTreeMap<String, usize>> = Mutex::new(BTreeMap::new());
+static DEFERRED_DELETIONS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+pub struct TableSnapshot {
+    pub table_schema: TableSchema,
+    memtable_rows: Vec<Row>,
+    memtable_range_tombstones: Vec<RangeTombstone>,
+    sstable_metadatas: Vec<SSTableMetadata>,
+}
+
+impl TableSnapshot {
+    pub fn new(table: &Table, sstable_dir: &str) -> TableSnapshot {
+        let sstable_metadatas = {
+            // listing under lock, so that no segment can be removed before getting pinned
+            let mut pinned_sstables = PINNED_SSTABLES.lock().unwrap();
+            let sstable_metadatas = get_sstables_metadata(&table.table_schema.name, sstable_dir);
+            for sstable_metadata in &sstable_metadatas {
+                *pinned_sstables
+                    .entry(sstable_metadata.file_path.clone())
+                    .or_insert(0) += 1;
+            }
+            sstable_metadatas
+        };
+
+        TableSnapshot {
+            table_schema: table.table_schema.clone(),
+            memtable_rows: table.memtable.rows(),
+            memtable_range_tombstones: table.memtable.range_tombstones().to_vec(),
+            sstable_metadatas,
+        }
+    }
+
+    // merged view of all pinned segments, newest version of every row wins, range tombstones
+    // shadow versions in sstables, memtable has the latest version of its rows
+    pub async fn rows(&self) -> impl Iterator<Item = Row> {
+        let mut sstable_metadatas: Vec<_> = self.sstable_metadatas.iter().collect();
+        sstable_metadatas.sort_by_key(|metadata| metadata.timestamp);
+
+        let mut rows = BTreeMap::new();
+        let mut range_tombstones = self.memtable_range_tombstones.clone();
+        for sstable_metadata in sstable_metadatas {
+            for row in read_sstable_rows(sstable_metadata, &self.table_schema).await {
+                merge_row(&mut rows, row);
+            }
+            range_tombstones
+                .extend(read_sstable_range_tombstones(sstable_metadata, &self.table_schema).await);
+        }
+        rows.retain(|_, row| !is_shadowed(row, &range_tombstones));
+        for row in &self.memtable_rows {
+            merge_row(&mut rows, row.clone());
+        }
+
+        let now = millis_from_epoch();
+        let table_options = self.table_schema.options.clone();
+        rows.into_values()
+            .filter(move |row| !row.marked_for_deletion && !table_options.is_row_expired(row, now))
+    }
+}
+
+impl Drop for TableSnapshot {
+    fn drop(&mut self) {
+        let mut pinned_sstables = PINNED_SSTABLES.lock().unwrap();
+        for sstable_metadata in &self.sstable_metadatas {
+            let file_path = &sstable_metadata.file_path;
+            let pins = pinned_sstables.get_mut(file_path).unwrap();
+            *pins -= 1;
+
+            if *pins == 0 {
+                pinned_sstables.remove(file_path);
+                if DEFERRED_DELETIONS.lock().unwrap().remove(file_path) {
+                    remove_file(file_path).unwrap();
+                }
+            }
+        }
+    }
+}
+
+fn merge_row(rows: &mut BTreeMap<String, Row>, row: Row) {
+    match rows.get(&row.primary_key) {
+        Some(current_row) if current_row.timestamp > row.timestamp => {}
+        _ => {
+            rows.insert(row.primary_key.clone(), row);
+        }
+    }
+}
+
+// segments pinned by a snapshot are removed once the last snapshot using them is dropped
+pub fn remove_sstable_file(file_path: &str) {
+    let pinned_sstables = PINNED_SSTABLES.lock().unwrap();
+    if pinned_sstables.contains_key(file_path) {
+        DEFERRED_DELETIONS
+            .lock()
+            .unwrap()
+            .insert(file_path.to_string());
+        return;
+    }
+
+    remove_file(file_path).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::AccessTracker;
+    use crate::commit_log::{CommitLog, PartialFlushes};
+    use crate::data_dir::DataDir;
+    use crate::table::ColumnType;
+    use crate::Memtable;
+    use common::value::Value::Varchar;
+    use futures::lock::Mutex;
+    use std::collections::HashMap;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    fn get_table(table_name: &str) -> Table {
+        let table_schema = TableSchema::new(table_name.to_string(), ColumnType::Varchar(100));
+        let commit_log = CommitLog {
+            file: None,
+            file_path: "test".to_string(),
+            file_offset: 0,
+            table_schema: table_schema.clone(),
+            partition: 0,
+            closed: true,
+            partial_flushes: PartialFlushes::default(),
+        };
+        Table {
+            memtable: Memtable::default(),
+            commit_log: Arc::new(Mutex::new(commit_log)),
+            table_schema,
+            sequences: HashMap::new(),
+            leases: HashMap::new(),
+            row_counts: HashMap::new(),
+            access_tracker: AccessTracker::default(),
+            data_dir: DataDir::default(),
+            write_intents: HashMap::new(),
+        }
+    }
+
+    fn get_row(key: &str) -> Row {
+        Row::new(key.to_string(), Varchar(key.to_string()), HashMap::new())
+    }
+
+    #[monoio::test]
+    async fn snapshot_is_not_affected_by_later_writes() {
+        let sstable_dir = "/tmp/snapshot_writes";
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let mut table = get_table("snapshot_writes");
+        table.memtable.insert(get_row("1"), false);
+        table.memtable.insert(get_row("2"), false);
+        table.memtable.delete(&get_row("2").primary_key, None);
+
+        let snapshot = TableSnapshot::new(&table, sstable_dir);
+        table.memtable.insert(get_row("3"), false);
+
+        let rows: Vec<_> = snapshot.rows().await.collect();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].primary_key, get_row("1").primary_key);
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+
+    #[monoio::test]
+    async fn pinned_sstable_deletion_is_deferred() {
+        let sstable_dir = "/tmp/snapshot_pins";
+        std::fs::create_dir_all(sstable_dir).unwrap();
+        let file_path = format!("{}/snapshot_pins-0-0-0", sstable_dir);
+        std::fs::write(&file_path, []).unwrap();
+
+        let table = get_table("snapshot_pins");
+        let snapshot = TableSnapshot::new(&table, sstable_dir);
+
+        remove_sstable_file(&file_path);
+        assert!(Path::new(&file_path).exists());
+
+        drop(snapshot);
+        assert!(!Path::new(&file_path).exists());
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+}
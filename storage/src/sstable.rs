@@ -1,26 +1,62 @@
-use crate::commit_log::CommitLog;
-use crate::table::{Table, TableSchema};
-use crate::util::{decode_row, encode_row, millis_from_epoch};
+use crate::commit_log::{CommitLog, FlushWatermark};
+use crate::history::{purge_sstable_history, retire_sstable_file};
+use crate::hooks::{notify_storage_hooks, publish_background_event, BackgroundEvent};
+use crate::index::{remove_sstable_index, write_sstable_index, SSTableIndex};
+use crate::io::{File, OpenOptions};
+use crate::range_tombstone::{
+    decode_range_tombstones, encode_range_tombstones, is_shadowed, RangeTombstone,
+};
+use crate::snapshot::remove_sstable_file;
+use crate::statistics::{remove_sstable_statistics, write_sstable_statistics, SSTableStatistics};
+use crate::supervisor::{supervise, BackgroundTask};
+use crate::table::{ColumnType, TableSchema};
+use crate::throttle::{compaction_io_throttle, IoThrottle};
+use crate::util::{
+    decode_legacy_row, decode_row, encode_row, millis_from_epoch, parse_table_file_name,
+};
 use crate::{Memtable, Row, MEGABYTE};
-use futures::channel::mpsc::Receiver;
+use common::partition::PartitionMap;
+use futures::channel::mpsc::{self, Receiver};
 use futures::channel::oneshot;
+use futures::future::join_all;
 use futures::lock::Mutex;
-use futures::StreamExt;
+use futures::{SinkExt, StreamExt};
 use monoio;
-use monoio::fs::{File, OpenOptions};
 use monoio::time::sleep;
-use std::collections::HashMap;
+use std::borrow::Borrow;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 use std::fs::read_dir;
+use std::io::Read;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
 use std::os::unix::fs::MetadataExt;
-use std::sync::Arc;
-use std::time::Duration;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 pub static SSTABLES_DIR: &str = "/var/lib/yard/sstables";
+// files start with magic and version of schema rows were encoded with, older files have no header,
+// range tombstones follow rows in files having any
+static SSTABLE_MAGIC: &[u8; 4] = b"YSS3";
+// files written before hash keys were length prefixed
+static UNPREFIXED_SSTABLE_MAGIC: &[u8; 4] = b"YSS2";
+// files written before sort keys were encoded in order preserving way
+static LEGACY_SSTABLE_MAGIC: &[u8; 4] = b"YSST";
+static SSTABLE_HEADER_SIZE: usize = 12;
+// compaction reads sstables in chunks of that size instead of whole files
+static SSTABLE_READ_CHUNK_SIZE: usize = 4 * MEGABYTE;
+static SSTABLE_READ_AHEAD_CHUNKS: usize = 2;
+static SSTABLE_WRITE_BUFFER_SIZE: usize = 4 * MEGABYTE;
+static PARTIAL_FLUSH_POLL_INTERVAL: Duration = Duration::from_millis(10);
 
 pub struct SSTableSegment {
     table_schema: TableSchema,
     memtable_rows: Vec<Row>,
     partition_index: HashMap<usize, usize>,
+    range_tombstones: Vec<RangeTombstone>,
+    // part of file name, newer sstables are read first
+    timestamp: u128,
+    throttle: Option<IoThrottle>,
 }
 
 impl SSTableSegment {
@@ -33,57 +69,231 @@ impl SSTableSegment {
             table_schema,
             memtable_rows: rows,
             partition_index,
+            range_tombstones: Vec::new(),
+            timestamp: millis_from_epoch(),
+            throttle: None,
         }
     }
 
-    pub async fn write_to_disk(self, sstable_dir: &str) -> Result<(), String> {
-        let partition_index_bytes = self
-            .partition_index
-            .into_iter()
-            .map(|(partition, row_number)| format!("{}:{}", partition, row_number))
-            .collect::<Vec<_>>()
-            .join(",")
-            .into_bytes();
-
-        let num_of_rows = self.memtable_rows.len();
-        let encoded_rows: Vec<_> = self
-            .memtable_rows
-            .into_iter()
-            .map(|row| encode_row(&row, &self.table_schema))
-            .reduce(|current, next| current.into_iter().chain(next.into_iter()).collect())
-            .unwrap();
+    pub fn with_timestamp(self, timestamp: u128) -> SSTableSegment {
+        SSTableSegment { timestamp, ..self }
+    }
+
+    pub fn with_range_tombstones(self, range_tombstones: Vec<RangeTombstone>) -> SSTableSegment {
+        SSTableSegment {
+            range_tombstones,
+            ..self
+        }
+    }
 
-        let partition_index_length = partition_index_bytes.len() as u64;
-        let file_name = format!(
-            "{}/{}-{}-{}-{}",
+    pub fn with_throttle(self, throttle: IoThrottle) -> SSTableSegment {
+        SSTableSegment {
+            throttle: Some(throttle),
+            ..self
+        }
+    }
+
+    pub async fn write_to_disk(self, sstable_dir: &str) -> Result<WrittenSSTable, String> {
+        write_sstable_file(
+            &self.table_schema,
+            self.memtable_rows
+                .iter()
+                .map(|row| (row.hash_key.as_str(), encode_row(row, &self.table_schema))),
+            &self.partition_index,
+            &self.range_tombstones,
+            self.timestamp,
             sstable_dir,
-            self.table_schema.name,
-            partition_index_length,
-            num_of_rows,
-            millis_from_epoch()
-        );
-        let file = OpenOptions::new()
-            .create(true)
-            .write(true)
-            .open(file_name)
-            .await
-            .map_err(|e| e.to_string())?;
+            self.throttle.as_ref(),
+        )
+        .await
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrittenSSTable {
+    pub file_path: String,
+    pub number_of_rows: usize,
+    pub file_size: u64,
+}
+
+// rows are encoded straight from memtable, so that flush does not copy it, nothing is written
+// for memtable with neither rows nor range tombstones
+pub async fn write_memtable_to_sstable(
+    memtable: &Memtable,
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+    timestamp: u128,
+    sstable_dir: &str,
+) -> Result<Option<WrittenSSTable>, String> {
+    if memtable.size == 0 && memtable.range_tombstones().is_empty() {
+        return Ok(None);
+    }
+
+    let (rows, partition_index) = memtable.encoded_sstable_rows(table_schema, partition_map);
+    write_sstable_file(
+        table_schema,
+        rows,
+        &partition_index,
+        memtable.range_tombstones(),
+        timestamp,
+        sstable_dir,
+        None,
+    )
+    .await
+    .map(Some)
+}
+
+// rows have to come sorted by partition, encoded along with their hash keys, they are gathered
+// into a bounded buffer that is written out whenever it fills up
+async fn write_sstable_file<'a>(
+    table_schema: &TableSchema,
+    rows: impl ExactSizeIterator<Item = (&'a str, Vec<u8>)>,
+    partition_index: &HashMap<usize, usize>,
+    range_tombstones: &[RangeTombstone],
+    timestamp: u128,
+    sstable_dir: &str,
+    throttle: Option<&IoThrottle>,
+) -> Result<WrittenSSTable, String> {
+    let number_of_rows = rows.len();
+    let partition_index_bytes = partition_index
+        .iter()
+        .map(|(partition, row_number)| format!("{}:{}", partition, row_number))
+        .collect::<Vec<_>>()
+        .join(",")
+        .into_bytes();
+
+    let partition_index_length = partition_index_bytes.len() as u64;
+    let file_name = format!(
+        "{}/{}-{}-{}-{}",
+        sstable_dir,
+        table_schema.name,
+        partition_index_length,
+        rows.len(),
+        timestamp
+    );
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .open(&file_name)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // partially written file would be picked up as a corrupted sstable on restart
+    let mut statistics = SSTableStatistics::default();
+    let mut sstable_index = SSTableIndex::for_table(table_schema);
+    let result = write_sstable_rows(
+        table_schema,
+        rows,
+        partition_index_bytes,
+        &file,
+        throttle,
+        &mut statistics,
+        &mut sstable_index,
+    )
+    .await;
+    let result = match result {
+        Ok(rows_end) => write_sstable_trailer(range_tombstones, rows_end, &file, throttle).await,
+        Err(error) => Err(error),
+    };
+    match result {
+        Ok(file_size) => {
+            write_sstable_statistics(statistics, &file_name, sstable_dir);
+            if let Some(sstable_index) = sstable_index {
+                write_sstable_index(sstable_index, &file_name, sstable_dir);
+            }
+            Ok(WrittenSSTable {
+                file_path: file_name,
+                number_of_rows,
+                file_size,
+            })
+        }
+        Err(error) => {
+            let _ = crate::io::remove_file(&file_name);
+            Err(error)
+        }
+    }
+}
+
+async fn write_sstable_rows<'a>(
+    table_schema: &TableSchema,
+    rows: impl ExactSizeIterator<Item = (&'a str, Vec<u8>)>,
+    partition_index_bytes: Vec<u8>,
+    file: &File,
+    throttle: Option<&IoThrottle>,
+    statistics: &mut SSTableStatistics,
+    sstable_index: &mut Option<SSTableIndex>,
+) -> Result<u64, String> {
+    let partition_index_length = partition_index_bytes.len() as u64;
+    let mut header_bytes = SSTABLE_MAGIC.to_vec();
+    header_bytes.extend_from_slice(&table_schema.schema_version().to_be_bytes());
+    header_bytes.extend(partition_index_bytes);
+    if let Some(throttle) = throttle {
+        throttle.acquire(header_bytes.len()).await;
+    }
+    file.write_all_at(header_bytes, 0)
+        .await
+        .0
+        .map_err(|e| e.to_string())?;
 
-        file.write_all_at(partition_index_bytes, 0)
+    let mut offset = SSTABLE_HEADER_SIZE as u64 + partition_index_length;
+    let mut buffer = Vec::with_capacity(SSTABLE_WRITE_BUFFER_SIZE);
+    for (hash_key, row_bytes) in rows {
+        statistics.add_row(hash_key);
+        if let Some(sstable_index) = sstable_index {
+            sstable_index.add_row(&row_bytes, table_schema);
+        }
+        buffer.extend_from_slice(&row_bytes);
+        if buffer.len() >= SSTABLE_WRITE_BUFFER_SIZE {
+            if let Some(throttle) = throttle {
+                throttle.acquire(buffer.len()).await;
+            }
+            let written = buffer.len() as u64;
+            let (result, written_buffer) = file.write_all_at(buffer, offset).await;
+            result.map_err(|e| e.to_string())?;
+            offset += written;
+            buffer = written_buffer;
+            buffer.clear();
+        }
+    }
+    if !buffer.is_empty() {
+        if let Some(throttle) = throttle {
+            throttle.acquire(buffer.len()).await;
+        }
+        let written = buffer.len() as u64;
+        file.write_all_at(buffer, offset)
             .await
             .0
             .map_err(|e| e.to_string())?;
+        offset += written;
+    }
+    Ok(offset)
+}
 
-        file.write_all_at(encoded_rows, partition_index_length)
+// range tombstones are written after rows, file is synced once all of it is written
+async fn write_sstable_trailer(
+    range_tombstones: &[RangeTombstone],
+    rows_end: u64,
+    file: &File,
+    throttle: Option<&IoThrottle>,
+) -> Result<u64, String> {
+    let mut file_size = rows_end;
+    if !range_tombstones.is_empty() {
+        let range_tombstones_bytes = encode_range_tombstones(range_tombstones);
+        if let Some(throttle) = throttle {
+            throttle.acquire(range_tombstones_bytes.len()).await;
+        }
+        file_size += range_tombstones_bytes.len() as u64;
+        file.write_all_at(range_tombstones_bytes, rows_end)
             .await
             .0
             .map_err(|e| e.to_string())?;
-        file.sync_all().await.unwrap();
-
-        Ok(())
     }
+    file.sync_all().await.map_err(|e| e.to_string())?;
+    Ok(file_size)
 }
 
+#[derive(Clone)]
 pub struct SSTableMetadata {
     pub file_path: String,
     pub table_name: String,
@@ -91,70 +301,390 @@ pub struct SSTableMetadata {
     pub number_of_rows: usize,
     pub timestamp: u128,
     pub file_size: u64,
+    pub header_size: usize,
+    // None for files written before schema versioning
+    pub schema_version: Option<u64>,
+    // rows are sorted by sort key formatted as string, not by encoded primary key
+    pub legacy_key_order: bool,
+    // hash keys are zero padded instead of length prefixed
+    pub legacy_rows: bool,
+}
+
+impl SSTableMetadata {
+    pub fn rows_offset(&self) -> usize {
+        self.header_size + self.partition_index_size
+    }
+
+    // range tombstones start there, files without any end there
+    fn rows_end(&self, row_byte_size: usize) -> u64 {
+        (self.rows_offset() + self.number_of_rows * row_byte_size) as u64
+    }
 }
 
 pub async fn flush_memtable_to_sstable(
     memtable: Memtable,
     commit_log: Arc<Mutex<CommitLog>>,
     table_schema: TableSchema,
-    total_number_of_partitions: usize,
+    sstable_dir: String,
+    partition_map: PartitionMap,
 ) {
-    let (rows, partition_index) = memtable.to_sstable_rows(total_number_of_partitions, false);
-    let sstable_segment = SSTableSegment::new(table_schema, rows, partition_index);
-    if let Err(error) = sstable_segment.write_to_disk(SSTABLES_DIR).await {
-        tracing::error!("Failed to flush memtable to sstable: {}", error);
+    if !write_flushed_memtable(
+        &memtable,
+        &commit_log,
+        &table_schema,
+        &sstable_dir,
+        &partition_map,
+    )
+    .await
+    {
+        return;
+    }
+
+    // partial flushes of the log have to be in sstables too, failed ones keep it for replay
+    loop {
+        let partial_flushes = commit_log.lock().await.partial_flushes;
+        match (partial_flushes.pending, partial_flushes.failed) {
+            (_, true) => return,
+            (0, false) => break,
+            _ => sleep(PARTIAL_FLUSH_POLL_INTERVAL).await,
+        }
+    }
+
+    // leftover log only gets replayed into the same sstable rows again
+    if let Err(error) = supervise(BackgroundTask::Flush, &table_schema.name, || {
+        let commit_log = commit_log.clone();
+        async move { commit_log.lock().await.delete().await }
+    })
+    .await
+    {
+        tracing::error!("Giving up on deleting flushed commit log: {}", error);
     }
+}
+
+// flushed rows stay in the log, which gets a watermark, so that replay leaves them out
+pub async fn flush_memtable_part_to_sstable(
+    memtable: Memtable,
+    commit_log: Arc<Mutex<CommitLog>>,
+    watermark: FlushWatermark,
+    table_schema: TableSchema,
+    sstable_dir: String,
+    partition_map: PartitionMap,
+) {
+    let flushed = write_flushed_memtable(
+        &memtable,
+        &commit_log,
+        &table_schema,
+        &sstable_dir,
+        &partition_map,
+    )
+    .await;
 
     let mut commit_log = commit_log.lock().await;
-    commit_log.delete().await;
+    commit_log.partial_flushes.pending -= 1;
+    match flushed {
+        // log deleted meanwhile had its rows flushed along with this part
+        true if commit_log.file.is_some() => commit_log.write_flush_watermark(&watermark).await,
+        true => {}
+        false => commit_log.partial_flushes.failed = true,
+    }
+}
+
+// false if rows could not be written, they stay in commit log then
+async fn write_flushed_memtable(
+    memtable: &Memtable,
+    commit_log: &Arc<Mutex<CommitLog>>,
+    table_schema: &TableSchema,
+    sstable_dir: &str,
+    partition_map: &PartitionMap,
+) -> bool {
+    #[cfg(feature = "simulation")]
+    crate::simulation::delay_flush().await;
+
+    let commit_log_path = commit_log.lock().await.file_path.clone();
+    let started = BackgroundEvent::started(
+        BackgroundTask::Flush,
+        &table_schema.name,
+        vec![segment_name(&commit_log_path)],
+        memtable.memory_size as u64,
+    );
+    publish_background_event(started.clone());
+    let started_at = Instant::now();
+
+    // retried writes overwrite the same file
+    let timestamp = millis_from_epoch();
+    let written_sstable = match supervise(BackgroundTask::Flush, &table_schema.name, || {
+        write_memtable_to_sstable(
+            memtable,
+            table_schema,
+            partition_map,
+            timestamp,
+            sstable_dir,
+        )
+    })
+    .await
+    {
+        Ok(written_sstable) => written_sstable,
+        // log is kept, so that its rows are replayed on restart
+        Err(error) => {
+            tracing::error!(
+                "Giving up on flushing memtable of table '{}', rows stay in commit log: {}",
+                table_schema.name,
+                error
+            );
+            publish_background_event(started.failed(&error, started_at.elapsed()));
+            return false;
+        }
+    };
+    let (output_segments, bytes_written) = match &written_sstable {
+        Some(written_sstable) => (
+            vec![segment_name(&written_sstable.file_path)],
+            written_sstable.file_size,
+        ),
+        None => (Vec::new(), 0),
+    };
+    publish_background_event(started.finished(
+        output_segments,
+        bytes_written,
+        started_at.elapsed(),
+    ));
+    if let Some(written_sstable) = written_sstable {
+        notify_storage_hooks(|hooks| {
+            hooks.on_flush(&table_schema.name, written_sstable.number_of_rows)
+        });
+    }
+    true
+}
+
+// what a get consulted, reported to clients asking to explain their reads
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReadTrace {
+    pub primary_key: String,
+    pub memtable_hit: bool,
+    pub memtable_time: Duration,
+    // newest first, in order they were consulted
+    pub sstables: Vec<SSTableRead>,
+    // older sstables could not hold a newer version than the one already found
+    pub sstables_not_consulted: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SSTableRead {
+    pub file_name: String,
+    // sstable has no rows of the partition, so only its partition index was read
+    pub partition_skipped: bool,
+    pub rows_read: usize,
+    pub bytes_read: u64,
+    pub found: bool,
+    pub time: Duration,
 }
 
 pub async fn read_row_from_sstable(
     primary_key: &str,
     partition: usize,
-    table: &Table,
+    table_schema: &TableSchema,
+    sstable_dir: &str,
+) -> Option<Row> {
+    let mut read_trace = ReadTrace::default();
+    trace_row_read_from_sstable(
+        primary_key,
+        partition,
+        table_schema,
+        sstable_dir,
+        &mut read_trace,
+    )
+    .await
+}
+
+pub async fn trace_row_read_from_sstable(
+    primary_key: &str,
+    partition: usize,
+    table_schema: &TableSchema,
     sstable_dir: &str,
+    read_trace: &mut ReadTrace,
 ) -> Option<Row> {
-    let mut sstable_metadatas = get_sstables_metadata(&table.table_schema.name, sstable_dir);
+    let mut sstable_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
     sstable_metadatas.sort_by(|metadata1, metadata2| metadata2.timestamp.cmp(&metadata1.timestamp));
 
-    for sstable_metadata in sstable_metadatas {
+    // compacted sstables are newer than rows they hold, so that first version found is not
+    // necessarily the newest one, e.g. tombstone of a newer sstable shadows the compacted row
+    let mut newest_row: Option<Row> = None;
+    let mut range_tombstones = Vec::new();
+    let number_of_sstables = sstable_metadatas.len();
+    for (index, sstable_metadata) in sstable_metadatas.into_iter().enumerate() {
+        // rows of sstable are not newer than the sstable itself
+        if newest_row
+            .as_ref()
+            .is_some_and(|row| row.timestamp >= sstable_metadata.timestamp)
+        {
+            read_trace.sstables_not_consulted = number_of_sstables - index;
+            break;
+        }
+
+        let started_at = Instant::now();
+        let mut sstable_read = SSTableRead {
+            file_name: sstable_file_name(&sstable_metadata.file_path),
+            ..SSTableRead::default()
+        };
+        let row = trace_row_read_from_sstable_file(
+            primary_key,
+            partition,
+            &sstable_metadata,
+            table_schema,
+            &mut sstable_read,
+        )
+        .await;
+        range_tombstones.extend(
+            read_sstable_range_tombstones(&sstable_metadata, table_schema)
+                .await
+                .into_iter()
+                .filter(|range_tombstone| range_tombstone.contains(primary_key)),
+        );
+        sstable_read.found = row.is_some();
+        sstable_read.time = started_at.elapsed();
+        read_trace.sstables.push(sstable_read);
+        if let Some(row) = row {
+            if newest_row
+                .as_ref()
+                .is_none_or(|newest_row| row.timestamp > newest_row.timestamp)
+            {
+                newest_row = Some(row);
+            }
+        }
+    }
+
+    // range tombstones of sstables not consulted are not newer than the row found
+    newest_row.map(|row| match is_shadowed(&row, &range_tombstones) {
+        true => shadowed_row_tombstone(row, &range_tombstones),
+        false => row,
+    })
+}
+
+// tombstone of the newest range tombstone shadowing the row, read like a row tombstone would be
+fn shadowed_row_tombstone(row: Row, range_tombstones: &[RangeTombstone]) -> Row {
+    let timestamp = range_tombstones
+        .iter()
+        .filter(|range_tombstone| range_tombstone.shadows(&row))
+        .map(|range_tombstone| range_tombstone.timestamp)
+        .max()
+        .unwrap_or(row.timestamp);
+    Row {
+        values: HashMap::new(),
+        timestamp,
+        marked_for_deletion: true,
+        ..row
+    }
+}
+
+pub(crate) async fn read_row_from_sstable_file(
+    primary_key: &str,
+    partition: usize,
+    sstable_metadata: &SSTableMetadata,
+    table_schema: &TableSchema,
+) -> Option<Row> {
+    let mut sstable_read = SSTableRead::default();
+    trace_row_read_from_sstable_file(
+        primary_key,
+        partition,
+        sstable_metadata,
+        table_schema,
+        &mut sstable_read,
+    )
+    .await
+}
+
+async fn trace_row_read_from_sstable_file(
+    primary_key: &str,
+    partition: usize,
+    sstable_metadata: &SSTableMetadata,
+    table_schema: &TableSchema,
+    sstable_read: &mut SSTableRead,
+) -> Option<Row> {
+    let file = OpenOptions::new()
+        .read(true)
+        .open(&sstable_metadata.file_path)
+        .await
+        .unwrap();
+
+    let partition_index = read_partition_index(&file, sstable_metadata).await;
+    sstable_read.bytes_read += sstable_metadata.partition_index_size as u64;
+    if !partition_index.contains_key(&partition) {
+        sstable_read.partition_skipped = true;
+        return None;
+    }
+
+    binary_search_row_in_file(
+        primary_key,
+        partition,
+        file,
+        partition_index,
+        sstable_metadata,
+        table_schema,
+        sstable_read,
+    )
+    .await
+}
+
+fn sstable_file_name(file_path: &str) -> String {
+    Path::new(file_path)
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+async fn read_partition_index(
+    file: &File,
+    sstable_metadata: &SSTableMetadata,
+) -> HashMap<usize, usize> {
+    let partition_index_bytes = vec![0u8; sstable_metadata.partition_index_size];
+    let partition_index_bytes = file
+        .read_exact_at(partition_index_bytes, sstable_metadata.header_size as u64)
+        .await
+        .1;
+
+    // sstables holding only range tombstones have no partitions
+    String::from_utf8(partition_index_bytes)
+        .unwrap()
+        .split(',')
+        .filter(|pair_string| !pair_string.is_empty())
+        .map(|pair_string| {
+            let (partition_string, row_number_string) = pair_string.split_once(":").unwrap();
+            (
+                partition_string.parse().unwrap(),
+                row_number_string.parse().unwrap(),
+            )
+        })
+        .collect()
+}
+
+// only partition indexes are read, rows of a partition span from its start to the next partition's start
+pub async fn count_sstable_rows_per_partition(
+    table_name: &str,
+    sstable_dir: &str,
+) -> HashMap<usize, usize> {
+    let mut rows_per_partition = HashMap::new();
+
+    for sstable_metadata in get_sstables_metadata(table_name, sstable_dir) {
         let file = OpenOptions::new()
             .read(true)
-            .open(sstable_metadata.file_path)
+            .open(&sstable_metadata.file_path)
             .await
             .unwrap();
+        let partition_index = read_partition_index(&file, &sstable_metadata).await;
 
-        let partition_index_bytes = vec![0u8; sstable_metadata.partition_index_size];
-        let partition_index_bytes = file.read_exact_at(partition_index_bytes, 0).await.1;
-
-        let partition_index: HashMap<_, _> = String::from_utf8(partition_index_bytes)
-            .unwrap()
-            .split(',')
-            .map(|pair_string| {
-                let (partition_string, row_number_string) = pair_string.split_once(":").unwrap();
-                (
-                    partition_string.parse().unwrap(),
-                    row_number_string.parse().unwrap(),
-                )
-            })
-            .collect();
+        let mut partition_starts: Vec<_> = partition_index.into_iter().collect();
+        partition_starts.sort_by_key(|(_, row_number)| *row_number);
+        let partition_ends = partition_starts
+            .iter()
+            .skip(1)
+            .map(|(_, row_number)| *row_number)
+            .chain([sstable_metadata.number_of_rows]);
 
-        if let Some(row) = binary_search_row_in_file(
-            primary_key,
-            partition,
-            file,
-            partition_index,
-            sstable_metadata.number_of_rows,
-            &table.table_schema,
-        )
-        .await
-        {
-            return Some(row);
+        for ((partition, start), end) in partition_starts.iter().zip(partition_ends) {
+            *rows_per_partition.entry(*partition).or_insert(0) += end - start;
         }
     }
 
-    None
+    rows_per_partition
 }
 
 async fn binary_search_row_in_file(
@@ -162,36 +692,43 @@ async fn binary_search_row_in_file(
     partition: usize,
     file: File,
     partition_index: HashMap<usize, usize>,
-    num_of_rows: usize,
+    sstable_metadata: &SSTableMetadata,
     table_schema: &TableSchema,
+    sstable_read: &mut SSTableRead,
 ) -> Option<Row> {
     if !partition_index.contains_key(&partition) {
         return None;
     }
 
+    // rows of every partition are contiguous, partition ends where the next one starts
     let mut left_row_number = partition_index[&partition];
-    let mut right_row_number = match partition_index.keys().max().unwrap() == &partition {
-        true => num_of_rows - 1,
-        false => partition_index[&(partition + 1)],
-    };
+    let mut right_row_number = partition_index
+        .values()
+        .filter(|row_number| **row_number > left_row_number)
+        .min()
+        .cloned()
+        .unwrap_or(sstable_metadata.number_of_rows);
+    let rows_offset = sstable_metadata.rows_offset();
 
     let mut row_bytes = vec![0u8; table_schema.row_byte_size()];
 
-    while left_row_number <= right_row_number {
+    while left_row_number < right_row_number {
         let current_row_number = (left_row_number + right_row_number) / 2;
         row_bytes = file
             .read_exact_at(
                 row_bytes,
-                (current_row_number * table_schema.row_byte_size()) as u64,
+                (rows_offset + current_row_number * table_schema.row_byte_size()) as u64,
             )
             .await
             .1;
+        sstable_read.rows_read += 1;
+        sstable_read.bytes_read += row_bytes.len() as u64;
         let current_row = decode_row(&row_bytes, table_schema);
 
         if primary_key > current_row.primary_key.as_str() {
             left_row_number = current_row_number + 1;
         } else if primary_key < current_row.primary_key.as_str() {
-            right_row_number = current_row_number - 1;
+            right_row_number = current_row_number;
         } else {
             return Some(current_row);
         }
@@ -200,6 +737,71 @@ async fn binary_search_row_in_file(
     None
 }
 
+// rows of a partition with primary key in range, first of them is found by binary search and the
+// rest are read one by one until the range ends
+pub(crate) async fn read_sstable_rows_in_range(
+    start: Bound<&str>,
+    end: Bound<&str>,
+    partition: usize,
+    sstable_metadata: &SSTableMetadata,
+    table_schema: &TableSchema,
+) -> Vec<Row> {
+    let file = OpenOptions::new()
+        .read(true)
+        .open(&sstable_metadata.file_path)
+        .await
+        .unwrap();
+    let partition_index = read_partition_index(&file, sstable_metadata).await;
+    let Some(&partition_start) = partition_index.get(&partition) else {
+        return Vec::new();
+    };
+    let partition_end = partition_index
+        .values()
+        .filter(|row_number| **row_number > partition_start)
+        .min()
+        .cloned()
+        .unwrap_or(sstable_metadata.number_of_rows);
+    let rows_offset = sstable_metadata.rows_offset();
+    let (row_byte_size, decode) = get_row_decoder(sstable_metadata, table_schema);
+
+    let mut row_bytes = vec![0u8; row_byte_size];
+    let (mut left_row_number, mut right_row_number) = (partition_start, partition_end);
+    while left_row_number < right_row_number {
+        let current_row_number = (left_row_number + right_row_number) / 2;
+        row_bytes = file
+            .read_exact_at(
+                row_bytes,
+                (rows_offset + current_row_number * row_byte_size) as u64,
+            )
+            .await
+            .1;
+        let primary_key = decode(&row_bytes, table_schema).primary_key;
+        let before_start = match start {
+            Bound::Included(start) => primary_key.as_str() < start,
+            Bound::Excluded(start) => primary_key.as_str() <= start,
+            Bound::Unbounded => false,
+        };
+        match before_start {
+            true => left_row_number = current_row_number + 1,
+            false => right_row_number = current_row_number,
+        }
+    }
+
+    let mut rows = Vec::new();
+    for row_number in left_row_number..partition_end {
+        row_bytes = file
+            .read_exact_at(row_bytes, (rows_offset + row_number * row_byte_size) as u64)
+            .await
+            .1;
+        let row = decode(&row_bytes, table_schema);
+        if !RangeBounds::<str>::contains(&(Bound::Unbounded, end), row.primary_key.as_str()) {
+            break;
+        }
+        rows.push(row);
+    }
+    rows
+}
+
 pub fn get_sstables_metadata(table_name: &str, sstable_dir: &str) -> Vec<SSTableMetadata> {
     read_dir(sstable_dir)
         .unwrap()
@@ -210,13 +812,17 @@ pub fn get_sstables_metadata(table_name: &str, sstable_dir: &str) -> Vec<SSTable
             let file_size = memtable_path.metadata().unwrap().size();
             let file_name = memtable_path.file_name().to_str().unwrap().to_string();
 
-            let split_result: Vec<_> = file_name.split("-").collect();
-            let file_table_name = split_result[0];
-            let partition_index_size = split_result[1].parse::<usize>().unwrap();
-            let number_of_rows = split_result[2].parse::<usize>().unwrap();
-            let timestamp = split_result[3].parse::<u128>().unwrap();
+            // superseded sstables kept for history have time they were superseded at appended
+            let (file_table_name, numbers) = parse_table_file_name(&file_name)?;
+            let [partition_index_size, number_of_rows, timestamp, ..] = numbers[..] else {
+                return None;
+            };
+            let (partition_index_size, number_of_rows) =
+                (partition_index_size as usize, number_of_rows as usize);
 
             if table_name == file_table_name {
+                let (schema_version, legacy_key_order, legacy_rows) =
+                    read_sstable_header(&file_path);
                 return Some(SSTableMetadata {
                     file_path: file_path.to_string(),
                     table_name: file_table_name.to_string(),
@@ -224,6 +830,13 @@ pub fn get_sstables_metadata(table_name: &str, sstable_dir: &str) -> Vec<SSTable
                     number_of_rows,
                     timestamp,
                     file_size,
+                    header_size: match schema_version {
+                        Some(_) => SSTABLE_HEADER_SIZE,
+                        None => 0,
+                    },
+                    schema_version,
+                    legacy_key_order,
+                    legacy_rows,
                 });
             }
             None
@@ -231,28 +844,185 @@ pub fn get_sstables_metadata(table_name: &str, sstable_dir: &str) -> Vec<SSTable
         .collect()
 }
 
+// schema version and whether rows are in legacy key order and legacy encoding
+fn read_sstable_header(file_path: &str) -> (Option<u64>, bool, bool) {
+    let mut header_bytes = [0u8; SSTABLE_HEADER_SIZE];
+    if std::fs::File::open(file_path)
+        .and_then(|mut file| file.read_exact(&mut header_bytes))
+        .is_err()
+    {
+        return (None, true, true);
+    }
+
+    let schema_version = u64::from_be_bytes(header_bytes[4..].try_into().unwrap());
+    if header_bytes.starts_with(SSTABLE_MAGIC) {
+        (Some(schema_version), false, false)
+    } else if header_bytes.starts_with(UNPREFIXED_SSTABLE_MAGIC) {
+        (Some(schema_version), false, true)
+    } else if header_bytes.starts_with(LEGACY_SSTABLE_MAGIC) {
+        (Some(schema_version), true, true)
+    } else {
+        (None, true, true)
+    }
+}
+
+pub fn check_sstables_schema_version(
+    table_schema: &TableSchema,
+    sstable_dir: &str,
+) -> Result<(), String> {
+    for sstable_metadata in get_sstables_metadata(&table_schema.name, sstable_dir) {
+        match sstable_metadata.schema_version {
+            Some(schema_version) if schema_version != table_schema.schema_version() => {
+                return Err(format!(
+                    "SSTable '{}' was written with schema version {}, table '{}' is at version {}",
+                    sstable_metadata.file_path,
+                    schema_version,
+                    table_schema.name,
+                    table_schema.schema_version()
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+// size tiered compaction, tunable at runtime through compaction thread
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactionSettings {
+    pub bucket_low: f64,
+    pub bucket_high: f64,
+    // sstables smaller than that are bucketed together regardless of their size
+    pub sstable_min_size: u64,
+    pub interval: Duration,
+    // tables compacted at the same time on every interval, one bucket each
+    pub max_concurrent_compactions: usize,
+    // shared by every database with sstables on the same disk, 0 means unlimited
+    pub io_rate_limit_mb: u64,
+}
+
+impl Default for CompactionSettings {
+    fn default() -> CompactionSettings {
+        CompactionSettings {
+            bucket_low: 0.5,
+            bucket_high: 1.5,
+            sstable_min_size: 50 * MEGABYTE as u64,
+            interval: Duration::from_secs(60),
+            max_concurrent_compactions: 2,
+            io_rate_limit_mb: 0,
+        }
+    }
+}
+
+// fields left as None keep their current value
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompactionSettingsUpdate {
+    pub bucket_low: Option<f64>,
+    pub bucket_high: Option<f64>,
+    pub sstable_min_size: Option<u64>,
+    pub interval: Option<Duration>,
+    pub max_concurrent_compactions: Option<usize>,
+    pub io_rate_limit_mb: Option<u64>,
+}
+
+impl CompactionSettings {
+    pub fn apply(&self, update: &CompactionSettingsUpdate) -> Result<CompactionSettings, String> {
+        let settings = CompactionSettings {
+            bucket_low: update.bucket_low.unwrap_or(self.bucket_low),
+            bucket_high: update.bucket_high.unwrap_or(self.bucket_high),
+            sstable_min_size: update.sstable_min_size.unwrap_or(self.sstable_min_size),
+            interval: update.interval.unwrap_or(self.interval),
+            max_concurrent_compactions: update
+                .max_concurrent_compactions
+                .unwrap_or(self.max_concurrent_compactions),
+            io_rate_limit_mb: update.io_rate_limit_mb.unwrap_or(self.io_rate_limit_mb),
+        };
+
+        if !(settings.bucket_low > 0.0 && settings.bucket_low <= 1.0) {
+            return Err(format!(
+                "'bucket_low' must be in (0, 1], got {}",
+                settings.bucket_low
+            ));
+        }
+        if !(settings.bucket_high >= 1.0 && settings.bucket_high.is_finite()) {
+            return Err(format!(
+                "'bucket_high' must be finite and at least 1, got {}",
+                settings.bucket_high
+            ));
+        }
+        if settings.interval.is_zero() {
+            return Err("'interval' must be greater than 0".to_string());
+        }
+        if settings.max_concurrent_compactions == 0 {
+            return Err("'max_concurrent_compactions' must be greater than 0".to_string());
+        }
+
+        Ok(settings)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactionBucket {
+    pub sstables: usize,
+    pub bytes: u64,
+    // bucket is waiting to be compacted
+    pub pending: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableCompactionState {
+    pub table_name: String,
+    pub buckets: Vec<CompactionBucket>,
+    pub pending_sstables: usize,
+    pub last_duration: Option<Duration>,
+    // tables with highest priority are compacted first, 0 if nothing is pending
+    pub priority: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactionState {
+    pub settings: CompactionSettings,
+    pub tables: Vec<TableCompactionState>,
+}
+
+static COMPACTION_BUCKET_SIZE_RANGE: RangeInclusive<usize> = 4..=32;
+// tables with fewer sstables are not compacted at all
+static MIN_SSTABLES_TO_COMPACT: usize = 5;
+
 pub async fn compact_sstables(
     table_schema: &TableSchema,
-    total_number_of_partitions: usize,
+    partition_map: &PartitionMap,
     sstable_dir: &str,
-) {
-    // size tiered compaction
-    let bucket_low = 0.5;
-    let bucket_high = 1.5;
-    let bucket_size_range = 4..=32;
-    let sstable_min_size = (50 * MEGABYTE) as f64;
+    settings: &CompactionSettings,
+) -> usize {
+    let sstables_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
+    let mut compacted_buckets = 0;
+    for bucket in get_compaction_buckets(sstables_metadatas, settings) {
+        if COMPACTION_BUCKET_SIZE_RANGE.contains(&bucket.len()) {
+            compact_bucket(bucket, table_schema, partition_map, sstable_dir).await;
+            compacted_buckets += 1;
+        }
+    }
+
+    compacted_buckets
+}
 
-    let mut sstables_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
+fn get_compaction_buckets(
+    mut sstables_metadatas: Vec<SSTableMetadata>,
+    settings: &CompactionSettings,
+) -> Vec<Vec<SSTableMetadata>> {
+    let sstable_min_size = settings.sstable_min_size as f64;
     sstables_metadatas
         .sort_by(|metadata1, metadata2| metadata2.file_size.cmp(&metadata1.file_size));
 
-    let mut buckets = Vec::new();
+    let mut buckets: Vec<Vec<SSTableMetadata>> = Vec::new();
     'outer: for sstable_metadata in sstables_metadatas {
         let sstable_size = sstable_metadata.file_size as f64;
         for bucket in buckets.iter_mut() {
-            let bucket_average_size = get_bucket_average_size(&bucket);
-            let bucket_range =
-                (bucket_low * bucket_average_size)..(bucket_high * bucket_average_size);
+            let bucket_average_size = get_bucket_average_size(bucket);
+            let bucket_range = (settings.bucket_low * bucket_average_size)
+                ..(settings.bucket_high * bucket_average_size);
             if bucket_range.contains(&sstable_size)
                 || (sstable_size < sstable_min_size && bucket_average_size < sstable_min_size)
             {
@@ -264,20 +1034,74 @@ pub async fn compact_sstables(
         buckets.push(vec![sstable_metadata]);
     }
 
-    for bucket in buckets {
-        if bucket_size_range.contains(&bucket.len()) {
-            compact_bucket(
-                bucket,
-                table_schema,
-                total_number_of_partitions,
-                sstable_dir,
-            )
-            .await;
-        }
+    buckets
+}
+
+pub fn get_table_compaction_state(
+    table_schema: &TableSchema,
+    sstable_dir: &str,
+    settings: &CompactionSettings,
+    last_duration: Option<Duration>,
+    waited_intervals: usize,
+) -> TableCompactionState {
+    let sstables_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
+    let table_compacted = sstables_metadatas.len() >= MIN_SSTABLES_TO_COMPACT;
+
+    let buckets: Vec<_> = get_compaction_buckets(sstables_metadatas, settings)
+        .iter()
+        .map(|bucket| CompactionBucket {
+            sstables: bucket.len(),
+            bytes: bucket.iter().map(|metadata| metadata.file_size).sum(),
+            pending: table_compacted && COMPACTION_BUCKET_SIZE_RANGE.contains(&bucket.len()),
+        })
+        .collect();
+
+    let sstables = buckets.iter().map(|bucket| bucket.sstables).sum();
+    let pending_sstables = buckets
+        .iter()
+        .filter(|bucket| bucket.pending)
+        .map(|bucket| bucket.sstables)
+        .sum();
+    TableCompactionState {
+        table_name: table_schema.name.clone(),
+        priority: compaction_priority(sstables, pending_sstables, waited_intervals),
+        pending_sstables,
+        buckets,
+        last_duration,
+    }
+}
+
+// number of sstables estimates read amplification, pending ones the backlog, tables passed over
+// get boosted on every interval they wait, so that one large backlog does not starve the others
+fn compaction_priority(sstables: usize, pending_sstables: usize, waited_intervals: usize) -> usize {
+    match pending_sstables {
+        0 => 0,
+        _ => (sstables + pending_sstables) * (waited_intervals + 1),
     }
 }
 
-fn get_bucket_average_size(bucket: &Vec<SSTableMetadata>) -> f64 {
+// tables with pending buckets, most urgent first, ties keep order of schemas
+fn schedule_compactions<'a>(
+    table_schemas: &'a [TableSchema],
+    sstable_dir: &str,
+    settings: &CompactionSettings,
+    waited_intervals: &HashMap<String, usize>,
+) -> Vec<&'a TableSchema> {
+    let mut pending: Vec<_> = table_schemas
+        .iter()
+        .map(|schema| {
+            let waited = waited_intervals.get(&schema.name).copied().unwrap_or(0);
+            let state = get_table_compaction_state(schema, sstable_dir, settings, None, waited);
+            (schema, state.priority)
+        })
+        .filter(|(_, priority)| *priority > 0)
+        .collect();
+    pending.sort_by(|(_, priority1), (_, priority2)| priority2.cmp(priority1));
+
+    pending.into_iter().map(|(schema, _)| schema).collect()
+}
+
+fn get_bucket_average_size(bucket: &[SSTableMetadata]) -> f64 {
     let bucket_total_size: u64 = bucket.iter().map(|metadata| metadata.file_size).sum();
     bucket_total_size as f64 / bucket.len() as f64
 }
@@ -285,80 +1109,1117 @@ fn get_bucket_average_size(bucket: &Vec<SSTableMetadata>) -> f64 {
 async fn compact_bucket(
     bucket: Vec<SSTableMetadata>,
     table_schema: &TableSchema,
-    total_number_of_partitions: usize,
+    partition_map: &PartitionMap,
     sstable_dir: &str,
 ) {
-    let mut memtable = Memtable::default();
+    let started = BackgroundEvent::started(
+        BackgroundTask::Compaction,
+        &table_schema.name,
+        bucket
+            .iter()
+            .map(|sstable_metadata| segment_name(&sstable_metadata.file_path))
+            .collect(),
+        bucket
+            .iter()
+            .map(|sstable_metadata| sstable_metadata.file_size)
+            .sum(),
+    );
+    publish_background_event(started.clone());
+    let started_at = Instant::now();
 
-    for sstable_metadata in bucket.iter() {
-        let file = OpenOptions::new()
-            .read(true)
-            .open(&sstable_metadata.file_path)
-            .await
-            .unwrap();
+    let throttle = compaction_io_throttle(sstable_dir);
+    let append_only = table_schema.options.append_only;
+    let mergeable = !append_only
+        && !bucket.iter().any(|sstable_metadata| {
+            sstable_metadata.legacy_rows || sstable_metadata.legacy_key_order
+        });
+    let merged_rows = match mergeable {
+        true => merge_sstables_rows(&bucket, table_schema, partition_map, &throttle).await,
+        false => None,
+    };
+    // sstables not sorted by current partition map and key encoding are merged through memtable
+    let mut rows = match merged_rows {
+        Some(rows) => rows,
+        None if append_only => concatenate_sstables_rows(&bucket, table_schema, &throttle).await,
+        None => {
+            let mut memtable = Memtable::default();
+            for sstable_metadata in bucket.iter() {
+                let mut row_stream =
+                    SSTableRowStream::open(sstable_metadata, table_schema, throttle.clone());
+                while let Some(row) = row_stream.next_row(table_schema).await {
+                    memtable.insert(row, true);
+                }
+            }
+            memtable.to_sstable_rows(partition_map).0
+        }
+    };
+    // range tombstones delete older versions merged from the bucket right away
+    let mut range_tombstones = Vec::new();
+    for sstable_metadata in &bucket {
+        range_tombstones
+            .extend(read_sstable_range_tombstones(sstable_metadata, table_schema).await);
+    }
+    if !append_only {
+        rows.retain(|row| !is_shadowed(row, &range_tombstones));
+        drop_obsolete_tombstones(
+            &mut rows,
+            &mut range_tombstones,
+            &bucket,
+            table_schema,
+            partition_map,
+            sstable_dir,
+        )
+        .await;
+    }
+
+    // expired rows are dropped after merge, so that older versions are overwritten first
+    if table_schema.options.retention.is_some() {
+        let now = millis_from_epoch();
+        rows.retain(|row| !table_schema.options.is_row_expired(row, now));
+    }
+    let partition_index = sort_rows_by_partition(&mut rows, partition_map);
+
+    let number_of_rows = rows.len();
+    let mut finished = started.finished(Vec::new(), 0, Duration::ZERO);
+    if !rows.is_empty() || !range_tombstones.is_empty() {
+        let sstable_segment = SSTableSegment::new(table_schema.clone(), rows, partition_index)
+            .with_range_tombstones(range_tombstones)
+            .with_throttle(throttle);
+        // merged sstables are kept, so that no rows are lost
+        match sstable_segment.write_to_disk(sstable_dir).await {
+            Ok(written_sstable) => {
+                finished.output_segments = vec![segment_name(&written_sstable.file_path)];
+                finished.bytes_written = written_sstable.file_size;
+            }
+            Err(error) => {
+                tracing::error!("Failed to write compacted sstable: {}", error);
+                publish_background_event(started.failed(&error, started_at.elapsed()));
+                return;
+            }
+        }
+    }
+    finished.duration = started_at.elapsed();
+    publish_background_event(finished);
+    notify_storage_hooks(|hooks| {
+        hooks.on_compaction(&table_schema.name, bucket.len(), number_of_rows)
+    });
+
+    for sstable_metadata in bucket {
+        if table_schema.options.history.is_some() {
+            retire_sstable_file(&sstable_metadata, sstable_dir);
+        }
+        remove_sstable_statistics(&sstable_metadata, sstable_dir);
+        remove_sstable_index(&sstable_metadata, sstable_dir);
+        remove_sstable_file(&sstable_metadata.file_path);
+    }
+}
+
+fn segment_name(file_path: &str) -> String {
+    Path::new(file_path)
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_path.to_string())
+}
+
+pub(crate) async fn read_sstable_rows(
+    sstable_metadata: &SSTableMetadata,
+    table_schema: &TableSchema,
+) -> Vec<Row> {
+    let file = OpenOptions::new()
+        .read(true)
+        .open(&sstable_metadata.file_path)
+        .await
+        .unwrap();
+
+    let (row_byte_size, decode) = get_row_decoder(sstable_metadata, table_schema);
+    let content_buffer = Vec::with_capacity(sstable_metadata.number_of_rows * row_byte_size);
+    let content_buffer = file
+        .read_exact_at(content_buffer, sstable_metadata.rows_offset() as u64)
+        .await
+        .1;
+
+    let mut rows = Vec::with_capacity(sstable_metadata.number_of_rows);
+    let mut offset = 0;
+    while offset != content_buffer.len() {
+        rows.push(decode(
+            &content_buffer[offset..offset + row_byte_size],
+            table_schema,
+        ));
+
+        offset += row_byte_size;
+    }
+
+    rows
+}
+
+// files without range tombstones end with their rows, so that nothing is read for them
+pub(crate) async fn read_sstable_range_tombstones(
+    sstable_metadata: &SSTableMetadata,
+    table_schema: &TableSchema,
+) -> Vec<RangeTombstone> {
+    let (row_byte_size, _) = get_row_decoder(sstable_metadata, table_schema);
+    let rows_end = sstable_metadata.rows_end(row_byte_size);
+    if sstable_metadata.file_size <= rows_end {
+        return Vec::new();
+    }
+
+    let file = OpenOptions::new()
+        .read(true)
+        .open(&sstable_metadata.file_path)
+        .await
+        .unwrap();
+    let range_tombstones_bytes =
+        Vec::with_capacity((sstable_metadata.file_size - rows_end) as usize);
+    let range_tombstones_bytes = file.read_exact_at(range_tombstones_bytes, rows_end).await.1;
+    decode_range_tombstones(&range_tombstones_bytes).unwrap_or_else(|| {
+        panic!(
+            "Corrupted range tombstones in sstable '{}'",
+            sstable_metadata.file_path
+        )
+    })
+}
+
+fn get_row_decoder(
+    sstable_metadata: &SSTableMetadata,
+    table_schema: &TableSchema,
+) -> (usize, RowDecoder) {
+    match sstable_metadata.legacy_rows {
+        true => (table_schema.legacy_row_byte_size(), decode_legacy_row),
+        false => (table_schema.row_byte_size(), decode_row),
+    }
+}
+
+type RowDecoder = fn(&[u8], &TableSchema) -> Row;
+
+// reads sstable rows in bounded chunks, next chunks are read ahead while current one is consumed
+struct SSTableRowStream {
+    chunks: Receiver<Vec<u8>>,
+    chunk: Vec<u8>,
+    offset: usize,
+    row_byte_size: usize,
+    decode: RowDecoder,
+}
+
+impl SSTableRowStream {
+    fn open(
+        sstable_metadata: &SSTableMetadata,
+        table_schema: &TableSchema,
+        throttle: IoThrottle,
+    ) -> SSTableRowStream {
+        let (row_byte_size, decode) = get_row_decoder(sstable_metadata, table_schema);
+        let chunk_size = (SSTABLE_READ_CHUNK_SIZE / row_byte_size).max(1) * row_byte_size;
+        let (mut sender, receiver) = mpsc::channel(SSTABLE_READ_AHEAD_CHUNKS);
+
+        let file_path = sstable_metadata.file_path.clone();
+        let mut offset = sstable_metadata.rows_offset() as u64;
+        let end = sstable_metadata.rows_end(row_byte_size);
+        monoio::spawn(async move {
+            let file = OpenOptions::new()
+                .read(true)
+                .open(&file_path)
+                .await
+                .unwrap();
+            while offset < end {
+                let length = (end - offset).min(chunk_size as u64);
+                throttle.acquire(length as usize).await;
+                let (result, chunk) = file
+                    .read_exact_at(Vec::with_capacity(length as usize), offset)
+                    .await;
+                result.unwrap();
+                offset += length;
+                // stream was dropped halfway
+                if sender.send(chunk).await.is_err() {
+                    break;
+                }
+            }
+        });
 
-        let content_buffer = Vec::with_capacity(
-            sstable_metadata.file_size as usize - sstable_metadata.partition_index_size,
+        SSTableRowStream {
+            chunks: receiver,
+            chunk: Vec::new(),
+            offset: 0,
+            row_byte_size,
+            decode,
+        }
+    }
+
+    async fn next_row(&mut self, table_schema: &TableSchema) -> Option<Row> {
+        if self.offset == self.chunk.len() {
+            self.chunk = self.chunks.next().await?;
+            self.offset = 0;
+        }
+
+        let row = (self.decode)(
+            &self.chunk[self.offset..self.offset + self.row_byte_size],
+            table_schema,
         );
-        let content_buffer = file
-            .read_exact_at(content_buffer, sstable_metadata.partition_index_size as u64)
-            .await
-            .1;
+        self.offset += self.row_byte_size;
+        Some(row)
+    }
+}
 
-        let mut offset = sstable_metadata.partition_index_size;
-        while offset != content_buffer.len() {
-            let row = decode_row(
-                &content_buffer[offset..offset + table_schema.row_byte_size()],
-                table_schema,
-            );
-            memtable.insert(row, true);
+// every sstable is sorted by partition and primary key, so rows are merged without re-sorting,
+// newest version of a row wins and tie goes to the sstable earlier in bucket like in memtable,
+// None if some sstable turns out not to be sorted by current partition map
+async fn merge_sstables_rows(
+    bucket: &[SSTableMetadata],
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+    throttle: &IoThrottle,
+) -> Option<Vec<Row>> {
+    let mut row_streams: Vec<_> = bucket
+        .iter()
+        .map(|sstable_metadata| {
+            SSTableRowStream::open(sstable_metadata, table_schema, throttle.clone())
+        })
+        .collect();
 
-            offset += table_schema.row_byte_size();
+    let mut heads = Vec::with_capacity(row_streams.len());
+    let mut heap = BinaryHeap::with_capacity(row_streams.len());
+    for (stream_index, row_stream) in row_streams.iter_mut().enumerate() {
+        let row = row_stream.next_row(table_schema).await;
+        if let Some(row) = &row {
+            let partition = partition_map.get_partition(&row.hash_key);
+            heap.push(Reverse((partition, row.primary_key.clone(), stream_index)));
         }
+        heads.push(row);
     }
 
-    let (rows, partition_index) = memtable.to_sstable_rows(total_number_of_partitions, true);
+    let mut rows: Vec<Row> = Vec::new();
+    while let Some(Reverse((partition, primary_key, stream_index))) = heap.pop() {
+        let row = heads[stream_index].take().unwrap();
+
+        if let Some(next_row) = row_streams[stream_index].next_row(table_schema).await {
+            let next_partition = partition_map.get_partition(&next_row.hash_key);
+            if (next_partition, &next_row.primary_key) <= (partition, &primary_key) {
+                return None;
+            }
+            heap.push(Reverse((
+                next_partition,
+                next_row.primary_key.clone(),
+                stream_index,
+            )));
+            heads[stream_index] = Some(next_row);
+        }
 
-    let sstable_segment = SSTableSegment::new(table_schema.clone(), rows, partition_index);
-    if let Err(error) = sstable_segment.write_to_disk(sstable_dir).await {
-        tracing::error!("Failed to flush memtable to sstable: {}", error);
+        match rows.last_mut() {
+            Some(last_row) if last_row.primary_key == row.primary_key => {
+                if row.timestamp > last_row.timestamp {
+                    *last_row = row;
+                }
+            }
+            _ => rows.push(row),
+        }
     }
 
+    Some(rows)
+}
+
+// rows of append-only tables are never overwritten nor deleted, so there are no versions to merge,
+// the same row can only be in two sstables when it got flushed again after replay, as a copy
+async fn concatenate_sstables_rows(
+    bucket: &[SSTableMetadata],
+    table_schema: &TableSchema,
+    throttle: &IoThrottle,
+) -> Vec<Row> {
+    let mut rows = Vec::new();
     for sstable_metadata in bucket {
-        std::fs::remove_file(sstable_metadata.file_path).unwrap();
+        let mut row_stream =
+            SSTableRowStream::open(sstable_metadata, table_schema, throttle.clone());
+        while let Some(row) = row_stream.next_row(table_schema).await {
+            rows.push(row);
+        }
+    }
+    // partitions are sorted out afterwards by a stable sort
+    rows.sort_by(|first, second| first.primary_key.cmp(&second.primary_key));
+    rows.dedup_by(|row, previous_row| row.primary_key == previous_row.primary_key);
+    rows
+}
+
+// tombstone is kept until no sstable outside of compacted bucket holds an older version of its row,
+// otherwise that version would be read again once the tombstone is gone, same goes for range
+// tombstones and older rows of their range
+async fn drop_obsolete_tombstones(
+    rows: &mut Vec<Row>,
+    range_tombstones: &mut Vec<RangeTombstone>,
+    bucket: &[SSTableMetadata],
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+    sstable_dir: &str,
+) {
+    let other_sstables: Vec<_> = get_sstables_metadata(&table_schema.name, sstable_dir)
+        .into_iter()
+        .filter(|sstable_metadata| {
+            !bucket
+                .iter()
+                .any(|bucket_metadata| bucket_metadata.file_path == sstable_metadata.file_path)
+        })
+        .collect();
+
+    let mut kept_rows = Vec::with_capacity(rows.len());
+    for row in rows.drain(..) {
+        if row.marked_for_deletion
+            && !has_older_version(&row, &other_sstables, table_schema, partition_map).await
+        {
+            continue;
+        }
+        kept_rows.push(row);
+    }
+    *rows = kept_rows;
+
+    // the same range tombstone is in several sstables when its log got replayed again
+    let mut kept_range_tombstones: Vec<RangeTombstone> = Vec::new();
+    for range_tombstone in range_tombstones.drain(..) {
+        if !kept_range_tombstones.contains(&range_tombstone)
+            && has_older_row_in_range(
+                &range_tombstone,
+                &other_sstables,
+                table_schema,
+                partition_map,
+            )
+            .await
+        {
+            kept_range_tombstones.push(range_tombstone);
+        }
+    }
+    *range_tombstones = kept_range_tombstones;
+}
+
+async fn has_older_version(
+    row: &Row,
+    sstables: &[SSTableMetadata],
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+) -> bool {
+    let partition = partition_map.get_partition(&row.hash_key);
+    for sstable_metadata in sstables {
+        let other_row =
+            read_row_from_sstable_file(&row.primary_key, partition, sstable_metadata, table_schema)
+                .await;
+        if other_row.is_some_and(|other_row| other_row.timestamp < row.timestamp) {
+            return true;
+        }
+    }
+
+    false
+}
+
+async fn has_older_row_in_range(
+    range_tombstone: &RangeTombstone,
+    sstables: &[SSTableMetadata],
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+) -> bool {
+    let partition = partition_map.get_partition(&range_tombstone.hash_key);
+    let (start, end) = (
+        range_tombstone.start.as_ref().map(String::as_str),
+        range_tombstone.end.as_ref().map(String::as_str),
+    );
+    for sstable_metadata in sstables {
+        let rows =
+            read_sstable_rows_in_range(start, end, partition, sstable_metadata, table_schema).await;
+        if rows.iter().any(|row| range_tombstone.shadows(row)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+// stable sort, rows sorted by primary key stay sorted within their partition
+pub(crate) fn sort_rows_by_partition<R: Borrow<Row>>(
+    rows: &mut [R],
+    partition_map: &PartitionMap,
+) -> HashMap<usize, usize> {
+    sort_by_partition(rows, |row| &row.borrow().hash_key, partition_map)
+}
+
+pub(crate) fn sort_by_partition<R>(
+    rows: &mut [R],
+    hash_key: impl Fn(&R) -> &str,
+    partition_map: &PartitionMap,
+) -> HashMap<usize, usize> {
+    rows.sort_by_cached_key(|row| partition_map.get_partition(hash_key(row)));
+
+    let mut partition_index = HashMap::new();
+    for (row_number, row) in rows.iter().enumerate() {
+        let row_partition = partition_map.get_partition(hash_key(row));
+        partition_index.entry(row_partition).or_insert(row_number);
+    }
+
+    partition_index
+}
+
+// rewrites every sstable containing rows past table retention
+pub async fn purge_expired_rows(
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+    sstable_dir: &str,
+) {
+    if table_schema.options.retention.is_none() {
+        return;
+    }
+
+    let throttle = compaction_io_throttle(sstable_dir);
+    for sstable_metadata in get_sstables_metadata(&table_schema.name, sstable_dir) {
+        let now = millis_from_epoch();
+        let mut has_expired_rows = false;
+        let mut row_stream =
+            SSTableRowStream::open(&sstable_metadata, table_schema, throttle.clone());
+        while let Some(row) = row_stream.next_row(table_schema).await {
+            if table_schema.options.is_row_expired(&row, now) {
+                has_expired_rows = true;
+                break;
+            }
+        }
+
+        if has_expired_rows {
+            compact_bucket(
+                vec![sstable_metadata],
+                table_schema,
+                partition_map,
+                sstable_dir,
+            )
+            .await;
+        }
     }
 }
 
+// merges all sstables of a table into one, so that rows get indexed by current partition map
+pub async fn rewrite_sstables_partitions(
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+    sstable_dir: &str,
+) {
+    let sstable_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
+    if !sstable_metadatas.is_empty() {
+        compact_bucket(sstable_metadatas, table_schema, partition_map, sstable_dir).await;
+    }
+}
+
+// binary search relies on rows being sorted by encoded primary key and having current encoding,
+// so sstables written before numeric sort keys were encoded or before hash keys were length
+// prefixed have to be rewritten
+pub async fn rewrite_legacy_sstables(
+    table_schema: &TableSchema,
+    partition_map: &PartitionMap,
+    sstable_dir: &str,
+) {
+    let key_order_matters = !matches!(table_schema.sort_key_type, ColumnType::Varchar(_));
+
+    let sstable_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
+    if sstable_metadatas.iter().any(|sstable_metadata| {
+        sstable_metadata.legacy_rows || (key_order_matters && sstable_metadata.legacy_key_order)
+    }) {
+        compact_bucket(sstable_metadatas, table_schema, partition_map, sstable_dir).await;
+    }
+}
+
+pub enum CompactionMessage {
+    RewritePartitions(Box<TableSchema>, oneshot::Sender<()>),
+    // applies settings update and reports state of every table
+    Inspect(
+        CompactionSettingsUpdate,
+        oneshot::Sender<Result<CompactionState, String>>,
+    ),
+    CtrlC(oneshot::Sender<()>),
+}
+
+// partition map and table schemas are shared with listener threads, as buckets can be reassigned
+// and tables synced or dropped at runtime, so sstables are never rewritten by an outdated schema
 pub async fn compaction_main(
-    mut ctrl_c_receiver: Receiver<oneshot::Sender<()>>,
-    table_schemas: Vec<TableSchema>,
-    total_number_of_partitions: usize,
+    mut receiver: Receiver<CompactionMessage>,
+    shared_table_schemas: Arc<RwLock<Vec<TableSchema>>>,
+    partition_map: Arc<RwLock<PartitionMap>>,
     sstable_dir: &str,
 ) {
-    let interval = Duration::from_secs(60);
+    let mut settings = CompactionSettings::default();
+    let mut last_durations = HashMap::new();
+    let mut waited_intervals: HashMap<String, usize> = HashMap::new();
+    let throttle = compaction_io_throttle(sstable_dir);
+    let purge_every_n_intervals = 60;
+    let mut intervals_passed = 0;
     loop {
         monoio::select! {
-            _ = sleep(interval) => {
-                for schema in &table_schemas {
-                    conditionally_compact_table_sstables(schema, total_number_of_partitions, sstable_dir).await;
+            _ = sleep(settings.interval) => {
+                intervals_passed += 1;
+                let partition_map = partition_map.read().unwrap().clone();
+                let table_schemas = shared_table_schemas.read().unwrap().clone();
+                let pending = schedule_compactions(&table_schemas, sstable_dir, &settings, &waited_intervals);
+                let (scheduled, passed_over) = pending.split_at(pending.len().min(settings.max_concurrent_compactions));
+                waited_intervals = passed_over
+                    .iter()
+                    .map(|schema| (schema.name.clone(), waited_intervals.get(&schema.name).copied().unwrap_or(0) + 1))
+                    .collect();
+
+                let compactions = scheduled.iter().map(|schema| {
+                    let (partition_map, settings) = (&partition_map, &settings);
+                    async move {
+                        // a failing table is left for the next interval, other tables are still compacted
+                        let compacted = supervise(BackgroundTask::Compaction, &schema.name, || async {
+                            Ok(compact_next_bucket(schema, partition_map, sstable_dir, settings).await)
+                        }).await;
+                        (schema, compacted)
+                    }
+                });
+                for (schema, compacted) in join_all(compactions).await {
+                    if let Ok(Some(duration)) = compacted {
+                        last_durations.insert(schema.name.clone(), duration);
+                    }
+                }
+
+                if intervals_passed % purge_every_n_intervals == 0 {
+                    for schema in &table_schemas {
+                        let _ = supervise(BackgroundTask::Compaction, &schema.name, || async {
+                            purge_expired_rows(schema, &partition_map, sstable_dir).await;
+                            purge_sstable_history(schema, sstable_dir);
+                            Ok(())
+                        }).await;
+                    }
                 }
             }
-            Some(ctrl_c_sender) = ctrl_c_receiver.next() => {
-                ctrl_c_sender.send(()).unwrap();
-                break;
+            Some(message) = receiver.next() => {
+                match message {
+                    CompactionMessage::RewritePartitions(table_schema, sender) => {
+                        let partition_map = partition_map.read().unwrap().clone();
+                        rewrite_sstables_partitions(&table_schema, &partition_map, sstable_dir).await;
+                        sender.send(()).unwrap();
+                    }
+                    CompactionMessage::Inspect(update, sender) => {
+                        // limit could have been changed through another database on the same disk
+                        settings.io_rate_limit_mb = throttle.rate_limit_mb();
+                        let table_schemas = shared_table_schemas.read().unwrap().clone();
+                        let result = settings.apply(&update).map(|new_settings| {
+                            settings = new_settings;
+                            throttle.set_rate_limit_mb(settings.io_rate_limit_mb);
+                            CompactionState {
+                                settings: settings.clone(),
+                                tables: table_schemas
+                                    .iter()
+                                    .map(|schema| get_table_compaction_state(
+                                        schema,
+                                        sstable_dir,
+                                        &settings,
+                                        last_durations.get(&schema.name).copied(),
+                                        waited_intervals.get(&schema.name).copied().unwrap_or(0),
+                                    ))
+                                    .collect(),
+                            }
+                        });
+                        let _ = sender.send(result);
+                    }
+                    CompactionMessage::CtrlC(ctrl_c_sender) => {
+                        ctrl_c_sender.send(()).unwrap();
+                        break;
+                    }
+                }
             }
         }
     }
 }
 
-async fn conditionally_compact_table_sstables(
+// compacts the largest pending bucket only, so that a table with large backlog takes its turn
+// and lets other tables through, returns how long compaction took, if any bucket got compacted
+async fn compact_next_bucket(
     table_schema: &TableSchema,
-    total_number_of_partitions: usize,
+    partition_map: &PartitionMap,
     sstable_dir: &str,
-) {
+    settings: &CompactionSettings,
+) -> Option<Duration> {
     let sstable_metadatas = get_sstables_metadata(&table_schema.name, sstable_dir);
 
-    if sstable_metadatas.len() > 4 {
-        compact_sstables(table_schema, total_number_of_partitions, sstable_dir).await;
+    if sstable_metadatas.len() < MIN_SSTABLES_TO_COMPACT {
+        return None;
+    }
+    let bucket = get_compaction_buckets(sstable_metadatas, settings)
+        .into_iter()
+        .filter(|bucket| COMPACTION_BUCKET_SIZE_RANGE.contains(&bucket.len()))
+        .max_by_key(|bucket| bucket.len())?;
+
+    let started_at = Instant::now();
+    compact_bucket(bucket, table_schema, partition_map, sstable_dir).await;
+    Some(started_at.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_dir::DataDir;
+    use crate::hooks::{register_storage_hooks, BackgroundStage, StorageHooks};
+    use crate::io::{clear_io_faults, inject_io_fault, IoFault, IoFaultEffect, IoOperation};
+    use common::value::Value::{Int32, Unsigned64};
+
+    #[monoio::test]
+    async fn compaction_merges_sstables_keeping_newest_rows() {
+        let sstable_dir = "/tmp/compaction_merges_sstables_keeping_newest_rows";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let partition_map = PartitionMap::new(1);
+        let row = |sort_key, timestamp| {
+            Row::new_with_timestamp("a".to_string(), Int32(sort_key), HashMap::new(), timestamp)
+        };
+        let mut deleted_row = row(-3, 30);
+        deleted_row.marked_for_deletion = true;
+
+        let segments = [
+            (1, vec![row(-3, 10), row(-2, 10)]),
+            (2, vec![deleted_row, row(-2, 20), row(-1, 5)]),
+        ];
+        for (timestamp, mut rows) in segments {
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_timestamp(timestamp)
+                .write_to_disk(sstable_dir)
+                .await
+                .unwrap();
+        }
+
+        let bucket = get_sstables_metadata("table", sstable_dir);
+        compact_bucket(bucket, &table_schema, &partition_map, sstable_dir).await;
+
+        let sstables_metadata = get_sstables_metadata("table", sstable_dir);
+        assert_eq!(sstables_metadata.len(), 1);
+        let rows: Vec<_> = read_sstable_rows(&sstables_metadata[0], &table_schema)
+            .await
+            .into_iter()
+            .map(|row| (row.sort_key, row.timestamp))
+            .collect();
+        assert_eq!(rows, vec![(Int32(-2), 20), (Int32(-1), 5)]);
+    }
+
+    #[monoio::test]
+    async fn compaction_of_append_only_table_concatenates_rows() {
+        let sstable_dir = "/tmp/compaction_of_append_only_table_concatenates_rows";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let table_schema =
+            TableSchema::from_string("events>sort_key:UNSIGNED64#append_only").unwrap();
+        let partition_map = PartitionMap::new(1);
+        let row = |sequence, timestamp| {
+            Row::new_with_timestamp(
+                "a".to_string(),
+                Unsigned64(sequence),
+                HashMap::new(),
+                timestamp,
+            )
+        };
+
+        // the same row lands in two segments when memtable is flushed again after a restart
+        let segments = [
+            (1, vec![row(1, 10), row(3, 10)]),
+            (2, vec![row(2, 20), row(3, 10), row(256, 20)]),
+        ];
+        for (timestamp, mut rows) in segments {
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_timestamp(timestamp)
+                .write_to_disk(sstable_dir)
+                .await
+                .unwrap();
+        }
+
+        let bucket = get_sstables_metadata("events", sstable_dir);
+        compact_bucket(bucket, &table_schema, &partition_map, sstable_dir).await;
+
+        let sstables_metadata = get_sstables_metadata("events", sstable_dir);
+        assert_eq!(sstables_metadata.len(), 1);
+        let sort_keys: Vec<_> = read_sstable_rows(&sstables_metadata[0], &table_schema)
+            .await
+            .into_iter()
+            .map(|row| row.sort_key)
+            .collect();
+        assert_eq!(
+            sort_keys,
+            vec![Unsigned64(1), Unsigned64(2), Unsigned64(3), Unsigned64(256)]
+        );
+    }
+
+    #[derive(Default)]
+    struct RecordingHooks {
+        events: std::sync::Mutex<Vec<BackgroundEvent>>,
+    }
+
+    // hooks are global, so only events of this test's table are recorded
+    impl StorageHooks for RecordingHooks {
+        fn on_background_event(&self, event: &BackgroundEvent) {
+            if event.table_name == "evented" {
+                self.events.lock().unwrap().push(event.clone());
+            }
+        }
+    }
+
+    #[monoio::test]
+    async fn compaction_publishes_lifecycle_events() {
+        let sstable_dir = "/tmp/compaction_publishes_lifecycle_events";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+        let hooks = Arc::new(RecordingHooks::default());
+        register_storage_hooks(hooks.clone());
+
+        let table_schema = TableSchema::new("evented".to_string(), ColumnType::Int32);
+        let partition_map = PartitionMap::new(1);
+        for timestamp in [1, 2] {
+            let mut rows = vec![Row::new_with_timestamp(
+                "a".to_string(),
+                Int32(-1),
+                HashMap::new(),
+                timestamp,
+            )];
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_timestamp(timestamp)
+                .write_to_disk(sstable_dir)
+                .await
+                .unwrap();
+        }
+        let bucket = get_sstables_metadata("evented", sstable_dir);
+        let bytes_read = bucket.iter().map(|metadata| metadata.file_size).sum();
+        compact_bucket(bucket, &table_schema, &partition_map, sstable_dir).await;
+
+        let events = hooks.events.lock().unwrap().clone();
+        assert_eq!(events.len(), 2);
+        let (started, finished) = (&events[0], &events[1]);
+        assert_eq!(started.task, BackgroundTask::Compaction);
+        assert_eq!(started.stage, BackgroundStage::Started);
+        assert_eq!(started.input_segments.len(), 2);
+        assert_eq!(started.bytes_read, bytes_read);
+        assert!(started.output_segments.is_empty());
+
+        let compacted = &get_sstables_metadata("evented", sstable_dir)[0];
+        assert_eq!(finished.stage, BackgroundStage::Finished);
+        assert_eq!(finished.input_segments, started.input_segments);
+        assert_eq!(
+            finished.output_segments,
+            vec![segment_name(&compacted.file_path)]
+        );
+        assert_eq!(finished.bytes_written, compacted.file_size);
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+
+    #[test]
+    fn files_not_named_like_sstables_are_skipped() {
+        let sstable_dir = "/tmp/files_not_named_like_sstables_are_skipped";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+        for file_name in ["table-x-1-2", "table-0-1", "table", "table-0-1-2"] {
+            std::fs::write(format!("{}/{}", sstable_dir, file_name), []).unwrap();
+        }
+
+        let sstables_metadata = get_sstables_metadata("table", sstable_dir);
+        assert_eq!(sstables_metadata.len(), 1);
+        assert_eq!(sstables_metadata[0].timestamp, 2);
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+
+    #[monoio::test]
+    async fn tombstone_outlives_compaction_of_newer_sstables() {
+        let root = "/tmp/tombstone_outlives_compaction_of_newer_sstables";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let sstable_dir = &data_dir.sstables;
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let partition_map = PartitionMap::new(1);
+        let row = |sort_key, timestamp| {
+            Row::new_with_timestamp("a".to_string(), Int32(sort_key), HashMap::new(), timestamp)
+        };
+        let mut deleted_row = row(-3, 30);
+        deleted_row.marked_for_deletion = true;
+
+        let segments = [
+            (100, vec![row(-3, 10)]),
+            (200, vec![deleted_row]),
+            (300, vec![row(-1, 50)]),
+        ];
+        for (timestamp, mut rows) in segments {
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_timestamp(timestamp)
+                .write_to_disk(sstable_dir)
+                .await
+                .unwrap();
+        }
+        let sstables = |timestamps: &[u128]| {
+            get_sstables_metadata("table", sstable_dir)
+                .into_iter()
+                .filter(|sstable_metadata| timestamps.contains(&sstable_metadata.timestamp))
+                .collect::<Vec<_>>()
+        };
+
+        // compacted sstable holding deleted row is newer than sstable of the tombstone
+        compact_bucket(
+            sstables(&[100, 300]),
+            &table_schema,
+            &partition_map,
+            sstable_dir,
+        )
+        .await;
+        let primary_key = row(-3, 0).primary_key;
+        let read_row = read_row_from_sstable(&primary_key, 0, &table_schema, sstable_dir)
+            .await
+            .unwrap();
+        assert!(read_row.marked_for_deletion);
+
+        // tombstone compacted on its own is kept, as compacted sstable still holds the row
+        compact_bucket(sstables(&[200]), &table_schema, &partition_map, sstable_dir).await;
+        let read_row = read_row_from_sstable(&primary_key, 0, &table_schema, sstable_dir)
+            .await
+            .unwrap();
+        assert!(read_row.marked_for_deletion);
+
+        let bucket = get_sstables_metadata("table", sstable_dir);
+        compact_bucket(bucket, &table_schema, &partition_map, sstable_dir).await;
+        assert!(
+            read_row_from_sstable(&primary_key, 0, &table_schema, sstable_dir)
+                .await
+                .is_none()
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn range_tombstone_shadows_older_rows_until_compacted_away() {
+        let root = "/tmp/range_tombstone_shadows_older_rows_until_compacted_away";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let sstable_dir = &data_dir.sstables;
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let partition_map = PartitionMap::new(1);
+        let row = |sort_key, timestamp| {
+            Row::new_with_timestamp("a".to_string(), Int32(sort_key), HashMap::new(), timestamp)
+        };
+        let range_tombstone = RangeTombstone {
+            hash_key: "a".to_string(),
+            start: Bound::Included(row(-5, 0).primary_key),
+            end: Bound::Excluded(row(0, 0).primary_key),
+            timestamp: 30,
+        };
+
+        let segments = [
+            (100, vec![row(-3, 10), row(-2, 10), row(5, 10)], Vec::new()),
+            (200, Vec::new(), vec![range_tombstone]),
+            (300, vec![row(-2, 50)], Vec::new()),
+        ];
+        for (timestamp, mut rows, range_tombstones) in segments {
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_range_tombstones(range_tombstones)
+                .with_timestamp(timestamp)
+                .write_to_disk(sstable_dir)
+                .await
+                .unwrap();
+        }
+        let read = |sort_key| {
+            let primary_key = row(sort_key, 0).primary_key;
+            let table_schema = table_schema.clone();
+            async move { read_row_from_sstable(&primary_key, 0, &table_schema, sstable_dir).await }
+        };
+        let check_rows = || async {
+            assert!(read(-3).await.unwrap().marked_for_deletion);
+            assert_eq!(read(-2).await.unwrap().timestamp, 50);
+            assert!(!read(5).await.unwrap().marked_for_deletion);
+        };
+        check_rows().await;
+
+        // range tombstone compacted on its own is kept, as older sstable still holds rows of range
+        let sstables = get_sstables_metadata("table", sstable_dir);
+        let tombstone_sstable = sstables
+            .into_iter()
+            .filter(|sstable_metadata| sstable_metadata.timestamp == 200)
+            .collect();
+        compact_bucket(
+            tombstone_sstable,
+            &table_schema,
+            &partition_map,
+            sstable_dir,
+        )
+        .await;
+        check_rows().await;
+
+        let bucket = get_sstables_metadata("table", sstable_dir);
+        compact_bucket(bucket, &table_schema, &partition_map, sstable_dir).await;
+        assert!(read(-3).await.is_none());
+        assert_eq!(read(-2).await.unwrap().timestamp, 50);
+        assert!(read(5).await.is_some());
+        let compacted_sstable = &get_sstables_metadata("table", sstable_dir)[0];
+        assert_eq!(compacted_sstable.number_of_rows, 2);
+        assert!(
+            read_sstable_range_tombstones(compacted_sstable, &table_schema)
+                .await
+                .is_empty()
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn read_trace_lists_consulted_sstables() {
+        let root = "/tmp/read_trace_lists_consulted_sstables";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let sstable_dir = &data_dir.sstables;
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let partition_map = PartitionMap::new(1);
+        let row = |sort_key, timestamp| {
+            Row::new_with_timestamp("a".to_string(), Int32(sort_key), HashMap::new(), timestamp)
+        };
+        let segments = [
+            (100, vec![row(-1, 10)]),
+            (200, vec![row(-1, 150)]),
+            (300, vec![row(-2, 250)]),
+        ];
+        for (timestamp, mut rows) in segments {
+            let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+            SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                .with_timestamp(timestamp)
+                .write_to_disk(sstable_dir)
+                .await
+                .unwrap();
+        }
+        let mut read_trace = ReadTrace::default();
+        let read_row = trace_row_read_from_sstable(
+            &row(-1, 0).primary_key,
+            0,
+            &table_schema,
+            sstable_dir,
+            &mut read_trace,
+        )
+        .await
+        .unwrap();
+        assert_eq!(read_row.timestamp, 150);
+
+        // oldest sstable cannot hold newer version than the one found
+        let found: Vec<_> = read_trace.sstables.iter().map(|read| read.found).collect();
+        assert_eq!(found, vec![false, true]);
+        assert_eq!(read_trace.sstables_not_consulted, 1);
+        for sstable_read in &read_trace.sstables {
+            assert!(!sstable_read.partition_skipped);
+            assert!(sstable_read.rows_read > 0);
+            assert!(sstable_read.bytes_read > 0);
+        }
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn injected_write_failure_is_reported() {
+        let sstable_dir = "/tmp/injected_write_failure_is_reported";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let table_schema = TableSchema::new("table".to_string(), ColumnType::Int32);
+        let partition_map = PartitionMap::new(1);
+        let mut memtable = Memtable::default();
+        memtable.insert(Row::new("a".to_string(), Int32(-1), HashMap::new()), false);
+
+        inject_io_fault(IoFault {
+            operation: IoOperation::Write,
+            path_pattern: sstable_dir.to_string(),
+            effect: IoFaultEffect::Fail(std::io::ErrorKind::StorageFull),
+            times: Some(1),
+        });
+        let error =
+            write_memtable_to_sstable(&memtable, &table_schema, &partition_map, 1, sstable_dir)
+                .await
+                .unwrap_err();
+        assert_eq!(error, "Injected Write failure");
+
+        // fault is used up, next write goes through
+        let written_sstable =
+            write_memtable_to_sstable(&memtable, &table_schema, &partition_map, 2, sstable_dir)
+                .await
+                .unwrap()
+                .unwrap();
+        assert_eq!(written_sstable.number_of_rows, 1);
+        clear_io_faults(sstable_dir);
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+
+    #[test]
+    fn compaction_settings_update_is_validated() {
+        let settings = CompactionSettings::default();
+
+        let updated = settings
+            .apply(&CompactionSettingsUpdate {
+                bucket_high: Some(2.0),
+                interval: Some(Duration::from_secs(5)),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(updated.bucket_low, settings.bucket_low);
+        assert_eq!(updated.bucket_high, 2.0);
+        assert_eq!(updated.interval, Duration::from_secs(5));
+
+        for update in [
+            CompactionSettingsUpdate {
+                bucket_low: Some(0.0),
+                ..Default::default()
+            },
+            CompactionSettingsUpdate {
+                bucket_high: Some(f64::NAN),
+                ..Default::default()
+            },
+            CompactionSettingsUpdate {
+                interval: Some(Duration::ZERO),
+                ..Default::default()
+            },
+            CompactionSettingsUpdate {
+                max_concurrent_compactions: Some(0),
+                ..Default::default()
+            },
+        ] {
+            assert!(settings.apply(&update).is_err());
+        }
+    }
+
+    #[monoio::test]
+    async fn tables_passed_over_are_boosted() {
+        let sstable_dir = "/tmp/tables_passed_over_are_boosted";
+        let _ = std::fs::remove_dir_all(sstable_dir);
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let partition_map = PartitionMap::new(1);
+        let small = TableSchema::new("small".to_string(), ColumnType::Int32);
+        let large = TableSchema::new("large".to_string(), ColumnType::Int32);
+        let idle = TableSchema::new("idle".to_string(), ColumnType::Int32);
+        for (table_schema, number_of_sstables) in [(&small, 5), (&large, 8), (&idle, 2)] {
+            for timestamp in 1..=number_of_sstables {
+                let mut rows = vec![Row::new("a".to_string(), Int32(-1), HashMap::new())];
+                let partition_index = sort_rows_by_partition(&mut rows, &partition_map);
+                SSTableSegment::new(table_schema.clone(), rows, partition_index)
+                    .with_timestamp(timestamp)
+                    .write_to_disk(sstable_dir)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let table_schemas = vec![small, large, idle];
+        let settings = CompactionSettings::default();
+        let names = |waited_intervals: &HashMap<String, usize>| -> Vec<String> {
+            schedule_compactions(&table_schemas, sstable_dir, &settings, waited_intervals)
+                .into_iter()
+                .map(|schema| schema.name.clone())
+                .collect()
+        };
+
+        // table below minimum number of sstables has nothing pending
+        assert_eq!(names(&HashMap::new()), vec!["large", "small"]);
+        let waited_intervals = HashMap::from([("small".to_string(), 1)]);
+        assert_eq!(names(&waited_intervals), vec!["small", "large"]);
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
     }
 }
@@ -0,0 +1,268 @@
+use crate::disk_usage::table_disk_usage;
+use crate::io::remove_file;
+use crate::sstable::{get_sstables_metadata, SSTableMetadata};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+// 2^10 registers, estimates are off by about 3%
+static SKETCH_PRECISION: u32 = 10;
+static SKETCH_REGISTERS: usize = 1 << SKETCH_PRECISION;
+// hash keys with 1, 2-3, 4-7, ... rows, the last bucket takes every larger hash key
+static ROWS_PER_HASH_KEY_BUCKETS: usize = 16;
+
+// hyperloglog of hash keys, sketches of sstables are merged into sketch of the whole table
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashKeySketch {
+    registers: Vec<u8>,
+}
+
+impl Default for HashKeySketch {
+    fn default() -> HashKeySketch {
+        HashKeySketch {
+            registers: vec![0; SKETCH_REGISTERS],
+        }
+    }
+}
+
+impl HashKeySketch {
+    pub fn add(&mut self, hash_key: &str) {
+        // default hasher is seeded with fixed keys, so sketches written by other runs can be merged
+        let mut hasher = DefaultHasher::new();
+        hash_key.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let register = (hash >> (64 - SKETCH_PRECISION)) as usize;
+        let rank = ((hash << SKETCH_PRECISION) | (1 << (SKETCH_PRECISION - 1))).leading_zeros() + 1;
+        self.registers[register] = self.registers[register].max(rank as u8);
+    }
+
+    pub fn merge(&mut self, other: &HashKeySketch) {
+        for (register, other_register) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other_register);
+        }
+    }
+
+    pub fn estimate(&self) -> u64 {
+        let registers = SKETCH_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / registers);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum();
+        let estimate = alpha * registers * registers / sum;
+
+        // small cardinalities are counted by empty registers instead
+        let empty_registers = self
+            .registers
+            .iter()
+            .filter(|register| **register == 0)
+            .count();
+        if estimate <= 2.5 * registers && empty_registers > 0 {
+            return (registers * (registers / empty_registers as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+
+    fn to_hex(&self) -> String {
+        self.registers
+            .iter()
+            .map(|register| format!("{:02x}", register))
+            .collect()
+    }
+
+    fn from_hex(hex: &str) -> Option<HashKeySketch> {
+        if hex.len() != SKETCH_REGISTERS * 2 {
+            return None;
+        }
+        let registers = (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+        Some(HashKeySketch { registers })
+    }
+}
+
+// collected while sstable gets written on flush or compaction, rows come sorted by partition
+// and primary key, so rows of a hash key are next to each other
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SSTableStatistics {
+    hash_keys: HashKeySketch,
+    rows_per_hash_key: Vec<u64>,
+    current_hash_key: Option<(String, u64)>,
+}
+
+impl SSTableStatistics {
+    pub(crate) fn add_row(&mut self, row_hash_key: &str) {
+        match &mut self.current_hash_key {
+            Some((hash_key, rows)) if hash_key == row_hash_key => *rows += 1,
+            _ => {
+                self.finish_hash_key();
+                self.hash_keys.add(row_hash_key);
+                self.current_hash_key = Some((row_hash_key.to_string(), 1));
+            }
+        }
+    }
+
+    fn finish_hash_key(&mut self) {
+        if let Some((_, rows)) = self.current_hash_key.take() {
+            let bucket = (rows.ilog2() as usize).min(ROWS_PER_HASH_KEY_BUCKETS - 1);
+            if self.rows_per_hash_key.len() <= bucket {
+                self.rows_per_hash_key.resize(bucket + 1, 0);
+            }
+            self.rows_per_hash_key[bucket] += 1;
+        }
+    }
+
+    fn into_file_string(mut self) -> String {
+        self.finish_hash_key();
+        let rows_per_hash_key: Vec<_> = self
+            .rows_per_hash_key
+            .iter()
+            .map(|hash_keys| hash_keys.to_string())
+            .collect();
+        format!(
+            "rows_per_hash_key={}\nhash_keys={}\n",
+            rows_per_hash_key.join(","),
+            self.hash_keys.to_hex()
+        )
+    }
+
+    fn from_file_string(file_string: &str) -> Option<SSTableStatistics> {
+        let mut statistics = SSTableStatistics::default();
+        for line in file_string.lines() {
+            match line.split_once('=')? {
+                ("rows_per_hash_key", "") => {}
+                ("rows_per_hash_key", buckets) => {
+                    statistics.rows_per_hash_key = buckets
+                        .split(',')
+                        .map(|hash_keys| hash_keys.parse().ok())
+                        .collect::<Option<_>>()?
+                }
+                ("hash_keys", hex) => statistics.hash_keys = HashKeySketch::from_hex(hex)?,
+                _ => {}
+            }
+        }
+        Some(statistics)
+    }
+}
+
+// estimates over sstables, rows still in memtables and versions not yet compacted away are
+// not accounted for
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableStatistics {
+    pub sstables: usize,
+    pub row_count_estimate: u64,
+    pub average_row_size: u64,
+    pub hash_key_cardinality: u64,
+    // hash keys with 1, 2-3, 4-7, ... rows, hash keys spread over sstables are counted in each
+    pub rows_per_hash_key: Vec<u64>,
+    // bytes of sstables, including superseded ones kept for history, as checked against max_disk_mb
+    pub disk_usage: u64,
+}
+
+// statistics are kept next to sstables dir, so that listing sstables never sees them
+pub fn statistics_dir(sstable_dir: &str) -> String {
+    format!("{}_statistics", sstable_dir.trim_end_matches('/'))
+}
+
+fn statistics_path(sstable_file_path: &str, sstable_dir: &str) -> String {
+    let file_name = Path::new(sstable_file_path)
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+    format!("{}/{}", statistics_dir(sstable_dir), file_name)
+}
+
+// statistics are only advisory, so failing to keep them does not fail the write
+pub(crate) fn write_sstable_statistics(
+    statistics: SSTableStatistics,
+    sstable_file_path: &str,
+    sstable_dir: &str,
+) {
+    let path = statistics_path(sstable_file_path, sstable_dir);
+    if let Err(error) = std::fs::create_dir_all(statistics_dir(sstable_dir))
+        .and_then(|_| std::fs::write(&path, statistics.into_file_string()))
+    {
+        tracing::error!("Failed to write statistics '{}': {}", path, error);
+    }
+}
+
+pub(crate) fn remove_sstable_statistics(sstable_metadata: &SSTableMetadata, sstable_dir: &str) {
+    let _ = remove_file(statistics_path(&sstable_metadata.file_path, sstable_dir));
+}
+
+// sstables written before statistics were kept only add to row count and row size
+pub fn get_table_statistics(table_name: &str, sstable_dir: &str) -> TableStatistics {
+    let mut table_statistics = TableStatistics::default();
+    let mut hash_keys = HashKeySketch::default();
+    let mut rows_bytes = 0;
+
+    for sstable_metadata in get_sstables_metadata(table_name, sstable_dir) {
+        table_statistics.sstables += 1;
+        table_statistics.row_count_estimate += sstable_metadata.number_of_rows as u64;
+        rows_bytes += sstable_metadata.file_size - sstable_metadata.rows_offset() as u64;
+
+        let statistics =
+            std::fs::read_to_string(statistics_path(&sstable_metadata.file_path, sstable_dir))
+                .ok()
+                .and_then(|file_string| SSTableStatistics::from_file_string(&file_string));
+        if let Some(statistics) = statistics {
+            hash_keys.merge(&statistics.hash_keys);
+            let buckets = &mut table_statistics.rows_per_hash_key;
+            if buckets.len() < statistics.rows_per_hash_key.len() {
+                buckets.resize(statistics.rows_per_hash_key.len(), 0);
+            }
+            for (bucket, hash_keys) in buckets.iter_mut().zip(statistics.rows_per_hash_key) {
+                *bucket += hash_keys;
+            }
+        }
+    }
+
+    table_statistics.average_row_size = rows_bytes
+        .checked_div(table_statistics.row_count_estimate)
+        .unwrap_or(0);
+    table_statistics.hash_key_cardinality = hash_keys.estimate();
+    table_statistics.disk_usage = table_disk_usage(table_name, sstable_dir);
+    table_statistics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sketch_estimates_distinct_hash_keys() {
+        let mut first = HashKeySketch::default();
+        let mut second = HashKeySketch::default();
+        for number in 0..20_000 {
+            first.add(&format!("user_{}", number));
+            // half of hash keys are in both sketches
+            second.add(&format!("user_{}", number + 10_000));
+        }
+        first.merge(&second);
+
+        let estimate = first.estimate() as f64;
+        assert!((estimate - 30_000.0).abs() / 30_000.0 < 0.1, "{}", estimate);
+        assert_eq!(HashKeySketch::default().estimate(), 0);
+    }
+
+    #[test]
+    fn statistics_are_read_back() {
+        let mut statistics = SSTableStatistics::default();
+        for (hash_key, rows) in [("a", 1), ("b", 3), ("c", 2), ("d", 40)] {
+            for _ in 0..rows {
+                statistics.add_row(hash_key);
+            }
+        }
+        let hash_keys = statistics.hash_keys.clone();
+
+        let statistics =
+            SSTableStatistics::from_file_string(&statistics.into_file_string()).unwrap();
+        assert_eq!(statistics.rows_per_hash_key, vec![1, 2, 0, 0, 0, 1]);
+        assert_eq!(statistics.hash_keys, hash_keys);
+        assert_eq!(statistics.hash_keys.estimate(), 4);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::util::millis_from_epoch;
+use futures::FutureExt;
+use monoio::time::sleep;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::Mutex;
+use std::time::Duration;
+
+static SUPERVISOR_MAX_ATTEMPTS: usize = 5;
+static SUPERVISOR_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+static SUPERVISOR_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+// background tasks run on every thread and on compaction threads, so failures are global
+static DEGRADED_TABLES: Mutex<BTreeMap<(String, BackgroundTask), TaskFailure>> =
+    Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BackgroundTask {
+    Flush,
+    Compaction,
+    CommitLogSync,
+}
+
+impl Display for BackgroundTask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackgroundTask::Flush => write!(f, "flush"),
+            BackgroundTask::Compaction => write!(f, "compaction"),
+            BackgroundTask::CommitLogSync => write!(f, "commit log sync"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskFailure {
+    pub table_name: String,
+    pub task: BackgroundTask,
+    pub last_error: String,
+    // failed attempts since task last succeeded
+    pub failures: usize,
+    pub since: u128,
+}
+
+impl Display for TaskFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of table '{}' failed {} times: {}",
+            self.task, self.table_name, self.failures, self.last_error
+        )
+    }
+}
+
+// table stays degraded until the failing task succeeds again
+pub fn degraded_tables() -> Vec<TaskFailure> {
+    DEGRADED_TABLES.lock().unwrap().values().cloned().collect()
+}
+
+// retries failed or panicked attempts with backoff, table is marked degraded until an attempt succeeds,
+// error of the last attempt is returned once attempts run out
+pub async fn supervise<T, F, Fut>(
+    task: BackgroundTask,
+    table_name: &str,
+    mut attempt: F,
+) -> Result<T, String>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    let mut backoff = SUPERVISOR_INITIAL_BACKOFF;
+    let mut attempt_number = 1;
+    loop {
+        let result = match AssertUnwindSafe(attempt()).catch_unwind().await {
+            Ok(result) => result,
+            Err(panic) => Err(format!("panicked: {}", panic_message(panic))),
+        };
+
+        match result {
+            Ok(value) => {
+                report_task_success(task, table_name);
+                return Ok(value);
+            }
+            Err(error) => {
+                tracing::error!(
+                    "Attempt {} of {} of table '{}' failed: {}",
+                    attempt_number,
+                    task,
+                    table_name,
+                    error
+                );
+                report_task_failure(task, table_name, &error);
+                if attempt_number == SUPERVISOR_MAX_ATTEMPTS {
+                    return Err(error);
+                }
+            }
+        }
+
+        sleep(backoff).await;
+        backoff = (backoff * 2).min(SUPERVISOR_MAX_BACKOFF);
+        attempt_number += 1;
+    }
+}
+
+// for tasks retrying on their own schedule, e.g. log syncer on its next pass
+pub fn report_task_failure(task: BackgroundTask, table_name: &str, error: &str) {
+    let mut degraded_tables = DEGRADED_TABLES.lock().unwrap();
+    let failure = degraded_tables
+        .entry((table_name.to_string(), task))
+        .or_insert_with(|| TaskFailure {
+            table_name: table_name.to_string(),
+            task,
+            last_error: String::new(),
+            failures: 0,
+            since: millis_from_epoch(),
+        });
+    failure.last_error = error.to_string();
+    failure.failures += 1;
+}
+
+pub fn report_task_success(task: BackgroundTask, table_name: &str) {
+    let mut degraded_tables = DEGRADED_TABLES.lock().unwrap();
+    if degraded_tables
+        .remove(&(table_name.to_string(), task))
+        .is_some()
+    {
+        tracing::warn!("{} of table '{}' recovered", task, table_name);
+    }
+}
+
+pub fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[monoio::test(timer_enabled = true)]
+    async fn failed_attempts_are_retried_and_reported() {
+        let attempts = Cell::new(0);
+        let table_name = "failed_attempts_are_retried_and_reported";
+
+        let result = supervise(BackgroundTask::Flush, table_name, || async {
+            attempts.set(attempts.get() + 1);
+            match attempts.get() {
+                1 => Err("disk full".to_string()),
+                2 => panic!("unexpected eof"),
+                _ => {
+                    let failure = degraded_tables()
+                        .into_iter()
+                        .find(|failure| failure.table_name == table_name)
+                        .unwrap();
+                    assert_eq!(failure.failures, 2);
+                    assert_eq!(failure.last_error, "panicked: unexpected eof");
+                    Ok(attempts.get())
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result, Ok(3));
+        assert!(degraded_tables()
+            .iter()
+            .all(|failure| failure.table_name != table_name));
+    }
+}
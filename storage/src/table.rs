@@ -1,76 +1,514 @@
 use self::ColumnType::*;
-use crate::commit_log::{periodically_sync_commit_log, CommitLog};
-use crate::sstable::{flush_memtable_to_sstable, get_sstables_metadata};
-use crate::{Memtable, HASH_KEY_BYTE_SIZE};
+use crate::auth::AuthContext;
+use crate::cache::AccessTracker;
+use crate::commit_log::{
+    check_commit_logs_schema_version, CommitLog, FlushWatermark, ReplayedState,
+};
+use crate::data_dir::DataDir;
+use crate::history::drop_sstable_history;
+use crate::hooks::notify_storage_hooks;
+use crate::index::remove_sstable_index;
+use crate::io::OpenOptions;
+use crate::lease::Lease;
+use crate::log_syncer::{register_commit_log, sync_commit_logs};
+use crate::migration::plan_migration;
+use crate::snapshot::{remove_sstable_file, TableSnapshot};
+use crate::sstable::{
+    check_sstables_schema_version, flush_memtable_part_to_sstable, flush_memtable_to_sstable,
+    get_sstables_metadata, read_row_from_sstable,
+};
+use crate::statistics::remove_sstable_statistics;
+use crate::table_options::TableOptions;
+use crate::transaction::{WriteIntent, WRITE_INTENT_TIMEOUT_MILLIS};
+use crate::util::millis_from_epoch;
+use crate::{encode_primary_key, Memtable, Row, HASH_KEY_BYTE_SIZE, MEGABYTE};
+use common::partition::{get_hash_key_bucket, PartitionMap};
+use common::value::Value;
 use futures::lock::Mutex;
-use monoio::fs::OpenOptions;
 use regex::Regex;
+use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use std::mem::size_of;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
 
 pub static TABLE_SCHEMAS_DIR: &str = "/var/lib/yard";
 pub static TABLE_SCHEMAS_FILE_PATH: &str = "var/lib/yard/table_schemas";
+// numbers temporary files schema file is written to
+static SCHEMA_FILE_WRITES: AtomicUsize = AtomicUsize::new(0);
+static TABLE_NAME_MAX_LENGTH: usize = 64;
+static SCHEMA_FILE_READ_CHUNK_SIZE: usize = 16 * 1024;
+static COMMIT_LOG_MAX_SIZE: u64 = 64 * MEGABYTE as u64;
 
 pub struct Table {
     pub memtable: Memtable,
     pub commit_log: Arc<Mutex<CommitLog>>,
     pub table_schema: TableSchema,
+    pub sequences: HashMap<String, u64>,
+    pub leases: HashMap<String, Lease>,
+    pub row_counts: HashMap<String, u64>,
+    pub access_tracker: AccessTracker,
+    pub data_dir: DataDir,
+    // primary keys reserved by transactions which passed prepare on this thread
+    pub write_intents: HashMap<String, WriteIntent>,
 }
 
 impl Table {
-    pub fn new(memtable: Memtable, commit_log: CommitLog, table_schema: TableSchema) -> Table {
+    pub fn new(
+        mut memtable: Memtable,
+        commit_log: CommitLog,
+        table_schema: TableSchema,
+        data_dir: DataDir,
+    ) -> Table {
         let commit_log = Arc::new(Mutex::new(commit_log));
-        monoio::spawn(periodically_sync_commit_log(
-            commit_log.clone(),
-            Duration::from_secs(10),
-        ));
+        register_commit_log(commit_log.clone());
+        memtable.set_indexed_columns(&table_schema.options.indexes);
+        memtable.set_value_compression(table_schema.options.compress_values_above);
 
         Table {
             memtable,
             commit_log,
             table_schema,
+            sequences: HashMap::new(),
+            leases: HashMap::new(),
+            row_counts: HashMap::new(),
+            access_tracker: AccessTracker::default(),
+            data_dir,
+            write_intents: HashMap::new(),
         }
     }
 
-    pub async fn flush_memtable_to_disk(
+    // full memtable is replaced by an empty one indexing the same columns
+    pub fn take_memtable(&mut self) -> Memtable {
+        let mut memtable = Memtable::default();
+        memtable.set_indexed_columns(&self.table_schema.options.indexes);
+        memtable.set_value_compression(self.table_schema.options.compress_values_above);
+        std::mem::replace(&mut self.memtable, memtable)
+    }
+
+    // transaction about to write given key, other writes of the key have to wait until it commits
+    // or aborts, intents of transactions which never heard back from coordinator expire
+    pub fn write_intent_holder(&self, primary_key: &str) -> Option<u64> {
+        self.write_intents
+            .get(primary_key)
+            .filter(|intent| {
+                millis_from_epoch().saturating_sub(intent.placed_at) < WRITE_INTENT_TIMEOUT_MILLIS
+            })
+            .map(|intent| intent.transaction_id)
+    }
+
+    // sequence is synced before it is handed out, so that it is never issued again after a crash
+    pub async fn next_sequence(&mut self, hash_key: &str) -> Result<u64, String> {
+        let sequence = self.increment_sequence(hash_key).await;
+        sync_commit_logs(&self.commit_log).await?;
+        Ok(sequence)
+    }
+
+    async fn increment_sequence(&mut self, hash_key: &str) -> u64 {
+        let sequence = self.sequences.entry(hash_key.to_string()).or_insert(0);
+        *sequence += 1;
+
+        let mut commit_log = self.commit_log.lock().await;
+        commit_log.write_sequence(hash_key, *sequence).await;
+        *sequence
+    }
+
+    // rows of append-only tables are keyed by next sequence of their hash key, so that they never
+    // overwrite each other, sequence is None for other tables. Sequence is logged before the row,
+    // so it is synced along with it
+    pub async fn assign_append_sort_key(&mut self, row: &mut Row) -> Option<u64> {
+        if !self.table_schema.options.append_only {
+            return None;
+        }
+        let sequence = self.increment_sequence(&row.hash_key).await;
+        row.sort_key = Value::Unsigned64(sequence);
+        row.primary_key = encode_primary_key(&row.hash_key, &row.sort_key);
+        Some(sequence)
+    }
+
+    pub async fn restore_state(&mut self, state: ReplayedState) {
+        let mut commit_log = self.commit_log.lock().await;
+        for (hash_key, sequence) in &state.sequences {
+            commit_log.write_sequence(hash_key, *sequence).await;
+        }
+        for (name, lease) in &state.leases {
+            commit_log.write_lease(name, lease).await;
+        }
+        for (hash_key, count) in &state.row_counts {
+            commit_log.write_row_count(hash_key, *count).await;
+        }
+        self.sequences = state.sequences;
+        self.leases = state.leases;
+        self.row_counts = state.row_counts;
+    }
+
+    // takes state of hash keys in bucket out of the table, memtable is flushed so that its rows can be reindexed
+    pub async fn release_bucket(
+        &mut self,
+        bucket: u32,
+        partitions: &HashSet<usize>,
+        partition_map: &PartitionMap,
+    ) -> ReplayedState {
+        let state = ReplayedState {
+            sequences: take_bucket_entries(&mut self.sequences, bucket),
+            leases: take_bucket_entries(&mut self.leases, bucket),
+            row_counts: take_bucket_entries(&mut self.row_counts, bucket),
+        };
+        self.access_tracker
+            .remove_hash_keys(|hash_key| get_hash_key_bucket(hash_key) == bucket);
+
+        let old_commit_log = self.rotate_commit_log(partitions).await;
+        let full_memtable = self.take_memtable();
+
+        flush_memtable_to_sstable(
+            full_memtable,
+            old_commit_log,
+            self.table_schema.clone(),
+            self.data_dir.sstables.clone(),
+            partition_map.clone(),
+        )
+        .await;
+        state
+    }
+
+    // entries created on this partition while bucket was migrating take precedence
+    pub async fn adopt_bucket(&mut self, state: ReplayedState) {
+        let commit_log = self.commit_log.clone();
+        let mut commit_log = commit_log.lock().await;
+        for (hash_key, sequence) in state.sequences {
+            if let Entry::Vacant(entry) = self.sequences.entry(hash_key) {
+                commit_log.write_sequence(entry.key(), sequence).await;
+                entry.insert(sequence);
+            }
+        }
+        for (name, lease) in state.leases {
+            if let Entry::Vacant(entry) = self.leases.entry(name) {
+                commit_log.write_lease(entry.key(), &lease).await;
+                entry.insert(lease);
+            }
+        }
+        for (hash_key, count) in state.row_counts {
+            if let Entry::Vacant(entry) = self.row_counts.entry(hash_key) {
+                commit_log.write_row_count(entry.key(), count).await;
+                entry.insert(count);
+            }
+        }
+    }
+
+    pub fn row_count(&self, hash_key: &str) -> Result<u64, String> {
+        if !self.table_schema.options.row_counts {
+            return Err(format!(
+                "Row counts are not enabled for table '{}'",
+                &self.table_schema.name
+            ));
+        }
+
+        Ok(self.row_counts.get(hash_key).cloned().unwrap_or(0))
+    }
+
+    // has to be called before row is inserted into memtable, overwrites do not change the count,
+    // rows deleted by tombstones or range tombstones count again
+    pub async fn count_insert(&mut self, row: &Row, partition_map: &PartitionMap) {
+        if !self.table_schema.options.row_counts {
+            return;
+        }
+
+        let current_row = match self.memtable.get_entry(&row.primary_key) {
+            Some(current_row) => Some(current_row),
+            None => {
+                let partition = partition_map.get_partition(&row.hash_key);
+                read_row_from_sstable(
+                    &row.primary_key,
+                    partition,
+                    &self.table_schema,
+                    &self.data_dir.sstables,
+                )
+                .await
+            }
+        };
+        if current_row.is_some_and(|current_row| !current_row.marked_for_deletion) {
+            return;
+        }
+
+        let count = self.row_counts.entry(row.hash_key.clone()).or_insert(0);
+        *count += 1;
+
+        let mut commit_log = self.commit_log.lock().await;
+        commit_log.write_row_count(&row.hash_key, *count).await;
+    }
+
+    // only rows still in memtable are compared, so duplicates of flushed rows are written again
+    pub fn is_duplicate_insert(&self, row: &Row) -> bool {
+        let dedup_window = match self.table_schema.options.dedup_window {
+            Some(dedup_window) => dedup_window.as_millis(),
+            None => return false,
+        };
+
+        self.memtable
+            .get_entry(&row.primary_key)
+            .is_some_and(|current_row| {
+                !current_row.marked_for_deletion
+                    && current_row.values == row.values
+                    && current_row.timestamp + dedup_window >= row.timestamp
+            })
+    }
+
+    // count_insert and track_access have to be called before
+    pub fn apply_insert(&mut self, row: Row, auth: &AuthContext) {
+        notify_storage_hooks(|hooks| hooks.on_insert(&self.table_schema.name, &row, auth));
+        self.memtable.insert(row, false);
+    }
+
+    pub async fn on_row_deleted(&mut self, hash_key: &str, primary_key: &str, auth: &AuthContext) {
+        notify_storage_hooks(|hooks| hooks.on_delete(&self.table_schema.name, primary_key, auth));
+        self.access_tracker.remove(primary_key);
+        if !self.table_schema.options.row_counts {
+            return;
+        }
+
+        let count = self.row_counts.entry(hash_key.to_string()).or_insert(0);
+        *count = count.saturating_sub(1);
+
+        let mut commit_log = self.commit_log.lock().await;
+        commit_log.write_row_count(hash_key, *count).await;
+    }
+
+    pub fn track_access(&mut self, row: &Row) {
+        if self.table_schema.options.cache_max_rows.is_some() {
+            self.access_tracker.touch(row);
+        }
+    }
+
+    // access times are not persisted, after restart rows start with their write timestamp
+    pub async fn restore_access_times(
         &mut self,
         partitions: &HashSet<usize>,
-        total_number_of_partitions: usize,
+        partition_map: &PartitionMap,
     ) {
+        if self.table_schema.options.cache_max_rows.is_none() {
+            return;
+        }
+
+        let snapshot = self.snapshot();
+        for row in snapshot.rows().await {
+            if partitions.contains(&partition_map.get_partition(&row.hash_key)) {
+                self.access_tracker.touch_at(&row, row.timestamp);
+            }
+        }
+    }
+
+    // evicts down to 90% of max rows, so that eviction runs in batches instead of on every insert
+    pub async fn evict_least_recently_used(&mut self) {
+        let max_rows = match self.table_schema.options.cache_max_rows {
+            Some(max_rows) => max_rows,
+            None => return,
+        };
+        if self.access_tracker.len() <= max_rows {
+            return;
+        }
+
+        let number_of_evicted = self.access_tracker.len() - max_rows * 9 / 10;
+        for (hash_key, sort_key) in self
+            .access_tracker
+            .pop_least_recently_used(number_of_evicted)
+        {
+            let values = self
+                .table_schema
+                .columns
+                .keys()
+                .map(|column_name| (column_name.clone(), Value::Null))
+                .collect();
+            let mut row = Row::new(hash_key, sort_key, values);
+            row.marked_for_deletion = true;
+
+            {
+                let mut commit_log = self.commit_log.lock().await;
+                commit_log.write_insert(&row).await;
+            }
+            self.on_row_deleted(
+                &row.hash_key.clone(),
+                &row.primary_key.clone(),
+                &AuthContext::anonymous(),
+            )
+            .await;
+            self.memtable.insert(row, false);
+        }
+    }
+
+    pub async fn acquire_lease(&mut self, name: &str, ttl_millis: u64) -> Result<Lease, String> {
+        let version = match self.leases.get(name) {
+            Some(lease) if !lease.is_expired() => {
+                return Err(format!("Lease '{}' is already held", name));
+            }
+            Some(lease) => lease.version + 1,
+            None => 1,
+        };
+
+        let lease = Lease {
+            version,
+            expires_at: millis_from_epoch() + ttl_millis as u128,
+        };
+        Ok(self.store_lease(name, lease).await)
+    }
+
+    pub async fn renew_lease(
+        &mut self,
+        name: &str,
+        version: u64,
+        ttl_millis: u64,
+    ) -> Result<Lease, String> {
+        self.check_lease_holder(name, version)?;
+
+        let lease = Lease {
+            version,
+            expires_at: millis_from_epoch() + ttl_millis as u128,
+        };
+        Ok(self.store_lease(name, lease).await)
+    }
+
+    pub async fn release_lease(&mut self, name: &str, version: u64) -> Result<Lease, String> {
+        self.check_lease_holder(name, version)?;
+
+        let lease = Lease {
+            version,
+            expires_at: 0,
+        };
+        Ok(self.store_lease(name, lease).await)
+    }
+
+    fn check_lease_holder(&self, name: &str, version: u64) -> Result<(), String> {
+        match self.leases.get(name) {
+            Some(lease) if lease.version == version && !lease.is_expired() => Ok(()),
+            Some(_) => Err(format!(
+                "Lease '{}' is not held with version {}",
+                name, version
+            )),
+            None => Err(format!("Lease '{}' does not exist", name)),
+        }
+    }
+
+    async fn store_lease(&mut self, name: &str, lease: Lease) -> Lease {
+        {
+            let mut commit_log = self.commit_log.lock().await;
+            commit_log.write_lease(name, &lease).await;
+        }
+        self.leases.insert(name.to_string(), lease.clone());
+        lease
+    }
+
+    // sequences, leases and row counts live only in commit logs, so they are carried over to every new segment
+    pub async fn rotate_commit_log(
+        &mut self,
+        partitions: &HashSet<usize>,
+    ) -> Arc<Mutex<CommitLog>> {
         {
             let mut commit_log = self.commit_log.lock().await;
             commit_log.closed = true;
         }
 
-        let mut full_memtable = Memtable::default();
-        let mut old_commit_log = Arc::new(Mutex::new(
-            CommitLog::open_new(&self.table_schema, partitions).await,
-        ));
+        let mut new_commit_log =
+            CommitLog::open_new(&self.table_schema, partitions, &self.data_dir.commit_logs).await;
+        for (hash_key, sequence) in &self.sequences {
+            new_commit_log.write_sequence(hash_key, *sequence).await;
+        }
+        for (name, lease) in &self.leases {
+            new_commit_log.write_lease(name, lease).await;
+        }
+        for (hash_key, count) in &self.row_counts {
+            new_commit_log.write_row_count(hash_key, *count).await;
+        }
+        // log syncer retries on its next pass
+        if !self.sequences.is_empty() || !self.leases.is_empty() || !self.row_counts.is_empty() {
+            if let Err(error) = new_commit_log.sync().await {
+                tracing::error!("{}", error);
+            }
+        }
 
+        let mut old_commit_log = Arc::new(Mutex::new(new_commit_log));
         std::mem::swap(&mut self.commit_log, &mut old_commit_log);
-        std::mem::swap(&mut self.memtable, &mut full_memtable);
 
-        monoio::spawn(periodically_sync_commit_log(
+        register_commit_log(self.commit_log.clone());
+        old_commit_log
+    }
+
+    pub fn snapshot(&self) -> TableSnapshot {
+        TableSnapshot::new(self, &self.data_dir.sstables)
+    }
+
+    pub async fn flush_memtable_if_needed(
+        &mut self,
+        partitions: &HashSet<usize>,
+        partition_map: &PartitionMap,
+    ) {
+        // partially flushed log is never rotated, so it gets rotated by full flush once too big
+        let commit_log_size = self.commit_log.lock().await.file_offset;
+        if self.memtable.max_size_reached() || commit_log_size > COMMIT_LOG_MAX_SIZE {
+            self.flush_memtable_to_disk(partitions, partition_map).await;
+        } else if self.table_schema.options.partial_flush && self.memtable.partial_flush_reached() {
+            self.flush_memtable_part_to_disk(partition_map).await;
+        }
+    }
+
+    // commit log is kept, flushed rows get skipped on replay thanks to the watermark
+    pub async fn flush_memtable_part_to_disk(&mut self, partition_map: &PartitionMap) {
+        let flushed_memtable = self.memtable.split_off_partial_flush();
+        let last_primary_key = match flushed_memtable.last_primary_key() {
+            Some(last_primary_key) => last_primary_key,
+            None => return,
+        };
+        let watermark = {
+            let mut commit_log = self.commit_log.lock().await;
+            commit_log.partial_flushes.pending += 1;
+            FlushWatermark {
+                offset: commit_log.file_offset,
+                last_primary_key,
+            }
+        };
+
+        monoio::spawn(flush_memtable_part_to_sstable(
+            flushed_memtable,
             self.commit_log.clone(),
-            Duration::from_secs(10),
+            watermark,
+            self.table_schema.clone(),
+            self.data_dir.sstables.clone(),
+            partition_map.clone(),
         ));
+    }
+
+    pub async fn flush_memtable_to_disk(
+        &mut self,
+        partitions: &HashSet<usize>,
+        partition_map: &PartitionMap,
+    ) {
+        let old_commit_log = self.rotate_commit_log(partitions).await;
+        let full_memtable = self.take_memtable();
+
         monoio::spawn(flush_memtable_to_sstable(
             full_memtable,
             old_commit_log,
             self.table_schema.clone(),
-            total_number_of_partitions,
+            self.data_dir.sstables.clone(),
+            partition_map.clone(),
         ));
     }
 }
 
+fn take_bucket_entries<V>(entries: &mut HashMap<String, V>, bucket: u32) -> HashMap<String, V> {
+    let (bucket_entries, other_entries) = std::mem::take(entries)
+        .into_iter()
+        .partition(|(key, _)| get_hash_key_bucket(key) == bucket);
+    *entries = other_entries;
+    bucket_entries
+}
+
 #[derive(Debug, Clone)]
 pub struct TableSchema {
     pub name: String,
     pub sort_key_type: ColumnType,
     pub columns: BTreeMap<String, Column>,
+    pub options: TableOptions,
 }
 
 impl TableSchema {
@@ -79,10 +517,17 @@ impl TableSchema {
             name: table_name,
             sort_key_type,
             columns: BTreeMap::new(),
+            options: TableOptions::default(),
         }
     }
 
     pub fn from_string(schema_string: &str) -> Result<TableSchema, String> {
+        let (schema_string, options) = match schema_string.split_once('#') {
+            Some((schema_string, options_string)) => {
+                (schema_string, TableOptions::from_string(options_string)?)
+            }
+            None => (schema_string, TableOptions::default()),
+        };
         let (table_name, columns_string) = schema_string
             .split_once('>')
             .ok_or("Invalid schema string".to_string())?;
@@ -113,26 +558,143 @@ impl TableSchema {
         let sort_key_column = columns
             .remove("sort_key")
             .ok_or("Invalid first column, should be 'sort_key'".to_string())?;
+
+        if let Some(retention_column) = &options.retention_column {
+            match columns
+                .get(retention_column)
+                .map(|column| &column.column_type)
+            {
+                Some(Int64) | Some(Unsigned64) => {}
+                _ => {
+                    return Err(format!(
+                        "Retention column '{}' has to be INT64 or UNSIGNED64 column",
+                        retention_column
+                    ))
+                }
+            }
+        }
+        if let Some(ttl_column) = &options.ttl_column {
+            match columns.get(ttl_column).map(|column| &column.column_type) {
+                Some(Int64) | Some(Unsigned64) => {}
+                _ => {
+                    return Err(format!(
+                        "TTL column '{}' has to be INT64 or UNSIGNED64 column",
+                        ttl_column
+                    ))
+                }
+            }
+        }
+        for column_transform in &options.transforms {
+            match columns
+                .get(&column_transform.column)
+                .map(|column| &column.column_type)
+            {
+                Some(Varchar(_)) => {}
+                _ => {
+                    return Err(format!(
+                        "Transformed column '{}' has to be VARCHAR column",
+                        column_transform.column
+                    ))
+                }
+            }
+        }
+        for reference in &options.references {
+            match columns
+                .get(&reference.column)
+                .map(|column| &column.column_type)
+            {
+                Some(Varchar(_)) => {}
+                _ => {
+                    return Err(format!(
+                        "Reference column '{}' has to be VARCHAR column",
+                        reference.column
+                    ))
+                }
+            }
+        }
+        if options.append_only {
+            if sort_key_column.column_type != Unsigned64 {
+                return Err(
+                    "Append-only table has to have UNSIGNED64 sort key, it is assigned from sequence"
+                        .to_string(),
+                );
+            }
+            // evicted rows are deleted by tombstones, which append-only tables do not have
+            if options.cache_max_rows.is_some() {
+                return Err("Append-only table cannot be a cache".to_string());
+            }
+        }
+        for (position, column) in options.indexes.iter().enumerate() {
+            validate_index_column(column, &columns)?;
+            if options.indexes[..position].contains(column) {
+                return Err(format!("Column '{}' is indexed more than once", column));
+            }
+        }
         Ok(TableSchema {
             name: table_name.to_string(),
             sort_key_type: sort_key_column.column_type,
             columns,
+            options,
         })
     }
 
+    // rows can then be queried by values of the column, e.g. table_schema.add_index("age")
+    pub fn add_index(&mut self, column: &str) -> Result<(), String> {
+        validate_index_column(column, &self.columns)?;
+        if self.options.indexes.iter().any(|indexed| indexed == column) {
+            return Err(format!("Column '{}' is already indexed", column));
+        }
+        self.options.indexes.push(column.to_string());
+        Ok(())
+    }
+
+    // inserts taking a value another row of the partition has are rejected, e.g. "email"
+    pub fn add_unique_index(&mut self, column: &str) -> Result<(), String> {
+        self.add_index(column)?;
+        self.options.unique_indexes.push(column.to_string());
+        Ok(())
+    }
+
+    pub fn hash_key_size(&self) -> usize {
+        self.options.hash_key_size.unwrap_or(HASH_KEY_BYTE_SIZE)
+    }
+
     pub fn row_byte_size(&self) -> usize {
+        // hash_key length, hash_key, sort_key, values, timestamp, tombstone
+        size_of::<u16>() + self.hash_key_size() + self.row_byte_size_without_hash_key()
+    }
+
+    // rows written before hash keys were length prefixed
+    pub(crate) fn legacy_row_byte_size(&self) -> usize {
+        HASH_KEY_BYTE_SIZE + self.row_byte_size_without_hash_key()
+    }
+
+    fn row_byte_size_without_hash_key(&self) -> usize {
         let values_byte_size: usize = self
             .columns
             .values()
             .map(|column| column.column_type.byte_size())
             .sum();
 
-        // hash_key, sort_key, values, timestamp, tombstone
-        HASH_KEY_BYTE_SIZE
-            + self.sort_key_type.byte_size()
-            + values_byte_size
-            + size_of::<u128>()
-            + 1
+        self.sort_key_type.byte_size() + values_byte_size + size_of::<u128>() + 1
+    }
+
+    // stamped into sstables and commit logs, changes whenever row encoding does, of options only
+    // hash key size matters
+    pub fn schema_version(&self) -> u64 {
+        let mut layout = format!("sort_key:{}", self.sort_key_type);
+        // default is left out, so that versions of existing tables stay the same
+        if self.hash_key_size() != HASH_KEY_BYTE_SIZE {
+            layout.push_str(&format!(";hash_key:{}", self.hash_key_size()));
+        }
+        for (name, column) in &self.columns {
+            layout.push_str(&format!(";{}:{}", name, column));
+        }
+
+        // FNV-1a, so that version stays the same across builds
+        layout.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
     }
 }
 
@@ -143,11 +705,16 @@ impl Display for TableSchema {
         for (name, column) in &self.columns {
             columns.push(format!("{}:{}", name, column.to_string()));
         }
-        write!(f, "{}>{}", self.name, columns.join(";"))
+        write!(f, "{}>{}", self.name, columns.join(";"))?;
+        if !self.options.is_default() {
+            write!(f, "#{}", self.options)?;
+        }
+
+        Ok(())
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Column {
     pub column_type: ColumnType,
     pub nullable: bool,
@@ -252,11 +819,16 @@ pub async fn read_table_schemas(file_path: &str) -> Result<Vec<TableSchema>, Str
         .await
         .map_err(|e| e.to_string())?;
 
-    let buffer = Vec::with_capacity(16 * 1024);
-    let (result, buffer) = file.read_at(buffer, 0).await;
-    let num_of_bytes = result.map_err(|e| e.to_string())?;
-    if num_of_bytes > buffer.capacity() {
-        return Err("Buffer overflow".to_string());
+    // read in chunks until end of file, as the file grows with every table
+    let mut buffer = Vec::new();
+    loop {
+        let chunk = Vec::with_capacity(SCHEMA_FILE_READ_CHUNK_SIZE);
+        let (result, chunk) = file.read_at(chunk, buffer.len() as u64).await;
+        let num_of_bytes = result.map_err(|e| e.to_string())?;
+        if num_of_bytes == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..num_of_bytes]);
     }
 
     if buffer.is_empty() {
@@ -275,15 +847,22 @@ pub async fn read_table_schemas(file_path: &str) -> Result<Vec<TableSchema>, Str
         .collect())
 }
 
+// written aside and renamed over the old file, so that crash never leaves a partial schema file,
+// every thread writes the file on DDL, so each write gets a file of its own
 pub async fn write_table_schemas_to_file(
     table_schemas: Vec<TableSchema>,
     file_path: &str,
 ) -> Result<(), String> {
+    let temporary_file_path = format!(
+        "{}.{}.tmp",
+        file_path,
+        SCHEMA_FILE_WRITES.fetch_add(1, Ordering::Relaxed)
+    );
     let file = OpenOptions::new()
         .write(true)
         .truncate(true)
         .create(true)
-        .open(file_path)
+        .open(&temporary_file_path)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -297,44 +876,162 @@ pub async fn write_table_schemas_to_file(
         .await
         .0
         .map_err(|e| e.to_string())?;
+    file.sync_all().await.map_err(|e| e.to_string())?;
+
+    std::fs::rename(&temporary_file_path, file_path).map_err(|e| e.to_string())
+}
+
+// rows encoded with a different schema would be decoded as garbage, so server refuses to start instead
+pub fn check_schema_compatibility(
+    table_schemas: &[TableSchema],
+    data_dir: &DataDir,
+) -> Result<(), String> {
+    for table_schema in table_schemas {
+        check_sstables_schema_version(table_schema, &data_dir.sstables)
+            .and_then(|_| check_commit_logs_schema_version(table_schema, &data_dir.commit_logs))
+            .map_err(|error| {
+                format!(
+                    "{}, restore matching schema or migrate the table data before starting",
+                    error
+                )
+            })?;
+    }
 
     Ok(())
 }
 
 pub async fn sync_model(
     schema_string: String,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
     partitions: &HashSet<usize>,
-    file_path: &str,
+    data_dir: &DataDir,
 ) -> Result<(), String> {
-    let table_schema = TableSchema::from_string(&schema_string)?;
+    sync_models(vec![schema_string], tables, partitions, data_dir).await
+}
+
+fn validate_index_column(column: &str, columns: &BTreeMap<String, Column>) -> Result<(), String> {
+    match columns.contains_key(column) {
+        true => Ok(()),
+        false => Err(format!(
+            "Indexed column '{}' is not a column of table",
+            column
+        )),
+    }
+}
+
+// table name starts names of its files, so it cannot contain their '-' separator or anything
+// leading out of data dir
+pub fn validate_table_name(table_name: &str) -> Result<(), String> {
+    if table_name.is_empty() || table_name.len() > TABLE_NAME_MAX_LENGTH {
+        return Err(format!(
+            "Invalid table name '{}', it has to be 1 to {} characters long",
+            table_name, TABLE_NAME_MAX_LENGTH
+        ));
+    }
+    if !table_name
+        .chars()
+        .all(|char| char.is_ascii_alphanumeric() || char == '_')
+    {
+        return Err(format!(
+            "Invalid table name '{}', only letters, digits and '_' are allowed",
+            table_name
+        ));
+    }
+    Ok(())
+}
+
+// either every schema is applied or none, schema file is written once for all of them,
+// errors of every schema requiring migration are returned together
+pub async fn sync_models(
+    schema_strings: Vec<String>,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    partitions: &HashSet<usize>,
+    data_dir: &DataDir,
+) -> Result<(), String> {
+    let mut table_schemas: Vec<TableSchema> = Vec::with_capacity(schema_strings.len());
+    for schema_string in &schema_strings {
+        let table_schema = TableSchema::from_string(schema_string)?;
+        validate_table_name(&table_schema.name)?;
+        if table_schemas
+            .iter()
+            .any(|other_schema| other_schema.name == table_schema.name)
+        {
+            return Err(format!(
+                "Table '{}' is synced more than once",
+                &table_schema.name
+            ));
+        }
+        table_schemas.push(table_schema);
+    }
+
     let mut tables = tables.lock().await;
-    if tables.contains_key(&table_schema.name) {
-        return Err(format!("Table '{}' already exists", &table_schema.name));
+    let mut new_schemas = Vec::new();
+    let mut metadata_changes = Vec::new();
+    let mut errors = Vec::new();
+    for table_schema in table_schemas {
+        match tables.get(&table_schema.name) {
+            Some(table) => match plan_migration(&table.table_schema, &table_schema) {
+                // rows of existing sstables and commit logs are encoded by the current schema,
+                // so the table has to be dropped and synced again to change it
+                Ok(migration_plan) if !migration_plan.is_empty() => errors.push(format!(
+                    "Table '{}' already exists with a different schema, which cannot be changed",
+                    &table_schema.name
+                )),
+                Ok(_) if table.table_schema.options.metadata != table_schema.options.metadata => {
+                    metadata_changes.push(table_schema)
+                }
+                Ok(_) => {}
+                Err(error) => errors.push(error),
+            },
+            None => new_schemas.push(table_schema),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    if new_schemas.is_empty() && metadata_changes.is_empty() {
+        return Ok(());
     }
 
-    tables.insert(
-        table_schema.name.clone(),
-        Table::new(
-            Memtable::default(),
-            CommitLog::open_new(&table_schema, partitions).await,
-            table_schema.clone(),
-        ),
-    );
+    // tables are changed only once schema file has all of them
+    let mut schemas_to_write: Vec<_> = tables
+        .values()
+        .map(|table| {
+            let mut table_schema = table.table_schema.clone();
+            if let Some(changed_schema) = metadata_changes
+                .iter()
+                .find(|changed_schema| changed_schema.name == table_schema.name)
+            {
+                table_schema.options.metadata = changed_schema.options.metadata.clone();
+            }
+            table_schema
+        })
+        .collect();
+    schemas_to_write.extend(new_schemas.iter().cloned());
+    write_table_schemas_to_file(schemas_to_write, &data_dir.table_schemas_file).await?;
 
-    let mut table_schemas = Vec::new();
-    for table in tables.values() {
-        table_schemas.push(table.table_schema.clone());
+    for changed_schema in metadata_changes {
+        let table = tables.get_mut(&changed_schema.name).unwrap();
+        table.table_schema.options.metadata = changed_schema.options.metadata;
+    }
+    for table_schema in new_schemas {
+        tables.insert(
+            table_schema.name.clone(),
+            Table::new(
+                Memtable::default(),
+                CommitLog::open_new(&table_schema, partitions, &data_dir.commit_logs).await,
+                table_schema.clone(),
+                data_dir.clone(),
+            ),
+        );
     }
-    table_schemas.push(table_schema);
-    write_table_schemas_to_file(table_schemas, file_path).await?;
 
     Ok(())
 }
 
 pub async fn drop_table(
     table_name: String,
-    tables: Arc<Mutex<HashMap<String, Table>>>,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
     schema_file_path: &str,
     sstable_dir: &str,
 ) -> Result<(), String> {
@@ -355,18 +1052,86 @@ pub async fn drop_table(
     }
 }
 
+pub async fn set_read_only(
+    table_name: &str,
+    read_only: bool,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    schema_file_path: &str,
+) -> Result<(), String> {
+    let mut tables = tables.lock().await;
+    let table = tables
+        .get_mut(table_name)
+        .ok_or(format!("Table '{}' does not exist", table_name))?;
+    table.table_schema.options.read_only = read_only;
+
+    let table_schemas: Vec<_> = tables
+        .values()
+        .map(|table| table.table_schema.clone())
+        .collect();
+    write_table_schemas_to_file(table_schemas, schema_file_path).await
+}
+
+// returns released state per table name
+pub async fn release_bucket(
+    bucket: u32,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    partitions: &HashSet<usize>,
+    partition_map: &PartitionMap,
+) -> Vec<(String, ReplayedState)> {
+    let mut tables = tables.lock().await;
+    let mut released_states = Vec::with_capacity(tables.len());
+    for (table_name, table) in tables.iter_mut() {
+        let state = table
+            .release_bucket(bucket, partitions, partition_map)
+            .await;
+        released_states.push((table_name.clone(), state));
+    }
+
+    released_states
+}
+
+pub async fn adopt_bucket(
+    released_states: Vec<(String, ReplayedState)>,
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    partitions: &HashSet<usize>,
+    partition_map: &PartitionMap,
+) {
+    let mut tables = tables.lock().await;
+    for (table_name, state) in released_states {
+        if let Some(table) = tables.get_mut(&table_name) {
+            table.adopt_bucket(state).await;
+        }
+    }
+
+    // bucket rows are in sstables by now, so they can be tracked from a snapshot
+    for table in tables.values_mut() {
+        table.restore_access_times(partitions, partition_map).await;
+    }
+}
+
 fn drop_table_sstables(table_name: &str, sstable_dir: &str) {
     let filenames = get_sstables_metadata(table_name, sstable_dir);
     for sstable_metadata in filenames {
-        std::fs::remove_file(sstable_metadata.file_path).unwrap();
+        remove_sstable_statistics(&sstable_metadata, sstable_dir);
+        remove_sstable_index(&sstable_metadata, sstable_dir);
+        remove_sstable_file(&sstable_metadata.file_path);
     }
+    drop_sstable_history(table_name, sstable_dir);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commit_log::{replay_commit_logs, PartialFlushes};
+    use crate::hooks::{register_storage_hooks, StorageHooks};
+    use crate::move_row::read_visible_row;
+    use crate::sstable::{
+        count_sstable_rows_per_partition, sort_rows_by_partition, SSTableSegment,
+    };
+    use crate::validation::{dropped_columns, transform_values, validate_values_against_schema};
     use monoio::fs::File;
     use std::iter::zip;
+    use std::time::Duration;
 
     fn get_table() -> Table {
         let table_schema = TableSchema::new("table".to_string(), Varchar(100));
@@ -378,11 +1143,18 @@ mod tests {
             table_schema: table_schema.clone(),
             partition: 0,
             closed: true,
+            partial_flushes: PartialFlushes::default(),
         };
         Table {
             memtable,
             commit_log: Arc::new(Mutex::new(commit_log)),
             table_schema,
+            sequences: HashMap::new(),
+            leases: HashMap::new(),
+            row_counts: HashMap::new(),
+            access_tracker: AccessTracker::default(),
+            data_dir: DataDir::default(),
+            write_intents: HashMap::new(),
         }
     }
 
@@ -449,6 +1221,316 @@ mod tests {
         assert_eq!(error3, "Invalid number of chars for VARCHAR".to_string());
     }
 
+    #[test]
+    fn schema_string_with_options() {
+        let schema_string = "table>sort_key:INT32;name:VARCHAR(100)#row_counts";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert!(table_schema.options.row_counts);
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let error = TableSchema::from_string("table>sort_key:INT32#unknown").unwrap_err();
+        assert_eq!(error, "Invalid table option 'unknown'".to_string());
+    }
+
+    #[test]
+    fn identical_insert_within_dedup_window_is_duplicate() {
+        let mut table = get_table();
+        table.table_schema =
+            TableSchema::from_string("table>sort_key:INT32;age:INT32#dedup_window=30s").unwrap();
+        assert_eq!(
+            table.table_schema.to_string(),
+            "table>sort_key:INT32;age:INT32#dedup_window=30s"
+        );
+
+        let row = |age, timestamp| {
+            let values = HashMap::from([("age".to_string(), Value::Int32(age))]);
+            Row::new_with_timestamp("a".to_string(), Value::Int32(-1), values, timestamp)
+        };
+        assert!(!table.is_duplicate_insert(&row(20, 1_000)));
+
+        table.apply_insert(row(20, 1_000), &AuthContext::anonymous());
+        assert!(table.is_duplicate_insert(&row(20, 31_000)));
+        assert!(!table.is_duplicate_insert(&row(21, 31_000)));
+        assert!(!table.is_duplicate_insert(&row(20, 31_001)));
+    }
+
+    #[test]
+    fn schema_string_with_retention() {
+        let schema_string =
+            "table>sort_key:INT32;created_at:UNSIGNED64#retention=30d,retention_column=created_at";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(
+            table_schema.options.retention,
+            Some(Duration::from_secs(30 * 86400))
+        );
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let mut row = Row::new(
+            "hash".to_string(),
+            Value::Int32(1),
+            HashMap::from([("created_at".to_string(), Value::Unsigned64(1000))]),
+        );
+        assert!(table_schema
+            .options
+            .is_row_expired(&row, millis_from_epoch()));
+
+        row.values.insert(
+            "created_at".to_string(),
+            Value::Unsigned64(millis_from_epoch() as u64),
+        );
+        assert!(!table_schema
+            .options
+            .is_row_expired(&row, millis_from_epoch()));
+
+        let error = TableSchema::from_string(
+            "table>sort_key:INT32;name:VARCHAR(10)#retention=1h,retention_column=name",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "Retention column 'name' has to be INT64 or UNSIGNED64 column".to_string()
+        );
+    }
+
+    #[test]
+    fn schema_string_with_append_only() {
+        let schema_string = "events>sort_key:UNSIGNED64;kind:VARCHAR(10)#append_only";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert!(table_schema.options.append_only);
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let error = TableSchema::from_string("events>sort_key:INT64#append_only").unwrap_err();
+        assert_eq!(
+            error,
+            "Append-only table has to have UNSIGNED64 sort key, it is assigned from sequence"
+                .to_string()
+        );
+        let error =
+            TableSchema::from_string("events>sort_key:UNSIGNED64#append_only,cache_max_rows=10")
+                .unwrap_err();
+        assert_eq!(error, "Append-only table cannot be a cache".to_string());
+    }
+
+    #[test]
+    fn schema_string_with_ttl_column() {
+        let schema_string = "table>sort_key:INT32;expires_at:INT64?#ttl_column=expires_at";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let now = millis_from_epoch();
+        let row = |expires_at| {
+            Row::new(
+                "hash".to_string(),
+                Value::Int32(1),
+                HashMap::from([("expires_at".to_string(), expires_at)]),
+            )
+        };
+        let options = &table_schema.options;
+        assert!(options.is_row_expired(&row(Value::Int64(now as i64 - 1)), now));
+        assert!(!options.is_row_expired(&row(Value::Int64(now as i64 + 60_000)), now));
+        assert!(!options.is_row_expired(&row(Value::Null), now));
+
+        let error =
+            TableSchema::from_string("table>sort_key:INT32;name:VARCHAR(10)#ttl_column=name")
+                .unwrap_err();
+        assert_eq!(
+            error,
+            "TTL column 'name' has to be INT64 or UNSIGNED64 column".to_string()
+        );
+    }
+
+    #[test]
+    fn schema_string_with_transforms() {
+        let schema_string = "user>sort_key:INT32;email:VARCHAR(8);name:VARCHAR(10)?\
+             #transform=email:trim,transform=email:lowercase,transform=email:truncate";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let mut values = HashMap::from([
+            (
+                "email".to_string(),
+                Value::Varchar("  ABCDEFGŁ ".to_string()),
+            ),
+            ("name".to_string(), Value::Null),
+        ]);
+        transform_values(&mut values, &table_schema);
+        // 'ł' takes two bytes and does not fit whole
+        assert_eq!(values["email"], Value::Varchar("abcdefg".to_string()));
+        assert_eq!(values["name"], Value::Null);
+
+        let error = TableSchema::from_string("user>sort_key:INT32;age:INT32#transform=age:trim")
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "Transformed column 'age' has to be VARCHAR column".to_string()
+        );
+        assert!(TableSchema::from_string(
+            "user>sort_key:INT32;email:VARCHAR(8)#transform=email:uppercase"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn unknown_columns_are_rejected_unless_table_is_lenient() {
+        let values = HashMap::from([
+            ("age".to_string(), Value::Int32(30)),
+            ("nickname".to_string(), Value::Null),
+            ("email".to_string(), Value::Null),
+        ]);
+
+        let table_schema = TableSchema::from_string("strict>sort_key:INT32;age:INT32").unwrap();
+        let error =
+            validate_values_against_schema(&Value::Int32(-1), &values, &table_schema).unwrap_err();
+        assert_eq!(
+            error,
+            "Invalid fields for table 'strict', [\"email\", \"nickname\"] are not in schema"
+        );
+
+        let schema_string = "lenient>sort_key:INT32;age:INT32#lenient_columns";
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(table_schema.to_string(), schema_string);
+        let mut lenient_values = values.clone();
+        transform_values(&mut lenient_values, &table_schema);
+        assert_eq!(lenient_values.keys().collect::<Vec<_>>(), vec!["age"]);
+        assert!(
+            validate_values_against_schema(&Value::Int32(-1), &lenient_values, &table_schema)
+                .is_ok()
+        );
+        assert_eq!(dropped_columns("lenient"), 2);
+    }
+
+    #[test]
+    fn schema_string_with_references() {
+        let schema_string = "post>sort_key:INT32;author_id:VARCHAR(50)?#references=author_id:user";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let values = HashMap::from([(
+            "author_id".to_string(),
+            Value::Varchar("user_1".to_string()),
+        )]);
+        let referenced_hash_keys = table_schema.options.referenced_hash_keys(&values);
+        assert_eq!(referenced_hash_keys.len(), 1);
+        assert_eq!(referenced_hash_keys[0].0.table, "user");
+        assert_eq!(referenced_hash_keys[0].1, "user_1");
+        assert!(table_schema
+            .options
+            .referenced_hash_keys(&HashMap::new())
+            .is_empty());
+
+        let error = TableSchema::from_string(
+            "post>sort_key:INT32;author_id:INT32#references=author_id:user",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "Reference column 'author_id' has to be VARCHAR column".to_string()
+        );
+    }
+
+    #[test]
+    fn schema_string_with_indexes() {
+        let mut table_schema =
+            TableSchema::from_string("user>sort_key:INT32;age:INT32?;name:VARCHAR(20)").unwrap();
+        table_schema.add_index("age").unwrap();
+        table_schema.add_index("name").unwrap();
+        assert_eq!(
+            table_schema.to_string(),
+            "user>sort_key:INT32;age:INT32?;name:VARCHAR(20)#index=age,index=name"
+        );
+        assert_eq!(
+            TableSchema::from_string(&table_schema.to_string())
+                .unwrap()
+                .options
+                .indexes,
+            vec!["age".to_string(), "name".to_string()]
+        );
+
+        assert_eq!(
+            table_schema.add_index("age").unwrap_err(),
+            "Column 'age' is already indexed".to_string()
+        );
+        assert_eq!(
+            table_schema.add_index("email").unwrap_err(),
+            "Indexed column 'email' is not a column of table".to_string()
+        );
+        assert!(
+            TableSchema::from_string("user>sort_key:INT32;age:INT32#index=age,index=age").is_err()
+        );
+    }
+
+    #[test]
+    fn schema_string_with_unique_indexes() {
+        let mut table_schema =
+            TableSchema::from_string("user>sort_key:INT32;age:INT32?;email:VARCHAR(50)").unwrap();
+        table_schema.add_index("age").unwrap();
+        table_schema.add_unique_index("email").unwrap();
+        let schema_string =
+            "user>sort_key:INT32;age:INT32?;email:VARCHAR(50)#index=age,unique_index=email";
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let options = TableSchema::from_string(schema_string).unwrap().options;
+        assert_eq!(
+            options.indexes,
+            vec!["age".to_string(), "email".to_string()]
+        );
+        assert_eq!(options.unique_indexes, vec!["email".to_string()]);
+        assert_eq!(
+            table_schema.add_unique_index("age").unwrap_err(),
+            "Column 'age' is already indexed".to_string()
+        );
+    }
+
+    #[test]
+    fn schema_string_with_row_policy() {
+        let schema_string = "order>sort_key:INT32#row_policy=tenant_prefix";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let auth = AuthContext {
+            user: Some("acme".to_string()),
+            roles: Vec::new(),
+        };
+        let options = &table_schema.options;
+        assert!(options.allows_row("acme/orders", &auth));
+        assert!(!options.allows_row("acme2/orders", &auth));
+        assert!(!options.allows_row("acme", &auth));
+        assert!(!options.allows_row("acme/orders", &AuthContext::anonymous()));
+
+        let error = TableSchema::from_string("order>sort_key:INT32#row_policy=other").unwrap_err();
+        assert_eq!(error, "Invalid table option 'row_policy=other'".to_string());
+    }
+
+    #[test]
+    fn schema_string_with_metadata() {
+        let schema_string =
+            "order>sort_key:INT32#row_counts,comment=orders%2C by tenant,owner=billing,tag=pii:no,tag=team:payments%23eu";
+
+        let table_schema = TableSchema::from_string(schema_string).unwrap();
+        assert_eq!(table_schema.to_string(), schema_string);
+
+        let metadata = &table_schema.options.metadata;
+        assert_eq!(metadata.comment, Some("orders, by tenant".to_string()));
+        assert_eq!(metadata.owner, Some("billing".to_string()));
+        assert_eq!(metadata.tags["team"], "payments#eu");
+
+        // metadata is updated by sync model, without migration
+        let without_metadata = TableSchema::from_string("order>sort_key:INT32#row_counts").unwrap();
+        assert!(plan_migration(&without_metadata, &table_schema)
+            .unwrap()
+            .is_empty());
+
+        let error = TableSchema::from_string("order>sort_key:INT32#comment=%2").unwrap_err();
+        assert_eq!(error, "Invalid encoded metadata value '%2'".to_string());
+    }
+
     #[monoio::test]
     async fn read_tables_from_empty_file() {
         let file_path = "/tmp/read_empty_schemas";
@@ -458,6 +1540,32 @@ mod tests {
         std::fs::remove_file(file_path).unwrap();
     }
 
+    #[monoio::test]
+    async fn read_schemas_of_hundreds_of_tables() {
+        let file_path = "/tmp/read_hundreds_of_schemas";
+        let table_schemas: Vec<_> = (0..300)
+            .map(|table| {
+                TableSchema::from_string(&format!(
+                    "table_{}>sort_key:INT32;description:VARCHAR(100)?;name:VARCHAR(100)",
+                    table
+                ))
+                .unwrap()
+            })
+            .collect();
+        write_table_schemas_to_file(table_schemas.clone(), file_path)
+            .await
+            .unwrap();
+        assert!(std::fs::metadata(file_path).unwrap().len() > SCHEMA_FILE_READ_CHUNK_SIZE as u64);
+
+        let schemas_from_file = read_table_schemas(file_path).await.unwrap();
+        assert_eq!(schemas_from_file.len(), table_schemas.len());
+        for (schema_from_file, table_schema) in zip(&schemas_from_file, &table_schemas) {
+            assert_eq!(schema_from_file.to_string(), table_schema.to_string());
+        }
+
+        std::fs::remove_file(file_path).unwrap();
+    }
+
     #[monoio::test]
     async fn write_and_read_table_schema() {
         let file_path = "/tmp/write_and_read_schemas";
@@ -489,6 +1597,91 @@ mod tests {
         std::fs::remove_file(file_path).unwrap();
     }
 
+    #[monoio::test]
+    async fn sstables_written_with_other_schema_are_refused() {
+        let sstable_dir = "/tmp/sstables_written_with_other_schema";
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let table = get_table();
+        let row = Row::new(
+            "hash".to_string(),
+            Value::Varchar("sort".to_string()),
+            HashMap::new(),
+        );
+        let segment = SSTableSegment::new(
+            table.table_schema.clone(),
+            vec![row],
+            HashMap::from([(0, 0)]),
+        );
+        segment.write_to_disk(sstable_dir).await.unwrap();
+
+        let sstable_metadatas = get_sstables_metadata("table", sstable_dir);
+        assert_eq!(
+            sstable_metadatas[0].schema_version,
+            Some(table.table_schema.schema_version())
+        );
+
+        let mut table_schema = table.table_schema.clone();
+        table_schema.options.row_counts = true;
+        assert_eq!(
+            table_schema.schema_version(),
+            table.table_schema.schema_version()
+        );
+        assert!(check_sstables_schema_version(&table_schema, sstable_dir).is_ok());
+
+        table_schema
+            .columns
+            .insert("age".to_string(), Column::new(Unsigned32, true));
+        assert!(check_sstables_schema_version(&table_schema, sstable_dir).is_err());
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+
+    #[monoio::test]
+    async fn rows_are_counted_per_partition() {
+        let sstable_dir = "/tmp/rows_are_counted_per_partition";
+        std::fs::create_dir_all(sstable_dir).unwrap();
+
+        let mut table = get_table();
+        let partition_map = PartitionMap::new(2);
+        let rows: Vec<_> = ["a", "b", "c", "d", "e", "f"]
+            .into_iter()
+            .map(|hash_key| {
+                Row::new(
+                    hash_key.to_string(),
+                    Value::Varchar("sort".to_string()),
+                    HashMap::new(),
+                )
+            })
+            .collect();
+        for row in rows.iter().cloned() {
+            table.memtable.insert(row, false);
+        }
+
+        let mut expected_rows_per_partition = HashMap::new();
+        for row in &rows {
+            *expected_rows_per_partition
+                .entry(partition_map.get_partition(&row.hash_key))
+                .or_insert(0) += 1;
+        }
+        assert_eq!(
+            table.memtable.count_rows_per_partition(&partition_map),
+            expected_rows_per_partition
+        );
+
+        let mut sstable_rows = rows.clone();
+        let partition_index = sort_rows_by_partition(&mut sstable_rows, &partition_map);
+        let segment =
+            SSTableSegment::new(table.table_schema.clone(), sstable_rows, partition_index);
+        segment.write_to_disk(sstable_dir).await.unwrap();
+        assert_eq!(
+            count_sstable_rows_per_partition("table", sstable_dir).await,
+            expected_rows_per_partition
+        );
+
+        std::fs::remove_dir_all(sstable_dir).unwrap();
+    }
+
     #[monoio::test]
     async fn drop_table_with_sstables() {
         let schema_path = "/tmp/drop_table_schemas";
@@ -506,7 +1699,7 @@ mod tests {
         .await
         .unwrap();
 
-        let tables = Arc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
         drop_table(
             "table".to_string(),
             tables.clone(),
@@ -523,4 +1716,253 @@ mod tests {
         std::fs::remove_file(schema_path).unwrap();
         std::fs::remove_dir_all(sstable_dir).unwrap();
     }
+
+    #[derive(Default)]
+    struct RecordingHooks {
+        events: std::sync::Mutex<Vec<String>>,
+    }
+
+    // hooks are global, so only rows of this test are recorded
+    impl StorageHooks for RecordingHooks {
+        fn on_insert(&self, table_name: &str, row: &Row, auth: &AuthContext) {
+            if row.hash_key == "hooked" {
+                let event = format!(
+                    "insert {} {} by {:?}",
+                    table_name, row.primary_key, auth.user
+                );
+                self.events.lock().unwrap().push(event);
+            }
+        }
+
+        fn on_delete(&self, table_name: &str, primary_key: &str, auth: &AuthContext) {
+            if primary_key.starts_with("hooked:") {
+                let event = format!("delete {} {} by {:?}", table_name, primary_key, auth.user);
+                self.events.lock().unwrap().push(event);
+            }
+        }
+    }
+
+    #[monoio::test]
+    async fn hooks_are_notified_of_applied_writes() {
+        let hooks = Arc::new(RecordingHooks::default());
+        register_storage_hooks(hooks.clone());
+
+        let mut table = get_table();
+        let row = Row::new(
+            "hooked".to_string(),
+            Value::Varchar("sort".to_string()),
+            HashMap::new(),
+        );
+        let auth = AuthContext {
+            user: Some("admin".to_string()),
+            roles: vec!["writer".to_string()],
+        };
+        table.apply_insert(row.clone(), &auth);
+        assert!(table.memtable.get(&row.primary_key).is_some());
+        table
+            .on_row_deleted(&row.hash_key, &row.primary_key, &AuthContext::anonymous())
+            .await;
+
+        assert_eq!(
+            *hooks.events.lock().unwrap(),
+            vec![
+                "insert table hooked:sort by Some(\"admin\")".to_string(),
+                "delete table hooked:sort by None".to_string(),
+            ]
+        );
+    }
+
+    #[monoio::test]
+    async fn models_are_synced_all_or_none() {
+        let root = "/tmp/models_are_synced_all_or_none";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let tables = Rc::new(Mutex::new(HashMap::new()));
+        let partitions = HashSet::from([0]);
+
+        sync_model(
+            "users>sort_key:INT32".to_string(),
+            tables.clone(),
+            &partitions,
+            &data_dir,
+        )
+        .await
+        .unwrap();
+
+        // schema of users cannot be changed, so orders is not created either
+        let error = sync_models(
+            vec![
+                "orders>sort_key:INT32".to_string(),
+                "users>sort_key:INT32;age:INT32".to_string(),
+            ],
+            tables.clone(),
+            &partitions,
+            &data_dir,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.contains("table 'users'"), "{}", error);
+        assert!(!tables.lock().await.contains_key("orders"));
+        // even changes a migration could express are not applied to rows written already
+        let error = sync_model(
+            "users>sort_key:INT32;age:INT32?".to_string(),
+            tables.clone(),
+            &partitions,
+            &data_dir,
+        )
+        .await
+        .unwrap_err();
+        assert!(error.contains("different schema"), "{}", error);
+        assert!(tables.lock().await["users"].table_schema.columns.is_empty());
+        let table_schemas = read_table_schemas(&data_dir.table_schemas_file)
+            .await
+            .unwrap();
+        assert_eq!(table_schemas.len(), 1);
+
+        sync_models(
+            vec![
+                "orders>sort_key:INT32".to_string(),
+                "users>sort_key:INT32".to_string(),
+            ],
+            tables.clone(),
+            &partitions,
+            &data_dir,
+        )
+        .await
+        .unwrap();
+        let mut table_names: Vec<_> = read_table_schemas(&data_dir.table_schemas_file)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|table_schema| table_schema.name)
+            .collect();
+        table_names.sort();
+        assert_eq!(table_names, vec!["orders", "users"]);
+        assert!(tables.lock().await.contains_key("orders"));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn table_names_that_do_not_fit_file_names_are_rejected() {
+        let root = "/tmp/table_names_that_do_not_fit_file_names_are_rejected";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let tables = Rc::new(Mutex::new(HashMap::new()));
+        let partitions = HashSet::from([0]);
+
+        for table_name in ["a-b", "../users", "", &"a".repeat(65)] {
+            let error = sync_model(
+                format!("{}>sort_key:INT32", table_name),
+                tables.clone(),
+                &partitions,
+                &data_dir,
+            )
+            .await
+            .unwrap_err();
+            assert!(error.starts_with("Invalid table name"), "{}", error);
+        }
+        assert!(tables.lock().await.is_empty());
+        assert!(validate_table_name("user_events2").is_ok());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn sequences_are_replayed_for_any_hash_key() {
+        let root = "/tmp/sequences_are_replayed_for_any_hash_key";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let tables = Rc::new(Mutex::new(HashMap::new()));
+        let partitions = HashSet::from([0]);
+        sync_model(
+            "orders>sort_key:INT32".to_string(),
+            tables.clone(),
+            &partitions,
+            &data_dir,
+        )
+        .await
+        .unwrap();
+
+        let mut tables = tables.lock().await;
+        let table = tables.get_mut("orders").unwrap();
+        for hash_key in ["shop:1", "shop\n2", "shop\n2"] {
+            table.next_sequence(hash_key).await.unwrap();
+        }
+        let table_schema = table.table_schema.clone();
+        drop(tables);
+
+        let state =
+            replay_commit_logs(&table_schema, &partitions, &PartitionMap::new(1), &data_dir).await;
+        assert_eq!(
+            state.sequences,
+            HashMap::from([("shop:1".to_string(), 1), ("shop\n2".to_string(), 2)])
+        );
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[monoio::test]
+    async fn row_versions_are_kept_across_flush_and_restart() {
+        let root = "/tmp/row_versions_are_kept_across_flush_and_restart";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let tables = Rc::new(Mutex::new(HashMap::new()));
+        let partitions = HashSet::from([0]);
+        let partition_map = PartitionMap::new(1);
+        sync_model(
+            "accounts>sort_key:INT32".to_string(),
+            tables.clone(),
+            &partitions,
+            &data_dir,
+        )
+        .await
+        .unwrap();
+
+        let mut tables = tables.lock().await;
+        let table = tables.get_mut("accounts").unwrap();
+        // saved the way server saves over rows kept only in sstables
+        let save = |version: u32| {
+            let mut row = Row::new("user_1".to_string(), Value::Int32(-1), HashMap::new());
+            row.version = version;
+            row
+        };
+        let row = save(3);
+        let primary_key = row.primary_key.clone();
+        table.commit_log.lock().await.write_insert(&row).await;
+        table.apply_insert(row, &AuthContext::default());
+
+        let memtable = table.take_memtable();
+        let commit_log = table.rotate_commit_log(&partitions).await;
+        flush_memtable_to_sstable(
+            memtable,
+            commit_log,
+            table.table_schema.clone(),
+            data_dir.sstables.clone(),
+            partition_map.clone(),
+        )
+        .await;
+        let flushed_row = read_visible_row(table, &primary_key, 0).await.unwrap();
+        assert_eq!(flushed_row.visible_version(), 3);
+
+        // saved row has to be newer than the flushed one
+        monoio::time::sleep(Duration::from_millis(2)).await;
+        let row = save(4);
+        table.commit_log.lock().await.write_insert(&row).await;
+        table.commit_log.lock().await.sync().await.unwrap();
+        let table_schema = table.table_schema.clone();
+        drop(tables);
+
+        // restart replays the log into an sstable
+        replay_commit_logs(&table_schema, &partitions, &partition_map, &data_dir).await;
+        let replayed_row =
+            read_row_from_sstable(&primary_key, 0, &table_schema, &data_dir.sstables)
+                .await
+                .unwrap();
+        assert_eq!(replayed_row.visible_version(), 4);
+        std::fs::remove_dir_all(root).unwrap();
+    }
 }
@@ -0,0 +1,461 @@
+use crate::auth::AuthContext;
+use crate::Row;
+use common::value::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+// options are appended to schema string after '#', e.g. "user>sort_key:INT32#row_counts"
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableOptions {
+    // maintain number of rows per hash key
+    pub row_counts: bool,
+    // rows older than retention are purged, age is taken from row timestamp or retention column
+    pub retention: Option<Duration>,
+    pub retention_column: Option<String>,
+    // rows expire once time in that column has passed, in millis since epoch, null never expires,
+    // e.g. "ttl_column=expires_at"
+    pub ttl_column: Option<String>,
+    // table works as a cache, least recently accessed rows are evicted above that many rows per thread
+    pub cache_max_rows: Option<usize>,
+    pub durability: Durability,
+    // column values have to be hash keys having rows in referenced table, e.g. "references=user_id:users"
+    pub references: Vec<Reference>,
+    // applied by server to inserted values in declared order, so that every client writes them
+    // normalized, e.g. "transform=email:trim,transform=email:lowercase"
+    pub transforms: Vec<ColumnTransform>,
+    // rows can be queried by values of these columns, null values are not indexed, e.g. "index=age"
+    pub indexes: Vec<String>,
+    // indexed columns whose values no two rows of a partition share, e.g. "unique_index=email"
+    pub unique_indexes: Vec<String>,
+    // writes are rejected, set by admins rather than declared by models
+    pub read_only: bool,
+    // limit of hash key length in bytes, every encoded row reserves that much for its hash key
+    pub hash_key_size: Option<usize>,
+    // inserted values of columns not in schema are dropped instead of rejecting the insert
+    pub lenient_columns: bool,
+    // memtable rows with lowest primary keys are flushed before memtable gets full,
+    // so that steady writes do not wait on flushes of whole memtables
+    pub partial_flush: bool,
+    // rows are only ever appended, server assigns their sort keys from sequence of hash key,
+    // rows cannot be deleted and expire by retention only, e.g. "append_only"
+    pub append_only: bool,
+    // rows outside of the policy are invisible to reads and cannot be written, e.g. "row_policy=tenant_prefix"
+    pub row_policy: Option<RowPolicy>,
+    // sstables superseded by compaction are kept that long for reads as of past time, e.g. "history=7d"
+    pub history: Option<Duration>,
+    // inserts identical to a row written that recently are acknowledged without being written again,
+    // the row keeps its original timestamp, e.g. "dedup_window=30s"
+    pub dedup_window: Option<Duration>,
+    // inserts are rejected while sstables of the table take more, e.g. "max_disk_mb=10240"
+    pub max_disk_mb: Option<u64>,
+    // text values longer than that many bytes are compressed in memtable and encoded rows, so that
+    // VARCHAR columns of compressible text can be declared shorter, e.g. "compress_values_above=256"
+    pub compress_values_above: Option<usize>,
+    // not interpreted by the server, e.g. "comment=user accounts,owner=billing,tag=team:payments"
+    pub metadata: TableMetadata,
+}
+
+// values are percent encoded in schema strings, see encode_metadata_value
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableMetadata {
+    pub comment: Option<String>,
+    pub owner: Option<String>,
+    pub tags: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub column: String,
+    // has to maintain row counts, existence of hash key is checked against them
+    pub table: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnTransform {
+    // has to be VARCHAR column
+    pub column: String,
+    pub transform: Transform,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    Lowercase,
+    Trim,
+    // longer values are cut to max length of column instead of being rejected
+    Truncate,
+}
+
+impl Transform {
+    fn from_string(transform_string: &str) -> Option<Transform> {
+        match transform_string {
+            "lowercase" => Some(Transform::Lowercase),
+            "trim" => Some(Transform::Trim),
+            "truncate" => Some(Transform::Truncate),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Transform::Lowercase => write!(f, "lowercase"),
+            Transform::Trim => write!(f, "trim"),
+            Transform::Truncate => write!(f, "truncate"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowPolicy {
+    // hash key has to start with user name and '/', e.g. user "acme" owns "acme/orders",
+    // anonymous requests own no rows
+    TenantPrefix,
+}
+
+impl RowPolicy {
+    pub fn allows(&self, hash_key: &str, auth: &AuthContext) -> bool {
+        match self {
+            RowPolicy::TenantPrefix => match &auth.user {
+                Some(user) => hash_key
+                    .strip_prefix(user.as_str())
+                    .is_some_and(|rest| rest.starts_with('/')),
+                None => false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Durability {
+    // commit log is synced in the background, last seconds of writes can be lost on crash
+    #[default]
+    Periodic,
+    // writes are acknowledged only after commit log is synced
+    Always,
+}
+
+impl TableOptions {
+    pub fn from_string(options_string: &str) -> Result<TableOptions, String> {
+        let mut options = TableOptions::default();
+        for option_string in options_string.split(',') {
+            let (name, value) = match option_string.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (option_string, None),
+            };
+
+            match (name, value) {
+                ("row_counts", None) => options.row_counts = true,
+                ("read_only", None) => options.read_only = true,
+                ("partial_flush", None) => options.partial_flush = true,
+                ("lenient_columns", None) => options.lenient_columns = true,
+                ("append_only", None) => options.append_only = true,
+                ("retention", Some(value)) => options.retention = Some(parse_duration(value)?),
+                ("history", Some(value)) => options.history = Some(parse_duration(value)?),
+                ("dedup_window", Some(value)) => {
+                    options.dedup_window = Some(parse_duration(value)?)
+                }
+                ("retention_column", Some(value)) => {
+                    options.retention_column = Some(value.to_string())
+                }
+                ("ttl_column", Some(value)) => options.ttl_column = Some(value.to_string()),
+                ("cache_max_rows", Some(value)) => {
+                    let max_rows = value
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid table option '{}'", option_string))?;
+                    options.cache_max_rows = Some(max_rows);
+                }
+                ("max_disk_mb", Some(value)) => {
+                    let max_disk_mb = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("Invalid table option '{}'", option_string))?;
+                    options.max_disk_mb = Some(max_disk_mb);
+                }
+                ("compress_values_above", Some(value)) => {
+                    let compress_values_above = value
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid table option '{}'", option_string))?;
+                    options.compress_values_above = Some(compress_values_above);
+                }
+                ("hash_key_size", Some(value)) => {
+                    let hash_key_size = value
+                        .parse::<u16>()
+                        .ok()
+                        .filter(|hash_key_size| *hash_key_size > 0)
+                        .ok_or(format!("Invalid table option '{}'", option_string))?;
+                    options.hash_key_size = Some(hash_key_size as usize);
+                }
+                ("row_policy", Some("tenant_prefix")) => {
+                    options.row_policy = Some(RowPolicy::TenantPrefix)
+                }
+                ("comment", Some(value)) => {
+                    options.metadata.comment = Some(decode_metadata_value(value)?)
+                }
+                ("owner", Some(value)) => {
+                    options.metadata.owner = Some(decode_metadata_value(value)?)
+                }
+                ("tag", Some(value)) => {
+                    let (key, value) = value
+                        .split_once(':')
+                        .filter(|(key, _)| !key.is_empty())
+                        .ok_or(format!("Invalid table option '{}'", option_string))?;
+                    options
+                        .metadata
+                        .tags
+                        .insert(decode_metadata_value(key)?, decode_metadata_value(value)?);
+                }
+                ("durability", Some("periodic")) => options.durability = Durability::Periodic,
+                ("durability", Some("always")) => options.durability = Durability::Always,
+                ("references", Some(value)) => {
+                    let (column, table) = value
+                        .split_once(':')
+                        .filter(|(column, table)| !column.is_empty() && !table.is_empty())
+                        .ok_or(format!("Invalid table option '{}'", option_string))?;
+                    options.references.push(Reference {
+                        column: column.to_string(),
+                        table: table.to_string(),
+                    });
+                }
+                ("transform", Some(value)) => {
+                    let (column, transform) = value
+                        .split_once(':')
+                        .filter(|(column, _)| !column.is_empty())
+                        .and_then(|(column, transform)| {
+                            Some((column, Transform::from_string(transform)?))
+                        })
+                        .ok_or(format!("Invalid table option '{}'", option_string))?;
+                    options.transforms.push(ColumnTransform {
+                        column: column.to_string(),
+                        transform,
+                    });
+                }
+                ("index", Some(value)) if !value.is_empty() => {
+                    options.indexes.push(value.to_string())
+                }
+                ("unique_index", Some(value)) if !value.is_empty() => {
+                    options.indexes.push(value.to_string());
+                    options.unique_indexes.push(value.to_string());
+                }
+                _ => return Err(format!("Invalid table option '{}'", option_string)),
+            }
+        }
+
+        if options.retention_column.is_some() && options.retention.is_none() {
+            return Err("Retention column requires retention to be set".to_string());
+        }
+
+        Ok(options)
+    }
+
+    pub fn is_default(&self) -> bool {
+        self == &TableOptions::default()
+    }
+
+    // hash keys referenced by inserted values, null values reference nothing
+    pub fn referenced_hash_keys<'a>(
+        &'a self,
+        values: &HashMap<String, Value>,
+    ) -> Vec<(&'a Reference, String)> {
+        self.references
+            .iter()
+            .filter_map(|reference| match values.get(&reference.column) {
+                Some(Value::Varchar(hash_key)) => Some((reference, hash_key.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn allows_row(&self, hash_key: &str, auth: &AuthContext) -> bool {
+        match &self.row_policy {
+            Some(row_policy) => row_policy.allows(hash_key, auth),
+            None => true,
+        }
+    }
+
+    pub fn is_row_expired(&self, row: &Row, now: u128) -> bool {
+        let expires_at = self
+            .ttl_column
+            .as_ref()
+            .and_then(|column_name| column_millis(row, column_name));
+        if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return true;
+        }
+
+        let retention = match self.retention {
+            Some(retention) => retention.as_millis(),
+            None => return false,
+        };
+
+        let row_millis = match &self.retention_column {
+            Some(column_name) => match column_millis(row, column_name) {
+                Some(millis) => millis,
+                None => return false,
+            },
+            None => row.timestamp,
+        };
+
+        row_millis + retention < now
+    }
+}
+
+impl Display for TableOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut options = Vec::new();
+        if self.row_counts {
+            options.push("row_counts".to_string());
+        }
+        if let Some(retention) = &self.retention {
+            options.push(format!("retention={}", duration_to_string(retention)));
+        }
+        if let Some(retention_column) = &self.retention_column {
+            options.push(format!("retention_column={}", retention_column));
+        }
+        if let Some(ttl_column) = &self.ttl_column {
+            options.push(format!("ttl_column={}", ttl_column));
+        }
+        if let Some(cache_max_rows) = &self.cache_max_rows {
+            options.push(format!("cache_max_rows={}", cache_max_rows));
+        }
+        if self.durability == Durability::Always {
+            options.push("durability=always".to_string());
+        }
+        for reference in &self.references {
+            options.push(format!(
+                "references={}:{}",
+                reference.column, reference.table
+            ));
+        }
+        for column_transform in &self.transforms {
+            options.push(format!(
+                "transform={}:{}",
+                column_transform.column, column_transform.transform
+            ));
+        }
+        for column in &self.indexes {
+            match self.unique_indexes.contains(column) {
+                true => options.push(format!("unique_index={}", column)),
+                false => options.push(format!("index={}", column)),
+            }
+        }
+        if self.read_only {
+            options.push("read_only".to_string());
+        }
+        if let Some(hash_key_size) = &self.hash_key_size {
+            options.push(format!("hash_key_size={}", hash_key_size));
+        }
+        if self.partial_flush {
+            options.push("partial_flush".to_string());
+        }
+        if self.lenient_columns {
+            options.push("lenient_columns".to_string());
+        }
+        if self.append_only {
+            options.push("append_only".to_string());
+        }
+        if let Some(RowPolicy::TenantPrefix) = &self.row_policy {
+            options.push("row_policy=tenant_prefix".to_string());
+        }
+        if let Some(history) = &self.history {
+            options.push(format!("history={}", duration_to_string(history)));
+        }
+        if let Some(dedup_window) = &self.dedup_window {
+            options.push(format!("dedup_window={}", duration_to_string(dedup_window)));
+        }
+        if let Some(max_disk_mb) = &self.max_disk_mb {
+            options.push(format!("max_disk_mb={}", max_disk_mb));
+        }
+        if let Some(compress_values_above) = &self.compress_values_above {
+            options.push(format!("compress_values_above={}", compress_values_above));
+        }
+        if let Some(comment) = &self.metadata.comment {
+            options.push(format!("comment={}", encode_metadata_value(comment)));
+        }
+        if let Some(owner) = &self.metadata.owner {
+            options.push(format!("owner={}", encode_metadata_value(owner)));
+        }
+        for (key, value) in &self.metadata.tags {
+            options.push(format!(
+                "tag={}:{}",
+                encode_metadata_value(key),
+                encode_metadata_value(value)
+            ));
+        }
+
+        write!(f, "{}", options.join(","))
+    }
+}
+
+// millis since epoch, negative and null values are no point in time
+fn column_millis(row: &Row, column_name: &str) -> Option<u128> {
+    match row.values.get(column_name) {
+        Some(Value::Int64(millis)) if *millis >= 0 => Some(*millis as u128),
+        Some(Value::Unsigned64(millis)) => Some(*millis as u128),
+        _ => None,
+    }
+}
+
+static DURATION_UNITS: [(&str, u64); 4] = [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)];
+
+fn parse_duration(duration_string: &str) -> Result<Duration, String> {
+    for (unit, seconds) in DURATION_UNITS {
+        if let Some(number_string) = duration_string.strip_suffix(unit) {
+            let number = number_string
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid duration '{}'", duration_string))?;
+            return Ok(Duration::from_secs(number * seconds));
+        }
+    }
+
+    Err(format!("Invalid duration '{}'", duration_string))
+}
+
+fn duration_to_string(duration: &Duration) -> String {
+    let total_seconds = duration.as_secs();
+    for (unit, seconds) in DURATION_UNITS {
+        if total_seconds / seconds * seconds == total_seconds {
+            return format!("{}{}", total_seconds / seconds, unit);
+        }
+    }
+
+    format!("{}s", total_seconds)
+}
+
+// ':' separates tag keys from values, the rest would break schema strings or schema file lines
+pub fn encode_metadata_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '%' | ',' | '#' | ':' | '\n' | '\r' => {
+                encoded.push_str(&format!("%{:02X}", character as u8))
+            }
+            _ => encoded.push(character),
+        }
+    }
+
+    encoded
+}
+
+fn decode_metadata_value(value: &str) -> Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'%' => {
+                let byte = value
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or(format!("Invalid encoded metadata value '{}'", value))?;
+                decoded.push(byte);
+                index += 3;
+            }
+            byte => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8(decoded).map_err(|_| format!("Invalid encoded metadata value '{}'", value))
+}
@@ -0,0 +1,115 @@
+use crate::MEGABYTE;
+use monoio::time::sleep;
+use std::collections::BTreeMap;
+use std::os::unix::fs::MetadataExt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// databases keeping sstables on the same disk share its budget, whichever compaction thread runs them
+static COMPACTION_IO_THROTTLES: Mutex<BTreeMap<u64, IoThrottle>> = Mutex::new(BTreeMap::new());
+
+// limits bytes per second read and written by compaction, so that commit log fsyncs
+// are not starved, limit of 0 means unlimited
+#[derive(Clone)]
+pub struct IoThrottle {
+    budget: Arc<Mutex<IoBudget>>,
+}
+
+struct IoBudget {
+    bytes_per_second: u64,
+    // negative once callers have taken more than was available, they wait for it to refill
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl IoBudget {
+    // returns how long caller has to wait before doing io of given size,
+    // at most one second worth of unused budget is kept for bursts
+    fn take(&mut self, bytes: usize, now: Instant) -> Duration {
+        if self.bytes_per_second == 0 {
+            return Duration::ZERO;
+        }
+
+        let rate = self.bytes_per_second as f64;
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.available = (self.available + elapsed.as_secs_f64() * rate).min(rate);
+        self.refilled_at = now;
+        self.available -= bytes as f64;
+
+        match self.available >= 0.0 {
+            true => Duration::ZERO,
+            false => Duration::from_secs_f64(-self.available / rate),
+        }
+    }
+}
+
+impl IoThrottle {
+    pub fn new(rate_limit_mb: u64) -> IoThrottle {
+        let bytes_per_second = rate_limit_mb * MEGABYTE as u64;
+        IoThrottle {
+            budget: Arc::new(Mutex::new(IoBudget {
+                bytes_per_second,
+                available: bytes_per_second as f64,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    pub fn rate_limit_mb(&self) -> u64 {
+        self.budget.lock().unwrap().bytes_per_second / MEGABYTE as u64
+    }
+
+    pub fn set_rate_limit_mb(&self, rate_limit_mb: u64) {
+        let mut budget = self.budget.lock().unwrap();
+        budget.bytes_per_second = rate_limit_mb * MEGABYTE as u64;
+        budget.available = budget.available.min(budget.bytes_per_second as f64);
+    }
+
+    pub async fn acquire(&self, bytes: usize) {
+        let wait = self.budget.lock().unwrap().take(bytes, Instant::now());
+        if !wait.is_zero() {
+            sleep(wait).await;
+        }
+    }
+}
+
+// throttle of the disk holding given directory, unlimited until rate limit gets set
+pub fn compaction_io_throttle(dir: &str) -> IoThrottle {
+    // directories that cannot be inspected share a single budget
+    let device = std::fs::metadata(dir)
+        .map(|metadata| metadata.dev())
+        .unwrap_or(0);
+
+    COMPACTION_IO_THROTTLES
+        .lock()
+        .unwrap()
+        .entry(device)
+        .or_insert_with(|| IoThrottle::new(0))
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_over_budget_waits_for_refill() {
+        let started_at = Instant::now();
+        let mut budget = IoBudget {
+            bytes_per_second: 100,
+            available: 100.0,
+            refilled_at: started_at,
+        };
+
+        assert_eq!(budget.take(60, started_at), Duration::ZERO);
+        assert_eq!(budget.take(90, started_at), Duration::from_millis(500));
+
+        // unused budget does not pile up past one second
+        let later = started_at + Duration::from_secs(10);
+        assert_eq!(budget.take(100, later), Duration::ZERO);
+        assert_eq!(budget.take(50, later), Duration::from_millis(500));
+
+        budget.bytes_per_second = 0;
+        assert_eq!(budget.take(1000, later), Duration::ZERO);
+    }
+}
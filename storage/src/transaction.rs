@@ -1,26 +1,60 @@
-use crate::sstable::flush_memtable_to_sstable;
+use crate::auth::AuthContext;
+use crate::data_dir::DataDir;
+use crate::move_row::read_visible_row;
+use crate::prepare_log::{
+    encode_operations, is_commit_decided, read_prepare_records, remove_prepare_record,
+    write_prepare_record,
+};
 use crate::table::Table;
-use crate::{Memtable, Row};
+use crate::util::millis_from_epoch;
+use crate::Row;
+use common::partition::PartitionMap;
 use futures::lock::Mutex;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+
+// intents of transaction whose commit or abort never arrived stop blocking its keys after that long
+pub static WRITE_INTENT_TIMEOUT_MILLIS: u128 = 5000;
+
+// row as transaction read it, it can commit only if row is still the same,
+// hash key tells which partition to read it from once flushed
+#[derive(Debug, Clone, PartialEq)]
+struct AffectedRow {
+    hash_key: String,
+    version: u32,
+    timestamp: u128,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WriteIntent {
+    pub transaction_id: u64,
+    pub placed_at: u128,
+}
 
 pub struct Transaction {
     // MVCC transaction
     pub id: u64,
-    // map of primary_key:version inside of map table_name:map
-    affected_rows: HashMap<String, HashMap<String, u32>>,
+    pub started_at: u128,
+    // map of primary_key:row inside of map table_name:map
+    affected_rows: HashMap<String, HashMap<String, AffectedRow>>,
     operations: HashMap<String, Vec<Operation>>,
     committed: bool,
+    // voted to commit, coordinator may decide to commit it from now on
+    prepared: bool,
+    // written once writes of this thread are prepared, removed on commit or abort
+    prepare_record: Option<String>,
 }
 
 impl Transaction {
     pub fn new(transaction_id: u64) -> Transaction {
         Transaction {
             id: transaction_id,
+            started_at: millis_from_epoch(),
             affected_rows: HashMap::new(),
             operations: HashMap::new(),
             committed: false,
+            prepared: false,
+            prepare_record: None,
         }
     }
 
@@ -30,28 +64,35 @@ impl Transaction {
         }
     }
 
-    pub fn insert(&mut self, row: Row, table: &Table) {
+    pub fn insert(&mut self, row: Row, table: &Table, auth: &AuthContext) {
         let table_name = table.table_schema.name.clone();
 
         if let Some(replaced_row) = table.memtable.get(&row.primary_key) {
-            self.add_affected_row(replaced_row, table_name.clone());
+            self.add_affected_row(&replaced_row, table_name.clone());
         }
 
         self.operations
             .entry(table_name)
             .or_insert(Vec::new())
-            .push(Operation::Insert(row));
+            .push(Operation::Insert(row, auth.clone()));
     }
 
-    pub fn delete(&mut self, primary_key: String, table: &Table) -> bool {
+    // rows flushed to sstables are deleted too, by a tombstone written on commit
+    pub async fn delete(
+        &mut self,
+        primary_key: String,
+        partition: usize,
+        table: &Table,
+        auth: &AuthContext,
+    ) -> bool {
         let table_name = table.table_schema.name.clone();
 
-        if let Some(deleted_row) = table.memtable.get(&primary_key) {
-            self.add_affected_row(deleted_row, table_name.clone());
+        if let Some(deleted_row) = read_visible_row(table, &primary_key, partition).await {
+            self.add_affected_row(&deleted_row, table_name.clone());
             self.operations
                 .entry(table_name)
                 .or_insert(Vec::new())
-                .push(Operation::Delete(primary_key));
+                .push(Operation::Delete(tombstone_of(deleted_row), auth.clone()));
             return true;
         }
 
@@ -64,78 +105,306 @@ impl Transaction {
             .entry(table_name)
             .or_insert(HashMap::new());
         if !table_affected_rows.contains_key(&row.primary_key) {
-            table_affected_rows.insert(row.primary_key.clone(), row.version);
+            table_affected_rows.insert(
+                row.primary_key.clone(),
+                AffectedRow {
+                    hash_key: row.hash_key.clone(),
+                    version: row.version,
+                    timestamp: row.timestamp,
+                },
+            );
         }
     }
 
-    pub async fn can_commit(&self, tables: Arc<Mutex<HashMap<String, Table>>>) -> bool {
+    // tables read for update or written to within this thread
+    pub fn affected_tables(&self) -> BTreeSet<String> {
+        self.affected_rows
+            .keys()
+            .chain(self.operations.keys())
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_prepared(&self) -> bool {
+        self.prepared
+    }
+
+    // keys read for update or written to, along with their table
+    fn touched_keys(&self) -> impl Iterator<Item = (&String, &String)> {
+        let read_keys = self.affected_rows.iter().flat_map(|(table_name, rows)| {
+            rows.keys()
+                .map(move |primary_key| (table_name, primary_key))
+        });
+        let written_keys = self.operations.iter().flat_map(|(table_name, operations)| {
+            operations
+                .iter()
+                .map(move |operation| (table_name, operation.primary_key()))
+        });
+        read_keys.chain(written_keys)
+    }
+
+    pub async fn can_commit(
+        &self,
+        tables: Rc<Mutex<HashMap<String, Table>>>,
+        partition_map: &PartitionMap,
+    ) -> bool {
         let tables = tables.lock().await;
-        for (table_name, affected_row_versions) in &self.affected_rows {
-            let memtable = &tables.get(table_name).unwrap().memtable;
-
-            for (primary_key, version) in affected_row_versions {
-                match memtable.get(&primary_key) {
-                    Some(memtable_row) => {
-                        if version != &memtable_row.version {
-                            return false;
-                        }
-                    }
-                    None => {
-                        return false;
-                    }
+        self.can_commit_with(&tables, partition_map).await
+    }
+
+    // validates transaction and reserves its keys under a single tables lock, so that nothing
+    // written on this thread between prepare and commit goes unnoticed or gets overwritten,
+    // writes are persisted before voting to commit, so that they outlive a crash of this thread
+    pub async fn prepare(
+        &mut self,
+        tables: Rc<Mutex<HashMap<String, Table>>>,
+        data_dir: &DataDir,
+        partitions: &HashSet<usize>,
+        partition_map: &PartitionMap,
+    ) -> bool {
+        let record_bytes = {
+            let mut tables = tables.lock().await;
+            if !self.can_commit_with(&tables, partition_map).await {
+                return false;
+            }
+            self.place_intents(&mut tables);
+            if self.operations.is_empty() {
+                self.prepared = true;
+                return true;
+            }
+            encode_operations(&self.operations, &tables)
+        };
+
+        let partition = *partitions.iter().min().unwrap();
+        match write_prepare_record(data_dir, self.id, partition, record_bytes).await {
+            Ok(record_path) => {
+                self.prepare_record = Some(record_path);
+                self.prepared = true;
+                true
+            }
+            Err(error) => {
+                tracing::error!("Failed to persist prepared transaction: {}", error);
+                self.release_intents(tables).await;
+                false
+            }
+        }
+    }
+
+    fn place_intents(&self, tables: &mut HashMap<String, Table>) {
+        let placed_at = millis_from_epoch();
+        for (table_name, primary_key) in self.touched_keys() {
+            if let Some(table) = tables.get_mut(table_name) {
+                table.write_intents.insert(
+                    primary_key.clone(),
+                    WriteIntent {
+                        transaction_id: self.id,
+                        placed_at,
+                    },
+                );
+            }
+        }
+    }
+
+    // transaction is aborted, so its prepare record is dropped as well
+    pub async fn release_intents(&self, tables: Rc<Mutex<HashMap<String, Table>>>) {
+        let mut tables = tables.lock().await;
+        self.release_intents_with(&mut tables);
+        if let Some(record_path) = &self.prepare_record {
+            remove_prepare_record(record_path, self.id);
+        }
+    }
+
+    fn release_intents_with(&self, tables: &mut HashMap<String, Table>) {
+        for (table_name, primary_key) in self.touched_keys() {
+            if let Some(table) = tables.get_mut(table_name) {
+                let held = table
+                    .write_intents
+                    .get(primary_key)
+                    .is_some_and(|intent| intent.transaction_id == self.id);
+                if held {
+                    table.write_intents.remove(primary_key);
                 }
             }
         }
-        true
+    }
+
+    async fn can_commit_with(
+        &self,
+        tables: &HashMap<String, Table>,
+        partition_map: &PartitionMap,
+    ) -> bool {
+        // table got dropped while transaction was running
+        if !self
+            .affected_tables()
+            .iter()
+            .all(|table_name| tables.contains_key(table_name))
+        {
+            return false;
+        }
+
+        // rows read from sstables may still be there only, plain inserts of those restart
+        // their version, so timestamp tells them apart
+        for (table_name, affected_rows) in &self.affected_rows {
+            let table = &tables[table_name];
+
+            for (primary_key, affected_row) in affected_rows {
+                let partition = partition_map.get_partition(&affected_row.hash_key);
+                match read_visible_row(table, primary_key, partition).await {
+                    Some(row)
+                        if row.version == affected_row.version
+                            && row.timestamp == affected_row.timestamp => {}
+                    _ => return false,
+                }
+            }
+        }
+
+        // keys prepared by another transaction may get written before this one commits
+        self.touched_keys().all(|(table_name, primary_key)| {
+            let intent_holder = tables
+                .get(table_name)
+                .and_then(|table| table.write_intent_holder(primary_key));
+            intent_holder.is_none_or(|transaction_id| transaction_id == self.id)
+        })
     }
 
     pub async fn commit(
         &mut self,
-        tables: Arc<Mutex<HashMap<String, Table>>>,
-        total_number_of_partitions: usize,
+        tables: Rc<Mutex<HashMap<String, Table>>>,
+        partitions: &HashSet<usize>,
+        partition_map: &PartitionMap,
     ) {
         let mut tables = tables.lock().await;
         self.committed = true;
+        let mut synced = Ok(());
 
         for (table_name, operations) in &self.operations {
-            let table = tables.get_mut(table_name).unwrap();
+            // table dropped after transaction got prepared, its writes are dropped along with it
+            let Some(table) = tables.get_mut(table_name) else {
+                tracing::warn!(
+                    "Table '{}' was dropped before transaction with id '{}' committed",
+                    table_name,
+                    self.id
+                );
+                continue;
+            };
+            {
+                let mut commit_log = table.commit_log.lock().await;
+                for operation in operations {
+                    match operation {
+                        Operation::Insert(row, _) => commit_log.write_insert(row).await,
+                        Operation::Delete(tombstone, _) => commit_log.write_insert(tombstone).await,
+                    }
+                }
+            }
             for operation in operations {
                 match operation {
-                    Operation::Insert(row) => {
-                        table.memtable.insert(row.clone(), false);
-                        if table.memtable.max_size_reached() {
-                            let mut full_memtable = Memtable::default();
-                            std::mem::swap(&mut table.memtable, &mut full_memtable);
-
-                            monoio::spawn(flush_memtable_to_sstable(
-                                full_memtable,
-                                table.commit_log.clone(),
-                                table.table_schema.clone(),
-                                total_number_of_partitions,
-                            ));
-                        }
+                    Operation::Insert(row, auth) => {
+                        table.count_insert(row, partition_map).await;
+                        table.track_access(row);
+                        table.apply_insert(row.clone(), auth);
+                        table.evict_least_recently_used().await;
                     }
-                    Operation::Delete(primary_key) => {
-                        table.memtable.delete(primary_key, None);
+                    Operation::Delete(tombstone, auth) => {
+                        table.memtable.insert(tombstone.clone(), false);
+                        let (hash_key, primary_key) = (&tombstone.hash_key, &tombstone.primary_key);
+                        table.on_row_deleted(hash_key, primary_key, auth).await;
                     }
                 }
             }
+            // record is only dropped once its writes are durable, so they are synced before a
+            // flush rotates the commit log holding them
+            if self.prepare_record.is_some() && synced.is_ok() {
+                synced = table.commit_log.lock().await.sync().await;
+            }
+            table
+                .flush_memtable_if_needed(partitions, partition_map)
+                .await;
+        }
+        self.release_intents_with(&mut tables);
+
+        if let Some(record_path) = self.prepare_record.take() {
+            if let Err(error) = synced {
+                tracing::error!("{}", error);
+                return;
+            }
+            remove_prepare_record(&record_path, self.id);
+        }
+    }
+}
+
+// transactions this thread prepared before it crashed, those coordinator decided to commit get
+// committed, the rest keep their keys reserved while in doubt, until coordinator commits or aborts them
+pub async fn recover_prepared_transactions(
+    tables: Rc<Mutex<HashMap<String, Table>>>,
+    data_dir: &DataDir,
+    partitions: &HashSet<usize>,
+    partition_map: &PartitionMap,
+) -> Vec<Transaction> {
+    let prepare_records = read_prepare_records(data_dir, partitions, &*tables.lock().await);
+
+    let mut in_doubt = Vec::new();
+    for prepare_record in prepare_records {
+        let mut transaction = Transaction::new(prepare_record.transaction_id);
+        transaction.operations = prepare_record.operations;
+        transaction.prepared = true;
+        transaction.prepare_record = Some(prepare_record.path);
+
+        match is_commit_decided(data_dir, transaction.id) {
+            true => {
+                transaction
+                    .commit(tables.clone(), partitions, partition_map)
+                    .await
+            }
+            false => {
+                transaction.place_intents(&mut *tables.lock().await);
+                in_doubt.push(transaction);
+            }
         }
     }
+    in_doubt
 }
 
-enum Operation {
-    Insert(Row),
-    Delete(String),
+// tombstone shadows the row in sstables as well, so it gets the next version
+pub(crate) fn tombstone_of(row: Row) -> Row {
+    Row {
+        values: HashMap::new(),
+        version: row.version + 1,
+        timestamp: millis_from_epoch(),
+        marked_for_deletion: true,
+        ..row
+    }
+}
+
+// operations keep who issued them, hooks are notified only once transaction commits
+pub(crate) enum Operation {
+    Insert(Row, AuthContext),
+    // tombstone of deleted row
+    Delete(Row, AuthContext),
+}
+
+impl Operation {
+    fn primary_key(&self) -> &String {
+        match self {
+            Operation::Insert(row, _) => &row.primary_key,
+            Operation::Delete(tombstone, _) => &tombstone.primary_key,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::commit_log::CommitLog;
+    use crate::cache::AccessTracker;
+    use crate::commit_log::{CommitLog, PartialFlushes};
+    use crate::prepare_log::{
+        decide_transaction, prepared_transactions_dir, release_abort_decision,
+    };
+    use crate::sstable::write_memtable_to_sstable;
     use crate::table::{ColumnType, TableSchema};
+    use crate::{Memtable, MEGABYTE};
     use common::value::Value::{Int32, Varchar};
     use rand::{thread_rng, RngCore};
+    use std::sync::Arc;
 
     fn get_table() -> Table {
         let table_schema = TableSchema::new("table".to_string(), ColumnType::Varchar(100));
@@ -147,14 +416,37 @@ mod tests {
             table_schema: table_schema.clone(),
             partition: 0,
             closed: true,
+            partial_flushes: PartialFlushes::default(),
         };
         Table {
             memtable,
             commit_log: Arc::new(Mutex::new(commit_log)),
             table_schema,
+            sequences: HashMap::new(),
+            leases: HashMap::new(),
+            row_counts: HashMap::new(),
+            access_tracker: AccessTracker::default(),
+            data_dir: DataDir::default(),
+            write_intents: HashMap::new(),
         }
     }
 
+    // committed writes are logged, so table committed to needs its commit log on disk
+    async fn get_table_in(root: &str) -> Table {
+        let data_dir = DataDir::new(root);
+        data_dir.create_dirs().unwrap();
+        let mut table = get_table();
+        let commit_log = CommitLog::open_new(
+            &table.table_schema,
+            &HashSet::from([0]),
+            &data_dir.commit_logs,
+        )
+        .await;
+        table.commit_log = Arc::new(Mutex::new(commit_log));
+        table.data_dir = data_dir;
+        table
+    }
+
     fn get_row(key: &str) -> Row {
         Row::new(key.to_string(), Varchar(key.to_string()), HashMap::new())
     }
@@ -171,42 +463,75 @@ mod tests {
         let mut transaction = get_new_transaction();
         transaction.get_for_update(Some(&row), "table".to_string());
 
-        let row_version = transaction.affected_rows["table"][&row.primary_key];
+        let row_version = transaction.affected_rows["table"][&row.primary_key].version;
         assert_eq!(row_version, 1);
     }
 
-    #[test]
-    fn test_insert_delete() {
+    #[monoio::test]
+    async fn test_insert_delete() {
+        let root = "/tmp/test_insert_delete";
+        let _ = std::fs::remove_dir_all(root);
         let mut transaction = get_new_transaction();
-        let table = get_table();
+        let table = get_table_in(root).await;
         let row = get_row("1");
 
-        transaction.insert(row.clone(), &table);
-        transaction.delete("some_delete".to_string(), &table);
+        transaction.insert(row.clone(), &table, &AuthContext::anonymous());
+        transaction
+            .delete(
+                "some_delete".to_string(),
+                0,
+                &table,
+                &AuthContext::anonymous(),
+            )
+            .await;
 
         assert_eq!(transaction.affected_rows, HashMap::new());
         assert_eq!(transaction.operations["table"].len(), 1);
     }
 
-    #[test]
-    fn test_insert_delete_when_data_in_memtable() {
+    #[monoio::test]
+    async fn test_insert_delete_when_data_in_memtable() {
         let mut transaction = get_new_transaction();
         let mut table = get_table();
 
         let row = get_row("1");
         table.memtable.insert(row.clone(), false);
 
-        transaction.insert(row.clone(), &table);
-        transaction.delete(row.primary_key.clone(), &table);
-
-        let row_version = transaction.affected_rows["table"][&row.primary_key];
+        transaction.insert(row.clone(), &table, &AuthContext::anonymous());
+        transaction
+            .delete(
+                row.primary_key.clone(),
+                0,
+                &table,
+                &AuthContext::anonymous(),
+            )
+            .await;
+
+        let row_version = transaction.affected_rows["table"][&row.primary_key].version;
         assert_eq!(row_version, 1);
         assert_eq!(transaction.operations["table"].len(), 2);
     }
 
+    #[test]
+    fn test_affected_tables() {
+        let mut transaction = get_new_transaction();
+        let table = get_table();
+        assert!(transaction.affected_tables().is_empty());
+
+        transaction.get_for_update(Some(&get_row("1")), "other_table".to_string());
+        transaction.insert(get_row("2"), &table, &AuthContext::anonymous());
+
+        assert_eq!(
+            transaction.affected_tables(),
+            BTreeSet::from(["other_table".to_string(), "table".to_string()])
+        );
+    }
+
     #[monoio::test]
     async fn test_commit_transaction() {
-        let mut table = get_table();
+        let root = "/tmp/test_commit_transaction";
+        let _ = std::fs::remove_dir_all(root);
+        let mut table = get_table_in(root).await;
 
         let mut row = get_row("1");
         let row_2 = get_row("2");
@@ -216,14 +541,27 @@ mod tests {
 
         row.values.insert("a".to_string(), Int32(1));
         let mut transaction = get_new_transaction();
-        transaction.insert(row.clone(), &table);
-        transaction.delete(row_2.primary_key.clone(), &table);
+        transaction.insert(row.clone(), &table, &AuthContext::anonymous());
+        transaction
+            .delete(
+                row_2.primary_key.clone(),
+                0,
+                &table,
+                &AuthContext::anonymous(),
+            )
+            .await;
 
         let tables = HashMap::from([("table".to_string(), table)]);
-        let tables = Arc::new(Mutex::new(tables));
-
-        assert!(transaction.can_commit(tables.clone()).await);
-        transaction.commit(tables.clone(), 1).await;
+        let tables = Rc::new(Mutex::new(tables));
+
+        assert!(
+            transaction
+                .can_commit(tables.clone(), &PartitionMap::new(1))
+                .await
+        );
+        transaction
+            .commit(tables.clone(), &HashSet::from([0]), &PartitionMap::new(1))
+            .await;
         {
             let tables = tables.lock().await;
             let table = tables.get("table").unwrap();
@@ -236,6 +574,53 @@ mod tests {
         }
     }
 
+    #[monoio::test(timer_enabled = true)]
+    async fn committed_transactions_keep_writing_after_filling_memtable() {
+        let root = "/tmp/committed_transactions_keep_writing_after_filling_memtable";
+        let _ = std::fs::remove_dir_all(root);
+        let table = get_table_in(root).await;
+        let first_commit_log = table.commit_log.clone();
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        let commit_row = |key: String| {
+            let tables = tables.clone();
+            async move {
+                let mut row = get_row(&key);
+                row.values
+                    .insert("text".to_string(), Varchar("x".repeat(MEGABYTE)));
+                let mut transaction = get_new_transaction();
+                transaction.insert(
+                    row,
+                    &tables.lock().await["table"],
+                    &AuthContext::anonymous(),
+                );
+                transaction
+                    .commit(tables, &HashSet::from([0]), &PartitionMap::new(1))
+                    .await;
+            }
+        };
+
+        let mut key = 0;
+        while Arc::ptr_eq(&tables.lock().await["table"].commit_log, &first_commit_log) {
+            key += 1;
+            commit_row(key.to_string()).await;
+        }
+        // full memtable got flushed from the commit log it was logged to, which gets deleted then
+        for _ in 0..500 {
+            if first_commit_log.lock().await.file.is_none() {
+                break;
+            }
+            monoio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(first_commit_log.lock().await.file.is_none());
+
+        commit_row("last".to_string()).await;
+        let tables = tables.lock().await;
+        let table = &tables["table"];
+        assert!(table.memtable.get(&"last:last".to_string()).is_some());
+        assert!(table.commit_log.lock().await.file_offset > 0);
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
     #[monoio::test]
     async fn test_get_for_update_cannot_commit_after_insert() {
         let mut table = get_table();
@@ -248,8 +633,8 @@ mod tests {
         row.values.insert("a".to_string(), Int32(1));
         table.memtable.insert(row.clone(), false);
 
-        let tables = Arc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
-        assert!(!transaction.can_commit(tables).await);
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        assert!(!transaction.can_commit(tables, &PartitionMap::new(1)).await);
     }
 
     #[monoio::test]
@@ -260,38 +645,340 @@ mod tests {
         table.memtable.insert(row.clone(), false);
 
         let mut transaction = get_new_transaction();
-        transaction.delete(row.primary_key.clone(), &table);
+        transaction
+            .delete(
+                row.primary_key.clone(),
+                0,
+                &table,
+                &AuthContext::anonymous(),
+            )
+            .await;
 
         row.values.insert("a".to_string(), Int32(1));
         table.memtable.insert(row.clone(), false);
 
-        let tables = Arc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
-        assert!(!transaction.can_commit(tables).await);
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        assert!(!transaction.can_commit(tables, &PartitionMap::new(1)).await);
     }
 
     #[monoio::test]
-    async fn test_two_transactions_simultaneously_commit() {
+    async fn test_dropped_table_is_skipped_instead_of_panicking() {
         let table = get_table();
+        let mut transaction = get_new_transaction();
+        transaction.get_for_update(Some(&get_row("1")), "dropped_table".to_string());
+        transaction.insert(get_row("2"), &table, &AuthContext::anonymous());
+
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        assert!(
+            !transaction
+                .can_commit(tables.clone(), &PartitionMap::new(1))
+                .await
+        );
+
+        // writes of a transaction prepared before the drop are dropped along with the table
+        tables.lock().await.remove("table");
+        transaction
+            .commit(tables.clone(), &HashSet::from([0]), &PartitionMap::new(1))
+            .await;
+        assert!(tables.lock().await.is_empty());
+    }
+
+    #[monoio::test]
+    async fn test_two_transactions_simultaneously_commit() {
+        let root = "/tmp/test_two_transactions_simultaneously_commit";
+        let _ = std::fs::remove_dir_all(root);
+        let table = get_table_in(root).await;
         let row = get_row("1");
         let row_2 = get_row("2");
 
         let mut transaction_1 = get_new_transaction();
         let mut transaction_2 = get_new_transaction();
 
-        transaction_1.insert(row.clone(), &table);
-        transaction_2.insert(row_2.clone(), &table);
-
-        let tables = Arc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
-        assert!(transaction_1.can_commit(tables.clone()).await);
-        assert!(transaction_2.can_commit(tables.clone()).await);
-
-        transaction_1.commit(tables.clone(), 1).await;
-        assert!(transaction_2.can_commit(tables.clone()).await);
-        transaction_2.commit(tables.clone(), 1).await;
+        transaction_1.insert(row.clone(), &table, &AuthContext::anonymous());
+        transaction_2.insert(row_2.clone(), &table, &AuthContext::anonymous());
+
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        assert!(
+            transaction_1
+                .can_commit(tables.clone(), &PartitionMap::new(1))
+                .await
+        );
+        assert!(
+            transaction_2
+                .can_commit(tables.clone(), &PartitionMap::new(1))
+                .await
+        );
+
+        transaction_1
+            .commit(tables.clone(), &HashSet::from([0]), &PartitionMap::new(1))
+            .await;
+        assert!(
+            transaction_2
+                .can_commit(tables.clone(), &PartitionMap::new(1))
+                .await
+        );
+        transaction_2
+            .commit(tables.clone(), &HashSet::from([0]), &PartitionMap::new(1))
+            .await;
 
         let tables_lock = tables.lock().await;
         let table = tables_lock.get("table").unwrap();
         assert!(table.memtable.get(&row.primary_key).is_some());
         assert!(table.memtable.get(&row_2.primary_key).is_some());
     }
+
+    #[monoio::test]
+    async fn test_prepared_transaction_reserves_its_keys() {
+        let root = "/tmp/test_prepared_transaction_reserves_its_keys";
+        let _ = std::fs::remove_dir_all(root);
+        let mut table = get_table_in(root).await;
+        let data_dir = table.data_dir.clone();
+        let partitions = HashSet::from([0]);
+        let row = get_row("1");
+        table.memtable.insert(row.clone(), false);
+
+        let mut transaction_1 = get_new_transaction();
+        let mut transaction_2 = get_new_transaction();
+        transaction_1.insert(row.clone(), &table, &AuthContext::anonymous());
+        transaction_2
+            .delete(
+                row.primary_key.clone(),
+                0,
+                &table,
+                &AuthContext::anonymous(),
+            )
+            .await;
+
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        assert!(
+            transaction_1
+                .prepare(
+                    tables.clone(),
+                    &data_dir,
+                    &partitions,
+                    &PartitionMap::new(1)
+                )
+                .await
+        );
+        assert_eq!(
+            tables
+                .lock()
+                .await
+                .get("table")
+                .unwrap()
+                .write_intent_holder(&row.primary_key),
+            Some(transaction_1.id)
+        );
+        assert!(
+            !transaction_2
+                .prepare(
+                    tables.clone(),
+                    &data_dir,
+                    &partitions,
+                    &PartitionMap::new(1)
+                )
+                .await
+        );
+
+        transaction_1
+            .commit(tables.clone(), &HashSet::from([0]), &PartitionMap::new(1))
+            .await;
+        assert!(tables
+            .lock()
+            .await
+            .get("table")
+            .unwrap()
+            .write_intents
+            .is_empty());
+        // row got a new version meanwhile
+        assert!(
+            !transaction_2
+                .can_commit(tables.clone(), &PartitionMap::new(1))
+                .await
+        );
+    }
+
+    #[monoio::test]
+    async fn in_doubt_transaction_is_committed_once_decided() {
+        let root = "/tmp/in_doubt_transaction_is_committed_once_decided";
+        let _ = std::fs::remove_dir_all(root);
+        let table = get_table_in(root).await;
+        let data_dir = table.data_dir.clone();
+        let partitions = HashSet::from([0]);
+        let partition_map = PartitionMap::new(1);
+        let row = get_row("1");
+
+        let mut transaction = get_new_transaction();
+        transaction.insert(row.clone(), &table, &AuthContext::anonymous());
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        assert!(
+            transaction
+                .prepare(
+                    tables.clone(),
+                    &data_dir,
+                    &partitions,
+                    &PartitionMap::new(1)
+                )
+                .await
+        );
+
+        // thread crashed before commit reached it, coordinator did not decide yet
+        let table = get_table_in(root).await;
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        let in_doubt =
+            recover_prepared_transactions(tables.clone(), &data_dir, &partitions, &partition_map)
+                .await;
+        assert_eq!(in_doubt.len(), 1);
+        assert_eq!(in_doubt[0].id, transaction.id);
+        {
+            let tables = tables.lock().await;
+            let table = tables.get("table").unwrap();
+            assert_eq!(
+                table.write_intent_holder(&row.primary_key),
+                Some(transaction.id)
+            );
+            assert!(table.memtable.get(&row.primary_key).is_none());
+        }
+
+        // coordinator decided to commit and crashed before telling this thread
+        assert!(decide_transaction(&data_dir, transaction.id, true)
+            .await
+            .unwrap());
+        let table = get_table_in(root).await;
+        let tables = Rc::new(Mutex::new(HashMap::from([("table".to_string(), table)])));
+        let in_doubt =
+            recover_prepared_transactions(tables.clone(), &data_dir, &partitions, &partition_map)
+                .await;
+        assert!(in_doubt.is_empty());
+        assert!(tables
+            .lock()
+            .await
+            .get("table")
+            .unwrap()
+            .memtable
+            .get(&row.primary_key)
+            .is_some());
+
+        // both the record and the decision are gone once committed
+        let prepared_files = std::fs::read_dir(prepared_transactions_dir(&data_dir))
+            .unwrap()
+            .count();
+        assert_eq!(prepared_files, 0);
+    }
+
+    #[monoio::test]
+    async fn first_decision_on_transaction_wins() {
+        let root = "/tmp/first_decision_on_transaction_wins";
+        let _ = std::fs::remove_dir_all(root);
+        let data_dir = get_table_in(root).await.data_dir.clone();
+
+        // participant gave up on its intents before coordinator got to commit
+        assert!(!decide_transaction(&data_dir, 1, false).await.unwrap());
+        assert!(!decide_transaction(&data_dir, 1, true).await.unwrap());
+        assert!(!is_commit_decided(&data_dir, 1));
+
+        // coordinator committed before participant gave up
+        assert!(decide_transaction(&data_dir, 2, true).await.unwrap());
+        assert!(decide_transaction(&data_dir, 2, false).await.unwrap());
+        assert!(is_commit_decided(&data_dir, 2));
+
+        // coordinator drops decision to abort once it learned about it
+        release_abort_decision(&data_dir, 1);
+        let prepared_files = std::fs::read_dir(prepared_transactions_dir(&data_dir))
+            .unwrap()
+            .count();
+        assert_eq!(prepared_files, 1);
+    }
+
+    #[monoio::test]
+    async fn flushed_row_is_moved_across_partitions() {
+        let root = "/tmp/flushed_row_is_moved_across_partitions";
+        let _ = std::fs::remove_dir_all(root);
+        let partition_map = PartitionMap::new(2);
+        let auth = AuthContext::anonymous();
+        let old_key = "file_0".to_string();
+        let old_partition = partition_map.get_partition(&old_key);
+        let new_key = (1..)
+            .map(|number| format!("file_{}", number))
+            .find(|key| partition_map.get_partition(key) != old_partition)
+            .unwrap();
+
+        // threads owning each partition have tables of their own
+        let old_table = get_table_in(&format!("{}/old", root)).await;
+        let new_table = get_table_in(&format!("{}/new", root)).await;
+        let row = get_row(&old_key);
+        let mut flushed_memtable = Memtable::default();
+        flushed_memtable.insert(row.clone(), false);
+        write_memtable_to_sstable(
+            &flushed_memtable,
+            &old_table.table_schema,
+            &partition_map,
+            millis_from_epoch(),
+            &old_table.data_dir.sstables,
+        )
+        .await
+        .unwrap();
+
+        let transaction_id = thread_rng().next_u64();
+        let mut old_transaction = Transaction::new(transaction_id);
+        let mut new_transaction = Transaction::new(transaction_id);
+        let read_row = read_visible_row(&old_table, &row.primary_key, old_partition).await;
+        old_transaction.get_for_update(read_row.as_ref(), "table".to_string());
+        assert!(
+            old_transaction
+                .delete(row.primary_key.clone(), old_partition, &old_table, &auth)
+                .await
+        );
+        let moved_row = Row::new(new_key.clone(), Varchar(new_key), row.values.clone());
+        new_transaction.insert(moved_row.clone(), &new_table, &auth);
+
+        let old_data_dir = old_table.data_dir.clone();
+        let new_data_dir = new_table.data_dir.clone();
+        let old_tables = Rc::new(Mutex::new(HashMap::from([(
+            "table".to_string(),
+            old_table,
+        )])));
+        let new_tables = Rc::new(Mutex::new(HashMap::from([(
+            "table".to_string(),
+            new_table,
+        )])));
+        let old_partitions = HashSet::from([old_partition]);
+        let new_partitions = HashSet::from([1 - old_partition]);
+        assert!(
+            old_transaction
+                .prepare(
+                    old_tables.clone(),
+                    &old_data_dir,
+                    &old_partitions,
+                    &partition_map
+                )
+                .await
+        );
+        assert!(
+            new_transaction
+                .prepare(
+                    new_tables.clone(),
+                    &new_data_dir,
+                    &new_partitions,
+                    &partition_map
+                )
+                .await
+        );
+        old_transaction
+            .commit(old_tables.clone(), &old_partitions, &partition_map)
+            .await;
+        new_transaction
+            .commit(new_tables.clone(), &new_partitions, &partition_map)
+            .await;
+
+        let old_tables = old_tables.lock().await;
+        let old_table = old_tables.get("table").unwrap();
+        assert!(read_visible_row(old_table, &row.primary_key, old_partition)
+            .await
+            .is_none());
+        let new_tables = new_tables.lock().await;
+        let new_table = new_tables.get("table").unwrap();
+        assert!(new_table.memtable.get(&moved_row.primary_key).is_some());
+        std::fs::remove_dir_all(root).unwrap();
+    }
 }
@@ -1,40 +1,99 @@
+use crate::compression::{compress_value_above, decompress_value, is_compressed};
 use crate::table::{ColumnType, TableSchema};
 use crate::{Row, HASH_KEY_BYTE_SIZE};
 use common::value::Value;
 use std::collections::HashMap;
-use std::io::{BufWriter, Write};
 use std::mem::size_of;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+// bits of encoded timestamp field below row version
+static VERSION_SHIFT: u32 = 96;
+
 pub fn millis_from_epoch() -> u128 {
+    #[cfg(feature = "simulation")]
+    if let Some(millis) = crate::simulation::simulated_millis() {
+        return millis;
+    }
+
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_millis()
 }
 
+// files of a table are named by its name followed by '-' separated numbers, table names cannot
+// contain '-', so that name ends at the first one, None for files named otherwise
+pub(crate) fn parse_table_file_name(file_name: &str) -> Option<(&str, Vec<u128>)> {
+    let mut parts = file_name.split('-');
+    let table_name = parts.next()?;
+    let numbers = parts
+        .map(|part| part.parse().ok())
+        .collect::<Option<Vec<u128>>>()?;
+    Some((table_name, numbers))
+}
+
 pub fn encode_row(row: &Row, table_schema: &TableSchema) -> Vec<u8> {
+    let values = table_schema
+        .columns
+        .keys()
+        .map(|name| Some(encode_value(row.values.get(name)?, table_schema)));
+    encode_row_values(
+        &row.hash_key,
+        &row.sort_key,
+        values,
+        row.version,
+        row.timestamp,
+        row.marked_for_deletion,
+        table_schema,
+    )
+}
+
+// large text of tables compressing values is flagged, so that it is told apart from plain text
+pub(crate) fn encode_value(value: &Value, table_schema: &TableSchema) -> Vec<u8> {
+    match value {
+        Value::Varchar(text) => {
+            compress_value_above(text, table_schema.options.compress_values_above)
+                .unwrap_or_else(|| text.as_bytes().to_vec())
+        }
+        _ => value.clone().to_bytes(),
+    }
+}
+
+// encoded values come in order of schema columns, missing ones, e.g. of tombstones, are left zeroed
+pub(crate) fn encode_row_values(
+    hash_key: &str,
+    sort_key: &Value,
+    values: impl Iterator<Item = Option<Vec<u8>>>,
+    version: u32,
+    timestamp: u128,
+    marked_for_deletion: bool,
+    table_schema: &TableSchema,
+) -> Vec<u8> {
     let mut bytes = Vec::new();
 
-    let mut hash_key_bytes = BufWriter::new(vec![0u8; HASH_KEY_BYTE_SIZE]);
-    hash_key_bytes.write_all(row.hash_key.as_bytes()).unwrap();
-    bytes.append(hash_key_bytes.get_mut());
+    // hash key is length prefixed and padded, so that every row has the same size
+    let hash_key_bytes = hash_key.as_bytes();
+    bytes.extend_from_slice(&(hash_key_bytes.len() as u16).to_be_bytes());
+    bytes.extend_from_slice(hash_key_bytes);
+    bytes.resize(size_of::<u16>() + table_schema.hash_key_size(), 0);
 
-    let mut sort_key_bytes = row.sort_key.clone().to_bytes();
+    // sort key and values are zero padded to size of their column, as decoding expects
+    let mut sort_key_bytes = sort_key.clone().to_bytes();
+    sort_key_bytes.resize(table_schema.sort_key_type.byte_size(), 0);
     bytes.append(&mut sort_key_bytes);
 
-    for (name, column) in &table_schema.columns {
-        let mut value_buffer = BufWriter::new(vec![0u8; column.column_type.byte_size()]);
-        value_buffer
-            .write_all(&row.values.get(name).unwrap().clone().to_bytes())
-            .unwrap();
-        bytes.append(&mut value_buffer.get_mut());
+    for (column, value) in table_schema.columns.values().zip(values) {
+        let mut value_bytes = value.unwrap_or_default();
+        value_bytes.resize(column.column_type.byte_size(), 0);
+        bytes.append(&mut value_bytes);
     }
 
-    let mut timestamp_bytes = row.timestamp.to_be_bytes().to_vec();
-    bytes.append(&mut timestamp_bytes);
+    // version takes the highest bytes of timestamp field, which millis never reach, so that rows
+    // written before versions were stored read back with version 0
+    let timestamp_field = ((version as u128) << VERSION_SHIFT) | timestamp;
+    bytes.extend_from_slice(&timestamp_field.to_be_bytes());
 
-    if row.marked_for_deletion {
+    if marked_for_deletion {
         bytes.push(1);
     } else {
         bytes.push(0);
@@ -44,11 +103,33 @@ pub fn encode_row(row: &Row, table_schema: &TableSchema) -> Vec<u8> {
 }
 
 pub fn decode_row(bytes: &[u8], table_schema: &TableSchema) -> Row {
+    let hash_key_length = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+    let hash_key_offset = size_of::<u16>();
+    let hash_key =
+        String::from_utf8(bytes[hash_key_offset..hash_key_offset + hash_key_length].to_vec())
+            .unwrap();
+
+    decode_row_after_hash_key(
+        hash_key,
+        &bytes[hash_key_offset + table_schema.hash_key_size()..],
+        table_schema,
+    )
+}
+
+// rows written before hash keys were length prefixed have them zero padded
+pub fn decode_legacy_row(bytes: &[u8], table_schema: &TableSchema) -> Row {
     let hash_key = String::from_utf8(bytes[..HASH_KEY_BYTE_SIZE].to_vec()).unwrap();
-    let mut offset = HASH_KEY_BYTE_SIZE;
+    let hash_key = hash_key.trim_end_matches('\0').to_string();
+
+    decode_row_after_hash_key(hash_key, &bytes[HASH_KEY_BYTE_SIZE..], table_schema)
+}
 
+fn decode_row_after_hash_key(hash_key: String, bytes: &[u8], table_schema: &TableSchema) -> Row {
+    let mut offset = 0;
+
+    // sort keys are never null, so leading zero byte is part of the key, e.g. of small unsigned ones
     let sort_key_size = table_schema.sort_key_type.byte_size();
-    let sort_key = parse_value_from_bytes(
+    let sort_key = parse_present_value_from_bytes(
         bytes[offset..offset + sort_key_size].to_vec(),
         table_schema.sort_key_type.clone(),
     );
@@ -67,7 +148,7 @@ pub fn decode_row(bytes: &[u8], table_schema: &TableSchema) -> Row {
     }
 
     let timestamp_size = size_of::<u128>();
-    let timestamp = u128::from_be_bytes(
+    let timestamp_field = u128::from_be_bytes(
         bytes[offset..offset + timestamp_size]
             .to_vec()
             .try_into()
@@ -75,7 +156,9 @@ pub fn decode_row(bytes: &[u8], table_schema: &TableSchema) -> Row {
     );
     offset += timestamp_size;
 
+    let timestamp = timestamp_field & ((1 << VERSION_SHIFT) - 1);
     let mut row = Row::new_with_timestamp(hash_key, sort_key, values, timestamp);
+    row.version = (timestamp_field >> VERSION_SHIFT) as u32;
 
     if bytes[offset] == 1u8 {
         row.marked_for_deletion = true;
@@ -89,8 +172,20 @@ pub fn parse_value_from_bytes(bytes: Vec<u8>, column_type: ColumnType) -> Value
         return Value::Null;
     }
 
+    parse_present_value_from_bytes(bytes, column_type)
+}
+
+fn parse_present_value_from_bytes(bytes: Vec<u8>, column_type: ColumnType) -> Value {
     match column_type {
-        ColumnType::Varchar(_) => Value::Varchar(String::from_utf8(bytes).unwrap()),
+        ColumnType::Varchar(_) if is_compressed(&bytes) => {
+            Value::Varchar(decompress_value(&bytes).unwrap())
+        }
+        ColumnType::Varchar(_) => Value::Varchar(
+            String::from_utf8(bytes)
+                .unwrap()
+                .trim_end_matches('\0')
+                .to_string(),
+        ),
         ColumnType::Int32 => Value::Int32(i32::from_be_bytes(bytes.try_into().unwrap())),
         ColumnType::Int64 => Value::Int64(i64::from_be_bytes(bytes.try_into().unwrap())),
         ColumnType::Unsigned32 => Value::Unsigned32(u32::from_be_bytes(bytes.try_into().unwrap())),
@@ -103,3 +198,66 @@ pub fn parse_value_from_bytes(bytes: Vec<u8>, column_type: ColumnType) -> Value
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::Column;
+
+    #[test]
+    fn values_shorter_than_column_are_read_back() {
+        let mut table_schema = TableSchema::new("users".to_string(), ColumnType::Varchar(16));
+        table_schema.columns.insert(
+            "name".to_string(),
+            Column::new(ColumnType::Varchar(32), false),
+        );
+        table_schema
+            .columns
+            .insert("age".to_string(), Column::new(ColumnType::Int32, true));
+
+        let values = HashMap::from([
+            ("name".to_string(), Value::Varchar("Ada".to_string())),
+            ("age".to_string(), Value::Null),
+        ]);
+        let mut row = Row::new(
+            "user_1".to_string(),
+            Value::Varchar("profile".to_string()),
+            values.clone(),
+        );
+        row.version = 7;
+
+        let row_bytes = encode_row(&row, &table_schema);
+        assert_eq!(row_bytes.len(), table_schema.row_byte_size());
+        let decoded_row = decode_row(&row_bytes, &table_schema);
+        assert_eq!(decoded_row.hash_key, "user_1");
+        assert_eq!(decoded_row.sort_key, Value::Varchar("profile".to_string()));
+        assert_eq!(decoded_row.values, values);
+        assert_eq!(decoded_row.version, 7);
+        assert_eq!(decoded_row.timestamp, row.timestamp);
+    }
+
+    #[test]
+    fn compressed_text_longer_than_column_is_read_back() {
+        let table_schema = TableSchema::from_string(
+            "posts>sort_key:INT32;body:VARCHAR(64)#compress_values_above=32",
+        )
+        .unwrap();
+        let body = Value::Varchar("all work and no play ".repeat(10));
+        let values = HashMap::from([("body".to_string(), body.clone())]);
+        let row = Row::new("post_1".to_string(), Value::Int32(1), values);
+
+        let row_bytes = encode_row(&row, &table_schema);
+        assert_eq!(row_bytes.len(), table_schema.row_byte_size());
+        assert_eq!(decode_row(&row_bytes, &table_schema).values["body"], body);
+    }
+
+    #[test]
+    fn sort_key_starting_with_zero_byte_is_read_back() {
+        let table_schema = TableSchema::new("events".to_string(), ColumnType::Unsigned64);
+        let row = Row::new("stream_1".to_string(), Value::Unsigned64(7), HashMap::new());
+
+        let decoded_row = decode_row(&encode_row(&row, &table_schema), &table_schema);
+        assert_eq!(decoded_row.sort_key, Value::Unsigned64(7));
+        assert_eq!(decoded_row.primary_key, row.primary_key);
+    }
+}
@@ -1,6 +1,93 @@
+use crate::compression::compress_value_above;
+use crate::delete_where::DeleteWhere;
+use crate::index::IndexRange;
+use crate::move_row::MoveRow;
 use crate::table::{ColumnType, TableSchema};
+use crate::table_options::Transform;
 use common::value::Value;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Bound;
+use std::sync::Mutex;
+
+// columns dropped from inserts into lenient tables per table, for as long as server runs
+static DROPPED_COLUMNS: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+// normalizes inserted values before they are validated, null and missing values are left as they are
+pub fn transform_values(values: &mut HashMap<String, Value>, table_schema: &TableSchema) {
+    if table_schema.options.lenient_columns {
+        drop_unknown_columns(values, table_schema);
+    }
+
+    for column_transform in &table_schema.options.transforms {
+        let value = match values.get_mut(&column_transform.column) {
+            Some(Value::Varchar(value)) => value,
+            _ => continue,
+        };
+
+        match column_transform.transform {
+            Transform::Lowercase => *value = value.to_lowercase(),
+            Transform::Trim => *value = value.trim().to_string(),
+            Transform::Truncate => {
+                let max_length = match table_schema.columns.get(&column_transform.column) {
+                    Some(column) => match column.column_type {
+                        ColumnType::Varchar(max_length) => max_length,
+                        _ => continue,
+                    },
+                    None => continue,
+                };
+                // length is checked in bytes, multibyte characters are not cut in half
+                let mut length = max_length.min(value.len());
+                while !value.is_char_boundary(length) {
+                    length -= 1;
+                }
+                value.truncate(length);
+            }
+        }
+    }
+}
+
+fn drop_unknown_columns(values: &mut HashMap<String, Value>, table_schema: &TableSchema) {
+    let mut unknown_columns = unknown_columns(values, table_schema);
+    if unknown_columns.is_empty() {
+        return;
+    }
+    for column_name in &unknown_columns {
+        values.remove(column_name);
+    }
+
+    let dropped_columns = {
+        let mut dropped_columns = DROPPED_COLUMNS.lock().unwrap();
+        let dropped = dropped_columns
+            .entry(table_schema.name.clone())
+            .or_default();
+        *dropped += unknown_columns.len() as u64;
+        *dropped
+    };
+    unknown_columns.sort();
+    tracing::warn!(
+        "Dropped unknown fields {:?} inserted into table '{}', {} dropped so far",
+        unknown_columns,
+        table_schema.name,
+        dropped_columns
+    );
+}
+
+pub fn dropped_columns(table_name: &str) -> u64 {
+    DROPPED_COLUMNS
+        .lock()
+        .unwrap()
+        .get(table_name)
+        .copied()
+        .unwrap_or(0)
+}
+
+fn unknown_columns(values: &HashMap<String, Value>, table_schema: &TableSchema) -> Vec<String> {
+    values
+        .keys()
+        .filter(|column_name| !table_schema.columns.contains_key(*column_name))
+        .cloned()
+        .collect()
+}
 
 pub fn validate_values_against_schema(
     sort_key: &Value,
@@ -18,17 +105,36 @@ pub fn validate_values_against_schema(
             &table_schema.name, column_diff
         ));
     }
+    // unknown fields of lenient tables are dropped before validation
+    let mut unknown_columns = unknown_columns(values, table_schema);
+    if !unknown_columns.is_empty() {
+        unknown_columns.sort();
+        return Err(format!(
+            "Invalid fields for table '{}', {:?} are not in schema",
+            &table_schema.name, unknown_columns
+        ));
+    }
 
     let mut errors = Vec::new();
-    if let Value::Null = sort_key {
-        errors.push("'sort_key' cannot be null".to_string());
-    }
-    if !check_value_matches_column_type(sort_key, &table_schema.sort_key_type) {
-        errors.push(format!(
-            "'sort_key': expected '{}', got '{}'",
-            &table_schema.sort_key_type,
-            value_to_column_type(sort_key)
-        ));
+    if table_schema.options.append_only {
+        // server assigns it once the insert is validated
+        if !matches!(sort_key, Value::Null) {
+            errors.push("'sort_key' is assigned by server in append-only table".to_string());
+        }
+    } else {
+        if let Value::Null = sort_key {
+            errors.push("'sort_key' cannot be null".to_string());
+        }
+        if let Err(error) = sort_key.validate_key() {
+            errors.push(format!("'sort_key': {}", error));
+        }
+        if !check_value_matches_column_type(sort_key, &table_schema.sort_key_type) {
+            errors.push(format!(
+                "'sort_key': expected '{}', got '{}'",
+                &table_schema.sort_key_type,
+                value_to_column_type(sort_key)
+            ));
+        }
     }
 
     for (column_name, column) in &table_schema.columns {
@@ -49,7 +155,7 @@ pub fn validate_values_against_schema(
             ));
         }
 
-        if !check_string_length(&value, &column.column_type) {
+        if !check_string_length(&value, &column.column_type, table_schema) {
             let max_length = match &column.column_type {
                 ColumnType::Varchar(max_length) => max_length,
                 _ => panic!("Invalid column type"),
@@ -71,15 +177,114 @@ pub fn validate_values_against_schema(
     Ok(())
 }
 
-fn check_string_length(value: &Value, column_type: &ColumnType) -> bool {
+// bounds have to be values of the type of indexed column
+pub fn validate_index_range(
+    index_range: &IndexRange,
+    table_schema: &TableSchema,
+) -> Result<(), String> {
+    let column_type = match table_schema.columns.get(&index_range.column) {
+        Some(column) if table_schema.options.indexes.contains(&index_range.column) => {
+            &column.column_type
+        }
+        _ => {
+            return Err(format!(
+                "Column '{}' of table '{}' is not indexed",
+                index_range.column, &table_schema.name
+            ))
+        }
+    };
+    for bound in [&index_range.start, &index_range.end] {
+        let (Bound::Included(value) | Bound::Excluded(value)) = bound else {
+            continue;
+        };
+        if let Value::Null = value {
+            return Err(format!("'{}': bound cannot be null", index_range.column));
+        }
+        if !check_value_matches_column_type(value, column_type) {
+            return Err(format!(
+                "'{}': expected '{}', got '{}'",
+                index_range.column,
+                column_type,
+                value_to_column_type(value)
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// bounds have to be sort keys of the table, filter has to be a value of its column
+pub fn validate_delete_where(
+    delete_where: &DeleteWhere,
+    table_schema: &TableSchema,
+) -> Result<(), String> {
+    for bound in [&delete_where.start, &delete_where.end] {
+        let (Bound::Included(sort_key) | Bound::Excluded(sort_key)) = bound else {
+            continue;
+        };
+        validate_sort_key_of_table(sort_key, table_schema)?;
+    }
+
+    let Some((column_name, value)) = &delete_where.filter else {
+        return Ok(());
+    };
+    let column = table_schema.columns.get(column_name).ok_or(format!(
+        "Column '{}' is not a column of table '{}'",
+        column_name, &table_schema.name
+    ))?;
+    if !matches!(value, Value::Null) && !check_value_matches_column_type(value, &column.column_type)
+    {
+        return Err(format!(
+            "'{}': expected '{}', got '{}'",
+            column_name,
+            column.column_type,
+            value_to_column_type(value)
+        ));
+    }
+
+    Ok(())
+}
+
+// both keys have to be sort keys of the table, hash keys are checked by the caller
+pub fn validate_move_row(move_row: &MoveRow, table_schema: &TableSchema) -> Result<(), String> {
+    validate_sort_key_of_table(&move_row.sort_key, table_schema)?;
+    validate_sort_key_of_table(&move_row.new_sort_key, table_schema)
+}
+
+fn validate_sort_key_of_table(sort_key: &Value, table_schema: &TableSchema) -> Result<(), String> {
+    if let Value::Null = sort_key {
+        return Err("'sort_key': cannot be null".to_string());
+    }
+    if let Err(error) = sort_key.validate_key() {
+        return Err(format!("'sort_key': {}", error));
+    }
+    if !check_value_matches_column_type(sort_key, &table_schema.sort_key_type) {
+        return Err(format!(
+            "'sort_key': expected '{}', got '{}'",
+            &table_schema.sort_key_type,
+            value_to_column_type(sort_key)
+        ));
+    }
+
+    Ok(())
+}
+
+// compressed text only has to fit in the column once compressed
+fn check_string_length(
+    value: &Value,
+    column_type: &ColumnType,
+    table_schema: &TableSchema,
+) -> bool {
     match value {
-        Value::Varchar(value) => {
+        Value::Varchar(text) => {
             let max_length = match column_type {
                 ColumnType::Varchar(max_length) => max_length,
                 _ => panic!("Invalid column type"),
             };
 
-            max_length >= &value.len()
+            max_length >= &text.len()
+                || compress_value_above(text, table_schema.options.compress_values_above)
+                    .is_some_and(|bytes| max_length >= &bytes.len())
         }
         _ => true,
     }